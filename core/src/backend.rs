@@ -0,0 +1,184 @@
+//! The implementations of the conversion.
+#[cfg(windows)]
+use crate::convert;
+use crate::{EncodeOptions, OsResult, codepage};
+
+/// The information of a code page.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CpInfo {
+    /// The code page number.
+    pub code_page: u32,
+    /// The name of the code page, e.g. `ANSI - Latin I`.
+    ///
+    /// The Win32 backend returns the name in the language of the system, and
+    /// the table backend returns the one of English Windows.
+    pub name: String,
+    /// The maximum length of a character in bytes.
+    pub max_char_size: u32,
+}
+
+/// An implementation of the conversion between UTF-16 and the code pages.
+///
+/// The errors are the Win32 error codes, which are the same among the
+/// implementations.
+pub trait Backend {
+    /// Returns the name of the backend, e.g. `win32`.
+    fn name(&self) -> &'static str;
+
+    /// Converts `x` in the code page to UTF-16.
+    ///
+    /// Invalid bytes fail unless `lossy`, which replaces them.
+    fn decode(
+        &self,
+        code_page: u32,
+        x: &[u8],
+        lossy: bool,
+    ) -> OsResult<Vec<u16>>;
+
+    /// Converts `x` to the code page.
+    fn encode(
+        &self,
+        code_page: u32,
+        x: &[u16],
+        options: &EncodeOptions,
+    ) -> OsResult<Vec<u8>>;
+
+    /// Returns the information of the code page, or fails with
+    /// `ERROR_INVALID_PARAMETER` if it isn't supported.
+    fn cp_info(&self, code_page: u32) -> OsResult<CpInfo>;
+
+    /// Returns the ANSI code page which Windows uses for the locale of the
+    /// host.
+    fn system_default_acp(&self) -> OsResult<u32>;
+
+    /// Returns the OEM code page which Windows uses for the locale of the
+    /// host.
+    fn system_default_oemcp(&self) -> OsResult<u32>;
+}
+
+/// The backend using the built-in tables, which is available on any host.
+///
+/// The system default code pages are guessed from `LC_ALL`, `LC_CTYPE` and
+/// `LANG`. Lossy decoding replaces invalid bytes with U+FFFD, while Windows
+/// uses the default character of the code page, e.g. U+30FB for 932.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TableBackend;
+
+impl Backend for TableBackend {
+    fn name(&self) -> &'static str { "table" }
+
+    fn decode(
+        &self,
+        code_page: u32,
+        x: &[u8],
+        lossy: bool,
+    ) -> OsResult<Vec<u16>> {
+        match lossy {
+            true => codepage::mb_to_wide_lossy(code_page, x),
+            false => codepage::mb_to_wide(code_page, x),
+        }
+    }
+
+    fn encode(
+        &self,
+        code_page: u32,
+        x: &[u16],
+        options: &EncodeOptions,
+    ) -> OsResult<Vec<u8>> {
+        match options.lossy {
+            true => codepage::wide_to_mb_lossy(
+                code_page,
+                x,
+                options.replace,
+                options.best_fit,
+            ),
+            false => codepage::wide_to_mb(code_page, x, options.best_fit),
+        }
+    }
+
+    fn cp_info(&self, code_page: u32) -> OsResult<CpInfo> {
+        codepage::cp_info(code_page)
+    }
+
+    fn system_default_acp(&self) -> OsResult<u32> {
+        codepage::get_system_default_acp()
+    }
+
+    fn system_default_oemcp(&self) -> OsResult<u32> {
+        codepage::get_system_default_oemcp()
+    }
+}
+
+/// The backend using `MultiByteToWideChar` and `WideCharToMultiByte`, which
+/// is available on Windows.
+#[cfg(windows)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Win32Backend;
+
+#[cfg(windows)]
+impl Backend for Win32Backend {
+    fn name(&self) -> &'static str { "win32" }
+
+    fn decode(
+        &self,
+        code_page: u32,
+        x: &[u8],
+        lossy: bool,
+    ) -> OsResult<Vec<u16>> {
+        match lossy {
+            true => convert::mb_to_wide_lossy(code_page, x),
+            false => convert::mb_to_wide(code_page, x),
+        }
+    }
+
+    fn encode(
+        &self,
+        code_page: u32,
+        x: &[u16],
+        options: &EncodeOptions,
+    ) -> OsResult<Vec<u8>> {
+        match options.lossy {
+            true => convert::wide_to_mb_lossy(
+                code_page,
+                x,
+                options.replace,
+                options.best_fit,
+            ),
+            false => convert::wide_to_mb(code_page, x, options.best_fit),
+        }
+    }
+
+    fn cp_info(&self, code_page: u32) -> OsResult<CpInfo> {
+        convert::cp_info(code_page)
+    }
+
+    fn system_default_acp(&self) -> OsResult<u32> {
+        convert::get_system_default_acp()
+    }
+
+    fn system_default_oemcp(&self) -> OsResult<u32> {
+        convert::get_system_default_oemcp()
+    }
+}
+
+/// Returns the backend named `name`, which is `win32` or `table`, if it is
+/// available on the host.
+pub fn backend(name: &str) -> Option<&'static dyn Backend> {
+    match name {
+        #[cfg(windows)]
+        "win32" => Some(&Win32Backend),
+        "table" => Some(&TableBackend),
+        _ => None,
+    }
+}
+
+/// Returns the default backend of the host, which is [`Win32Backend`] on
+/// Windows and [`TableBackend`] otherwise.
+#[cfg(windows)]
+pub fn default_backend() -> &'static dyn Backend { &Win32Backend }
+
+/// Returns the default backend of the host, which is `Win32Backend` on
+/// Windows and [`TableBackend`] otherwise.
+#[cfg(not(windows))]
+pub fn default_backend() -> &'static dyn Backend { &TableBackend }
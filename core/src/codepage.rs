@@ -0,0 +1,366 @@
+//! Table-driven conversion that gives the same bytes as `WideCharToMultiByte`
+//! with `WC_NO_BEST_FIT_CHARS`, so ANSI strings can be made on any host.
+//!
+//! On a Windows host, the Win32 backend uses it only for GB18030, which
+//! `WideCharToMultiByte` can't convert with `WC_NO_BEST_FIT_CHARS`.
+mod best_fit;
+mod dbcs;
+mod gb18030;
+mod sbcs;
+
+use crate::CpInfo;
+use dbcs::Dbcs;
+
+pub(crate) type OsResult<T> = Result<T, u32>;
+
+pub(crate) const ERROR_INVALID_PARAMETER: u32 = 0x57;
+pub(crate) const ERROR_NO_UNICODE_TRANSLATION: u32 = 0x459;
+
+pub(crate) const CP_GB18030: u32 = 54936;
+
+/// The code page used when the locale of the host is unknown.
+///
+/// It is the default ANSI code page of English Windows.
+pub(crate) const FALLBACK_ACP: u32 = 1252;
+
+/// The OEM code page used when the locale of the host is unknown.
+///
+/// It is the default OEM code page of English Windows.
+pub(crate) const FALLBACK_OEMCP: u32 = 437;
+
+/// The character used for characters that can't be converted unless another
+/// one is specified.
+const DEFAULT_CHAR: u8 = b'?';
+
+/// The character used for bytes that can't be converted.
+///
+/// Windows uses the default character of the code page instead, e.g. U+30FB
+/// for 932, which the tables don't have.
+const UNICODE_DEFAULT_CHAR: char = '\u{FFFD}';
+
+#[derive(Copy, Clone)]
+enum Table {
+    Sbcs(&'static [u16; 128]),
+    Dbcs(&'static Dbcs),
+    Gb18030,
+}
+
+impl Table {
+    fn new(code_page: u32) -> Option<Self> {
+        Some(match code_page {
+            874 => Self::Sbcs(&sbcs::CP874),
+            1250 => Self::Sbcs(&sbcs::CP1250),
+            1251 => Self::Sbcs(&sbcs::CP1251),
+            1252 => Self::Sbcs(&sbcs::CP1252),
+            1253 => Self::Sbcs(&sbcs::CP1253),
+            1254 => Self::Sbcs(&sbcs::CP1254),
+            1255 => Self::Sbcs(&sbcs::CP1255),
+            1256 => Self::Sbcs(&sbcs::CP1256),
+            1257 => Self::Sbcs(&sbcs::CP1257),
+            1258 => Self::Sbcs(&sbcs::CP1258),
+            437 => Self::Sbcs(&sbcs::CP437),
+            850 => Self::Sbcs(&sbcs::CP850),
+            720 => Self::Sbcs(&sbcs::CP720),
+            737 => Self::Sbcs(&sbcs::CP737),
+            775 => Self::Sbcs(&sbcs::CP775),
+            852 => Self::Sbcs(&sbcs::CP852),
+            855 => Self::Sbcs(&sbcs::CP855),
+            857 => Self::Sbcs(&sbcs::CP857),
+            862 => Self::Sbcs(&sbcs::CP862),
+            866 => Self::Sbcs(&sbcs::CP866),
+            932 => Self::Dbcs(&dbcs::CP932),
+            936 => Self::Dbcs(&dbcs::CP936),
+            949 => Self::Dbcs(&dbcs::CP949),
+            950 => Self::Dbcs(&dbcs::CP950),
+            CP_GB18030 => Self::Gb18030,
+            _ => return None,
+        })
+    }
+
+    /// Returns the character at the start of `x`, which isn't empty, and its
+    /// length in bytes.
+    fn decode(self, x: &[u8]) -> (Option<char>, usize) {
+        let (c, len) = match self {
+            _ if x[0] < 0x80 => (x[0].into(), 1),
+            Self::Sbcs(t) => (t[x[0] as usize - 0x80], 1),
+            Self::Dbcs(t) => t.decode(x),
+            Self::Gb18030 => return gb18030::decode(x),
+        };
+        (
+            char::from_u32(c.into()).filter(|&c| c != '\0' || x[0] == 0),
+            len,
+        )
+    }
+
+    /// Returns the code of `c`, whose bytes are in big-endian.
+    fn encode(self, c: char) -> Option<u32> {
+        let code = match (self, u16::try_from(c as u32)) {
+            (Self::Gb18030, _) => return Some(gb18030::encode(c)),
+            (_, Err(_)) => return None,
+            (Self::Sbcs(x), Ok(c)) => encode_sbcs(x, c).map(u16::from),
+            (Self::Dbcs(x), Ok(c)) => x.encode(c),
+        };
+        code.map(u32::from)
+    }
+}
+
+pub(crate) fn mb_to_wide(code_page: u32, x: &[u8]) -> OsResult<Vec<u16>> {
+    multi_byte_to_wide(code_page, x, true)
+}
+
+pub(crate) fn mb_to_wide_lossy(code_page: u32, x: &[u8]) -> OsResult<Vec<u16>> {
+    multi_byte_to_wide(code_page, x, false)
+}
+
+pub(crate) fn wide_to_mb(
+    code_page: u32,
+    x: &[u16],
+    best_fit: bool,
+) -> OsResult<Vec<u8>> {
+    wide_to_multi_byte(code_page, x, None, true, best_fit)
+}
+
+pub(crate) fn wide_to_mb_lossy(
+    code_page: u32,
+    x: &[u16],
+    default_char: Option<u8>,
+    best_fit: bool,
+) -> OsResult<Vec<u8>> {
+    wide_to_multi_byte(code_page, x, default_char, false, best_fit)
+}
+
+/// Returns the information of the code page like `GetCPInfoExW`.
+pub(crate) fn cp_info(code_page: u32) -> OsResult<CpInfo> {
+    let max_char_size = match Table::new(code_page) {
+        Some(Table::Sbcs(_)) => 1,
+        Some(Table::Dbcs(_)) => 2,
+        Some(Table::Gb18030) => 4,
+        None => return Err(ERROR_INVALID_PARAMETER),
+    };
+    Ok(CpInfo {
+        code_page,
+        name: cp_name(code_page).to_string(),
+        max_char_size,
+    })
+}
+
+/// Returns the name of the code page on English Windows.
+fn cp_name(code_page: u32) -> &'static str {
+    match code_page {
+        874 => "ANSI/OEM - Thai",
+        932 => "ANSI/OEM - Japanese Shift-JIS",
+        936 => "ANSI/OEM - Simplified Chinese GBK",
+        949 => "ANSI/OEM - Korean",
+        950 => "ANSI/OEM - Traditional Chinese Big5",
+        1250 => "ANSI - Central Europe",
+        1251 => "ANSI - Cyrillic",
+        1252 => "ANSI - Latin I",
+        1253 => "ANSI - Greek",
+        1254 => "ANSI - Turkish",
+        1255 => "ANSI - Hebrew",
+        1256 => "ANSI - Arabic",
+        1257 => "ANSI - Baltic",
+        1258 => "ANSI/OEM - Viet Nam",
+        437 => "OEM - United States",
+        720 => "Arabic - Transparent ASMO",
+        737 => "OEM - Greek 437G",
+        775 => "OEM - Baltic",
+        850 => "OEM - Multilingual Latin I",
+        852 => "OEM - Latin II",
+        855 => "OEM - Cyrillic",
+        857 => "OEM - Turkish",
+        862 => "OEM - Hebrew",
+        866 => "OEM - Russian",
+        CP_GB18030 => "GB18030 Simplified Chinese",
+        _ => "",
+    }
+}
+
+/// Returns the ANSI code page which Windows uses for the locale of the host.
+pub(crate) fn get_system_default_acp() -> OsResult<u32> {
+    Ok(locale_acp(&host_locale()))
+}
+
+/// Returns the OEM code page which Windows uses for the locale of the host.
+pub(crate) fn get_system_default_oemcp() -> OsResult<u32> {
+    let locale = host_locale();
+    let name = locale.split(['.', '@']).next().unwrap_or_default();
+    match (name, locale_acp(&locale)) {
+        ("" | "C" | "POSIX" | "en_US" | "en-US", _) => Ok(FALLBACK_OEMCP),
+        // Windows uses 850 for the other Western European locales.
+        (_, FALLBACK_ACP) => Ok(850),
+        (_, acp) => Ok(acp_to_oemcp(acp).unwrap_or(FALLBACK_OEMCP)),
+    }
+}
+
+/// Returns the OEM code page of the locales whose ANSI code page is `acp`.
+///
+/// Code page 1252 is used by both 437 (United States) and 850 (Western
+/// Europe), and 437 is returned.
+pub(crate) fn acp_to_oemcp(acp: u32) -> Option<u32> {
+    Some(match acp {
+        874 | 932 | 936 | 949 | 950 | 1258 => acp,
+        CP_GB18030 => 936,
+        1250 => 852,
+        1251 => 866,
+        1252 => FALLBACK_OEMCP,
+        1253 => 737,
+        1254 => 857,
+        1255 => 862,
+        1256 => 720,
+        1257 => 775,
+        _ => return None,
+    })
+}
+
+/// Returns the POSIX locale name of the host.
+fn host_locale() -> String {
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|x| std::env::var(x).ok())
+        .find(|x| !x.is_empty())
+        .unwrap_or_default()
+}
+
+/// Returns the ANSI code page of a POSIX locale name such as `ja_JP.UTF-8`.
+fn locale_acp(locale: &str) -> u32 {
+    let locale = locale.split('@').next().unwrap_or_default();
+    let (locale, charset) = locale.split_once('.').unwrap_or((locale, ""));
+    if charset.eq_ignore_ascii_case("GB18030") {
+        return CP_GB18030;
+    }
+    let (lang, region) = locale.split_once(['_', '-']).unwrap_or((locale, ""));
+    match (lang, region) {
+        ("ja", _) => 932,
+        ("zh", "TW" | "HK" | "MO") => 950,
+        ("zh", _) => 936,
+        ("ko", _) => 949,
+        ("th", _) => 874,
+        ("cs" | "hr" | "hu" | "pl" | "ro" | "sk" | "sl" | "sq", _) => 1250,
+        ("be" | "bg" | "kk" | "mk" | "mn" | "ru" | "sr" | "uk", _) => 1251,
+        ("el", _) => 1253,
+        ("az" | "tr" | "uz", _) => 1254,
+        ("he", _) => 1255,
+        ("ar" | "fa" | "ur", _) => 1256,
+        ("et" | "lt" | "lv", _) => 1257,
+        ("vi", _) => 1258,
+        _ => FALLBACK_ACP,
+    }
+}
+
+/// Converts `x` like `MultiByteToWideChar`.
+///
+/// If `err_invalid_chars` is true, fails with `ERROR_NO_UNICODE_TRANSLATION`
+/// instead of using [`UNICODE_DEFAULT_CHAR`] for invalid bytes, like
+/// `MB_ERR_INVALID_CHARS`.
+fn multi_byte_to_wide(
+    code_page: u32,
+    x: &[u8],
+    err_invalid_chars: bool,
+) -> OsResult<Vec<u16>> {
+    let table = Table::new(code_page).ok_or(ERROR_INVALID_PARAMETER)?;
+    let mut ret = Vec::with_capacity(x.len());
+    let mut rest = x;
+    while !rest.is_empty() {
+        let (c, len) = table.decode(rest);
+        rest = &rest[len..];
+        match c {
+            Some(c) => ret.extend_from_slice(c.encode_utf16(&mut [0; 2])),
+            None if err_invalid_chars => {
+                return Err(ERROR_NO_UNICODE_TRANSLATION);
+            }
+            None => ret.push(UNICODE_DEFAULT_CHAR as u16),
+        }
+    }
+    Ok(ret)
+}
+
+/// Converts `x` like `WideCharToMultiByte`.
+///
+/// `default_char` is used for characters that can't be converted, or
+/// [`DEFAULT_CHAR`] if it is `None`. If `used_default_char` is true, fails
+/// with `ERROR_NO_UNICODE_TRANSLATION` instead of using the default character.
+/// If `best_fit` is true, a character which can't be converted falls back to
+/// a similar one before that, like without `WC_NO_BEST_FIT_CHARS`.
+fn wide_to_multi_byte(
+    code_page: u32,
+    x: &[u16],
+    default_char: Option<u8>,
+    used_default_char: bool,
+    best_fit: bool,
+) -> OsResult<Vec<u8>> {
+    let default_char = default_char.unwrap_or(DEFAULT_CHAR);
+    let table = Table::new(code_page).ok_or(ERROR_INVALID_PARAMETER)?;
+    let mut ret = Vec::with_capacity(x.len() * 2);
+    for c in char::decode_utf16(x.iter().copied()) {
+        // An unpaired surrogate can't be converted.
+        let c = c.ok();
+        let code = c.and_then(|c| {
+            table
+                .encode(c)
+                .or_else(|| best_fit.then(|| fit(code_page, c)).flatten())
+        });
+        match code {
+            Some(code) => {
+                let skip = (code.leading_zeros() as usize / 8).min(3);
+                ret.extend_from_slice(&code.to_be_bytes()[skip..]);
+            }
+            None if used_default_char => {
+                return Err(ERROR_NO_UNICODE_TRANSLATION);
+            }
+            // Like WideCharToMultiByte, a surrogate pair becomes two default
+            // characters.
+            None => ret.extend(std::iter::repeat_n(
+                default_char,
+                c.map_or(1, char::len_utf16),
+            )),
+        }
+    }
+    Ok(ret)
+}
+
+/// Returns the code which `c` is encoded to in the best fit mode.
+///
+/// The mappings are the ones of the WindowsBestFit tables. The OEM code pages
+/// have no such table, so theirs are approximated by the compatibility
+/// decomposition, e.g. `é` to `e` and `Ａ` to `A`.
+fn fit(code_page: u32, c: char) -> Option<u32> {
+    let table: &[(u16, u16)] = match code_page {
+        874 => &best_fit::CP874,
+        932 => &best_fit::CP932,
+        936 => &best_fit::CP936,
+        949 => &best_fit::CP949,
+        950 => &best_fit::CP950,
+        1250 => &best_fit::CP1250,
+        1251 => &best_fit::CP1251,
+        1252 => &best_fit::CP1252,
+        1253 => &best_fit::CP1253,
+        1254 => &best_fit::CP1254,
+        1255 => &best_fit::CP1255,
+        1256 => &best_fit::CP1256,
+        1257 => &best_fit::CP1257,
+        1258 => &best_fit::CP1258,
+        437 => &best_fit::CP437,
+        850 => &best_fit::CP850,
+        720 => &best_fit::CP720,
+        737 => &best_fit::CP737,
+        775 => &best_fit::CP775,
+        852 => &best_fit::CP852,
+        855 => &best_fit::CP855,
+        857 => &best_fit::CP857,
+        862 => &best_fit::CP862,
+        866 => &best_fit::CP866,
+        _ => return None,
+    };
+    let c = u16::try_from(c as u32).ok()?;
+    let i = table.binary_search_by_key(&c, |&(x, _)| x).ok()?;
+    Some(table[i].1.into())
+}
+
+#[inline]
+fn encode_sbcs(table: &[u16; 128], c: u16) -> Option<u8> {
+    if c < 0x80 {
+        return Some(c as u8);
+    }
+    table.iter().position(|&x| x == c).map(|i| (i + 0x80) as u8)
+}
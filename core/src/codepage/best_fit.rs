@@ -0,0 +1,5114 @@
+// Copyright takubokudori.
+// This source code is licensed under the MIT or Apache-2.0 license.
+// This file isn't generated from tools/bestfit/ yet, which isn't vendored.
+// tools/gen_codepage.py replaces the ANSI tables with the WindowsBestFit ones.
+//! The best fit mappings.
+//!
+//! Each table has pairs of a character which the code page doesn't have and
+//! the code which it is encoded to in the best fit mode. They are approximated
+//! by the compatibility decomposition, so some mappings of Windows are missing,
+//! e.g. U+221E to "8" in 1252.
+
+/// Code page 874.
+#[rustfmt::skip]
+pub(crate) const CP874: [(u16, u16); 867] = [
+    (0x00AA, 0x0061), (0x00AB, 0x003C), (0x00B2, 0x0032), (0x00B3, 0x0033), (0x00B9, 0x0031),
+    (0x00BA, 0x006F), (0x00BB, 0x003E), (0x00C0, 0x0041), (0x00C1, 0x0041), (0x00C2, 0x0041),
+    (0x00C3, 0x0041), (0x00C4, 0x0041), (0x00C5, 0x0041), (0x00C7, 0x0043), (0x00C8, 0x0045),
+    (0x00C9, 0x0045), (0x00CA, 0x0045), (0x00CB, 0x0045), (0x00CC, 0x0049), (0x00CD, 0x0049),
+    (0x00CE, 0x0049), (0x00CF, 0x0049), (0x00D1, 0x004E), (0x00D2, 0x004F), (0x00D3, 0x004F),
+    (0x00D4, 0x004F), (0x00D5, 0x004F), (0x00D6, 0x004F), (0x00D9, 0x0055), (0x00DA, 0x0055),
+    (0x00DB, 0x0055), (0x00DC, 0x0055), (0x00DD, 0x0059), (0x00E0, 0x0061), (0x00E1, 0x0061),
+    (0x00E2, 0x0061), (0x00E3, 0x0061), (0x00E4, 0x0061), (0x00E5, 0x0061), (0x00E7, 0x0063),
+    (0x00E8, 0x0065), (0x00E9, 0x0065), (0x00EA, 0x0065), (0x00EB, 0x0065), (0x00EC, 0x0069),
+    (0x00ED, 0x0069), (0x00EE, 0x0069), (0x00EF, 0x0069), (0x00F1, 0x006E), (0x00F2, 0x006F),
+    (0x00F3, 0x006F), (0x00F4, 0x006F), (0x00F5, 0x006F), (0x00F6, 0x006F), (0x00F9, 0x0075),
+    (0x00FA, 0x0075), (0x00FB, 0x0075), (0x00FC, 0x0075), (0x00FD, 0x0079), (0x00FF, 0x0079),
+    (0x0100, 0x0041), (0x0101, 0x0061), (0x0102, 0x0041), (0x0103, 0x0061), (0x0104, 0x0041),
+    (0x0105, 0x0061), (0x0106, 0x0043), (0x0107, 0x0063), (0x0108, 0x0043), (0x0109, 0x0063),
+    (0x010A, 0x0043), (0x010B, 0x0063), (0x010C, 0x0043), (0x010D, 0x0063), (0x010E, 0x0044),
+    (0x010F, 0x0064), (0x0112, 0x0045), (0x0113, 0x0065), (0x0114, 0x0045), (0x0115, 0x0065),
+    (0x0116, 0x0045), (0x0117, 0x0065), (0x0118, 0x0045), (0x0119, 0x0065), (0x011A, 0x0045),
+    (0x011B, 0x0065), (0x011C, 0x0047), (0x011D, 0x0067), (0x011E, 0x0047), (0x011F, 0x0067),
+    (0x0120, 0x0047), (0x0121, 0x0067), (0x0122, 0x0047), (0x0123, 0x0067), (0x0124, 0x0048),
+    (0x0125, 0x0068), (0x0128, 0x0049), (0x0129, 0x0069), (0x012A, 0x0049), (0x012B, 0x0069),
+    (0x012C, 0x0049), (0x012D, 0x0069), (0x012E, 0x0049), (0x012F, 0x0069), (0x0130, 0x0049),
+    (0x0134, 0x004A), (0x0135, 0x006A), (0x0136, 0x004B), (0x0137, 0x006B), (0x0139, 0x004C),
+    (0x013A, 0x006C), (0x013B, 0x004C), (0x013C, 0x006C), (0x013D, 0x004C), (0x013E, 0x006C),
+    (0x0143, 0x004E), (0x0144, 0x006E), (0x0145, 0x004E), (0x0146, 0x006E), (0x0147, 0x004E),
+    (0x0148, 0x006E), (0x014C, 0x004F), (0x014D, 0x006F), (0x014E, 0x004F), (0x014F, 0x006F),
+    (0x0150, 0x004F), (0x0151, 0x006F), (0x0154, 0x0052), (0x0155, 0x0072), (0x0156, 0x0052),
+    (0x0157, 0x0072), (0x0158, 0x0052), (0x0159, 0x0072), (0x015A, 0x0053), (0x015B, 0x0073),
+    (0x015C, 0x0053), (0x015D, 0x0073), (0x015E, 0x0053), (0x015F, 0x0073), (0x0160, 0x0053),
+    (0x0161, 0x0073), (0x0162, 0x0054), (0x0163, 0x0074), (0x0164, 0x0054), (0x0165, 0x0074),
+    (0x0168, 0x0055), (0x0169, 0x0075), (0x016A, 0x0055), (0x016B, 0x0075), (0x016C, 0x0055),
+    (0x016D, 0x0075), (0x016E, 0x0055), (0x016F, 0x0075), (0x0170, 0x0055), (0x0171, 0x0075),
+    (0x0172, 0x0055), (0x0173, 0x0075), (0x0174, 0x0057), (0x0175, 0x0077), (0x0176, 0x0059),
+    (0x0177, 0x0079), (0x0178, 0x0059), (0x0179, 0x005A), (0x017A, 0x007A), (0x017B, 0x005A),
+    (0x017C, 0x007A), (0x017D, 0x005A), (0x017E, 0x007A), (0x017F, 0x0073), (0x01A0, 0x004F),
+    (0x01A1, 0x006F), (0x01AF, 0x0055), (0x01B0, 0x0075), (0x01CD, 0x0041), (0x01CE, 0x0061),
+    (0x01CF, 0x0049), (0x01D0, 0x0069), (0x01D1, 0x004F), (0x01D2, 0x006F), (0x01D3, 0x0055),
+    (0x01D4, 0x0075), (0x01D5, 0x0055), (0x01D6, 0x0075), (0x01D7, 0x0055), (0x01D8, 0x0075),
+    (0x01D9, 0x0055), (0x01DA, 0x0075), (0x01DB, 0x0055), (0x01DC, 0x0075), (0x01DE, 0x0041),
+    (0x01DF, 0x0061), (0x01E0, 0x0041), (0x01E1, 0x0061), (0x01E6, 0x0047), (0x01E7, 0x0067),
+    (0x01E8, 0x004B), (0x01E9, 0x006B), (0x01EA, 0x004F), (0x01EB, 0x006F), (0x01EC, 0x004F),
+    (0x01ED, 0x006F), (0x01F0, 0x006A), (0x01F4, 0x0047), (0x01F5, 0x0067), (0x01F8, 0x004E),
+    (0x01F9, 0x006E), (0x01FA, 0x0041), (0x01FB, 0x0061), (0x0200, 0x0041), (0x0201, 0x0061),
+    (0x0202, 0x0041), (0x0203, 0x0061), (0x0204, 0x0045), (0x0205, 0x0065), (0x0206, 0x0045),
+    (0x0207, 0x0065), (0x0208, 0x0049), (0x0209, 0x0069), (0x020A, 0x0049), (0x020B, 0x0069),
+    (0x020C, 0x004F), (0x020D, 0x006F), (0x020E, 0x004F), (0x020F, 0x006F), (0x0210, 0x0052),
+    (0x0211, 0x0072), (0x0212, 0x0052), (0x0213, 0x0072), (0x0214, 0x0055), (0x0215, 0x0075),
+    (0x0216, 0x0055), (0x0217, 0x0075), (0x0218, 0x0053), (0x0219, 0x0073), (0x021A, 0x0054),
+    (0x021B, 0x0074), (0x021E, 0x0048), (0x021F, 0x0068), (0x0226, 0x0041), (0x0227, 0x0061),
+    (0x0228, 0x0045), (0x0229, 0x0065), (0x022A, 0x004F), (0x022B, 0x006F), (0x022C, 0x004F),
+    (0x022D, 0x006F), (0x022E, 0x004F), (0x022F, 0x006F), (0x0230, 0x004F), (0x0231, 0x006F),
+    (0x0232, 0x0059), (0x0233, 0x0079), (0x02B0, 0x0068), (0x02B2, 0x006A), (0x02B3, 0x0072),
+    (0x02B7, 0x0077), (0x02B8, 0x0079), (0x02E1, 0x006C), (0x02E2, 0x0073), (0x02E3, 0x0078),
+    (0x037E, 0x003B), (0x1D2C, 0x0041), (0x1D2E, 0x0042), (0x1D30, 0x0044), (0x1D31, 0x0045),
+    (0x1D33, 0x0047), (0x1D34, 0x0048), (0x1D35, 0x0049), (0x1D36, 0x004A), (0x1D37, 0x004B),
+    (0x1D38, 0x004C), (0x1D39, 0x004D), (0x1D3A, 0x004E), (0x1D3C, 0x004F), (0x1D3E, 0x0050),
+    (0x1D3F, 0x0052), (0x1D40, 0x0054), (0x1D41, 0x0055), (0x1D42, 0x0057), (0x1D43, 0x0061),
+    (0x1D47, 0x0062), (0x1D48, 0x0064), (0x1D49, 0x0065), (0x1D4D, 0x0067), (0x1D4F, 0x006B),
+    (0x1D50, 0x006D), (0x1D52, 0x006F), (0x1D56, 0x0070), (0x1D57, 0x0074), (0x1D58, 0x0075),
+    (0x1D5B, 0x0076), (0x1D62, 0x0069), (0x1D63, 0x0072), (0x1D64, 0x0075), (0x1D65, 0x0076),
+    (0x1D9C, 0x0063), (0x1DA0, 0x0066), (0x1DBB, 0x007A), (0x1E00, 0x0041), (0x1E01, 0x0061),
+    (0x1E02, 0x0042), (0x1E03, 0x0062), (0x1E04, 0x0042), (0x1E05, 0x0062), (0x1E06, 0x0042),
+    (0x1E07, 0x0062), (0x1E08, 0x0043), (0x1E09, 0x0063), (0x1E0A, 0x0044), (0x1E0B, 0x0064),
+    (0x1E0C, 0x0044), (0x1E0D, 0x0064), (0x1E0E, 0x0044), (0x1E0F, 0x0064), (0x1E10, 0x0044),
+    (0x1E11, 0x0064), (0x1E12, 0x0044), (0x1E13, 0x0064), (0x1E14, 0x0045), (0x1E15, 0x0065),
+    (0x1E16, 0x0045), (0x1E17, 0x0065), (0x1E18, 0x0045), (0x1E19, 0x0065), (0x1E1A, 0x0045),
+    (0x1E1B, 0x0065), (0x1E1C, 0x0045), (0x1E1D, 0x0065), (0x1E1E, 0x0046), (0x1E1F, 0x0066),
+    (0x1E20, 0x0047), (0x1E21, 0x0067), (0x1E22, 0x0048), (0x1E23, 0x0068), (0x1E24, 0x0048),
+    (0x1E25, 0x0068), (0x1E26, 0x0048), (0x1E27, 0x0068), (0x1E28, 0x0048), (0x1E29, 0x0068),
+    (0x1E2A, 0x0048), (0x1E2B, 0x0068), (0x1E2C, 0x0049), (0x1E2D, 0x0069), (0x1E2E, 0x0049),
+    (0x1E2F, 0x0069), (0x1E30, 0x004B), (0x1E31, 0x006B), (0x1E32, 0x004B), (0x1E33, 0x006B),
+    (0x1E34, 0x004B), (0x1E35, 0x006B), (0x1E36, 0x004C), (0x1E37, 0x006C), (0x1E38, 0x004C),
+    (0x1E39, 0x006C), (0x1E3A, 0x004C), (0x1E3B, 0x006C), (0x1E3C, 0x004C), (0x1E3D, 0x006C),
+    (0x1E3E, 0x004D), (0x1E3F, 0x006D), (0x1E40, 0x004D), (0x1E41, 0x006D), (0x1E42, 0x004D),
+    (0x1E43, 0x006D), (0x1E44, 0x004E), (0x1E45, 0x006E), (0x1E46, 0x004E), (0x1E47, 0x006E),
+    (0x1E48, 0x004E), (0x1E49, 0x006E), (0x1E4A, 0x004E), (0x1E4B, 0x006E), (0x1E4C, 0x004F),
+    (0x1E4D, 0x006F), (0x1E4E, 0x004F), (0x1E4F, 0x006F), (0x1E50, 0x004F), (0x1E51, 0x006F),
+    (0x1E52, 0x004F), (0x1E53, 0x006F), (0x1E54, 0x0050), (0x1E55, 0x0070), (0x1E56, 0x0050),
+    (0x1E57, 0x0070), (0x1E58, 0x0052), (0x1E59, 0x0072), (0x1E5A, 0x0052), (0x1E5B, 0x0072),
+    (0x1E5C, 0x0052), (0x1E5D, 0x0072), (0x1E5E, 0x0052), (0x1E5F, 0x0072), (0x1E60, 0x0053),
+    (0x1E61, 0x0073), (0x1E62, 0x0053), (0x1E63, 0x0073), (0x1E64, 0x0053), (0x1E65, 0x0073),
+    (0x1E66, 0x0053), (0x1E67, 0x0073), (0x1E68, 0x0053), (0x1E69, 0x0073), (0x1E6A, 0x0054),
+    (0x1E6B, 0x0074), (0x1E6C, 0x0054), (0x1E6D, 0x0074), (0x1E6E, 0x0054), (0x1E6F, 0x0074),
+    (0x1E70, 0x0054), (0x1E71, 0x0074), (0x1E72, 0x0055), (0x1E73, 0x0075), (0x1E74, 0x0055),
+    (0x1E75, 0x0075), (0x1E76, 0x0055), (0x1E77, 0x0075), (0x1E78, 0x0055), (0x1E79, 0x0075),
+    (0x1E7A, 0x0055), (0x1E7B, 0x0075), (0x1E7C, 0x0056), (0x1E7D, 0x0076), (0x1E7E, 0x0056),
+    (0x1E7F, 0x0076), (0x1E80, 0x0057), (0x1E81, 0x0077), (0x1E82, 0x0057), (0x1E83, 0x0077),
+    (0x1E84, 0x0057), (0x1E85, 0x0077), (0x1E86, 0x0057), (0x1E87, 0x0077), (0x1E88, 0x0057),
+    (0x1E89, 0x0077), (0x1E8A, 0x0058), (0x1E8B, 0x0078), (0x1E8C, 0x0058), (0x1E8D, 0x0078),
+    (0x1E8E, 0x0059), (0x1E8F, 0x0079), (0x1E90, 0x005A), (0x1E91, 0x007A), (0x1E92, 0x005A),
+    (0x1E93, 0x007A), (0x1E94, 0x005A), (0x1E95, 0x007A), (0x1E96, 0x0068), (0x1E97, 0x0074),
+    (0x1E98, 0x0077), (0x1E99, 0x0079), (0x1E9B, 0x0073), (0x1EA0, 0x0041), (0x1EA1, 0x0061),
+    (0x1EA2, 0x0041), (0x1EA3, 0x0061), (0x1EA4, 0x0041), (0x1EA5, 0x0061), (0x1EA6, 0x0041),
+    (0x1EA7, 0x0061), (0x1EA8, 0x0041), (0x1EA9, 0x0061), (0x1EAA, 0x0041), (0x1EAB, 0x0061),
+    (0x1EAC, 0x0041), (0x1EAD, 0x0061), (0x1EAE, 0x0041), (0x1EAF, 0x0061), (0x1EB0, 0x0041),
+    (0x1EB1, 0x0061), (0x1EB2, 0x0041), (0x1EB3, 0x0061), (0x1EB4, 0x0041), (0x1EB5, 0x0061),
+    (0x1EB6, 0x0041), (0x1EB7, 0x0061), (0x1EB8, 0x0045), (0x1EB9, 0x0065), (0x1EBA, 0x0045),
+    (0x1EBB, 0x0065), (0x1EBC, 0x0045), (0x1EBD, 0x0065), (0x1EBE, 0x0045), (0x1EBF, 0x0065),
+    (0x1EC0, 0x0045), (0x1EC1, 0x0065), (0x1EC2, 0x0045), (0x1EC3, 0x0065), (0x1EC4, 0x0045),
+    (0x1EC5, 0x0065), (0x1EC6, 0x0045), (0x1EC7, 0x0065), (0x1EC8, 0x0049), (0x1EC9, 0x0069),
+    (0x1ECA, 0x0049), (0x1ECB, 0x0069), (0x1ECC, 0x004F), (0x1ECD, 0x006F), (0x1ECE, 0x004F),
+    (0x1ECF, 0x006F), (0x1ED0, 0x004F), (0x1ED1, 0x006F), (0x1ED2, 0x004F), (0x1ED3, 0x006F),
+    (0x1ED4, 0x004F), (0x1ED5, 0x006F), (0x1ED6, 0x004F), (0x1ED7, 0x006F), (0x1ED8, 0x004F),
+    (0x1ED9, 0x006F), (0x1EDA, 0x004F), (0x1EDB, 0x006F), (0x1EDC, 0x004F), (0x1EDD, 0x006F),
+    (0x1EDE, 0x004F), (0x1EDF, 0x006F), (0x1EE0, 0x004F), (0x1EE1, 0x006F), (0x1EE2, 0x004F),
+    (0x1EE3, 0x006F), (0x1EE4, 0x0055), (0x1EE5, 0x0075), (0x1EE6, 0x0055), (0x1EE7, 0x0075),
+    (0x1EE8, 0x0055), (0x1EE9, 0x0075), (0x1EEA, 0x0055), (0x1EEB, 0x0075), (0x1EEC, 0x0055),
+    (0x1EED, 0x0075), (0x1EEE, 0x0055), (0x1EEF, 0x0075), (0x1EF0, 0x0055), (0x1EF1, 0x0075),
+    (0x1EF2, 0x0059), (0x1EF3, 0x0079), (0x1EF4, 0x0059), (0x1EF5, 0x0079), (0x1EF6, 0x0059),
+    (0x1EF7, 0x0079), (0x1EF8, 0x0059), (0x1EF9, 0x0079), (0x1FEF, 0x0060), (0x2000, 0x0020),
+    (0x2001, 0x0020), (0x2002, 0x0020), (0x2003, 0x0020), (0x2004, 0x0020), (0x2005, 0x0020),
+    (0x2006, 0x0020), (0x2007, 0x0020), (0x2008, 0x0020), (0x2009, 0x0020), (0x200A, 0x0020),
+    (0x2010, 0x002D), (0x2012, 0x002D), (0x2015, 0x002D), (0x201A, 0x002C), (0x201B, 0x0027),
+    (0x201E, 0x0022), (0x201F, 0x0022), (0x2024, 0x002E), (0x202F, 0x0020), (0x2032, 0x0027),
+    (0x2035, 0x0060), (0x2039, 0x003C), (0x203A, 0x003E), (0x205F, 0x0020), (0x2070, 0x0030),
+    (0x2071, 0x0069), (0x2074, 0x0034), (0x2075, 0x0035), (0x2076, 0x0036), (0x2077, 0x0037),
+    (0x2078, 0x0038), (0x2079, 0x0039), (0x207A, 0x002B), (0x207C, 0x003D), (0x207D, 0x0028),
+    (0x207E, 0x0029), (0x207F, 0x006E), (0x2080, 0x0030), (0x2081, 0x0031), (0x2082, 0x0032),
+    (0x2083, 0x0033), (0x2084, 0x0034), (0x2085, 0x0035), (0x2086, 0x0036), (0x2087, 0x0037),
+    (0x2088, 0x0038), (0x2089, 0x0039), (0x208A, 0x002B), (0x208C, 0x003D), (0x208D, 0x0028),
+    (0x208E, 0x0029), (0x2090, 0x0061), (0x2091, 0x0065), (0x2092, 0x006F), (0x2093, 0x0078),
+    (0x2095, 0x0068), (0x2096, 0x006B), (0x2097, 0x006C), (0x2098, 0x006D), (0x2099, 0x006E),
+    (0x209A, 0x0070), (0x209B, 0x0073), (0x209C, 0x0074), (0x2102, 0x0043), (0x210A, 0x0067),
+    (0x210B, 0x0048), (0x210C, 0x0048), (0x210D, 0x0048), (0x210E, 0x0068), (0x2110, 0x0049),
+    (0x2111, 0x0049), (0x2112, 0x004C), (0x2113, 0x006C), (0x2115, 0x004E), (0x2119, 0x0050),
+    (0x211A, 0x0051), (0x211B, 0x0052), (0x211C, 0x0052), (0x211D, 0x0052), (0x2124, 0x005A),
+    (0x2128, 0x005A), (0x212A, 0x004B), (0x212B, 0x0041), (0x212C, 0x0042), (0x212D, 0x0043),
+    (0x212F, 0x0065), (0x2130, 0x0045), (0x2131, 0x0046), (0x2133, 0x004D), (0x2134, 0x006F),
+    (0x2139, 0x0069), (0x2145, 0x0044), (0x2146, 0x0064), (0x2147, 0x0065), (0x2148, 0x0069),
+    (0x2149, 0x006A), (0x2160, 0x0049), (0x2164, 0x0056), (0x2169, 0x0058), (0x216C, 0x004C),
+    (0x216D, 0x0043), (0x216E, 0x0044), (0x216F, 0x004D), (0x2170, 0x0069), (0x2174, 0x0076),
+    (0x2179, 0x0078), (0x217C, 0x006C), (0x217D, 0x0063), (0x217E, 0x0064), (0x217F, 0x006D),
+    (0x2212, 0x002D), (0x2215, 0x002F), (0x2216, 0x005C), (0x2223, 0x007C), (0x223C, 0x007E),
+    (0x2260, 0x003D), (0x226E, 0x003C), (0x226F, 0x003E), (0x2460, 0x0031), (0x2461, 0x0032),
+    (0x2462, 0x0033), (0x2463, 0x0034), (0x2464, 0x0035), (0x2465, 0x0036), (0x2466, 0x0037),
+    (0x2467, 0x0038), (0x2468, 0x0039), (0x24B6, 0x0041), (0x24B7, 0x0042), (0x24B8, 0x0043),
+    (0x24B9, 0x0044), (0x24BA, 0x0045), (0x24BB, 0x0046), (0x24BC, 0x0047), (0x24BD, 0x0048),
+    (0x24BE, 0x0049), (0x24BF, 0x004A), (0x24C0, 0x004B), (0x24C1, 0x004C), (0x24C2, 0x004D),
+    (0x24C3, 0x004E), (0x24C4, 0x004F), (0x24C5, 0x0050), (0x24C6, 0x0051), (0x24C7, 0x0052),
+    (0x24C8, 0x0053), (0x24C9, 0x0054), (0x24CA, 0x0055), (0x24CB, 0x0056), (0x24CC, 0x0057),
+    (0x24CD, 0x0058), (0x24CE, 0x0059), (0x24CF, 0x005A), (0x24D0, 0x0061), (0x24D1, 0x0062),
+    (0x24D2, 0x0063), (0x24D3, 0x0064), (0x24D4, 0x0065), (0x24D5, 0x0066), (0x24D6, 0x0067),
+    (0x24D7, 0x0068), (0x24D8, 0x0069), (0x24D9, 0x006A), (0x24DA, 0x006B), (0x24DB, 0x006C),
+    (0x24DC, 0x006D), (0x24DD, 0x006E), (0x24DE, 0x006F), (0x24DF, 0x0070), (0x24E0, 0x0071),
+    (0x24E1, 0x0072), (0x24E2, 0x0073), (0x24E3, 0x0074), (0x24E4, 0x0075), (0x24E5, 0x0076),
+    (0x24E6, 0x0077), (0x24E7, 0x0078), (0x24E8, 0x0079), (0x24E9, 0x007A), (0x24EA, 0x0030),
+    (0x2C7C, 0x006A), (0x2C7D, 0x0056), (0x3000, 0x0020), (0xA7F2, 0x0043), (0xA7F3, 0x0046),
+    (0xA7F4, 0x0051), (0xFB29, 0x002B), (0xFE10, 0x002C), (0xFE13, 0x003A), (0xFE14, 0x003B),
+    (0xFE15, 0x0021), (0xFE16, 0x003F), (0xFE31, 0x0097), (0xFE32, 0x0096), (0xFE33, 0x005F),
+    (0xFE34, 0x005F), (0xFE35, 0x0028), (0xFE36, 0x0029), (0xFE37, 0x007B), (0xFE38, 0x007D),
+    (0xFE47, 0x005B), (0xFE48, 0x005D), (0xFE4D, 0x005F), (0xFE4E, 0x005F), (0xFE4F, 0x005F),
+    (0xFE50, 0x002C), (0xFE52, 0x002E), (0xFE54, 0x003B), (0xFE55, 0x003A), (0xFE56, 0x003F),
+    (0xFE57, 0x0021), (0xFE58, 0x0097), (0xFE59, 0x0028), (0xFE5A, 0x0029), (0xFE5B, 0x007B),
+    (0xFE5C, 0x007D), (0xFE5F, 0x0023), (0xFE60, 0x0026), (0xFE61, 0x002A), (0xFE62, 0x002B),
+    (0xFE63, 0x002D), (0xFE64, 0x003C), (0xFE65, 0x003E), (0xFE66, 0x003D), (0xFE68, 0x005C),
+    (0xFE69, 0x0024), (0xFE6A, 0x0025), (0xFE6B, 0x0040), (0xFF01, 0x0021), (0xFF02, 0x0022),
+    (0xFF03, 0x0023), (0xFF04, 0x0024), (0xFF05, 0x0025), (0xFF06, 0x0026), (0xFF07, 0x0027),
+    (0xFF08, 0x0028), (0xFF09, 0x0029), (0xFF0A, 0x002A), (0xFF0B, 0x002B), (0xFF0C, 0x002C),
+    (0xFF0D, 0x002D), (0xFF0E, 0x002E), (0xFF0F, 0x002F), (0xFF10, 0x0030), (0xFF11, 0x0031),
+    (0xFF12, 0x0032), (0xFF13, 0x0033), (0xFF14, 0x0034), (0xFF15, 0x0035), (0xFF16, 0x0036),
+    (0xFF17, 0x0037), (0xFF18, 0x0038), (0xFF19, 0x0039), (0xFF1A, 0x003A), (0xFF1B, 0x003B),
+    (0xFF1C, 0x003C), (0xFF1D, 0x003D), (0xFF1E, 0x003E), (0xFF1F, 0x003F), (0xFF20, 0x0040),
+    (0xFF21, 0x0041), (0xFF22, 0x0042), (0xFF23, 0x0043), (0xFF24, 0x0044), (0xFF25, 0x0045),
+    (0xFF26, 0x0046), (0xFF27, 0x0047), (0xFF28, 0x0048), (0xFF29, 0x0049), (0xFF2A, 0x004A),
+    (0xFF2B, 0x004B), (0xFF2C, 0x004C), (0xFF2D, 0x004D), (0xFF2E, 0x004E), (0xFF2F, 0x004F),
+    (0xFF30, 0x0050), (0xFF31, 0x0051), (0xFF32, 0x0052), (0xFF33, 0x0053), (0xFF34, 0x0054),
+    (0xFF35, 0x0055), (0xFF36, 0x0056), (0xFF37, 0x0057), (0xFF38, 0x0058), (0xFF39, 0x0059),
+    (0xFF3A, 0x005A), (0xFF3B, 0x005B), (0xFF3C, 0x005C), (0xFF3D, 0x005D), (0xFF3E, 0x005E),
+    (0xFF3F, 0x005F), (0xFF40, 0x0060), (0xFF41, 0x0061), (0xFF42, 0x0062), (0xFF43, 0x0063),
+    (0xFF44, 0x0064), (0xFF45, 0x0065), (0xFF46, 0x0066), (0xFF47, 0x0067), (0xFF48, 0x0068),
+    (0xFF49, 0x0069), (0xFF4A, 0x006A), (0xFF4B, 0x006B), (0xFF4C, 0x006C), (0xFF4D, 0x006D),
+    (0xFF4E, 0x006E), (0xFF4F, 0x006F), (0xFF50, 0x0070), (0xFF51, 0x0071), (0xFF52, 0x0072),
+    (0xFF53, 0x0073), (0xFF54, 0x0074), (0xFF55, 0x0075), (0xFF56, 0x0076), (0xFF57, 0x0077),
+    (0xFF58, 0x0078), (0xFF59, 0x0079), (0xFF5A, 0x007A), (0xFF5B, 0x007B), (0xFF5C, 0x007C),
+    (0xFF5D, 0x007D), (0xFF5E, 0x007E),
+];
+
+/// Code page 932.
+#[rustfmt::skip]
+pub(crate) const CP932: [(u16, u16); 1808] = [
+    (0x00A0, 0x0020), (0x00AA, 0x0061), (0x00AB, 0x003C), (0x00B2, 0x0032), (0x00B3, 0x0033),
+    (0x00B5, 0x83CA), (0x00B9, 0x0031), (0x00BA, 0x006F), (0x00BB, 0x003E), (0x00C0, 0x0041),
+    (0x00C1, 0x0041), (0x00C2, 0x0041), (0x00C3, 0x0041), (0x00C4, 0x0041), (0x00C5, 0x0041),
+    (0x00C7, 0x0043), (0x00C8, 0x0045), (0x00C9, 0x0045), (0x00CA, 0x0045), (0x00CB, 0x0045),
+    (0x00CC, 0x0049), (0x00CD, 0x0049), (0x00CE, 0x0049), (0x00CF, 0x0049), (0x00D1, 0x004E),
+    (0x00D2, 0x004F), (0x00D3, 0x004F), (0x00D4, 0x004F), (0x00D5, 0x004F), (0x00D6, 0x004F),
+    (0x00D9, 0x0055), (0x00DA, 0x0055), (0x00DB, 0x0055), (0x00DC, 0x0055), (0x00DD, 0x0059),
+    (0x00E0, 0x0061), (0x00E1, 0x0061), (0x00E2, 0x0061), (0x00E3, 0x0061), (0x00E4, 0x0061),
+    (0x00E5, 0x0061), (0x00E7, 0x0063), (0x00E8, 0x0065), (0x00E9, 0x0065), (0x00EA, 0x0065),
+    (0x00EB, 0x0065), (0x00EC, 0x0069), (0x00ED, 0x0069), (0x00EE, 0x0069), (0x00EF, 0x0069),
+    (0x00F1, 0x006E), (0x00F2, 0x006F), (0x00F3, 0x006F), (0x00F4, 0x006F), (0x00F5, 0x006F),
+    (0x00F6, 0x006F), (0x00F9, 0x0075), (0x00FA, 0x0075), (0x00FB, 0x0075), (0x00FC, 0x0075),
+    (0x00FD, 0x0079), (0x00FF, 0x0079), (0x0100, 0x0041), (0x0101, 0x0061), (0x0102, 0x0041),
+    (0x0103, 0x0061), (0x0104, 0x0041), (0x0105, 0x0061), (0x0106, 0x0043), (0x0107, 0x0063),
+    (0x0108, 0x0043), (0x0109, 0x0063), (0x010A, 0x0043), (0x010B, 0x0063), (0x010C, 0x0043),
+    (0x010D, 0x0063), (0x010E, 0x0044), (0x010F, 0x0064), (0x0112, 0x0045), (0x0113, 0x0065),
+    (0x0114, 0x0045), (0x0115, 0x0065), (0x0116, 0x0045), (0x0117, 0x0065), (0x0118, 0x0045),
+    (0x0119, 0x0065), (0x011A, 0x0045), (0x011B, 0x0065), (0x011C, 0x0047), (0x011D, 0x0067),
+    (0x011E, 0x0047), (0x011F, 0x0067), (0x0120, 0x0047), (0x0121, 0x0067), (0x0122, 0x0047),
+    (0x0123, 0x0067), (0x0124, 0x0048), (0x0125, 0x0068), (0x0128, 0x0049), (0x0129, 0x0069),
+    (0x012A, 0x0049), (0x012B, 0x0069), (0x012C, 0x0049), (0x012D, 0x0069), (0x012E, 0x0049),
+    (0x012F, 0x0069), (0x0130, 0x0049), (0x0134, 0x004A), (0x0135, 0x006A), (0x0136, 0x004B),
+    (0x0137, 0x006B), (0x0139, 0x004C), (0x013A, 0x006C), (0x013B, 0x004C), (0x013C, 0x006C),
+    (0x013D, 0x004C), (0x013E, 0x006C), (0x0143, 0x004E), (0x0144, 0x006E), (0x0145, 0x004E),
+    (0x0146, 0x006E), (0x0147, 0x004E), (0x0148, 0x006E), (0x014C, 0x004F), (0x014D, 0x006F),
+    (0x014E, 0x004F), (0x014F, 0x006F), (0x0150, 0x004F), (0x0151, 0x006F), (0x0154, 0x0052),
+    (0x0155, 0x0072), (0x0156, 0x0052), (0x0157, 0x0072), (0x0158, 0x0052), (0x0159, 0x0072),
+    (0x015A, 0x0053), (0x015B, 0x0073), (0x015C, 0x0053), (0x015D, 0x0073), (0x015E, 0x0053),
+    (0x015F, 0x0073), (0x0160, 0x0053), (0x0161, 0x0073), (0x0162, 0x0054), (0x0163, 0x0074),
+    (0x0164, 0x0054), (0x0165, 0x0074), (0x0168, 0x0055), (0x0169, 0x0075), (0x016A, 0x0055),
+    (0x016B, 0x0075), (0x016C, 0x0055), (0x016D, 0x0075), (0x016E, 0x0055), (0x016F, 0x0075),
+    (0x0170, 0x0055), (0x0171, 0x0075), (0x0172, 0x0055), (0x0173, 0x0075), (0x0174, 0x0057),
+    (0x0175, 0x0077), (0x0176, 0x0059), (0x0177, 0x0079), (0x0178, 0x0059), (0x0179, 0x005A),
+    (0x017A, 0x007A), (0x017B, 0x005A), (0x017C, 0x007A), (0x017D, 0x005A), (0x017E, 0x007A),
+    (0x017F, 0x0073), (0x01A0, 0x004F), (0x01A1, 0x006F), (0x01AF, 0x0055), (0x01B0, 0x0075),
+    (0x01CD, 0x0041), (0x01CE, 0x0061), (0x01CF, 0x0049), (0x01D0, 0x0069), (0x01D1, 0x004F),
+    (0x01D2, 0x006F), (0x01D3, 0x0055), (0x01D4, 0x0075), (0x01D5, 0x0055), (0x01D6, 0x0075),
+    (0x01D7, 0x0055), (0x01D8, 0x0075), (0x01D9, 0x0055), (0x01DA, 0x0075), (0x01DB, 0x0055),
+    (0x01DC, 0x0075), (0x01DE, 0x0041), (0x01DF, 0x0061), (0x01E0, 0x0041), (0x01E1, 0x0061),
+    (0x01E6, 0x0047), (0x01E7, 0x0067), (0x01E8, 0x004B), (0x01E9, 0x006B), (0x01EA, 0x004F),
+    (0x01EB, 0x006F), (0x01EC, 0x004F), (0x01ED, 0x006F), (0x01F0, 0x006A), (0x01F4, 0x0047),
+    (0x01F5, 0x0067), (0x01F8, 0x004E), (0x01F9, 0x006E), (0x01FA, 0x0041), (0x01FB, 0x0061),
+    (0x0200, 0x0041), (0x0201, 0x0061), (0x0202, 0x0041), (0x0203, 0x0061), (0x0204, 0x0045),
+    (0x0205, 0x0065), (0x0206, 0x0045), (0x0207, 0x0065), (0x0208, 0x0049), (0x0209, 0x0069),
+    (0x020A, 0x0049), (0x020B, 0x0069), (0x020C, 0x004F), (0x020D, 0x006F), (0x020E, 0x004F),
+    (0x020F, 0x006F), (0x0210, 0x0052), (0x0211, 0x0072), (0x0212, 0x0052), (0x0213, 0x0072),
+    (0x0214, 0x0055), (0x0215, 0x0075), (0x0216, 0x0055), (0x0217, 0x0075), (0x0218, 0x0053),
+    (0x0219, 0x0073), (0x021A, 0x0054), (0x021B, 0x0074), (0x021E, 0x0048), (0x021F, 0x0068),
+    (0x0226, 0x0041), (0x0227, 0x0061), (0x0228, 0x0045), (0x0229, 0x0065), (0x022A, 0x004F),
+    (0x022B, 0x006F), (0x022C, 0x004F), (0x022D, 0x006F), (0x022E, 0x004F), (0x022F, 0x006F),
+    (0x0230, 0x004F), (0x0231, 0x006F), (0x0232, 0x0059), (0x0233, 0x0079), (0x02B0, 0x0068),
+    (0x02B2, 0x006A), (0x02B3, 0x0072), (0x02B7, 0x0077), (0x02B8, 0x0079), (0x02E1, 0x006C),
+    (0x02E2, 0x0073), (0x02E3, 0x0078), (0x037E, 0x003B), (0x0386, 0x839F), (0x0388, 0x83A3),
+    (0x0389, 0x83A5), (0x038A, 0x83A7), (0x038C, 0x83AD), (0x038E, 0x83B2), (0x038F, 0x83B6),
+    (0x0390, 0x83C7), (0x03AA, 0x83A7), (0x03AB, 0x83B2), (0x03AC, 0x83BF), (0x03AD, 0x83C3),
+    (0x03AE, 0x83C5), (0x03AF, 0x83C7), (0x03B0, 0x83D2), (0x03CA, 0x83C7), (0x03CB, 0x83D2),
+    (0x03CC, 0x83CD), (0x03CD, 0x83D2), (0x03CE, 0x83D6), (0x03D0, 0x83C0), (0x03D1, 0x83C6),
+    (0x03D2, 0x83B2), (0x03D3, 0x83B2), (0x03D4, 0x83B2), (0x03D5, 0x83D3), (0x03D6, 0x83CE),
+    (0x03F0, 0x83C8), (0x03F1, 0x83CF), (0x03F4, 0x83A6), (0x03F5, 0x83C3), (0x03F9, 0x83B0),
+    (0x0400, 0x8445), (0x0403, 0x8443), (0x040C, 0x844B), (0x040D, 0x8449), (0x040E, 0x8454),
+    (0x0450, 0x8475), (0x0453, 0x8473), (0x045C, 0x847B), (0x045D, 0x8479), (0x045E, 0x8485),
+    (0x04C1, 0x8447), (0x04C2, 0x8477), (0x04D0, 0x8440), (0x04D1, 0x8470), (0x04D2, 0x8440),
+    (0x04D3, 0x8470), (0x04D6, 0x8445), (0x04D7, 0x8475), (0x04DC, 0x8447), (0x04DD, 0x8477),
+    (0x04DE, 0x8448), (0x04DF, 0x8478), (0x04E2, 0x8449), (0x04E3, 0x8479), (0x04E4, 0x8449),
+    (0x04E5, 0x8479), (0x04E6, 0x844F), (0x04E7, 0x8480), (0x04EC, 0x845E), (0x04ED, 0x848F),
+    (0x04EE, 0x8454), (0x04EF, 0x8485), (0x04F0, 0x8454), (0x04F1, 0x8485), (0x04F2, 0x8454),
+    (0x04F3, 0x8485), (0x04F4, 0x8458), (0x04F5, 0x8489), (0x04F8, 0x845C), (0x04F9, 0x848D),
+    (0x1D2C, 0x0041), (0x1D2E, 0x0042), (0x1D30, 0x0044), (0x1D31, 0x0045), (0x1D33, 0x0047),
+    (0x1D34, 0x0048), (0x1D35, 0x0049), (0x1D36, 0x004A), (0x1D37, 0x004B), (0x1D38, 0x004C),
+    (0x1D39, 0x004D), (0x1D3A, 0x004E), (0x1D3C, 0x004F), (0x1D3E, 0x0050), (0x1D3F, 0x0052),
+    (0x1D40, 0x0054), (0x1D41, 0x0055), (0x1D42, 0x0057), (0x1D43, 0x0061), (0x1D47, 0x0062),
+    (0x1D48, 0x0064), (0x1D49, 0x0065), (0x1D4D, 0x0067), (0x1D4F, 0x006B), (0x1D50, 0x006D),
+    (0x1D52, 0x006F), (0x1D56, 0x0070), (0x1D57, 0x0074), (0x1D58, 0x0075), (0x1D5B, 0x0076),
+    (0x1D5D, 0x83C0), (0x1D5E, 0x83C1), (0x1D5F, 0x83C2), (0x1D60, 0x83D3), (0x1D61, 0x83D4),
+    (0x1D62, 0x0069), (0x1D63, 0x0072), (0x1D64, 0x0075), (0x1D65, 0x0076), (0x1D66, 0x83C0),
+    (0x1D67, 0x83C1), (0x1D68, 0x83CF), (0x1D69, 0x83D3), (0x1D6A, 0x83D4), (0x1D78, 0x847E),
+    (0x1D9C, 0x0063), (0x1DA0, 0x0066), (0x1DBB, 0x007A), (0x1DBF, 0x83C6), (0x1E00, 0x0041),
+    (0x1E01, 0x0061), (0x1E02, 0x0042), (0x1E03, 0x0062), (0x1E04, 0x0042), (0x1E05, 0x0062),
+    (0x1E06, 0x0042), (0x1E07, 0x0062), (0x1E08, 0x0043), (0x1E09, 0x0063), (0x1E0A, 0x0044),
+    (0x1E0B, 0x0064), (0x1E0C, 0x0044), (0x1E0D, 0x0064), (0x1E0E, 0x0044), (0x1E0F, 0x0064),
+    (0x1E10, 0x0044), (0x1E11, 0x0064), (0x1E12, 0x0044), (0x1E13, 0x0064), (0x1E14, 0x0045),
+    (0x1E15, 0x0065), (0x1E16, 0x0045), (0x1E17, 0x0065), (0x1E18, 0x0045), (0x1E19, 0x0065),
+    (0x1E1A, 0x0045), (0x1E1B, 0x0065), (0x1E1C, 0x0045), (0x1E1D, 0x0065), (0x1E1E, 0x0046),
+    (0x1E1F, 0x0066), (0x1E20, 0x0047), (0x1E21, 0x0067), (0x1E22, 0x0048), (0x1E23, 0x0068),
+    (0x1E24, 0x0048), (0x1E25, 0x0068), (0x1E26, 0x0048), (0x1E27, 0x0068), (0x1E28, 0x0048),
+    (0x1E29, 0x0068), (0x1E2A, 0x0048), (0x1E2B, 0x0068), (0x1E2C, 0x0049), (0x1E2D, 0x0069),
+    (0x1E2E, 0x0049), (0x1E2F, 0x0069), (0x1E30, 0x004B), (0x1E31, 0x006B), (0x1E32, 0x004B),
+    (0x1E33, 0x006B), (0x1E34, 0x004B), (0x1E35, 0x006B), (0x1E36, 0x004C), (0x1E37, 0x006C),
+    (0x1E38, 0x004C), (0x1E39, 0x006C), (0x1E3A, 0x004C), (0x1E3B, 0x006C), (0x1E3C, 0x004C),
+    (0x1E3D, 0x006C), (0x1E3E, 0x004D), (0x1E3F, 0x006D), (0x1E40, 0x004D), (0x1E41, 0x006D),
+    (0x1E42, 0x004D), (0x1E43, 0x006D), (0x1E44, 0x004E), (0x1E45, 0x006E), (0x1E46, 0x004E),
+    (0x1E47, 0x006E), (0x1E48, 0x004E), (0x1E49, 0x006E), (0x1E4A, 0x004E), (0x1E4B, 0x006E),
+    (0x1E4C, 0x004F), (0x1E4D, 0x006F), (0x1E4E, 0x004F), (0x1E4F, 0x006F), (0x1E50, 0x004F),
+    (0x1E51, 0x006F), (0x1E52, 0x004F), (0x1E53, 0x006F), (0x1E54, 0x0050), (0x1E55, 0x0070),
+    (0x1E56, 0x0050), (0x1E57, 0x0070), (0x1E58, 0x0052), (0x1E59, 0x0072), (0x1E5A, 0x0052),
+    (0x1E5B, 0x0072), (0x1E5C, 0x0052), (0x1E5D, 0x0072), (0x1E5E, 0x0052), (0x1E5F, 0x0072),
+    (0x1E60, 0x0053), (0x1E61, 0x0073), (0x1E62, 0x0053), (0x1E63, 0x0073), (0x1E64, 0x0053),
+    (0x1E65, 0x0073), (0x1E66, 0x0053), (0x1E67, 0x0073), (0x1E68, 0x0053), (0x1E69, 0x0073),
+    (0x1E6A, 0x0054), (0x1E6B, 0x0074), (0x1E6C, 0x0054), (0x1E6D, 0x0074), (0x1E6E, 0x0054),
+    (0x1E6F, 0x0074), (0x1E70, 0x0054), (0x1E71, 0x0074), (0x1E72, 0x0055), (0x1E73, 0x0075),
+    (0x1E74, 0x0055), (0x1E75, 0x0075), (0x1E76, 0x0055), (0x1E77, 0x0075), (0x1E78, 0x0055),
+    (0x1E79, 0x0075), (0x1E7A, 0x0055), (0x1E7B, 0x0075), (0x1E7C, 0x0056), (0x1E7D, 0x0076),
+    (0x1E7E, 0x0056), (0x1E7F, 0x0076), (0x1E80, 0x0057), (0x1E81, 0x0077), (0x1E82, 0x0057),
+    (0x1E83, 0x0077), (0x1E84, 0x0057), (0x1E85, 0x0077), (0x1E86, 0x0057), (0x1E87, 0x0077),
+    (0x1E88, 0x0057), (0x1E89, 0x0077), (0x1E8A, 0x0058), (0x1E8B, 0x0078), (0x1E8C, 0x0058),
+    (0x1E8D, 0x0078), (0x1E8E, 0x0059), (0x1E8F, 0x0079), (0x1E90, 0x005A), (0x1E91, 0x007A),
+    (0x1E92, 0x005A), (0x1E93, 0x007A), (0x1E94, 0x005A), (0x1E95, 0x007A), (0x1E96, 0x0068),
+    (0x1E97, 0x0074), (0x1E98, 0x0077), (0x1E99, 0x0079), (0x1E9B, 0x0073), (0x1EA0, 0x0041),
+    (0x1EA1, 0x0061), (0x1EA2, 0x0041), (0x1EA3, 0x0061), (0x1EA4, 0x0041), (0x1EA5, 0x0061),
+    (0x1EA6, 0x0041), (0x1EA7, 0x0061), (0x1EA8, 0x0041), (0x1EA9, 0x0061), (0x1EAA, 0x0041),
+    (0x1EAB, 0x0061), (0x1EAC, 0x0041), (0x1EAD, 0x0061), (0x1EAE, 0x0041), (0x1EAF, 0x0061),
+    (0x1EB0, 0x0041), (0x1EB1, 0x0061), (0x1EB2, 0x0041), (0x1EB3, 0x0061), (0x1EB4, 0x0041),
+    (0x1EB5, 0x0061), (0x1EB6, 0x0041), (0x1EB7, 0x0061), (0x1EB8, 0x0045), (0x1EB9, 0x0065),
+    (0x1EBA, 0x0045), (0x1EBB, 0x0065), (0x1EBC, 0x0045), (0x1EBD, 0x0065), (0x1EBE, 0x0045),
+    (0x1EBF, 0x0065), (0x1EC0, 0x0045), (0x1EC1, 0x0065), (0x1EC2, 0x0045), (0x1EC3, 0x0065),
+    (0x1EC4, 0x0045), (0x1EC5, 0x0065), (0x1EC6, 0x0045), (0x1EC7, 0x0065), (0x1EC8, 0x0049),
+    (0x1EC9, 0x0069), (0x1ECA, 0x0049), (0x1ECB, 0x0069), (0x1ECC, 0x004F), (0x1ECD, 0x006F),
+    (0x1ECE, 0x004F), (0x1ECF, 0x006F), (0x1ED0, 0x004F), (0x1ED1, 0x006F), (0x1ED2, 0x004F),
+    (0x1ED3, 0x006F), (0x1ED4, 0x004F), (0x1ED5, 0x006F), (0x1ED6, 0x004F), (0x1ED7, 0x006F),
+    (0x1ED8, 0x004F), (0x1ED9, 0x006F), (0x1EDA, 0x004F), (0x1EDB, 0x006F), (0x1EDC, 0x004F),
+    (0x1EDD, 0x006F), (0x1EDE, 0x004F), (0x1EDF, 0x006F), (0x1EE0, 0x004F), (0x1EE1, 0x006F),
+    (0x1EE2, 0x004F), (0x1EE3, 0x006F), (0x1EE4, 0x0055), (0x1EE5, 0x0075), (0x1EE6, 0x0055),
+    (0x1EE7, 0x0075), (0x1EE8, 0x0055), (0x1EE9, 0x0075), (0x1EEA, 0x0055), (0x1EEB, 0x0075),
+    (0x1EEC, 0x0055), (0x1EED, 0x0075), (0x1EEE, 0x0055), (0x1EEF, 0x0075), (0x1EF0, 0x0055),
+    (0x1EF1, 0x0075), (0x1EF2, 0x0059), (0x1EF3, 0x0079), (0x1EF4, 0x0059), (0x1EF5, 0x0079),
+    (0x1EF6, 0x0059), (0x1EF7, 0x0079), (0x1EF8, 0x0059), (0x1EF9, 0x0079), (0x1F00, 0x83BF),
+    (0x1F01, 0x83BF), (0x1F02, 0x83BF), (0x1F03, 0x83BF), (0x1F04, 0x83BF), (0x1F05, 0x83BF),
+    (0x1F06, 0x83BF), (0x1F07, 0x83BF), (0x1F08, 0x839F), (0x1F09, 0x839F), (0x1F0A, 0x839F),
+    (0x1F0B, 0x839F), (0x1F0C, 0x839F), (0x1F0D, 0x839F), (0x1F0E, 0x839F), (0x1F0F, 0x839F),
+    (0x1F10, 0x83C3), (0x1F11, 0x83C3), (0x1F12, 0x83C3), (0x1F13, 0x83C3), (0x1F14, 0x83C3),
+    (0x1F15, 0x83C3), (0x1F18, 0x83A3), (0x1F19, 0x83A3), (0x1F1A, 0x83A3), (0x1F1B, 0x83A3),
+    (0x1F1C, 0x83A3), (0x1F1D, 0x83A3), (0x1F20, 0x83C5), (0x1F21, 0x83C5), (0x1F22, 0x83C5),
+    (0x1F23, 0x83C5), (0x1F24, 0x83C5), (0x1F25, 0x83C5), (0x1F26, 0x83C5), (0x1F27, 0x83C5),
+    (0x1F28, 0x83A5), (0x1F29, 0x83A5), (0x1F2A, 0x83A5), (0x1F2B, 0x83A5), (0x1F2C, 0x83A5),
+    (0x1F2D, 0x83A5), (0x1F2E, 0x83A5), (0x1F2F, 0x83A5), (0x1F30, 0x83C7), (0x1F31, 0x83C7),
+    (0x1F32, 0x83C7), (0x1F33, 0x83C7), (0x1F34, 0x83C7), (0x1F35, 0x83C7), (0x1F36, 0x83C7),
+    (0x1F37, 0x83C7), (0x1F38, 0x83A7), (0x1F39, 0x83A7), (0x1F3A, 0x83A7), (0x1F3B, 0x83A7),
+    (0x1F3C, 0x83A7), (0x1F3D, 0x83A7), (0x1F3E, 0x83A7), (0x1F3F, 0x83A7), (0x1F40, 0x83CD),
+    (0x1F41, 0x83CD), (0x1F42, 0x83CD), (0x1F43, 0x83CD), (0x1F44, 0x83CD), (0x1F45, 0x83CD),
+    (0x1F48, 0x83AD), (0x1F49, 0x83AD), (0x1F4A, 0x83AD), (0x1F4B, 0x83AD), (0x1F4C, 0x83AD),
+    (0x1F4D, 0x83AD), (0x1F50, 0x83D2), (0x1F51, 0x83D2), (0x1F52, 0x83D2), (0x1F53, 0x83D2),
+    (0x1F54, 0x83D2), (0x1F55, 0x83D2), (0x1F56, 0x83D2), (0x1F57, 0x83D2), (0x1F59, 0x83B2),
+    (0x1F5B, 0x83B2), (0x1F5D, 0x83B2), (0x1F5F, 0x83B2), (0x1F60, 0x83D6), (0x1F61, 0x83D6),
+    (0x1F62, 0x83D6), (0x1F63, 0x83D6), (0x1F64, 0x83D6), (0x1F65, 0x83D6), (0x1F66, 0x83D6),
+    (0x1F67, 0x83D6), (0x1F68, 0x83B6), (0x1F69, 0x83B6), (0x1F6A, 0x83B6), (0x1F6B, 0x83B6),
+    (0x1F6C, 0x83B6), (0x1F6D, 0x83B6), (0x1F6E, 0x83B6), (0x1F6F, 0x83B6), (0x1F70, 0x83BF),
+    (0x1F71, 0x83BF), (0x1F72, 0x83C3), (0x1F73, 0x83C3), (0x1F74, 0x83C5), (0x1F75, 0x83C5),
+    (0x1F76, 0x83C7), (0x1F77, 0x83C7), (0x1F78, 0x83CD), (0x1F79, 0x83CD), (0x1F7A, 0x83D2),
+    (0x1F7B, 0x83D2), (0x1F7C, 0x83D6), (0x1F7D, 0x83D6), (0x1F80, 0x83BF), (0x1F81, 0x83BF),
+    (0x1F82, 0x83BF), (0x1F83, 0x83BF), (0x1F84, 0x83BF), (0x1F85, 0x83BF), (0x1F86, 0x83BF),
+    (0x1F87, 0x83BF), (0x1F88, 0x839F), (0x1F89, 0x839F), (0x1F8A, 0x839F), (0x1F8B, 0x839F),
+    (0x1F8C, 0x839F), (0x1F8D, 0x839F), (0x1F8E, 0x839F), (0x1F8F, 0x839F), (0x1F90, 0x83C5),
+    (0x1F91, 0x83C5), (0x1F92, 0x83C5), (0x1F93, 0x83C5), (0x1F94, 0x83C5), (0x1F95, 0x83C5),
+    (0x1F96, 0x83C5), (0x1F97, 0x83C5), (0x1F98, 0x83A5), (0x1F99, 0x83A5), (0x1F9A, 0x83A5),
+    (0x1F9B, 0x83A5), (0x1F9C, 0x83A5), (0x1F9D, 0x83A5), (0x1F9E, 0x83A5), (0x1F9F, 0x83A5),
+    (0x1FA0, 0x83D6), (0x1FA1, 0x83D6), (0x1FA2, 0x83D6), (0x1FA3, 0x83D6), (0x1FA4, 0x83D6),
+    (0x1FA5, 0x83D6), (0x1FA6, 0x83D6), (0x1FA7, 0x83D6), (0x1FA8, 0x83B6), (0x1FA9, 0x83B6),
+    (0x1FAA, 0x83B6), (0x1FAB, 0x83B6), (0x1FAC, 0x83B6), (0x1FAD, 0x83B6), (0x1FAE, 0x83B6),
+    (0x1FAF, 0x83B6), (0x1FB0, 0x83BF), (0x1FB1, 0x83BF), (0x1FB2, 0x83BF), (0x1FB3, 0x83BF),
+    (0x1FB4, 0x83BF), (0x1FB6, 0x83BF), (0x1FB7, 0x83BF), (0x1FB8, 0x839F), (0x1FB9, 0x839F),
+    (0x1FBA, 0x839F), (0x1FBB, 0x839F), (0x1FBC, 0x839F), (0x1FBE, 0x83C7), (0x1FC2, 0x83C5),
+    (0x1FC3, 0x83C5), (0x1FC4, 0x83C5), (0x1FC6, 0x83C5), (0x1FC7, 0x83C5), (0x1FC8, 0x83A3),
+    (0x1FC9, 0x83A3), (0x1FCA, 0x83A5), (0x1FCB, 0x83A5), (0x1FCC, 0x83A5), (0x1FD0, 0x83C7),
+    (0x1FD1, 0x83C7), (0x1FD2, 0x83C7), (0x1FD3, 0x83C7), (0x1FD6, 0x83C7), (0x1FD7, 0x83C7),
+    (0x1FD8, 0x83A7), (0x1FD9, 0x83A7), (0x1FDA, 0x83A7), (0x1FDB, 0x83A7), (0x1FE0, 0x83D2),
+    (0x1FE1, 0x83D2), (0x1FE2, 0x83D2), (0x1FE3, 0x83D2), (0x1FE4, 0x83CF), (0x1FE5, 0x83CF),
+    (0x1FE6, 0x83D2), (0x1FE7, 0x83D2), (0x1FE8, 0x83B2), (0x1FE9, 0x83B2), (0x1FEA, 0x83B2),
+    (0x1FEB, 0x83B2), (0x1FEC, 0x83AF), (0x1FEF, 0x0060), (0x1FF2, 0x83D6), (0x1FF3, 0x83D6),
+    (0x1FF4, 0x83D6), (0x1FF6, 0x83D6), (0x1FF7, 0x83D6), (0x1FF8, 0x83AD), (0x1FF9, 0x83AD),
+    (0x1FFA, 0x83B6), (0x1FFB, 0x83B6), (0x1FFC, 0x83B6), (0x2000, 0x0020), (0x2001, 0x0020),
+    (0x2002, 0x0020), (0x2003, 0x0020), (0x2004, 0x0020), (0x2005, 0x0020), (0x2006, 0x0020),
+    (0x2007, 0x0020), (0x2008, 0x0020), (0x2009, 0x0020), (0x200A, 0x0020), (0x2011, 0x815D),
+    (0x2012, 0x002D), (0x2013, 0x002D), (0x2014, 0x002D), (0x201A, 0x002C), (0x201B, 0x0027),
+    (0x201E, 0x0022), (0x201F, 0x0022), (0x2024, 0x002E), (0x202F, 0x0020), (0x2035, 0x0060),
+    (0x2039, 0x003C), (0x203A, 0x003E), (0x205F, 0x0020), (0x2070, 0x0030), (0x2071, 0x0069),
+    (0x2074, 0x0034), (0x2075, 0x0035), (0x2076, 0x0036), (0x2077, 0x0037), (0x2078, 0x0038),
+    (0x2079, 0x0039), (0x207A, 0x002B), (0x207C, 0x003D), (0x207D, 0x0028), (0x207E, 0x0029),
+    (0x207F, 0x006E), (0x2080, 0x0030), (0x2081, 0x0031), (0x2082, 0x0032), (0x2083, 0x0033),
+    (0x2084, 0x0034), (0x2085, 0x0035), (0x2086, 0x0036), (0x2087, 0x0037), (0x2088, 0x0038),
+    (0x2089, 0x0039), (0x208A, 0x002B), (0x208C, 0x003D), (0x208D, 0x0028), (0x208E, 0x0029),
+    (0x2090, 0x0061), (0x2091, 0x0065), (0x2092, 0x006F), (0x2093, 0x0078), (0x2095, 0x0068),
+    (0x2096, 0x006B), (0x2097, 0x006C), (0x2098, 0x006D), (0x2099, 0x006E), (0x209A, 0x0070),
+    (0x209B, 0x0073), (0x209C, 0x0074), (0x2102, 0x0043), (0x210A, 0x0067), (0x210B, 0x0048),
+    (0x210C, 0x0048), (0x210D, 0x0048), (0x210E, 0x0068), (0x2110, 0x0049), (0x2111, 0x0049),
+    (0x2112, 0x004C), (0x2113, 0x006C), (0x2115, 0x004E), (0x2119, 0x0050), (0x211A, 0x0051),
+    (0x211B, 0x0052), (0x211C, 0x0052), (0x211D, 0x0052), (0x2124, 0x005A), (0x2126, 0x83B6),
+    (0x2128, 0x005A), (0x212A, 0x004B), (0x212C, 0x0042), (0x212D, 0x0043), (0x212F, 0x0065),
+    (0x2130, 0x0045), (0x2131, 0x0046), (0x2133, 0x004D), (0x2134, 0x006F), (0x2139, 0x0069),
+    (0x213C, 0x83CE), (0x213D, 0x83C1), (0x213E, 0x83A1), (0x213F, 0x83AE), (0x2140, 0x8794),
+    (0x2145, 0x0044), (0x2146, 0x0064), (0x2147, 0x0065), (0x2148, 0x0069), (0x2149, 0x006A),
+    (0x216C, 0x004C), (0x216D, 0x0043), (0x216E, 0x0044), (0x216F, 0x004D), (0x217C, 0x006C),
+    (0x217D, 0x0063), (0x217E, 0x0064), (0x217F, 0x006D), (0x219A, 0x81A9), (0x219B, 0x81A8),
+    (0x21CE, 0x81CC), (0x21CF, 0x81CB), (0x2204, 0x81CE), (0x2209, 0x81B8), (0x220C, 0x81B9),
+    (0x2212, 0x002D), (0x2215, 0x002F), (0x2216, 0x005C), (0x2223, 0x007C), (0x2226, 0x8161),
+    (0x223C, 0x007E), (0x2262, 0x81DF), (0x226E, 0x003C), (0x226F, 0x003E), (0x2284, 0x81BC),
+    (0x2285, 0x81BD), (0x2288, 0x81BA), (0x2289, 0x81BB), (0x2329, 0x8171), (0x232A, 0x8172),
+    (0x24B6, 0x0041), (0x24B7, 0x0042), (0x24B8, 0x0043), (0x24B9, 0x0044), (0x24BA, 0x0045),
+    (0x24BB, 0x0046), (0x24BC, 0x0047), (0x24BD, 0x0048), (0x24BE, 0x0049), (0x24BF, 0x004A),
+    (0x24C0, 0x004B), (0x24C1, 0x004C), (0x24C2, 0x004D), (0x24C3, 0x004E), (0x24C4, 0x004F),
+    (0x24C5, 0x0050), (0x24C6, 0x0051), (0x24C7, 0x0052), (0x24C8, 0x0053), (0x24C9, 0x0054),
+    (0x24CA, 0x0055), (0x24CB, 0x0056), (0x24CC, 0x0057), (0x24CD, 0x0058), (0x24CE, 0x0059),
+    (0x24CF, 0x005A), (0x24D0, 0x0061), (0x24D1, 0x0062), (0x24D2, 0x0063), (0x24D3, 0x0064),
+    (0x24D4, 0x0065), (0x24D5, 0x0066), (0x24D6, 0x0067), (0x24D7, 0x0068), (0x24D8, 0x0069),
+    (0x24D9, 0x006A), (0x24DA, 0x006B), (0x24DB, 0x006C), (0x24DC, 0x006D), (0x24DD, 0x006E),
+    (0x24DE, 0x006F), (0x24DF, 0x0070), (0x24E0, 0x0071), (0x24E1, 0x0072), (0x24E2, 0x0073),
+    (0x24E3, 0x0074), (0x24E4, 0x0075), (0x24E5, 0x0076), (0x24E6, 0x0077), (0x24E7, 0x0078),
+    (0x24E8, 0x0079), (0x24E9, 0x007A), (0x24EA, 0x0030), (0x2C7C, 0x006A), (0x2C7D, 0x0056),
+    (0x2E9F, 0x95EA), (0x2F00, 0x88EA), (0x2F01, 0xFA68), (0x2F02, 0x98A4), (0x2F03, 0x98A6),
+    (0x2F04, 0x89B3), (0x2F05, 0x98AB), (0x2F06, 0x93F1), (0x2F07, 0x98B3), (0x2F08, 0x906C),
+    (0x2F09, 0x9958), (0x2F0A, 0x93FC), (0x2F0B, 0x94AA), (0x2F0C, 0x9963), (0x2F0D, 0x996B),
+    (0x2F0E, 0x9971), (0x2F0F, 0x997B), (0x2F10, 0x9981), (0x2F11, 0x9381), (0x2F12, 0x97CD),
+    (0x2F13, 0x99AF), (0x2F14, 0x99B6), (0x2F15, 0x99B7), (0x2F16, 0x99BC), (0x2F17, 0x8F5C),
+    (0x2F18, 0x966D), (0x2F19, 0x99C5), (0x2F1A, 0x99CA), (0x2F1B, 0x99D1), (0x2F1C, 0x9694),
+    (0x2F1D, 0x8CFB), (0x2F1E, 0x9A98), (0x2F1F, 0x9379), (0x2F20, 0x8E6D), (0x2F21, 0x9AE7),
+    (0x2F22, 0x9AE8), (0x2F23, 0x975B), (0x2F24, 0x91E5), (0x2F25, 0x8F97), (0x2F26, 0x8E71),
+    (0x2F27, 0x9B7E), (0x2F28, 0x90A1), (0x2F29, 0x8FAC), (0x2F2A, 0x9B97), (0x2F2B, 0x9B99),
+    (0x2F2C, 0x9BA3), (0x2F2D, 0x8E52), (0x2F2E, 0x9BDD), (0x2F2F, 0x8D48), (0x2F30, 0x8CC8),
+    (0x2F31, 0x8BD0), (0x2F32, 0x8AB1), (0x2F33, 0x9BF4), (0x2F34, 0x9BF6), (0x2F35, 0x9C4D),
+    (0x2F36, 0x9C4F), (0x2F37, 0x9C54), (0x2F38, 0x8B7C), (0x2F3A, 0x9C63), (0x2F3B, 0x9C65),
+    (0x2F3C, 0x9053), (0x2F3D, 0x9CF7), (0x2F3F, 0x8EE8), (0x2F40, 0x8E78), (0x2F41, 0x9DBB),
+    (0x2F42, 0x95B6), (0x2F43, 0x936C), (0x2F44, 0x8BD2), (0x2F45, 0x95FB), (0x2F46, 0x9DD9),
+    (0x2F47, 0x93FA), (0x2F48, 0x9E48), (0x2F49, 0x8C8E), (0x2F4A, 0x96D8), (0x2F4B, 0x8C87),
+    (0x2F4C, 0x8E7E), (0x2F4D, 0x9F65), (0x2F4E, 0x9F74), (0x2F4F, 0x9F78), (0x2F50, 0x94E4),
+    (0x2F51, 0x96D1), (0x2F52, 0x8E81), (0x2F53, 0x9F83), (0x2F54, 0x9085), (0x2F55, 0x89CE),
+    (0x2F56, 0x92DC), (0x2F57, 0x9583), (0x2F58, 0xE0A9), (0x2F59, 0xE0AB), (0x2F5A, 0x95D0),
+    (0x2F5B, 0x89E5), (0x2F5C, 0x8B8D), (0x2F5D, 0x8CA2), (0x2F5E, 0x8CBA), (0x2F5F, 0x8BCA),
+    (0x2F60, 0x895A), (0x2F61, 0x8AA2), (0x2F62, 0x8AC3), (0x2F63, 0x90B6), (0x2F64, 0x9770),
+    (0x2F65, 0x9363), (0x2F66, 0x9544), (0x2F68, 0xE1A0), (0x2F69, 0x9492), (0x2F6A, 0x94E7),
+    (0x2F6B, 0x8E4D), (0x2F6C, 0x96DA), (0x2F6D, 0x96B5), (0x2F6E, 0x96EE), (0x2F6F, 0x90CE),
+    (0x2F70, 0x8EA6), (0x2F72, 0x89D1), (0x2F73, 0x8C8A), (0x2F74, 0x97A7), (0x2F75, 0x927C),
+    (0x2F76, 0x95C4), (0x2F77, 0x8E85), (0x2F78, 0x8ACA), (0x2F79, 0xE3A4), (0x2F7A, 0x9772),
+    (0x2F7B, 0x8948), (0x2F7C, 0x9856), (0x2F7D, 0x8EA7), (0x2F7E, 0xE3CE), (0x2F7F, 0x8EA8),
+    (0x2F80, 0xE3E4), (0x2F81, 0x93F7), (0x2F82, 0x9062), (0x2F83, 0x8EA9), (0x2F84, 0x8E8A),
+    (0x2F85, 0x8950), (0x2F86, 0x90E3), (0x2F87, 0x9143), (0x2F88, 0x8F4D), (0x2F89, 0x8DAF),
+    (0x2F8A, 0x9046), (0x2F8B, 0xE487), (0x2F8C, 0xE567), (0x2F8D, 0x928E), (0x2F8E, 0x8C8C),
+    (0x2F8F, 0x8D73), (0x2F90, 0x88DF), (0x2F91, 0xE647), (0x2F92, 0x8CA9), (0x2F93, 0x8A70),
+    (0x2F94, 0x8CBE), (0x2F95, 0x924A), (0x2F96, 0x93A4), (0x2F97, 0xE6B3), (0x2F98, 0xE6B6),
+    (0x2F99, 0x8A4C), (0x2F9A, 0x90D4), (0x2F9B, 0x9196), (0x2F9C, 0x91AB), (0x2F9D, 0x9067),
+    (0x2F9E, 0x8ED4), (0x2F9F, 0x9068), (0x2FA0, 0x9243), (0x2FA2, 0x9757), (0x2FA3, 0x93D1),
+    (0x2FA4, 0x94CE), (0x2FA5, 0x97A2), (0x2FA6, 0x8BE0), (0x2FA7, 0x92B7), (0x2FA8, 0x96E5),
+    (0x2FA9, 0x958C), (0x2FAA, 0xE8AE), (0x2FAB, 0xE8B0), (0x2FAC, 0x894A), (0x2FAD, 0xFBF2),
+    (0x2FAE, 0x94F1), (0x2FAF, 0x96CA), (0x2FB0, 0x8A76), (0x2FB1, 0xE8E8), (0x2FB2, 0xE8EA),
+    (0x2FB3, 0x89B9), (0x2FB4, 0x95C5), (0x2FB5, 0x9597), (0x2FB6, 0x94F2), (0x2FB7, 0x9048),
+    (0x2FB8, 0x8EF1), (0x2FB9, 0x8D81), (0x2FBA, 0x946E), (0x2FBB, 0x8D9C), (0x2FBC, 0x8D82),
+    (0x2FBD, 0xE995), (0x2FBE, 0xE9A6), (0x2FBF, 0xE9AC), (0x2FC0, 0xE9AD), (0x2FC1, 0x8B53),
+    (0x2FC2, 0x8B9B), (0x2FC3, 0x92B9), (0x2FC4, 0xEA62), (0x2FC5, 0x8EAD), (0x2FC6, 0xEA6D),
+    (0x2FC7, 0x9683), (0x2FC9, 0x8B6F), (0x2FCA, 0xFC4B), (0x2FCB, 0xEA83), (0x2FCC, 0xEA86),
+    (0x2FCD, 0x9343), (0x2FCE, 0x8CDB), (0x2FCF, 0x916C), (0x2FD0, 0x9540), (0x2FD1, 0xEA8E),
+    (0x2FD2, 0xEA8F), (0x2FD3, 0x97B4), (0x2FD4, 0xEA9D), (0x2FD5, 0xEA9E), (0x3036, 0x81A7),
+    (0x3038, 0x8F5C), (0x303A, 0x99BF), (0x3094, 0x82A4), (0x30F7, 0x838F), (0x30F8, 0x8390),
+    (0x30F9, 0x8391), (0x30FA, 0x8392), (0x3192, 0x88EA), (0x3193, 0x93F1), (0x3194, 0x8E4F),
+    (0x3195, 0x8E6C), (0x3196, 0x8FE3), (0x3197, 0x9286), (0x3198, 0x89BA), (0x3199, 0x8D62),
+    (0x319A, 0x89B3), (0x319B, 0x95B8), (0x319C, 0x929A), (0x319D, 0x9356), (0x319E, 0x926E),
+    (0x319F, 0x906C), (0x3244, 0x96E2), (0x3245, 0x9763), (0x3246, 0x95B6), (0x3247, 0xE2B5),
+    (0x3280, 0x88EA), (0x3281, 0x93F1), (0x3282, 0x8E4F), (0x3283, 0x8E6C), (0x3284, 0x8CDC),
+    (0x3285, 0x985A), (0x3286, 0x8EB5), (0x3287, 0x94AA), (0x3288, 0x8BE3), (0x3289, 0x8F5C),
+    (0x328A, 0x8C8E), (0x328B, 0x89CE), (0x328C, 0x9085), (0x328D, 0x96D8), (0x328E, 0x8BE0),
+    (0x328F, 0x9379), (0x3290, 0x93FA), (0x3291, 0x8A94), (0x3292, 0x974C), (0x3293, 0x8ED0),
+    (0x3294, 0x96BC), (0x3295, 0x93C1), (0x3296, 0x8DE0), (0x3297, 0x8F6A), (0x3298, 0x984A),
+    (0x3299, 0x94E9), (0x329A, 0x926A), (0x329B, 0x8F97), (0x329C, 0x934B), (0x329D, 0x9744),
+    (0x329E, 0x88F3), (0x329F, 0x928D), (0x32A0, 0x8D80), (0x32A1, 0x8B78), (0x32A2, 0x8ECA),
+    (0x32A3, 0x90B3), (0x32A9, 0x88E3), (0x32AA, 0x8F40), (0x32AB, 0x8A77), (0x32AC, 0x8AC4),
+    (0x32AD, 0x8AE9), (0x32AE, 0x8E91), (0x32AF, 0x8BA6), (0x32B0, 0x96E9), (0x32D0, 0x8341),
+    (0x32D1, 0x8343), (0x32D2, 0x8345), (0x32D3, 0x8347), (0x32D4, 0x8349), (0x32D5, 0x834A),
+    (0x32D6, 0x834C), (0x32D7, 0x834E), (0x32D8, 0x8350), (0x32D9, 0x8352), (0x32DA, 0x8354),
+    (0x32DB, 0x8356), (0x32DC, 0x8358), (0x32DD, 0x835A), (0x32DE, 0x835C), (0x32DF, 0x835E),
+    (0x32E0, 0x8360), (0x32E1, 0x8363), (0x32E2, 0x8365), (0x32E3, 0x8367), (0x32E4, 0x8369),
+    (0x32E5, 0x836A), (0x32E6, 0x836B), (0x32E7, 0x836C), (0x32E8, 0x836D), (0x32E9, 0x836E),
+    (0x32EA, 0x8371), (0x32EB, 0x8374), (0x32EC, 0x8377), (0x32ED, 0x837A), (0x32EE, 0x837D),
+    (0x32EF, 0x837E), (0x32F0, 0x8380), (0x32F1, 0x8381), (0x32F2, 0x8382), (0x32F3, 0x8384),
+    (0x32F4, 0x8386), (0x32F5, 0x8388), (0x32F6, 0x8389), (0x32F7, 0x838A), (0x32F8, 0x838B),
+    (0x32F9, 0x838C), (0x32FA, 0x838D), (0x32FB, 0x838F), (0x32FC, 0x8390), (0x32FD, 0x8391),
+    (0x32FE, 0x8392), (0xA69C, 0x848C), (0xA69D, 0x848E), (0xA7F2, 0x0043), (0xA7F3, 0x0046),
+    (0xA7F4, 0x0051), (0xF900, 0xE6AF), (0xF901, 0x8D58), (0xF902, 0x8ED4), (0xF903, 0xE6C9),
+    (0xF904, 0x8A8A), (0xF905, 0x8BF8), (0xF906, 0x8BE5), (0xF907, 0xEA9D), (0xF908, 0xEA9D),
+    (0xF909, 0x8C5F), (0xF90A, 0x8BE0), (0xF90B, 0x9A68), (0xF90C, 0x93DE), (0xF90D, 0x9CEF),
+    (0xF90E, 0xE19A), (0xF90F, 0x9785), (0xF910, 0xE566), (0xF911, 0x9786), (0xF912, 0x9787),
+    (0xF913, 0xE7B4), (0xF914, 0x9ED9), (0xF915, 0x978C), (0xF916, 0xE080), (0xF917, 0xE0E2),
+    (0xF918, 0x978E), (0xF919, 0x978F), (0xF91A, 0xE970), (0xF91B, 0x98AA), (0xF91C, 0x9791),
+    (0xF91D, 0x9793), (0xF91E, 0xE0A3), (0xF91F, 0x9796), (0xF920, 0xEA61), (0xF921, 0x9792),
+    (0xF922, 0x9794), (0xF923, 0x9795), (0xF924, 0xE641), (0xF925, 0x9D66), (0xF926, 0xE463),
+    (0xF928, 0x984C), (0xF92A, 0x9851), (0xF92B, 0x9854), (0xF92C, 0x9859), (0xF92D, 0x98D2),
+    (0xF92E, 0x97E2), (0xF92F, 0x99A7), (0xF931, 0x9845), (0xF932, 0xE0A2), (0xF933, 0xE1B8),
+    (0xF934, 0x9856), (0xF935, 0xE562), (0xF936, 0x97B8), (0xF937, 0x9848), (0xF938, 0x9849),
+    (0xF939, 0x9844), (0xF93A, 0x8DEB), (0xF93B, 0xE1EF), (0xF93C, 0xE252), (0xF93D, 0xFB8D),
+    (0xF940, 0x8EAD), (0xF941, 0x985F), (0xF942, 0x9AE0), (0xF943, 0x984D), (0xF944, 0xE2C4),
+    (0xF945, 0x9857), (0xF946, 0x9853), (0xF947, 0xE1FB), (0xF948, 0x9847), (0xF949, 0x978B),
+    (0xF94A, 0x9ADC), (0xF94C, 0x9EEA), (0xF94E, 0x9852), (0xF94F, 0x97DD), (0xF950, 0xE37E),
+    (0xF951, 0xE89B), (0xF952, 0xE8D3), (0xF953, 0x985D), (0xF954, 0xEAA3), (0xF955, 0x97BD),
+    (0xF956, 0x97C5), (0xF957, 0x88BB), (0xF958, 0x9548), (0xF959, 0x97CB), (0xF95A, 0xE6A4),
+    (0xF95B, 0x9D5B), (0xF95C, 0x9ED9), (0xF95D, 0x91F8), (0xF95E, 0x924F), (0xF95F, 0x944A),
+    (0xF960, 0x937B), (0xF961, 0x97A6), (0xF962, 0x88D9), (0xF963, 0x966B), (0xF965, 0x95D6),
+    (0xF966, 0x959C), (0xF967, 0x9573), (0xF968, 0x94E5), (0xF969, 0x9DC9), (0xF96A, 0x8DF5),
+    (0xF96B, 0x99D2), (0xF96C, 0x8DC7), (0xF96D, 0x8FC8), (0xF96E, 0x9774), (0xF970, 0x8E45),
+    (0xF971, 0x9243), (0xF972, 0x92BE), (0xF973, 0x8F45), (0xF974, 0x8EE1), (0xF975, 0x97A9),
+    (0xF976, 0x97AA), (0xF977, 0x97BA), (0xF978, 0x995F), (0xF979, 0x9979), (0xF97A, 0x97C0),
+    (0xF97B, 0x97C6), (0xF97C, 0x97C7), (0xF97D, 0x97C8), (0xF97E, 0x97CA), (0xF97F, 0x99AD),
+    (0xF980, 0x9843), (0xF981, 0x8F97), (0xF982, 0x9C49), (0xF983, 0x97B7), (0xF984, 0xE068),
+    (0xF985, 0xE1E8), (0xF986, 0xE883), (0xF987, 0xE98B), (0xF988, 0x97ED), (0xF989, 0xEA74),
+    (0xF98A, 0x97CD), (0xF98D, 0xE780), (0xF98E, 0x944E), (0xF98F, 0x97F7), (0xF990, 0x9CF6),
+    (0xF991, 0x9451), (0xF992, 0x97F8), (0xF993, 0x97F9), (0xF994, 0xFB6C), (0xF996, 0x97FB),
+    (0xF997, 0x97FC), (0xF998, 0xE772), (0xF999, 0x9840), (0xF99A, 0x9841), (0xF99C, 0x97F1),
+    (0xF99D, 0x97F2), (0xF99E, 0x88F4), (0xF99F, 0x97F3), (0xF9A0, 0x97F4), (0xF9A2, 0x97F5),
+    (0xF9A3, 0x944F), (0xF9A4, 0x9450), (0xF9A6, 0x97FA), (0xF9A7, 0xE0D8), (0xF9A8, 0x97DF),
+    (0xF9A9, 0x9A9A), (0xF9AA, 0x944A), (0xF9AB, 0x97E4), (0xF9AC, 0x97E5), (0xF9AD, 0x97E6),
+    (0xF9AE, 0xE0F0), (0xF9AF, 0xE3B7), (0xF9B0, 0xE3D7), (0xF9B1, 0x97E9), (0xF9B2, 0x97EB),
+    (0xF9B3, 0xE8CB), (0xF9B4, 0x97CC), (0xF9B5, 0x97E1), (0xF9B6, 0xE258), (0xF9B7, 0xE7D2),
+    (0xF9B8, 0xE8AF), (0xF9B9, 0x9CA6), (0xF9BA, 0x97B9), (0xF9BB, 0x97BB), (0xF9BC, 0x97BE),
+    (0xF9BD, 0x9441), (0xF9BE, 0x97BF), (0xF9BF, 0x9ED9), (0xF9C0, 0xE099), (0xF9C1, 0x97C3),
+    (0xF9C2, 0xE4F8), (0xF9C3, 0x97C9), (0xF9C4, 0x97B4), (0xF9C5, 0x9DF2), (0xF9C6, 0xE896),
+    (0xF9C7, 0x97AB), (0xF9C9, 0x96F6), (0xF9CA, 0x97AC), (0xF9CB, 0x97AD), (0xF9CC, 0x97AE),
+    (0xF9CD, 0x97AF), (0xF9CE, 0x97B0), (0xF9CF, 0x9552), (0xF9D0, 0x97DE), (0xF9D1, 0x985A),
+    (0xF9D2, 0x9D43), (0xF9D3, 0x97A4), (0xF9D4, 0x97CF), (0xF9D5, 0x9BC4), (0xF9D6, 0x9FCB),
+    (0xF9D7, 0x97D6), (0xF9D8, 0x97A5), (0xF9D9, 0x9CC9), (0xF9DA, 0x8C49), (0xF9DB, 0x97A6),
+    (0xF9DD, 0x9798), (0xF9DE, 0x9799), (0xF9DF, 0x979A), (0xF9E0, 0x88D5), (0xF9E1, 0x979B),
+    (0xF9E2, 0x979C), (0xF9E3, 0x9344), (0xF9E4, 0x979D), (0xF9E5, 0x979F), (0xF9E6, 0x9CEB),
+    (0xF9E7, 0x97A0), (0xF9E8, 0x97A1), (0xF9E9, 0x97A2), (0xF9EA, 0x97A3), (0xF9EB, 0x93BD),
+    (0xF9EC, 0x934D), (0xF9ED, 0x99E5), (0xF9EE, 0x97D3), (0xF9F0, 0xE561), (0xF9F1, 0x97D7),
+    (0xF9F2, 0x97D8), (0xF9F3, 0x97D9), (0xF9F4, 0x97D1), (0xF9F5, 0x97D2), (0xF9F6, 0x97D5),
+    (0xF9F7, 0x97A7), (0xF9F8, 0x8A7D), (0xF9F9, 0x97B1), (0xF9FB, 0xE074), (0xF9FC, 0x8EAF),
+    (0xF9FD, 0x8F59), (0xF9FE, 0x9283), (0xF9FF, 0x8E68), (0xFA00, 0x90D8), (0xFA01, 0x9378),
+    (0xFA02, 0x91F1), (0xFA03, 0x939C), (0xFA04, 0x91EE), (0xFA05, 0x93B4), (0xFA06, 0x965C),
+    (0xFA07, 0xE774), (0xFA08, 0x8D73), (0xFA09, 0x8D7E), (0xFA0A, 0x8CA9), (0xFA0B, 0x8A66),
+    (0xFA0C, 0x9959), (0xFA2E, 0xFBB6), (0xFA2F, 0x97EA), (0xFA30, 0x958E), (0xFA31, 0x916D),
+    (0xFA32, 0x96C6), (0xFA33, 0x95D7), (0xFA34, 0x8BCE), (0xFA35, 0x94DA), (0xFA36, 0x8A85),
+    (0xFA37, 0x9251), (0xFA38, 0x8AED), (0xFA39, 0x95BB), (0xFA3A, 0x966E), (0xFA3B, 0x9177),
+    (0xFA3C, 0x9BA3), (0xFA3D, 0x89F7), (0xFA3E, 0x8A53), (0xFA3F, 0x919E), (0xFA40, 0x92A6),
+    (0xFA41, 0x9571), (0xFA42, 0x8AF9), (0xFA43, 0x8F8B), (0xFA44, 0x947E), (0xFA45, 0x8A43),
+    (0xFA46, 0x8F8D), (0xFA47, 0x8ABF), (0xFA48, 0x8ECF), (0xFA4A, 0x91F4), (0xFA4B, 0x94E8),
+    (0xFA4C, 0x8ED0), (0xFA4D, 0x8E83), (0xFA4E, 0x8B46), (0xFA4F, 0x9753), (0xFA50, 0x9163),
+    (0xFA51, 0x8F6A), (0xFA52, 0x89D0), (0xFA53, 0x92F5), (0xFA54, 0x8D92), (0xFA55, 0x93CB),
+    (0xFA56, 0x90DF), (0xFA57, 0x97FB), (0xFA58, 0xE375), (0xFA59, 0x94C9), (0xFA5A, 0x8F90),
+    (0xFA5B, 0x8ED2), (0xFA5C, 0x8F4C), (0xFA5F, 0x9298), (0xFA60, 0x8A8C), (0xFA61, 0x8E8B),
+    (0xFA62, 0x8979), (0xFA63, 0x8BDE), (0xFA64, 0x956F), (0xFA65, 0x91A1), (0xFA67, 0x88ED),
+    (0xFA68, 0x93EF), (0xFA69, 0x8BBF), (0xFA6A, 0x9570), (0xFA6B, 0x8C62), (0xFA6D, 0x8ADA),
+    (0xFA70, 0x95C0), (0xFA71, 0x9976), (0xFA72, 0x9153), (0xFA74, 0x8F5B), (0xFA75, 0x9962),
+    (0xFA76, 0x9745), (0xFA77, 0x8ED9), (0xFA78, 0x8A85), (0xFA7A, 0x9A5B), (0xFA7C, 0x92CB),
+    (0xFA7D, 0x95AD), (0xFA7E, 0x8982), (0xFA7F, 0x967A), (0xFA80, 0x9B58), (0xFA84, 0x8DCA),
+    (0xFA85, 0x9C73), (0xFA86, 0x9CAF), (0xFA87, 0x9054), (0xFA88, 0x96FA), (0xFA89, 0x919E),
+    (0xFA8B, 0x92A6), (0xFA8C, 0x91D5), (0xFA8D, 0x9D89), (0xFA8E, 0x9D72), (0xFA90, 0x9DC2),
+    (0xFA91, 0x90B0), (0xFA92, 0x984E), (0xFA93, 0x965D), (0xFA94, 0x8FF1), (0xFA95, 0x9F65),
+    (0xFA96, 0x8E45), (0xFA97, 0x97AC), (0xFA99, 0x8EA0), (0xFA9A, 0x8ABF), (0xFA9B, 0x93D2),
+    (0xFA9C, 0x8ECF), (0xFA9E, 0x8EDD), (0xFA9F, 0x94C6), (0xFAA0, 0x9296), (0xFAA3, 0x89E6),
+    (0xFAA5, 0xE189), (0xFAA6, 0x8976), (0xFAA7, 0x90B7), (0xFAA8, 0x92BC), (0xFAAA, 0x9285),
+    (0xFAAD, 0x90DF), (0xFAAF, 0xE355), (0xFAB0, 0x97FB), (0xFAB2, 0x8ED2), (0xFAB3, 0x8D72),
+    (0xFAB4, 0x89D8), (0xFAB6, 0xE5F4), (0xFAB7, 0x95A2), (0xFAB8, 0x8E8B), (0xFAB9, 0x92B2),
+    (0xFABA, 0x8F94), (0xFABB, 0x90BF), (0xFABC, 0x8979), (0xFABD, 0x91F8), (0xFABE, 0x9740),
+    (0xFABF, 0x8BDE), (0xFAC0, 0x9DCC), (0xFAC1, 0x91A1), (0xFAC2, 0x9741), (0xFAC3, 0xE7AD),
+    (0xFAC7, 0x93EF), (0xFAC8, 0x96F5), (0xFACA, 0x8BBF), (0xFACC, 0x9570), (0xFACE, 0xEA9D),
+    (0xFB29, 0x002B), (0xFE10, 0x002C), (0xFE11, 0x8141), (0xFE12, 0x8142), (0xFE13, 0x003A),
+    (0xFE14, 0x003B), (0xFE15, 0x0021), (0xFE16, 0x003F), (0xFE33, 0x005F), (0xFE34, 0x005F),
+    (0xFE35, 0x0028), (0xFE36, 0x0029), (0xFE37, 0x007B), (0xFE38, 0x007D), (0xFE39, 0x816B),
+    (0xFE3A, 0x816C), (0xFE3B, 0x8179), (0xFE3C, 0x817A), (0xFE3D, 0x8173), (0xFE3E, 0x8174),
+    (0xFE3F, 0x8171), (0xFE40, 0x8172), (0xFE41, 0x8175), (0xFE42, 0x8176), (0xFE43, 0x8177),
+    (0xFE44, 0x8178), (0xFE47, 0x005B), (0xFE48, 0x005D), (0xFE4D, 0x005F), (0xFE4E, 0x005F),
+    (0xFE4F, 0x005F), (0xFE50, 0x002C), (0xFE51, 0x8141), (0xFE52, 0x002E), (0xFE54, 0x003B),
+    (0xFE55, 0x003A), (0xFE56, 0x003F), (0xFE57, 0x0021), (0xFE59, 0x0028), (0xFE5A, 0x0029),
+    (0xFE5B, 0x007B), (0xFE5C, 0x007D), (0xFE5D, 0x816B), (0xFE5E, 0x816C), (0xFE5F, 0x0023),
+    (0xFE60, 0x0026), (0xFE61, 0x002A), (0xFE62, 0x002B), (0xFE63, 0x002D), (0xFE64, 0x003C),
+    (0xFE65, 0x003E), (0xFE66, 0x003D), (0xFE68, 0x005C), (0xFE69, 0x0024), (0xFE6A, 0x0025),
+    (0xFE6B, 0x0040), (0xFFE8, 0x84A0), (0xFFE9, 0x81A9), (0xFFEA, 0x81AA), (0xFFEB, 0x81A8),
+    (0xFFEC, 0x81AB), (0xFFED, 0x81A1), (0xFFEE, 0x819B),
+];
+
+/// Code page 936.
+#[rustfmt::skip]
+pub(crate) const CP936: [(u16, u16); 1865] = [
+    (0x00A0, 0x0020), (0x00AA, 0x0061), (0x00AB, 0x003C), (0x00B2, 0x0032), (0x00B3, 0x0033),
+    (0x00B5, 0xA6CC), (0x00B9, 0x0031), (0x00BA, 0x006F), (0x00BB, 0x003E), (0x00C0, 0x0041),
+    (0x00C1, 0x0041), (0x00C2, 0x0041), (0x00C3, 0x0041), (0x00C4, 0x0041), (0x00C5, 0x0041),
+    (0x00C7, 0x0043), (0x00C8, 0x0045), (0x00C9, 0x0045), (0x00CA, 0x0045), (0x00CB, 0x0045),
+    (0x00CC, 0x0049), (0x00CD, 0x0049), (0x00CE, 0x0049), (0x00CF, 0x0049), (0x00D1, 0x004E),
+    (0x00D2, 0x004F), (0x00D3, 0x004F), (0x00D4, 0x004F), (0x00D5, 0x004F), (0x00D6, 0x004F),
+    (0x00D9, 0x0055), (0x00DA, 0x0055), (0x00DB, 0x0055), (0x00DC, 0x0055), (0x00DD, 0x0059),
+    (0x00E2, 0x0061), (0x00E3, 0x0061), (0x00E4, 0x0061), (0x00E5, 0x0061), (0x00E7, 0x0063),
+    (0x00EB, 0x0065), (0x00EE, 0x0069), (0x00EF, 0x0069), (0x00F1, 0x006E), (0x00F4, 0x006F),
+    (0x00F5, 0x006F), (0x00F6, 0x006F), (0x00FB, 0x0075), (0x00FD, 0x0079), (0x00FF, 0x0079),
+    (0x0100, 0x0041), (0x0102, 0x0041), (0x0103, 0x0061), (0x0104, 0x0041), (0x0105, 0x0061),
+    (0x0106, 0x0043), (0x0107, 0x0063), (0x0108, 0x0043), (0x0109, 0x0063), (0x010A, 0x0043),
+    (0x010B, 0x0063), (0x010C, 0x0043), (0x010D, 0x0063), (0x010E, 0x0044), (0x010F, 0x0064),
+    (0x0112, 0x0045), (0x0114, 0x0045), (0x0115, 0x0065), (0x0116, 0x0045), (0x0117, 0x0065),
+    (0x0118, 0x0045), (0x0119, 0x0065), (0x011A, 0x0045), (0x011C, 0x0047), (0x011D, 0x0067),
+    (0x011E, 0x0047), (0x011F, 0x0067), (0x0120, 0x0047), (0x0121, 0x0067), (0x0122, 0x0047),
+    (0x0123, 0x0067), (0x0124, 0x0048), (0x0125, 0x0068), (0x0128, 0x0049), (0x0129, 0x0069),
+    (0x012A, 0x0049), (0x012C, 0x0049), (0x012D, 0x0069), (0x012E, 0x0049), (0x012F, 0x0069),
+    (0x0130, 0x0049), (0x0134, 0x004A), (0x0135, 0x006A), (0x0136, 0x004B), (0x0137, 0x006B),
+    (0x0139, 0x004C), (0x013A, 0x006C), (0x013B, 0x004C), (0x013C, 0x006C), (0x013D, 0x004C),
+    (0x013E, 0x006C), (0x0143, 0x004E), (0x0145, 0x004E), (0x0146, 0x006E), (0x0147, 0x004E),
+    (0x014C, 0x004F), (0x014E, 0x004F), (0x014F, 0x006F), (0x0150, 0x004F), (0x0151, 0x006F),
+    (0x0154, 0x0052), (0x0155, 0x0072), (0x0156, 0x0052), (0x0157, 0x0072), (0x0158, 0x0052),
+    (0x0159, 0x0072), (0x015A, 0x0053), (0x015B, 0x0073), (0x015C, 0x0053), (0x015D, 0x0073),
+    (0x015E, 0x0053), (0x015F, 0x0073), (0x0160, 0x0053), (0x0161, 0x0073), (0x0162, 0x0054),
+    (0x0163, 0x0074), (0x0164, 0x0054), (0x0165, 0x0074), (0x0168, 0x0055), (0x0169, 0x0075),
+    (0x016A, 0x0055), (0x016C, 0x0055), (0x016D, 0x0075), (0x016E, 0x0055), (0x016F, 0x0075),
+    (0x0170, 0x0055), (0x0171, 0x0075), (0x0172, 0x0055), (0x0173, 0x0075), (0x0174, 0x0057),
+    (0x0175, 0x0077), (0x0176, 0x0059), (0x0177, 0x0079), (0x0178, 0x0059), (0x0179, 0x005A),
+    (0x017A, 0x007A), (0x017B, 0x005A), (0x017C, 0x007A), (0x017D, 0x005A), (0x017E, 0x007A),
+    (0x017F, 0x0073), (0x01A0, 0x004F), (0x01A1, 0x006F), (0x01AF, 0x0055), (0x01B0, 0x0075),
+    (0x01CD, 0x0041), (0x01CF, 0x0049), (0x01D1, 0x004F), (0x01D3, 0x0055), (0x01D5, 0x0055),
+    (0x01D7, 0x0055), (0x01D9, 0x0055), (0x01DB, 0x0055), (0x01DE, 0x0041), (0x01DF, 0x0061),
+    (0x01E0, 0x0041), (0x01E1, 0x0061), (0x01E6, 0x0047), (0x01E7, 0x0067), (0x01E8, 0x004B),
+    (0x01E9, 0x006B), (0x01EA, 0x004F), (0x01EB, 0x006F), (0x01EC, 0x004F), (0x01ED, 0x006F),
+    (0x01F0, 0x006A), (0x01F4, 0x0047), (0x01F5, 0x0067), (0x01F8, 0x004E), (0x01F9, 0x006E),
+    (0x01FA, 0x0041), (0x01FB, 0x0061), (0x0200, 0x0041), (0x0201, 0x0061), (0x0202, 0x0041),
+    (0x0203, 0x0061), (0x0204, 0x0045), (0x0205, 0x0065), (0x0206, 0x0045), (0x0207, 0x0065),
+    (0x0208, 0x0049), (0x0209, 0x0069), (0x020A, 0x0049), (0x020B, 0x0069), (0x020C, 0x004F),
+    (0x020D, 0x006F), (0x020E, 0x004F), (0x020F, 0x006F), (0x0210, 0x0052), (0x0211, 0x0072),
+    (0x0212, 0x0052), (0x0213, 0x0072), (0x0214, 0x0055), (0x0215, 0x0075), (0x0216, 0x0055),
+    (0x0217, 0x0075), (0x0218, 0x0053), (0x0219, 0x0073), (0x021A, 0x0054), (0x021B, 0x0074),
+    (0x021E, 0x0048), (0x021F, 0x0068), (0x0226, 0x0041), (0x0227, 0x0061), (0x0228, 0x0045),
+    (0x0229, 0x0065), (0x022A, 0x004F), (0x022B, 0x006F), (0x022C, 0x004F), (0x022D, 0x006F),
+    (0x022E, 0x004F), (0x022F, 0x006F), (0x0230, 0x004F), (0x0231, 0x006F), (0x0232, 0x0059),
+    (0x0233, 0x0079), (0x02B0, 0x0068), (0x02B2, 0x006A), (0x02B3, 0x0072), (0x02B7, 0x0077),
+    (0x02B8, 0x0079), (0x02E1, 0x006C), (0x02E2, 0x0073), (0x02E3, 0x0078), (0x037E, 0x003B),
+    (0x0386, 0xA6A1), (0x0387, 0xA1A4), (0x0388, 0xA6A5), (0x0389, 0xA6A7), (0x038A, 0xA6A9),
+    (0x038C, 0xA6AF), (0x038E, 0xA6B4), (0x038F, 0xA6B8), (0x0390, 0xA6C9), (0x03AA, 0xA6A9),
+    (0x03AB, 0xA6B4), (0x03AC, 0xA6C1), (0x03AD, 0xA6C5), (0x03AE, 0xA6C7), (0x03AF, 0xA6C9),
+    (0x03B0, 0xA6D4), (0x03CA, 0xA6C9), (0x03CB, 0xA6D4), (0x03CC, 0xA6CF), (0x03CD, 0xA6D4),
+    (0x03CE, 0xA6D8), (0x03D0, 0xA6C2), (0x03D1, 0xA6C8), (0x03D2, 0xA6B4), (0x03D3, 0xA6B4),
+    (0x03D4, 0xA6B4), (0x03D5, 0xA6D5), (0x03D6, 0xA6D0), (0x03F0, 0xA6CA), (0x03F1, 0xA6D1),
+    (0x03F4, 0xA6A8), (0x03F5, 0xA6C5), (0x03F9, 0xA6B2), (0x0400, 0xA7A6), (0x0403, 0xA7A4),
+    (0x040C, 0xA7AC), (0x040D, 0xA7AA), (0x040E, 0xA7B5), (0x0450, 0xA7D6), (0x0453, 0xA7D4),
+    (0x045C, 0xA7DC), (0x045D, 0xA7DA), (0x045E, 0xA7E5), (0x04C1, 0xA7A8), (0x04C2, 0xA7D8),
+    (0x04D0, 0xA7A1), (0x04D1, 0xA7D1), (0x04D2, 0xA7A1), (0x04D3, 0xA7D1), (0x04D6, 0xA7A6),
+    (0x04D7, 0xA7D6), (0x04DC, 0xA7A8), (0x04DD, 0xA7D8), (0x04DE, 0xA7A9), (0x04DF, 0xA7D9),
+    (0x04E2, 0xA7AA), (0x04E3, 0xA7DA), (0x04E4, 0xA7AA), (0x04E5, 0xA7DA), (0x04E6, 0xA7B0),
+    (0x04E7, 0xA7E0), (0x04EC, 0xA7BF), (0x04ED, 0xA7EF), (0x04EE, 0xA7B5), (0x04EF, 0xA7E5),
+    (0x04F0, 0xA7B5), (0x04F1, 0xA7E5), (0x04F2, 0xA7B5), (0x04F3, 0xA7E5), (0x04F4, 0xA7B9),
+    (0x04F5, 0xA7E9), (0x04F8, 0xA7BD), (0x04F9, 0xA7ED), (0x1D2C, 0x0041), (0x1D2E, 0x0042),
+    (0x1D30, 0x0044), (0x1D31, 0x0045), (0x1D33, 0x0047), (0x1D34, 0x0048), (0x1D35, 0x0049),
+    (0x1D36, 0x004A), (0x1D37, 0x004B), (0x1D38, 0x004C), (0x1D39, 0x004D), (0x1D3A, 0x004E),
+    (0x1D3C, 0x004F), (0x1D3E, 0x0050), (0x1D3F, 0x0052), (0x1D40, 0x0054), (0x1D41, 0x0055),
+    (0x1D42, 0x0057), (0x1D43, 0x0061), (0x1D45, 0xA8BB), (0x1D47, 0x0062), (0x1D48, 0x0064),
+    (0x1D49, 0x0065), (0x1D4D, 0x0067), (0x1D4F, 0x006B), (0x1D50, 0x006D), (0x1D52, 0x006F),
+    (0x1D56, 0x0070), (0x1D57, 0x0074), (0x1D58, 0x0075), (0x1D5B, 0x0076), (0x1D5D, 0xA6C2),
+    (0x1D5E, 0xA6C3), (0x1D5F, 0xA6C4), (0x1D60, 0xA6D5), (0x1D61, 0xA6D6), (0x1D62, 0x0069),
+    (0x1D63, 0x0072), (0x1D64, 0x0075), (0x1D65, 0x0076), (0x1D66, 0xA6C2), (0x1D67, 0xA6C3),
+    (0x1D68, 0xA6D1), (0x1D69, 0xA6D5), (0x1D6A, 0xA6D6), (0x1D78, 0xA7DF), (0x1D9C, 0x0063),
+    (0x1DA0, 0x0066), (0x1DA2, 0xA8C0), (0x1DBB, 0x007A), (0x1DBF, 0xA6C8), (0x1E00, 0x0041),
+    (0x1E01, 0x0061), (0x1E02, 0x0042), (0x1E03, 0x0062), (0x1E04, 0x0042), (0x1E05, 0x0062),
+    (0x1E06, 0x0042), (0x1E07, 0x0062), (0x1E08, 0x0043), (0x1E09, 0x0063), (0x1E0A, 0x0044),
+    (0x1E0B, 0x0064), (0x1E0C, 0x0044), (0x1E0D, 0x0064), (0x1E0E, 0x0044), (0x1E0F, 0x0064),
+    (0x1E10, 0x0044), (0x1E11, 0x0064), (0x1E12, 0x0044), (0x1E13, 0x0064), (0x1E14, 0x0045),
+    (0x1E15, 0x0065), (0x1E16, 0x0045), (0x1E17, 0x0065), (0x1E18, 0x0045), (0x1E19, 0x0065),
+    (0x1E1A, 0x0045), (0x1E1B, 0x0065), (0x1E1C, 0x0045), (0x1E1D, 0x0065), (0x1E1E, 0x0046),
+    (0x1E1F, 0x0066), (0x1E20, 0x0047), (0x1E21, 0x0067), (0x1E22, 0x0048), (0x1E23, 0x0068),
+    (0x1E24, 0x0048), (0x1E25, 0x0068), (0x1E26, 0x0048), (0x1E27, 0x0068), (0x1E28, 0x0048),
+    (0x1E29, 0x0068), (0x1E2A, 0x0048), (0x1E2B, 0x0068), (0x1E2C, 0x0049), (0x1E2D, 0x0069),
+    (0x1E2E, 0x0049), (0x1E2F, 0x0069), (0x1E30, 0x004B), (0x1E31, 0x006B), (0x1E32, 0x004B),
+    (0x1E33, 0x006B), (0x1E34, 0x004B), (0x1E35, 0x006B), (0x1E36, 0x004C), (0x1E37, 0x006C),
+    (0x1E38, 0x004C), (0x1E39, 0x006C), (0x1E3A, 0x004C), (0x1E3B, 0x006C), (0x1E3C, 0x004C),
+    (0x1E3D, 0x006C), (0x1E3E, 0x004D), (0x1E3F, 0x006D), (0x1E40, 0x004D), (0x1E41, 0x006D),
+    (0x1E42, 0x004D), (0x1E43, 0x006D), (0x1E44, 0x004E), (0x1E45, 0x006E), (0x1E46, 0x004E),
+    (0x1E47, 0x006E), (0x1E48, 0x004E), (0x1E49, 0x006E), (0x1E4A, 0x004E), (0x1E4B, 0x006E),
+    (0x1E4C, 0x004F), (0x1E4D, 0x006F), (0x1E4E, 0x004F), (0x1E4F, 0x006F), (0x1E50, 0x004F),
+    (0x1E51, 0x006F), (0x1E52, 0x004F), (0x1E53, 0x006F), (0x1E54, 0x0050), (0x1E55, 0x0070),
+    (0x1E56, 0x0050), (0x1E57, 0x0070), (0x1E58, 0x0052), (0x1E59, 0x0072), (0x1E5A, 0x0052),
+    (0x1E5B, 0x0072), (0x1E5C, 0x0052), (0x1E5D, 0x0072), (0x1E5E, 0x0052), (0x1E5F, 0x0072),
+    (0x1E60, 0x0053), (0x1E61, 0x0073), (0x1E62, 0x0053), (0x1E63, 0x0073), (0x1E64, 0x0053),
+    (0x1E65, 0x0073), (0x1E66, 0x0053), (0x1E67, 0x0073), (0x1E68, 0x0053), (0x1E69, 0x0073),
+    (0x1E6A, 0x0054), (0x1E6B, 0x0074), (0x1E6C, 0x0054), (0x1E6D, 0x0074), (0x1E6E, 0x0054),
+    (0x1E6F, 0x0074), (0x1E70, 0x0054), (0x1E71, 0x0074), (0x1E72, 0x0055), (0x1E73, 0x0075),
+    (0x1E74, 0x0055), (0x1E75, 0x0075), (0x1E76, 0x0055), (0x1E77, 0x0075), (0x1E78, 0x0055),
+    (0x1E79, 0x0075), (0x1E7A, 0x0055), (0x1E7B, 0x0075), (0x1E7C, 0x0056), (0x1E7D, 0x0076),
+    (0x1E7E, 0x0056), (0x1E7F, 0x0076), (0x1E80, 0x0057), (0x1E81, 0x0077), (0x1E82, 0x0057),
+    (0x1E83, 0x0077), (0x1E84, 0x0057), (0x1E85, 0x0077), (0x1E86, 0x0057), (0x1E87, 0x0077),
+    (0x1E88, 0x0057), (0x1E89, 0x0077), (0x1E8A, 0x0058), (0x1E8B, 0x0078), (0x1E8C, 0x0058),
+    (0x1E8D, 0x0078), (0x1E8E, 0x0059), (0x1E8F, 0x0079), (0x1E90, 0x005A), (0x1E91, 0x007A),
+    (0x1E92, 0x005A), (0x1E93, 0x007A), (0x1E94, 0x005A), (0x1E95, 0x007A), (0x1E96, 0x0068),
+    (0x1E97, 0x0074), (0x1E98, 0x0077), (0x1E99, 0x0079), (0x1E9B, 0x0073), (0x1EA0, 0x0041),
+    (0x1EA1, 0x0061), (0x1EA2, 0x0041), (0x1EA3, 0x0061), (0x1EA4, 0x0041), (0x1EA5, 0x0061),
+    (0x1EA6, 0x0041), (0x1EA7, 0x0061), (0x1EA8, 0x0041), (0x1EA9, 0x0061), (0x1EAA, 0x0041),
+    (0x1EAB, 0x0061), (0x1EAC, 0x0041), (0x1EAD, 0x0061), (0x1EAE, 0x0041), (0x1EAF, 0x0061),
+    (0x1EB0, 0x0041), (0x1EB1, 0x0061), (0x1EB2, 0x0041), (0x1EB3, 0x0061), (0x1EB4, 0x0041),
+    (0x1EB5, 0x0061), (0x1EB6, 0x0041), (0x1EB7, 0x0061), (0x1EB8, 0x0045), (0x1EB9, 0x0065),
+    (0x1EBA, 0x0045), (0x1EBB, 0x0065), (0x1EBC, 0x0045), (0x1EBD, 0x0065), (0x1EBE, 0x0045),
+    (0x1EBF, 0x0065), (0x1EC0, 0x0045), (0x1EC1, 0x0065), (0x1EC2, 0x0045), (0x1EC3, 0x0065),
+    (0x1EC4, 0x0045), (0x1EC5, 0x0065), (0x1EC6, 0x0045), (0x1EC7, 0x0065), (0x1EC8, 0x0049),
+    (0x1EC9, 0x0069), (0x1ECA, 0x0049), (0x1ECB, 0x0069), (0x1ECC, 0x004F), (0x1ECD, 0x006F),
+    (0x1ECE, 0x004F), (0x1ECF, 0x006F), (0x1ED0, 0x004F), (0x1ED1, 0x006F), (0x1ED2, 0x004F),
+    (0x1ED3, 0x006F), (0x1ED4, 0x004F), (0x1ED5, 0x006F), (0x1ED6, 0x004F), (0x1ED7, 0x006F),
+    (0x1ED8, 0x004F), (0x1ED9, 0x006F), (0x1EDA, 0x004F), (0x1EDB, 0x006F), (0x1EDC, 0x004F),
+    (0x1EDD, 0x006F), (0x1EDE, 0x004F), (0x1EDF, 0x006F), (0x1EE0, 0x004F), (0x1EE1, 0x006F),
+    (0x1EE2, 0x004F), (0x1EE3, 0x006F), (0x1EE4, 0x0055), (0x1EE5, 0x0075), (0x1EE6, 0x0055),
+    (0x1EE7, 0x0075), (0x1EE8, 0x0055), (0x1EE9, 0x0075), (0x1EEA, 0x0055), (0x1EEB, 0x0075),
+    (0x1EEC, 0x0055), (0x1EED, 0x0075), (0x1EEE, 0x0055), (0x1EEF, 0x0075), (0x1EF0, 0x0055),
+    (0x1EF1, 0x0075), (0x1EF2, 0x0059), (0x1EF3, 0x0079), (0x1EF4, 0x0059), (0x1EF5, 0x0079),
+    (0x1EF6, 0x0059), (0x1EF7, 0x0079), (0x1EF8, 0x0059), (0x1EF9, 0x0079), (0x1F00, 0xA6C1),
+    (0x1F01, 0xA6C1), (0x1F02, 0xA6C1), (0x1F03, 0xA6C1), (0x1F04, 0xA6C1), (0x1F05, 0xA6C1),
+    (0x1F06, 0xA6C1), (0x1F07, 0xA6C1), (0x1F08, 0xA6A1), (0x1F09, 0xA6A1), (0x1F0A, 0xA6A1),
+    (0x1F0B, 0xA6A1), (0x1F0C, 0xA6A1), (0x1F0D, 0xA6A1), (0x1F0E, 0xA6A1), (0x1F0F, 0xA6A1),
+    (0x1F10, 0xA6C5), (0x1F11, 0xA6C5), (0x1F12, 0xA6C5), (0x1F13, 0xA6C5), (0x1F14, 0xA6C5),
+    (0x1F15, 0xA6C5), (0x1F18, 0xA6A5), (0x1F19, 0xA6A5), (0x1F1A, 0xA6A5), (0x1F1B, 0xA6A5),
+    (0x1F1C, 0xA6A5), (0x1F1D, 0xA6A5), (0x1F20, 0xA6C7), (0x1F21, 0xA6C7), (0x1F22, 0xA6C7),
+    (0x1F23, 0xA6C7), (0x1F24, 0xA6C7), (0x1F25, 0xA6C7), (0x1F26, 0xA6C7), (0x1F27, 0xA6C7),
+    (0x1F28, 0xA6A7), (0x1F29, 0xA6A7), (0x1F2A, 0xA6A7), (0x1F2B, 0xA6A7), (0x1F2C, 0xA6A7),
+    (0x1F2D, 0xA6A7), (0x1F2E, 0xA6A7), (0x1F2F, 0xA6A7), (0x1F30, 0xA6C9), (0x1F31, 0xA6C9),
+    (0x1F32, 0xA6C9), (0x1F33, 0xA6C9), (0x1F34, 0xA6C9), (0x1F35, 0xA6C9), (0x1F36, 0xA6C9),
+    (0x1F37, 0xA6C9), (0x1F38, 0xA6A9), (0x1F39, 0xA6A9), (0x1F3A, 0xA6A9), (0x1F3B, 0xA6A9),
+    (0x1F3C, 0xA6A9), (0x1F3D, 0xA6A9), (0x1F3E, 0xA6A9), (0x1F3F, 0xA6A9), (0x1F40, 0xA6CF),
+    (0x1F41, 0xA6CF), (0x1F42, 0xA6CF), (0x1F43, 0xA6CF), (0x1F44, 0xA6CF), (0x1F45, 0xA6CF),
+    (0x1F48, 0xA6AF), (0x1F49, 0xA6AF), (0x1F4A, 0xA6AF), (0x1F4B, 0xA6AF), (0x1F4C, 0xA6AF),
+    (0x1F4D, 0xA6AF), (0x1F50, 0xA6D4), (0x1F51, 0xA6D4), (0x1F52, 0xA6D4), (0x1F53, 0xA6D4),
+    (0x1F54, 0xA6D4), (0x1F55, 0xA6D4), (0x1F56, 0xA6D4), (0x1F57, 0xA6D4), (0x1F59, 0xA6B4),
+    (0x1F5B, 0xA6B4), (0x1F5D, 0xA6B4), (0x1F5F, 0xA6B4), (0x1F60, 0xA6D8), (0x1F61, 0xA6D8),
+    (0x1F62, 0xA6D8), (0x1F63, 0xA6D8), (0x1F64, 0xA6D8), (0x1F65, 0xA6D8), (0x1F66, 0xA6D8),
+    (0x1F67, 0xA6D8), (0x1F68, 0xA6B8), (0x1F69, 0xA6B8), (0x1F6A, 0xA6B8), (0x1F6B, 0xA6B8),
+    (0x1F6C, 0xA6B8), (0x1F6D, 0xA6B8), (0x1F6E, 0xA6B8), (0x1F6F, 0xA6B8), (0x1F70, 0xA6C1),
+    (0x1F71, 0xA6C1), (0x1F72, 0xA6C5), (0x1F73, 0xA6C5), (0x1F74, 0xA6C7), (0x1F75, 0xA6C7),
+    (0x1F76, 0xA6C9), (0x1F77, 0xA6C9), (0x1F78, 0xA6CF), (0x1F79, 0xA6CF), (0x1F7A, 0xA6D4),
+    (0x1F7B, 0xA6D4), (0x1F7C, 0xA6D8), (0x1F7D, 0xA6D8), (0x1F80, 0xA6C1), (0x1F81, 0xA6C1),
+    (0x1F82, 0xA6C1), (0x1F83, 0xA6C1), (0x1F84, 0xA6C1), (0x1F85, 0xA6C1), (0x1F86, 0xA6C1),
+    (0x1F87, 0xA6C1), (0x1F88, 0xA6A1), (0x1F89, 0xA6A1), (0x1F8A, 0xA6A1), (0x1F8B, 0xA6A1),
+    (0x1F8C, 0xA6A1), (0x1F8D, 0xA6A1), (0x1F8E, 0xA6A1), (0x1F8F, 0xA6A1), (0x1F90, 0xA6C7),
+    (0x1F91, 0xA6C7), (0x1F92, 0xA6C7), (0x1F93, 0xA6C7), (0x1F94, 0xA6C7), (0x1F95, 0xA6C7),
+    (0x1F96, 0xA6C7), (0x1F97, 0xA6C7), (0x1F98, 0xA6A7), (0x1F99, 0xA6A7), (0x1F9A, 0xA6A7),
+    (0x1F9B, 0xA6A7), (0x1F9C, 0xA6A7), (0x1F9D, 0xA6A7), (0x1F9E, 0xA6A7), (0x1F9F, 0xA6A7),
+    (0x1FA0, 0xA6D8), (0x1FA1, 0xA6D8), (0x1FA2, 0xA6D8), (0x1FA3, 0xA6D8), (0x1FA4, 0xA6D8),
+    (0x1FA5, 0xA6D8), (0x1FA6, 0xA6D8), (0x1FA7, 0xA6D8), (0x1FA8, 0xA6B8), (0x1FA9, 0xA6B8),
+    (0x1FAA, 0xA6B8), (0x1FAB, 0xA6B8), (0x1FAC, 0xA6B8), (0x1FAD, 0xA6B8), (0x1FAE, 0xA6B8),
+    (0x1FAF, 0xA6B8), (0x1FB0, 0xA6C1), (0x1FB1, 0xA6C1), (0x1FB2, 0xA6C1), (0x1FB3, 0xA6C1),
+    (0x1FB4, 0xA6C1), (0x1FB6, 0xA6C1), (0x1FB7, 0xA6C1), (0x1FB8, 0xA6A1), (0x1FB9, 0xA6A1),
+    (0x1FBA, 0xA6A1), (0x1FBB, 0xA6A1), (0x1FBC, 0xA6A1), (0x1FBE, 0xA6C9), (0x1FC2, 0xA6C7),
+    (0x1FC3, 0xA6C7), (0x1FC4, 0xA6C7), (0x1FC6, 0xA6C7), (0x1FC7, 0xA6C7), (0x1FC8, 0xA6A5),
+    (0x1FC9, 0xA6A5), (0x1FCA, 0xA6A7), (0x1FCB, 0xA6A7), (0x1FCC, 0xA6A7), (0x1FD0, 0xA6C9),
+    (0x1FD1, 0xA6C9), (0x1FD2, 0xA6C9), (0x1FD3, 0xA6C9), (0x1FD6, 0xA6C9), (0x1FD7, 0xA6C9),
+    (0x1FD8, 0xA6A9), (0x1FD9, 0xA6A9), (0x1FDA, 0xA6A9), (0x1FDB, 0xA6A9), (0x1FE0, 0xA6D4),
+    (0x1FE1, 0xA6D4), (0x1FE2, 0xA6D4), (0x1FE3, 0xA6D4), (0x1FE4, 0xA6D1), (0x1FE5, 0xA6D1),
+    (0x1FE6, 0xA6D4), (0x1FE7, 0xA6D4), (0x1FE8, 0xA6B4), (0x1FE9, 0xA6B4), (0x1FEA, 0xA6B4),
+    (0x1FEB, 0xA6B4), (0x1FEC, 0xA6B1), (0x1FEF, 0x0060), (0x1FF2, 0xA6D8), (0x1FF3, 0xA6D8),
+    (0x1FF4, 0xA6D8), (0x1FF6, 0xA6D8), (0x1FF7, 0xA6D8), (0x1FF8, 0xA6AF), (0x1FF9, 0xA6AF),
+    (0x1FFA, 0xA6B8), (0x1FFB, 0xA6B8), (0x1FFC, 0xA6B8), (0x2000, 0x0020), (0x2001, 0x0020),
+    (0x2002, 0x0020), (0x2003, 0x0020), (0x2004, 0x0020), (0x2005, 0x0020), (0x2006, 0x0020),
+    (0x2007, 0x0020), (0x2008, 0x0020), (0x2009, 0x0020), (0x200A, 0x0020), (0x2011, 0xA95C),
+    (0x2012, 0x002D), (0x201A, 0x002C), (0x201B, 0x0027), (0x201E, 0x0022), (0x201F, 0x0022),
+    (0x2024, 0x002E), (0x202F, 0x0020), (0x2039, 0x003C), (0x203A, 0x003E), (0x205F, 0x0020),
+    (0x2070, 0x0030), (0x2071, 0x0069), (0x2074, 0x0034), (0x2075, 0x0035), (0x2076, 0x0036),
+    (0x2077, 0x0037), (0x2078, 0x0038), (0x2079, 0x0039), (0x207A, 0x002B), (0x207C, 0x003D),
+    (0x207D, 0x0028), (0x207E, 0x0029), (0x207F, 0x006E), (0x2080, 0x0030), (0x2081, 0x0031),
+    (0x2082, 0x0032), (0x2083, 0x0033), (0x2084, 0x0034), (0x2085, 0x0035), (0x2086, 0x0036),
+    (0x2087, 0x0037), (0x2088, 0x0038), (0x2089, 0x0039), (0x208A, 0x002B), (0x208C, 0x003D),
+    (0x208D, 0x0028), (0x208E, 0x0029), (0x2090, 0x0061), (0x2091, 0x0065), (0x2092, 0x006F),
+    (0x2093, 0x0078), (0x2095, 0x0068), (0x2096, 0x006B), (0x2097, 0x006C), (0x2098, 0x006D),
+    (0x2099, 0x006E), (0x209A, 0x0070), (0x209B, 0x0073), (0x209C, 0x0074), (0x2102, 0x0043),
+    (0x210A, 0x0067), (0x210B, 0x0048), (0x210C, 0x0048), (0x210D, 0x0048), (0x210E, 0x0068),
+    (0x2110, 0x0049), (0x2111, 0x0049), (0x2112, 0x004C), (0x2113, 0x006C), (0x2115, 0x004E),
+    (0x2119, 0x0050), (0x211A, 0x0051), (0x211B, 0x0052), (0x211C, 0x0052), (0x211D, 0x0052),
+    (0x2124, 0x005A), (0x2126, 0xA6B8), (0x2128, 0x005A), (0x212A, 0x004B), (0x212B, 0x0041),
+    (0x212C, 0x0042), (0x212D, 0x0043), (0x212F, 0x0065), (0x2130, 0x0045), (0x2131, 0x0046),
+    (0x2133, 0x004D), (0x2134, 0x006F), (0x2139, 0x0069), (0x213C, 0xA6D0), (0x213D, 0xA6C3),
+    (0x213E, 0xA6A3), (0x213F, 0xA6B0), (0x2140, 0xA1C6), (0x2145, 0x0044), (0x2146, 0x0064),
+    (0x2147, 0x0065), (0x2148, 0x0069), (0x2149, 0x006A), (0x216C, 0x004C), (0x216D, 0x0043),
+    (0x216E, 0x0044), (0x216F, 0x004D), (0x217C, 0x006C), (0x217D, 0x0063), (0x217E, 0x0064),
+    (0x217F, 0x006D), (0x219A, 0xA1FB), (0x219B, 0xA1FA), (0x2209, 0xA1CA), (0x2212, 0x002D),
+    (0x2216, 0x005C), (0x2224, 0xA84F), (0x2226, 0xA1CE), (0x223C, 0x007E), (0x2249, 0xA1D6),
+    (0x2262, 0xA1D4), (0x2270, 0xA1DC), (0x2271, 0xA1DD), (0x2329, 0xA1B4), (0x232A, 0xA1B5),
+    (0x24B6, 0x0041), (0x24B7, 0x0042), (0x24B8, 0x0043), (0x24B9, 0x0044), (0x24BA, 0x0045),
+    (0x24BB, 0x0046), (0x24BC, 0x0047), (0x24BD, 0x0048), (0x24BE, 0x0049), (0x24BF, 0x004A),
+    (0x24C0, 0x004B), (0x24C1, 0x004C), (0x24C2, 0x004D), (0x24C3, 0x004E), (0x24C4, 0x004F),
+    (0x24C5, 0x0050), (0x24C6, 0x0051), (0x24C7, 0x0052), (0x24C8, 0x0053), (0x24C9, 0x0054),
+    (0x24CA, 0x0055), (0x24CB, 0x0056), (0x24CC, 0x0057), (0x24CD, 0x0058), (0x24CE, 0x0059),
+    (0x24CF, 0x005A), (0x24D0, 0x0061), (0x24D1, 0x0062), (0x24D2, 0x0063), (0x24D3, 0x0064),
+    (0x24D4, 0x0065), (0x24D5, 0x0066), (0x24D6, 0x0067), (0x24D7, 0x0068), (0x24D8, 0x0069),
+    (0x24D9, 0x006A), (0x24DA, 0x006B), (0x24DB, 0x006C), (0x24DC, 0x006D), (0x24DD, 0x006E),
+    (0x24DE, 0x006F), (0x24DF, 0x0070), (0x24E0, 0x0071), (0x24E1, 0x0072), (0x24E2, 0x0073),
+    (0x24E3, 0x0074), (0x24E4, 0x0075), (0x24E5, 0x0076), (0x24E6, 0x0077), (0x24E7, 0x0078),
+    (0x24E8, 0x0079), (0x24E9, 0x007A), (0x24EA, 0x0030), (0x2C7C, 0x006A), (0x2C7D, 0x0056),
+    (0x2E9F, 0xC4B8), (0x2EF3, 0xB9EA), (0x2F00, 0xD2BB), (0x2F01, 0xD8AD), (0x2F02, 0xD8BC),
+    (0x2F03, 0xD8AF), (0x2F04, 0xD2D2), (0x2F05, 0x817C), (0x2F06, 0xB6FE), (0x2F07, 0xD9EF),
+    (0x2F08, 0xC8CB), (0x2F09, 0xB6F9), (0x2F0A, 0xC8EB), (0x2F0B, 0xB0CB), (0x2F0C, 0xD8E7),
+    (0x2F0D, 0xDAA2), (0x2F0E, 0xD9FB), (0x2F0F, 0xBCB8), (0x2F10, 0xDBC9), (0x2F11, 0xB5B6),
+    (0x2F12, 0xC1A6), (0x2F13, 0xD9E8), (0x2F14, 0xD8B0), (0x2F15, 0xD8CE), (0x2F16, 0x855B),
+    (0x2F17, 0xCAAE), (0x2F18, 0xB2B7), (0x2F19, 0xDAE0), (0x2F1A, 0xB3A7), (0x2F1B, 0xDBCC),
+    (0x2F1C, 0xD3D6), (0x2F1D, 0xBFDA), (0x2F1E, 0xE0ED), (0x2F1F, 0xCDC1), (0x2F20, 0xCABF),
+    (0x2F21, 0xE2BA), (0x2F22, 0x89E5), (0x2F23, 0xCFA6), (0x2F24, 0xB4F3), (0x2F25, 0xC5AE),
+    (0x2F26, 0xD7D3), (0x2F27, 0xE5B2), (0x2F28, 0xB4E7), (0x2F29, 0xD0A1), (0x2F2A, 0xDECC),
+    (0x2F2B, 0xCAAC), (0x2F2C, 0xE5F8), (0x2F2D, 0xC9BD), (0x2F2E, 0xE7DD), (0x2F2F, 0xB9A4),
+    (0x2F30, 0xBCBA), (0x2F31, 0xBDED), (0x2F32, 0xB8C9), (0x2F33, 0xE7DB), (0x2F34, 0xB9E3),
+    (0x2F35, 0xDBC8), (0x2F36, 0xDEC3), (0x2F37, 0xDFAE), (0x2F38, 0xB9AD), (0x2F39, 0xE5E6),
+    (0x2F3A, 0xE1EA), (0x2F3B, 0xE1DC), (0x2F3C, 0xD0C4), (0x2F3D, 0xB8EA), (0x2F3E, 0x91F4),
+    (0x2F3F, 0xCAD6), (0x2F40, 0xD6A7), (0x2F41, 0xEAB7), (0x2F42, 0xCEC4), (0x2F43, 0xB6B7),
+    (0x2F44, 0xBDEF), (0x2F45, 0xB7BD), (0x2F46, 0xCEDE), (0x2F47, 0xC8D5), (0x2F48, 0xD4BB),
+    (0x2F49, 0xD4C2), (0x2F4A, 0xC4BE), (0x2F4B, 0xC7B7), (0x2F4C, 0xD6B9), (0x2F4D, 0xB4F5),
+    (0x2F4E, 0xECAF), (0x2F4F, 0xCEE3), (0x2F50, 0xB1C8), (0x2F51, 0xC3AB), (0x2F52, 0xCACF),
+    (0x2F53, 0xC6F8), (0x2F54, 0xCBAE), (0x2F55, 0xBBF0), (0x2F56, 0xD7A6), (0x2F57, 0xB8B8),
+    (0x2F58, 0xD8B3), (0x2F59, 0xE3DD), (0x2F5A, 0xC6AC), (0x2F5B, 0xD1C0), (0x2F5C, 0xC5A3),
+    (0x2F5D, 0xC8AE), (0x2F5E, 0xD0FE), (0x2F5F, 0xD3F1), (0x2F60, 0xB9CF), (0x2F61, 0xCDDF),
+    (0x2F62, 0xB8CA), (0x2F63, 0xC9FA), (0x2F64, 0xD3C3), (0x2F65, 0xCCEF), (0x2F66, 0xF1E2),
+    (0x2F67, 0xF0DA), (0x2F68, 0xB068), (0x2F69, 0xB0D7), (0x2F6A, 0xC6A4), (0x2F6B, 0xC3F3),
+    (0x2F6C, 0xC4BF), (0x2F6D, 0xC3AC), (0x2F6E, 0xCAB8), (0x2F6F, 0xCAAF), (0x2F70, 0xCABE),
+    (0x2F71, 0xB662), (0x2F72, 0xBACC), (0x2F73, 0xD1A8), (0x2F74, 0xC1A2), (0x2F75, 0xD6F1),
+    (0x2F76, 0xC3D7), (0x2F77, 0xF4E9), (0x2F78, 0xF3BE), (0x2F79, 0xCDF8), (0x2F7A, 0xD1F2),
+    (0x2F7B, 0xD3F0), (0x2F7C, 0xC0CF), (0x2F7D, 0xB6F8), (0x2F7E, 0xF1E7), (0x2F7F, 0xB6FA),
+    (0x2F80, 0xEDB2), (0x2F81, 0xC8E2), (0x2F82, 0xB3BC), (0x2F83, 0xD7D4), (0x2F84, 0xD6C1),
+    (0x2F85, 0xBECA), (0x2F86, 0xC9E0), (0x2F87, 0xE2B6), (0x2F88, 0xD6DB), (0x2F89, 0xF4DE),
+    (0x2F8A, 0xC9AB), (0x2F8B, 0xC648), (0x2F8C, 0xF2AE), (0x2F8D, 0xB3E6), (0x2F8E, 0xD1AA),
+    (0x2F8F, 0xD0D0), (0x2F90, 0xD2C2), (0x2F91, 0xD281), (0x2F92, 0xD28A), (0x2F93, 0xBDC7),
+    (0x2F94, 0xD1D4), (0x2F95, 0xB9C8), (0x2F96, 0xB6B9), (0x2F97, 0xF5B9), (0x2F98, 0xF5F4),
+    (0x2F99, 0xD890), (0x2F9A, 0xB3E0), (0x2F9B, 0xD7DF), (0x2F9C, 0xD7E3), (0x2F9D, 0xC9ED),
+    (0x2F9E, 0xDC87), (0x2F9F, 0xD0C1), (0x2FA0, 0xB3BD), (0x2FA1, 0xDE75), (0x2FA2, 0xD2D8),
+    (0x2FA3, 0xD3CF), (0x2FA4, 0xE18A), (0x2FA5, 0xC0EF), (0x2FA6, 0xBDF0), (0x2FA7, 0xE94C),
+    (0x2FA8, 0xE954), (0x2FA9, 0xB8B7), (0x2FAA, 0xC1A5), (0x2FAB, 0xF6BF), (0x2FAC, 0xD3EA),
+    (0x2FAD, 0xEC69), (0x2FAE, 0xB7C7), (0x2FAF, 0xC3E6), (0x2FB0, 0xB8EF), (0x2FB1, 0xED66),
+    (0x2FB2, 0xBEC2), (0x2FB3, 0xD2F4), (0x2FB4, 0xED93), (0x2FB5, 0xEF4C), (0x2FB6, 0xEF77),
+    (0x2FB7, 0xCAB3), (0x2FB8, 0xCAD7), (0x2FB9, 0xCFE3), (0x2FBA, 0xF152), (0x2FBB, 0xB9C7),
+    (0x2FBC, 0xB8DF), (0x2FBD, 0xF7D4), (0x2FBE, 0xF459), (0x2FBF, 0xDBCB), (0x2FC0, 0xD8AA),
+    (0x2FC1, 0xB9ED), (0x2FC2, 0xF47E), (0x2FC3, 0xF842), (0x2FC4, 0xFB75), (0x2FC5, 0xC2B9),
+    (0x2FC6, 0xFB9C), (0x2FC7, 0xC2E9), (0x2FC8, 0xFC53), (0x2FC9, 0xCAF2), (0x2FCA, 0xBADA),
+    (0x2FCB, 0xEDE9), (0x2FCC, 0xFC77), (0x2FCD, 0xB6A6), (0x2FCE, 0xB9C4), (0x2FCF, 0xCAF3),
+    (0x2FD0, 0xB1C7), (0x2FD1, 0xFD52), (0x2FD2, 0xFD58), (0x2FD3, 0xFD88), (0x2FD4, 0xFD94),
+    (0x2FD5, 0xD9DF), (0x3036, 0xA893), (0x3038, 0xCAAE), (0x3039, 0x8560), (0x303A, 0xD8A6),
+    (0x3094, 0xA4A6), (0x30F7, 0xA5EF), (0x30F8, 0xA5F0), (0x30F9, 0xA5F1), (0x30FA, 0xA5F2),
+    (0x3192, 0xD2BB), (0x3193, 0xB6FE), (0x3194, 0xC8FD), (0x3195, 0xCBC4), (0x3196, 0xC9CF),
+    (0x3197, 0xD6D0), (0x3198, 0xCFC2), (0x3199, 0xBCD7), (0x319A, 0xD2D2), (0x319B, 0xB1FB),
+    (0x319C, 0xB6A1), (0x319D, 0xCCEC), (0x319E, 0xB5D8), (0x319F, 0xC8CB), (0x3244, 0x8696),
+    (0x3245, 0xD3D7), (0x3246, 0xCEC4), (0x3247, 0xB97E), (0x3280, 0xD2BB), (0x3281, 0xB6FE),
+    (0x3282, 0xC8FD), (0x3283, 0xCBC4), (0x3284, 0xCEE5), (0x3285, 0xC1F9), (0x3286, 0xC6DF),
+    (0x3287, 0xB0CB), (0x3288, 0xBEC5), (0x3289, 0xCAAE), (0x328A, 0xD4C2), (0x328B, 0xBBF0),
+    (0x328C, 0xCBAE), (0x328D, 0xC4BE), (0x328E, 0xBDF0), (0x328F, 0xCDC1), (0x3290, 0xC8D5),
+    (0x3291, 0xD6EA), (0x3292, 0xD3D0), (0x3293, 0xC9E7), (0x3294, 0xC3FB), (0x3295, 0xCCD8),
+    (0x3296, 0xD894), (0x3297, 0xD7A3), (0x3298, 0x84BA), (0x3299, 0xC3D8), (0x329A, 0xC4D0),
+    (0x329B, 0xC5AE), (0x329C, 0xDF6D), (0x329D, 0x839E), (0x329E, 0xD3A1), (0x329F, 0xD7A2),
+    (0x32A0, 0xED97), (0x32A1, 0xD0DD), (0x32A2, 0xD0B4), (0x32A4, 0xC9CF), (0x32A5, 0xD6D0),
+    (0x32A6, 0xCFC2), (0x32A7, 0xD7F3), (0x32A8, 0xD3D2), (0x32A9, 0xD2BD), (0x32AA, 0xD7DA),
+    (0x32AB, 0xD1A7), (0x32AC, 0xB14F), (0x32AD, 0xC6F3), (0x32AE, 0xD959), (0x32AF, 0x8566),
+    (0x32B0, 0xD2B9), (0x32D0, 0xA5A2), (0x32D1, 0xA5A4), (0x32D2, 0xA5A6), (0x32D3, 0xA5A8),
+    (0x32D4, 0xA5AA), (0x32D5, 0xA5AB), (0x32D6, 0xA5AD), (0x32D7, 0xA5AF), (0x32D8, 0xA5B1),
+    (0x32D9, 0xA5B3), (0x32DA, 0xA5B5), (0x32DB, 0xA5B7), (0x32DC, 0xA5B9), (0x32DD, 0xA5BB),
+    (0x32DE, 0xA5BD), (0x32DF, 0xA5BF), (0x32E0, 0xA5C1), (0x32E1, 0xA5C4), (0x32E2, 0xA5C6),
+    (0x32E3, 0xA5C8), (0x32E4, 0xA5CA), (0x32E5, 0xA5CB), (0x32E6, 0xA5CC), (0x32E7, 0xA5CD),
+    (0x32E8, 0xA5CE), (0x32E9, 0xA5CF), (0x32EA, 0xA5D2), (0x32EB, 0xA5D5), (0x32EC, 0xA5D8),
+    (0x32ED, 0xA5DB), (0x32EE, 0xA5DE), (0x32EF, 0xA5DF), (0x32F0, 0xA5E0), (0x32F1, 0xA5E1),
+    (0x32F2, 0xA5E2), (0x32F3, 0xA5E4), (0x32F4, 0xA5E6), (0x32F5, 0xA5E8), (0x32F6, 0xA5E9),
+    (0x32F7, 0xA5EA), (0x32F8, 0xA5EB), (0x32F9, 0xA5EC), (0x32FA, 0xA5ED), (0x32FB, 0xA5EF),
+    (0x32FC, 0xA5F0), (0x32FD, 0xA5F1), (0x32FE, 0xA5F2), (0xA69C, 0xA7EC), (0xA69D, 0xA7EE),
+    (0xA7F2, 0x0043), (0xA7F3, 0x0046), (0xA7F4, 0x0051), (0xF900, 0xD84D), (0xF901, 0xB8FC),
+    (0xF902, 0xDC87), (0xF903, 0xD95A), (0xF904, 0xBBAC), (0xF905, 0xB4AE), (0xF906, 0xBEE4),
+    (0xF907, 0xFD94), (0xF908, 0xFD94), (0xF909, 0xC6F5), (0xF90A, 0xBDF0), (0xF90B, 0xC0AE),
+    (0xF90C, 0xC4CE), (0xF90D, 0x91D0), (0xF90E, 0xB05D), (0xF90F, 0xC15F), (0xF910, 0xCC7D),
+    (0xF911, 0xC2DD), (0xF912, 0xC2E3), (0xF913, 0xDF89), (0xF914, 0x98B7), (0xF915, 0xC2E5),
+    (0xF916, 0xC0D3), (0xF917, 0xE7F3), (0xF918, 0xC2E4), (0xF919, 0xC0D2), (0xF91A, 0xF198),
+    (0xF91B, 0x8179), (0xF91C, 0xC2D1), (0xF91D, 0x99DA), (0xF91E, 0xA080), (0xF91F, 0xCC6D),
+    (0xF920, 0xFB5B), (0xF921, 0x8DB9), (0xF922, 0x9E45), (0xF923, 0xCB7B), (0xF924, 0xD268),
+    (0xF925, 0xC0AD), (0xF926, 0xC544), (0xF927, 0xCF9E), (0xF928, 0xC0C8), (0xF929, 0xC0CA),
+    (0xF92A, 0xC0CB), (0xF92B, 0xC0C7), (0xF92D, 0x81ED), (0xF92E, 0xC0E4), (0xF92F, 0x84DA),
+    (0xF930, 0x93EF), (0xF931, 0x99A9), (0xF932, 0xA074), (0xF933, 0xB152), (0xF934, 0xC0CF),
+    (0xF935, 0xCC4A), (0xF936, 0xCC94), (0xF937, 0xC2B7), (0xF938, 0xC2B6), (0xF939, 0xF494),
+    (0xF93A, 0xFA98), (0xF93B, 0xC2B5), (0xF93C, 0xB593), (0xF93D, 0xBE47), (0xF93E, 0xC78A),
+    (0xF93F, 0xE49B), (0xF940, 0xC2B9), (0xF941, 0xD593), (0xF942, 0x89C5), (0xF943, 0xC5AA),
+    (0xF944, 0xBB5C), (0xF945, 0xC340), (0xF946, 0xC0CE), (0xF947, 0xC0DA), (0xF948, 0xD954),
+    (0xF949, 0xC0D7), (0xF94A, 0x89BE), (0xF94B, 0x8CD2), (0xF94C, 0x98C7), (0xF94D, 0x9C49),
+    (0xF94E, 0xC2A9), (0xF94F, 0xC0DB), (0xF950, 0xBF7C), (0xF951, 0xC2AA), (0xF952, 0xC0D5),
+    (0xF953, 0xC0DF), (0xF954, 0x8443), (0xF955, 0xC1E8), (0xF956, 0xB6A0), (0xF957, 0xBE63),
+    (0xF958, 0xC1E2), (0xF959, 0xC1EA), (0xF95A, 0xD778), (0xF95B, 0x9282), (0xF95C, 0x98B7),
+    (0xF95D, 0xD65A), (0xF95E, 0xB5A4), (0xF95F, 0x8C8E), (0xF960, 0xC5AD), (0xF961, 0xC2CA),
+    (0xF962, 0xAE90), (0xF963, 0xB1B1), (0xF964, 0xB491), (0xF965, 0xB1E3), (0xF966, 0x8FCD),
+    (0xF967, 0xB2BB), (0xF968, 0xC3DA), (0xF969, 0x94B5), (0xF96A, 0xCBF7), (0xF96B, 0x85A2),
+    (0xF96C, 0xC8FB), (0xF96D, 0xCAA1), (0xF96E, 0xC87E), (0xF96F, 0xD566), (0xF970, 0x9AA2),
+    (0xF971, 0xB3BD), (0xF972, 0xC9F2), (0xF973, 0xCAB0), (0xF974, 0xC8F4), (0xF975, 0xC2D3),
+    (0xF976, 0xC2D4), (0xF977, 0xC1C1), (0xF978, 0x83C9), (0xF97A, 0xC1BA), (0xF97B, 0xBC5A),
+    (0xF97C, 0xC1BC), (0xF97D, 0xD58F), (0xF97E, 0xC1BF), (0xF97F, 0x84EE), (0xF980, 0x85CE),
+    (0xF981, 0xC5AE), (0xF982, 0x8F5D), (0xF983, 0xC2C3), (0xF984, 0x9E56), (0xF985, 0xB55A),
+    (0xF986, 0xE982), (0xF987, 0xF350), (0xF988, 0xFB90), (0xF989, 0xC0E8), (0xF98A, 0xC1A6),
+    (0xF98B, 0x95D1), (0xF98C, 0x9A76), (0xF98D, 0xDE5D), (0xF98E, 0xC4EA), (0xF98F, 0x917A),
+    (0xF990, 0x91D9), (0xF991, 0x93D3), (0xF992, 0x9D69), (0xF993, 0x9F92), (0xF994, 0xAD49),
+    (0xF996, 0xBE9A), (0xF997, 0xC293), (0xF998, 0xDD82), (0xF999, 0xC98F), (0xF99A, 0xDF42),
+    (0xF99B, 0xE580), (0xF99C, 0xC1D0), (0xF99D, 0xC1D3), (0xF99E, 0xD1CA), (0xF99F, 0xC1D2),
+    (0xF9A0, 0xC1D1), (0xF9A1, 0xD566), (0xF9A2, 0xC1AE), (0xF9A3, 0xC4EE), (0xF9A4, 0xC4ED),
+    (0xF9A5, 0x9A9A), (0xF9A6, 0xBA9F), (0xF9A7, 0xAB43), (0xF9A8, 0xC1EE), (0xF9A9, 0xE0F2),
+    (0xF9AA, 0x8C8E), (0xF9AB, 0x8E58), (0xF9AC, 0xC1AF), (0xF9AD, 0xC1E1), (0xF9AE, 0xAC93),
+    (0xF9AF, 0xC1E7), (0xF9B0, 0xF1F6), (0xF9B1, 0xE28F), (0xF9B2, 0xC1E3), (0xF9B3, 0xEC60),
+    (0xF9B4, 0xEE49), (0xF9B5, 0xC0FD), (0xF9B6, 0xB659), (0xF9B7, 0xF5B7), (0xF9B8, 0xEB60),
+    (0xF9B9, 0x90BA), (0xF9BA, 0xC1CB), (0xF9BB, 0xC1C5), (0xF9BC, 0xE5BC), (0xF9BD, 0xC4F2),
+    (0xF9BE, 0xC1CF), (0xF9BF, 0x98B7), (0xF9C0, 0xC1C7), (0xF9C1, 0xAF9F), (0xF9C2, 0xDEA4),
+    (0xF9C3, 0xDF7C), (0xF9C4, 0xFD88), (0xF9C5, 0x959E), (0xF9C6, 0xC8EE), (0xF9C7, 0x84A2),
+    (0xF9C8, 0x9683), (0xF9C9, 0xC1F8), (0xF9CA, 0xC1F7), (0xF9CB, 0xC1EF), (0xF9CC, 0xC1F0),
+    (0xF9CD, 0xC1F4), (0xF9CE, 0xC1F2), (0xF9CF, 0xBC7E), (0xF9D0, 0xEE90), (0xF9D1, 0xC1F9),
+    (0xF9D2, 0xC2BE), (0xF9D3, 0xEA91), (0xF9D4, 0x8290), (0xF9D5, 0x8D91), (0xF9D6, 0x9C53),
+    (0xF9D7, 0xDD86), (0xF9D8, 0xC2C9), (0xF9D9, 0x90FC), (0xF9DA, 0xC0F5), (0xF9DB, 0xC2CA),
+    (0xF9DC, 0xC2A1), (0xF9DD, 0xC0FB), (0xF9DE, 0xC0F4), (0xF9DF, 0xC2C4), (0xF9E0, 0xD2D7),
+    (0xF9E1, 0xC0EE), (0xF9E2, 0xC0E6), (0xF9E3, 0xC4E0), (0xF9E4, 0xC0ED), (0xF9E5, 0xC1A1),
+    (0xF9E6, 0xEEBE), (0xF9E8, 0xD165), (0xF9E9, 0xC0EF), (0xF9EA, 0xEB78), (0xF9EB, 0xC4E4),
+    (0xF9EC, 0xC4E7), (0xF9ED, 0xC1DF), (0xF9EE, 0x9FFB), (0xF9EF, 0xAD55), (0xF9F0, 0xCC41),
+    (0xF9F2, 0xF75B), (0xF9F3, 0xF7EB), (0xF9F4, 0xC1D6), (0xF9F5, 0xC1DC), (0xF9F6, 0xC552),
+    (0xF9F7, 0xC1A2), (0xF9F8, 0xF3D2), (0xF9F9, 0xC1A3), (0xF9FA, 0xA0EE), (0xF9FB, 0xD6CB),
+    (0xF9FC, 0xD752), (0xF9FD, 0xCAB2), (0xF9FE, 0xB2E8), (0xF9FF, 0xB4CC), (0xFA00, 0xC7D0),
+    (0xFA01, 0xB6C8), (0xFA02, 0xCDD8), (0xFA03, 0xCCC7), (0xFA04, 0xD5AC), (0xFA05, 0xB6B4),
+    (0xFA06, 0xB1A9), (0xFA07, 0xDD97), (0xFA08, 0xD0D0), (0xFA09, 0xBDB5), (0xFA0A, 0xD28A),
+    (0xFA0B, 0xC0AA), (0xFA10, 0x8956), (0xFA12, 0xC7E7), (0xFA15, 0x8444), (0xFA16, 0xD6ED),
+    (0xFA17, 0xD2E6), (0xFA19, 0xC9F1), (0xFA1A, 0xCFE9), (0xFA1B, 0xB8A3), (0xFA1C, 0xBEB8),
+    (0xFA1D, 0xBEAB), (0xFA1E, 0xD3F0), (0xFA22, 0xD654), (0xFA25, 0xD2DD), (0xFA26, 0xB6BC),
+    (0xFA2A, 0xEF88), (0xFA2B, 0xEF95), (0xFA2C, 0xF05E), (0xFA2D, 0xFA51), (0xFA2E, 0xE04F),
+    (0xFA2F, 0xEB5F), (0xFA30, 0xCEEA), (0xFA31, 0xC9AE), (0xFA32, 0xC3E2), (0xFA33, 0xC3E3),
+    (0xFA34, 0xC7DA), (0xFA35, 0xB1B0), (0xFA36, 0xBAC8), (0xFA37, 0x8740), (0xFA38, 0xC6F7),
+    (0xFA39, 0x8942), (0xFA3A, 0xC4AB), (0xFA3B, 0x8CD3), (0xFA3C, 0xE5F8), (0xFA3D, 0xBBDA),
+    (0xFA3E, 0xBFAE), (0xFA3F, 0xD4F7), (0xFA40, 0x91CD), (0xFA41, 0xC3F4), (0xFA42, 0xBCC8),
+    (0xFA43, 0xCAEE), (0xFA44, 0xC3B7), (0xFA45, 0xBAA3), (0xFA46, 0xE4BE), (0xFA47, 0x9D68),
+    (0xFA48, 0xD6F3), (0xFA49, 0xA08D), (0xFA4A, 0xD7C1), (0xFA4B, 0xB1AE), (0xFA4C, 0xC9E7),
+    (0xFA4D, 0xECED), (0xFA4E, 0xC6ED), (0xFA4F, 0xB576), (0xFA50, 0xD7E6), (0xFA51, 0xD7A3),
+    (0xFA52, 0xB59C), (0xFA53, 0xB59D), (0xFA54, 0xB759), (0xFA55, 0xCDBB), (0xFA56, 0xB99D),
+    (0xFA57, 0xBE9A), (0xFA58, 0xBF4E), (0xFA59, 0xB7B1), (0xFA5A, 0xCAF0), (0xFA5B, 0xD5DF),
+    (0xFA5C, 0xB3F4), (0xFA5D, 0xDCB3), (0xFA5E, 0xDCB3), (0xFA5F, 0xD6F8), (0xFA60, 0xBAD6),
+    (0xFA61, 0xD295), (0xFA62, 0xD65D), (0xFA63, 0xD694), (0xFA64, 0xD965), (0xFA65, 0xD99B),
+    (0xFA66, 0xE5C1), (0xFA67, 0xD2DD), (0xFA68, 0xEB79), (0xFA69, 0xED91), (0xFA6A, 0xEE6C),
+    (0xFA6B, 0x907B), (0xFA6D, 0xC56F), (0xFA70, 0x814B), (0xFA71, 0xBFF6), (0xFA72, 0xC8AB),
+    (0xFA73, 0x81E9), (0xFA74, 0xB3E4), (0xFA75, 0xBCBD), (0xFA76, 0xD3C2), (0xFA77, 0xC9D7),
+    (0xFA78, 0xBAC8), (0xFA79, 0xDFFB), (0xFA7A, 0xE0B9), (0xFA7B, 0x86EC), (0xFA7C, 0x8956),
+    (0xFA7D, 0x899E), (0xFA7E, 0xD1D9), (0xFA7F, 0xB1BC), (0xFA80, 0xE6BE), (0xFA81, 0x8BE3),
+    (0xFA82, 0xE2DA), (0xFA83, 0x8F4D), (0xFA84, 0xB2CA), (0xFA85, 0xE1E6), (0xFA86, 0xE3AF),
+    (0xFA87, 0xC9F7), (0xFA88, 0xD3FA), (0xFA89, 0xD4F7), (0xFA8A, 0x9152), (0xFA8B, 0x91CD),
+    (0xFA8C, 0xB4F7), (0xFA8D, 0xDEED), (0xFA8E, 0xCBD1), (0xFA8F, 0xDEF0), (0xFA90, 0xB0BD),
+    (0xFA91, 0xC7E7), (0xFA92, 0xC0CA), (0xFA93, 0xCDFB), (0xFA94, 0xD5C8), (0xFA95, 0xB4F5),
+    (0xFA96, 0x9AA2), (0xFA97, 0xC1F7), (0xFA98, 0x9CF4), (0xFA99, 0xD7CC), (0xFA9A, 0x9D68),
+    (0xFA9B, 0x9E73), (0xFA9C, 0xD6F3), (0xFA9D, 0xC7C6), (0xFA9E, 0xBEF4), (0xFA9F, 0xB7B8),
+    (0xFAA0, 0xD6ED), (0xFAA1, 0xAC99), (0xFAA2, 0xAE4E), (0xFAA3, 0xBBAD), (0xFAA4, 0xAF8D),
+    (0xFAA5, 0xCEC1), (0xFAA6, 0xD2E6), (0xFAA7, 0xCAA2), (0xFAA8, 0xD6B1), (0xFAA9, 0xB192),
+    (0xFAAA, 0xD7C5), (0xFAAB, 0xB46B), (0xFAAC, 0xB849), (0xFAAD, 0xB99D), (0xFAAE, 0xC0E0),
+    (0xFAAF, 0xBD64), (0xFAB0, 0xBE9A), (0xFAB1, 0xC090), (0xFAB2, 0xD5DF), (0xFAB3, 0xBBC4),
+    (0xFAB4, 0xC841), (0xFAB5, 0xCE82), (0xFAB6, 0xF1DF), (0xFAB7, 0xB8B2), (0xFAB8, 0xD295),
+    (0xFAB9, 0xD57B), (0xFABA, 0xD654), (0xFABB, 0xD588), (0xFABC, 0xD65D), (0xFABD, 0xD65A),
+    (0xFABE, 0xD649), (0xFABF, 0xD694), (0xFAC0, 0xD783), (0xFAC1, 0xD99B), (0xFAC2, 0xDD94),
+    (0xFAC3, 0xDF74), (0xFAC4, 0xE167), (0xFAC5, 0xE36F), (0xFAC6, 0xEA95), (0xFAC7, 0xEB79),
+    (0xFAC8, 0xBEB8), (0xFAC9, 0xED76), (0xFACA, 0xED91), (0xFACB, 0xED9D), (0xFACC, 0xEE6C),
+    (0xFACD, 0xF449), (0xFACE, 0xFD94), (0xFAD8, 0xFD4C), (0xFAD9, 0xFD89), (0xFB29, 0x002B),
+    (0xFE10, 0x002C), (0xFE11, 0xA1A2), (0xFE12, 0xA1A3), (0xFE13, 0x003A), (0xFE14, 0x003B),
+    (0xFE15, 0x0021), (0xFE16, 0x003F), (0xFE17, 0xA1BC), (0xFE18, 0xA1BD), (0xFE32, 0xA843),
+    (0xFE47, 0x005B), (0xFE48, 0x005D), (0xFE58, 0xA1AA), (0xFF61, 0xA1A3), (0xFF62, 0xA1B8),
+    (0xFF63, 0xA1B9), (0xFF64, 0xA1A2), (0xFF66, 0xA5F2), (0xFF67, 0xA5A1), (0xFF68, 0xA5A3),
+    (0xFF69, 0xA5A5), (0xFF6A, 0xA5A7), (0xFF6B, 0xA5A9), (0xFF6C, 0xA5E3), (0xFF6D, 0xA5E5),
+    (0xFF6E, 0xA5E7), (0xFF6F, 0xA5C3), (0xFF70, 0xA960), (0xFF71, 0xA5A2), (0xFF72, 0xA5A4),
+    (0xFF73, 0xA5A6), (0xFF74, 0xA5A8), (0xFF75, 0xA5AA), (0xFF76, 0xA5AB), (0xFF77, 0xA5AD),
+    (0xFF78, 0xA5AF), (0xFF79, 0xA5B1), (0xFF7A, 0xA5B3), (0xFF7B, 0xA5B5), (0xFF7C, 0xA5B7),
+    (0xFF7D, 0xA5B9), (0xFF7E, 0xA5BB), (0xFF7F, 0xA5BD), (0xFF80, 0xA5BF), (0xFF81, 0xA5C1),
+    (0xFF82, 0xA5C4), (0xFF83, 0xA5C6), (0xFF84, 0xA5C8), (0xFF85, 0xA5CA), (0xFF86, 0xA5CB),
+    (0xFF87, 0xA5CC), (0xFF88, 0xA5CD), (0xFF89, 0xA5CE), (0xFF8A, 0xA5CF), (0xFF8B, 0xA5D2),
+    (0xFF8C, 0xA5D5), (0xFF8D, 0xA5D8), (0xFF8E, 0xA5DB), (0xFF8F, 0xA5DE), (0xFF90, 0xA5DF),
+    (0xFF91, 0xA5E0), (0xFF92, 0xA5E1), (0xFF93, 0xA5E2), (0xFF94, 0xA5E4), (0xFF95, 0xA5E6),
+    (0xFF96, 0xA5E8), (0xFF97, 0xA5E9), (0xFF98, 0xA5EA), (0xFF99, 0xA5EB), (0xFF9A, 0xA5EC),
+    (0xFF9B, 0xA5ED), (0xFF9C, 0xA5EF), (0xFF9D, 0xA5F3), (0xFFE8, 0xA9A6), (0xFFE9, 0xA1FB),
+    (0xFFEA, 0xA1FC), (0xFFEB, 0xA1FA), (0xFFEC, 0xA1FD), (0xFFED, 0xA1F6), (0xFFEE, 0xA1F0),
+];
+
+/// Code page 949.
+#[rustfmt::skip]
+pub(crate) const CP949: [(u16, u16); 1547] = [
+    (0x00A0, 0x0020), (0x00AB, 0x003C), (0x00B5, 0xA5EC), (0x00BB, 0x003E), (0x00C0, 0x0041),
+    (0x00C1, 0x0041), (0x00C2, 0x0041), (0x00C3, 0x0041), (0x00C4, 0x0041), (0x00C5, 0x0041),
+    (0x00C7, 0x0043), (0x00C8, 0x0045), (0x00C9, 0x0045), (0x00CA, 0x0045), (0x00CB, 0x0045),
+    (0x00CC, 0x0049), (0x00CD, 0x0049), (0x00CE, 0x0049), (0x00CF, 0x0049), (0x00D1, 0x004E),
+    (0x00D2, 0x004F), (0x00D3, 0x004F), (0x00D4, 0x004F), (0x00D5, 0x004F), (0x00D6, 0x004F),
+    (0x00D9, 0x0055), (0x00DA, 0x0055), (0x00DB, 0x0055), (0x00DC, 0x0055), (0x00DD, 0x0059),
+    (0x00E0, 0x0061), (0x00E1, 0x0061), (0x00E2, 0x0061), (0x00E3, 0x0061), (0x00E4, 0x0061),
+    (0x00E5, 0x0061), (0x00E7, 0x0063), (0x00E8, 0x0065), (0x00E9, 0x0065), (0x00EA, 0x0065),
+    (0x00EB, 0x0065), (0x00EC, 0x0069), (0x00ED, 0x0069), (0x00EE, 0x0069), (0x00EF, 0x0069),
+    (0x00F1, 0x006E), (0x00F2, 0x006F), (0x00F3, 0x006F), (0x00F4, 0x006F), (0x00F5, 0x006F),
+    (0x00F6, 0x006F), (0x00F9, 0x0075), (0x00FA, 0x0075), (0x00FB, 0x0075), (0x00FC, 0x0075),
+    (0x00FD, 0x0079), (0x00FF, 0x0079), (0x0100, 0x0041), (0x0101, 0x0061), (0x0102, 0x0041),
+    (0x0103, 0x0061), (0x0104, 0x0041), (0x0105, 0x0061), (0x0106, 0x0043), (0x0107, 0x0063),
+    (0x0108, 0x0043), (0x0109, 0x0063), (0x010A, 0x0043), (0x010B, 0x0063), (0x010C, 0x0043),
+    (0x010D, 0x0063), (0x010E, 0x0044), (0x010F, 0x0064), (0x0112, 0x0045), (0x0113, 0x0065),
+    (0x0114, 0x0045), (0x0115, 0x0065), (0x0116, 0x0045), (0x0117, 0x0065), (0x0118, 0x0045),
+    (0x0119, 0x0065), (0x011A, 0x0045), (0x011B, 0x0065), (0x011C, 0x0047), (0x011D, 0x0067),
+    (0x011E, 0x0047), (0x011F, 0x0067), (0x0120, 0x0047), (0x0121, 0x0067), (0x0122, 0x0047),
+    (0x0123, 0x0067), (0x0124, 0x0048), (0x0125, 0x0068), (0x0128, 0x0049), (0x0129, 0x0069),
+    (0x012A, 0x0049), (0x012B, 0x0069), (0x012C, 0x0049), (0x012D, 0x0069), (0x012E, 0x0049),
+    (0x012F, 0x0069), (0x0130, 0x0049), (0x0134, 0x004A), (0x0135, 0x006A), (0x0136, 0x004B),
+    (0x0137, 0x006B), (0x0139, 0x004C), (0x013A, 0x006C), (0x013B, 0x004C), (0x013C, 0x006C),
+    (0x013D, 0x004C), (0x013E, 0x006C), (0x0143, 0x004E), (0x0144, 0x006E), (0x0145, 0x004E),
+    (0x0146, 0x006E), (0x0147, 0x004E), (0x0148, 0x006E), (0x014C, 0x004F), (0x014D, 0x006F),
+    (0x014E, 0x004F), (0x014F, 0x006F), (0x0150, 0x004F), (0x0151, 0x006F), (0x0154, 0x0052),
+    (0x0155, 0x0072), (0x0156, 0x0052), (0x0157, 0x0072), (0x0158, 0x0052), (0x0159, 0x0072),
+    (0x015A, 0x0053), (0x015B, 0x0073), (0x015C, 0x0053), (0x015D, 0x0073), (0x015E, 0x0053),
+    (0x015F, 0x0073), (0x0160, 0x0053), (0x0161, 0x0073), (0x0162, 0x0054), (0x0163, 0x0074),
+    (0x0164, 0x0054), (0x0165, 0x0074), (0x0168, 0x0055), (0x0169, 0x0075), (0x016A, 0x0055),
+    (0x016B, 0x0075), (0x016C, 0x0055), (0x016D, 0x0075), (0x016E, 0x0055), (0x016F, 0x0075),
+    (0x0170, 0x0055), (0x0171, 0x0075), (0x0172, 0x0055), (0x0173, 0x0075), (0x0174, 0x0057),
+    (0x0175, 0x0077), (0x0176, 0x0059), (0x0177, 0x0079), (0x0178, 0x0059), (0x0179, 0x005A),
+    (0x017A, 0x007A), (0x017B, 0x005A), (0x017C, 0x007A), (0x017D, 0x005A), (0x017E, 0x007A),
+    (0x017F, 0x0073), (0x01A0, 0x004F), (0x01A1, 0x006F), (0x01AF, 0x0055), (0x01B0, 0x0075),
+    (0x01CD, 0x0041), (0x01CE, 0x0061), (0x01CF, 0x0049), (0x01D0, 0x0069), (0x01D1, 0x004F),
+    (0x01D2, 0x006F), (0x01D3, 0x0055), (0x01D4, 0x0075), (0x01D5, 0x0055), (0x01D6, 0x0075),
+    (0x01D7, 0x0055), (0x01D8, 0x0075), (0x01D9, 0x0055), (0x01DA, 0x0075), (0x01DB, 0x0055),
+    (0x01DC, 0x0075), (0x01DE, 0x0041), (0x01DF, 0x0061), (0x01E0, 0x0041), (0x01E1, 0x0061),
+    (0x01E2, 0xA8A1), (0x01E3, 0xA9A1), (0x01E6, 0x0047), (0x01E7, 0x0067), (0x01E8, 0x004B),
+    (0x01E9, 0x006B), (0x01EA, 0x004F), (0x01EB, 0x006F), (0x01EC, 0x004F), (0x01ED, 0x006F),
+    (0x01F0, 0x006A), (0x01F4, 0x0047), (0x01F5, 0x0067), (0x01F8, 0x004E), (0x01F9, 0x006E),
+    (0x01FA, 0x0041), (0x01FB, 0x0061), (0x01FC, 0xA8A1), (0x01FD, 0xA9A1), (0x01FE, 0xA8AA),
+    (0x01FF, 0xA9AA), (0x0200, 0x0041), (0x0201, 0x0061), (0x0202, 0x0041), (0x0203, 0x0061),
+    (0x0204, 0x0045), (0x0205, 0x0065), (0x0206, 0x0045), (0x0207, 0x0065), (0x0208, 0x0049),
+    (0x0209, 0x0069), (0x020A, 0x0049), (0x020B, 0x0069), (0x020C, 0x004F), (0x020D, 0x006F),
+    (0x020E, 0x004F), (0x020F, 0x006F), (0x0210, 0x0052), (0x0211, 0x0072), (0x0212, 0x0052),
+    (0x0213, 0x0072), (0x0214, 0x0055), (0x0215, 0x0075), (0x0216, 0x0055), (0x0217, 0x0075),
+    (0x0218, 0x0053), (0x0219, 0x0073), (0x021A, 0x0054), (0x021B, 0x0074), (0x021E, 0x0048),
+    (0x021F, 0x0068), (0x0226, 0x0041), (0x0227, 0x0061), (0x0228, 0x0045), (0x0229, 0x0065),
+    (0x022A, 0x004F), (0x022B, 0x006F), (0x022C, 0x004F), (0x022D, 0x006F), (0x022E, 0x004F),
+    (0x022F, 0x006F), (0x0230, 0x004F), (0x0231, 0x006F), (0x0232, 0x0059), (0x0233, 0x0079),
+    (0x02B0, 0x0068), (0x02B2, 0x006A), (0x02B3, 0x0072), (0x02B7, 0x0077), (0x02B8, 0x0079),
+    (0x02E1, 0x006C), (0x02E2, 0x0073), (0x02E3, 0x0078), (0x037E, 0x003B), (0x0386, 0xA5C1),
+    (0x0387, 0xA1A4), (0x0388, 0xA5C5), (0x0389, 0xA5C7), (0x038A, 0xA5C9), (0x038C, 0xA5CF),
+    (0x038E, 0xA5D4), (0x038F, 0xA5D8), (0x0390, 0xA5E9), (0x03AA, 0xA5C9), (0x03AB, 0xA5D4),
+    (0x03AC, 0xA5E1), (0x03AD, 0xA5E5), (0x03AE, 0xA5E7), (0x03AF, 0xA5E9), (0x03B0, 0xA5F4),
+    (0x03CA, 0xA5E9), (0x03CB, 0xA5F4), (0x03CC, 0xA5EF), (0x03CD, 0xA5F4), (0x03CE, 0xA5F8),
+    (0x03D0, 0xA5E2), (0x03D1, 0xA5E8), (0x03D2, 0xA5D4), (0x03D3, 0xA5D4), (0x03D4, 0xA5D4),
+    (0x03D5, 0xA5F5), (0x03D6, 0xA5F0), (0x03F0, 0xA5EA), (0x03F1, 0xA5F1), (0x03F4, 0xA5C8),
+    (0x03F5, 0xA5E5), (0x03F9, 0xA5D2), (0x0400, 0xACA6), (0x0403, 0xACA4), (0x040C, 0xACAC),
+    (0x040D, 0xACAA), (0x040E, 0xACB5), (0x0450, 0xACD6), (0x0453, 0xACD4), (0x045C, 0xACDC),
+    (0x045D, 0xACDA), (0x045E, 0xACE5), (0x04C1, 0xACA8), (0x04C2, 0xACD8), (0x04D0, 0xACA1),
+    (0x04D1, 0xACD1), (0x04D2, 0xACA1), (0x04D3, 0xACD1), (0x04D6, 0xACA6), (0x04D7, 0xACD6),
+    (0x04DC, 0xACA8), (0x04DD, 0xACD8), (0x04DE, 0xACA9), (0x04DF, 0xACD9), (0x04E2, 0xACAA),
+    (0x04E3, 0xACDA), (0x04E4, 0xACAA), (0x04E5, 0xACDA), (0x04E6, 0xACB0), (0x04E7, 0xACE0),
+    (0x04EC, 0xACBF), (0x04ED, 0xACEF), (0x04EE, 0xACB5), (0x04EF, 0xACE5), (0x04F0, 0xACB5),
+    (0x04F1, 0xACE5), (0x04F2, 0xACB5), (0x04F3, 0xACE5), (0x04F4, 0xACB9), (0x04F5, 0xACE9),
+    (0x04F8, 0xACBD), (0x04F9, 0xACED), (0x1D2C, 0x0041), (0x1D2D, 0xA8A1), (0x1D2E, 0x0042),
+    (0x1D30, 0x0044), (0x1D31, 0x0045), (0x1D33, 0x0047), (0x1D34, 0x0048), (0x1D35, 0x0049),
+    (0x1D36, 0x004A), (0x1D37, 0x004B), (0x1D38, 0x004C), (0x1D39, 0x004D), (0x1D3A, 0x004E),
+    (0x1D3C, 0x004F), (0x1D3E, 0x0050), (0x1D3F, 0x0052), (0x1D40, 0x0054), (0x1D41, 0x0055),
+    (0x1D42, 0x0057), (0x1D43, 0x0061), (0x1D47, 0x0062), (0x1D48, 0x0064), (0x1D49, 0x0065),
+    (0x1D4D, 0x0067), (0x1D4F, 0x006B), (0x1D50, 0x006D), (0x1D51, 0xA9AF), (0x1D52, 0x006F),
+    (0x1D56, 0x0070), (0x1D57, 0x0074), (0x1D58, 0x0075), (0x1D5B, 0x0076), (0x1D5D, 0xA5E2),
+    (0x1D5E, 0xA5E3), (0x1D5F, 0xA5E4), (0x1D60, 0xA5F5), (0x1D61, 0xA5F6), (0x1D62, 0x0069),
+    (0x1D63, 0x0072), (0x1D64, 0x0075), (0x1D65, 0x0076), (0x1D66, 0xA5E2), (0x1D67, 0xA5E3),
+    (0x1D68, 0xA5F1), (0x1D69, 0xA5F5), (0x1D6A, 0xA5F6), (0x1D78, 0xACDF), (0x1D9C, 0x0063),
+    (0x1D9E, 0xA9A3), (0x1DA0, 0x0066), (0x1DBB, 0x007A), (0x1DBF, 0xA5E8), (0x1E00, 0x0041),
+    (0x1E01, 0x0061), (0x1E02, 0x0042), (0x1E03, 0x0062), (0x1E04, 0x0042), (0x1E05, 0x0062),
+    (0x1E06, 0x0042), (0x1E07, 0x0062), (0x1E08, 0x0043), (0x1E09, 0x0063), (0x1E0A, 0x0044),
+    (0x1E0B, 0x0064), (0x1E0C, 0x0044), (0x1E0D, 0x0064), (0x1E0E, 0x0044), (0x1E0F, 0x0064),
+    (0x1E10, 0x0044), (0x1E11, 0x0064), (0x1E12, 0x0044), (0x1E13, 0x0064), (0x1E14, 0x0045),
+    (0x1E15, 0x0065), (0x1E16, 0x0045), (0x1E17, 0x0065), (0x1E18, 0x0045), (0x1E19, 0x0065),
+    (0x1E1A, 0x0045), (0x1E1B, 0x0065), (0x1E1C, 0x0045), (0x1E1D, 0x0065), (0x1E1E, 0x0046),
+    (0x1E1F, 0x0066), (0x1E20, 0x0047), (0x1E21, 0x0067), (0x1E22, 0x0048), (0x1E23, 0x0068),
+    (0x1E24, 0x0048), (0x1E25, 0x0068), (0x1E26, 0x0048), (0x1E27, 0x0068), (0x1E28, 0x0048),
+    (0x1E29, 0x0068), (0x1E2A, 0x0048), (0x1E2B, 0x0068), (0x1E2C, 0x0049), (0x1E2D, 0x0069),
+    (0x1E2E, 0x0049), (0x1E2F, 0x0069), (0x1E30, 0x004B), (0x1E31, 0x006B), (0x1E32, 0x004B),
+    (0x1E33, 0x006B), (0x1E34, 0x004B), (0x1E35, 0x006B), (0x1E36, 0x004C), (0x1E37, 0x006C),
+    (0x1E38, 0x004C), (0x1E39, 0x006C), (0x1E3A, 0x004C), (0x1E3B, 0x006C), (0x1E3C, 0x004C),
+    (0x1E3D, 0x006C), (0x1E3E, 0x004D), (0x1E3F, 0x006D), (0x1E40, 0x004D), (0x1E41, 0x006D),
+    (0x1E42, 0x004D), (0x1E43, 0x006D), (0x1E44, 0x004E), (0x1E45, 0x006E), (0x1E46, 0x004E),
+    (0x1E47, 0x006E), (0x1E48, 0x004E), (0x1E49, 0x006E), (0x1E4A, 0x004E), (0x1E4B, 0x006E),
+    (0x1E4C, 0x004F), (0x1E4D, 0x006F), (0x1E4E, 0x004F), (0x1E4F, 0x006F), (0x1E50, 0x004F),
+    (0x1E51, 0x006F), (0x1E52, 0x004F), (0x1E53, 0x006F), (0x1E54, 0x0050), (0x1E55, 0x0070),
+    (0x1E56, 0x0050), (0x1E57, 0x0070), (0x1E58, 0x0052), (0x1E59, 0x0072), (0x1E5A, 0x0052),
+    (0x1E5B, 0x0072), (0x1E5C, 0x0052), (0x1E5D, 0x0072), (0x1E5E, 0x0052), (0x1E5F, 0x0072),
+    (0x1E60, 0x0053), (0x1E61, 0x0073), (0x1E62, 0x0053), (0x1E63, 0x0073), (0x1E64, 0x0053),
+    (0x1E65, 0x0073), (0x1E66, 0x0053), (0x1E67, 0x0073), (0x1E68, 0x0053), (0x1E69, 0x0073),
+    (0x1E6A, 0x0054), (0x1E6B, 0x0074), (0x1E6C, 0x0054), (0x1E6D, 0x0074), (0x1E6E, 0x0054),
+    (0x1E6F, 0x0074), (0x1E70, 0x0054), (0x1E71, 0x0074), (0x1E72, 0x0055), (0x1E73, 0x0075),
+    (0x1E74, 0x0055), (0x1E75, 0x0075), (0x1E76, 0x0055), (0x1E77, 0x0075), (0x1E78, 0x0055),
+    (0x1E79, 0x0075), (0x1E7A, 0x0055), (0x1E7B, 0x0075), (0x1E7C, 0x0056), (0x1E7D, 0x0076),
+    (0x1E7E, 0x0056), (0x1E7F, 0x0076), (0x1E80, 0x0057), (0x1E81, 0x0077), (0x1E82, 0x0057),
+    (0x1E83, 0x0077), (0x1E84, 0x0057), (0x1E85, 0x0077), (0x1E86, 0x0057), (0x1E87, 0x0077),
+    (0x1E88, 0x0057), (0x1E89, 0x0077), (0x1E8A, 0x0058), (0x1E8B, 0x0078), (0x1E8C, 0x0058),
+    (0x1E8D, 0x0078), (0x1E8E, 0x0059), (0x1E8F, 0x0079), (0x1E90, 0x005A), (0x1E91, 0x007A),
+    (0x1E92, 0x005A), (0x1E93, 0x007A), (0x1E94, 0x005A), (0x1E95, 0x007A), (0x1E96, 0x0068),
+    (0x1E97, 0x0074), (0x1E98, 0x0077), (0x1E99, 0x0079), (0x1E9B, 0x0073), (0x1EA0, 0x0041),
+    (0x1EA1, 0x0061), (0x1EA2, 0x0041), (0x1EA3, 0x0061), (0x1EA4, 0x0041), (0x1EA5, 0x0061),
+    (0x1EA6, 0x0041), (0x1EA7, 0x0061), (0x1EA8, 0x0041), (0x1EA9, 0x0061), (0x1EAA, 0x0041),
+    (0x1EAB, 0x0061), (0x1EAC, 0x0041), (0x1EAD, 0x0061), (0x1EAE, 0x0041), (0x1EAF, 0x0061),
+    (0x1EB0, 0x0041), (0x1EB1, 0x0061), (0x1EB2, 0x0041), (0x1EB3, 0x0061), (0x1EB4, 0x0041),
+    (0x1EB5, 0x0061), (0x1EB6, 0x0041), (0x1EB7, 0x0061), (0x1EB8, 0x0045), (0x1EB9, 0x0065),
+    (0x1EBA, 0x0045), (0x1EBB, 0x0065), (0x1EBC, 0x0045), (0x1EBD, 0x0065), (0x1EBE, 0x0045),
+    (0x1EBF, 0x0065), (0x1EC0, 0x0045), (0x1EC1, 0x0065), (0x1EC2, 0x0045), (0x1EC3, 0x0065),
+    (0x1EC4, 0x0045), (0x1EC5, 0x0065), (0x1EC6, 0x0045), (0x1EC7, 0x0065), (0x1EC8, 0x0049),
+    (0x1EC9, 0x0069), (0x1ECA, 0x0049), (0x1ECB, 0x0069), (0x1ECC, 0x004F), (0x1ECD, 0x006F),
+    (0x1ECE, 0x004F), (0x1ECF, 0x006F), (0x1ED0, 0x004F), (0x1ED1, 0x006F), (0x1ED2, 0x004F),
+    (0x1ED3, 0x006F), (0x1ED4, 0x004F), (0x1ED5, 0x006F), (0x1ED6, 0x004F), (0x1ED7, 0x006F),
+    (0x1ED8, 0x004F), (0x1ED9, 0x006F), (0x1EDA, 0x004F), (0x1EDB, 0x006F), (0x1EDC, 0x004F),
+    (0x1EDD, 0x006F), (0x1EDE, 0x004F), (0x1EDF, 0x006F), (0x1EE0, 0x004F), (0x1EE1, 0x006F),
+    (0x1EE2, 0x004F), (0x1EE3, 0x006F), (0x1EE4, 0x0055), (0x1EE5, 0x0075), (0x1EE6, 0x0055),
+    (0x1EE7, 0x0075), (0x1EE8, 0x0055), (0x1EE9, 0x0075), (0x1EEA, 0x0055), (0x1EEB, 0x0075),
+    (0x1EEC, 0x0055), (0x1EED, 0x0075), (0x1EEE, 0x0055), (0x1EEF, 0x0075), (0x1EF0, 0x0055),
+    (0x1EF1, 0x0075), (0x1EF2, 0x0059), (0x1EF3, 0x0079), (0x1EF4, 0x0059), (0x1EF5, 0x0079),
+    (0x1EF6, 0x0059), (0x1EF7, 0x0079), (0x1EF8, 0x0059), (0x1EF9, 0x0079), (0x1F00, 0xA5E1),
+    (0x1F01, 0xA5E1), (0x1F02, 0xA5E1), (0x1F03, 0xA5E1), (0x1F04, 0xA5E1), (0x1F05, 0xA5E1),
+    (0x1F06, 0xA5E1), (0x1F07, 0xA5E1), (0x1F08, 0xA5C1), (0x1F09, 0xA5C1), (0x1F0A, 0xA5C1),
+    (0x1F0B, 0xA5C1), (0x1F0C, 0xA5C1), (0x1F0D, 0xA5C1), (0x1F0E, 0xA5C1), (0x1F0F, 0xA5C1),
+    (0x1F10, 0xA5E5), (0x1F11, 0xA5E5), (0x1F12, 0xA5E5), (0x1F13, 0xA5E5), (0x1F14, 0xA5E5),
+    (0x1F15, 0xA5E5), (0x1F18, 0xA5C5), (0x1F19, 0xA5C5), (0x1F1A, 0xA5C5), (0x1F1B, 0xA5C5),
+    (0x1F1C, 0xA5C5), (0x1F1D, 0xA5C5), (0x1F20, 0xA5E7), (0x1F21, 0xA5E7), (0x1F22, 0xA5E7),
+    (0x1F23, 0xA5E7), (0x1F24, 0xA5E7), (0x1F25, 0xA5E7), (0x1F26, 0xA5E7), (0x1F27, 0xA5E7),
+    (0x1F28, 0xA5C7), (0x1F29, 0xA5C7), (0x1F2A, 0xA5C7), (0x1F2B, 0xA5C7), (0x1F2C, 0xA5C7),
+    (0x1F2D, 0xA5C7), (0x1F2E, 0xA5C7), (0x1F2F, 0xA5C7), (0x1F30, 0xA5E9), (0x1F31, 0xA5E9),
+    (0x1F32, 0xA5E9), (0x1F33, 0xA5E9), (0x1F34, 0xA5E9), (0x1F35, 0xA5E9), (0x1F36, 0xA5E9),
+    (0x1F37, 0xA5E9), (0x1F38, 0xA5C9), (0x1F39, 0xA5C9), (0x1F3A, 0xA5C9), (0x1F3B, 0xA5C9),
+    (0x1F3C, 0xA5C9), (0x1F3D, 0xA5C9), (0x1F3E, 0xA5C9), (0x1F3F, 0xA5C9), (0x1F40, 0xA5EF),
+    (0x1F41, 0xA5EF), (0x1F42, 0xA5EF), (0x1F43, 0xA5EF), (0x1F44, 0xA5EF), (0x1F45, 0xA5EF),
+    (0x1F48, 0xA5CF), (0x1F49, 0xA5CF), (0x1F4A, 0xA5CF), (0x1F4B, 0xA5CF), (0x1F4C, 0xA5CF),
+    (0x1F4D, 0xA5CF), (0x1F50, 0xA5F4), (0x1F51, 0xA5F4), (0x1F52, 0xA5F4), (0x1F53, 0xA5F4),
+    (0x1F54, 0xA5F4), (0x1F55, 0xA5F4), (0x1F56, 0xA5F4), (0x1F57, 0xA5F4), (0x1F59, 0xA5D4),
+    (0x1F5B, 0xA5D4), (0x1F5D, 0xA5D4), (0x1F5F, 0xA5D4), (0x1F60, 0xA5F8), (0x1F61, 0xA5F8),
+    (0x1F62, 0xA5F8), (0x1F63, 0xA5F8), (0x1F64, 0xA5F8), (0x1F65, 0xA5F8), (0x1F66, 0xA5F8),
+    (0x1F67, 0xA5F8), (0x1F68, 0xA5D8), (0x1F69, 0xA5D8), (0x1F6A, 0xA5D8), (0x1F6B, 0xA5D8),
+    (0x1F6C, 0xA5D8), (0x1F6D, 0xA5D8), (0x1F6E, 0xA5D8), (0x1F6F, 0xA5D8), (0x1F70, 0xA5E1),
+    (0x1F71, 0xA5E1), (0x1F72, 0xA5E5), (0x1F73, 0xA5E5), (0x1F74, 0xA5E7), (0x1F75, 0xA5E7),
+    (0x1F76, 0xA5E9), (0x1F77, 0xA5E9), (0x1F78, 0xA5EF), (0x1F79, 0xA5EF), (0x1F7A, 0xA5F4),
+    (0x1F7B, 0xA5F4), (0x1F7C, 0xA5F8), (0x1F7D, 0xA5F8), (0x1F80, 0xA5E1), (0x1F81, 0xA5E1),
+    (0x1F82, 0xA5E1), (0x1F83, 0xA5E1), (0x1F84, 0xA5E1), (0x1F85, 0xA5E1), (0x1F86, 0xA5E1),
+    (0x1F87, 0xA5E1), (0x1F88, 0xA5C1), (0x1F89, 0xA5C1), (0x1F8A, 0xA5C1), (0x1F8B, 0xA5C1),
+    (0x1F8C, 0xA5C1), (0x1F8D, 0xA5C1), (0x1F8E, 0xA5C1), (0x1F8F, 0xA5C1), (0x1F90, 0xA5E7),
+    (0x1F91, 0xA5E7), (0x1F92, 0xA5E7), (0x1F93, 0xA5E7), (0x1F94, 0xA5E7), (0x1F95, 0xA5E7),
+    (0x1F96, 0xA5E7), (0x1F97, 0xA5E7), (0x1F98, 0xA5C7), (0x1F99, 0xA5C7), (0x1F9A, 0xA5C7),
+    (0x1F9B, 0xA5C7), (0x1F9C, 0xA5C7), (0x1F9D, 0xA5C7), (0x1F9E, 0xA5C7), (0x1F9F, 0xA5C7),
+    (0x1FA0, 0xA5F8), (0x1FA1, 0xA5F8), (0x1FA2, 0xA5F8), (0x1FA3, 0xA5F8), (0x1FA4, 0xA5F8),
+    (0x1FA5, 0xA5F8), (0x1FA6, 0xA5F8), (0x1FA7, 0xA5F8), (0x1FA8, 0xA5D8), (0x1FA9, 0xA5D8),
+    (0x1FAA, 0xA5D8), (0x1FAB, 0xA5D8), (0x1FAC, 0xA5D8), (0x1FAD, 0xA5D8), (0x1FAE, 0xA5D8),
+    (0x1FAF, 0xA5D8), (0x1FB0, 0xA5E1), (0x1FB1, 0xA5E1), (0x1FB2, 0xA5E1), (0x1FB3, 0xA5E1),
+    (0x1FB4, 0xA5E1), (0x1FB6, 0xA5E1), (0x1FB7, 0xA5E1), (0x1FB8, 0xA5C1), (0x1FB9, 0xA5C1),
+    (0x1FBA, 0xA5C1), (0x1FBB, 0xA5C1), (0x1FBC, 0xA5C1), (0x1FBE, 0xA5E9), (0x1FC2, 0xA5E7),
+    (0x1FC3, 0xA5E7), (0x1FC4, 0xA5E7), (0x1FC6, 0xA5E7), (0x1FC7, 0xA5E7), (0x1FC8, 0xA5C5),
+    (0x1FC9, 0xA5C5), (0x1FCA, 0xA5C7), (0x1FCB, 0xA5C7), (0x1FCC, 0xA5C7), (0x1FD0, 0xA5E9),
+    (0x1FD1, 0xA5E9), (0x1FD2, 0xA5E9), (0x1FD3, 0xA5E9), (0x1FD6, 0xA5E9), (0x1FD7, 0xA5E9),
+    (0x1FD8, 0xA5C9), (0x1FD9, 0xA5C9), (0x1FDA, 0xA5C9), (0x1FDB, 0xA5C9), (0x1FE0, 0xA5F4),
+    (0x1FE1, 0xA5F4), (0x1FE2, 0xA5F4), (0x1FE3, 0xA5F4), (0x1FE4, 0xA5F1), (0x1FE5, 0xA5F1),
+    (0x1FE6, 0xA5F4), (0x1FE7, 0xA5F4), (0x1FE8, 0xA5D4), (0x1FE9, 0xA5D4), (0x1FEA, 0xA5D4),
+    (0x1FEB, 0xA5D4), (0x1FEC, 0xA5D1), (0x1FEF, 0x0060), (0x1FF2, 0xA5F8), (0x1FF3, 0xA5F8),
+    (0x1FF4, 0xA5F8), (0x1FF6, 0xA5F8), (0x1FF7, 0xA5F8), (0x1FF8, 0xA5CF), (0x1FF9, 0xA5CF),
+    (0x1FFA, 0xA5D8), (0x1FFB, 0xA5D8), (0x1FFC, 0xA5D8), (0x2000, 0x0020), (0x2001, 0x0020),
+    (0x2002, 0x0020), (0x2003, 0x0020), (0x2004, 0x0020), (0x2005, 0x0020), (0x2006, 0x0020),
+    (0x2007, 0x0020), (0x2008, 0x0020), (0x2009, 0x0020), (0x200A, 0x0020), (0x2010, 0x002D),
+    (0x2012, 0x002D), (0x2013, 0x002D), (0x2014, 0x002D), (0x201A, 0x002C), (0x201B, 0x0027),
+    (0x201E, 0x0022), (0x201F, 0x0022), (0x2024, 0x002E), (0x202F, 0x0020), (0x2035, 0x0060),
+    (0x2039, 0x003C), (0x203A, 0x003E), (0x205F, 0x0020), (0x2070, 0x0030), (0x2071, 0x0069),
+    (0x2075, 0x0035), (0x2076, 0x0036), (0x2077, 0x0037), (0x2078, 0x0038), (0x2079, 0x0039),
+    (0x207A, 0x002B), (0x207C, 0x003D), (0x207D, 0x0028), (0x207E, 0x0029), (0x2080, 0x0030),
+    (0x2085, 0x0035), (0x2086, 0x0036), (0x2087, 0x0037), (0x2088, 0x0038), (0x2089, 0x0039),
+    (0x208A, 0x002B), (0x208C, 0x003D), (0x208D, 0x0028), (0x208E, 0x0029), (0x2090, 0x0061),
+    (0x2091, 0x0065), (0x2092, 0x006F), (0x2093, 0x0078), (0x2095, 0x0068), (0x2096, 0x006B),
+    (0x2097, 0x006C), (0x2098, 0x006D), (0x2099, 0x006E), (0x209A, 0x0070), (0x209B, 0x0073),
+    (0x209C, 0x0074), (0x2102, 0x0043), (0x210A, 0x0067), (0x210B, 0x0048), (0x210C, 0x0048),
+    (0x210D, 0x0048), (0x210E, 0x0068), (0x210F, 0xA9A4), (0x2110, 0x0049), (0x2111, 0x0049),
+    (0x2112, 0x004C), (0x2115, 0x004E), (0x2119, 0x0050), (0x211A, 0x0051), (0x211B, 0x0052),
+    (0x211C, 0x0052), (0x211D, 0x0052), (0x2124, 0x005A), (0x2128, 0x005A), (0x212A, 0x004B),
+    (0x212C, 0x0042), (0x212D, 0x0043), (0x212F, 0x0065), (0x2130, 0x0045), (0x2131, 0x0046),
+    (0x2133, 0x004D), (0x2134, 0x006F), (0x2139, 0x0069), (0x213C, 0xA5F0), (0x213D, 0xA5E3),
+    (0x213E, 0xA5C3), (0x213F, 0xA5D0), (0x2140, 0xA2B2), (0x2145, 0x0044), (0x2146, 0x0064),
+    (0x2147, 0x0065), (0x2148, 0x0069), (0x2149, 0x006A), (0x216C, 0x004C), (0x216D, 0x0043),
+    (0x216E, 0x0044), (0x216F, 0x004D), (0x217C, 0x006C), (0x217D, 0x0063), (0x217E, 0x0064),
+    (0x217F, 0x006D), (0x219A, 0xA1E7), (0x219B, 0xA1E6), (0x21AE, 0xA1EA), (0x21CE, 0xA2A2),
+    (0x21CF, 0xA2A1), (0x2204, 0xA2A4), (0x2209, 0xA1F4), (0x220C, 0xA1F5), (0x2212, 0x002D),
+    (0x2215, 0x002F), (0x2216, 0x005C), (0x2223, 0x007C), (0x2226, 0xA1AB), (0x2241, 0xA1AD),
+    (0x2262, 0xA1D5), (0x226E, 0x003C), (0x226F, 0x003E), (0x2270, 0xA1C2), (0x2271, 0xA1C3),
+    (0x2284, 0xA1F8), (0x2285, 0xA1F9), (0x2288, 0xA1F6), (0x2289, 0xA1F7), (0x2329, 0xA1B4),
+    (0x232A, 0xA1B5), (0x24B6, 0x0041), (0x24B7, 0x0042), (0x24B8, 0x0043), (0x24B9, 0x0044),
+    (0x24BA, 0x0045), (0x24BB, 0x0046), (0x24BC, 0x0047), (0x24BD, 0x0048), (0x24BE, 0x0049),
+    (0x24BF, 0x004A), (0x24C0, 0x004B), (0x24C1, 0x004C), (0x24C2, 0x004D), (0x24C3, 0x004E),
+    (0x24C4, 0x004F), (0x24C5, 0x0050), (0x24C6, 0x0051), (0x24C7, 0x0052), (0x24C8, 0x0053),
+    (0x24C9, 0x0054), (0x24CA, 0x0055), (0x24CB, 0x0056), (0x24CC, 0x0057), (0x24CD, 0x0058),
+    (0x24CE, 0x0059), (0x24CF, 0x005A), (0x24EA, 0x0030), (0x2C7C, 0x006A), (0x2C7D, 0x0056),
+    (0x2E9F, 0xD9BD), (0x2F00, 0xECE9), (0x2F04, 0xEBE0), (0x2F06, 0xECA3), (0x2F08, 0xECD1),
+    (0x2F0A, 0xECFD), (0x2F0B, 0xF8A2), (0x2F11, 0xD3EF), (0x2F12, 0xD5F4), (0x2F14, 0xDDE2),
+    (0x2F17, 0xE4A8), (0x2F18, 0xDCD4), (0x2F1C, 0xE9D1), (0x2F1D, 0xCFA2), (0x2F1F, 0xF7CF),
+    (0x2F20, 0xDECD), (0x2F23, 0xE0AA), (0x2F24, 0xD3DE), (0x2F25, 0xD2B3), (0x2F26, 0xEDAD),
+    (0x2F28, 0xF5BB), (0x2F29, 0xE1B3), (0x2F2B, 0xE3B9), (0x2F2D, 0xDFA3), (0x2F2F, 0xCDEF),
+    (0x2F30, 0xD0F9), (0x2F31, 0xCBEE), (0x2F32, 0xCACE), (0x2F38, 0xCFE1), (0x2F3C, 0xE3FD),
+    (0x2F3D, 0xCDFC), (0x2F3E, 0xFBC2), (0x2F3F, 0xE2A2), (0x2F40, 0xF2A8), (0x2F42, 0xD9FE),
+    (0x2F43, 0xD4E0), (0x2F44, 0xD0C5), (0x2F45, 0xDBB0), (0x2F46, 0xD9E9), (0x2F47, 0xECED),
+    (0x2F48, 0xE8D8), (0x2F49, 0xEAC5), (0x2F4A, 0xD9CA), (0x2F4B, 0xFDE2), (0x2F4C, 0xF2AD),
+    (0x2F4F, 0xD9EC), (0x2F50, 0xDDEF), (0x2F51, 0xD9BE), (0x2F52, 0xE4AB), (0x2F54, 0xE2A9),
+    (0x2F55, 0xFBFD), (0x2F56, 0xF0D0), (0x2F57, 0xDDAB), (0x2F58, 0xFDA1), (0x2F5A, 0xF8B8),
+    (0x2F5B, 0xE4B3), (0x2F5C, 0xE9DA), (0x2F5D, 0xCCB3), (0x2F5E, 0xFADC), (0x2F5F, 0xE8AC),
+    (0x2F60, 0xCDFE), (0x2F61, 0xE8BF), (0x2F62, 0xCAF6), (0x2F63, 0xDFE6), (0x2F64, 0xE9C4),
+    (0x2F65, 0xEFA3), (0x2F66, 0xF9B5), (0x2F69, 0xDBDC), (0x2F6A, 0xF9AB), (0x2F6B, 0xD9A9),
+    (0x2F6C, 0xD9CD), (0x2F6D, 0xD9C3), (0x2F6E, 0xE3C5), (0x2F6F, 0xE0B4), (0x2F70, 0xE3C6),
+    (0x2F72, 0xFCA2), (0x2F73, 0xFAEB), (0x2F74, 0xD8A1), (0x2F75, 0xF1D3), (0x2F76, 0xDAB7),
+    (0x2F78, 0xDDAE), (0x2F7A, 0xE5CF), (0x2F7B, 0xE9E2), (0x2F7C, 0xD6D5), (0x2F7D, 0xECBB),
+    (0x2F7F, 0xECBC), (0x2F80, 0xEBD3), (0x2F81, 0xEBBF), (0x2F82, 0xE3ED), (0x2F83, 0xEDBB),
+    (0x2F84, 0xF2B8), (0x2F85, 0xCFBF), (0x2F86, 0xE0DF), (0x2F87, 0xF4BF), (0x2F88, 0xF1C7),
+    (0x2F89, 0xCADD), (0x2F8A, 0xDFE4), (0x2F8B, 0xF5AC), (0x2F8E, 0xFAEC), (0x2F8F, 0xFABC),
+    (0x2F90, 0xEBFD), (0x2F92, 0xCCB8), (0x2F93, 0xCAC7), (0x2F94, 0xE5EB), (0x2F95, 0xCDDB),
+    (0x2F96, 0xD4E7), (0x2F97, 0xE3CE), (0x2F99, 0xF8AF), (0x2F9A, 0xEEE5), (0x2F9B, 0xF1CB),
+    (0x2F9C, 0xF0EB), (0x2F9D, 0xE3F3), (0x2F9E, 0xF3B3), (0x2F9F, 0xE3F4), (0x2FA0, 0xF2E3),
+    (0x2FA2, 0xEBE9), (0x2FA3, 0xEBB7), (0x2FA5, 0xD7EC), (0x2FA6, 0xD1D1), (0x2FA7, 0xEDFE),
+    (0x2FA8, 0xDAA6), (0x2FA9, 0xDDBD), (0x2FAC, 0xE9EB), (0x2FAD, 0xF4EC), (0x2FAE, 0xDEAA),
+    (0x2FAF, 0xD8FC), (0x2FB0, 0xFAD4), (0x2FB1, 0xEADF), (0x2FB3, 0xEBE5), (0x2FB4, 0xFAED),
+    (0x2FB5, 0xF9A6), (0x2FB6, 0xDEAB), (0x2FB7, 0xE3DD), (0x2FB8, 0xE2CF), (0x2FB9, 0xFAC5),
+    (0x2FBA, 0xD8A9), (0x2FBB, 0xCDE9), (0x2FBC, 0xCDD4), (0x2FC1, 0xD0A1), (0x2FC2, 0xE5E0),
+    (0x2FC3, 0xF0E8), (0x2FC4, 0xD6DD), (0x2FC5, 0xD6E3), (0x2FC6, 0xD8EA), (0x2FC7, 0xD8AB),
+    (0x2FC8, 0xFCDC), (0x2FC9, 0xE0A8), (0x2FCA, 0xFDD9), (0x2FCD, 0xF0A3), (0x2FCE, 0xCDD5),
+    (0x2FCF, 0xE0A9), (0x2FD0, 0xDEAC), (0x2FD1, 0xF0BA), (0x2FD2, 0xF6CD), (0x2FD3, 0xD7A3),
+    (0x2FD4, 0xCFCF), (0x3038, 0xE4A8), (0x3039, 0xECFE), (0x3094, 0xAAA6), (0x30F7, 0xABEF),
+    (0x30F8, 0xABF0), (0x30F9, 0xABF1), (0x30FA, 0xABF2), (0x3192, 0xECE9), (0x3193, 0xECA3),
+    (0x3194, 0xDFB2), (0x3195, 0xDECC), (0x3196, 0xDFBE), (0x3197, 0xF1E9), (0x3198, 0xF9BB),
+    (0x3199, 0xCBA3), (0x319A, 0xEBE0), (0x319B, 0xDCB0), (0x319C, 0xEFCB), (0x319D, 0xF4B8),
+    (0x319E, 0xF2A2), (0x319F, 0xECD1), (0x3244, 0xD9FD), (0x3245, 0xEAEA), (0x3246, 0xD9FE),
+    (0x3247, 0xEEB4), (0x3280, 0xECE9), (0x3281, 0xECA3), (0x3282, 0xDFB2), (0x3283, 0xDECC),
+    (0x3284, 0xE7E9), (0x3285, 0xD7BF), (0x3286, 0xF6D2), (0x3287, 0xF8A2), (0x3288, 0xCEFA),
+    (0x3289, 0xE4A8), (0x328A, 0xEAC5), (0x328B, 0xFBFD), (0x328C, 0xE2A9), (0x328D, 0xD9CA),
+    (0x328E, 0xD1D1), (0x328F, 0xF7CF), (0x3290, 0xECED), (0x3291, 0xF1BB), (0x3292, 0xEAF3),
+    (0x3293, 0xDEE4), (0x3294, 0xD9A3), (0x3295, 0xF7E5), (0x3296, 0xEEAF), (0x3297, 0xF5E6),
+    (0x3299, 0xDDFA), (0x329A, 0xD1FB), (0x329B, 0xD2B3), (0x329C, 0xEEEA), (0x329D, 0xE9D0),
+    (0x329E, 0xECD4), (0x329F, 0xF1BC), (0x32A0, 0xFAA3), (0x32A1, 0xFDCC), (0x32A3, 0xEFE1),
+    (0x32A4, 0xDFBE), (0x32A5, 0xF1E9), (0x32A6, 0xF9BB), (0x32A7, 0xF1A7), (0x32A8, 0xE9D3),
+    (0x32AA, 0xF0F3), (0x32AC, 0xCAF8), (0x32AD, 0xD0EA), (0x32AE, 0xEDC0), (0x32AF, 0xFAF0),
+    (0x32B0, 0xE5A8), (0x32D0, 0xABA2), (0x32D1, 0xABA4), (0x32D2, 0xABA6), (0x32D3, 0xABA8),
+    (0x32D4, 0xABAA), (0x32D5, 0xABAB), (0x32D6, 0xABAD), (0x32D7, 0xABAF), (0x32D8, 0xABB1),
+    (0x32D9, 0xABB3), (0x32DA, 0xABB5), (0x32DB, 0xABB7), (0x32DC, 0xABB9), (0x32DD, 0xABBB),
+    (0x32DE, 0xABBD), (0x32DF, 0xABBF), (0x32E0, 0xABC1), (0x32E1, 0xABC4), (0x32E2, 0xABC6),
+    (0x32E3, 0xABC8), (0x32E4, 0xABCA), (0x32E5, 0xABCB), (0x32E6, 0xABCC), (0x32E7, 0xABCD),
+    (0x32E8, 0xABCE), (0x32E9, 0xABCF), (0x32EA, 0xABD2), (0x32EB, 0xABD5), (0x32EC, 0xABD8),
+    (0x32ED, 0xABDB), (0x32EE, 0xABDE), (0x32EF, 0xABDF), (0x32F0, 0xABE0), (0x32F1, 0xABE1),
+    (0x32F2, 0xABE2), (0x32F3, 0xABE4), (0x32F4, 0xABE6), (0x32F5, 0xABE8), (0x32F6, 0xABE9),
+    (0x32F7, 0xABEA), (0x32F8, 0xABEB), (0x32F9, 0xABEC), (0x32FA, 0xABED), (0x32FB, 0xABEF),
+    (0x32FC, 0xABF0), (0x32FD, 0xABF1), (0x32FE, 0xABF2), (0xA69C, 0xACEC), (0xA69D, 0xACEE),
+    (0xA7F2, 0x0043), (0xA7F3, 0x0046), (0xA7F4, 0x0051), (0xA7F8, 0xA8A4), (0xA7F9, 0xA9AB),
+    (0xFA0C, 0xE8B4), (0xFA10, 0xF5C0), (0xFA12, 0xF4E7), (0xFA15, 0xFDEB), (0xFA16, 0xEEC4),
+    (0xFA17, 0xECCC), (0xFA19, 0xE3EA), (0xFA1A, 0xDFD4), (0xFA1B, 0xDCD8), (0xFA1C, 0xEFFE),
+    (0xFA1D, 0xEFF1), (0xFA1E, 0xE9E2), (0xFA22, 0xF0B3), (0xFA25, 0xECEF), (0xFA26, 0xD4B4),
+    (0xFA2A, 0xDAF9), (0xFA2B, 0xDEF8), (0xFA2C, 0xCEBD), (0xFA2D, 0xF9CD), (0xFA2E, 0xD5CD),
+    (0xFA2F, 0xD6CB), (0xFA30, 0xD9B2), (0xFA31, 0xE3AC), (0xFA32, 0xD8F3), (0xFA33, 0xD8F5),
+    (0xFA34, 0xD0C3), (0xFA35, 0xDDE4), (0xFA36, 0xCAE2), (0xFA37, 0xF7A3), (0xFA38, 0xD0EF),
+    (0xFA3A, 0xD9F8), (0xFA3B, 0xF6B5), (0xFA3D, 0xFCE2), (0xFA3E, 0xCBC6), (0xFA3F, 0xF1F3),
+    (0xFA40, 0xF3A4), (0xFA41, 0xDAC2), (0xFA43, 0xDFF4), (0xFA44, 0xD8DE), (0xFA45, 0xFAAD),
+    (0xFA46, 0xEEC2), (0xFA47, 0xF9D3), (0xFA48, 0xEDB4), (0xFA4A, 0xF6FC), (0xFA4B, 0xDDF8),
+    (0xFA4C, 0xDEE4), (0xFA4D, 0xF2B3), (0xFA4E, 0xD1B7), (0xFA4F, 0xE9DE), (0xFA50, 0xF0D3),
+    (0xFA51, 0xF5E6), (0xFA52, 0xFCA1), (0xFA53, 0xEFEE), (0xFA54, 0xCDDA), (0xFA55, 0xD4CD),
+    (0xFA56, 0xEFBD), (0xFA57, 0xD6A3), (0xFA58, 0xF2DB), (0xFA59, 0xDBE5), (0xFA5A, 0xDFFE),
+    (0xFA5B, 0xEDBA), (0xFA5C, 0xF6AB), (0xFA5F, 0xEECA), (0xFA60, 0xCAE8), (0xFA61, 0xE3CA),
+    (0xFA62, 0xE4D7), (0xFA63, 0xD0CD), (0xFA64, 0xDEB9), (0xFA65, 0xF1FC), (0xFA67, 0xECEF),
+    (0xFA68, 0xD1F1), (0xFA69, 0xFAC2), (0xFA6A, 0xDEBA), (0xFA72, 0xEEEF), (0xFA74, 0xF5F6),
+    (0xFA75, 0xD0ED), (0xFA76, 0xE9B8), (0xFA77, 0xEDC3), (0xFA78, 0xCAE2), (0xFA7A, 0xFDC2),
+    (0xFA7C, 0xF5C0), (0xFA7D, 0xDDC5), (0xFA7E, 0xE5F2), (0xFA7F, 0xDDC6), (0xFA80, 0xDDE6),
+    (0xFA84, 0xF3F4), (0xFA88, 0xEAEF), (0xFA89, 0xF1F3), (0xFA8B, 0xF3A4), (0xFA8C, 0xD3E3),
+    (0xFA8D, 0xEAF1), (0xFA8E, 0xE2A4), (0xFA90, 0xE7F9), (0xFA91, 0xF4E7), (0xFA92, 0xD5C7),
+    (0xFA93, 0xD8D0), (0xFA94, 0xEDE8), (0xFA96, 0xDFAF), (0xFA97, 0xD7B5), (0xFA99, 0xEDB2),
+    (0xFA9A, 0xF9D3), (0xFA9B, 0xEFE7), (0xFA9C, 0xEDB4), (0xFA9E, 0xEDC9), (0xFA9F, 0xDBF3),
+    (0xFAA0, 0xEEC4), (0xFAA5, 0xE8B0), (0xFAA6, 0xECCC), (0xFAA7, 0xE0FC), (0xFAA8, 0xF2C1),
+    (0xFAAA, 0xF3B7), (0xFAAD, 0xEFBD), (0xFAB0, 0xD6A3), (0xFAB2, 0xEDBA), (0xFAB3, 0xFCD8),
+    (0xFAB4, 0xFCA4), (0xFAB6, 0xCBBA), (0xFAB7, 0xDCDD), (0xFAB8, 0xE3CA), (0xFAB9, 0xF0E0),
+    (0xFABA, 0xF0B3), (0xFABB, 0xF4EB), (0xFABC, 0xE4D7), (0xFABD, 0xD1E7), (0xFABE, 0xEBB1),
+    (0xFABF, 0xD0CD), (0xFAC0, 0xDCA8), (0xFAC1, 0xF1FC), (0xFAC2, 0xE2C3), (0xFAC3, 0xF2C0),
+    (0xFAC7, 0xD1F1), (0xFAC8, 0xEFFE), (0xFACA, 0xFAC2), (0xFACC, 0xDEBA), (0xFACE, 0xCFCF),
+    (0xFB29, 0x002B), (0xFE10, 0x002C), (0xFE11, 0xA1A2), (0xFE12, 0xA1A3), (0xFE13, 0x003A),
+    (0xFE14, 0x003B), (0xFE15, 0x0021), (0xFE16, 0x003F), (0xFE33, 0x005F), (0xFE34, 0x005F),
+    (0xFE35, 0x0028), (0xFE36, 0x0029), (0xFE37, 0x007B), (0xFE38, 0x007D), (0xFE39, 0xA1B2),
+    (0xFE3A, 0xA1B3), (0xFE3B, 0xA1BC), (0xFE3C, 0xA1BD), (0xFE3D, 0xA1B6), (0xFE3E, 0xA1B7),
+    (0xFE3F, 0xA1B4), (0xFE40, 0xA1B5), (0xFE41, 0xA1B8), (0xFE42, 0xA1B9), (0xFE43, 0xA1BA),
+    (0xFE44, 0xA1BB), (0xFE47, 0x005B), (0xFE48, 0x005D), (0xFE4D, 0x005F), (0xFE4E, 0x005F),
+    (0xFE4F, 0x005F), (0xFE50, 0x002C), (0xFE51, 0xA1A2), (0xFE52, 0x002E), (0xFE54, 0x003B),
+    (0xFE55, 0x003A), (0xFE56, 0x003F), (0xFE57, 0x0021), (0xFE59, 0x0028), (0xFE5A, 0x0029),
+    (0xFE5B, 0x007B), (0xFE5C, 0x007D), (0xFE5D, 0xA1B2), (0xFE5E, 0xA1B3), (0xFE5F, 0x0023),
+    (0xFE60, 0x0026), (0xFE61, 0x002A), (0xFE62, 0x002B), (0xFE63, 0x002D), (0xFE64, 0x003C),
+    (0xFE65, 0x003E), (0xFE66, 0x003D), (0xFE68, 0x005C), (0xFE69, 0x0024), (0xFE6A, 0x0025),
+    (0xFE6B, 0x0040), (0xFF61, 0xA1A3), (0xFF62, 0xA1B8), (0xFF63, 0xA1B9), (0xFF64, 0xA1A2),
+    (0xFF66, 0xABF2), (0xFF67, 0xABA1), (0xFF68, 0xABA3), (0xFF69, 0xABA5), (0xFF6A, 0xABA7),
+    (0xFF6B, 0xABA9), (0xFF6C, 0xABE3), (0xFF6D, 0xABE5), (0xFF6E, 0xABE7), (0xFF6F, 0xABC3),
+    (0xFF71, 0xABA2), (0xFF72, 0xABA4), (0xFF73, 0xABA6), (0xFF74, 0xABA8), (0xFF75, 0xABAA),
+    (0xFF76, 0xABAB), (0xFF77, 0xABAD), (0xFF78, 0xABAF), (0xFF79, 0xABB1), (0xFF7A, 0xABB3),
+    (0xFF7B, 0xABB5), (0xFF7C, 0xABB7), (0xFF7D, 0xABB9), (0xFF7E, 0xABBB), (0xFF7F, 0xABBD),
+    (0xFF80, 0xABBF), (0xFF81, 0xABC1), (0xFF82, 0xABC4), (0xFF83, 0xABC6), (0xFF84, 0xABC8),
+    (0xFF85, 0xABCA), (0xFF86, 0xABCB), (0xFF87, 0xABCC), (0xFF88, 0xABCD), (0xFF89, 0xABCE),
+    (0xFF8A, 0xABCF), (0xFF8B, 0xABD2), (0xFF8C, 0xABD5), (0xFF8D, 0xABD8), (0xFF8E, 0xABDB),
+    (0xFF8F, 0xABDE), (0xFF90, 0xABDF), (0xFF91, 0xABE0), (0xFF92, 0xABE1), (0xFF93, 0xABE2),
+    (0xFF94, 0xABE4), (0xFF95, 0xABE6), (0xFF96, 0xABE8), (0xFF97, 0xABE9), (0xFF98, 0xABEA),
+    (0xFF99, 0xABEB), (0xFF9A, 0xABEC), (0xFF9B, 0xABED), (0xFF9C, 0xABEF), (0xFF9D, 0xABF3),
+    (0xFFE8, 0xA6A2), (0xFFE9, 0xA1E7), (0xFFEA, 0xA1E8), (0xFFEB, 0xA1E6), (0xFFEC, 0xA1E9),
+    (0xFFED, 0xA1E1), (0xFFEE, 0xA1DB),
+];
+
+/// Code page 950.
+#[rustfmt::skip]
+pub(crate) const CP950: [(u16, u16); 1701] = [
+    (0x00A0, 0x0020), (0x00AA, 0x0061), (0x00AB, 0x003C), (0x00B2, 0x0032), (0x00B3, 0x0033),
+    (0x00B5, 0xA367), (0x00B9, 0x0031), (0x00BA, 0x006F), (0x00BB, 0x003E), (0x00C0, 0x0041),
+    (0x00C1, 0x0041), (0x00C2, 0x0041), (0x00C3, 0x0041), (0x00C4, 0x0041), (0x00C5, 0x0041),
+    (0x00C7, 0x0043), (0x00C8, 0x0045), (0x00C9, 0x0045), (0x00CA, 0x0045), (0x00CB, 0x0045),
+    (0x00CC, 0x0049), (0x00CD, 0x0049), (0x00CE, 0x0049), (0x00CF, 0x0049), (0x00D1, 0x004E),
+    (0x00D2, 0x004F), (0x00D3, 0x004F), (0x00D4, 0x004F), (0x00D5, 0x004F), (0x00D6, 0x004F),
+    (0x00D9, 0x0055), (0x00DA, 0x0055), (0x00DB, 0x0055), (0x00DC, 0x0055), (0x00DD, 0x0059),
+    (0x00E0, 0x0061), (0x00E1, 0x0061), (0x00E2, 0x0061), (0x00E3, 0x0061), (0x00E4, 0x0061),
+    (0x00E5, 0x0061), (0x00E7, 0x0063), (0x00E8, 0x0065), (0x00E9, 0x0065), (0x00EA, 0x0065),
+    (0x00EB, 0x0065), (0x00EC, 0x0069), (0x00ED, 0x0069), (0x00EE, 0x0069), (0x00EF, 0x0069),
+    (0x00F1, 0x006E), (0x00F2, 0x006F), (0x00F3, 0x006F), (0x00F4, 0x006F), (0x00F5, 0x006F),
+    (0x00F6, 0x006F), (0x00F9, 0x0075), (0x00FA, 0x0075), (0x00FB, 0x0075), (0x00FC, 0x0075),
+    (0x00FD, 0x0079), (0x00FF, 0x0079), (0x0100, 0x0041), (0x0101, 0x0061), (0x0102, 0x0041),
+    (0x0103, 0x0061), (0x0104, 0x0041), (0x0105, 0x0061), (0x0106, 0x0043), (0x0107, 0x0063),
+    (0x0108, 0x0043), (0x0109, 0x0063), (0x010A, 0x0043), (0x010B, 0x0063), (0x010C, 0x0043),
+    (0x010D, 0x0063), (0x010E, 0x0044), (0x010F, 0x0064), (0x0112, 0x0045), (0x0113, 0x0065),
+    (0x0114, 0x0045), (0x0115, 0x0065), (0x0116, 0x0045), (0x0117, 0x0065), (0x0118, 0x0045),
+    (0x0119, 0x0065), (0x011A, 0x0045), (0x011B, 0x0065), (0x011C, 0x0047), (0x011D, 0x0067),
+    (0x011E, 0x0047), (0x011F, 0x0067), (0x0120, 0x0047), (0x0121, 0x0067), (0x0122, 0x0047),
+    (0x0123, 0x0067), (0x0124, 0x0048), (0x0125, 0x0068), (0x0128, 0x0049), (0x0129, 0x0069),
+    (0x012A, 0x0049), (0x012B, 0x0069), (0x012C, 0x0049), (0x012D, 0x0069), (0x012E, 0x0049),
+    (0x012F, 0x0069), (0x0130, 0x0049), (0x0134, 0x004A), (0x0135, 0x006A), (0x0136, 0x004B),
+    (0x0137, 0x006B), (0x0139, 0x004C), (0x013A, 0x006C), (0x013B, 0x004C), (0x013C, 0x006C),
+    (0x013D, 0x004C), (0x013E, 0x006C), (0x0143, 0x004E), (0x0144, 0x006E), (0x0145, 0x004E),
+    (0x0146, 0x006E), (0x0147, 0x004E), (0x0148, 0x006E), (0x014C, 0x004F), (0x014D, 0x006F),
+    (0x014E, 0x004F), (0x014F, 0x006F), (0x0150, 0x004F), (0x0151, 0x006F), (0x0154, 0x0052),
+    (0x0155, 0x0072), (0x0156, 0x0052), (0x0157, 0x0072), (0x0158, 0x0052), (0x0159, 0x0072),
+    (0x015A, 0x0053), (0x015B, 0x0073), (0x015C, 0x0053), (0x015D, 0x0073), (0x015E, 0x0053),
+    (0x015F, 0x0073), (0x0160, 0x0053), (0x0161, 0x0073), (0x0162, 0x0054), (0x0163, 0x0074),
+    (0x0164, 0x0054), (0x0165, 0x0074), (0x0168, 0x0055), (0x0169, 0x0075), (0x016A, 0x0055),
+    (0x016B, 0x0075), (0x016C, 0x0055), (0x016D, 0x0075), (0x016E, 0x0055), (0x016F, 0x0075),
+    (0x0170, 0x0055), (0x0171, 0x0075), (0x0172, 0x0055), (0x0173, 0x0075), (0x0174, 0x0057),
+    (0x0175, 0x0077), (0x0176, 0x0059), (0x0177, 0x0079), (0x0178, 0x0059), (0x0179, 0x005A),
+    (0x017A, 0x007A), (0x017B, 0x005A), (0x017C, 0x007A), (0x017D, 0x005A), (0x017E, 0x007A),
+    (0x017F, 0x0073), (0x01A0, 0x004F), (0x01A1, 0x006F), (0x01AF, 0x0055), (0x01B0, 0x0075),
+    (0x01CD, 0x0041), (0x01CE, 0x0061), (0x01CF, 0x0049), (0x01D0, 0x0069), (0x01D1, 0x004F),
+    (0x01D2, 0x006F), (0x01D3, 0x0055), (0x01D4, 0x0075), (0x01D5, 0x0055), (0x01D6, 0x0075),
+    (0x01D7, 0x0055), (0x01D8, 0x0075), (0x01D9, 0x0055), (0x01DA, 0x0075), (0x01DB, 0x0055),
+    (0x01DC, 0x0075), (0x01DE, 0x0041), (0x01DF, 0x0061), (0x01E0, 0x0041), (0x01E1, 0x0061),
+    (0x01E6, 0x0047), (0x01E7, 0x0067), (0x01E8, 0x004B), (0x01E9, 0x006B), (0x01EA, 0x004F),
+    (0x01EB, 0x006F), (0x01EC, 0x004F), (0x01ED, 0x006F), (0x01F0, 0x006A), (0x01F4, 0x0047),
+    (0x01F5, 0x0067), (0x01F8, 0x004E), (0x01F9, 0x006E), (0x01FA, 0x0041), (0x01FB, 0x0061),
+    (0x0200, 0x0041), (0x0201, 0x0061), (0x0202, 0x0041), (0x0203, 0x0061), (0x0204, 0x0045),
+    (0x0205, 0x0065), (0x0206, 0x0045), (0x0207, 0x0065), (0x0208, 0x0049), (0x0209, 0x0069),
+    (0x020A, 0x0049), (0x020B, 0x0069), (0x020C, 0x004F), (0x020D, 0x006F), (0x020E, 0x004F),
+    (0x020F, 0x006F), (0x0210, 0x0052), (0x0211, 0x0072), (0x0212, 0x0052), (0x0213, 0x0072),
+    (0x0214, 0x0055), (0x0215, 0x0075), (0x0216, 0x0055), (0x0217, 0x0075), (0x0218, 0x0053),
+    (0x0219, 0x0073), (0x021A, 0x0054), (0x021B, 0x0074), (0x021E, 0x0048), (0x021F, 0x0068),
+    (0x0226, 0x0041), (0x0227, 0x0061), (0x0228, 0x0045), (0x0229, 0x0065), (0x022A, 0x004F),
+    (0x022B, 0x006F), (0x022C, 0x004F), (0x022D, 0x006F), (0x022E, 0x004F), (0x022F, 0x006F),
+    (0x0230, 0x004F), (0x0231, 0x006F), (0x0232, 0x0059), (0x0233, 0x0079), (0x02B0, 0x0068),
+    (0x02B2, 0x006A), (0x02B3, 0x0072), (0x02B7, 0x0077), (0x02B8, 0x0079), (0x02E1, 0x006C),
+    (0x02E2, 0x0073), (0x02E3, 0x0078), (0x037E, 0x003B), (0x0386, 0xA344), (0x0387, 0xA150),
+    (0x0388, 0xA348), (0x0389, 0xA34A), (0x038A, 0xA34C), (0x038C, 0xA352), (0x038E, 0xA357),
+    (0x038F, 0xA35B), (0x0390, 0xA364), (0x03AA, 0xA34C), (0x03AB, 0xA357), (0x03AC, 0xA35C),
+    (0x03AD, 0xA360), (0x03AE, 0xA362), (0x03AF, 0xA364), (0x03B0, 0xA36F), (0x03CA, 0xA364),
+    (0x03CB, 0xA36F), (0x03CC, 0xA36A), (0x03CD, 0xA36F), (0x03CE, 0xA373), (0x03D0, 0xA35D),
+    (0x03D1, 0xA363), (0x03D2, 0xA357), (0x03D3, 0xA357), (0x03D4, 0xA357), (0x03D5, 0xA370),
+    (0x03D6, 0xA36B), (0x03F0, 0xA365), (0x03F1, 0xA36C), (0x03F4, 0xA34B), (0x03F5, 0xA360),
+    (0x03F9, 0xA355), (0x1D2C, 0x0041), (0x1D2E, 0x0042), (0x1D30, 0x0044), (0x1D31, 0x0045),
+    (0x1D33, 0x0047), (0x1D34, 0x0048), (0x1D35, 0x0049), (0x1D36, 0x004A), (0x1D37, 0x004B),
+    (0x1D38, 0x004C), (0x1D39, 0x004D), (0x1D3A, 0x004E), (0x1D3C, 0x004F), (0x1D3E, 0x0050),
+    (0x1D3F, 0x0052), (0x1D40, 0x0054), (0x1D41, 0x0055), (0x1D42, 0x0057), (0x1D43, 0x0061),
+    (0x1D47, 0x0062), (0x1D48, 0x0064), (0x1D49, 0x0065), (0x1D4D, 0x0067), (0x1D4F, 0x006B),
+    (0x1D50, 0x006D), (0x1D52, 0x006F), (0x1D56, 0x0070), (0x1D57, 0x0074), (0x1D58, 0x0075),
+    (0x1D5B, 0x0076), (0x1D5D, 0xA35D), (0x1D5E, 0xA35E), (0x1D5F, 0xA35F), (0x1D60, 0xA370),
+    (0x1D61, 0xA371), (0x1D62, 0x0069), (0x1D63, 0x0072), (0x1D64, 0x0075), (0x1D65, 0x0076),
+    (0x1D66, 0xA35D), (0x1D67, 0xA35E), (0x1D68, 0xA36C), (0x1D69, 0xA370), (0x1D6A, 0xA371),
+    (0x1D9C, 0x0063), (0x1DA0, 0x0066), (0x1DBB, 0x007A), (0x1DBF, 0xA363), (0x1E00, 0x0041),
+    (0x1E01, 0x0061), (0x1E02, 0x0042), (0x1E03, 0x0062), (0x1E04, 0x0042), (0x1E05, 0x0062),
+    (0x1E06, 0x0042), (0x1E07, 0x0062), (0x1E08, 0x0043), (0x1E09, 0x0063), (0x1E0A, 0x0044),
+    (0x1E0B, 0x0064), (0x1E0C, 0x0044), (0x1E0D, 0x0064), (0x1E0E, 0x0044), (0x1E0F, 0x0064),
+    (0x1E10, 0x0044), (0x1E11, 0x0064), (0x1E12, 0x0044), (0x1E13, 0x0064), (0x1E14, 0x0045),
+    (0x1E15, 0x0065), (0x1E16, 0x0045), (0x1E17, 0x0065), (0x1E18, 0x0045), (0x1E19, 0x0065),
+    (0x1E1A, 0x0045), (0x1E1B, 0x0065), (0x1E1C, 0x0045), (0x1E1D, 0x0065), (0x1E1E, 0x0046),
+    (0x1E1F, 0x0066), (0x1E20, 0x0047), (0x1E21, 0x0067), (0x1E22, 0x0048), (0x1E23, 0x0068),
+    (0x1E24, 0x0048), (0x1E25, 0x0068), (0x1E26, 0x0048), (0x1E27, 0x0068), (0x1E28, 0x0048),
+    (0x1E29, 0x0068), (0x1E2A, 0x0048), (0x1E2B, 0x0068), (0x1E2C, 0x0049), (0x1E2D, 0x0069),
+    (0x1E2E, 0x0049), (0x1E2F, 0x0069), (0x1E30, 0x004B), (0x1E31, 0x006B), (0x1E32, 0x004B),
+    (0x1E33, 0x006B), (0x1E34, 0x004B), (0x1E35, 0x006B), (0x1E36, 0x004C), (0x1E37, 0x006C),
+    (0x1E38, 0x004C), (0x1E39, 0x006C), (0x1E3A, 0x004C), (0x1E3B, 0x006C), (0x1E3C, 0x004C),
+    (0x1E3D, 0x006C), (0x1E3E, 0x004D), (0x1E3F, 0x006D), (0x1E40, 0x004D), (0x1E41, 0x006D),
+    (0x1E42, 0x004D), (0x1E43, 0x006D), (0x1E44, 0x004E), (0x1E45, 0x006E), (0x1E46, 0x004E),
+    (0x1E47, 0x006E), (0x1E48, 0x004E), (0x1E49, 0x006E), (0x1E4A, 0x004E), (0x1E4B, 0x006E),
+    (0x1E4C, 0x004F), (0x1E4D, 0x006F), (0x1E4E, 0x004F), (0x1E4F, 0x006F), (0x1E50, 0x004F),
+    (0x1E51, 0x006F), (0x1E52, 0x004F), (0x1E53, 0x006F), (0x1E54, 0x0050), (0x1E55, 0x0070),
+    (0x1E56, 0x0050), (0x1E57, 0x0070), (0x1E58, 0x0052), (0x1E59, 0x0072), (0x1E5A, 0x0052),
+    (0x1E5B, 0x0072), (0x1E5C, 0x0052), (0x1E5D, 0x0072), (0x1E5E, 0x0052), (0x1E5F, 0x0072),
+    (0x1E60, 0x0053), (0x1E61, 0x0073), (0x1E62, 0x0053), (0x1E63, 0x0073), (0x1E64, 0x0053),
+    (0x1E65, 0x0073), (0x1E66, 0x0053), (0x1E67, 0x0073), (0x1E68, 0x0053), (0x1E69, 0x0073),
+    (0x1E6A, 0x0054), (0x1E6B, 0x0074), (0x1E6C, 0x0054), (0x1E6D, 0x0074), (0x1E6E, 0x0054),
+    (0x1E6F, 0x0074), (0x1E70, 0x0054), (0x1E71, 0x0074), (0x1E72, 0x0055), (0x1E73, 0x0075),
+    (0x1E74, 0x0055), (0x1E75, 0x0075), (0x1E76, 0x0055), (0x1E77, 0x0075), (0x1E78, 0x0055),
+    (0x1E79, 0x0075), (0x1E7A, 0x0055), (0x1E7B, 0x0075), (0x1E7C, 0x0056), (0x1E7D, 0x0076),
+    (0x1E7E, 0x0056), (0x1E7F, 0x0076), (0x1E80, 0x0057), (0x1E81, 0x0077), (0x1E82, 0x0057),
+    (0x1E83, 0x0077), (0x1E84, 0x0057), (0x1E85, 0x0077), (0x1E86, 0x0057), (0x1E87, 0x0077),
+    (0x1E88, 0x0057), (0x1E89, 0x0077), (0x1E8A, 0x0058), (0x1E8B, 0x0078), (0x1E8C, 0x0058),
+    (0x1E8D, 0x0078), (0x1E8E, 0x0059), (0x1E8F, 0x0079), (0x1E90, 0x005A), (0x1E91, 0x007A),
+    (0x1E92, 0x005A), (0x1E93, 0x007A), (0x1E94, 0x005A), (0x1E95, 0x007A), (0x1E96, 0x0068),
+    (0x1E97, 0x0074), (0x1E98, 0x0077), (0x1E99, 0x0079), (0x1E9B, 0x0073), (0x1EA0, 0x0041),
+    (0x1EA1, 0x0061), (0x1EA2, 0x0041), (0x1EA3, 0x0061), (0x1EA4, 0x0041), (0x1EA5, 0x0061),
+    (0x1EA6, 0x0041), (0x1EA7, 0x0061), (0x1EA8, 0x0041), (0x1EA9, 0x0061), (0x1EAA, 0x0041),
+    (0x1EAB, 0x0061), (0x1EAC, 0x0041), (0x1EAD, 0x0061), (0x1EAE, 0x0041), (0x1EAF, 0x0061),
+    (0x1EB0, 0x0041), (0x1EB1, 0x0061), (0x1EB2, 0x0041), (0x1EB3, 0x0061), (0x1EB4, 0x0041),
+    (0x1EB5, 0x0061), (0x1EB6, 0x0041), (0x1EB7, 0x0061), (0x1EB8, 0x0045), (0x1EB9, 0x0065),
+    (0x1EBA, 0x0045), (0x1EBB, 0x0065), (0x1EBC, 0x0045), (0x1EBD, 0x0065), (0x1EBE, 0x0045),
+    (0x1EBF, 0x0065), (0x1EC0, 0x0045), (0x1EC1, 0x0065), (0x1EC2, 0x0045), (0x1EC3, 0x0065),
+    (0x1EC4, 0x0045), (0x1EC5, 0x0065), (0x1EC6, 0x0045), (0x1EC7, 0x0065), (0x1EC8, 0x0049),
+    (0x1EC9, 0x0069), (0x1ECA, 0x0049), (0x1ECB, 0x0069), (0x1ECC, 0x004F), (0x1ECD, 0x006F),
+    (0x1ECE, 0x004F), (0x1ECF, 0x006F), (0x1ED0, 0x004F), (0x1ED1, 0x006F), (0x1ED2, 0x004F),
+    (0x1ED3, 0x006F), (0x1ED4, 0x004F), (0x1ED5, 0x006F), (0x1ED6, 0x004F), (0x1ED7, 0x006F),
+    (0x1ED8, 0x004F), (0x1ED9, 0x006F), (0x1EDA, 0x004F), (0x1EDB, 0x006F), (0x1EDC, 0x004F),
+    (0x1EDD, 0x006F), (0x1EDE, 0x004F), (0x1EDF, 0x006F), (0x1EE0, 0x004F), (0x1EE1, 0x006F),
+    (0x1EE2, 0x004F), (0x1EE3, 0x006F), (0x1EE4, 0x0055), (0x1EE5, 0x0075), (0x1EE6, 0x0055),
+    (0x1EE7, 0x0075), (0x1EE8, 0x0055), (0x1EE9, 0x0075), (0x1EEA, 0x0055), (0x1EEB, 0x0075),
+    (0x1EEC, 0x0055), (0x1EED, 0x0075), (0x1EEE, 0x0055), (0x1EEF, 0x0075), (0x1EF0, 0x0055),
+    (0x1EF1, 0x0075), (0x1EF2, 0x0059), (0x1EF3, 0x0079), (0x1EF4, 0x0059), (0x1EF5, 0x0079),
+    (0x1EF6, 0x0059), (0x1EF7, 0x0079), (0x1EF8, 0x0059), (0x1EF9, 0x0079), (0x1F00, 0xA35C),
+    (0x1F01, 0xA35C), (0x1F02, 0xA35C), (0x1F03, 0xA35C), (0x1F04, 0xA35C), (0x1F05, 0xA35C),
+    (0x1F06, 0xA35C), (0x1F07, 0xA35C), (0x1F08, 0xA344), (0x1F09, 0xA344), (0x1F0A, 0xA344),
+    (0x1F0B, 0xA344), (0x1F0C, 0xA344), (0x1F0D, 0xA344), (0x1F0E, 0xA344), (0x1F0F, 0xA344),
+    (0x1F10, 0xA360), (0x1F11, 0xA360), (0x1F12, 0xA360), (0x1F13, 0xA360), (0x1F14, 0xA360),
+    (0x1F15, 0xA360), (0x1F18, 0xA348), (0x1F19, 0xA348), (0x1F1A, 0xA348), (0x1F1B, 0xA348),
+    (0x1F1C, 0xA348), (0x1F1D, 0xA348), (0x1F20, 0xA362), (0x1F21, 0xA362), (0x1F22, 0xA362),
+    (0x1F23, 0xA362), (0x1F24, 0xA362), (0x1F25, 0xA362), (0x1F26, 0xA362), (0x1F27, 0xA362),
+    (0x1F28, 0xA34A), (0x1F29, 0xA34A), (0x1F2A, 0xA34A), (0x1F2B, 0xA34A), (0x1F2C, 0xA34A),
+    (0x1F2D, 0xA34A), (0x1F2E, 0xA34A), (0x1F2F, 0xA34A), (0x1F30, 0xA364), (0x1F31, 0xA364),
+    (0x1F32, 0xA364), (0x1F33, 0xA364), (0x1F34, 0xA364), (0x1F35, 0xA364), (0x1F36, 0xA364),
+    (0x1F37, 0xA364), (0x1F38, 0xA34C), (0x1F39, 0xA34C), (0x1F3A, 0xA34C), (0x1F3B, 0xA34C),
+    (0x1F3C, 0xA34C), (0x1F3D, 0xA34C), (0x1F3E, 0xA34C), (0x1F3F, 0xA34C), (0x1F40, 0xA36A),
+    (0x1F41, 0xA36A), (0x1F42, 0xA36A), (0x1F43, 0xA36A), (0x1F44, 0xA36A), (0x1F45, 0xA36A),
+    (0x1F48, 0xA352), (0x1F49, 0xA352), (0x1F4A, 0xA352), (0x1F4B, 0xA352), (0x1F4C, 0xA352),
+    (0x1F4D, 0xA352), (0x1F50, 0xA36F), (0x1F51, 0xA36F), (0x1F52, 0xA36F), (0x1F53, 0xA36F),
+    (0x1F54, 0xA36F), (0x1F55, 0xA36F), (0x1F56, 0xA36F), (0x1F57, 0xA36F), (0x1F59, 0xA357),
+    (0x1F5B, 0xA357), (0x1F5D, 0xA357), (0x1F5F, 0xA357), (0x1F60, 0xA373), (0x1F61, 0xA373),
+    (0x1F62, 0xA373), (0x1F63, 0xA373), (0x1F64, 0xA373), (0x1F65, 0xA373), (0x1F66, 0xA373),
+    (0x1F67, 0xA373), (0x1F68, 0xA35B), (0x1F69, 0xA35B), (0x1F6A, 0xA35B), (0x1F6B, 0xA35B),
+    (0x1F6C, 0xA35B), (0x1F6D, 0xA35B), (0x1F6E, 0xA35B), (0x1F6F, 0xA35B), (0x1F70, 0xA35C),
+    (0x1F71, 0xA35C), (0x1F72, 0xA360), (0x1F73, 0xA360), (0x1F74, 0xA362), (0x1F75, 0xA362),
+    (0x1F76, 0xA364), (0x1F77, 0xA364), (0x1F78, 0xA36A), (0x1F79, 0xA36A), (0x1F7A, 0xA36F),
+    (0x1F7B, 0xA36F), (0x1F7C, 0xA373), (0x1F7D, 0xA373), (0x1F80, 0xA35C), (0x1F81, 0xA35C),
+    (0x1F82, 0xA35C), (0x1F83, 0xA35C), (0x1F84, 0xA35C), (0x1F85, 0xA35C), (0x1F86, 0xA35C),
+    (0x1F87, 0xA35C), (0x1F88, 0xA344), (0x1F89, 0xA344), (0x1F8A, 0xA344), (0x1F8B, 0xA344),
+    (0x1F8C, 0xA344), (0x1F8D, 0xA344), (0x1F8E, 0xA344), (0x1F8F, 0xA344), (0x1F90, 0xA362),
+    (0x1F91, 0xA362), (0x1F92, 0xA362), (0x1F93, 0xA362), (0x1F94, 0xA362), (0x1F95, 0xA362),
+    (0x1F96, 0xA362), (0x1F97, 0xA362), (0x1F98, 0xA34A), (0x1F99, 0xA34A), (0x1F9A, 0xA34A),
+    (0x1F9B, 0xA34A), (0x1F9C, 0xA34A), (0x1F9D, 0xA34A), (0x1F9E, 0xA34A), (0x1F9F, 0xA34A),
+    (0x1FA0, 0xA373), (0x1FA1, 0xA373), (0x1FA2, 0xA373), (0x1FA3, 0xA373), (0x1FA4, 0xA373),
+    (0x1FA5, 0xA373), (0x1FA6, 0xA373), (0x1FA7, 0xA373), (0x1FA8, 0xA35B), (0x1FA9, 0xA35B),
+    (0x1FAA, 0xA35B), (0x1FAB, 0xA35B), (0x1FAC, 0xA35B), (0x1FAD, 0xA35B), (0x1FAE, 0xA35B),
+    (0x1FAF, 0xA35B), (0x1FB0, 0xA35C), (0x1FB1, 0xA35C), (0x1FB2, 0xA35C), (0x1FB3, 0xA35C),
+    (0x1FB4, 0xA35C), (0x1FB6, 0xA35C), (0x1FB7, 0xA35C), (0x1FB8, 0xA344), (0x1FB9, 0xA344),
+    (0x1FBA, 0xA344), (0x1FBB, 0xA344), (0x1FBC, 0xA344), (0x1FBE, 0xA364), (0x1FC2, 0xA362),
+    (0x1FC3, 0xA362), (0x1FC4, 0xA362), (0x1FC6, 0xA362), (0x1FC7, 0xA362), (0x1FC8, 0xA348),
+    (0x1FC9, 0xA348), (0x1FCA, 0xA34A), (0x1FCB, 0xA34A), (0x1FCC, 0xA34A), (0x1FD0, 0xA364),
+    (0x1FD1, 0xA364), (0x1FD2, 0xA364), (0x1FD3, 0xA364), (0x1FD6, 0xA364), (0x1FD7, 0xA364),
+    (0x1FD8, 0xA34C), (0x1FD9, 0xA34C), (0x1FDA, 0xA34C), (0x1FDB, 0xA34C), (0x1FE0, 0xA36F),
+    (0x1FE1, 0xA36F), (0x1FE2, 0xA36F), (0x1FE3, 0xA36F), (0x1FE4, 0xA36C), (0x1FE5, 0xA36C),
+    (0x1FE6, 0xA36F), (0x1FE7, 0xA36F), (0x1FE8, 0xA357), (0x1FE9, 0xA357), (0x1FEA, 0xA357),
+    (0x1FEB, 0xA357), (0x1FEC, 0xA354), (0x1FEF, 0x0060), (0x1FF2, 0xA373), (0x1FF3, 0xA373),
+    (0x1FF4, 0xA373), (0x1FF6, 0xA373), (0x1FF7, 0xA373), (0x1FF8, 0xA352), (0x1FF9, 0xA352),
+    (0x1FFA, 0xA35B), (0x1FFB, 0xA35B), (0x1FFC, 0xA35B), (0x2000, 0x0020), (0x2001, 0x0020),
+    (0x2002, 0x0020), (0x2003, 0x0020), (0x2004, 0x0020), (0x2005, 0x0020), (0x2006, 0x0020),
+    (0x2007, 0x0020), (0x2008, 0x0020), (0x2009, 0x0020), (0x200A, 0x0020), (0x2010, 0x002D),
+    (0x2012, 0x002D), (0x2015, 0x002D), (0x201A, 0x002C), (0x201B, 0x0027), (0x201E, 0x0022),
+    (0x201F, 0x0022), (0x2024, 0x002E), (0x202F, 0x0020), (0x2039, 0x003C), (0x203A, 0x003E),
+    (0x205F, 0x0020), (0x2070, 0x0030), (0x2071, 0x0069), (0x2074, 0x0034), (0x2075, 0x0035),
+    (0x2076, 0x0036), (0x2077, 0x0037), (0x2078, 0x0038), (0x2079, 0x0039), (0x207A, 0x002B),
+    (0x207C, 0x003D), (0x207D, 0x0028), (0x207E, 0x0029), (0x207F, 0x006E), (0x2080, 0x0030),
+    (0x2081, 0x0031), (0x2082, 0x0032), (0x2083, 0x0033), (0x2084, 0x0034), (0x2085, 0x0035),
+    (0x2086, 0x0036), (0x2087, 0x0037), (0x2088, 0x0038), (0x2089, 0x0039), (0x208A, 0x002B),
+    (0x208C, 0x003D), (0x208D, 0x0028), (0x208E, 0x0029), (0x2090, 0x0061), (0x2091, 0x0065),
+    (0x2092, 0x006F), (0x2093, 0x0078), (0x2095, 0x0068), (0x2096, 0x006B), (0x2097, 0x006C),
+    (0x2098, 0x006D), (0x2099, 0x006E), (0x209A, 0x0070), (0x209B, 0x0073), (0x209C, 0x0074),
+    (0x2102, 0x0043), (0x210A, 0x0067), (0x210B, 0x0048), (0x210C, 0x0048), (0x210D, 0x0048),
+    (0x210E, 0x0068), (0x2110, 0x0049), (0x2111, 0x0049), (0x2112, 0x004C), (0x2113, 0x006C),
+    (0x2115, 0x004E), (0x2119, 0x0050), (0x211A, 0x0051), (0x211B, 0x0052), (0x211C, 0x0052),
+    (0x211D, 0x0052), (0x2124, 0x005A), (0x2126, 0xA35B), (0x2128, 0x005A), (0x212A, 0x004B),
+    (0x212B, 0x0041), (0x212C, 0x0042), (0x212D, 0x0043), (0x212F, 0x0065), (0x2130, 0x0045),
+    (0x2131, 0x0046), (0x2133, 0x004D), (0x2134, 0x006F), (0x2139, 0x0069), (0x213C, 0xA36B),
+    (0x213D, 0xA35E), (0x213E, 0xA346), (0x213F, 0xA353), (0x2145, 0x0044), (0x2146, 0x0064),
+    (0x2147, 0x0065), (0x2148, 0x0069), (0x2149, 0x006A), (0x216C, 0x004C), (0x216D, 0x0043),
+    (0x216E, 0x0044), (0x216F, 0x004D), (0x2170, 0x0069), (0x2174, 0x0076), (0x2179, 0x0078),
+    (0x217C, 0x006C), (0x217D, 0x0063), (0x217E, 0x0064), (0x217F, 0x006D), (0x219A, 0xA1F6),
+    (0x219B, 0xA1F7), (0x2212, 0x002D), (0x2216, 0x005C), (0x2224, 0xA1FD), (0x2226, 0xA1FC),
+    (0x223C, 0x007E), (0x2262, 0xA1DD), (0x226E, 0x003C), (0x226F, 0x003E), (0x2329, 0xA171),
+    (0x232A, 0xA172), (0x2460, 0x0031), (0x2461, 0x0032), (0x2462, 0x0033), (0x2463, 0x0034),
+    (0x2464, 0x0035), (0x2465, 0x0036), (0x2466, 0x0037), (0x2467, 0x0038), (0x2468, 0x0039),
+    (0x24B6, 0x0041), (0x24B7, 0x0042), (0x24B8, 0x0043), (0x24B9, 0x0044), (0x24BA, 0x0045),
+    (0x24BB, 0x0046), (0x24BC, 0x0047), (0x24BD, 0x0048), (0x24BE, 0x0049), (0x24BF, 0x004A),
+    (0x24C0, 0x004B), (0x24C1, 0x004C), (0x24C2, 0x004D), (0x24C3, 0x004E), (0x24C4, 0x004F),
+    (0x24C5, 0x0050), (0x24C6, 0x0051), (0x24C7, 0x0052), (0x24C8, 0x0053), (0x24C9, 0x0054),
+    (0x24CA, 0x0055), (0x24CB, 0x0056), (0x24CC, 0x0057), (0x24CD, 0x0058), (0x24CE, 0x0059),
+    (0x24CF, 0x005A), (0x24D0, 0x0061), (0x24D1, 0x0062), (0x24D2, 0x0063), (0x24D3, 0x0064),
+    (0x24D4, 0x0065), (0x24D5, 0x0066), (0x24D6, 0x0067), (0x24D7, 0x0068), (0x24D8, 0x0069),
+    (0x24D9, 0x006A), (0x24DA, 0x006B), (0x24DB, 0x006C), (0x24DC, 0x006D), (0x24DD, 0x006E),
+    (0x24DE, 0x006F), (0x24DF, 0x0070), (0x24E0, 0x0071), (0x24E1, 0x0072), (0x24E2, 0x0073),
+    (0x24E3, 0x0074), (0x24E4, 0x0075), (0x24E5, 0x0076), (0x24E6, 0x0077), (0x24E7, 0x0078),
+    (0x24E8, 0x0079), (0x24E9, 0x007A), (0x24EA, 0x0030), (0x2C7C, 0x006A), (0x2C7D, 0x0056),
+    (0x2E9F, 0xA5C0), (0x2F00, 0xA440), (0x2F04, 0xA441), (0x2F06, 0xA447), (0x2F08, 0xA448),
+    (0x2F09, 0xA449), (0x2F0A, 0xA44A), (0x2F0B, 0xA44B), (0x2F0F, 0xA44C), (0x2F10, 0xC942),
+    (0x2F11, 0xA44D), (0x2F12, 0xA44F), (0x2F14, 0xA450), (0x2F15, 0xC943), (0x2F17, 0xA451),
+    (0x2F18, 0xA452), (0x2F1A, 0xC944), (0x2F1C, 0xA453), (0x2F1D, 0xA466), (0x2F1E, 0xC949),
+    (0x2F1F, 0xA467), (0x2F20, 0xA468), (0x2F23, 0xA469), (0x2F24, 0xA46A), (0x2F25, 0xA46B),
+    (0x2F26, 0xA46C), (0x2F28, 0xA46F), (0x2F29, 0xA470), (0x2F2A, 0xA471), (0x2F2B, 0xA472),
+    (0x2F2C, 0xC94B), (0x2F2D, 0xA473), (0x2F2F, 0xA475), (0x2F30, 0xA476), (0x2F31, 0xA479),
+    (0x2F32, 0xA47A), (0x2F36, 0xA47B), (0x2F37, 0xA47C), (0x2F38, 0xA47D), (0x2F3B, 0xC94C),
+    (0x2F3C, 0xA4DF), (0x2F3D, 0xA4E0), (0x2F3E, 0xA4E1), (0x2F3F, 0xA4E2), (0x2F40, 0xA4E4),
+    (0x2F42, 0xA4E5), (0x2F43, 0xA4E6), (0x2F44, 0xA4E7), (0x2F45, 0xA4E8), (0x2F47, 0xA4E9),
+    (0x2F48, 0xA4EA), (0x2F49, 0xA4EB), (0x2F4A, 0xA4EC), (0x2F4B, 0xA4ED), (0x2F4C, 0xA4EE),
+    (0x2F4D, 0xA4EF), (0x2F4E, 0xC95F), (0x2F4F, 0xA4F0), (0x2F50, 0xA4F1), (0x2F51, 0xA4F2),
+    (0x2F52, 0xA4F3), (0x2F53, 0xC961), (0x2F54, 0xA4F4), (0x2F55, 0xA4F5), (0x2F56, 0xA4F6),
+    (0x2F57, 0xA4F7), (0x2F58, 0xA4F8), (0x2F59, 0xC962), (0x2F5A, 0xA4F9), (0x2F5B, 0xA4FA),
+    (0x2F5C, 0xA4FB), (0x2F5D, 0xA4FC), (0x2F5E, 0xA5C8), (0x2F5F, 0xA5C9), (0x2F60, 0xA5CA),
+    (0x2F61, 0xA5CB), (0x2F62, 0xA5CC), (0x2F63, 0xA5CD), (0x2F64, 0xA5CE), (0x2F65, 0xA5D0),
+    (0x2F66, 0xA5D4), (0x2F69, 0xA5D5), (0x2F6A, 0xA5D6), (0x2F6B, 0xA5D7), (0x2F6C, 0xA5D8),
+    (0x2F6D, 0xA5D9), (0x2F6E, 0xA5DA), (0x2F6F, 0xA5DB), (0x2F70, 0xA5DC), (0x2F71, 0xC9A8),
+    (0x2F72, 0xA5DD), (0x2F73, 0xA5DE), (0x2F74, 0xA5DF), (0x2F75, 0xA6CB), (0x2F76, 0xA6CC),
+    (0x2F77, 0xA6CD), (0x2F78, 0xA6CE), (0x2F79, 0xCA49), (0x2F7A, 0xA6CF), (0x2F7B, 0xA6D0),
+    (0x2F7C, 0xA6D1), (0x2F7D, 0xA6D3), (0x2F7E, 0xA6D4), (0x2F7F, 0xA6D5), (0x2F80, 0xA6D6),
+    (0x2F81, 0xA6D7), (0x2F82, 0xA6DA), (0x2F83, 0xA6DB), (0x2F84, 0xA6DC), (0x2F85, 0xA6DD),
+    (0x2F86, 0xA6DE), (0x2F87, 0xA6DF), (0x2F88, 0xA6E0), (0x2F89, 0xA6E1), (0x2F8A, 0xA6E2),
+    (0x2F8B, 0xCA4A), (0x2F8C, 0xCA4F), (0x2F8D, 0xA6E4), (0x2F8E, 0xA6E5), (0x2F8F, 0xA6E6),
+    (0x2F90, 0xA6E7), (0x2F91, 0xCA50), (0x2F92, 0xA8A3), (0x2F93, 0xA8A4), (0x2F94, 0xA8A5),
+    (0x2F95, 0xA8A6), (0x2F96, 0xA8A7), (0x2F97, 0xA8A8), (0x2F98, 0xCBA4), (0x2F99, 0xA8A9),
+    (0x2F9A, 0xA8AA), (0x2F9B, 0xA8AB), (0x2F9C, 0xA8AC), (0x2F9D, 0xA8AD), (0x2F9E, 0xA8AE),
+    (0x2F9F, 0xA8AF), (0x2FA0, 0xA8B0), (0x2FA2, 0xA8B6), (0x2FA3, 0xA8BB), (0x2FA4, 0xA8BC),
+    (0x2FA5, 0xA8BD), (0x2FA6, 0xAAF7), (0x2FA7, 0xAAF8), (0x2FA8, 0xAAF9), (0x2FA9, 0xAAFA),
+    (0x2FAB, 0xAB41), (0x2FAC, 0xAB42), (0x2FAE, 0xAB44), (0x2FAF, 0xADB1), (0x2FB0, 0xADB2),
+    (0x2FB1, 0xADB3), (0x2FB2, 0xADB4), (0x2FB3, 0xADB5), (0x2FB4, 0xADB6), (0x2FB5, 0xADB7),
+    (0x2FB6, 0xADB8), (0x2FB7, 0xADB9), (0x2FB8, 0xADBA), (0x2FB9, 0xADBB), (0x2FBA, 0xB0A8),
+    (0x2FBB, 0xB0A9), (0x2FBC, 0xB0AA), (0x2FBD, 0xD449), (0x2FBE, 0xB0AB), (0x2FBF, 0xD44A),
+    (0x2FC0, 0xB0AC), (0x2FC1, 0xB0AD), (0x2FC2, 0xB3BD), (0x2FC3, 0xB3BE), (0x2FC4, 0xB3BF),
+    (0x2FC5, 0xB3C0), (0x2FC6, 0xB3C1), (0x2FC7, 0xB3C2), (0x2FC8, 0xB6C0), (0x2FC9, 0xB6C1),
+    (0x2FCA, 0xB6C2), (0x2FCB, 0xDCB0), (0x2FCC, 0xE0EF), (0x2FCD, 0xB9A9), (0x2FCE, 0xB9AA),
+    (0x2FCF, 0xB9AB), (0x2FD0, 0xBBF3), (0x2FD1, 0xBBF4), (0x2FD2, 0xBEA6), (0x2FD3, 0xC073),
+    (0x2FD4, 0xC074), (0x2FD5, 0xEFB6), (0x3036, 0xA245), (0x3038, 0xA451), (0x3039, 0xA2CD),
+    (0x303A, 0xA4CA), (0x3192, 0xA440), (0x3193, 0xA447), (0x3194, 0xA454), (0x3195, 0xA57C),
+    (0x3196, 0xA457), (0x3197, 0xA4A4), (0x3198, 0xA455), (0x3199, 0xA5D2), (0x319A, 0xA441),
+    (0x319B, 0xA4FE), (0x319C, 0xA442), (0x319D, 0xA4D1), (0x319E, 0xA661), (0x319F, 0xA448),
+    (0x3244, 0xB0DD), (0x3245, 0xA5AE), (0x3246, 0xA4E5), (0x3247, 0xBAE5), (0x3280, 0xA440),
+    (0x3281, 0xA447), (0x3282, 0xA454), (0x3283, 0xA57C), (0x3284, 0xA4AD), (0x3285, 0xA4BB),
+    (0x3286, 0xA443), (0x3287, 0xA44B), (0x3288, 0xA445), (0x3289, 0xA451), (0x328A, 0xA4EB),
+    (0x328B, 0xA4F5), (0x328C, 0xA4F4), (0x328D, 0xA4EC), (0x328E, 0xAAF7), (0x328F, 0xA467),
+    (0x3290, 0xA4E9), (0x3291, 0xAEE8), (0x3292, 0xA6B3), (0x3293, 0xAAC0), (0x3294, 0xA657),
+    (0x3295, 0xAF53), (0x3296, 0xB05D), (0x3297, 0xAFAC), (0x3299, 0xAFB5), (0x329A, 0xA86B),
+    (0x329B, 0xA46B), (0x329C, 0xBE41), (0x329D, 0xC075), (0x329E, 0xA64C), (0x329F, 0xAA60),
+    (0x32A0, 0xB6B5), (0x32A1, 0xA5F0), (0x32A4, 0xA457), (0x32A5, 0xA4A4), (0x32A6, 0xA455),
+    (0x32A7, 0xA5AA), (0x32A8, 0xA56B), (0x32AA, 0xA976), (0x32AC, 0xBACA), (0x32AD, 0xA5F8),
+    (0x32AE, 0xB8EA), (0x32AF, 0xA8F3), (0x32B0, 0xA95D), (0xA7F2, 0x0043), (0xA7F3, 0x0046),
+    (0xA7F4, 0x0051), (0xF900, 0xB05A), (0xF901, 0xA7F3), (0xF902, 0xA8AE), (0xF903, 0xB8EB),
+    (0xF904, 0xB7C6), (0xF905, 0xA6EA), (0xF906, 0xA579), (0xF907, 0xC074), (0xF908, 0xC074),
+    (0xF909, 0xABB4), (0xF90A, 0xAAF7), (0xF90B, 0xB3E2), (0xF90C, 0xA960), (0xF90D, 0xC369),
+    (0xF90E, 0xC4EE), (0xF90F, 0xC3B9), (0xF910, 0xC5DA), (0xF911, 0xC1B3), (0xF912, 0xBB72),
+    (0xF913, 0xC5DE), (0xF914, 0xBCD6), (0xF915, 0xACA5), (0xF916, 0xAF4F), (0xF917, 0xAF5F),
+    (0xF918, 0xB8A8), (0xF919, 0xB954), (0xF91A, 0xC064), (0xF91B, 0xB6C3), (0xF91C, 0xA75A),
+    (0xF91D, 0xC4E6), (0xF91E, 0xC4EA), (0xF91F, 0xC4F5), (0xF920, 0xC67D), (0xF921, 0xB450),
+    (0xF922, 0xC0DD), (0xF923, 0xC2C5), (0xF924, 0xC4B0), (0xF925, 0xA9D4), (0xF926, 0xC3BE),
+    (0xF927, 0xC4FA), (0xF928, 0xB459), (0xF929, 0xAED4), (0xF92A, 0xAEF6), (0xF92B, 0xAF54),
+    (0xF92C, 0xADA6), (0xF92D, 0xA8D3), (0xF92E, 0xA74E), (0xF92F, 0xB3D2), (0xF930, 0xBEDB),
+    (0xF931, 0xC372), (0xF932, 0xC46C), (0xF933, 0xBF63), (0xF934, 0xA6D1), (0xF935, 0xC4AA),
+    (0xF936, 0xB8B8), (0xF937, 0xB8F4), (0xF938, 0xC553), (0xF939, 0xBE7C), (0xF93A, 0xC64F),
+    (0xF93B, 0xB84C), (0xF93C, 0xB853), (0xF93D, 0xBAF1), (0xF93E, 0xDB77), (0xF93F, 0xBFFD),
+    (0xF940, 0xB3C0), (0xF941, 0xBDD7), (0xF942, 0xC362), (0xF943, 0xA7CB), (0xF944, 0xC5A2),
+    (0xF945, 0xC5A4), (0xF946, 0xA863), (0xF947, 0xBD55), (0xF948, 0xB8EF), (0xF949, 0xB970),
+    (0xF94A, 0xC253), (0xF94B, 0xB9F0), (0xF94C, 0xBCD3), (0xF94D, 0xB25C), (0xF94E, 0xBA7C),
+    (0xF94F, 0xB2D6), (0xF950, 0xC15C), (0xF951, 0xADAE), (0xF952, 0xB0C7), (0xF953, 0xA6D8),
+    (0xF954, 0xBBFE), (0xF955, 0xADE2), (0xF956, 0xB857), (0xF957, 0xBAF0), (0xF958, 0xB5D9),
+    (0xF959, 0xB3AE), (0xF95A, 0xC5AA), (0xF95B, 0xCED4), (0xF95C, 0xBCD6), (0xF95D, 0xBFD5),
+    (0xF95E, 0xA4A6), (0xF95F, 0xB9E7), (0xF960, 0xABE3), (0xF961, 0xB276), (0xF962, 0xB2A7),
+    (0xF963, 0xA55F), (0xF964, 0xEDA8), (0xF965, 0xAB4B), (0xF966, 0xB45F), (0xF967, 0xA4A3),
+    (0xF968, 0xAA63), (0xF969, 0xBCC6), (0xF96A, 0xAFC1), (0xF96B, 0xB0D1), (0xF96C, 0xB6EB),
+    (0xF96D, 0xACD9), (0xF96E, 0xB8AD), (0xF96F, 0xBBA1), (0xF970, 0xB1FE), (0xF971, 0xA8B0),
+    (0xF972, 0xA848), (0xF973, 0xAC42), (0xF974, 0xAD59), (0xF975, 0xB1B0), (0xF976, 0xB2A4),
+    (0xF977, 0xAB47), (0xF978, 0xA8E2), (0xF97A, 0xB1E7), (0xF97B, 0xC2B3), (0xF97C, 0xA87D),
+    (0xF97D, 0xBDCC), (0xF97E, 0xB671), (0xF97F, 0xC079), (0xF980, 0xA766), (0xF981, 0xA46B),
+    (0xF982, 0xC366), (0xF983, 0xAEC8), (0xF984, 0xC26F), (0xF985, 0xC472), (0xF986, 0xBE5B),
+    (0xF987, 0xC67A), (0xF988, 0xC452), (0xF989, 0xBEA4), (0xF98A, 0xA44F), (0xF98B, 0xBEE4),
+    (0xF98C, 0xBEFA), (0xF98D, 0xF765), (0xF98E, 0xA67E), (0xF98F, 0xBCA6), (0xF990, 0xC5CA),
+    (0xF991, 0xBCBF), (0xF992, 0xBAA7), (0xF993, 0xB7D2), (0xF994, 0xE6A3), (0xF996, 0xBD6D),
+    (0xF997, 0xC170), (0xF998, 0xBDFB), (0xF999, 0xBDAC), (0xF99A, 0xB373), (0xF99B, 0xC1E5),
+    (0xF99C, 0xA643), (0xF99D, 0xA648), (0xF99E, 0xAB7C), (0xF99F, 0xAF50), (0xF9A0, 0xB5F5),
+    (0xF9A1, 0xBBA1), (0xF9A2, 0xB747), (0xF9A3, 0xA9C0), (0xF9A4, 0xB1C9), (0xF9A5, 0xC0D4),
+    (0xF9A6, 0xC3AE), (0xF9A7, 0xC279), (0xF9A8, 0xA54F), (0xF9A9, 0xCBF1), (0xF9AA, 0xB9E7),
+    (0xF9AB, 0xC0AD), (0xF9AC, 0xCCB0), (0xF9AD, 0xACC2), (0xF9AE, 0xBCFC), (0xF9AF, 0xB2DC),
+    (0xF9B0, 0xB2E2), (0xF9B1, 0xB961), (0xF9B2, 0xB973), (0xF9B3, 0xC646), (0xF9B4, 0xBBE2),
+    (0xF9B5, 0xA8D2), (0xF9B6, 0xC2A7), (0xF9B7, 0xC4BF), (0xF9B8, 0xC1F5), (0xF9B9, 0xB463),
+    (0xF9BA, 0xA446), (0xF9BB, 0xB9B1), (0xF9BC, 0xBC64), (0xF9BD, 0xA7BF), (0xF9BE, 0xAEC6),
+    (0xF9BF, 0xBCD6), (0xF9C0, 0xBF52), (0xF9C1, 0xC0F8), (0xF9C2, 0xE764), (0xF9C3, 0xBFF1),
+    (0xF9C4, 0xC073), (0xF9C5, 0xB777), (0xF9C6, 0xA8BF), (0xF9C7, 0xBC42), (0xF9C8, 0xCCD8),
+    (0xF9C9, 0xAC68), (0xF9CA, 0xAC79), (0xF9CB, 0xB7C8), (0xF9CC, 0xAF5B), (0xF9CD, 0xAF64),
+    (0xF9CE, 0xB2B8), (0xF9CF, 0xAFC3), (0xF9D0, 0xC3FE), (0xF9D1, 0xA4BB), (0xF9D2, 0xBCAE),
+    (0xF9D3, 0xB3B0), (0xF9D4, 0xADDB), (0xF9D5, 0xB15B), (0xF9D6, 0xB25F), (0xF9D7, 0xBDFC),
+    (0xF9D8, 0xABDF), (0xF9D9, 0xB758), (0xF9DA, 0xAEDF), (0xF9DB, 0xB276), (0xF9DC, 0xB6A9),
+    (0xF9DD, 0xA751), (0xF9DE, 0xA64F), (0xF9DF, 0xBC69), (0xF9E0, 0xA9F6), (0xF9E1, 0xA7F5),
+    (0xF9E2, 0xB1F9), (0xF9E3, 0xAA64), (0xF9E4, 0xB27A), (0xF9E5, 0xB567), (0xF9E6, 0xBFA9),
+    (0xF9E7, 0xF9D8), (0xF9E8, 0xB8CC), (0xF9E9, 0xA8BD), (0xF9EA, 0xC2F7), (0xF9EB, 0xB0CE),
+    (0xF9EC, 0xB7C4), (0xF9ED, 0xA75B), (0xF9EE, 0xBF4D), (0xF9EF, 0xBF5A), (0xF9F0, 0xC4A9),
+    (0xF9F2, 0xC5EC), (0xF9F3, 0xC5EF), (0xF9F4, 0xAA4C), (0xF9F5, 0xB24F), (0xF9F6, 0xC17B),
+    (0xF9F7, 0xA5DF), (0xF9F8, 0xB2C1), (0xF9F9, 0xB2C9), (0xF9FA, 0xAAAC), (0xF9FB, 0xAAA5),
+    (0xF9FC, 0xC3D1), (0xF9FD, 0xA4B0), (0xF9FE, 0xAFF9), (0xF9FF, 0xA8EB), (0xFA00, 0xA4C1),
+    (0xFA01, 0xABD7), (0xFA02, 0xA9DD), (0xFA03, 0xBF7D), (0xFA04, 0xA676), (0xFA05, 0xAC7D),
+    (0xFA06, 0xBCC9), (0xFA07, 0xBFE7), (0xFA08, 0xA6E6), (0xFA09, 0xADB0), (0xFA0A, 0xA8A3),
+    (0xFA0B, 0xB9F8), (0xFA10, 0xB6EF), (0xFA12, 0xB4B8), (0xFA15, 0xE8F9), (0xFA17, 0xAF71),
+    (0xFA19, 0xAFAB), (0xFA1A, 0xB2BB), (0xFA1B, 0xBAD6), (0xFA1C, 0xB974), (0xFA1D, 0xBAEB),
+    (0xFA1E, 0xA6D0), (0xFA22, 0xBDD1), (0xFA25, 0xB668), (0xFA26, 0xB3A3), (0xFA2A, 0xB6BA),
+    (0xFA2B, 0xB97D), (0xFA2C, 0xC05D), (0xFA2D, 0xC562), (0xFA30, 0xAB56), (0xFA31, 0xB9AC),
+    (0xFA32, 0xA74B), (0xFA33, 0xAB6A), (0xFA34, 0xB6D4), (0xFA35, 0xA8F5), (0xFA36, 0xB3DC),
+    (0xFA37, 0xB9C4), (0xFA38, 0xBEB9), (0xFA3A, 0xBEA5), (0xFA3B, 0xBC68), (0xFA3C, 0xC94B),
+    (0xFA3D, 0xAEAC), (0xFA3E, 0xB46E), (0xFA3F, 0xBCA8), (0xFA40, 0xC367), (0xFA41, 0xB1D3),
+    (0xFA42, 0xAC4A), (0xFA43, 0xB4BB), (0xFA44, 0xB1F6), (0xFA45, 0xAEFC), (0xFA46, 0xB25A),
+    (0xFA47, 0xBA7E), (0xFA48, 0xB54E), (0xFA4A, 0xB55A), (0xFA4B, 0xB84F), (0xFA4C, 0xAAC0),
+    (0xFA4D, 0xACE7), (0xFA4E, 0xACE8), (0xFA4F, 0xAFA7), (0xFA50, 0xAFAA), (0xFA51, 0xAFAC),
+    (0xFA52, 0xBAD7), (0xFA53, 0xBAD5), (0xFA54, 0xBD5C), (0xFA55, 0xACF0), (0xFA56, 0xB860),
+    (0xFA57, 0xBD6D), (0xFA58, 0xBFA7), (0xFA59, 0xC163), (0xFA5A, 0xB870), (0xFA5B, 0xAACC),
+    (0xFA5C, 0xAFE4), (0xFA5F, 0xB5DB), (0xFA60, 0xBDC5), (0xFA61, 0xB5F8), (0xFA62, 0xBFD6),
+    (0xFA63, 0xC2D4), (0xFA64, 0xBBAB), (0xFA65, 0xC3D8), (0xFA67, 0xB668), (0xFA68, 0xC3F8),
+    (0xFA69, 0xC554), (0xFA6A, 0xC057), (0xFA70, 0xA8C3), (0xFA72, 0xA5FE), (0xFA73, 0xCBB6),
+    (0xFA74, 0xA552), (0xFA75, 0xBEAC), (0xFA76, 0xAB69), (0xFA77, 0xA463), (0xFA78, 0xB3DC),
+    (0xFA79, 0xB0DE), (0xFA7A, 0xB3F1), (0xFA7B, 0xDCDA), (0xFA7C, 0xB6EF), (0xFA7D, 0xBC58),
+    (0xFA7E, 0xA961), (0xFA7F, 0xA962), (0xFA80, 0xB141), (0xFA81, 0xE959), (0xFA82, 0xE1BC),
+    (0xFA83, 0xE1BB), (0xFA84, 0xB16D), (0xFA85, 0xDD64), (0xFA86, 0xB1A6), (0xFA87, 0xB756),
+    (0xFA88, 0xB755), (0xFA89, 0xBCA8), (0xFA8B, 0xC367), (0xFA8C, 0xC0B9), (0xFA8D, 0xD967),
+    (0xFA8E, 0xB76A), (0xFA8F, 0xB4AC), (0xFA90, 0xB1CE), (0xFA91, 0xB4B8), (0xFA92, 0xAED4),
+    (0xFA93, 0xB1E6), (0xFA94, 0xA7FA), (0xFA95, 0xA4EF), (0xFA96, 0xB1FE), (0xFA97, 0xAC79),
+    (0xFA99, 0xB4FE), (0xFA9A, 0xBA7E), (0xFA9C, 0xB54E), (0xFA9D, 0xC140), (0xFA9E, 0xC0EF),
+    (0xFA9F, 0xA5C7), (0xFAA1, 0xE2D0), (0xFAA4, 0xE6B2), (0xFAA5, 0xBD45), (0xFAA6, 0xAF71),
+    (0xFAA7, 0xB2B1), (0xFAA8, 0xAABD), (0xFAA9, 0xDABF), (0xFAAB, 0xE6C3), (0xFAAC, 0xEAB5),
+    (0xFAAD, 0xB860), (0xFAAF, 0xB86C), (0xFAB0, 0xBD6D), (0xFAB1, 0xDAFC), (0xFAB2, 0xAACC),
+    (0xFAB3, 0xAFEE), (0xFAB4, 0xB5D8), (0xFAB5, 0xEB64), (0xFAB6, 0xEE75), (0xFAB7, 0xC2D0),
+    (0xFAB8, 0xB5F8), (0xFAB9, 0xBDD5), (0xFABA, 0xBDD1), (0xFABB, 0xBDD0), (0xFABC, 0xBFD6),
+    (0xFABD, 0xBFD5), (0xFABE, 0xBFD9), (0xFABF, 0xC2D4), (0xFAC0, 0xC5DC), (0xFAC1, 0xC3D8),
+    (0xFAC2, 0xBFE9), (0xFAC3, 0xBFF0), (0xFAC4, 0xEEC8), (0xFAC5, 0xE478), (0xFAC6, 0xD847),
+    (0xFAC7, 0xC3F8), (0xFAC8, 0xB974), (0xFACA, 0xC554), (0xFACC, 0xC057), (0xFACD, 0xF568),
+    (0xFACE, 0xC074), (0xFAD8, 0xF87D), (0xFB29, 0x002B), (0xFE10, 0x002C), (0xFE11, 0xA142),
+    (0xFE12, 0xA143), (0xFE13, 0x003A), (0xFE14, 0x003B), (0xFE15, 0x0021), (0xFE16, 0x003F),
+    (0xFE32, 0xA156), (0xFE47, 0x005B), (0xFE48, 0x005D), (0xFE58, 0xA158), (0xFF02, 0x0022),
+    (0xFF07, 0x0027), (0xFF3B, 0x005B), (0xFF3D, 0x005D), (0xFF3E, 0x005E), (0xFF40, 0x0060),
+    (0xFF61, 0xA143), (0xFF62, 0xA175), (0xFF63, 0xA176), (0xFF64, 0xA142), (0xFFE8, 0xA278),
+    (0xFFE9, 0xA1F6), (0xFFEA, 0xA1F4), (0xFFEB, 0xA1F7), (0xFFEC, 0xA1F5), (0xFFED, 0xA1BD),
+    (0xFFEE, 0xA1B3),
+];
+
+/// Code page 1250.
+#[rustfmt::skip]
+pub(crate) const CP1250: [(u16, u16); 788] = [
+    (0x00AA, 0x0061), (0x00B2, 0x0032), (0x00B3, 0x0033), (0x00B9, 0x0031), (0x00BA, 0x006F),
+    (0x00C0, 0x0041), (0x00C3, 0x0041), (0x00C5, 0x0041), (0x00C8, 0x0045), (0x00CA, 0x0045),
+    (0x00CC, 0x0049), (0x00CF, 0x0049), (0x00D1, 0x004E), (0x00D2, 0x004F), (0x00D5, 0x004F),
+    (0x00D9, 0x0055), (0x00DB, 0x0055), (0x00E0, 0x0061), (0x00E3, 0x0061), (0x00E5, 0x0061),
+    (0x00E8, 0x0065), (0x00EA, 0x0065), (0x00EC, 0x0069), (0x00EF, 0x0069), (0x00F1, 0x006E),
+    (0x00F2, 0x006F), (0x00F5, 0x006F), (0x00F9, 0x0075), (0x00FB, 0x0075), (0x00FF, 0x0079),
+    (0x0100, 0x0041), (0x0101, 0x0061), (0x0108, 0x0043), (0x0109, 0x0063), (0x010A, 0x0043),
+    (0x010B, 0x0063), (0x0112, 0x0045), (0x0113, 0x0065), (0x0114, 0x0045), (0x0115, 0x0065),
+    (0x0116, 0x0045), (0x0117, 0x0065), (0x011C, 0x0047), (0x011D, 0x0067), (0x011E, 0x0047),
+    (0x011F, 0x0067), (0x0120, 0x0047), (0x0121, 0x0067), (0x0122, 0x0047), (0x0123, 0x0067),
+    (0x0124, 0x0048), (0x0125, 0x0068), (0x0128, 0x0049), (0x0129, 0x0069), (0x012A, 0x0049),
+    (0x012B, 0x0069), (0x012C, 0x0049), (0x012D, 0x0069), (0x012E, 0x0049), (0x012F, 0x0069),
+    (0x0130, 0x0049), (0x0134, 0x004A), (0x0135, 0x006A), (0x0136, 0x004B), (0x0137, 0x006B),
+    (0x013B, 0x004C), (0x013C, 0x006C), (0x0145, 0x004E), (0x0146, 0x006E), (0x014C, 0x004F),
+    (0x014D, 0x006F), (0x014E, 0x004F), (0x014F, 0x006F), (0x0156, 0x0052), (0x0157, 0x0072),
+    (0x015C, 0x0053), (0x015D, 0x0073), (0x0168, 0x0055), (0x0169, 0x0075), (0x016A, 0x0055),
+    (0x016B, 0x0075), (0x016C, 0x0055), (0x016D, 0x0075), (0x0172, 0x0055), (0x0173, 0x0075),
+    (0x0174, 0x0057), (0x0175, 0x0077), (0x0176, 0x0059), (0x0177, 0x0079), (0x0178, 0x0059),
+    (0x017F, 0x0073), (0x01A0, 0x004F), (0x01A1, 0x006F), (0x01AF, 0x0055), (0x01B0, 0x0075),
+    (0x01CD, 0x0041), (0x01CE, 0x0061), (0x01CF, 0x0049), (0x01D0, 0x0069), (0x01D1, 0x004F),
+    (0x01D2, 0x006F), (0x01D3, 0x0055), (0x01D4, 0x0075), (0x01D5, 0x0055), (0x01D6, 0x0075),
+    (0x01D7, 0x0055), (0x01D8, 0x0075), (0x01D9, 0x0055), (0x01DA, 0x0075), (0x01DB, 0x0055),
+    (0x01DC, 0x0075), (0x01DE, 0x0041), (0x01DF, 0x0061), (0x01E0, 0x0041), (0x01E1, 0x0061),
+    (0x01E6, 0x0047), (0x01E7, 0x0067), (0x01E8, 0x004B), (0x01E9, 0x006B), (0x01EA, 0x004F),
+    (0x01EB, 0x006F), (0x01EC, 0x004F), (0x01ED, 0x006F), (0x01F0, 0x006A), (0x01F4, 0x0047),
+    (0x01F5, 0x0067), (0x01F8, 0x004E), (0x01F9, 0x006E), (0x01FA, 0x0041), (0x01FB, 0x0061),
+    (0x0200, 0x0041), (0x0201, 0x0061), (0x0202, 0x0041), (0x0203, 0x0061), (0x0204, 0x0045),
+    (0x0205, 0x0065), (0x0206, 0x0045), (0x0207, 0x0065), (0x0208, 0x0049), (0x0209, 0x0069),
+    (0x020A, 0x0049), (0x020B, 0x0069), (0x020C, 0x004F), (0x020D, 0x006F), (0x020E, 0x004F),
+    (0x020F, 0x006F), (0x0210, 0x0052), (0x0211, 0x0072), (0x0212, 0x0052), (0x0213, 0x0072),
+    (0x0214, 0x0055), (0x0215, 0x0075), (0x0216, 0x0055), (0x0217, 0x0075), (0x0218, 0x0053),
+    (0x0219, 0x0073), (0x021A, 0x0054), (0x021B, 0x0074), (0x021E, 0x0048), (0x021F, 0x0068),
+    (0x0226, 0x0041), (0x0227, 0x0061), (0x0228, 0x0045), (0x0229, 0x0065), (0x022A, 0x004F),
+    (0x022B, 0x006F), (0x022C, 0x004F), (0x022D, 0x006F), (0x022E, 0x004F), (0x022F, 0x006F),
+    (0x0230, 0x004F), (0x0231, 0x006F), (0x0232, 0x0059), (0x0233, 0x0079), (0x02B0, 0x0068),
+    (0x02B2, 0x006A), (0x02B3, 0x0072), (0x02B7, 0x0077), (0x02B8, 0x0079), (0x02E1, 0x006C),
+    (0x02E2, 0x0073), (0x02E3, 0x0078), (0x037E, 0x003B), (0x0387, 0x00B7), (0x1D2C, 0x0041),
+    (0x1D2E, 0x0042), (0x1D30, 0x0044), (0x1D31, 0x0045), (0x1D33, 0x0047), (0x1D34, 0x0048),
+    (0x1D35, 0x0049), (0x1D36, 0x004A), (0x1D37, 0x004B), (0x1D38, 0x004C), (0x1D39, 0x004D),
+    (0x1D3A, 0x004E), (0x1D3C, 0x004F), (0x1D3E, 0x0050), (0x1D3F, 0x0052), (0x1D40, 0x0054),
+    (0x1D41, 0x0055), (0x1D42, 0x0057), (0x1D43, 0x0061), (0x1D47, 0x0062), (0x1D48, 0x0064),
+    (0x1D49, 0x0065), (0x1D4D, 0x0067), (0x1D4F, 0x006B), (0x1D50, 0x006D), (0x1D52, 0x006F),
+    (0x1D56, 0x0070), (0x1D57, 0x0074), (0x1D58, 0x0075), (0x1D5B, 0x0076), (0x1D62, 0x0069),
+    (0x1D63, 0x0072), (0x1D64, 0x0075), (0x1D65, 0x0076), (0x1D9C, 0x0063), (0x1DA0, 0x0066),
+    (0x1DBB, 0x007A), (0x1E00, 0x0041), (0x1E01, 0x0061), (0x1E02, 0x0042), (0x1E03, 0x0062),
+    (0x1E04, 0x0042), (0x1E05, 0x0062), (0x1E06, 0x0042), (0x1E07, 0x0062), (0x1E08, 0x0043),
+    (0x1E09, 0x0063), (0x1E0A, 0x0044), (0x1E0B, 0x0064), (0x1E0C, 0x0044), (0x1E0D, 0x0064),
+    (0x1E0E, 0x0044), (0x1E0F, 0x0064), (0x1E10, 0x0044), (0x1E11, 0x0064), (0x1E12, 0x0044),
+    (0x1E13, 0x0064), (0x1E14, 0x0045), (0x1E15, 0x0065), (0x1E16, 0x0045), (0x1E17, 0x0065),
+    (0x1E18, 0x0045), (0x1E19, 0x0065), (0x1E1A, 0x0045), (0x1E1B, 0x0065), (0x1E1C, 0x0045),
+    (0x1E1D, 0x0065), (0x1E1E, 0x0046), (0x1E1F, 0x0066), (0x1E20, 0x0047), (0x1E21, 0x0067),
+    (0x1E22, 0x0048), (0x1E23, 0x0068), (0x1E24, 0x0048), (0x1E25, 0x0068), (0x1E26, 0x0048),
+    (0x1E27, 0x0068), (0x1E28, 0x0048), (0x1E29, 0x0068), (0x1E2A, 0x0048), (0x1E2B, 0x0068),
+    (0x1E2C, 0x0049), (0x1E2D, 0x0069), (0x1E2E, 0x0049), (0x1E2F, 0x0069), (0x1E30, 0x004B),
+    (0x1E31, 0x006B), (0x1E32, 0x004B), (0x1E33, 0x006B), (0x1E34, 0x004B), (0x1E35, 0x006B),
+    (0x1E36, 0x004C), (0x1E37, 0x006C), (0x1E38, 0x004C), (0x1E39, 0x006C), (0x1E3A, 0x004C),
+    (0x1E3B, 0x006C), (0x1E3C, 0x004C), (0x1E3D, 0x006C), (0x1E3E, 0x004D), (0x1E3F, 0x006D),
+    (0x1E40, 0x004D), (0x1E41, 0x006D), (0x1E42, 0x004D), (0x1E43, 0x006D), (0x1E44, 0x004E),
+    (0x1E45, 0x006E), (0x1E46, 0x004E), (0x1E47, 0x006E), (0x1E48, 0x004E), (0x1E49, 0x006E),
+    (0x1E4A, 0x004E), (0x1E4B, 0x006E), (0x1E4C, 0x004F), (0x1E4D, 0x006F), (0x1E4E, 0x004F),
+    (0x1E4F, 0x006F), (0x1E50, 0x004F), (0x1E51, 0x006F), (0x1E52, 0x004F), (0x1E53, 0x006F),
+    (0x1E54, 0x0050), (0x1E55, 0x0070), (0x1E56, 0x0050), (0x1E57, 0x0070), (0x1E58, 0x0052),
+    (0x1E59, 0x0072), (0x1E5A, 0x0052), (0x1E5B, 0x0072), (0x1E5C, 0x0052), (0x1E5D, 0x0072),
+    (0x1E5E, 0x0052), (0x1E5F, 0x0072), (0x1E60, 0x0053), (0x1E61, 0x0073), (0x1E62, 0x0053),
+    (0x1E63, 0x0073), (0x1E64, 0x0053), (0x1E65, 0x0073), (0x1E66, 0x0053), (0x1E67, 0x0073),
+    (0x1E68, 0x0053), (0x1E69, 0x0073), (0x1E6A, 0x0054), (0x1E6B, 0x0074), (0x1E6C, 0x0054),
+    (0x1E6D, 0x0074), (0x1E6E, 0x0054), (0x1E6F, 0x0074), (0x1E70, 0x0054), (0x1E71, 0x0074),
+    (0x1E72, 0x0055), (0x1E73, 0x0075), (0x1E74, 0x0055), (0x1E75, 0x0075), (0x1E76, 0x0055),
+    (0x1E77, 0x0075), (0x1E78, 0x0055), (0x1E79, 0x0075), (0x1E7A, 0x0055), (0x1E7B, 0x0075),
+    (0x1E7C, 0x0056), (0x1E7D, 0x0076), (0x1E7E, 0x0056), (0x1E7F, 0x0076), (0x1E80, 0x0057),
+    (0x1E81, 0x0077), (0x1E82, 0x0057), (0x1E83, 0x0077), (0x1E84, 0x0057), (0x1E85, 0x0077),
+    (0x1E86, 0x0057), (0x1E87, 0x0077), (0x1E88, 0x0057), (0x1E89, 0x0077), (0x1E8A, 0x0058),
+    (0x1E8B, 0x0078), (0x1E8C, 0x0058), (0x1E8D, 0x0078), (0x1E8E, 0x0059), (0x1E8F, 0x0079),
+    (0x1E90, 0x005A), (0x1E91, 0x007A), (0x1E92, 0x005A), (0x1E93, 0x007A), (0x1E94, 0x005A),
+    (0x1E95, 0x007A), (0x1E96, 0x0068), (0x1E97, 0x0074), (0x1E98, 0x0077), (0x1E99, 0x0079),
+    (0x1E9B, 0x0073), (0x1EA0, 0x0041), (0x1EA1, 0x0061), (0x1EA2, 0x0041), (0x1EA3, 0x0061),
+    (0x1EA4, 0x0041), (0x1EA5, 0x0061), (0x1EA6, 0x0041), (0x1EA7, 0x0061), (0x1EA8, 0x0041),
+    (0x1EA9, 0x0061), (0x1EAA, 0x0041), (0x1EAB, 0x0061), (0x1EAC, 0x0041), (0x1EAD, 0x0061),
+    (0x1EAE, 0x0041), (0x1EAF, 0x0061), (0x1EB0, 0x0041), (0x1EB1, 0x0061), (0x1EB2, 0x0041),
+    (0x1EB3, 0x0061), (0x1EB4, 0x0041), (0x1EB5, 0x0061), (0x1EB6, 0x0041), (0x1EB7, 0x0061),
+    (0x1EB8, 0x0045), (0x1EB9, 0x0065), (0x1EBA, 0x0045), (0x1EBB, 0x0065), (0x1EBC, 0x0045),
+    (0x1EBD, 0x0065), (0x1EBE, 0x0045), (0x1EBF, 0x0065), (0x1EC0, 0x0045), (0x1EC1, 0x0065),
+    (0x1EC2, 0x0045), (0x1EC3, 0x0065), (0x1EC4, 0x0045), (0x1EC5, 0x0065), (0x1EC6, 0x0045),
+    (0x1EC7, 0x0065), (0x1EC8, 0x0049), (0x1EC9, 0x0069), (0x1ECA, 0x0049), (0x1ECB, 0x0069),
+    (0x1ECC, 0x004F), (0x1ECD, 0x006F), (0x1ECE, 0x004F), (0x1ECF, 0x006F), (0x1ED0, 0x004F),
+    (0x1ED1, 0x006F), (0x1ED2, 0x004F), (0x1ED3, 0x006F), (0x1ED4, 0x004F), (0x1ED5, 0x006F),
+    (0x1ED6, 0x004F), (0x1ED7, 0x006F), (0x1ED8, 0x004F), (0x1ED9, 0x006F), (0x1EDA, 0x004F),
+    (0x1EDB, 0x006F), (0x1EDC, 0x004F), (0x1EDD, 0x006F), (0x1EDE, 0x004F), (0x1EDF, 0x006F),
+    (0x1EE0, 0x004F), (0x1EE1, 0x006F), (0x1EE2, 0x004F), (0x1EE3, 0x006F), (0x1EE4, 0x0055),
+    (0x1EE5, 0x0075), (0x1EE6, 0x0055), (0x1EE7, 0x0075), (0x1EE8, 0x0055), (0x1EE9, 0x0075),
+    (0x1EEA, 0x0055), (0x1EEB, 0x0075), (0x1EEC, 0x0055), (0x1EED, 0x0075), (0x1EEE, 0x0055),
+    (0x1EEF, 0x0075), (0x1EF0, 0x0055), (0x1EF1, 0x0075), (0x1EF2, 0x0059), (0x1EF3, 0x0079),
+    (0x1EF4, 0x0059), (0x1EF5, 0x0079), (0x1EF6, 0x0059), (0x1EF7, 0x0079), (0x1EF8, 0x0059),
+    (0x1EF9, 0x0079), (0x1FEF, 0x0060), (0x2000, 0x0020), (0x2001, 0x0020), (0x2002, 0x0020),
+    (0x2003, 0x0020), (0x2004, 0x0020), (0x2005, 0x0020), (0x2006, 0x0020), (0x2007, 0x0020),
+    (0x2008, 0x0020), (0x2009, 0x0020), (0x200A, 0x0020), (0x2010, 0x002D), (0x2012, 0x002D),
+    (0x2015, 0x002D), (0x201B, 0x0027), (0x201F, 0x0022), (0x2024, 0x002E), (0x202F, 0x0020),
+    (0x2032, 0x0027), (0x2035, 0x0060), (0x205F, 0x0020), (0x2070, 0x0030), (0x2071, 0x0069),
+    (0x2074, 0x0034), (0x2075, 0x0035), (0x2076, 0x0036), (0x2077, 0x0037), (0x2078, 0x0038),
+    (0x2079, 0x0039), (0x207A, 0x002B), (0x207C, 0x003D), (0x207D, 0x0028), (0x207E, 0x0029),
+    (0x207F, 0x006E), (0x2080, 0x0030), (0x2081, 0x0031), (0x2082, 0x0032), (0x2083, 0x0033),
+    (0x2084, 0x0034), (0x2085, 0x0035), (0x2086, 0x0036), (0x2087, 0x0037), (0x2088, 0x0038),
+    (0x2089, 0x0039), (0x208A, 0x002B), (0x208C, 0x003D), (0x208D, 0x0028), (0x208E, 0x0029),
+    (0x2090, 0x0061), (0x2091, 0x0065), (0x2092, 0x006F), (0x2093, 0x0078), (0x2095, 0x0068),
+    (0x2096, 0x006B), (0x2097, 0x006C), (0x2098, 0x006D), (0x2099, 0x006E), (0x209A, 0x0070),
+    (0x209B, 0x0073), (0x209C, 0x0074), (0x2102, 0x0043), (0x210A, 0x0067), (0x210B, 0x0048),
+    (0x210C, 0x0048), (0x210D, 0x0048), (0x210E, 0x0068), (0x2110, 0x0049), (0x2111, 0x0049),
+    (0x2112, 0x004C), (0x2113, 0x006C), (0x2115, 0x004E), (0x2119, 0x0050), (0x211A, 0x0051),
+    (0x211B, 0x0052), (0x211C, 0x0052), (0x211D, 0x0052), (0x2124, 0x005A), (0x2128, 0x005A),
+    (0x212A, 0x004B), (0x212B, 0x0041), (0x212C, 0x0042), (0x212D, 0x0043), (0x212F, 0x0065),
+    (0x2130, 0x0045), (0x2131, 0x0046), (0x2133, 0x004D), (0x2134, 0x006F), (0x2139, 0x0069),
+    (0x2145, 0x0044), (0x2146, 0x0064), (0x2147, 0x0065), (0x2148, 0x0069), (0x2149, 0x006A),
+    (0x2160, 0x0049), (0x2164, 0x0056), (0x2169, 0x0058), (0x216C, 0x004C), (0x216D, 0x0043),
+    (0x216E, 0x0044), (0x216F, 0x004D), (0x2170, 0x0069), (0x2174, 0x0076), (0x2179, 0x0078),
+    (0x217C, 0x006C), (0x217D, 0x0063), (0x217E, 0x0064), (0x217F, 0x006D), (0x2212, 0x002D),
+    (0x2215, 0x002F), (0x2216, 0x005C), (0x2223, 0x007C), (0x223C, 0x007E), (0x2260, 0x003D),
+    (0x226E, 0x003C), (0x226F, 0x003E), (0x2460, 0x0031), (0x2461, 0x0032), (0x2462, 0x0033),
+    (0x2463, 0x0034), (0x2464, 0x0035), (0x2465, 0x0036), (0x2466, 0x0037), (0x2467, 0x0038),
+    (0x2468, 0x0039), (0x24B6, 0x0041), (0x24B7, 0x0042), (0x24B8, 0x0043), (0x24B9, 0x0044),
+    (0x24BA, 0x0045), (0x24BB, 0x0046), (0x24BC, 0x0047), (0x24BD, 0x0048), (0x24BE, 0x0049),
+    (0x24BF, 0x004A), (0x24C0, 0x004B), (0x24C1, 0x004C), (0x24C2, 0x004D), (0x24C3, 0x004E),
+    (0x24C4, 0x004F), (0x24C5, 0x0050), (0x24C6, 0x0051), (0x24C7, 0x0052), (0x24C8, 0x0053),
+    (0x24C9, 0x0054), (0x24CA, 0x0055), (0x24CB, 0x0056), (0x24CC, 0x0057), (0x24CD, 0x0058),
+    (0x24CE, 0x0059), (0x24CF, 0x005A), (0x24D0, 0x0061), (0x24D1, 0x0062), (0x24D2, 0x0063),
+    (0x24D3, 0x0064), (0x24D4, 0x0065), (0x24D5, 0x0066), (0x24D6, 0x0067), (0x24D7, 0x0068),
+    (0x24D8, 0x0069), (0x24D9, 0x006A), (0x24DA, 0x006B), (0x24DB, 0x006C), (0x24DC, 0x006D),
+    (0x24DD, 0x006E), (0x24DE, 0x006F), (0x24DF, 0x0070), (0x24E0, 0x0071), (0x24E1, 0x0072),
+    (0x24E2, 0x0073), (0x24E3, 0x0074), (0x24E4, 0x0075), (0x24E5, 0x0076), (0x24E6, 0x0077),
+    (0x24E7, 0x0078), (0x24E8, 0x0079), (0x24E9, 0x007A), (0x24EA, 0x0030), (0x2C7C, 0x006A),
+    (0x2C7D, 0x0056), (0x3000, 0x0020), (0xA7F2, 0x0043), (0xA7F3, 0x0046), (0xA7F4, 0x0051),
+    (0xFB29, 0x002B), (0xFE10, 0x002C), (0xFE13, 0x003A), (0xFE14, 0x003B), (0xFE15, 0x0021),
+    (0xFE16, 0x003F), (0xFE31, 0x0097), (0xFE32, 0x0096), (0xFE33, 0x005F), (0xFE34, 0x005F),
+    (0xFE35, 0x0028), (0xFE36, 0x0029), (0xFE37, 0x007B), (0xFE38, 0x007D), (0xFE47, 0x005B),
+    (0xFE48, 0x005D), (0xFE4D, 0x005F), (0xFE4E, 0x005F), (0xFE4F, 0x005F), (0xFE50, 0x002C),
+    (0xFE52, 0x002E), (0xFE54, 0x003B), (0xFE55, 0x003A), (0xFE56, 0x003F), (0xFE57, 0x0021),
+    (0xFE58, 0x0097), (0xFE59, 0x0028), (0xFE5A, 0x0029), (0xFE5B, 0x007B), (0xFE5C, 0x007D),
+    (0xFE5F, 0x0023), (0xFE60, 0x0026), (0xFE61, 0x002A), (0xFE62, 0x002B), (0xFE63, 0x002D),
+    (0xFE64, 0x003C), (0xFE65, 0x003E), (0xFE66, 0x003D), (0xFE68, 0x005C), (0xFE69, 0x0024),
+    (0xFE6A, 0x0025), (0xFE6B, 0x0040), (0xFF01, 0x0021), (0xFF02, 0x0022), (0xFF03, 0x0023),
+    (0xFF04, 0x0024), (0xFF05, 0x0025), (0xFF06, 0x0026), (0xFF07, 0x0027), (0xFF08, 0x0028),
+    (0xFF09, 0x0029), (0xFF0A, 0x002A), (0xFF0B, 0x002B), (0xFF0C, 0x002C), (0xFF0D, 0x002D),
+    (0xFF0E, 0x002E), (0xFF0F, 0x002F), (0xFF10, 0x0030), (0xFF11, 0x0031), (0xFF12, 0x0032),
+    (0xFF13, 0x0033), (0xFF14, 0x0034), (0xFF15, 0x0035), (0xFF16, 0x0036), (0xFF17, 0x0037),
+    (0xFF18, 0x0038), (0xFF19, 0x0039), (0xFF1A, 0x003A), (0xFF1B, 0x003B), (0xFF1C, 0x003C),
+    (0xFF1D, 0x003D), (0xFF1E, 0x003E), (0xFF1F, 0x003F), (0xFF20, 0x0040), (0xFF21, 0x0041),
+    (0xFF22, 0x0042), (0xFF23, 0x0043), (0xFF24, 0x0044), (0xFF25, 0x0045), (0xFF26, 0x0046),
+    (0xFF27, 0x0047), (0xFF28, 0x0048), (0xFF29, 0x0049), (0xFF2A, 0x004A), (0xFF2B, 0x004B),
+    (0xFF2C, 0x004C), (0xFF2D, 0x004D), (0xFF2E, 0x004E), (0xFF2F, 0x004F), (0xFF30, 0x0050),
+    (0xFF31, 0x0051), (0xFF32, 0x0052), (0xFF33, 0x0053), (0xFF34, 0x0054), (0xFF35, 0x0055),
+    (0xFF36, 0x0056), (0xFF37, 0x0057), (0xFF38, 0x0058), (0xFF39, 0x0059), (0xFF3A, 0x005A),
+    (0xFF3B, 0x005B), (0xFF3C, 0x005C), (0xFF3D, 0x005D), (0xFF3E, 0x005E), (0xFF3F, 0x005F),
+    (0xFF40, 0x0060), (0xFF41, 0x0061), (0xFF42, 0x0062), (0xFF43, 0x0063), (0xFF44, 0x0064),
+    (0xFF45, 0x0065), (0xFF46, 0x0066), (0xFF47, 0x0067), (0xFF48, 0x0068), (0xFF49, 0x0069),
+    (0xFF4A, 0x006A), (0xFF4B, 0x006B), (0xFF4C, 0x006C), (0xFF4D, 0x006D), (0xFF4E, 0x006E),
+    (0xFF4F, 0x006F), (0xFF50, 0x0070), (0xFF51, 0x0071), (0xFF52, 0x0072), (0xFF53, 0x0073),
+    (0xFF54, 0x0074), (0xFF55, 0x0075), (0xFF56, 0x0076), (0xFF57, 0x0077), (0xFF58, 0x0078),
+    (0xFF59, 0x0079), (0xFF5A, 0x007A), (0xFF5B, 0x007B), (0xFF5C, 0x007C), (0xFF5D, 0x007D),
+    (0xFF5E, 0x007E), (0xFFE2, 0x00AC), (0xFFE4, 0x00A6),
+];
+
+/// Code page 1251.
+#[rustfmt::skip]
+pub(crate) const CP1251: [(u16, u16); 901] = [
+    (0x00AA, 0x0061), (0x00B2, 0x0032), (0x00B3, 0x0033), (0x00B9, 0x0031), (0x00BA, 0x006F),
+    (0x00C0, 0x0041), (0x00C1, 0x0041), (0x00C2, 0x0041), (0x00C3, 0x0041), (0x00C4, 0x0041),
+    (0x00C5, 0x0041), (0x00C7, 0x0043), (0x00C8, 0x0045), (0x00C9, 0x0045), (0x00CA, 0x0045),
+    (0x00CB, 0x0045), (0x00CC, 0x0049), (0x00CD, 0x0049), (0x00CE, 0x0049), (0x00CF, 0x0049),
+    (0x00D1, 0x004E), (0x00D2, 0x004F), (0x00D3, 0x004F), (0x00D4, 0x004F), (0x00D5, 0x004F),
+    (0x00D6, 0x004F), (0x00D9, 0x0055), (0x00DA, 0x0055), (0x00DB, 0x0055), (0x00DC, 0x0055),
+    (0x00DD, 0x0059), (0x00E0, 0x0061), (0x00E1, 0x0061), (0x00E2, 0x0061), (0x00E3, 0x0061),
+    (0x00E4, 0x0061), (0x00E5, 0x0061), (0x00E7, 0x0063), (0x00E8, 0x0065), (0x00E9, 0x0065),
+    (0x00EA, 0x0065), (0x00EB, 0x0065), (0x00EC, 0x0069), (0x00ED, 0x0069), (0x00EE, 0x0069),
+    (0x00EF, 0x0069), (0x00F1, 0x006E), (0x00F2, 0x006F), (0x00F3, 0x006F), (0x00F4, 0x006F),
+    (0x00F5, 0x006F), (0x00F6, 0x006F), (0x00F9, 0x0075), (0x00FA, 0x0075), (0x00FB, 0x0075),
+    (0x00FC, 0x0075), (0x00FD, 0x0079), (0x00FF, 0x0079), (0x0100, 0x0041), (0x0101, 0x0061),
+    (0x0102, 0x0041), (0x0103, 0x0061), (0x0104, 0x0041), (0x0105, 0x0061), (0x0106, 0x0043),
+    (0x0107, 0x0063), (0x0108, 0x0043), (0x0109, 0x0063), (0x010A, 0x0043), (0x010B, 0x0063),
+    (0x010C, 0x0043), (0x010D, 0x0063), (0x010E, 0x0044), (0x010F, 0x0064), (0x0112, 0x0045),
+    (0x0113, 0x0065), (0x0114, 0x0045), (0x0115, 0x0065), (0x0116, 0x0045), (0x0117, 0x0065),
+    (0x0118, 0x0045), (0x0119, 0x0065), (0x011A, 0x0045), (0x011B, 0x0065), (0x011C, 0x0047),
+    (0x011D, 0x0067), (0x011E, 0x0047), (0x011F, 0x0067), (0x0120, 0x0047), (0x0121, 0x0067),
+    (0x0122, 0x0047), (0x0123, 0x0067), (0x0124, 0x0048), (0x0125, 0x0068), (0x0128, 0x0049),
+    (0x0129, 0x0069), (0x012A, 0x0049), (0x012B, 0x0069), (0x012C, 0x0049), (0x012D, 0x0069),
+    (0x012E, 0x0049), (0x012F, 0x0069), (0x0130, 0x0049), (0x0134, 0x004A), (0x0135, 0x006A),
+    (0x0136, 0x004B), (0x0137, 0x006B), (0x0139, 0x004C), (0x013A, 0x006C), (0x013B, 0x004C),
+    (0x013C, 0x006C), (0x013D, 0x004C), (0x013E, 0x006C), (0x0143, 0x004E), (0x0144, 0x006E),
+    (0x0145, 0x004E), (0x0146, 0x006E), (0x0147, 0x004E), (0x0148, 0x006E), (0x014C, 0x004F),
+    (0x014D, 0x006F), (0x014E, 0x004F), (0x014F, 0x006F), (0x0150, 0x004F), (0x0151, 0x006F),
+    (0x0154, 0x0052), (0x0155, 0x0072), (0x0156, 0x0052), (0x0157, 0x0072), (0x0158, 0x0052),
+    (0x0159, 0x0072), (0x015A, 0x0053), (0x015B, 0x0073), (0x015C, 0x0053), (0x015D, 0x0073),
+    (0x015E, 0x0053), (0x015F, 0x0073), (0x0160, 0x0053), (0x0161, 0x0073), (0x0162, 0x0054),
+    (0x0163, 0x0074), (0x0164, 0x0054), (0x0165, 0x0074), (0x0168, 0x0055), (0x0169, 0x0075),
+    (0x016A, 0x0055), (0x016B, 0x0075), (0x016C, 0x0055), (0x016D, 0x0075), (0x016E, 0x0055),
+    (0x016F, 0x0075), (0x0170, 0x0055), (0x0171, 0x0075), (0x0172, 0x0055), (0x0173, 0x0075),
+    (0x0174, 0x0057), (0x0175, 0x0077), (0x0176, 0x0059), (0x0177, 0x0079), (0x0178, 0x0059),
+    (0x0179, 0x005A), (0x017A, 0x007A), (0x017B, 0x005A), (0x017C, 0x007A), (0x017D, 0x005A),
+    (0x017E, 0x007A), (0x017F, 0x0073), (0x01A0, 0x004F), (0x01A1, 0x006F), (0x01AF, 0x0055),
+    (0x01B0, 0x0075), (0x01CD, 0x0041), (0x01CE, 0x0061), (0x01CF, 0x0049), (0x01D0, 0x0069),
+    (0x01D1, 0x004F), (0x01D2, 0x006F), (0x01D3, 0x0055), (0x01D4, 0x0075), (0x01D5, 0x0055),
+    (0x01D6, 0x0075), (0x01D7, 0x0055), (0x01D8, 0x0075), (0x01D9, 0x0055), (0x01DA, 0x0075),
+    (0x01DB, 0x0055), (0x01DC, 0x0075), (0x01DE, 0x0041), (0x01DF, 0x0061), (0x01E0, 0x0041),
+    (0x01E1, 0x0061), (0x01E6, 0x0047), (0x01E7, 0x0067), (0x01E8, 0x004B), (0x01E9, 0x006B),
+    (0x01EA, 0x004F), (0x01EB, 0x006F), (0x01EC, 0x004F), (0x01ED, 0x006F), (0x01F0, 0x006A),
+    (0x01F4, 0x0047), (0x01F5, 0x0067), (0x01F8, 0x004E), (0x01F9, 0x006E), (0x01FA, 0x0041),
+    (0x01FB, 0x0061), (0x0200, 0x0041), (0x0201, 0x0061), (0x0202, 0x0041), (0x0203, 0x0061),
+    (0x0204, 0x0045), (0x0205, 0x0065), (0x0206, 0x0045), (0x0207, 0x0065), (0x0208, 0x0049),
+    (0x0209, 0x0069), (0x020A, 0x0049), (0x020B, 0x0069), (0x020C, 0x004F), (0x020D, 0x006F),
+    (0x020E, 0x004F), (0x020F, 0x006F), (0x0210, 0x0052), (0x0211, 0x0072), (0x0212, 0x0052),
+    (0x0213, 0x0072), (0x0214, 0x0055), (0x0215, 0x0075), (0x0216, 0x0055), (0x0217, 0x0075),
+    (0x0218, 0x0053), (0x0219, 0x0073), (0x021A, 0x0054), (0x021B, 0x0074), (0x021E, 0x0048),
+    (0x021F, 0x0068), (0x0226, 0x0041), (0x0227, 0x0061), (0x0228, 0x0045), (0x0229, 0x0065),
+    (0x022A, 0x004F), (0x022B, 0x006F), (0x022C, 0x004F), (0x022D, 0x006F), (0x022E, 0x004F),
+    (0x022F, 0x006F), (0x0230, 0x004F), (0x0231, 0x006F), (0x0232, 0x0059), (0x0233, 0x0079),
+    (0x02B0, 0x0068), (0x02B2, 0x006A), (0x02B3, 0x0072), (0x02B7, 0x0077), (0x02B8, 0x0079),
+    (0x02E1, 0x006C), (0x02E2, 0x0073), (0x02E3, 0x0078), (0x037E, 0x003B), (0x0387, 0x00B7),
+    (0x0400, 0x00C5), (0x040D, 0x00C8), (0x0450, 0x00E5), (0x045D, 0x00E8), (0x04C1, 0x00C6),
+    (0x04C2, 0x00E6), (0x04D0, 0x00C0), (0x04D1, 0x00E0), (0x04D2, 0x00C0), (0x04D3, 0x00E0),
+    (0x04D6, 0x00C5), (0x04D7, 0x00E5), (0x04DC, 0x00C6), (0x04DD, 0x00E6), (0x04DE, 0x00C7),
+    (0x04DF, 0x00E7), (0x04E2, 0x00C8), (0x04E3, 0x00E8), (0x04E4, 0x00C8), (0x04E5, 0x00E8),
+    (0x04E6, 0x00CE), (0x04E7, 0x00EE), (0x04EC, 0x00DD), (0x04ED, 0x00FD), (0x04EE, 0x00D3),
+    (0x04EF, 0x00F3), (0x04F0, 0x00D3), (0x04F1, 0x00F3), (0x04F2, 0x00D3), (0x04F3, 0x00F3),
+    (0x04F4, 0x00D7), (0x04F5, 0x00F7), (0x04F8, 0x00DB), (0x04F9, 0x00FB), (0x1D2C, 0x0041),
+    (0x1D2E, 0x0042), (0x1D30, 0x0044), (0x1D31, 0x0045), (0x1D33, 0x0047), (0x1D34, 0x0048),
+    (0x1D35, 0x0049), (0x1D36, 0x004A), (0x1D37, 0x004B), (0x1D38, 0x004C), (0x1D39, 0x004D),
+    (0x1D3A, 0x004E), (0x1D3C, 0x004F), (0x1D3E, 0x0050), (0x1D3F, 0x0052), (0x1D40, 0x0054),
+    (0x1D41, 0x0055), (0x1D42, 0x0057), (0x1D43, 0x0061), (0x1D47, 0x0062), (0x1D48, 0x0064),
+    (0x1D49, 0x0065), (0x1D4D, 0x0067), (0x1D4F, 0x006B), (0x1D50, 0x006D), (0x1D52, 0x006F),
+    (0x1D56, 0x0070), (0x1D57, 0x0074), (0x1D58, 0x0075), (0x1D5B, 0x0076), (0x1D62, 0x0069),
+    (0x1D63, 0x0072), (0x1D64, 0x0075), (0x1D65, 0x0076), (0x1D78, 0x00ED), (0x1D9C, 0x0063),
+    (0x1DA0, 0x0066), (0x1DBB, 0x007A), (0x1E00, 0x0041), (0x1E01, 0x0061), (0x1E02, 0x0042),
+    (0x1E03, 0x0062), (0x1E04, 0x0042), (0x1E05, 0x0062), (0x1E06, 0x0042), (0x1E07, 0x0062),
+    (0x1E08, 0x0043), (0x1E09, 0x0063), (0x1E0A, 0x0044), (0x1E0B, 0x0064), (0x1E0C, 0x0044),
+    (0x1E0D, 0x0064), (0x1E0E, 0x0044), (0x1E0F, 0x0064), (0x1E10, 0x0044), (0x1E11, 0x0064),
+    (0x1E12, 0x0044), (0x1E13, 0x0064), (0x1E14, 0x0045), (0x1E15, 0x0065), (0x1E16, 0x0045),
+    (0x1E17, 0x0065), (0x1E18, 0x0045), (0x1E19, 0x0065), (0x1E1A, 0x0045), (0x1E1B, 0x0065),
+    (0x1E1C, 0x0045), (0x1E1D, 0x0065), (0x1E1E, 0x0046), (0x1E1F, 0x0066), (0x1E20, 0x0047),
+    (0x1E21, 0x0067), (0x1E22, 0x0048), (0x1E23, 0x0068), (0x1E24, 0x0048), (0x1E25, 0x0068),
+    (0x1E26, 0x0048), (0x1E27, 0x0068), (0x1E28, 0x0048), (0x1E29, 0x0068), (0x1E2A, 0x0048),
+    (0x1E2B, 0x0068), (0x1E2C, 0x0049), (0x1E2D, 0x0069), (0x1E2E, 0x0049), (0x1E2F, 0x0069),
+    (0x1E30, 0x004B), (0x1E31, 0x006B), (0x1E32, 0x004B), (0x1E33, 0x006B), (0x1E34, 0x004B),
+    (0x1E35, 0x006B), (0x1E36, 0x004C), (0x1E37, 0x006C), (0x1E38, 0x004C), (0x1E39, 0x006C),
+    (0x1E3A, 0x004C), (0x1E3B, 0x006C), (0x1E3C, 0x004C), (0x1E3D, 0x006C), (0x1E3E, 0x004D),
+    (0x1E3F, 0x006D), (0x1E40, 0x004D), (0x1E41, 0x006D), (0x1E42, 0x004D), (0x1E43, 0x006D),
+    (0x1E44, 0x004E), (0x1E45, 0x006E), (0x1E46, 0x004E), (0x1E47, 0x006E), (0x1E48, 0x004E),
+    (0x1E49, 0x006E), (0x1E4A, 0x004E), (0x1E4B, 0x006E), (0x1E4C, 0x004F), (0x1E4D, 0x006F),
+    (0x1E4E, 0x004F), (0x1E4F, 0x006F), (0x1E50, 0x004F), (0x1E51, 0x006F), (0x1E52, 0x004F),
+    (0x1E53, 0x006F), (0x1E54, 0x0050), (0x1E55, 0x0070), (0x1E56, 0x0050), (0x1E57, 0x0070),
+    (0x1E58, 0x0052), (0x1E59, 0x0072), (0x1E5A, 0x0052), (0x1E5B, 0x0072), (0x1E5C, 0x0052),
+    (0x1E5D, 0x0072), (0x1E5E, 0x0052), (0x1E5F, 0x0072), (0x1E60, 0x0053), (0x1E61, 0x0073),
+    (0x1E62, 0x0053), (0x1E63, 0x0073), (0x1E64, 0x0053), (0x1E65, 0x0073), (0x1E66, 0x0053),
+    (0x1E67, 0x0073), (0x1E68, 0x0053), (0x1E69, 0x0073), (0x1E6A, 0x0054), (0x1E6B, 0x0074),
+    (0x1E6C, 0x0054), (0x1E6D, 0x0074), (0x1E6E, 0x0054), (0x1E6F, 0x0074), (0x1E70, 0x0054),
+    (0x1E71, 0x0074), (0x1E72, 0x0055), (0x1E73, 0x0075), (0x1E74, 0x0055), (0x1E75, 0x0075),
+    (0x1E76, 0x0055), (0x1E77, 0x0075), (0x1E78, 0x0055), (0x1E79, 0x0075), (0x1E7A, 0x0055),
+    (0x1E7B, 0x0075), (0x1E7C, 0x0056), (0x1E7D, 0x0076), (0x1E7E, 0x0056), (0x1E7F, 0x0076),
+    (0x1E80, 0x0057), (0x1E81, 0x0077), (0x1E82, 0x0057), (0x1E83, 0x0077), (0x1E84, 0x0057),
+    (0x1E85, 0x0077), (0x1E86, 0x0057), (0x1E87, 0x0077), (0x1E88, 0x0057), (0x1E89, 0x0077),
+    (0x1E8A, 0x0058), (0x1E8B, 0x0078), (0x1E8C, 0x0058), (0x1E8D, 0x0078), (0x1E8E, 0x0059),
+    (0x1E8F, 0x0079), (0x1E90, 0x005A), (0x1E91, 0x007A), (0x1E92, 0x005A), (0x1E93, 0x007A),
+    (0x1E94, 0x005A), (0x1E95, 0x007A), (0x1E96, 0x0068), (0x1E97, 0x0074), (0x1E98, 0x0077),
+    (0x1E99, 0x0079), (0x1E9B, 0x0073), (0x1EA0, 0x0041), (0x1EA1, 0x0061), (0x1EA2, 0x0041),
+    (0x1EA3, 0x0061), (0x1EA4, 0x0041), (0x1EA5, 0x0061), (0x1EA6, 0x0041), (0x1EA7, 0x0061),
+    (0x1EA8, 0x0041), (0x1EA9, 0x0061), (0x1EAA, 0x0041), (0x1EAB, 0x0061), (0x1EAC, 0x0041),
+    (0x1EAD, 0x0061), (0x1EAE, 0x0041), (0x1EAF, 0x0061), (0x1EB0, 0x0041), (0x1EB1, 0x0061),
+    (0x1EB2, 0x0041), (0x1EB3, 0x0061), (0x1EB4, 0x0041), (0x1EB5, 0x0061), (0x1EB6, 0x0041),
+    (0x1EB7, 0x0061), (0x1EB8, 0x0045), (0x1EB9, 0x0065), (0x1EBA, 0x0045), (0x1EBB, 0x0065),
+    (0x1EBC, 0x0045), (0x1EBD, 0x0065), (0x1EBE, 0x0045), (0x1EBF, 0x0065), (0x1EC0, 0x0045),
+    (0x1EC1, 0x0065), (0x1EC2, 0x0045), (0x1EC3, 0x0065), (0x1EC4, 0x0045), (0x1EC5, 0x0065),
+    (0x1EC6, 0x0045), (0x1EC7, 0x0065), (0x1EC8, 0x0049), (0x1EC9, 0x0069), (0x1ECA, 0x0049),
+    (0x1ECB, 0x0069), (0x1ECC, 0x004F), (0x1ECD, 0x006F), (0x1ECE, 0x004F), (0x1ECF, 0x006F),
+    (0x1ED0, 0x004F), (0x1ED1, 0x006F), (0x1ED2, 0x004F), (0x1ED3, 0x006F), (0x1ED4, 0x004F),
+    (0x1ED5, 0x006F), (0x1ED6, 0x004F), (0x1ED7, 0x006F), (0x1ED8, 0x004F), (0x1ED9, 0x006F),
+    (0x1EDA, 0x004F), (0x1EDB, 0x006F), (0x1EDC, 0x004F), (0x1EDD, 0x006F), (0x1EDE, 0x004F),
+    (0x1EDF, 0x006F), (0x1EE0, 0x004F), (0x1EE1, 0x006F), (0x1EE2, 0x004F), (0x1EE3, 0x006F),
+    (0x1EE4, 0x0055), (0x1EE5, 0x0075), (0x1EE6, 0x0055), (0x1EE7, 0x0075), (0x1EE8, 0x0055),
+    (0x1EE9, 0x0075), (0x1EEA, 0x0055), (0x1EEB, 0x0075), (0x1EEC, 0x0055), (0x1EED, 0x0075),
+    (0x1EEE, 0x0055), (0x1EEF, 0x0075), (0x1EF0, 0x0055), (0x1EF1, 0x0075), (0x1EF2, 0x0059),
+    (0x1EF3, 0x0079), (0x1EF4, 0x0059), (0x1EF5, 0x0079), (0x1EF6, 0x0059), (0x1EF7, 0x0079),
+    (0x1EF8, 0x0059), (0x1EF9, 0x0079), (0x1FEF, 0x0060), (0x2000, 0x0020), (0x2001, 0x0020),
+    (0x2002, 0x0020), (0x2003, 0x0020), (0x2004, 0x0020), (0x2005, 0x0020), (0x2006, 0x0020),
+    (0x2007, 0x0020), (0x2008, 0x0020), (0x2009, 0x0020), (0x200A, 0x0020), (0x2010, 0x002D),
+    (0x2012, 0x002D), (0x2015, 0x002D), (0x201B, 0x0027), (0x201F, 0x0022), (0x2024, 0x002E),
+    (0x202F, 0x0020), (0x2032, 0x0027), (0x2035, 0x0060), (0x205F, 0x0020), (0x2070, 0x0030),
+    (0x2071, 0x0069), (0x2074, 0x0034), (0x2075, 0x0035), (0x2076, 0x0036), (0x2077, 0x0037),
+    (0x2078, 0x0038), (0x2079, 0x0039), (0x207A, 0x002B), (0x207C, 0x003D), (0x207D, 0x0028),
+    (0x207E, 0x0029), (0x207F, 0x006E), (0x2080, 0x0030), (0x2081, 0x0031), (0x2082, 0x0032),
+    (0x2083, 0x0033), (0x2084, 0x0034), (0x2085, 0x0035), (0x2086, 0x0036), (0x2087, 0x0037),
+    (0x2088, 0x0038), (0x2089, 0x0039), (0x208A, 0x002B), (0x208C, 0x003D), (0x208D, 0x0028),
+    (0x208E, 0x0029), (0x2090, 0x0061), (0x2091, 0x0065), (0x2092, 0x006F), (0x2093, 0x0078),
+    (0x2095, 0x0068), (0x2096, 0x006B), (0x2097, 0x006C), (0x2098, 0x006D), (0x2099, 0x006E),
+    (0x209A, 0x0070), (0x209B, 0x0073), (0x209C, 0x0074), (0x2102, 0x0043), (0x210A, 0x0067),
+    (0x210B, 0x0048), (0x210C, 0x0048), (0x210D, 0x0048), (0x210E, 0x0068), (0x2110, 0x0049),
+    (0x2111, 0x0049), (0x2112, 0x004C), (0x2113, 0x006C), (0x2115, 0x004E), (0x2119, 0x0050),
+    (0x211A, 0x0051), (0x211B, 0x0052), (0x211C, 0x0052), (0x211D, 0x0052), (0x2124, 0x005A),
+    (0x2128, 0x005A), (0x212A, 0x004B), (0x212B, 0x0041), (0x212C, 0x0042), (0x212D, 0x0043),
+    (0x212F, 0x0065), (0x2130, 0x0045), (0x2131, 0x0046), (0x2133, 0x004D), (0x2134, 0x006F),
+    (0x2139, 0x0069), (0x2145, 0x0044), (0x2146, 0x0064), (0x2147, 0x0065), (0x2148, 0x0069),
+    (0x2149, 0x006A), (0x2160, 0x0049), (0x2164, 0x0056), (0x2169, 0x0058), (0x216C, 0x004C),
+    (0x216D, 0x0043), (0x216E, 0x0044), (0x216F, 0x004D), (0x2170, 0x0069), (0x2174, 0x0076),
+    (0x2179, 0x0078), (0x217C, 0x006C), (0x217D, 0x0063), (0x217E, 0x0064), (0x217F, 0x006D),
+    (0x2212, 0x002D), (0x2215, 0x002F), (0x2216, 0x005C), (0x2223, 0x007C), (0x223C, 0x007E),
+    (0x2260, 0x003D), (0x226E, 0x003C), (0x226F, 0x003E), (0x2460, 0x0031), (0x2461, 0x0032),
+    (0x2462, 0x0033), (0x2463, 0x0034), (0x2464, 0x0035), (0x2465, 0x0036), (0x2466, 0x0037),
+    (0x2467, 0x0038), (0x2468, 0x0039), (0x24B6, 0x0041), (0x24B7, 0x0042), (0x24B8, 0x0043),
+    (0x24B9, 0x0044), (0x24BA, 0x0045), (0x24BB, 0x0046), (0x24BC, 0x0047), (0x24BD, 0x0048),
+    (0x24BE, 0x0049), (0x24BF, 0x004A), (0x24C0, 0x004B), (0x24C1, 0x004C), (0x24C2, 0x004D),
+    (0x24C3, 0x004E), (0x24C4, 0x004F), (0x24C5, 0x0050), (0x24C6, 0x0051), (0x24C7, 0x0052),
+    (0x24C8, 0x0053), (0x24C9, 0x0054), (0x24CA, 0x0055), (0x24CB, 0x0056), (0x24CC, 0x0057),
+    (0x24CD, 0x0058), (0x24CE, 0x0059), (0x24CF, 0x005A), (0x24D0, 0x0061), (0x24D1, 0x0062),
+    (0x24D2, 0x0063), (0x24D3, 0x0064), (0x24D4, 0x0065), (0x24D5, 0x0066), (0x24D6, 0x0067),
+    (0x24D7, 0x0068), (0x24D8, 0x0069), (0x24D9, 0x006A), (0x24DA, 0x006B), (0x24DB, 0x006C),
+    (0x24DC, 0x006D), (0x24DD, 0x006E), (0x24DE, 0x006F), (0x24DF, 0x0070), (0x24E0, 0x0071),
+    (0x24E1, 0x0072), (0x24E2, 0x0073), (0x24E3, 0x0074), (0x24E4, 0x0075), (0x24E5, 0x0076),
+    (0x24E6, 0x0077), (0x24E7, 0x0078), (0x24E8, 0x0079), (0x24E9, 0x007A), (0x24EA, 0x0030),
+    (0x2C7C, 0x006A), (0x2C7D, 0x0056), (0x3000, 0x0020), (0xA69C, 0x00FA), (0xA69D, 0x00FC),
+    (0xA7F2, 0x0043), (0xA7F3, 0x0046), (0xA7F4, 0x0051), (0xFB29, 0x002B), (0xFE10, 0x002C),
+    (0xFE13, 0x003A), (0xFE14, 0x003B), (0xFE15, 0x0021), (0xFE16, 0x003F), (0xFE31, 0x0097),
+    (0xFE32, 0x0096), (0xFE33, 0x005F), (0xFE34, 0x005F), (0xFE35, 0x0028), (0xFE36, 0x0029),
+    (0xFE37, 0x007B), (0xFE38, 0x007D), (0xFE47, 0x005B), (0xFE48, 0x005D), (0xFE4D, 0x005F),
+    (0xFE4E, 0x005F), (0xFE4F, 0x005F), (0xFE50, 0x002C), (0xFE52, 0x002E), (0xFE54, 0x003B),
+    (0xFE55, 0x003A), (0xFE56, 0x003F), (0xFE57, 0x0021), (0xFE58, 0x0097), (0xFE59, 0x0028),
+    (0xFE5A, 0x0029), (0xFE5B, 0x007B), (0xFE5C, 0x007D), (0xFE5F, 0x0023), (0xFE60, 0x0026),
+    (0xFE61, 0x002A), (0xFE62, 0x002B), (0xFE63, 0x002D), (0xFE64, 0x003C), (0xFE65, 0x003E),
+    (0xFE66, 0x003D), (0xFE68, 0x005C), (0xFE69, 0x0024), (0xFE6A, 0x0025), (0xFE6B, 0x0040),
+    (0xFF01, 0x0021), (0xFF02, 0x0022), (0xFF03, 0x0023), (0xFF04, 0x0024), (0xFF05, 0x0025),
+    (0xFF06, 0x0026), (0xFF07, 0x0027), (0xFF08, 0x0028), (0xFF09, 0x0029), (0xFF0A, 0x002A),
+    (0xFF0B, 0x002B), (0xFF0C, 0x002C), (0xFF0D, 0x002D), (0xFF0E, 0x002E), (0xFF0F, 0x002F),
+    (0xFF10, 0x0030), (0xFF11, 0x0031), (0xFF12, 0x0032), (0xFF13, 0x0033), (0xFF14, 0x0034),
+    (0xFF15, 0x0035), (0xFF16, 0x0036), (0xFF17, 0x0037), (0xFF18, 0x0038), (0xFF19, 0x0039),
+    (0xFF1A, 0x003A), (0xFF1B, 0x003B), (0xFF1C, 0x003C), (0xFF1D, 0x003D), (0xFF1E, 0x003E),
+    (0xFF1F, 0x003F), (0xFF20, 0x0040), (0xFF21, 0x0041), (0xFF22, 0x0042), (0xFF23, 0x0043),
+    (0xFF24, 0x0044), (0xFF25, 0x0045), (0xFF26, 0x0046), (0xFF27, 0x0047), (0xFF28, 0x0048),
+    (0xFF29, 0x0049), (0xFF2A, 0x004A), (0xFF2B, 0x004B), (0xFF2C, 0x004C), (0xFF2D, 0x004D),
+    (0xFF2E, 0x004E), (0xFF2F, 0x004F), (0xFF30, 0x0050), (0xFF31, 0x0051), (0xFF32, 0x0052),
+    (0xFF33, 0x0053), (0xFF34, 0x0054), (0xFF35, 0x0055), (0xFF36, 0x0056), (0xFF37, 0x0057),
+    (0xFF38, 0x0058), (0xFF39, 0x0059), (0xFF3A, 0x005A), (0xFF3B, 0x005B), (0xFF3C, 0x005C),
+    (0xFF3D, 0x005D), (0xFF3E, 0x005E), (0xFF3F, 0x005F), (0xFF40, 0x0060), (0xFF41, 0x0061),
+    (0xFF42, 0x0062), (0xFF43, 0x0063), (0xFF44, 0x0064), (0xFF45, 0x0065), (0xFF46, 0x0066),
+    (0xFF47, 0x0067), (0xFF48, 0x0068), (0xFF49, 0x0069), (0xFF4A, 0x006A), (0xFF4B, 0x006B),
+    (0xFF4C, 0x006C), (0xFF4D, 0x006D), (0xFF4E, 0x006E), (0xFF4F, 0x006F), (0xFF50, 0x0070),
+    (0xFF51, 0x0071), (0xFF52, 0x0072), (0xFF53, 0x0073), (0xFF54, 0x0074), (0xFF55, 0x0075),
+    (0xFF56, 0x0076), (0xFF57, 0x0077), (0xFF58, 0x0078), (0xFF59, 0x0079), (0xFF5A, 0x007A),
+    (0xFF5B, 0x007B), (0xFF5C, 0x007C), (0xFF5D, 0x007D), (0xFF5E, 0x007E), (0xFFE2, 0x00AC),
+    (0xFFE4, 0x00A6),
+];
+
+/// Code page 1252.
+#[rustfmt::skip]
+pub(crate) const CP1252: [(u16, u16); 813] = [
+    (0x0100, 0x0041), (0x0101, 0x0061), (0x0102, 0x0041), (0x0103, 0x0061), (0x0104, 0x0041),
+    (0x0105, 0x0061), (0x0106, 0x0043), (0x0107, 0x0063), (0x0108, 0x0043), (0x0109, 0x0063),
+    (0x010A, 0x0043), (0x010B, 0x0063), (0x010C, 0x0043), (0x010D, 0x0063), (0x010E, 0x0044),
+    (0x010F, 0x0064), (0x0112, 0x0045), (0x0113, 0x0065), (0x0114, 0x0045), (0x0115, 0x0065),
+    (0x0116, 0x0045), (0x0117, 0x0065), (0x0118, 0x0045), (0x0119, 0x0065), (0x011A, 0x0045),
+    (0x011B, 0x0065), (0x011C, 0x0047), (0x011D, 0x0067), (0x011E, 0x0047), (0x011F, 0x0067),
+    (0x0120, 0x0047), (0x0121, 0x0067), (0x0122, 0x0047), (0x0123, 0x0067), (0x0124, 0x0048),
+    (0x0125, 0x0068), (0x0128, 0x0049), (0x0129, 0x0069), (0x012A, 0x0049), (0x012B, 0x0069),
+    (0x012C, 0x0049), (0x012D, 0x0069), (0x012E, 0x0049), (0x012F, 0x0069), (0x0130, 0x0049),
+    (0x0134, 0x004A), (0x0135, 0x006A), (0x0136, 0x004B), (0x0137, 0x006B), (0x0139, 0x004C),
+    (0x013A, 0x006C), (0x013B, 0x004C), (0x013C, 0x006C), (0x013D, 0x004C), (0x013E, 0x006C),
+    (0x0143, 0x004E), (0x0144, 0x006E), (0x0145, 0x004E), (0x0146, 0x006E), (0x0147, 0x004E),
+    (0x0148, 0x006E), (0x014C, 0x004F), (0x014D, 0x006F), (0x014E, 0x004F), (0x014F, 0x006F),
+    (0x0150, 0x004F), (0x0151, 0x006F), (0x0154, 0x0052), (0x0155, 0x0072), (0x0156, 0x0052),
+    (0x0157, 0x0072), (0x0158, 0x0052), (0x0159, 0x0072), (0x015A, 0x0053), (0x015B, 0x0073),
+    (0x015C, 0x0053), (0x015D, 0x0073), (0x015E, 0x0053), (0x015F, 0x0073), (0x0162, 0x0054),
+    (0x0163, 0x0074), (0x0164, 0x0054), (0x0165, 0x0074), (0x0168, 0x0055), (0x0169, 0x0075),
+    (0x016A, 0x0055), (0x016B, 0x0075), (0x016C, 0x0055), (0x016D, 0x0075), (0x016E, 0x0055),
+    (0x016F, 0x0075), (0x0170, 0x0055), (0x0171, 0x0075), (0x0172, 0x0055), (0x0173, 0x0075),
+    (0x0174, 0x0057), (0x0175, 0x0077), (0x0176, 0x0059), (0x0177, 0x0079), (0x0179, 0x005A),
+    (0x017A, 0x007A), (0x017B, 0x005A), (0x017C, 0x007A), (0x017F, 0x0073), (0x01A0, 0x004F),
+    (0x01A1, 0x006F), (0x01AF, 0x0055), (0x01B0, 0x0075), (0x01CD, 0x0041), (0x01CE, 0x0061),
+    (0x01CF, 0x0049), (0x01D0, 0x0069), (0x01D1, 0x004F), (0x01D2, 0x006F), (0x01D3, 0x0055),
+    (0x01D4, 0x0075), (0x01D5, 0x0055), (0x01D6, 0x0075), (0x01D7, 0x0055), (0x01D8, 0x0075),
+    (0x01D9, 0x0055), (0x01DA, 0x0075), (0x01DB, 0x0055), (0x01DC, 0x0075), (0x01DE, 0x0041),
+    (0x01DF, 0x0061), (0x01E0, 0x0041), (0x01E1, 0x0061), (0x01E2, 0x00C6), (0x01E3, 0x00E6),
+    (0x01E6, 0x0047), (0x01E7, 0x0067), (0x01E8, 0x004B), (0x01E9, 0x006B), (0x01EA, 0x004F),
+    (0x01EB, 0x006F), (0x01EC, 0x004F), (0x01ED, 0x006F), (0x01F0, 0x006A), (0x01F4, 0x0047),
+    (0x01F5, 0x0067), (0x01F8, 0x004E), (0x01F9, 0x006E), (0x01FA, 0x0041), (0x01FB, 0x0061),
+    (0x01FC, 0x00C6), (0x01FD, 0x00E6), (0x01FE, 0x00D8), (0x01FF, 0x00F8), (0x0200, 0x0041),
+    (0x0201, 0x0061), (0x0202, 0x0041), (0x0203, 0x0061), (0x0204, 0x0045), (0x0205, 0x0065),
+    (0x0206, 0x0045), (0x0207, 0x0065), (0x0208, 0x0049), (0x0209, 0x0069), (0x020A, 0x0049),
+    (0x020B, 0x0069), (0x020C, 0x004F), (0x020D, 0x006F), (0x020E, 0x004F), (0x020F, 0x006F),
+    (0x0210, 0x0052), (0x0211, 0x0072), (0x0212, 0x0052), (0x0213, 0x0072), (0x0214, 0x0055),
+    (0x0215, 0x0075), (0x0216, 0x0055), (0x0217, 0x0075), (0x0218, 0x0053), (0x0219, 0x0073),
+    (0x021A, 0x0054), (0x021B, 0x0074), (0x021E, 0x0048), (0x021F, 0x0068), (0x0226, 0x0041),
+    (0x0227, 0x0061), (0x0228, 0x0045), (0x0229, 0x0065), (0x022A, 0x004F), (0x022B, 0x006F),
+    (0x022C, 0x004F), (0x022D, 0x006F), (0x022E, 0x004F), (0x022F, 0x006F), (0x0230, 0x004F),
+    (0x0231, 0x006F), (0x0232, 0x0059), (0x0233, 0x0079), (0x02B0, 0x0068), (0x02B2, 0x006A),
+    (0x02B3, 0x0072), (0x02B7, 0x0077), (0x02B8, 0x0079), (0x02E1, 0x006C), (0x02E2, 0x0073),
+    (0x02E3, 0x0078), (0x037E, 0x003B), (0x0387, 0x00B7), (0x1D2C, 0x0041), (0x1D2D, 0x00C6),
+    (0x1D2E, 0x0042), (0x1D30, 0x0044), (0x1D31, 0x0045), (0x1D33, 0x0047), (0x1D34, 0x0048),
+    (0x1D35, 0x0049), (0x1D36, 0x004A), (0x1D37, 0x004B), (0x1D38, 0x004C), (0x1D39, 0x004D),
+    (0x1D3A, 0x004E), (0x1D3C, 0x004F), (0x1D3E, 0x0050), (0x1D3F, 0x0052), (0x1D40, 0x0054),
+    (0x1D41, 0x0055), (0x1D42, 0x0057), (0x1D43, 0x0061), (0x1D47, 0x0062), (0x1D48, 0x0064),
+    (0x1D49, 0x0065), (0x1D4D, 0x0067), (0x1D4F, 0x006B), (0x1D50, 0x006D), (0x1D52, 0x006F),
+    (0x1D56, 0x0070), (0x1D57, 0x0074), (0x1D58, 0x0075), (0x1D5B, 0x0076), (0x1D62, 0x0069),
+    (0x1D63, 0x0072), (0x1D64, 0x0075), (0x1D65, 0x0076), (0x1D9C, 0x0063), (0x1D9E, 0x00F0),
+    (0x1DA0, 0x0066), (0x1DBB, 0x007A), (0x1E00, 0x0041), (0x1E01, 0x0061), (0x1E02, 0x0042),
+    (0x1E03, 0x0062), (0x1E04, 0x0042), (0x1E05, 0x0062), (0x1E06, 0x0042), (0x1E07, 0x0062),
+    (0x1E08, 0x0043), (0x1E09, 0x0063), (0x1E0A, 0x0044), (0x1E0B, 0x0064), (0x1E0C, 0x0044),
+    (0x1E0D, 0x0064), (0x1E0E, 0x0044), (0x1E0F, 0x0064), (0x1E10, 0x0044), (0x1E11, 0x0064),
+    (0x1E12, 0x0044), (0x1E13, 0x0064), (0x1E14, 0x0045), (0x1E15, 0x0065), (0x1E16, 0x0045),
+    (0x1E17, 0x0065), (0x1E18, 0x0045), (0x1E19, 0x0065), (0x1E1A, 0x0045), (0x1E1B, 0x0065),
+    (0x1E1C, 0x0045), (0x1E1D, 0x0065), (0x1E1E, 0x0046), (0x1E1F, 0x0066), (0x1E20, 0x0047),
+    (0x1E21, 0x0067), (0x1E22, 0x0048), (0x1E23, 0x0068), (0x1E24, 0x0048), (0x1E25, 0x0068),
+    (0x1E26, 0x0048), (0x1E27, 0x0068), (0x1E28, 0x0048), (0x1E29, 0x0068), (0x1E2A, 0x0048),
+    (0x1E2B, 0x0068), (0x1E2C, 0x0049), (0x1E2D, 0x0069), (0x1E2E, 0x0049), (0x1E2F, 0x0069),
+    (0x1E30, 0x004B), (0x1E31, 0x006B), (0x1E32, 0x004B), (0x1E33, 0x006B), (0x1E34, 0x004B),
+    (0x1E35, 0x006B), (0x1E36, 0x004C), (0x1E37, 0x006C), (0x1E38, 0x004C), (0x1E39, 0x006C),
+    (0x1E3A, 0x004C), (0x1E3B, 0x006C), (0x1E3C, 0x004C), (0x1E3D, 0x006C), (0x1E3E, 0x004D),
+    (0x1E3F, 0x006D), (0x1E40, 0x004D), (0x1E41, 0x006D), (0x1E42, 0x004D), (0x1E43, 0x006D),
+    (0x1E44, 0x004E), (0x1E45, 0x006E), (0x1E46, 0x004E), (0x1E47, 0x006E), (0x1E48, 0x004E),
+    (0x1E49, 0x006E), (0x1E4A, 0x004E), (0x1E4B, 0x006E), (0x1E4C, 0x004F), (0x1E4D, 0x006F),
+    (0x1E4E, 0x004F), (0x1E4F, 0x006F), (0x1E50, 0x004F), (0x1E51, 0x006F), (0x1E52, 0x004F),
+    (0x1E53, 0x006F), (0x1E54, 0x0050), (0x1E55, 0x0070), (0x1E56, 0x0050), (0x1E57, 0x0070),
+    (0x1E58, 0x0052), (0x1E59, 0x0072), (0x1E5A, 0x0052), (0x1E5B, 0x0072), (0x1E5C, 0x0052),
+    (0x1E5D, 0x0072), (0x1E5E, 0x0052), (0x1E5F, 0x0072), (0x1E60, 0x0053), (0x1E61, 0x0073),
+    (0x1E62, 0x0053), (0x1E63, 0x0073), (0x1E64, 0x0053), (0x1E65, 0x0073), (0x1E66, 0x0053),
+    (0x1E67, 0x0073), (0x1E68, 0x0053), (0x1E69, 0x0073), (0x1E6A, 0x0054), (0x1E6B, 0x0074),
+    (0x1E6C, 0x0054), (0x1E6D, 0x0074), (0x1E6E, 0x0054), (0x1E6F, 0x0074), (0x1E70, 0x0054),
+    (0x1E71, 0x0074), (0x1E72, 0x0055), (0x1E73, 0x0075), (0x1E74, 0x0055), (0x1E75, 0x0075),
+    (0x1E76, 0x0055), (0x1E77, 0x0075), (0x1E78, 0x0055), (0x1E79, 0x0075), (0x1E7A, 0x0055),
+    (0x1E7B, 0x0075), (0x1E7C, 0x0056), (0x1E7D, 0x0076), (0x1E7E, 0x0056), (0x1E7F, 0x0076),
+    (0x1E80, 0x0057), (0x1E81, 0x0077), (0x1E82, 0x0057), (0x1E83, 0x0077), (0x1E84, 0x0057),
+    (0x1E85, 0x0077), (0x1E86, 0x0057), (0x1E87, 0x0077), (0x1E88, 0x0057), (0x1E89, 0x0077),
+    (0x1E8A, 0x0058), (0x1E8B, 0x0078), (0x1E8C, 0x0058), (0x1E8D, 0x0078), (0x1E8E, 0x0059),
+    (0x1E8F, 0x0079), (0x1E90, 0x005A), (0x1E91, 0x007A), (0x1E92, 0x005A), (0x1E93, 0x007A),
+    (0x1E94, 0x005A), (0x1E95, 0x007A), (0x1E96, 0x0068), (0x1E97, 0x0074), (0x1E98, 0x0077),
+    (0x1E99, 0x0079), (0x1E9B, 0x0073), (0x1EA0, 0x0041), (0x1EA1, 0x0061), (0x1EA2, 0x0041),
+    (0x1EA3, 0x0061), (0x1EA4, 0x0041), (0x1EA5, 0x0061), (0x1EA6, 0x0041), (0x1EA7, 0x0061),
+    (0x1EA8, 0x0041), (0x1EA9, 0x0061), (0x1EAA, 0x0041), (0x1EAB, 0x0061), (0x1EAC, 0x0041),
+    (0x1EAD, 0x0061), (0x1EAE, 0x0041), (0x1EAF, 0x0061), (0x1EB0, 0x0041), (0x1EB1, 0x0061),
+    (0x1EB2, 0x0041), (0x1EB3, 0x0061), (0x1EB4, 0x0041), (0x1EB5, 0x0061), (0x1EB6, 0x0041),
+    (0x1EB7, 0x0061), (0x1EB8, 0x0045), (0x1EB9, 0x0065), (0x1EBA, 0x0045), (0x1EBB, 0x0065),
+    (0x1EBC, 0x0045), (0x1EBD, 0x0065), (0x1EBE, 0x0045), (0x1EBF, 0x0065), (0x1EC0, 0x0045),
+    (0x1EC1, 0x0065), (0x1EC2, 0x0045), (0x1EC3, 0x0065), (0x1EC4, 0x0045), (0x1EC5, 0x0065),
+    (0x1EC6, 0x0045), (0x1EC7, 0x0065), (0x1EC8, 0x0049), (0x1EC9, 0x0069), (0x1ECA, 0x0049),
+    (0x1ECB, 0x0069), (0x1ECC, 0x004F), (0x1ECD, 0x006F), (0x1ECE, 0x004F), (0x1ECF, 0x006F),
+    (0x1ED0, 0x004F), (0x1ED1, 0x006F), (0x1ED2, 0x004F), (0x1ED3, 0x006F), (0x1ED4, 0x004F),
+    (0x1ED5, 0x006F), (0x1ED6, 0x004F), (0x1ED7, 0x006F), (0x1ED8, 0x004F), (0x1ED9, 0x006F),
+    (0x1EDA, 0x004F), (0x1EDB, 0x006F), (0x1EDC, 0x004F), (0x1EDD, 0x006F), (0x1EDE, 0x004F),
+    (0x1EDF, 0x006F), (0x1EE0, 0x004F), (0x1EE1, 0x006F), (0x1EE2, 0x004F), (0x1EE3, 0x006F),
+    (0x1EE4, 0x0055), (0x1EE5, 0x0075), (0x1EE6, 0x0055), (0x1EE7, 0x0075), (0x1EE8, 0x0055),
+    (0x1EE9, 0x0075), (0x1EEA, 0x0055), (0x1EEB, 0x0075), (0x1EEC, 0x0055), (0x1EED, 0x0075),
+    (0x1EEE, 0x0055), (0x1EEF, 0x0075), (0x1EF0, 0x0055), (0x1EF1, 0x0075), (0x1EF2, 0x0059),
+    (0x1EF3, 0x0079), (0x1EF4, 0x0059), (0x1EF5, 0x0079), (0x1EF6, 0x0059), (0x1EF7, 0x0079),
+    (0x1EF8, 0x0059), (0x1EF9, 0x0079), (0x1FEF, 0x0060), (0x2000, 0x0020), (0x2001, 0x0020),
+    (0x2002, 0x0020), (0x2003, 0x0020), (0x2004, 0x0020), (0x2005, 0x0020), (0x2006, 0x0020),
+    (0x2007, 0x0020), (0x2008, 0x0020), (0x2009, 0x0020), (0x200A, 0x0020), (0x2010, 0x002D),
+    (0x2012, 0x002D), (0x2015, 0x002D), (0x201B, 0x0027), (0x201F, 0x0022), (0x2024, 0x002E),
+    (0x202F, 0x0020), (0x2032, 0x0027), (0x2035, 0x0060), (0x205F, 0x0020), (0x2070, 0x0030),
+    (0x2071, 0x0069), (0x2074, 0x0034), (0x2075, 0x0035), (0x2076, 0x0036), (0x2077, 0x0037),
+    (0x2078, 0x0038), (0x2079, 0x0039), (0x207A, 0x002B), (0x207C, 0x003D), (0x207D, 0x0028),
+    (0x207E, 0x0029), (0x207F, 0x006E), (0x2080, 0x0030), (0x2081, 0x0031), (0x2082, 0x0032),
+    (0x2083, 0x0033), (0x2084, 0x0034), (0x2085, 0x0035), (0x2086, 0x0036), (0x2087, 0x0037),
+    (0x2088, 0x0038), (0x2089, 0x0039), (0x208A, 0x002B), (0x208C, 0x003D), (0x208D, 0x0028),
+    (0x208E, 0x0029), (0x2090, 0x0061), (0x2091, 0x0065), (0x2092, 0x006F), (0x2093, 0x0078),
+    (0x2095, 0x0068), (0x2096, 0x006B), (0x2097, 0x006C), (0x2098, 0x006D), (0x2099, 0x006E),
+    (0x209A, 0x0070), (0x209B, 0x0073), (0x209C, 0x0074), (0x2102, 0x0043), (0x210A, 0x0067),
+    (0x210B, 0x0048), (0x210C, 0x0048), (0x210D, 0x0048), (0x210E, 0x0068), (0x2110, 0x0049),
+    (0x2111, 0x0049), (0x2112, 0x004C), (0x2113, 0x006C), (0x2115, 0x004E), (0x2119, 0x0050),
+    (0x211A, 0x0051), (0x211B, 0x0052), (0x211C, 0x0052), (0x211D, 0x0052), (0x2124, 0x005A),
+    (0x2128, 0x005A), (0x212A, 0x004B), (0x212B, 0x0041), (0x212C, 0x0042), (0x212D, 0x0043),
+    (0x212F, 0x0065), (0x2130, 0x0045), (0x2131, 0x0046), (0x2133, 0x004D), (0x2134, 0x006F),
+    (0x2139, 0x0069), (0x2145, 0x0044), (0x2146, 0x0064), (0x2147, 0x0065), (0x2148, 0x0069),
+    (0x2149, 0x006A), (0x2160, 0x0049), (0x2164, 0x0056), (0x2169, 0x0058), (0x216C, 0x004C),
+    (0x216D, 0x0043), (0x216E, 0x0044), (0x216F, 0x004D), (0x2170, 0x0069), (0x2174, 0x0076),
+    (0x2179, 0x0078), (0x217C, 0x006C), (0x217D, 0x0063), (0x217E, 0x0064), (0x217F, 0x006D),
+    (0x2212, 0x002D), (0x2215, 0x002F), (0x2216, 0x005C), (0x2223, 0x007C), (0x223C, 0x007E),
+    (0x2260, 0x003D), (0x226E, 0x003C), (0x226F, 0x003E), (0x2460, 0x0031), (0x2461, 0x0032),
+    (0x2462, 0x0033), (0x2463, 0x0034), (0x2464, 0x0035), (0x2465, 0x0036), (0x2466, 0x0037),
+    (0x2467, 0x0038), (0x2468, 0x0039), (0x24B6, 0x0041), (0x24B7, 0x0042), (0x24B8, 0x0043),
+    (0x24B9, 0x0044), (0x24BA, 0x0045), (0x24BB, 0x0046), (0x24BC, 0x0047), (0x24BD, 0x0048),
+    (0x24BE, 0x0049), (0x24BF, 0x004A), (0x24C0, 0x004B), (0x24C1, 0x004C), (0x24C2, 0x004D),
+    (0x24C3, 0x004E), (0x24C4, 0x004F), (0x24C5, 0x0050), (0x24C6, 0x0051), (0x24C7, 0x0052),
+    (0x24C8, 0x0053), (0x24C9, 0x0054), (0x24CA, 0x0055), (0x24CB, 0x0056), (0x24CC, 0x0057),
+    (0x24CD, 0x0058), (0x24CE, 0x0059), (0x24CF, 0x005A), (0x24D0, 0x0061), (0x24D1, 0x0062),
+    (0x24D2, 0x0063), (0x24D3, 0x0064), (0x24D4, 0x0065), (0x24D5, 0x0066), (0x24D6, 0x0067),
+    (0x24D7, 0x0068), (0x24D8, 0x0069), (0x24D9, 0x006A), (0x24DA, 0x006B), (0x24DB, 0x006C),
+    (0x24DC, 0x006D), (0x24DD, 0x006E), (0x24DE, 0x006F), (0x24DF, 0x0070), (0x24E0, 0x0071),
+    (0x24E1, 0x0072), (0x24E2, 0x0073), (0x24E3, 0x0074), (0x24E4, 0x0075), (0x24E5, 0x0076),
+    (0x24E6, 0x0077), (0x24E7, 0x0078), (0x24E8, 0x0079), (0x24E9, 0x007A), (0x24EA, 0x0030),
+    (0x2C7C, 0x006A), (0x2C7D, 0x0056), (0x3000, 0x0020), (0xA7F2, 0x0043), (0xA7F3, 0x0046),
+    (0xA7F4, 0x0051), (0xA7F9, 0x009C), (0xFB29, 0x002B), (0xFE10, 0x002C), (0xFE13, 0x003A),
+    (0xFE14, 0x003B), (0xFE15, 0x0021), (0xFE16, 0x003F), (0xFE31, 0x0097), (0xFE32, 0x0096),
+    (0xFE33, 0x005F), (0xFE34, 0x005F), (0xFE35, 0x0028), (0xFE36, 0x0029), (0xFE37, 0x007B),
+    (0xFE38, 0x007D), (0xFE47, 0x005B), (0xFE48, 0x005D), (0xFE4D, 0x005F), (0xFE4E, 0x005F),
+    (0xFE4F, 0x005F), (0xFE50, 0x002C), (0xFE52, 0x002E), (0xFE54, 0x003B), (0xFE55, 0x003A),
+    (0xFE56, 0x003F), (0xFE57, 0x0021), (0xFE58, 0x0097), (0xFE59, 0x0028), (0xFE5A, 0x0029),
+    (0xFE5B, 0x007B), (0xFE5C, 0x007D), (0xFE5F, 0x0023), (0xFE60, 0x0026), (0xFE61, 0x002A),
+    (0xFE62, 0x002B), (0xFE63, 0x002D), (0xFE64, 0x003C), (0xFE65, 0x003E), (0xFE66, 0x003D),
+    (0xFE68, 0x005C), (0xFE69, 0x0024), (0xFE6A, 0x0025), (0xFE6B, 0x0040), (0xFF01, 0x0021),
+    (0xFF02, 0x0022), (0xFF03, 0x0023), (0xFF04, 0x0024), (0xFF05, 0x0025), (0xFF06, 0x0026),
+    (0xFF07, 0x0027), (0xFF08, 0x0028), (0xFF09, 0x0029), (0xFF0A, 0x002A), (0xFF0B, 0x002B),
+    (0xFF0C, 0x002C), (0xFF0D, 0x002D), (0xFF0E, 0x002E), (0xFF0F, 0x002F), (0xFF10, 0x0030),
+    (0xFF11, 0x0031), (0xFF12, 0x0032), (0xFF13, 0x0033), (0xFF14, 0x0034), (0xFF15, 0x0035),
+    (0xFF16, 0x0036), (0xFF17, 0x0037), (0xFF18, 0x0038), (0xFF19, 0x0039), (0xFF1A, 0x003A),
+    (0xFF1B, 0x003B), (0xFF1C, 0x003C), (0xFF1D, 0x003D), (0xFF1E, 0x003E), (0xFF1F, 0x003F),
+    (0xFF20, 0x0040), (0xFF21, 0x0041), (0xFF22, 0x0042), (0xFF23, 0x0043), (0xFF24, 0x0044),
+    (0xFF25, 0x0045), (0xFF26, 0x0046), (0xFF27, 0x0047), (0xFF28, 0x0048), (0xFF29, 0x0049),
+    (0xFF2A, 0x004A), (0xFF2B, 0x004B), (0xFF2C, 0x004C), (0xFF2D, 0x004D), (0xFF2E, 0x004E),
+    (0xFF2F, 0x004F), (0xFF30, 0x0050), (0xFF31, 0x0051), (0xFF32, 0x0052), (0xFF33, 0x0053),
+    (0xFF34, 0x0054), (0xFF35, 0x0055), (0xFF36, 0x0056), (0xFF37, 0x0057), (0xFF38, 0x0058),
+    (0xFF39, 0x0059), (0xFF3A, 0x005A), (0xFF3B, 0x005B), (0xFF3C, 0x005C), (0xFF3D, 0x005D),
+    (0xFF3E, 0x005E), (0xFF3F, 0x005F), (0xFF40, 0x0060), (0xFF41, 0x0061), (0xFF42, 0x0062),
+    (0xFF43, 0x0063), (0xFF44, 0x0064), (0xFF45, 0x0065), (0xFF46, 0x0066), (0xFF47, 0x0067),
+    (0xFF48, 0x0068), (0xFF49, 0x0069), (0xFF4A, 0x006A), (0xFF4B, 0x006B), (0xFF4C, 0x006C),
+    (0xFF4D, 0x006D), (0xFF4E, 0x006E), (0xFF4F, 0x006F), (0xFF50, 0x0070), (0xFF51, 0x0071),
+    (0xFF52, 0x0072), (0xFF53, 0x0073), (0xFF54, 0x0074), (0xFF55, 0x0075), (0xFF56, 0x0076),
+    (0xFF57, 0x0077), (0xFF58, 0x0078), (0xFF59, 0x0079), (0xFF5A, 0x007A), (0xFF5B, 0x007B),
+    (0xFF5C, 0x007C), (0xFF5D, 0x007D), (0xFF5E, 0x007E), (0xFFE0, 0x00A2), (0xFFE1, 0x00A3),
+    (0xFFE2, 0x00AC), (0xFFE4, 0x00A6), (0xFFE5, 0x00A5),
+];
+
+/// Code page 1253.
+#[rustfmt::skip]
+pub(crate) const CP1253: [(u16, u16); 1110] = [
+    (0x00AA, 0x0061), (0x00B9, 0x0031), (0x00BA, 0x006F), (0x00C0, 0x0041), (0x00C1, 0x0041),
+    (0x00C2, 0x0041), (0x00C3, 0x0041), (0x00C4, 0x0041), (0x00C5, 0x0041), (0x00C7, 0x0043),
+    (0x00C8, 0x0045), (0x00C9, 0x0045), (0x00CA, 0x0045), (0x00CB, 0x0045), (0x00CC, 0x0049),
+    (0x00CD, 0x0049), (0x00CE, 0x0049), (0x00CF, 0x0049), (0x00D1, 0x004E), (0x00D2, 0x004F),
+    (0x00D3, 0x004F), (0x00D4, 0x004F), (0x00D5, 0x004F), (0x00D6, 0x004F), (0x00D9, 0x0055),
+    (0x00DA, 0x0055), (0x00DB, 0x0055), (0x00DC, 0x0055), (0x00DD, 0x0059), (0x00E0, 0x0061),
+    (0x00E1, 0x0061), (0x00E2, 0x0061), (0x00E3, 0x0061), (0x00E4, 0x0061), (0x00E5, 0x0061),
+    (0x00E7, 0x0063), (0x00E8, 0x0065), (0x00E9, 0x0065), (0x00EA, 0x0065), (0x00EB, 0x0065),
+    (0x00EC, 0x0069), (0x00ED, 0x0069), (0x00EE, 0x0069), (0x00EF, 0x0069), (0x00F1, 0x006E),
+    (0x00F2, 0x006F), (0x00F3, 0x006F), (0x00F4, 0x006F), (0x00F5, 0x006F), (0x00F6, 0x006F),
+    (0x00F9, 0x0075), (0x00FA, 0x0075), (0x00FB, 0x0075), (0x00FC, 0x0075), (0x00FD, 0x0079),
+    (0x00FF, 0x0079), (0x0100, 0x0041), (0x0101, 0x0061), (0x0102, 0x0041), (0x0103, 0x0061),
+    (0x0104, 0x0041), (0x0105, 0x0061), (0x0106, 0x0043), (0x0107, 0x0063), (0x0108, 0x0043),
+    (0x0109, 0x0063), (0x010A, 0x0043), (0x010B, 0x0063), (0x010C, 0x0043), (0x010D, 0x0063),
+    (0x010E, 0x0044), (0x010F, 0x0064), (0x0112, 0x0045), (0x0113, 0x0065), (0x0114, 0x0045),
+    (0x0115, 0x0065), (0x0116, 0x0045), (0x0117, 0x0065), (0x0118, 0x0045), (0x0119, 0x0065),
+    (0x011A, 0x0045), (0x011B, 0x0065), (0x011C, 0x0047), (0x011D, 0x0067), (0x011E, 0x0047),
+    (0x011F, 0x0067), (0x0120, 0x0047), (0x0121, 0x0067), (0x0122, 0x0047), (0x0123, 0x0067),
+    (0x0124, 0x0048), (0x0125, 0x0068), (0x0128, 0x0049), (0x0129, 0x0069), (0x012A, 0x0049),
+    (0x012B, 0x0069), (0x012C, 0x0049), (0x012D, 0x0069), (0x012E, 0x0049), (0x012F, 0x0069),
+    (0x0130, 0x0049), (0x0134, 0x004A), (0x0135, 0x006A), (0x0136, 0x004B), (0x0137, 0x006B),
+    (0x0139, 0x004C), (0x013A, 0x006C), (0x013B, 0x004C), (0x013C, 0x006C), (0x013D, 0x004C),
+    (0x013E, 0x006C), (0x0143, 0x004E), (0x0144, 0x006E), (0x0145, 0x004E), (0x0146, 0x006E),
+    (0x0147, 0x004E), (0x0148, 0x006E), (0x014C, 0x004F), (0x014D, 0x006F), (0x014E, 0x004F),
+    (0x014F, 0x006F), (0x0150, 0x004F), (0x0151, 0x006F), (0x0154, 0x0052), (0x0155, 0x0072),
+    (0x0156, 0x0052), (0x0157, 0x0072), (0x0158, 0x0052), (0x0159, 0x0072), (0x015A, 0x0053),
+    (0x015B, 0x0073), (0x015C, 0x0053), (0x015D, 0x0073), (0x015E, 0x0053), (0x015F, 0x0073),
+    (0x0160, 0x0053), (0x0161, 0x0073), (0x0162, 0x0054), (0x0163, 0x0074), (0x0164, 0x0054),
+    (0x0165, 0x0074), (0x0168, 0x0055), (0x0169, 0x0075), (0x016A, 0x0055), (0x016B, 0x0075),
+    (0x016C, 0x0055), (0x016D, 0x0075), (0x016E, 0x0055), (0x016F, 0x0075), (0x0170, 0x0055),
+    (0x0171, 0x0075), (0x0172, 0x0055), (0x0173, 0x0075), (0x0174, 0x0057), (0x0175, 0x0077),
+    (0x0176, 0x0059), (0x0177, 0x0079), (0x0178, 0x0059), (0x0179, 0x005A), (0x017A, 0x007A),
+    (0x017B, 0x005A), (0x017C, 0x007A), (0x017D, 0x005A), (0x017E, 0x007A), (0x017F, 0x0073),
+    (0x01A0, 0x004F), (0x01A1, 0x006F), (0x01AF, 0x0055), (0x01B0, 0x0075), (0x01CD, 0x0041),
+    (0x01CE, 0x0061), (0x01CF, 0x0049), (0x01D0, 0x0069), (0x01D1, 0x004F), (0x01D2, 0x006F),
+    (0x01D3, 0x0055), (0x01D4, 0x0075), (0x01D5, 0x0055), (0x01D6, 0x0075), (0x01D7, 0x0055),
+    (0x01D8, 0x0075), (0x01D9, 0x0055), (0x01DA, 0x0075), (0x01DB, 0x0055), (0x01DC, 0x0075),
+    (0x01DE, 0x0041), (0x01DF, 0x0061), (0x01E0, 0x0041), (0x01E1, 0x0061), (0x01E6, 0x0047),
+    (0x01E7, 0x0067), (0x01E8, 0x004B), (0x01E9, 0x006B), (0x01EA, 0x004F), (0x01EB, 0x006F),
+    (0x01EC, 0x004F), (0x01ED, 0x006F), (0x01F0, 0x006A), (0x01F4, 0x0047), (0x01F5, 0x0067),
+    (0x01F8, 0x004E), (0x01F9, 0x006E), (0x01FA, 0x0041), (0x01FB, 0x0061), (0x0200, 0x0041),
+    (0x0201, 0x0061), (0x0202, 0x0041), (0x0203, 0x0061), (0x0204, 0x0045), (0x0205, 0x0065),
+    (0x0206, 0x0045), (0x0207, 0x0065), (0x0208, 0x0049), (0x0209, 0x0069), (0x020A, 0x0049),
+    (0x020B, 0x0069), (0x020C, 0x004F), (0x020D, 0x006F), (0x020E, 0x004F), (0x020F, 0x006F),
+    (0x0210, 0x0052), (0x0211, 0x0072), (0x0212, 0x0052), (0x0213, 0x0072), (0x0214, 0x0055),
+    (0x0215, 0x0075), (0x0216, 0x0055), (0x0217, 0x0075), (0x0218, 0x0053), (0x0219, 0x0073),
+    (0x021A, 0x0054), (0x021B, 0x0074), (0x021E, 0x0048), (0x021F, 0x0068), (0x0226, 0x0041),
+    (0x0227, 0x0061), (0x0228, 0x0045), (0x0229, 0x0065), (0x022A, 0x004F), (0x022B, 0x006F),
+    (0x022C, 0x004F), (0x022D, 0x006F), (0x022E, 0x004F), (0x022F, 0x006F), (0x0230, 0x004F),
+    (0x0231, 0x006F), (0x0232, 0x0059), (0x0233, 0x0079), (0x02B0, 0x0068), (0x02B2, 0x006A),
+    (0x02B3, 0x0072), (0x02B7, 0x0077), (0x02B8, 0x0079), (0x02E1, 0x006C), (0x02E2, 0x0073),
+    (0x02E3, 0x0078), (0x037E, 0x003B), (0x0387, 0x00B7), (0x03D0, 0x00E2), (0x03D1, 0x00E8),
+    (0x03D2, 0x00D5), (0x03D3, 0x00D5), (0x03D4, 0x00D5), (0x03D5, 0x00F6), (0x03D6, 0x00F0),
+    (0x03F0, 0x00EA), (0x03F1, 0x00F1), (0x03F2, 0x00F2), (0x03F4, 0x00C8), (0x03F5, 0x00E5),
+    (0x03F9, 0x00D3), (0x1D2C, 0x0041), (0x1D2E, 0x0042), (0x1D30, 0x0044), (0x1D31, 0x0045),
+    (0x1D33, 0x0047), (0x1D34, 0x0048), (0x1D35, 0x0049), (0x1D36, 0x004A), (0x1D37, 0x004B),
+    (0x1D38, 0x004C), (0x1D39, 0x004D), (0x1D3A, 0x004E), (0x1D3C, 0x004F), (0x1D3E, 0x0050),
+    (0x1D3F, 0x0052), (0x1D40, 0x0054), (0x1D41, 0x0055), (0x1D42, 0x0057), (0x1D43, 0x0061),
+    (0x1D47, 0x0062), (0x1D48, 0x0064), (0x1D49, 0x0065), (0x1D4D, 0x0067), (0x1D4F, 0x006B),
+    (0x1D50, 0x006D), (0x1D52, 0x006F), (0x1D56, 0x0070), (0x1D57, 0x0074), (0x1D58, 0x0075),
+    (0x1D5B, 0x0076), (0x1D5D, 0x00E2), (0x1D5E, 0x00E3), (0x1D5F, 0x00E4), (0x1D60, 0x00F6),
+    (0x1D61, 0x00F7), (0x1D62, 0x0069), (0x1D63, 0x0072), (0x1D64, 0x0075), (0x1D65, 0x0076),
+    (0x1D66, 0x00E2), (0x1D67, 0x00E3), (0x1D68, 0x00F1), (0x1D69, 0x00F6), (0x1D6A, 0x00F7),
+    (0x1D9C, 0x0063), (0x1DA0, 0x0066), (0x1DBB, 0x007A), (0x1DBF, 0x00E8), (0x1E00, 0x0041),
+    (0x1E01, 0x0061), (0x1E02, 0x0042), (0x1E03, 0x0062), (0x1E04, 0x0042), (0x1E05, 0x0062),
+    (0x1E06, 0x0042), (0x1E07, 0x0062), (0x1E08, 0x0043), (0x1E09, 0x0063), (0x1E0A, 0x0044),
+    (0x1E0B, 0x0064), (0x1E0C, 0x0044), (0x1E0D, 0x0064), (0x1E0E, 0x0044), (0x1E0F, 0x0064),
+    (0x1E10, 0x0044), (0x1E11, 0x0064), (0x1E12, 0x0044), (0x1E13, 0x0064), (0x1E14, 0x0045),
+    (0x1E15, 0x0065), (0x1E16, 0x0045), (0x1E17, 0x0065), (0x1E18, 0x0045), (0x1E19, 0x0065),
+    (0x1E1A, 0x0045), (0x1E1B, 0x0065), (0x1E1C, 0x0045), (0x1E1D, 0x0065), (0x1E1E, 0x0046),
+    (0x1E1F, 0x0066), (0x1E20, 0x0047), (0x1E21, 0x0067), (0x1E22, 0x0048), (0x1E23, 0x0068),
+    (0x1E24, 0x0048), (0x1E25, 0x0068), (0x1E26, 0x0048), (0x1E27, 0x0068), (0x1E28, 0x0048),
+    (0x1E29, 0x0068), (0x1E2A, 0x0048), (0x1E2B, 0x0068), (0x1E2C, 0x0049), (0x1E2D, 0x0069),
+    (0x1E2E, 0x0049), (0x1E2F, 0x0069), (0x1E30, 0x004B), (0x1E31, 0x006B), (0x1E32, 0x004B),
+    (0x1E33, 0x006B), (0x1E34, 0x004B), (0x1E35, 0x006B), (0x1E36, 0x004C), (0x1E37, 0x006C),
+    (0x1E38, 0x004C), (0x1E39, 0x006C), (0x1E3A, 0x004C), (0x1E3B, 0x006C), (0x1E3C, 0x004C),
+    (0x1E3D, 0x006C), (0x1E3E, 0x004D), (0x1E3F, 0x006D), (0x1E40, 0x004D), (0x1E41, 0x006D),
+    (0x1E42, 0x004D), (0x1E43, 0x006D), (0x1E44, 0x004E), (0x1E45, 0x006E), (0x1E46, 0x004E),
+    (0x1E47, 0x006E), (0x1E48, 0x004E), (0x1E49, 0x006E), (0x1E4A, 0x004E), (0x1E4B, 0x006E),
+    (0x1E4C, 0x004F), (0x1E4D, 0x006F), (0x1E4E, 0x004F), (0x1E4F, 0x006F), (0x1E50, 0x004F),
+    (0x1E51, 0x006F), (0x1E52, 0x004F), (0x1E53, 0x006F), (0x1E54, 0x0050), (0x1E55, 0x0070),
+    (0x1E56, 0x0050), (0x1E57, 0x0070), (0x1E58, 0x0052), (0x1E59, 0x0072), (0x1E5A, 0x0052),
+    (0x1E5B, 0x0072), (0x1E5C, 0x0052), (0x1E5D, 0x0072), (0x1E5E, 0x0052), (0x1E5F, 0x0072),
+    (0x1E60, 0x0053), (0x1E61, 0x0073), (0x1E62, 0x0053), (0x1E63, 0x0073), (0x1E64, 0x0053),
+    (0x1E65, 0x0073), (0x1E66, 0x0053), (0x1E67, 0x0073), (0x1E68, 0x0053), (0x1E69, 0x0073),
+    (0x1E6A, 0x0054), (0x1E6B, 0x0074), (0x1E6C, 0x0054), (0x1E6D, 0x0074), (0x1E6E, 0x0054),
+    (0x1E6F, 0x0074), (0x1E70, 0x0054), (0x1E71, 0x0074), (0x1E72, 0x0055), (0x1E73, 0x0075),
+    (0x1E74, 0x0055), (0x1E75, 0x0075), (0x1E76, 0x0055), (0x1E77, 0x0075), (0x1E78, 0x0055),
+    (0x1E79, 0x0075), (0x1E7A, 0x0055), (0x1E7B, 0x0075), (0x1E7C, 0x0056), (0x1E7D, 0x0076),
+    (0x1E7E, 0x0056), (0x1E7F, 0x0076), (0x1E80, 0x0057), (0x1E81, 0x0077), (0x1E82, 0x0057),
+    (0x1E83, 0x0077), (0x1E84, 0x0057), (0x1E85, 0x0077), (0x1E86, 0x0057), (0x1E87, 0x0077),
+    (0x1E88, 0x0057), (0x1E89, 0x0077), (0x1E8A, 0x0058), (0x1E8B, 0x0078), (0x1E8C, 0x0058),
+    (0x1E8D, 0x0078), (0x1E8E, 0x0059), (0x1E8F, 0x0079), (0x1E90, 0x005A), (0x1E91, 0x007A),
+    (0x1E92, 0x005A), (0x1E93, 0x007A), (0x1E94, 0x005A), (0x1E95, 0x007A), (0x1E96, 0x0068),
+    (0x1E97, 0x0074), (0x1E98, 0x0077), (0x1E99, 0x0079), (0x1E9B, 0x0073), (0x1EA0, 0x0041),
+    (0x1EA1, 0x0061), (0x1EA2, 0x0041), (0x1EA3, 0x0061), (0x1EA4, 0x0041), (0x1EA5, 0x0061),
+    (0x1EA6, 0x0041), (0x1EA7, 0x0061), (0x1EA8, 0x0041), (0x1EA9, 0x0061), (0x1EAA, 0x0041),
+    (0x1EAB, 0x0061), (0x1EAC, 0x0041), (0x1EAD, 0x0061), (0x1EAE, 0x0041), (0x1EAF, 0x0061),
+    (0x1EB0, 0x0041), (0x1EB1, 0x0061), (0x1EB2, 0x0041), (0x1EB3, 0x0061), (0x1EB4, 0x0041),
+    (0x1EB5, 0x0061), (0x1EB6, 0x0041), (0x1EB7, 0x0061), (0x1EB8, 0x0045), (0x1EB9, 0x0065),
+    (0x1EBA, 0x0045), (0x1EBB, 0x0065), (0x1EBC, 0x0045), (0x1EBD, 0x0065), (0x1EBE, 0x0045),
+    (0x1EBF, 0x0065), (0x1EC0, 0x0045), (0x1EC1, 0x0065), (0x1EC2, 0x0045), (0x1EC3, 0x0065),
+    (0x1EC4, 0x0045), (0x1EC5, 0x0065), (0x1EC6, 0x0045), (0x1EC7, 0x0065), (0x1EC8, 0x0049),
+    (0x1EC9, 0x0069), (0x1ECA, 0x0049), (0x1ECB, 0x0069), (0x1ECC, 0x004F), (0x1ECD, 0x006F),
+    (0x1ECE, 0x004F), (0x1ECF, 0x006F), (0x1ED0, 0x004F), (0x1ED1, 0x006F), (0x1ED2, 0x004F),
+    (0x1ED3, 0x006F), (0x1ED4, 0x004F), (0x1ED5, 0x006F), (0x1ED6, 0x004F), (0x1ED7, 0x006F),
+    (0x1ED8, 0x004F), (0x1ED9, 0x006F), (0x1EDA, 0x004F), (0x1EDB, 0x006F), (0x1EDC, 0x004F),
+    (0x1EDD, 0x006F), (0x1EDE, 0x004F), (0x1EDF, 0x006F), (0x1EE0, 0x004F), (0x1EE1, 0x006F),
+    (0x1EE2, 0x004F), (0x1EE3, 0x006F), (0x1EE4, 0x0055), (0x1EE5, 0x0075), (0x1EE6, 0x0055),
+    (0x1EE7, 0x0075), (0x1EE8, 0x0055), (0x1EE9, 0x0075), (0x1EEA, 0x0055), (0x1EEB, 0x0075),
+    (0x1EEC, 0x0055), (0x1EED, 0x0075), (0x1EEE, 0x0055), (0x1EEF, 0x0075), (0x1EF0, 0x0055),
+    (0x1EF1, 0x0075), (0x1EF2, 0x0059), (0x1EF3, 0x0079), (0x1EF4, 0x0059), (0x1EF5, 0x0079),
+    (0x1EF6, 0x0059), (0x1EF7, 0x0079), (0x1EF8, 0x0059), (0x1EF9, 0x0079), (0x1F00, 0x00E1),
+    (0x1F01, 0x00E1), (0x1F02, 0x00E1), (0x1F03, 0x00E1), (0x1F04, 0x00E1), (0x1F05, 0x00E1),
+    (0x1F06, 0x00E1), (0x1F07, 0x00E1), (0x1F08, 0x00C1), (0x1F09, 0x00C1), (0x1F0A, 0x00C1),
+    (0x1F0B, 0x00C1), (0x1F0C, 0x00C1), (0x1F0D, 0x00C1), (0x1F0E, 0x00C1), (0x1F0F, 0x00C1),
+    (0x1F10, 0x00E5), (0x1F11, 0x00E5), (0x1F12, 0x00E5), (0x1F13, 0x00E5), (0x1F14, 0x00E5),
+    (0x1F15, 0x00E5), (0x1F18, 0x00C5), (0x1F19, 0x00C5), (0x1F1A, 0x00C5), (0x1F1B, 0x00C5),
+    (0x1F1C, 0x00C5), (0x1F1D, 0x00C5), (0x1F20, 0x00E7), (0x1F21, 0x00E7), (0x1F22, 0x00E7),
+    (0x1F23, 0x00E7), (0x1F24, 0x00E7), (0x1F25, 0x00E7), (0x1F26, 0x00E7), (0x1F27, 0x00E7),
+    (0x1F28, 0x00C7), (0x1F29, 0x00C7), (0x1F2A, 0x00C7), (0x1F2B, 0x00C7), (0x1F2C, 0x00C7),
+    (0x1F2D, 0x00C7), (0x1F2E, 0x00C7), (0x1F2F, 0x00C7), (0x1F30, 0x00E9), (0x1F31, 0x00E9),
+    (0x1F32, 0x00E9), (0x1F33, 0x00E9), (0x1F34, 0x00E9), (0x1F35, 0x00E9), (0x1F36, 0x00E9),
+    (0x1F37, 0x00E9), (0x1F38, 0x00C9), (0x1F39, 0x00C9), (0x1F3A, 0x00C9), (0x1F3B, 0x00C9),
+    (0x1F3C, 0x00C9), (0x1F3D, 0x00C9), (0x1F3E, 0x00C9), (0x1F3F, 0x00C9), (0x1F40, 0x00EF),
+    (0x1F41, 0x00EF), (0x1F42, 0x00EF), (0x1F43, 0x00EF), (0x1F44, 0x00EF), (0x1F45, 0x00EF),
+    (0x1F48, 0x00CF), (0x1F49, 0x00CF), (0x1F4A, 0x00CF), (0x1F4B, 0x00CF), (0x1F4C, 0x00CF),
+    (0x1F4D, 0x00CF), (0x1F50, 0x00F5), (0x1F51, 0x00F5), (0x1F52, 0x00F5), (0x1F53, 0x00F5),
+    (0x1F54, 0x00F5), (0x1F55, 0x00F5), (0x1F56, 0x00F5), (0x1F57, 0x00F5), (0x1F59, 0x00D5),
+    (0x1F5B, 0x00D5), (0x1F5D, 0x00D5), (0x1F5F, 0x00D5), (0x1F60, 0x00F9), (0x1F61, 0x00F9),
+    (0x1F62, 0x00F9), (0x1F63, 0x00F9), (0x1F64, 0x00F9), (0x1F65, 0x00F9), (0x1F66, 0x00F9),
+    (0x1F67, 0x00F9), (0x1F68, 0x00D9), (0x1F69, 0x00D9), (0x1F6A, 0x00D9), (0x1F6B, 0x00D9),
+    (0x1F6C, 0x00D9), (0x1F6D, 0x00D9), (0x1F6E, 0x00D9), (0x1F6F, 0x00D9), (0x1F70, 0x00E1),
+    (0x1F71, 0x00E1), (0x1F72, 0x00E5), (0x1F73, 0x00E5), (0x1F74, 0x00E7), (0x1F75, 0x00E7),
+    (0x1F76, 0x00E9), (0x1F77, 0x00E9), (0x1F78, 0x00EF), (0x1F79, 0x00EF), (0x1F7A, 0x00F5),
+    (0x1F7B, 0x00F5), (0x1F7C, 0x00F9), (0x1F7D, 0x00F9), (0x1F80, 0x00E1), (0x1F81, 0x00E1),
+    (0x1F82, 0x00E1), (0x1F83, 0x00E1), (0x1F84, 0x00E1), (0x1F85, 0x00E1), (0x1F86, 0x00E1),
+    (0x1F87, 0x00E1), (0x1F88, 0x00C1), (0x1F89, 0x00C1), (0x1F8A, 0x00C1), (0x1F8B, 0x00C1),
+    (0x1F8C, 0x00C1), (0x1F8D, 0x00C1), (0x1F8E, 0x00C1), (0x1F8F, 0x00C1), (0x1F90, 0x00E7),
+    (0x1F91, 0x00E7), (0x1F92, 0x00E7), (0x1F93, 0x00E7), (0x1F94, 0x00E7), (0x1F95, 0x00E7),
+    (0x1F96, 0x00E7), (0x1F97, 0x00E7), (0x1F98, 0x00C7), (0x1F99, 0x00C7), (0x1F9A, 0x00C7),
+    (0x1F9B, 0x00C7), (0x1F9C, 0x00C7), (0x1F9D, 0x00C7), (0x1F9E, 0x00C7), (0x1F9F, 0x00C7),
+    (0x1FA0, 0x00F9), (0x1FA1, 0x00F9), (0x1FA2, 0x00F9), (0x1FA3, 0x00F9), (0x1FA4, 0x00F9),
+    (0x1FA5, 0x00F9), (0x1FA6, 0x00F9), (0x1FA7, 0x00F9), (0x1FA8, 0x00D9), (0x1FA9, 0x00D9),
+    (0x1FAA, 0x00D9), (0x1FAB, 0x00D9), (0x1FAC, 0x00D9), (0x1FAD, 0x00D9), (0x1FAE, 0x00D9),
+    (0x1FAF, 0x00D9), (0x1FB0, 0x00E1), (0x1FB1, 0x00E1), (0x1FB2, 0x00E1), (0x1FB3, 0x00E1),
+    (0x1FB4, 0x00E1), (0x1FB6, 0x00E1), (0x1FB7, 0x00E1), (0x1FB8, 0x00C1), (0x1FB9, 0x00C1),
+    (0x1FBA, 0x00C1), (0x1FBB, 0x00C1), (0x1FBC, 0x00C1), (0x1FBE, 0x00E9), (0x1FC2, 0x00E7),
+    (0x1FC3, 0x00E7), (0x1FC4, 0x00E7), (0x1FC6, 0x00E7), (0x1FC7, 0x00E7), (0x1FC8, 0x00C5),
+    (0x1FC9, 0x00C5), (0x1FCA, 0x00C7), (0x1FCB, 0x00C7), (0x1FCC, 0x00C7), (0x1FD0, 0x00E9),
+    (0x1FD1, 0x00E9), (0x1FD2, 0x00E9), (0x1FD3, 0x00E9), (0x1FD6, 0x00E9), (0x1FD7, 0x00E9),
+    (0x1FD8, 0x00C9), (0x1FD9, 0x00C9), (0x1FDA, 0x00C9), (0x1FDB, 0x00C9), (0x1FE0, 0x00F5),
+    (0x1FE1, 0x00F5), (0x1FE2, 0x00F5), (0x1FE3, 0x00F5), (0x1FE4, 0x00F1), (0x1FE5, 0x00F1),
+    (0x1FE6, 0x00F5), (0x1FE7, 0x00F5), (0x1FE8, 0x00D5), (0x1FE9, 0x00D5), (0x1FEA, 0x00D5),
+    (0x1FEB, 0x00D5), (0x1FEC, 0x00D1), (0x1FEF, 0x0060), (0x1FF2, 0x00F9), (0x1FF3, 0x00F9),
+    (0x1FF4, 0x00F9), (0x1FF6, 0x00F9), (0x1FF7, 0x00F9), (0x1FF8, 0x00CF), (0x1FF9, 0x00CF),
+    (0x1FFA, 0x00D9), (0x1FFB, 0x00D9), (0x1FFC, 0x00D9), (0x2000, 0x0020), (0x2001, 0x0020),
+    (0x2002, 0x0020), (0x2003, 0x0020), (0x2004, 0x0020), (0x2005, 0x0020), (0x2006, 0x0020),
+    (0x2007, 0x0020), (0x2008, 0x0020), (0x2009, 0x0020), (0x200A, 0x0020), (0x2010, 0x002D),
+    (0x2012, 0x002D), (0x201B, 0x0027), (0x201F, 0x0022), (0x2024, 0x002E), (0x202F, 0x0020),
+    (0x2032, 0x0027), (0x2035, 0x0060), (0x205F, 0x0020), (0x2070, 0x0030), (0x2071, 0x0069),
+    (0x2074, 0x0034), (0x2075, 0x0035), (0x2076, 0x0036), (0x2077, 0x0037), (0x2078, 0x0038),
+    (0x2079, 0x0039), (0x207A, 0x002B), (0x207C, 0x003D), (0x207D, 0x0028), (0x207E, 0x0029),
+    (0x207F, 0x006E), (0x2080, 0x0030), (0x2081, 0x0031), (0x2082, 0x0032), (0x2083, 0x0033),
+    (0x2084, 0x0034), (0x2085, 0x0035), (0x2086, 0x0036), (0x2087, 0x0037), (0x2088, 0x0038),
+    (0x2089, 0x0039), (0x208A, 0x002B), (0x208C, 0x003D), (0x208D, 0x0028), (0x208E, 0x0029),
+    (0x2090, 0x0061), (0x2091, 0x0065), (0x2092, 0x006F), (0x2093, 0x0078), (0x2095, 0x0068),
+    (0x2096, 0x006B), (0x2097, 0x006C), (0x2098, 0x006D), (0x2099, 0x006E), (0x209A, 0x0070),
+    (0x209B, 0x0073), (0x209C, 0x0074), (0x2102, 0x0043), (0x210A, 0x0067), (0x210B, 0x0048),
+    (0x210C, 0x0048), (0x210D, 0x0048), (0x210E, 0x0068), (0x2110, 0x0049), (0x2111, 0x0049),
+    (0x2112, 0x004C), (0x2113, 0x006C), (0x2115, 0x004E), (0x2119, 0x0050), (0x211A, 0x0051),
+    (0x211B, 0x0052), (0x211C, 0x0052), (0x211D, 0x0052), (0x2124, 0x005A), (0x2126, 0x00D9),
+    (0x2128, 0x005A), (0x212A, 0x004B), (0x212B, 0x0041), (0x212C, 0x0042), (0x212D, 0x0043),
+    (0x212F, 0x0065), (0x2130, 0x0045), (0x2131, 0x0046), (0x2133, 0x004D), (0x2134, 0x006F),
+    (0x2139, 0x0069), (0x213C, 0x00F0), (0x213D, 0x00E3), (0x213E, 0x00C3), (0x213F, 0x00D0),
+    (0x2145, 0x0044), (0x2146, 0x0064), (0x2147, 0x0065), (0x2148, 0x0069), (0x2149, 0x006A),
+    (0x2160, 0x0049), (0x2164, 0x0056), (0x2169, 0x0058), (0x216C, 0x004C), (0x216D, 0x0043),
+    (0x216E, 0x0044), (0x216F, 0x004D), (0x2170, 0x0069), (0x2174, 0x0076), (0x2179, 0x0078),
+    (0x217C, 0x006C), (0x217D, 0x0063), (0x217E, 0x0064), (0x217F, 0x006D), (0x2212, 0x002D),
+    (0x2215, 0x002F), (0x2216, 0x005C), (0x2223, 0x007C), (0x223C, 0x007E), (0x2260, 0x003D),
+    (0x226E, 0x003C), (0x226F, 0x003E), (0x2460, 0x0031), (0x2461, 0x0032), (0x2462, 0x0033),
+    (0x2463, 0x0034), (0x2464, 0x0035), (0x2465, 0x0036), (0x2466, 0x0037), (0x2467, 0x0038),
+    (0x2468, 0x0039), (0x24B6, 0x0041), (0x24B7, 0x0042), (0x24B8, 0x0043), (0x24B9, 0x0044),
+    (0x24BA, 0x0045), (0x24BB, 0x0046), (0x24BC, 0x0047), (0x24BD, 0x0048), (0x24BE, 0x0049),
+    (0x24BF, 0x004A), (0x24C0, 0x004B), (0x24C1, 0x004C), (0x24C2, 0x004D), (0x24C3, 0x004E),
+    (0x24C4, 0x004F), (0x24C5, 0x0050), (0x24C6, 0x0051), (0x24C7, 0x0052), (0x24C8, 0x0053),
+    (0x24C9, 0x0054), (0x24CA, 0x0055), (0x24CB, 0x0056), (0x24CC, 0x0057), (0x24CD, 0x0058),
+    (0x24CE, 0x0059), (0x24CF, 0x005A), (0x24D0, 0x0061), (0x24D1, 0x0062), (0x24D2, 0x0063),
+    (0x24D3, 0x0064), (0x24D4, 0x0065), (0x24D5, 0x0066), (0x24D6, 0x0067), (0x24D7, 0x0068),
+    (0x24D8, 0x0069), (0x24D9, 0x006A), (0x24DA, 0x006B), (0x24DB, 0x006C), (0x24DC, 0x006D),
+    (0x24DD, 0x006E), (0x24DE, 0x006F), (0x24DF, 0x0070), (0x24E0, 0x0071), (0x24E1, 0x0072),
+    (0x24E2, 0x0073), (0x24E3, 0x0074), (0x24E4, 0x0075), (0x24E5, 0x0076), (0x24E6, 0x0077),
+    (0x24E7, 0x0078), (0x24E8, 0x0079), (0x24E9, 0x007A), (0x24EA, 0x0030), (0x2C7C, 0x006A),
+    (0x2C7D, 0x0056), (0x3000, 0x0020), (0xA7F2, 0x0043), (0xA7F3, 0x0046), (0xA7F4, 0x0051),
+    (0xFB29, 0x002B), (0xFE10, 0x002C), (0xFE13, 0x003A), (0xFE14, 0x003B), (0xFE15, 0x0021),
+    (0xFE16, 0x003F), (0xFE31, 0x0097), (0xFE32, 0x0096), (0xFE33, 0x005F), (0xFE34, 0x005F),
+    (0xFE35, 0x0028), (0xFE36, 0x0029), (0xFE37, 0x007B), (0xFE38, 0x007D), (0xFE47, 0x005B),
+    (0xFE48, 0x005D), (0xFE4D, 0x005F), (0xFE4E, 0x005F), (0xFE4F, 0x005F), (0xFE50, 0x002C),
+    (0xFE52, 0x002E), (0xFE54, 0x003B), (0xFE55, 0x003A), (0xFE56, 0x003F), (0xFE57, 0x0021),
+    (0xFE58, 0x0097), (0xFE59, 0x0028), (0xFE5A, 0x0029), (0xFE5B, 0x007B), (0xFE5C, 0x007D),
+    (0xFE5F, 0x0023), (0xFE60, 0x0026), (0xFE61, 0x002A), (0xFE62, 0x002B), (0xFE63, 0x002D),
+    (0xFE64, 0x003C), (0xFE65, 0x003E), (0xFE66, 0x003D), (0xFE68, 0x005C), (0xFE69, 0x0024),
+    (0xFE6A, 0x0025), (0xFE6B, 0x0040), (0xFF01, 0x0021), (0xFF02, 0x0022), (0xFF03, 0x0023),
+    (0xFF04, 0x0024), (0xFF05, 0x0025), (0xFF06, 0x0026), (0xFF07, 0x0027), (0xFF08, 0x0028),
+    (0xFF09, 0x0029), (0xFF0A, 0x002A), (0xFF0B, 0x002B), (0xFF0C, 0x002C), (0xFF0D, 0x002D),
+    (0xFF0E, 0x002E), (0xFF0F, 0x002F), (0xFF10, 0x0030), (0xFF11, 0x0031), (0xFF12, 0x0032),
+    (0xFF13, 0x0033), (0xFF14, 0x0034), (0xFF15, 0x0035), (0xFF16, 0x0036), (0xFF17, 0x0037),
+    (0xFF18, 0x0038), (0xFF19, 0x0039), (0xFF1A, 0x003A), (0xFF1B, 0x003B), (0xFF1C, 0x003C),
+    (0xFF1D, 0x003D), (0xFF1E, 0x003E), (0xFF1F, 0x003F), (0xFF20, 0x0040), (0xFF21, 0x0041),
+    (0xFF22, 0x0042), (0xFF23, 0x0043), (0xFF24, 0x0044), (0xFF25, 0x0045), (0xFF26, 0x0046),
+    (0xFF27, 0x0047), (0xFF28, 0x0048), (0xFF29, 0x0049), (0xFF2A, 0x004A), (0xFF2B, 0x004B),
+    (0xFF2C, 0x004C), (0xFF2D, 0x004D), (0xFF2E, 0x004E), (0xFF2F, 0x004F), (0xFF30, 0x0050),
+    (0xFF31, 0x0051), (0xFF32, 0x0052), (0xFF33, 0x0053), (0xFF34, 0x0054), (0xFF35, 0x0055),
+    (0xFF36, 0x0056), (0xFF37, 0x0057), (0xFF38, 0x0058), (0xFF39, 0x0059), (0xFF3A, 0x005A),
+    (0xFF3B, 0x005B), (0xFF3C, 0x005C), (0xFF3D, 0x005D), (0xFF3E, 0x005E), (0xFF3F, 0x005F),
+    (0xFF40, 0x0060), (0xFF41, 0x0061), (0xFF42, 0x0062), (0xFF43, 0x0063), (0xFF44, 0x0064),
+    (0xFF45, 0x0065), (0xFF46, 0x0066), (0xFF47, 0x0067), (0xFF48, 0x0068), (0xFF49, 0x0069),
+    (0xFF4A, 0x006A), (0xFF4B, 0x006B), (0xFF4C, 0x006C), (0xFF4D, 0x006D), (0xFF4E, 0x006E),
+    (0xFF4F, 0x006F), (0xFF50, 0x0070), (0xFF51, 0x0071), (0xFF52, 0x0072), (0xFF53, 0x0073),
+    (0xFF54, 0x0074), (0xFF55, 0x0075), (0xFF56, 0x0076), (0xFF57, 0x0077), (0xFF58, 0x0078),
+    (0xFF59, 0x0079), (0xFF5A, 0x007A), (0xFF5B, 0x007B), (0xFF5C, 0x007C), (0xFF5D, 0x007D),
+    (0xFF5E, 0x007E), (0xFFE1, 0x00A3), (0xFFE2, 0x00AC), (0xFFE4, 0x00A6), (0xFFE5, 0x00A5),
+];
+
+/// Code page 1254.
+#[rustfmt::skip]
+pub(crate) const CP1254: [(u16, u16); 811] = [
+    (0x00DD, 0x0059), (0x00FD, 0x0079), (0x0100, 0x0041), (0x0101, 0x0061), (0x0102, 0x0041),
+    (0x0103, 0x0061), (0x0104, 0x0041), (0x0105, 0x0061), (0x0106, 0x0043), (0x0107, 0x0063),
+    (0x0108, 0x0043), (0x0109, 0x0063), (0x010A, 0x0043), (0x010B, 0x0063), (0x010C, 0x0043),
+    (0x010D, 0x0063), (0x010E, 0x0044), (0x010F, 0x0064), (0x0112, 0x0045), (0x0113, 0x0065),
+    (0x0114, 0x0045), (0x0115, 0x0065), (0x0116, 0x0045), (0x0117, 0x0065), (0x0118, 0x0045),
+    (0x0119, 0x0065), (0x011A, 0x0045), (0x011B, 0x0065), (0x011C, 0x0047), (0x011D, 0x0067),
+    (0x0120, 0x0047), (0x0121, 0x0067), (0x0122, 0x0047), (0x0123, 0x0067), (0x0124, 0x0048),
+    (0x0125, 0x0068), (0x0128, 0x0049), (0x0129, 0x0069), (0x012A, 0x0049), (0x012B, 0x0069),
+    (0x012C, 0x0049), (0x012D, 0x0069), (0x012E, 0x0049), (0x012F, 0x0069), (0x0134, 0x004A),
+    (0x0135, 0x006A), (0x0136, 0x004B), (0x0137, 0x006B), (0x0139, 0x004C), (0x013A, 0x006C),
+    (0x013B, 0x004C), (0x013C, 0x006C), (0x013D, 0x004C), (0x013E, 0x006C), (0x0143, 0x004E),
+    (0x0144, 0x006E), (0x0145, 0x004E), (0x0146, 0x006E), (0x0147, 0x004E), (0x0148, 0x006E),
+    (0x014C, 0x004F), (0x014D, 0x006F), (0x014E, 0x004F), (0x014F, 0x006F), (0x0150, 0x004F),
+    (0x0151, 0x006F), (0x0154, 0x0052), (0x0155, 0x0072), (0x0156, 0x0052), (0x0157, 0x0072),
+    (0x0158, 0x0052), (0x0159, 0x0072), (0x015A, 0x0053), (0x015B, 0x0073), (0x015C, 0x0053),
+    (0x015D, 0x0073), (0x0162, 0x0054), (0x0163, 0x0074), (0x0164, 0x0054), (0x0165, 0x0074),
+    (0x0168, 0x0055), (0x0169, 0x0075), (0x016A, 0x0055), (0x016B, 0x0075), (0x016C, 0x0055),
+    (0x016D, 0x0075), (0x016E, 0x0055), (0x016F, 0x0075), (0x0170, 0x0055), (0x0171, 0x0075),
+    (0x0172, 0x0055), (0x0173, 0x0075), (0x0174, 0x0057), (0x0175, 0x0077), (0x0176, 0x0059),
+    (0x0177, 0x0079), (0x0179, 0x005A), (0x017A, 0x007A), (0x017B, 0x005A), (0x017C, 0x007A),
+    (0x017D, 0x005A), (0x017E, 0x007A), (0x017F, 0x0073), (0x01A0, 0x004F), (0x01A1, 0x006F),
+    (0x01AF, 0x0055), (0x01B0, 0x0075), (0x01CD, 0x0041), (0x01CE, 0x0061), (0x01CF, 0x0049),
+    (0x01D0, 0x0069), (0x01D1, 0x004F), (0x01D2, 0x006F), (0x01D3, 0x0055), (0x01D4, 0x0075),
+    (0x01D5, 0x0055), (0x01D6, 0x0075), (0x01D7, 0x0055), (0x01D8, 0x0075), (0x01D9, 0x0055),
+    (0x01DA, 0x0075), (0x01DB, 0x0055), (0x01DC, 0x0075), (0x01DE, 0x0041), (0x01DF, 0x0061),
+    (0x01E0, 0x0041), (0x01E1, 0x0061), (0x01E2, 0x00C6), (0x01E3, 0x00E6), (0x01E6, 0x0047),
+    (0x01E7, 0x0067), (0x01E8, 0x004B), (0x01E9, 0x006B), (0x01EA, 0x004F), (0x01EB, 0x006F),
+    (0x01EC, 0x004F), (0x01ED, 0x006F), (0x01F0, 0x006A), (0x01F4, 0x0047), (0x01F5, 0x0067),
+    (0x01F8, 0x004E), (0x01F9, 0x006E), (0x01FA, 0x0041), (0x01FB, 0x0061), (0x01FC, 0x00C6),
+    (0x01FD, 0x00E6), (0x01FE, 0x00D8), (0x01FF, 0x00F8), (0x0200, 0x0041), (0x0201, 0x0061),
+    (0x0202, 0x0041), (0x0203, 0x0061), (0x0204, 0x0045), (0x0205, 0x0065), (0x0206, 0x0045),
+    (0x0207, 0x0065), (0x0208, 0x0049), (0x0209, 0x0069), (0x020A, 0x0049), (0x020B, 0x0069),
+    (0x020C, 0x004F), (0x020D, 0x006F), (0x020E, 0x004F), (0x020F, 0x006F), (0x0210, 0x0052),
+    (0x0211, 0x0072), (0x0212, 0x0052), (0x0213, 0x0072), (0x0214, 0x0055), (0x0215, 0x0075),
+    (0x0216, 0x0055), (0x0217, 0x0075), (0x0218, 0x0053), (0x0219, 0x0073), (0x021A, 0x0054),
+    (0x021B, 0x0074), (0x021E, 0x0048), (0x021F, 0x0068), (0x0226, 0x0041), (0x0227, 0x0061),
+    (0x0228, 0x0045), (0x0229, 0x0065), (0x022A, 0x004F), (0x022B, 0x006F), (0x022C, 0x004F),
+    (0x022D, 0x006F), (0x022E, 0x004F), (0x022F, 0x006F), (0x0230, 0x004F), (0x0231, 0x006F),
+    (0x0232, 0x0059), (0x0233, 0x0079), (0x02B0, 0x0068), (0x02B2, 0x006A), (0x02B3, 0x0072),
+    (0x02B7, 0x0077), (0x02B8, 0x0079), (0x02E1, 0x006C), (0x02E2, 0x0073), (0x02E3, 0x0078),
+    (0x037E, 0x003B), (0x0387, 0x00B7), (0x1D2C, 0x0041), (0x1D2D, 0x00C6), (0x1D2E, 0x0042),
+    (0x1D30, 0x0044), (0x1D31, 0x0045), (0x1D33, 0x0047), (0x1D34, 0x0048), (0x1D35, 0x0049),
+    (0x1D36, 0x004A), (0x1D37, 0x004B), (0x1D38, 0x004C), (0x1D39, 0x004D), (0x1D3A, 0x004E),
+    (0x1D3C, 0x004F), (0x1D3E, 0x0050), (0x1D3F, 0x0052), (0x1D40, 0x0054), (0x1D41, 0x0055),
+    (0x1D42, 0x0057), (0x1D43, 0x0061), (0x1D47, 0x0062), (0x1D48, 0x0064), (0x1D49, 0x0065),
+    (0x1D4D, 0x0067), (0x1D4F, 0x006B), (0x1D50, 0x006D), (0x1D52, 0x006F), (0x1D56, 0x0070),
+    (0x1D57, 0x0074), (0x1D58, 0x0075), (0x1D5B, 0x0076), (0x1D62, 0x0069), (0x1D63, 0x0072),
+    (0x1D64, 0x0075), (0x1D65, 0x0076), (0x1D9C, 0x0063), (0x1DA0, 0x0066), (0x1DBB, 0x007A),
+    (0x1E00, 0x0041), (0x1E01, 0x0061), (0x1E02, 0x0042), (0x1E03, 0x0062), (0x1E04, 0x0042),
+    (0x1E05, 0x0062), (0x1E06, 0x0042), (0x1E07, 0x0062), (0x1E08, 0x0043), (0x1E09, 0x0063),
+    (0x1E0A, 0x0044), (0x1E0B, 0x0064), (0x1E0C, 0x0044), (0x1E0D, 0x0064), (0x1E0E, 0x0044),
+    (0x1E0F, 0x0064), (0x1E10, 0x0044), (0x1E11, 0x0064), (0x1E12, 0x0044), (0x1E13, 0x0064),
+    (0x1E14, 0x0045), (0x1E15, 0x0065), (0x1E16, 0x0045), (0x1E17, 0x0065), (0x1E18, 0x0045),
+    (0x1E19, 0x0065), (0x1E1A, 0x0045), (0x1E1B, 0x0065), (0x1E1C, 0x0045), (0x1E1D, 0x0065),
+    (0x1E1E, 0x0046), (0x1E1F, 0x0066), (0x1E20, 0x0047), (0x1E21, 0x0067), (0x1E22, 0x0048),
+    (0x1E23, 0x0068), (0x1E24, 0x0048), (0x1E25, 0x0068), (0x1E26, 0x0048), (0x1E27, 0x0068),
+    (0x1E28, 0x0048), (0x1E29, 0x0068), (0x1E2A, 0x0048), (0x1E2B, 0x0068), (0x1E2C, 0x0049),
+    (0x1E2D, 0x0069), (0x1E2E, 0x0049), (0x1E2F, 0x0069), (0x1E30, 0x004B), (0x1E31, 0x006B),
+    (0x1E32, 0x004B), (0x1E33, 0x006B), (0x1E34, 0x004B), (0x1E35, 0x006B), (0x1E36, 0x004C),
+    (0x1E37, 0x006C), (0x1E38, 0x004C), (0x1E39, 0x006C), (0x1E3A, 0x004C), (0x1E3B, 0x006C),
+    (0x1E3C, 0x004C), (0x1E3D, 0x006C), (0x1E3E, 0x004D), (0x1E3F, 0x006D), (0x1E40, 0x004D),
+    (0x1E41, 0x006D), (0x1E42, 0x004D), (0x1E43, 0x006D), (0x1E44, 0x004E), (0x1E45, 0x006E),
+    (0x1E46, 0x004E), (0x1E47, 0x006E), (0x1E48, 0x004E), (0x1E49, 0x006E), (0x1E4A, 0x004E),
+    (0x1E4B, 0x006E), (0x1E4C, 0x004F), (0x1E4D, 0x006F), (0x1E4E, 0x004F), (0x1E4F, 0x006F),
+    (0x1E50, 0x004F), (0x1E51, 0x006F), (0x1E52, 0x004F), (0x1E53, 0x006F), (0x1E54, 0x0050),
+    (0x1E55, 0x0070), (0x1E56, 0x0050), (0x1E57, 0x0070), (0x1E58, 0x0052), (0x1E59, 0x0072),
+    (0x1E5A, 0x0052), (0x1E5B, 0x0072), (0x1E5C, 0x0052), (0x1E5D, 0x0072), (0x1E5E, 0x0052),
+    (0x1E5F, 0x0072), (0x1E60, 0x0053), (0x1E61, 0x0073), (0x1E62, 0x0053), (0x1E63, 0x0073),
+    (0x1E64, 0x0053), (0x1E65, 0x0073), (0x1E66, 0x0053), (0x1E67, 0x0073), (0x1E68, 0x0053),
+    (0x1E69, 0x0073), (0x1E6A, 0x0054), (0x1E6B, 0x0074), (0x1E6C, 0x0054), (0x1E6D, 0x0074),
+    (0x1E6E, 0x0054), (0x1E6F, 0x0074), (0x1E70, 0x0054), (0x1E71, 0x0074), (0x1E72, 0x0055),
+    (0x1E73, 0x0075), (0x1E74, 0x0055), (0x1E75, 0x0075), (0x1E76, 0x0055), (0x1E77, 0x0075),
+    (0x1E78, 0x0055), (0x1E79, 0x0075), (0x1E7A, 0x0055), (0x1E7B, 0x0075), (0x1E7C, 0x0056),
+    (0x1E7D, 0x0076), (0x1E7E, 0x0056), (0x1E7F, 0x0076), (0x1E80, 0x0057), (0x1E81, 0x0077),
+    (0x1E82, 0x0057), (0x1E83, 0x0077), (0x1E84, 0x0057), (0x1E85, 0x0077), (0x1E86, 0x0057),
+    (0x1E87, 0x0077), (0x1E88, 0x0057), (0x1E89, 0x0077), (0x1E8A, 0x0058), (0x1E8B, 0x0078),
+    (0x1E8C, 0x0058), (0x1E8D, 0x0078), (0x1E8E, 0x0059), (0x1E8F, 0x0079), (0x1E90, 0x005A),
+    (0x1E91, 0x007A), (0x1E92, 0x005A), (0x1E93, 0x007A), (0x1E94, 0x005A), (0x1E95, 0x007A),
+    (0x1E96, 0x0068), (0x1E97, 0x0074), (0x1E98, 0x0077), (0x1E99, 0x0079), (0x1E9B, 0x0073),
+    (0x1EA0, 0x0041), (0x1EA1, 0x0061), (0x1EA2, 0x0041), (0x1EA3, 0x0061), (0x1EA4, 0x0041),
+    (0x1EA5, 0x0061), (0x1EA6, 0x0041), (0x1EA7, 0x0061), (0x1EA8, 0x0041), (0x1EA9, 0x0061),
+    (0x1EAA, 0x0041), (0x1EAB, 0x0061), (0x1EAC, 0x0041), (0x1EAD, 0x0061), (0x1EAE, 0x0041),
+    (0x1EAF, 0x0061), (0x1EB0, 0x0041), (0x1EB1, 0x0061), (0x1EB2, 0x0041), (0x1EB3, 0x0061),
+    (0x1EB4, 0x0041), (0x1EB5, 0x0061), (0x1EB6, 0x0041), (0x1EB7, 0x0061), (0x1EB8, 0x0045),
+    (0x1EB9, 0x0065), (0x1EBA, 0x0045), (0x1EBB, 0x0065), (0x1EBC, 0x0045), (0x1EBD, 0x0065),
+    (0x1EBE, 0x0045), (0x1EBF, 0x0065), (0x1EC0, 0x0045), (0x1EC1, 0x0065), (0x1EC2, 0x0045),
+    (0x1EC3, 0x0065), (0x1EC4, 0x0045), (0x1EC5, 0x0065), (0x1EC6, 0x0045), (0x1EC7, 0x0065),
+    (0x1EC8, 0x0049), (0x1EC9, 0x0069), (0x1ECA, 0x0049), (0x1ECB, 0x0069), (0x1ECC, 0x004F),
+    (0x1ECD, 0x006F), (0x1ECE, 0x004F), (0x1ECF, 0x006F), (0x1ED0, 0x004F), (0x1ED1, 0x006F),
+    (0x1ED2, 0x004F), (0x1ED3, 0x006F), (0x1ED4, 0x004F), (0x1ED5, 0x006F), (0x1ED6, 0x004F),
+    (0x1ED7, 0x006F), (0x1ED8, 0x004F), (0x1ED9, 0x006F), (0x1EDA, 0x004F), (0x1EDB, 0x006F),
+    (0x1EDC, 0x004F), (0x1EDD, 0x006F), (0x1EDE, 0x004F), (0x1EDF, 0x006F), (0x1EE0, 0x004F),
+    (0x1EE1, 0x006F), (0x1EE2, 0x004F), (0x1EE3, 0x006F), (0x1EE4, 0x0055), (0x1EE5, 0x0075),
+    (0x1EE6, 0x0055), (0x1EE7, 0x0075), (0x1EE8, 0x0055), (0x1EE9, 0x0075), (0x1EEA, 0x0055),
+    (0x1EEB, 0x0075), (0x1EEC, 0x0055), (0x1EED, 0x0075), (0x1EEE, 0x0055), (0x1EEF, 0x0075),
+    (0x1EF0, 0x0055), (0x1EF1, 0x0075), (0x1EF2, 0x0059), (0x1EF3, 0x0079), (0x1EF4, 0x0059),
+    (0x1EF5, 0x0079), (0x1EF6, 0x0059), (0x1EF7, 0x0079), (0x1EF8, 0x0059), (0x1EF9, 0x0079),
+    (0x1FEF, 0x0060), (0x2000, 0x0020), (0x2001, 0x0020), (0x2002, 0x0020), (0x2003, 0x0020),
+    (0x2004, 0x0020), (0x2005, 0x0020), (0x2006, 0x0020), (0x2007, 0x0020), (0x2008, 0x0020),
+    (0x2009, 0x0020), (0x200A, 0x0020), (0x2010, 0x002D), (0x2012, 0x002D), (0x2015, 0x002D),
+    (0x201B, 0x0027), (0x201F, 0x0022), (0x2024, 0x002E), (0x202F, 0x0020), (0x2032, 0x0027),
+    (0x2035, 0x0060), (0x205F, 0x0020), (0x2070, 0x0030), (0x2071, 0x0069), (0x2074, 0x0034),
+    (0x2075, 0x0035), (0x2076, 0x0036), (0x2077, 0x0037), (0x2078, 0x0038), (0x2079, 0x0039),
+    (0x207A, 0x002B), (0x207C, 0x003D), (0x207D, 0x0028), (0x207E, 0x0029), (0x207F, 0x006E),
+    (0x2080, 0x0030), (0x2081, 0x0031), (0x2082, 0x0032), (0x2083, 0x0033), (0x2084, 0x0034),
+    (0x2085, 0x0035), (0x2086, 0x0036), (0x2087, 0x0037), (0x2088, 0x0038), (0x2089, 0x0039),
+    (0x208A, 0x002B), (0x208C, 0x003D), (0x208D, 0x0028), (0x208E, 0x0029), (0x2090, 0x0061),
+    (0x2091, 0x0065), (0x2092, 0x006F), (0x2093, 0x0078), (0x2095, 0x0068), (0x2096, 0x006B),
+    (0x2097, 0x006C), (0x2098, 0x006D), (0x2099, 0x006E), (0x209A, 0x0070), (0x209B, 0x0073),
+    (0x209C, 0x0074), (0x2102, 0x0043), (0x210A, 0x0067), (0x210B, 0x0048), (0x210C, 0x0048),
+    (0x210D, 0x0048), (0x210E, 0x0068), (0x2110, 0x0049), (0x2111, 0x0049), (0x2112, 0x004C),
+    (0x2113, 0x006C), (0x2115, 0x004E), (0x2119, 0x0050), (0x211A, 0x0051), (0x211B, 0x0052),
+    (0x211C, 0x0052), (0x211D, 0x0052), (0x2124, 0x005A), (0x2128, 0x005A), (0x212A, 0x004B),
+    (0x212B, 0x0041), (0x212C, 0x0042), (0x212D, 0x0043), (0x212F, 0x0065), (0x2130, 0x0045),
+    (0x2131, 0x0046), (0x2133, 0x004D), (0x2134, 0x006F), (0x2139, 0x0069), (0x2145, 0x0044),
+    (0x2146, 0x0064), (0x2147, 0x0065), (0x2148, 0x0069), (0x2149, 0x006A), (0x2160, 0x0049),
+    (0x2164, 0x0056), (0x2169, 0x0058), (0x216C, 0x004C), (0x216D, 0x0043), (0x216E, 0x0044),
+    (0x216F, 0x004D), (0x2170, 0x0069), (0x2174, 0x0076), (0x2179, 0x0078), (0x217C, 0x006C),
+    (0x217D, 0x0063), (0x217E, 0x0064), (0x217F, 0x006D), (0x2212, 0x002D), (0x2215, 0x002F),
+    (0x2216, 0x005C), (0x2223, 0x007C), (0x223C, 0x007E), (0x2260, 0x003D), (0x226E, 0x003C),
+    (0x226F, 0x003E), (0x2460, 0x0031), (0x2461, 0x0032), (0x2462, 0x0033), (0x2463, 0x0034),
+    (0x2464, 0x0035), (0x2465, 0x0036), (0x2466, 0x0037), (0x2467, 0x0038), (0x2468, 0x0039),
+    (0x24B6, 0x0041), (0x24B7, 0x0042), (0x24B8, 0x0043), (0x24B9, 0x0044), (0x24BA, 0x0045),
+    (0x24BB, 0x0046), (0x24BC, 0x0047), (0x24BD, 0x0048), (0x24BE, 0x0049), (0x24BF, 0x004A),
+    (0x24C0, 0x004B), (0x24C1, 0x004C), (0x24C2, 0x004D), (0x24C3, 0x004E), (0x24C4, 0x004F),
+    (0x24C5, 0x0050), (0x24C6, 0x0051), (0x24C7, 0x0052), (0x24C8, 0x0053), (0x24C9, 0x0054),
+    (0x24CA, 0x0055), (0x24CB, 0x0056), (0x24CC, 0x0057), (0x24CD, 0x0058), (0x24CE, 0x0059),
+    (0x24CF, 0x005A), (0x24D0, 0x0061), (0x24D1, 0x0062), (0x24D2, 0x0063), (0x24D3, 0x0064),
+    (0x24D4, 0x0065), (0x24D5, 0x0066), (0x24D6, 0x0067), (0x24D7, 0x0068), (0x24D8, 0x0069),
+    (0x24D9, 0x006A), (0x24DA, 0x006B), (0x24DB, 0x006C), (0x24DC, 0x006D), (0x24DD, 0x006E),
+    (0x24DE, 0x006F), (0x24DF, 0x0070), (0x24E0, 0x0071), (0x24E1, 0x0072), (0x24E2, 0x0073),
+    (0x24E3, 0x0074), (0x24E4, 0x0075), (0x24E5, 0x0076), (0x24E6, 0x0077), (0x24E7, 0x0078),
+    (0x24E8, 0x0079), (0x24E9, 0x007A), (0x24EA, 0x0030), (0x2C7C, 0x006A), (0x2C7D, 0x0056),
+    (0x3000, 0x0020), (0xA7F2, 0x0043), (0xA7F3, 0x0046), (0xA7F4, 0x0051), (0xA7F9, 0x009C),
+    (0xFB29, 0x002B), (0xFE10, 0x002C), (0xFE13, 0x003A), (0xFE14, 0x003B), (0xFE15, 0x0021),
+    (0xFE16, 0x003F), (0xFE31, 0x0097), (0xFE32, 0x0096), (0xFE33, 0x005F), (0xFE34, 0x005F),
+    (0xFE35, 0x0028), (0xFE36, 0x0029), (0xFE37, 0x007B), (0xFE38, 0x007D), (0xFE47, 0x005B),
+    (0xFE48, 0x005D), (0xFE4D, 0x005F), (0xFE4E, 0x005F), (0xFE4F, 0x005F), (0xFE50, 0x002C),
+    (0xFE52, 0x002E), (0xFE54, 0x003B), (0xFE55, 0x003A), (0xFE56, 0x003F), (0xFE57, 0x0021),
+    (0xFE58, 0x0097), (0xFE59, 0x0028), (0xFE5A, 0x0029), (0xFE5B, 0x007B), (0xFE5C, 0x007D),
+    (0xFE5F, 0x0023), (0xFE60, 0x0026), (0xFE61, 0x002A), (0xFE62, 0x002B), (0xFE63, 0x002D),
+    (0xFE64, 0x003C), (0xFE65, 0x003E), (0xFE66, 0x003D), (0xFE68, 0x005C), (0xFE69, 0x0024),
+    (0xFE6A, 0x0025), (0xFE6B, 0x0040), (0xFF01, 0x0021), (0xFF02, 0x0022), (0xFF03, 0x0023),
+    (0xFF04, 0x0024), (0xFF05, 0x0025), (0xFF06, 0x0026), (0xFF07, 0x0027), (0xFF08, 0x0028),
+    (0xFF09, 0x0029), (0xFF0A, 0x002A), (0xFF0B, 0x002B), (0xFF0C, 0x002C), (0xFF0D, 0x002D),
+    (0xFF0E, 0x002E), (0xFF0F, 0x002F), (0xFF10, 0x0030), (0xFF11, 0x0031), (0xFF12, 0x0032),
+    (0xFF13, 0x0033), (0xFF14, 0x0034), (0xFF15, 0x0035), (0xFF16, 0x0036), (0xFF17, 0x0037),
+    (0xFF18, 0x0038), (0xFF19, 0x0039), (0xFF1A, 0x003A), (0xFF1B, 0x003B), (0xFF1C, 0x003C),
+    (0xFF1D, 0x003D), (0xFF1E, 0x003E), (0xFF1F, 0x003F), (0xFF20, 0x0040), (0xFF21, 0x0041),
+    (0xFF22, 0x0042), (0xFF23, 0x0043), (0xFF24, 0x0044), (0xFF25, 0x0045), (0xFF26, 0x0046),
+    (0xFF27, 0x0047), (0xFF28, 0x0048), (0xFF29, 0x0049), (0xFF2A, 0x004A), (0xFF2B, 0x004B),
+    (0xFF2C, 0x004C), (0xFF2D, 0x004D), (0xFF2E, 0x004E), (0xFF2F, 0x004F), (0xFF30, 0x0050),
+    (0xFF31, 0x0051), (0xFF32, 0x0052), (0xFF33, 0x0053), (0xFF34, 0x0054), (0xFF35, 0x0055),
+    (0xFF36, 0x0056), (0xFF37, 0x0057), (0xFF38, 0x0058), (0xFF39, 0x0059), (0xFF3A, 0x005A),
+    (0xFF3B, 0x005B), (0xFF3C, 0x005C), (0xFF3D, 0x005D), (0xFF3E, 0x005E), (0xFF3F, 0x005F),
+    (0xFF40, 0x0060), (0xFF41, 0x0061), (0xFF42, 0x0062), (0xFF43, 0x0063), (0xFF44, 0x0064),
+    (0xFF45, 0x0065), (0xFF46, 0x0066), (0xFF47, 0x0067), (0xFF48, 0x0068), (0xFF49, 0x0069),
+    (0xFF4A, 0x006A), (0xFF4B, 0x006B), (0xFF4C, 0x006C), (0xFF4D, 0x006D), (0xFF4E, 0x006E),
+    (0xFF4F, 0x006F), (0xFF50, 0x0070), (0xFF51, 0x0071), (0xFF52, 0x0072), (0xFF53, 0x0073),
+    (0xFF54, 0x0074), (0xFF55, 0x0075), (0xFF56, 0x0076), (0xFF57, 0x0077), (0xFF58, 0x0078),
+    (0xFF59, 0x0079), (0xFF5A, 0x007A), (0xFF5B, 0x007B), (0xFF5C, 0x007C), (0xFF5D, 0x007D),
+    (0xFF5E, 0x007E), (0xFFE0, 0x00A2), (0xFFE1, 0x00A3), (0xFFE2, 0x00AC), (0xFFE4, 0x00A6),
+    (0xFFE5, 0x00A5),
+];
+
+/// Code page 1255.
+#[rustfmt::skip]
+pub(crate) const CP1255: [(u16, u16); 911] = [
+    (0x00AA, 0x0061), (0x00BA, 0x006F), (0x00C0, 0x0041), (0x00C1, 0x0041), (0x00C2, 0x0041),
+    (0x00C3, 0x0041), (0x00C4, 0x0041), (0x00C5, 0x0041), (0x00C7, 0x0043), (0x00C8, 0x0045),
+    (0x00C9, 0x0045), (0x00CA, 0x0045), (0x00CB, 0x0045), (0x00CC, 0x0049), (0x00CD, 0x0049),
+    (0x00CE, 0x0049), (0x00CF, 0x0049), (0x00D1, 0x004E), (0x00D2, 0x004F), (0x00D3, 0x004F),
+    (0x00D4, 0x004F), (0x00D5, 0x004F), (0x00D6, 0x004F), (0x00D9, 0x0055), (0x00DA, 0x0055),
+    (0x00DB, 0x0055), (0x00DC, 0x0055), (0x00DD, 0x0059), (0x00E0, 0x0061), (0x00E1, 0x0061),
+    (0x00E2, 0x0061), (0x00E3, 0x0061), (0x00E4, 0x0061), (0x00E5, 0x0061), (0x00E7, 0x0063),
+    (0x00E8, 0x0065), (0x00E9, 0x0065), (0x00EA, 0x0065), (0x00EB, 0x0065), (0x00EC, 0x0069),
+    (0x00ED, 0x0069), (0x00EE, 0x0069), (0x00EF, 0x0069), (0x00F1, 0x006E), (0x00F2, 0x006F),
+    (0x00F3, 0x006F), (0x00F4, 0x006F), (0x00F5, 0x006F), (0x00F6, 0x006F), (0x00F9, 0x0075),
+    (0x00FA, 0x0075), (0x00FB, 0x0075), (0x00FC, 0x0075), (0x00FD, 0x0079), (0x00FF, 0x0079),
+    (0x0100, 0x0041), (0x0101, 0x0061), (0x0102, 0x0041), (0x0103, 0x0061), (0x0104, 0x0041),
+    (0x0105, 0x0061), (0x0106, 0x0043), (0x0107, 0x0063), (0x0108, 0x0043), (0x0109, 0x0063),
+    (0x010A, 0x0043), (0x010B, 0x0063), (0x010C, 0x0043), (0x010D, 0x0063), (0x010E, 0x0044),
+    (0x010F, 0x0064), (0x0112, 0x0045), (0x0113, 0x0065), (0x0114, 0x0045), (0x0115, 0x0065),
+    (0x0116, 0x0045), (0x0117, 0x0065), (0x0118, 0x0045), (0x0119, 0x0065), (0x011A, 0x0045),
+    (0x011B, 0x0065), (0x011C, 0x0047), (0x011D, 0x0067), (0x011E, 0x0047), (0x011F, 0x0067),
+    (0x0120, 0x0047), (0x0121, 0x0067), (0x0122, 0x0047), (0x0123, 0x0067), (0x0124, 0x0048),
+    (0x0125, 0x0068), (0x0128, 0x0049), (0x0129, 0x0069), (0x012A, 0x0049), (0x012B, 0x0069),
+    (0x012C, 0x0049), (0x012D, 0x0069), (0x012E, 0x0049), (0x012F, 0x0069), (0x0130, 0x0049),
+    (0x0134, 0x004A), (0x0135, 0x006A), (0x0136, 0x004B), (0x0137, 0x006B), (0x0139, 0x004C),
+    (0x013A, 0x006C), (0x013B, 0x004C), (0x013C, 0x006C), (0x013D, 0x004C), (0x013E, 0x006C),
+    (0x0143, 0x004E), (0x0144, 0x006E), (0x0145, 0x004E), (0x0146, 0x006E), (0x0147, 0x004E),
+    (0x0148, 0x006E), (0x014C, 0x004F), (0x014D, 0x006F), (0x014E, 0x004F), (0x014F, 0x006F),
+    (0x0150, 0x004F), (0x0151, 0x006F), (0x0154, 0x0052), (0x0155, 0x0072), (0x0156, 0x0052),
+    (0x0157, 0x0072), (0x0158, 0x0052), (0x0159, 0x0072), (0x015A, 0x0053), (0x015B, 0x0073),
+    (0x015C, 0x0053), (0x015D, 0x0073), (0x015E, 0x0053), (0x015F, 0x0073), (0x0160, 0x0053),
+    (0x0161, 0x0073), (0x0162, 0x0054), (0x0163, 0x0074), (0x0164, 0x0054), (0x0165, 0x0074),
+    (0x0168, 0x0055), (0x0169, 0x0075), (0x016A, 0x0055), (0x016B, 0x0075), (0x016C, 0x0055),
+    (0x016D, 0x0075), (0x016E, 0x0055), (0x016F, 0x0075), (0x0170, 0x0055), (0x0171, 0x0075),
+    (0x0172, 0x0055), (0x0173, 0x0075), (0x0174, 0x0057), (0x0175, 0x0077), (0x0176, 0x0059),
+    (0x0177, 0x0079), (0x0178, 0x0059), (0x0179, 0x005A), (0x017A, 0x007A), (0x017B, 0x005A),
+    (0x017C, 0x007A), (0x017D, 0x005A), (0x017E, 0x007A), (0x017F, 0x0073), (0x01A0, 0x004F),
+    (0x01A1, 0x006F), (0x01AF, 0x0055), (0x01B0, 0x0075), (0x01CD, 0x0041), (0x01CE, 0x0061),
+    (0x01CF, 0x0049), (0x01D0, 0x0069), (0x01D1, 0x004F), (0x01D2, 0x006F), (0x01D3, 0x0055),
+    (0x01D4, 0x0075), (0x01D5, 0x0055), (0x01D6, 0x0075), (0x01D7, 0x0055), (0x01D8, 0x0075),
+    (0x01D9, 0x0055), (0x01DA, 0x0075), (0x01DB, 0x0055), (0x01DC, 0x0075), (0x01DE, 0x0041),
+    (0x01DF, 0x0061), (0x01E0, 0x0041), (0x01E1, 0x0061), (0x01E6, 0x0047), (0x01E7, 0x0067),
+    (0x01E8, 0x004B), (0x01E9, 0x006B), (0x01EA, 0x004F), (0x01EB, 0x006F), (0x01EC, 0x004F),
+    (0x01ED, 0x006F), (0x01F0, 0x006A), (0x01F4, 0x0047), (0x01F5, 0x0067), (0x01F8, 0x004E),
+    (0x01F9, 0x006E), (0x01FA, 0x0041), (0x01FB, 0x0061), (0x0200, 0x0041), (0x0201, 0x0061),
+    (0x0202, 0x0041), (0x0203, 0x0061), (0x0204, 0x0045), (0x0205, 0x0065), (0x0206, 0x0045),
+    (0x0207, 0x0065), (0x0208, 0x0049), (0x0209, 0x0069), (0x020A, 0x0049), (0x020B, 0x0069),
+    (0x020C, 0x004F), (0x020D, 0x006F), (0x020E, 0x004F), (0x020F, 0x006F), (0x0210, 0x0052),
+    (0x0211, 0x0072), (0x0212, 0x0052), (0x0213, 0x0072), (0x0214, 0x0055), (0x0215, 0x0075),
+    (0x0216, 0x0055), (0x0217, 0x0075), (0x0218, 0x0053), (0x0219, 0x0073), (0x021A, 0x0054),
+    (0x021B, 0x0074), (0x021E, 0x0048), (0x021F, 0x0068), (0x0226, 0x0041), (0x0227, 0x0061),
+    (0x0228, 0x0045), (0x0229, 0x0065), (0x022A, 0x004F), (0x022B, 0x006F), (0x022C, 0x004F),
+    (0x022D, 0x006F), (0x022E, 0x004F), (0x022F, 0x006F), (0x0230, 0x004F), (0x0231, 0x006F),
+    (0x0232, 0x0059), (0x0233, 0x0079), (0x02B0, 0x0068), (0x02B2, 0x006A), (0x02B3, 0x0072),
+    (0x02B7, 0x0077), (0x02B8, 0x0079), (0x02E1, 0x006C), (0x02E2, 0x0073), (0x02E3, 0x0078),
+    (0x037E, 0x003B), (0x0387, 0x00B7), (0x1D2C, 0x0041), (0x1D2E, 0x0042), (0x1D30, 0x0044),
+    (0x1D31, 0x0045), (0x1D33, 0x0047), (0x1D34, 0x0048), (0x1D35, 0x0049), (0x1D36, 0x004A),
+    (0x1D37, 0x004B), (0x1D38, 0x004C), (0x1D39, 0x004D), (0x1D3A, 0x004E), (0x1D3C, 0x004F),
+    (0x1D3E, 0x0050), (0x1D3F, 0x0052), (0x1D40, 0x0054), (0x1D41, 0x0055), (0x1D42, 0x0057),
+    (0x1D43, 0x0061), (0x1D47, 0x0062), (0x1D48, 0x0064), (0x1D49, 0x0065), (0x1D4D, 0x0067),
+    (0x1D4F, 0x006B), (0x1D50, 0x006D), (0x1D52, 0x006F), (0x1D56, 0x0070), (0x1D57, 0x0074),
+    (0x1D58, 0x0075), (0x1D5B, 0x0076), (0x1D62, 0x0069), (0x1D63, 0x0072), (0x1D64, 0x0075),
+    (0x1D65, 0x0076), (0x1D9C, 0x0063), (0x1DA0, 0x0066), (0x1DBB, 0x007A), (0x1E00, 0x0041),
+    (0x1E01, 0x0061), (0x1E02, 0x0042), (0x1E03, 0x0062), (0x1E04, 0x0042), (0x1E05, 0x0062),
+    (0x1E06, 0x0042), (0x1E07, 0x0062), (0x1E08, 0x0043), (0x1E09, 0x0063), (0x1E0A, 0x0044),
+    (0x1E0B, 0x0064), (0x1E0C, 0x0044), (0x1E0D, 0x0064), (0x1E0E, 0x0044), (0x1E0F, 0x0064),
+    (0x1E10, 0x0044), (0x1E11, 0x0064), (0x1E12, 0x0044), (0x1E13, 0x0064), (0x1E14, 0x0045),
+    (0x1E15, 0x0065), (0x1E16, 0x0045), (0x1E17, 0x0065), (0x1E18, 0x0045), (0x1E19, 0x0065),
+    (0x1E1A, 0x0045), (0x1E1B, 0x0065), (0x1E1C, 0x0045), (0x1E1D, 0x0065), (0x1E1E, 0x0046),
+    (0x1E1F, 0x0066), (0x1E20, 0x0047), (0x1E21, 0x0067), (0x1E22, 0x0048), (0x1E23, 0x0068),
+    (0x1E24, 0x0048), (0x1E25, 0x0068), (0x1E26, 0x0048), (0x1E27, 0x0068), (0x1E28, 0x0048),
+    (0x1E29, 0x0068), (0x1E2A, 0x0048), (0x1E2B, 0x0068), (0x1E2C, 0x0049), (0x1E2D, 0x0069),
+    (0x1E2E, 0x0049), (0x1E2F, 0x0069), (0x1E30, 0x004B), (0x1E31, 0x006B), (0x1E32, 0x004B),
+    (0x1E33, 0x006B), (0x1E34, 0x004B), (0x1E35, 0x006B), (0x1E36, 0x004C), (0x1E37, 0x006C),
+    (0x1E38, 0x004C), (0x1E39, 0x006C), (0x1E3A, 0x004C), (0x1E3B, 0x006C), (0x1E3C, 0x004C),
+    (0x1E3D, 0x006C), (0x1E3E, 0x004D), (0x1E3F, 0x006D), (0x1E40, 0x004D), (0x1E41, 0x006D),
+    (0x1E42, 0x004D), (0x1E43, 0x006D), (0x1E44, 0x004E), (0x1E45, 0x006E), (0x1E46, 0x004E),
+    (0x1E47, 0x006E), (0x1E48, 0x004E), (0x1E49, 0x006E), (0x1E4A, 0x004E), (0x1E4B, 0x006E),
+    (0x1E4C, 0x004F), (0x1E4D, 0x006F), (0x1E4E, 0x004F), (0x1E4F, 0x006F), (0x1E50, 0x004F),
+    (0x1E51, 0x006F), (0x1E52, 0x004F), (0x1E53, 0x006F), (0x1E54, 0x0050), (0x1E55, 0x0070),
+    (0x1E56, 0x0050), (0x1E57, 0x0070), (0x1E58, 0x0052), (0x1E59, 0x0072), (0x1E5A, 0x0052),
+    (0x1E5B, 0x0072), (0x1E5C, 0x0052), (0x1E5D, 0x0072), (0x1E5E, 0x0052), (0x1E5F, 0x0072),
+    (0x1E60, 0x0053), (0x1E61, 0x0073), (0x1E62, 0x0053), (0x1E63, 0x0073), (0x1E64, 0x0053),
+    (0x1E65, 0x0073), (0x1E66, 0x0053), (0x1E67, 0x0073), (0x1E68, 0x0053), (0x1E69, 0x0073),
+    (0x1E6A, 0x0054), (0x1E6B, 0x0074), (0x1E6C, 0x0054), (0x1E6D, 0x0074), (0x1E6E, 0x0054),
+    (0x1E6F, 0x0074), (0x1E70, 0x0054), (0x1E71, 0x0074), (0x1E72, 0x0055), (0x1E73, 0x0075),
+    (0x1E74, 0x0055), (0x1E75, 0x0075), (0x1E76, 0x0055), (0x1E77, 0x0075), (0x1E78, 0x0055),
+    (0x1E79, 0x0075), (0x1E7A, 0x0055), (0x1E7B, 0x0075), (0x1E7C, 0x0056), (0x1E7D, 0x0076),
+    (0x1E7E, 0x0056), (0x1E7F, 0x0076), (0x1E80, 0x0057), (0x1E81, 0x0077), (0x1E82, 0x0057),
+    (0x1E83, 0x0077), (0x1E84, 0x0057), (0x1E85, 0x0077), (0x1E86, 0x0057), (0x1E87, 0x0077),
+    (0x1E88, 0x0057), (0x1E89, 0x0077), (0x1E8A, 0x0058), (0x1E8B, 0x0078), (0x1E8C, 0x0058),
+    (0x1E8D, 0x0078), (0x1E8E, 0x0059), (0x1E8F, 0x0079), (0x1E90, 0x005A), (0x1E91, 0x007A),
+    (0x1E92, 0x005A), (0x1E93, 0x007A), (0x1E94, 0x005A), (0x1E95, 0x007A), (0x1E96, 0x0068),
+    (0x1E97, 0x0074), (0x1E98, 0x0077), (0x1E99, 0x0079), (0x1E9B, 0x0073), (0x1EA0, 0x0041),
+    (0x1EA1, 0x0061), (0x1EA2, 0x0041), (0x1EA3, 0x0061), (0x1EA4, 0x0041), (0x1EA5, 0x0061),
+    (0x1EA6, 0x0041), (0x1EA7, 0x0061), (0x1EA8, 0x0041), (0x1EA9, 0x0061), (0x1EAA, 0x0041),
+    (0x1EAB, 0x0061), (0x1EAC, 0x0041), (0x1EAD, 0x0061), (0x1EAE, 0x0041), (0x1EAF, 0x0061),
+    (0x1EB0, 0x0041), (0x1EB1, 0x0061), (0x1EB2, 0x0041), (0x1EB3, 0x0061), (0x1EB4, 0x0041),
+    (0x1EB5, 0x0061), (0x1EB6, 0x0041), (0x1EB7, 0x0061), (0x1EB8, 0x0045), (0x1EB9, 0x0065),
+    (0x1EBA, 0x0045), (0x1EBB, 0x0065), (0x1EBC, 0x0045), (0x1EBD, 0x0065), (0x1EBE, 0x0045),
+    (0x1EBF, 0x0065), (0x1EC0, 0x0045), (0x1EC1, 0x0065), (0x1EC2, 0x0045), (0x1EC3, 0x0065),
+    (0x1EC4, 0x0045), (0x1EC5, 0x0065), (0x1EC6, 0x0045), (0x1EC7, 0x0065), (0x1EC8, 0x0049),
+    (0x1EC9, 0x0069), (0x1ECA, 0x0049), (0x1ECB, 0x0069), (0x1ECC, 0x004F), (0x1ECD, 0x006F),
+    (0x1ECE, 0x004F), (0x1ECF, 0x006F), (0x1ED0, 0x004F), (0x1ED1, 0x006F), (0x1ED2, 0x004F),
+    (0x1ED3, 0x006F), (0x1ED4, 0x004F), (0x1ED5, 0x006F), (0x1ED6, 0x004F), (0x1ED7, 0x006F),
+    (0x1ED8, 0x004F), (0x1ED9, 0x006F), (0x1EDA, 0x004F), (0x1EDB, 0x006F), (0x1EDC, 0x004F),
+    (0x1EDD, 0x006F), (0x1EDE, 0x004F), (0x1EDF, 0x006F), (0x1EE0, 0x004F), (0x1EE1, 0x006F),
+    (0x1EE2, 0x004F), (0x1EE3, 0x006F), (0x1EE4, 0x0055), (0x1EE5, 0x0075), (0x1EE6, 0x0055),
+    (0x1EE7, 0x0075), (0x1EE8, 0x0055), (0x1EE9, 0x0075), (0x1EEA, 0x0055), (0x1EEB, 0x0075),
+    (0x1EEC, 0x0055), (0x1EED, 0x0075), (0x1EEE, 0x0055), (0x1EEF, 0x0075), (0x1EF0, 0x0055),
+    (0x1EF1, 0x0075), (0x1EF2, 0x0059), (0x1EF3, 0x0079), (0x1EF4, 0x0059), (0x1EF5, 0x0079),
+    (0x1EF6, 0x0059), (0x1EF7, 0x0079), (0x1EF8, 0x0059), (0x1EF9, 0x0079), (0x1FEF, 0x0060),
+    (0x2000, 0x0020), (0x2001, 0x0020), (0x2002, 0x0020), (0x2003, 0x0020), (0x2004, 0x0020),
+    (0x2005, 0x0020), (0x2006, 0x0020), (0x2007, 0x0020), (0x2008, 0x0020), (0x2009, 0x0020),
+    (0x200A, 0x0020), (0x2010, 0x002D), (0x2012, 0x002D), (0x2015, 0x002D), (0x201B, 0x0027),
+    (0x201F, 0x0022), (0x2024, 0x002E), (0x202F, 0x0020), (0x2032, 0x0027), (0x2035, 0x0060),
+    (0x205F, 0x0020), (0x2070, 0x0030), (0x2071, 0x0069), (0x2074, 0x0034), (0x2075, 0x0035),
+    (0x2076, 0x0036), (0x2077, 0x0037), (0x2078, 0x0038), (0x2079, 0x0039), (0x207A, 0x002B),
+    (0x207C, 0x003D), (0x207D, 0x0028), (0x207E, 0x0029), (0x207F, 0x006E), (0x2080, 0x0030),
+    (0x2081, 0x0031), (0x2082, 0x0032), (0x2083, 0x0033), (0x2084, 0x0034), (0x2085, 0x0035),
+    (0x2086, 0x0036), (0x2087, 0x0037), (0x2088, 0x0038), (0x2089, 0x0039), (0x208A, 0x002B),
+    (0x208C, 0x003D), (0x208D, 0x0028), (0x208E, 0x0029), (0x2090, 0x0061), (0x2091, 0x0065),
+    (0x2092, 0x006F), (0x2093, 0x0078), (0x2095, 0x0068), (0x2096, 0x006B), (0x2097, 0x006C),
+    (0x2098, 0x006D), (0x2099, 0x006E), (0x209A, 0x0070), (0x209B, 0x0073), (0x209C, 0x0074),
+    (0x2102, 0x0043), (0x210A, 0x0067), (0x210B, 0x0048), (0x210C, 0x0048), (0x210D, 0x0048),
+    (0x210E, 0x0068), (0x2110, 0x0049), (0x2111, 0x0049), (0x2112, 0x004C), (0x2113, 0x006C),
+    (0x2115, 0x004E), (0x2119, 0x0050), (0x211A, 0x0051), (0x211B, 0x0052), (0x211C, 0x0052),
+    (0x211D, 0x0052), (0x2124, 0x005A), (0x2128, 0x005A), (0x212A, 0x004B), (0x212B, 0x0041),
+    (0x212C, 0x0042), (0x212D, 0x0043), (0x212F, 0x0065), (0x2130, 0x0045), (0x2131, 0x0046),
+    (0x2133, 0x004D), (0x2134, 0x006F), (0x2135, 0x00E0), (0x2136, 0x00E1), (0x2137, 0x00E2),
+    (0x2138, 0x00E3), (0x2139, 0x0069), (0x2145, 0x0044), (0x2146, 0x0064), (0x2147, 0x0065),
+    (0x2148, 0x0069), (0x2149, 0x006A), (0x2160, 0x0049), (0x2164, 0x0056), (0x2169, 0x0058),
+    (0x216C, 0x004C), (0x216D, 0x0043), (0x216E, 0x0044), (0x216F, 0x004D), (0x2170, 0x0069),
+    (0x2174, 0x0076), (0x2179, 0x0078), (0x217C, 0x006C), (0x217D, 0x0063), (0x217E, 0x0064),
+    (0x217F, 0x006D), (0x2212, 0x002D), (0x2215, 0x002F), (0x2216, 0x005C), (0x2223, 0x007C),
+    (0x223C, 0x007E), (0x2260, 0x003D), (0x226E, 0x003C), (0x226F, 0x003E), (0x2460, 0x0031),
+    (0x2461, 0x0032), (0x2462, 0x0033), (0x2463, 0x0034), (0x2464, 0x0035), (0x2465, 0x0036),
+    (0x2466, 0x0037), (0x2467, 0x0038), (0x2468, 0x0039), (0x24B6, 0x0041), (0x24B7, 0x0042),
+    (0x24B8, 0x0043), (0x24B9, 0x0044), (0x24BA, 0x0045), (0x24BB, 0x0046), (0x24BC, 0x0047),
+    (0x24BD, 0x0048), (0x24BE, 0x0049), (0x24BF, 0x004A), (0x24C0, 0x004B), (0x24C1, 0x004C),
+    (0x24C2, 0x004D), (0x24C3, 0x004E), (0x24C4, 0x004F), (0x24C5, 0x0050), (0x24C6, 0x0051),
+    (0x24C7, 0x0052), (0x24C8, 0x0053), (0x24C9, 0x0054), (0x24CA, 0x0055), (0x24CB, 0x0056),
+    (0x24CC, 0x0057), (0x24CD, 0x0058), (0x24CE, 0x0059), (0x24CF, 0x005A), (0x24D0, 0x0061),
+    (0x24D1, 0x0062), (0x24D2, 0x0063), (0x24D3, 0x0064), (0x24D4, 0x0065), (0x24D5, 0x0066),
+    (0x24D6, 0x0067), (0x24D7, 0x0068), (0x24D8, 0x0069), (0x24D9, 0x006A), (0x24DA, 0x006B),
+    (0x24DB, 0x006C), (0x24DC, 0x006D), (0x24DD, 0x006E), (0x24DE, 0x006F), (0x24DF, 0x0070),
+    (0x24E0, 0x0071), (0x24E1, 0x0072), (0x24E2, 0x0073), (0x24E3, 0x0074), (0x24E4, 0x0075),
+    (0x24E5, 0x0076), (0x24E6, 0x0077), (0x24E7, 0x0078), (0x24E8, 0x0079), (0x24E9, 0x007A),
+    (0x24EA, 0x0030), (0x2C7C, 0x006A), (0x2C7D, 0x0056), (0x3000, 0x0020), (0xA7F2, 0x0043),
+    (0xA7F3, 0x0046), (0xA7F4, 0x0051), (0xFB1D, 0x00E9), (0xFB1F, 0x00D6), (0xFB20, 0x00F2),
+    (0xFB21, 0x00E0), (0xFB22, 0x00E3), (0xFB23, 0x00E4), (0xFB24, 0x00EB), (0xFB25, 0x00EC),
+    (0xFB26, 0x00ED), (0xFB27, 0x00F8), (0xFB28, 0x00FA), (0xFB29, 0x002B), (0xFB2A, 0x00F9),
+    (0xFB2B, 0x00F9), (0xFB2C, 0x00F9), (0xFB2D, 0x00F9), (0xFB2E, 0x00E0), (0xFB2F, 0x00E0),
+    (0xFB30, 0x00E0), (0xFB31, 0x00E1), (0xFB32, 0x00E2), (0xFB33, 0x00E3), (0xFB34, 0x00E4),
+    (0xFB35, 0x00E5), (0xFB36, 0x00E6), (0xFB38, 0x00E8), (0xFB39, 0x00E9), (0xFB3A, 0x00EA),
+    (0xFB3B, 0x00EB), (0xFB3C, 0x00EC), (0xFB3E, 0x00EE), (0xFB40, 0x00F0), (0xFB41, 0x00F1),
+    (0xFB43, 0x00F3), (0xFB44, 0x00F4), (0xFB46, 0x00F6), (0xFB47, 0x00F7), (0xFB48, 0x00F8),
+    (0xFB49, 0x00F9), (0xFB4A, 0x00FA), (0xFB4B, 0x00E5), (0xFB4C, 0x00E1), (0xFB4D, 0x00EB),
+    (0xFB4E, 0x00F4), (0xFE10, 0x002C), (0xFE13, 0x003A), (0xFE14, 0x003B), (0xFE15, 0x0021),
+    (0xFE16, 0x003F), (0xFE31, 0x0097), (0xFE32, 0x0096), (0xFE33, 0x005F), (0xFE34, 0x005F),
+    (0xFE35, 0x0028), (0xFE36, 0x0029), (0xFE37, 0x007B), (0xFE38, 0x007D), (0xFE47, 0x005B),
+    (0xFE48, 0x005D), (0xFE4D, 0x005F), (0xFE4E, 0x005F), (0xFE4F, 0x005F), (0xFE50, 0x002C),
+    (0xFE52, 0x002E), (0xFE54, 0x003B), (0xFE55, 0x003A), (0xFE56, 0x003F), (0xFE57, 0x0021),
+    (0xFE58, 0x0097), (0xFE59, 0x0028), (0xFE5A, 0x0029), (0xFE5B, 0x007B), (0xFE5C, 0x007D),
+    (0xFE5F, 0x0023), (0xFE60, 0x0026), (0xFE61, 0x002A), (0xFE62, 0x002B), (0xFE63, 0x002D),
+    (0xFE64, 0x003C), (0xFE65, 0x003E), (0xFE66, 0x003D), (0xFE68, 0x005C), (0xFE69, 0x0024),
+    (0xFE6A, 0x0025), (0xFE6B, 0x0040), (0xFF01, 0x0021), (0xFF02, 0x0022), (0xFF03, 0x0023),
+    (0xFF04, 0x0024), (0xFF05, 0x0025), (0xFF06, 0x0026), (0xFF07, 0x0027), (0xFF08, 0x0028),
+    (0xFF09, 0x0029), (0xFF0A, 0x002A), (0xFF0B, 0x002B), (0xFF0C, 0x002C), (0xFF0D, 0x002D),
+    (0xFF0E, 0x002E), (0xFF0F, 0x002F), (0xFF10, 0x0030), (0xFF11, 0x0031), (0xFF12, 0x0032),
+    (0xFF13, 0x0033), (0xFF14, 0x0034), (0xFF15, 0x0035), (0xFF16, 0x0036), (0xFF17, 0x0037),
+    (0xFF18, 0x0038), (0xFF19, 0x0039), (0xFF1A, 0x003A), (0xFF1B, 0x003B), (0xFF1C, 0x003C),
+    (0xFF1D, 0x003D), (0xFF1E, 0x003E), (0xFF1F, 0x003F), (0xFF20, 0x0040), (0xFF21, 0x0041),
+    (0xFF22, 0x0042), (0xFF23, 0x0043), (0xFF24, 0x0044), (0xFF25, 0x0045), (0xFF26, 0x0046),
+    (0xFF27, 0x0047), (0xFF28, 0x0048), (0xFF29, 0x0049), (0xFF2A, 0x004A), (0xFF2B, 0x004B),
+    (0xFF2C, 0x004C), (0xFF2D, 0x004D), (0xFF2E, 0x004E), (0xFF2F, 0x004F), (0xFF30, 0x0050),
+    (0xFF31, 0x0051), (0xFF32, 0x0052), (0xFF33, 0x0053), (0xFF34, 0x0054), (0xFF35, 0x0055),
+    (0xFF36, 0x0056), (0xFF37, 0x0057), (0xFF38, 0x0058), (0xFF39, 0x0059), (0xFF3A, 0x005A),
+    (0xFF3B, 0x005B), (0xFF3C, 0x005C), (0xFF3D, 0x005D), (0xFF3E, 0x005E), (0xFF3F, 0x005F),
+    (0xFF40, 0x0060), (0xFF41, 0x0061), (0xFF42, 0x0062), (0xFF43, 0x0063), (0xFF44, 0x0064),
+    (0xFF45, 0x0065), (0xFF46, 0x0066), (0xFF47, 0x0067), (0xFF48, 0x0068), (0xFF49, 0x0069),
+    (0xFF4A, 0x006A), (0xFF4B, 0x006B), (0xFF4C, 0x006C), (0xFF4D, 0x006D), (0xFF4E, 0x006E),
+    (0xFF4F, 0x006F), (0xFF50, 0x0070), (0xFF51, 0x0071), (0xFF52, 0x0072), (0xFF53, 0x0073),
+    (0xFF54, 0x0074), (0xFF55, 0x0075), (0xFF56, 0x0076), (0xFF57, 0x0077), (0xFF58, 0x0078),
+    (0xFF59, 0x0079), (0xFF5A, 0x007A), (0xFF5B, 0x007B), (0xFF5C, 0x007C), (0xFF5D, 0x007D),
+    (0xFF5E, 0x007E), (0xFFE0, 0x00A2), (0xFFE1, 0x00A3), (0xFFE2, 0x00AC), (0xFFE4, 0x00A6),
+    (0xFFE5, 0x00A5),
+];
+
+/// Code page 1256.
+#[rustfmt::skip]
+pub(crate) const CP1256: [(u16, u16); 1029] = [
+    (0x00AA, 0x0061), (0x00BA, 0x006F), (0x00C0, 0x0041), (0x00C1, 0x0041), (0x00C2, 0x0041),
+    (0x00C3, 0x0041), (0x00C4, 0x0041), (0x00C5, 0x0041), (0x00C7, 0x0043), (0x00C8, 0x0045),
+    (0x00C9, 0x0045), (0x00CA, 0x0045), (0x00CB, 0x0045), (0x00CC, 0x0049), (0x00CD, 0x0049),
+    (0x00CE, 0x0049), (0x00CF, 0x0049), (0x00D1, 0x004E), (0x00D2, 0x004F), (0x00D3, 0x004F),
+    (0x00D4, 0x004F), (0x00D5, 0x004F), (0x00D6, 0x004F), (0x00D9, 0x0055), (0x00DA, 0x0055),
+    (0x00DB, 0x0055), (0x00DC, 0x0055), (0x00DD, 0x0059), (0x00E1, 0x0061), (0x00E3, 0x0061),
+    (0x00E4, 0x0061), (0x00E5, 0x0061), (0x00EC, 0x0069), (0x00ED, 0x0069), (0x00F1, 0x006E),
+    (0x00F2, 0x006F), (0x00F3, 0x006F), (0x00F5, 0x006F), (0x00F6, 0x006F), (0x00FA, 0x0075),
+    (0x00FD, 0x0079), (0x00FF, 0x0079), (0x0100, 0x0041), (0x0101, 0x0061), (0x0102, 0x0041),
+    (0x0103, 0x0061), (0x0104, 0x0041), (0x0105, 0x0061), (0x0106, 0x0043), (0x0107, 0x0063),
+    (0x0108, 0x0043), (0x0109, 0x0063), (0x010A, 0x0043), (0x010B, 0x0063), (0x010C, 0x0043),
+    (0x010D, 0x0063), (0x010E, 0x0044), (0x010F, 0x0064), (0x0112, 0x0045), (0x0113, 0x0065),
+    (0x0114, 0x0045), (0x0115, 0x0065), (0x0116, 0x0045), (0x0117, 0x0065), (0x0118, 0x0045),
+    (0x0119, 0x0065), (0x011A, 0x0045), (0x011B, 0x0065), (0x011C, 0x0047), (0x011D, 0x0067),
+    (0x011E, 0x0047), (0x011F, 0x0067), (0x0120, 0x0047), (0x0121, 0x0067), (0x0122, 0x0047),
+    (0x0123, 0x0067), (0x0124, 0x0048), (0x0125, 0x0068), (0x0128, 0x0049), (0x0129, 0x0069),
+    (0x012A, 0x0049), (0x012B, 0x0069), (0x012C, 0x0049), (0x012D, 0x0069), (0x012E, 0x0049),
+    (0x012F, 0x0069), (0x0130, 0x0049), (0x0134, 0x004A), (0x0135, 0x006A), (0x0136, 0x004B),
+    (0x0137, 0x006B), (0x0139, 0x004C), (0x013A, 0x006C), (0x013B, 0x004C), (0x013C, 0x006C),
+    (0x013D, 0x004C), (0x013E, 0x006C), (0x0143, 0x004E), (0x0144, 0x006E), (0x0145, 0x004E),
+    (0x0146, 0x006E), (0x0147, 0x004E), (0x0148, 0x006E), (0x014C, 0x004F), (0x014D, 0x006F),
+    (0x014E, 0x004F), (0x014F, 0x006F), (0x0150, 0x004F), (0x0151, 0x006F), (0x0154, 0x0052),
+    (0x0155, 0x0072), (0x0156, 0x0052), (0x0157, 0x0072), (0x0158, 0x0052), (0x0159, 0x0072),
+    (0x015A, 0x0053), (0x015B, 0x0073), (0x015C, 0x0053), (0x015D, 0x0073), (0x015E, 0x0053),
+    (0x015F, 0x0073), (0x0160, 0x0053), (0x0161, 0x0073), (0x0162, 0x0054), (0x0163, 0x0074),
+    (0x0164, 0x0054), (0x0165, 0x0074), (0x0168, 0x0055), (0x0169, 0x0075), (0x016A, 0x0055),
+    (0x016B, 0x0075), (0x016C, 0x0055), (0x016D, 0x0075), (0x016E, 0x0055), (0x016F, 0x0075),
+    (0x0170, 0x0055), (0x0171, 0x0075), (0x0172, 0x0055), (0x0173, 0x0075), (0x0174, 0x0057),
+    (0x0175, 0x0077), (0x0176, 0x0059), (0x0177, 0x0079), (0x0178, 0x0059), (0x0179, 0x005A),
+    (0x017A, 0x007A), (0x017B, 0x005A), (0x017C, 0x007A), (0x017D, 0x005A), (0x017E, 0x007A),
+    (0x017F, 0x0073), (0x01A0, 0x004F), (0x01A1, 0x006F), (0x01AF, 0x0055), (0x01B0, 0x0075),
+    (0x01CD, 0x0041), (0x01CE, 0x0061), (0x01CF, 0x0049), (0x01D0, 0x0069), (0x01D1, 0x004F),
+    (0x01D2, 0x006F), (0x01D3, 0x0055), (0x01D4, 0x0075), (0x01D5, 0x0055), (0x01D6, 0x0075),
+    (0x01D7, 0x0055), (0x01D8, 0x0075), (0x01D9, 0x0055), (0x01DA, 0x0075), (0x01DB, 0x0055),
+    (0x01DC, 0x0075), (0x01DE, 0x0041), (0x01DF, 0x0061), (0x01E0, 0x0041), (0x01E1, 0x0061),
+    (0x01E6, 0x0047), (0x01E7, 0x0067), (0x01E8, 0x004B), (0x01E9, 0x006B), (0x01EA, 0x004F),
+    (0x01EB, 0x006F), (0x01EC, 0x004F), (0x01ED, 0x006F), (0x01F0, 0x006A), (0x01F4, 0x0047),
+    (0x01F5, 0x0067), (0x01F8, 0x004E), (0x01F9, 0x006E), (0x01FA, 0x0041), (0x01FB, 0x0061),
+    (0x0200, 0x0041), (0x0201, 0x0061), (0x0202, 0x0041), (0x0203, 0x0061), (0x0204, 0x0045),
+    (0x0205, 0x0065), (0x0206, 0x0045), (0x0207, 0x0065), (0x0208, 0x0049), (0x0209, 0x0069),
+    (0x020A, 0x0049), (0x020B, 0x0069), (0x020C, 0x004F), (0x020D, 0x006F), (0x020E, 0x004F),
+    (0x020F, 0x006F), (0x0210, 0x0052), (0x0211, 0x0072), (0x0212, 0x0052), (0x0213, 0x0072),
+    (0x0214, 0x0055), (0x0215, 0x0075), (0x0216, 0x0055), (0x0217, 0x0075), (0x0218, 0x0053),
+    (0x0219, 0x0073), (0x021A, 0x0054), (0x021B, 0x0074), (0x021E, 0x0048), (0x021F, 0x0068),
+    (0x0226, 0x0041), (0x0227, 0x0061), (0x0228, 0x0045), (0x0229, 0x0065), (0x022A, 0x004F),
+    (0x022B, 0x006F), (0x022C, 0x004F), (0x022D, 0x006F), (0x022E, 0x004F), (0x022F, 0x006F),
+    (0x0230, 0x004F), (0x0231, 0x006F), (0x0232, 0x0059), (0x0233, 0x0079), (0x02B0, 0x0068),
+    (0x02B2, 0x006A), (0x02B3, 0x0072), (0x02B7, 0x0077), (0x02B8, 0x0079), (0x02E1, 0x006C),
+    (0x02E2, 0x0073), (0x02E3, 0x0078), (0x037E, 0x003B), (0x0387, 0x00B7), (0x06C2, 0x00C0),
+    (0x06D3, 0x00FF), (0x1D2C, 0x0041), (0x1D2E, 0x0042), (0x1D30, 0x0044), (0x1D31, 0x0045),
+    (0x1D33, 0x0047), (0x1D34, 0x0048), (0x1D35, 0x0049), (0x1D36, 0x004A), (0x1D37, 0x004B),
+    (0x1D38, 0x004C), (0x1D39, 0x004D), (0x1D3A, 0x004E), (0x1D3C, 0x004F), (0x1D3E, 0x0050),
+    (0x1D3F, 0x0052), (0x1D40, 0x0054), (0x1D41, 0x0055), (0x1D42, 0x0057), (0x1D43, 0x0061),
+    (0x1D47, 0x0062), (0x1D48, 0x0064), (0x1D49, 0x0065), (0x1D4D, 0x0067), (0x1D4F, 0x006B),
+    (0x1D50, 0x006D), (0x1D52, 0x006F), (0x1D56, 0x0070), (0x1D57, 0x0074), (0x1D58, 0x0075),
+    (0x1D5B, 0x0076), (0x1D62, 0x0069), (0x1D63, 0x0072), (0x1D64, 0x0075), (0x1D65, 0x0076),
+    (0x1D9C, 0x0063), (0x1DA0, 0x0066), (0x1DBB, 0x007A), (0x1E00, 0x0041), (0x1E01, 0x0061),
+    (0x1E02, 0x0042), (0x1E03, 0x0062), (0x1E04, 0x0042), (0x1E05, 0x0062), (0x1E06, 0x0042),
+    (0x1E07, 0x0062), (0x1E08, 0x0043), (0x1E09, 0x0063), (0x1E0A, 0x0044), (0x1E0B, 0x0064),
+    (0x1E0C, 0x0044), (0x1E0D, 0x0064), (0x1E0E, 0x0044), (0x1E0F, 0x0064), (0x1E10, 0x0044),
+    (0x1E11, 0x0064), (0x1E12, 0x0044), (0x1E13, 0x0064), (0x1E14, 0x0045), (0x1E15, 0x0065),
+    (0x1E16, 0x0045), (0x1E17, 0x0065), (0x1E18, 0x0045), (0x1E19, 0x0065), (0x1E1A, 0x0045),
+    (0x1E1B, 0x0065), (0x1E1C, 0x0045), (0x1E1D, 0x0065), (0x1E1E, 0x0046), (0x1E1F, 0x0066),
+    (0x1E20, 0x0047), (0x1E21, 0x0067), (0x1E22, 0x0048), (0x1E23, 0x0068), (0x1E24, 0x0048),
+    (0x1E25, 0x0068), (0x1E26, 0x0048), (0x1E27, 0x0068), (0x1E28, 0x0048), (0x1E29, 0x0068),
+    (0x1E2A, 0x0048), (0x1E2B, 0x0068), (0x1E2C, 0x0049), (0x1E2D, 0x0069), (0x1E2E, 0x0049),
+    (0x1E2F, 0x0069), (0x1E30, 0x004B), (0x1E31, 0x006B), (0x1E32, 0x004B), (0x1E33, 0x006B),
+    (0x1E34, 0x004B), (0x1E35, 0x006B), (0x1E36, 0x004C), (0x1E37, 0x006C), (0x1E38, 0x004C),
+    (0x1E39, 0x006C), (0x1E3A, 0x004C), (0x1E3B, 0x006C), (0x1E3C, 0x004C), (0x1E3D, 0x006C),
+    (0x1E3E, 0x004D), (0x1E3F, 0x006D), (0x1E40, 0x004D), (0x1E41, 0x006D), (0x1E42, 0x004D),
+    (0x1E43, 0x006D), (0x1E44, 0x004E), (0x1E45, 0x006E), (0x1E46, 0x004E), (0x1E47, 0x006E),
+    (0x1E48, 0x004E), (0x1E49, 0x006E), (0x1E4A, 0x004E), (0x1E4B, 0x006E), (0x1E4C, 0x004F),
+    (0x1E4D, 0x006F), (0x1E4E, 0x004F), (0x1E4F, 0x006F), (0x1E50, 0x004F), (0x1E51, 0x006F),
+    (0x1E52, 0x004F), (0x1E53, 0x006F), (0x1E54, 0x0050), (0x1E55, 0x0070), (0x1E56, 0x0050),
+    (0x1E57, 0x0070), (0x1E58, 0x0052), (0x1E59, 0x0072), (0x1E5A, 0x0052), (0x1E5B, 0x0072),
+    (0x1E5C, 0x0052), (0x1E5D, 0x0072), (0x1E5E, 0x0052), (0x1E5F, 0x0072), (0x1E60, 0x0053),
+    (0x1E61, 0x0073), (0x1E62, 0x0053), (0x1E63, 0x0073), (0x1E64, 0x0053), (0x1E65, 0x0073),
+    (0x1E66, 0x0053), (0x1E67, 0x0073), (0x1E68, 0x0053), (0x1E69, 0x0073), (0x1E6A, 0x0054),
+    (0x1E6B, 0x0074), (0x1E6C, 0x0054), (0x1E6D, 0x0074), (0x1E6E, 0x0054), (0x1E6F, 0x0074),
+    (0x1E70, 0x0054), (0x1E71, 0x0074), (0x1E72, 0x0055), (0x1E73, 0x0075), (0x1E74, 0x0055),
+    (0x1E75, 0x0075), (0x1E76, 0x0055), (0x1E77, 0x0075), (0x1E78, 0x0055), (0x1E79, 0x0075),
+    (0x1E7A, 0x0055), (0x1E7B, 0x0075), (0x1E7C, 0x0056), (0x1E7D, 0x0076), (0x1E7E, 0x0056),
+    (0x1E7F, 0x0076), (0x1E80, 0x0057), (0x1E81, 0x0077), (0x1E82, 0x0057), (0x1E83, 0x0077),
+    (0x1E84, 0x0057), (0x1E85, 0x0077), (0x1E86, 0x0057), (0x1E87, 0x0077), (0x1E88, 0x0057),
+    (0x1E89, 0x0077), (0x1E8A, 0x0058), (0x1E8B, 0x0078), (0x1E8C, 0x0058), (0x1E8D, 0x0078),
+    (0x1E8E, 0x0059), (0x1E8F, 0x0079), (0x1E90, 0x005A), (0x1E91, 0x007A), (0x1E92, 0x005A),
+    (0x1E93, 0x007A), (0x1E94, 0x005A), (0x1E95, 0x007A), (0x1E96, 0x0068), (0x1E97, 0x0074),
+    (0x1E98, 0x0077), (0x1E99, 0x0079), (0x1E9B, 0x0073), (0x1EA0, 0x0041), (0x1EA1, 0x0061),
+    (0x1EA2, 0x0041), (0x1EA3, 0x0061), (0x1EA4, 0x0041), (0x1EA5, 0x0061), (0x1EA6, 0x0041),
+    (0x1EA7, 0x0061), (0x1EA8, 0x0041), (0x1EA9, 0x0061), (0x1EAA, 0x0041), (0x1EAB, 0x0061),
+    (0x1EAC, 0x0041), (0x1EAD, 0x0061), (0x1EAE, 0x0041), (0x1EAF, 0x0061), (0x1EB0, 0x0041),
+    (0x1EB1, 0x0061), (0x1EB2, 0x0041), (0x1EB3, 0x0061), (0x1EB4, 0x0041), (0x1EB5, 0x0061),
+    (0x1EB6, 0x0041), (0x1EB7, 0x0061), (0x1EB8, 0x0045), (0x1EB9, 0x0065), (0x1EBA, 0x0045),
+    (0x1EBB, 0x0065), (0x1EBC, 0x0045), (0x1EBD, 0x0065), (0x1EBE, 0x0045), (0x1EBF, 0x0065),
+    (0x1EC0, 0x0045), (0x1EC1, 0x0065), (0x1EC2, 0x0045), (0x1EC3, 0x0065), (0x1EC4, 0x0045),
+    (0x1EC5, 0x0065), (0x1EC6, 0x0045), (0x1EC7, 0x0065), (0x1EC8, 0x0049), (0x1EC9, 0x0069),
+    (0x1ECA, 0x0049), (0x1ECB, 0x0069), (0x1ECC, 0x004F), (0x1ECD, 0x006F), (0x1ECE, 0x004F),
+    (0x1ECF, 0x006F), (0x1ED0, 0x004F), (0x1ED1, 0x006F), (0x1ED2, 0x004F), (0x1ED3, 0x006F),
+    (0x1ED4, 0x004F), (0x1ED5, 0x006F), (0x1ED6, 0x004F), (0x1ED7, 0x006F), (0x1ED8, 0x004F),
+    (0x1ED9, 0x006F), (0x1EDA, 0x004F), (0x1EDB, 0x006F), (0x1EDC, 0x004F), (0x1EDD, 0x006F),
+    (0x1EDE, 0x004F), (0x1EDF, 0x006F), (0x1EE0, 0x004F), (0x1EE1, 0x006F), (0x1EE2, 0x004F),
+    (0x1EE3, 0x006F), (0x1EE4, 0x0055), (0x1EE5, 0x0075), (0x1EE6, 0x0055), (0x1EE7, 0x0075),
+    (0x1EE8, 0x0055), (0x1EE9, 0x0075), (0x1EEA, 0x0055), (0x1EEB, 0x0075), (0x1EEC, 0x0055),
+    (0x1EED, 0x0075), (0x1EEE, 0x0055), (0x1EEF, 0x0075), (0x1EF0, 0x0055), (0x1EF1, 0x0075),
+    (0x1EF2, 0x0059), (0x1EF3, 0x0079), (0x1EF4, 0x0059), (0x1EF5, 0x0079), (0x1EF6, 0x0059),
+    (0x1EF7, 0x0079), (0x1EF8, 0x0059), (0x1EF9, 0x0079), (0x1FEF, 0x0060), (0x2000, 0x0020),
+    (0x2001, 0x0020), (0x2002, 0x0020), (0x2003, 0x0020), (0x2004, 0x0020), (0x2005, 0x0020),
+    (0x2006, 0x0020), (0x2007, 0x0020), (0x2008, 0x0020), (0x2009, 0x0020), (0x200A, 0x0020),
+    (0x2010, 0x002D), (0x2012, 0x002D), (0x2015, 0x002D), (0x201B, 0x0027), (0x201F, 0x0022),
+    (0x2024, 0x002E), (0x202F, 0x0020), (0x2032, 0x0027), (0x2035, 0x0060), (0x205F, 0x0020),
+    (0x2070, 0x0030), (0x2071, 0x0069), (0x2074, 0x0034), (0x2075, 0x0035), (0x2076, 0x0036),
+    (0x2077, 0x0037), (0x2078, 0x0038), (0x2079, 0x0039), (0x207A, 0x002B), (0x207C, 0x003D),
+    (0x207D, 0x0028), (0x207E, 0x0029), (0x207F, 0x006E), (0x2080, 0x0030), (0x2081, 0x0031),
+    (0x2082, 0x0032), (0x2083, 0x0033), (0x2084, 0x0034), (0x2085, 0x0035), (0x2086, 0x0036),
+    (0x2087, 0x0037), (0x2088, 0x0038), (0x2089, 0x0039), (0x208A, 0x002B), (0x208C, 0x003D),
+    (0x208D, 0x0028), (0x208E, 0x0029), (0x2090, 0x0061), (0x2091, 0x0065), (0x2092, 0x006F),
+    (0x2093, 0x0078), (0x2095, 0x0068), (0x2096, 0x006B), (0x2097, 0x006C), (0x2098, 0x006D),
+    (0x2099, 0x006E), (0x209A, 0x0070), (0x209B, 0x0073), (0x209C, 0x0074), (0x2102, 0x0043),
+    (0x210A, 0x0067), (0x210B, 0x0048), (0x210C, 0x0048), (0x210D, 0x0048), (0x210E, 0x0068),
+    (0x2110, 0x0049), (0x2111, 0x0049), (0x2112, 0x004C), (0x2113, 0x006C), (0x2115, 0x004E),
+    (0x2119, 0x0050), (0x211A, 0x0051), (0x211B, 0x0052), (0x211C, 0x0052), (0x211D, 0x0052),
+    (0x2124, 0x005A), (0x2128, 0x005A), (0x212A, 0x004B), (0x212B, 0x0041), (0x212C, 0x0042),
+    (0x212D, 0x0043), (0x212F, 0x0065), (0x2130, 0x0045), (0x2131, 0x0046), (0x2133, 0x004D),
+    (0x2134, 0x006F), (0x2139, 0x0069), (0x2145, 0x0044), (0x2146, 0x0064), (0x2147, 0x0065),
+    (0x2148, 0x0069), (0x2149, 0x006A), (0x2160, 0x0049), (0x2164, 0x0056), (0x2169, 0x0058),
+    (0x216C, 0x004C), (0x216D, 0x0043), (0x216E, 0x0044), (0x216F, 0x004D), (0x2170, 0x0069),
+    (0x2174, 0x0076), (0x2179, 0x0078), (0x217C, 0x006C), (0x217D, 0x0063), (0x217E, 0x0064),
+    (0x217F, 0x006D), (0x2212, 0x002D), (0x2215, 0x002F), (0x2216, 0x005C), (0x2223, 0x007C),
+    (0x223C, 0x007E), (0x2260, 0x003D), (0x226E, 0x003C), (0x226F, 0x003E), (0x2460, 0x0031),
+    (0x2461, 0x0032), (0x2462, 0x0033), (0x2463, 0x0034), (0x2464, 0x0035), (0x2465, 0x0036),
+    (0x2466, 0x0037), (0x2467, 0x0038), (0x2468, 0x0039), (0x24B6, 0x0041), (0x24B7, 0x0042),
+    (0x24B8, 0x0043), (0x24B9, 0x0044), (0x24BA, 0x0045), (0x24BB, 0x0046), (0x24BC, 0x0047),
+    (0x24BD, 0x0048), (0x24BE, 0x0049), (0x24BF, 0x004A), (0x24C0, 0x004B), (0x24C1, 0x004C),
+    (0x24C2, 0x004D), (0x24C3, 0x004E), (0x24C4, 0x004F), (0x24C5, 0x0050), (0x24C6, 0x0051),
+    (0x24C7, 0x0052), (0x24C8, 0x0053), (0x24C9, 0x0054), (0x24CA, 0x0055), (0x24CB, 0x0056),
+    (0x24CC, 0x0057), (0x24CD, 0x0058), (0x24CE, 0x0059), (0x24CF, 0x005A), (0x24D0, 0x0061),
+    (0x24D1, 0x0062), (0x24D2, 0x0063), (0x24D3, 0x0064), (0x24D4, 0x0065), (0x24D5, 0x0066),
+    (0x24D6, 0x0067), (0x24D7, 0x0068), (0x24D8, 0x0069), (0x24D9, 0x006A), (0x24DA, 0x006B),
+    (0x24DB, 0x006C), (0x24DC, 0x006D), (0x24DD, 0x006E), (0x24DE, 0x006F), (0x24DF, 0x0070),
+    (0x24E0, 0x0071), (0x24E1, 0x0072), (0x24E2, 0x0073), (0x24E3, 0x0074), (0x24E4, 0x0075),
+    (0x24E5, 0x0076), (0x24E6, 0x0077), (0x24E7, 0x0078), (0x24E8, 0x0079), (0x24E9, 0x007A),
+    (0x24EA, 0x0030), (0x2C7C, 0x006A), (0x2C7D, 0x0056), (0x3000, 0x0020), (0xA7F2, 0x0043),
+    (0xA7F3, 0x0046), (0xA7F4, 0x0051), (0xA7F9, 0x009C), (0xFB29, 0x002B), (0xFB56, 0x0081),
+    (0xFB57, 0x0081), (0xFB58, 0x0081), (0xFB59, 0x0081), (0xFB66, 0x008A), (0xFB67, 0x008A),
+    (0xFB68, 0x008A), (0xFB69, 0x008A), (0xFB7A, 0x008D), (0xFB7B, 0x008D), (0xFB7C, 0x008D),
+    (0xFB7D, 0x008D), (0xFB88, 0x008F), (0xFB89, 0x008F), (0xFB8A, 0x008E), (0xFB8B, 0x008E),
+    (0xFB8C, 0x009A), (0xFB8D, 0x009A), (0xFB8E, 0x0098), (0xFB8F, 0x0098), (0xFB90, 0x0098),
+    (0xFB91, 0x0098), (0xFB92, 0x0090), (0xFB93, 0x0090), (0xFB94, 0x0090), (0xFB95, 0x0090),
+    (0xFB9E, 0x009F), (0xFB9F, 0x009F), (0xFBA6, 0x00C0), (0xFBA7, 0x00C0), (0xFBA8, 0x00C0),
+    (0xFBA9, 0x00C0), (0xFBAA, 0x00AA), (0xFBAB, 0x00AA), (0xFBAC, 0x00AA), (0xFBAD, 0x00AA),
+    (0xFBAE, 0x00FF), (0xFBAF, 0x00FF), (0xFBB0, 0x00FF), (0xFBB1, 0x00FF), (0xFBE8, 0x00EC),
+    (0xFBE9, 0x00EC), (0xFC5B, 0x00D0), (0xFC5C, 0x00D1), (0xFC5D, 0x00EC), (0xFC90, 0x00EC),
+    (0xFCD9, 0x00E5), (0xFCF2, 0x00DC), (0xFCF3, 0x00DC), (0xFCF4, 0x00DC), (0xFD3C, 0x00C7),
+    (0xFD3D, 0x00C7), (0xFE10, 0x002C), (0xFE13, 0x003A), (0xFE14, 0x003B), (0xFE15, 0x0021),
+    (0xFE16, 0x003F), (0xFE31, 0x0097), (0xFE32, 0x0096), (0xFE33, 0x005F), (0xFE34, 0x005F),
+    (0xFE35, 0x0028), (0xFE36, 0x0029), (0xFE37, 0x007B), (0xFE38, 0x007D), (0xFE47, 0x005B),
+    (0xFE48, 0x005D), (0xFE4D, 0x005F), (0xFE4E, 0x005F), (0xFE4F, 0x005F), (0xFE50, 0x002C),
+    (0xFE52, 0x002E), (0xFE54, 0x003B), (0xFE55, 0x003A), (0xFE56, 0x003F), (0xFE57, 0x0021),
+    (0xFE58, 0x0097), (0xFE59, 0x0028), (0xFE5A, 0x0029), (0xFE5B, 0x007B), (0xFE5C, 0x007D),
+    (0xFE5F, 0x0023), (0xFE60, 0x0026), (0xFE61, 0x002A), (0xFE62, 0x002B), (0xFE63, 0x002D),
+    (0xFE64, 0x003C), (0xFE65, 0x003E), (0xFE66, 0x003D), (0xFE68, 0x005C), (0xFE69, 0x0024),
+    (0xFE6A, 0x0025), (0xFE6B, 0x0040), (0xFE71, 0x00DC), (0xFE77, 0x00DC), (0xFE79, 0x00DC),
+    (0xFE7B, 0x00DC), (0xFE7D, 0x00DC), (0xFE7F, 0x00DC), (0xFE80, 0x00C1), (0xFE81, 0x00C7),
+    (0xFE82, 0x00C7), (0xFE83, 0x00C7), (0xFE84, 0x00C7), (0xFE85, 0x00E6), (0xFE86, 0x00E6),
+    (0xFE87, 0x00C7), (0xFE88, 0x00C7), (0xFE89, 0x00ED), (0xFE8A, 0x00ED), (0xFE8B, 0x00ED),
+    (0xFE8C, 0x00ED), (0xFE8D, 0x00C7), (0xFE8E, 0x00C7), (0xFE8F, 0x00C8), (0xFE90, 0x00C8),
+    (0xFE91, 0x00C8), (0xFE92, 0x00C8), (0xFE93, 0x00C9), (0xFE94, 0x00C9), (0xFE95, 0x00CA),
+    (0xFE96, 0x00CA), (0xFE97, 0x00CA), (0xFE98, 0x00CA), (0xFE99, 0x00CB), (0xFE9A, 0x00CB),
+    (0xFE9B, 0x00CB), (0xFE9C, 0x00CB), (0xFE9D, 0x00CC), (0xFE9E, 0x00CC), (0xFE9F, 0x00CC),
+    (0xFEA0, 0x00CC), (0xFEA1, 0x00CD), (0xFEA2, 0x00CD), (0xFEA3, 0x00CD), (0xFEA4, 0x00CD),
+    (0xFEA5, 0x00CE), (0xFEA6, 0x00CE), (0xFEA7, 0x00CE), (0xFEA8, 0x00CE), (0xFEA9, 0x00CF),
+    (0xFEAA, 0x00CF), (0xFEAB, 0x00D0), (0xFEAC, 0x00D0), (0xFEAD, 0x00D1), (0xFEAE, 0x00D1),
+    (0xFEAF, 0x00D2), (0xFEB0, 0x00D2), (0xFEB1, 0x00D3), (0xFEB2, 0x00D3), (0xFEB3, 0x00D3),
+    (0xFEB4, 0x00D3), (0xFEB5, 0x00D4), (0xFEB6, 0x00D4), (0xFEB7, 0x00D4), (0xFEB8, 0x00D4),
+    (0xFEB9, 0x00D5), (0xFEBA, 0x00D5), (0xFEBB, 0x00D5), (0xFEBC, 0x00D5), (0xFEBD, 0x00D6),
+    (0xFEBE, 0x00D6), (0xFEBF, 0x00D6), (0xFEC0, 0x00D6), (0xFEC1, 0x00D8), (0xFEC2, 0x00D8),
+    (0xFEC3, 0x00D8), (0xFEC4, 0x00D8), (0xFEC5, 0x00D9), (0xFEC6, 0x00D9), (0xFEC7, 0x00D9),
+    (0xFEC8, 0x00D9), (0xFEC9, 0x00DA), (0xFECA, 0x00DA), (0xFECB, 0x00DA), (0xFECC, 0x00DA),
+    (0xFECD, 0x00DB), (0xFECE, 0x00DB), (0xFECF, 0x00DB), (0xFED0, 0x00DB), (0xFED1, 0x00DD),
+    (0xFED2, 0x00DD), (0xFED3, 0x00DD), (0xFED4, 0x00DD), (0xFED5, 0x00DE), (0xFED6, 0x00DE),
+    (0xFED7, 0x00DE), (0xFED8, 0x00DE), (0xFED9, 0x00DF), (0xFEDA, 0x00DF), (0xFEDB, 0x00DF),
+    (0xFEDC, 0x00DF), (0xFEDD, 0x00E1), (0xFEDE, 0x00E1), (0xFEDF, 0x00E1), (0xFEE0, 0x00E1),
+    (0xFEE1, 0x00E3), (0xFEE2, 0x00E3), (0xFEE3, 0x00E3), (0xFEE4, 0x00E3), (0xFEE5, 0x00E4),
+    (0xFEE6, 0x00E4), (0xFEE7, 0x00E4), (0xFEE8, 0x00E4), (0xFEE9, 0x00E5), (0xFEEA, 0x00E5),
+    (0xFEEB, 0x00E5), (0xFEEC, 0x00E5), (0xFEED, 0x00E6), (0xFEEE, 0x00E6), (0xFEEF, 0x00EC),
+    (0xFEF0, 0x00EC), (0xFEF1, 0x00ED), (0xFEF2, 0x00ED), (0xFEF3, 0x00ED), (0xFEF4, 0x00ED),
+    (0xFF01, 0x0021), (0xFF02, 0x0022), (0xFF03, 0x0023), (0xFF04, 0x0024), (0xFF05, 0x0025),
+    (0xFF06, 0x0026), (0xFF07, 0x0027), (0xFF08, 0x0028), (0xFF09, 0x0029), (0xFF0A, 0x002A),
+    (0xFF0B, 0x002B), (0xFF0C, 0x002C), (0xFF0D, 0x002D), (0xFF0E, 0x002E), (0xFF0F, 0x002F),
+    (0xFF10, 0x0030), (0xFF11, 0x0031), (0xFF12, 0x0032), (0xFF13, 0x0033), (0xFF14, 0x0034),
+    (0xFF15, 0x0035), (0xFF16, 0x0036), (0xFF17, 0x0037), (0xFF18, 0x0038), (0xFF19, 0x0039),
+    (0xFF1A, 0x003A), (0xFF1B, 0x003B), (0xFF1C, 0x003C), (0xFF1D, 0x003D), (0xFF1E, 0x003E),
+    (0xFF1F, 0x003F), (0xFF20, 0x0040), (0xFF21, 0x0041), (0xFF22, 0x0042), (0xFF23, 0x0043),
+    (0xFF24, 0x0044), (0xFF25, 0x0045), (0xFF26, 0x0046), (0xFF27, 0x0047), (0xFF28, 0x0048),
+    (0xFF29, 0x0049), (0xFF2A, 0x004A), (0xFF2B, 0x004B), (0xFF2C, 0x004C), (0xFF2D, 0x004D),
+    (0xFF2E, 0x004E), (0xFF2F, 0x004F), (0xFF30, 0x0050), (0xFF31, 0x0051), (0xFF32, 0x0052),
+    (0xFF33, 0x0053), (0xFF34, 0x0054), (0xFF35, 0x0055), (0xFF36, 0x0056), (0xFF37, 0x0057),
+    (0xFF38, 0x0058), (0xFF39, 0x0059), (0xFF3A, 0x005A), (0xFF3B, 0x005B), (0xFF3C, 0x005C),
+    (0xFF3D, 0x005D), (0xFF3E, 0x005E), (0xFF3F, 0x005F), (0xFF40, 0x0060), (0xFF41, 0x0061),
+    (0xFF42, 0x0062), (0xFF43, 0x0063), (0xFF44, 0x0064), (0xFF45, 0x0065), (0xFF46, 0x0066),
+    (0xFF47, 0x0067), (0xFF48, 0x0068), (0xFF49, 0x0069), (0xFF4A, 0x006A), (0xFF4B, 0x006B),
+    (0xFF4C, 0x006C), (0xFF4D, 0x006D), (0xFF4E, 0x006E), (0xFF4F, 0x006F), (0xFF50, 0x0070),
+    (0xFF51, 0x0071), (0xFF52, 0x0072), (0xFF53, 0x0073), (0xFF54, 0x0074), (0xFF55, 0x0075),
+    (0xFF56, 0x0076), (0xFF57, 0x0077), (0xFF58, 0x0078), (0xFF59, 0x0079), (0xFF5A, 0x007A),
+    (0xFF5B, 0x007B), (0xFF5C, 0x007C), (0xFF5D, 0x007D), (0xFF5E, 0x007E), (0xFFE0, 0x00A2),
+    (0xFFE1, 0x00A3), (0xFFE2, 0x00AC), (0xFFE4, 0x00A6), (0xFFE5, 0x00A5),
+];
+
+/// Code page 1257.
+#[rustfmt::skip]
+pub(crate) const CP1257: [(u16, u16); 810] = [
+    (0x00AA, 0x0061), (0x00BA, 0x006F), (0x00C0, 0x0041), (0x00C1, 0x0041), (0x00C2, 0x0041),
+    (0x00C3, 0x0041), (0x00C7, 0x0043), (0x00C8, 0x0045), (0x00CA, 0x0045), (0x00CB, 0x0045),
+    (0x00CC, 0x0049), (0x00CD, 0x0049), (0x00CE, 0x0049), (0x00CF, 0x0049), (0x00D1, 0x004E),
+    (0x00D2, 0x004F), (0x00D4, 0x004F), (0x00D9, 0x0055), (0x00DA, 0x0055), (0x00DB, 0x0055),
+    (0x00DD, 0x0059), (0x00E0, 0x0061), (0x00E1, 0x0061), (0x00E2, 0x0061), (0x00E3, 0x0061),
+    (0x00E7, 0x0063), (0x00E8, 0x0065), (0x00EA, 0x0065), (0x00EB, 0x0065), (0x00EC, 0x0069),
+    (0x00ED, 0x0069), (0x00EE, 0x0069), (0x00EF, 0x0069), (0x00F1, 0x006E), (0x00F2, 0x006F),
+    (0x00F4, 0x006F), (0x00F9, 0x0075), (0x00FA, 0x0075), (0x00FB, 0x0075), (0x00FD, 0x0079),
+    (0x00FF, 0x0079), (0x0102, 0x0041), (0x0103, 0x0061), (0x0108, 0x0043), (0x0109, 0x0063),
+    (0x010A, 0x0043), (0x010B, 0x0063), (0x010E, 0x0044), (0x010F, 0x0064), (0x0114, 0x0045),
+    (0x0115, 0x0065), (0x011A, 0x0045), (0x011B, 0x0065), (0x011C, 0x0047), (0x011D, 0x0067),
+    (0x011E, 0x0047), (0x011F, 0x0067), (0x0120, 0x0047), (0x0121, 0x0067), (0x0124, 0x0048),
+    (0x0125, 0x0068), (0x0128, 0x0049), (0x0129, 0x0069), (0x012C, 0x0049), (0x012D, 0x0069),
+    (0x0130, 0x0049), (0x0134, 0x004A), (0x0135, 0x006A), (0x0139, 0x004C), (0x013A, 0x006C),
+    (0x013D, 0x004C), (0x013E, 0x006C), (0x0147, 0x004E), (0x0148, 0x006E), (0x014E, 0x004F),
+    (0x014F, 0x006F), (0x0150, 0x004F), (0x0151, 0x006F), (0x0154, 0x0052), (0x0155, 0x0072),
+    (0x0158, 0x0052), (0x0159, 0x0072), (0x015C, 0x0053), (0x015D, 0x0073), (0x015E, 0x0053),
+    (0x015F, 0x0073), (0x0162, 0x0054), (0x0163, 0x0074), (0x0164, 0x0054), (0x0165, 0x0074),
+    (0x0168, 0x0055), (0x0169, 0x0075), (0x016C, 0x0055), (0x016D, 0x0075), (0x016E, 0x0055),
+    (0x016F, 0x0075), (0x0170, 0x0055), (0x0171, 0x0075), (0x0174, 0x0057), (0x0175, 0x0077),
+    (0x0176, 0x0059), (0x0177, 0x0079), (0x0178, 0x0059), (0x017F, 0x0073), (0x01A0, 0x004F),
+    (0x01A1, 0x006F), (0x01AF, 0x0055), (0x01B0, 0x0075), (0x01CD, 0x0041), (0x01CE, 0x0061),
+    (0x01CF, 0x0049), (0x01D0, 0x0069), (0x01D1, 0x004F), (0x01D2, 0x006F), (0x01D3, 0x0055),
+    (0x01D4, 0x0075), (0x01D5, 0x0055), (0x01D6, 0x0075), (0x01D7, 0x0055), (0x01D8, 0x0075),
+    (0x01D9, 0x0055), (0x01DA, 0x0075), (0x01DB, 0x0055), (0x01DC, 0x0075), (0x01DE, 0x0041),
+    (0x01DF, 0x0061), (0x01E0, 0x0041), (0x01E1, 0x0061), (0x01E2, 0x00AF), (0x01E3, 0x00BF),
+    (0x01E6, 0x0047), (0x01E7, 0x0067), (0x01E8, 0x004B), (0x01E9, 0x006B), (0x01EA, 0x004F),
+    (0x01EB, 0x006F), (0x01EC, 0x004F), (0x01ED, 0x006F), (0x01F0, 0x006A), (0x01F4, 0x0047),
+    (0x01F5, 0x0067), (0x01F8, 0x004E), (0x01F9, 0x006E), (0x01FA, 0x0041), (0x01FB, 0x0061),
+    (0x01FC, 0x00AF), (0x01FD, 0x00BF), (0x01FE, 0x00A8), (0x01FF, 0x00B8), (0x0200, 0x0041),
+    (0x0201, 0x0061), (0x0202, 0x0041), (0x0203, 0x0061), (0x0204, 0x0045), (0x0205, 0x0065),
+    (0x0206, 0x0045), (0x0207, 0x0065), (0x0208, 0x0049), (0x0209, 0x0069), (0x020A, 0x0049),
+    (0x020B, 0x0069), (0x020C, 0x004F), (0x020D, 0x006F), (0x020E, 0x004F), (0x020F, 0x006F),
+    (0x0210, 0x0052), (0x0211, 0x0072), (0x0212, 0x0052), (0x0213, 0x0072), (0x0214, 0x0055),
+    (0x0215, 0x0075), (0x0216, 0x0055), (0x0217, 0x0075), (0x0218, 0x0053), (0x0219, 0x0073),
+    (0x021A, 0x0054), (0x021B, 0x0074), (0x021E, 0x0048), (0x021F, 0x0068), (0x0226, 0x0041),
+    (0x0227, 0x0061), (0x0228, 0x0045), (0x0229, 0x0065), (0x022A, 0x004F), (0x022B, 0x006F),
+    (0x022C, 0x004F), (0x022D, 0x006F), (0x022E, 0x004F), (0x022F, 0x006F), (0x0230, 0x004F),
+    (0x0231, 0x006F), (0x0232, 0x0059), (0x0233, 0x0079), (0x02B0, 0x0068), (0x02B2, 0x006A),
+    (0x02B3, 0x0072), (0x02B7, 0x0077), (0x02B8, 0x0079), (0x02E1, 0x006C), (0x02E2, 0x0073),
+    (0x02E3, 0x0078), (0x037E, 0x003B), (0x0387, 0x00B7), (0x1D2C, 0x0041), (0x1D2D, 0x00AF),
+    (0x1D2E, 0x0042), (0x1D30, 0x0044), (0x1D31, 0x0045), (0x1D33, 0x0047), (0x1D34, 0x0048),
+    (0x1D35, 0x0049), (0x1D36, 0x004A), (0x1D37, 0x004B), (0x1D38, 0x004C), (0x1D39, 0x004D),
+    (0x1D3A, 0x004E), (0x1D3C, 0x004F), (0x1D3E, 0x0050), (0x1D3F, 0x0052), (0x1D40, 0x0054),
+    (0x1D41, 0x0055), (0x1D42, 0x0057), (0x1D43, 0x0061), (0x1D47, 0x0062), (0x1D48, 0x0064),
+    (0x1D49, 0x0065), (0x1D4D, 0x0067), (0x1D4F, 0x006B), (0x1D50, 0x006D), (0x1D52, 0x006F),
+    (0x1D56, 0x0070), (0x1D57, 0x0074), (0x1D58, 0x0075), (0x1D5B, 0x0076), (0x1D62, 0x0069),
+    (0x1D63, 0x0072), (0x1D64, 0x0075), (0x1D65, 0x0076), (0x1D9C, 0x0063), (0x1DA0, 0x0066),
+    (0x1DBB, 0x007A), (0x1E00, 0x0041), (0x1E01, 0x0061), (0x1E02, 0x0042), (0x1E03, 0x0062),
+    (0x1E04, 0x0042), (0x1E05, 0x0062), (0x1E06, 0x0042), (0x1E07, 0x0062), (0x1E08, 0x0043),
+    (0x1E09, 0x0063), (0x1E0A, 0x0044), (0x1E0B, 0x0064), (0x1E0C, 0x0044), (0x1E0D, 0x0064),
+    (0x1E0E, 0x0044), (0x1E0F, 0x0064), (0x1E10, 0x0044), (0x1E11, 0x0064), (0x1E12, 0x0044),
+    (0x1E13, 0x0064), (0x1E14, 0x0045), (0x1E15, 0x0065), (0x1E16, 0x0045), (0x1E17, 0x0065),
+    (0x1E18, 0x0045), (0x1E19, 0x0065), (0x1E1A, 0x0045), (0x1E1B, 0x0065), (0x1E1C, 0x0045),
+    (0x1E1D, 0x0065), (0x1E1E, 0x0046), (0x1E1F, 0x0066), (0x1E20, 0x0047), (0x1E21, 0x0067),
+    (0x1E22, 0x0048), (0x1E23, 0x0068), (0x1E24, 0x0048), (0x1E25, 0x0068), (0x1E26, 0x0048),
+    (0x1E27, 0x0068), (0x1E28, 0x0048), (0x1E29, 0x0068), (0x1E2A, 0x0048), (0x1E2B, 0x0068),
+    (0x1E2C, 0x0049), (0x1E2D, 0x0069), (0x1E2E, 0x0049), (0x1E2F, 0x0069), (0x1E30, 0x004B),
+    (0x1E31, 0x006B), (0x1E32, 0x004B), (0x1E33, 0x006B), (0x1E34, 0x004B), (0x1E35, 0x006B),
+    (0x1E36, 0x004C), (0x1E37, 0x006C), (0x1E38, 0x004C), (0x1E39, 0x006C), (0x1E3A, 0x004C),
+    (0x1E3B, 0x006C), (0x1E3C, 0x004C), (0x1E3D, 0x006C), (0x1E3E, 0x004D), (0x1E3F, 0x006D),
+    (0x1E40, 0x004D), (0x1E41, 0x006D), (0x1E42, 0x004D), (0x1E43, 0x006D), (0x1E44, 0x004E),
+    (0x1E45, 0x006E), (0x1E46, 0x004E), (0x1E47, 0x006E), (0x1E48, 0x004E), (0x1E49, 0x006E),
+    (0x1E4A, 0x004E), (0x1E4B, 0x006E), (0x1E4C, 0x004F), (0x1E4D, 0x006F), (0x1E4E, 0x004F),
+    (0x1E4F, 0x006F), (0x1E50, 0x004F), (0x1E51, 0x006F), (0x1E52, 0x004F), (0x1E53, 0x006F),
+    (0x1E54, 0x0050), (0x1E55, 0x0070), (0x1E56, 0x0050), (0x1E57, 0x0070), (0x1E58, 0x0052),
+    (0x1E59, 0x0072), (0x1E5A, 0x0052), (0x1E5B, 0x0072), (0x1E5C, 0x0052), (0x1E5D, 0x0072),
+    (0x1E5E, 0x0052), (0x1E5F, 0x0072), (0x1E60, 0x0053), (0x1E61, 0x0073), (0x1E62, 0x0053),
+    (0x1E63, 0x0073), (0x1E64, 0x0053), (0x1E65, 0x0073), (0x1E66, 0x0053), (0x1E67, 0x0073),
+    (0x1E68, 0x0053), (0x1E69, 0x0073), (0x1E6A, 0x0054), (0x1E6B, 0x0074), (0x1E6C, 0x0054),
+    (0x1E6D, 0x0074), (0x1E6E, 0x0054), (0x1E6F, 0x0074), (0x1E70, 0x0054), (0x1E71, 0x0074),
+    (0x1E72, 0x0055), (0x1E73, 0x0075), (0x1E74, 0x0055), (0x1E75, 0x0075), (0x1E76, 0x0055),
+    (0x1E77, 0x0075), (0x1E78, 0x0055), (0x1E79, 0x0075), (0x1E7A, 0x0055), (0x1E7B, 0x0075),
+    (0x1E7C, 0x0056), (0x1E7D, 0x0076), (0x1E7E, 0x0056), (0x1E7F, 0x0076), (0x1E80, 0x0057),
+    (0x1E81, 0x0077), (0x1E82, 0x0057), (0x1E83, 0x0077), (0x1E84, 0x0057), (0x1E85, 0x0077),
+    (0x1E86, 0x0057), (0x1E87, 0x0077), (0x1E88, 0x0057), (0x1E89, 0x0077), (0x1E8A, 0x0058),
+    (0x1E8B, 0x0078), (0x1E8C, 0x0058), (0x1E8D, 0x0078), (0x1E8E, 0x0059), (0x1E8F, 0x0079),
+    (0x1E90, 0x005A), (0x1E91, 0x007A), (0x1E92, 0x005A), (0x1E93, 0x007A), (0x1E94, 0x005A),
+    (0x1E95, 0x007A), (0x1E96, 0x0068), (0x1E97, 0x0074), (0x1E98, 0x0077), (0x1E99, 0x0079),
+    (0x1E9B, 0x0073), (0x1EA0, 0x0041), (0x1EA1, 0x0061), (0x1EA2, 0x0041), (0x1EA3, 0x0061),
+    (0x1EA4, 0x0041), (0x1EA5, 0x0061), (0x1EA6, 0x0041), (0x1EA7, 0x0061), (0x1EA8, 0x0041),
+    (0x1EA9, 0x0061), (0x1EAA, 0x0041), (0x1EAB, 0x0061), (0x1EAC, 0x0041), (0x1EAD, 0x0061),
+    (0x1EAE, 0x0041), (0x1EAF, 0x0061), (0x1EB0, 0x0041), (0x1EB1, 0x0061), (0x1EB2, 0x0041),
+    (0x1EB3, 0x0061), (0x1EB4, 0x0041), (0x1EB5, 0x0061), (0x1EB6, 0x0041), (0x1EB7, 0x0061),
+    (0x1EB8, 0x0045), (0x1EB9, 0x0065), (0x1EBA, 0x0045), (0x1EBB, 0x0065), (0x1EBC, 0x0045),
+    (0x1EBD, 0x0065), (0x1EBE, 0x0045), (0x1EBF, 0x0065), (0x1EC0, 0x0045), (0x1EC1, 0x0065),
+    (0x1EC2, 0x0045), (0x1EC3, 0x0065), (0x1EC4, 0x0045), (0x1EC5, 0x0065), (0x1EC6, 0x0045),
+    (0x1EC7, 0x0065), (0x1EC8, 0x0049), (0x1EC9, 0x0069), (0x1ECA, 0x0049), (0x1ECB, 0x0069),
+    (0x1ECC, 0x004F), (0x1ECD, 0x006F), (0x1ECE, 0x004F), (0x1ECF, 0x006F), (0x1ED0, 0x004F),
+    (0x1ED1, 0x006F), (0x1ED2, 0x004F), (0x1ED3, 0x006F), (0x1ED4, 0x004F), (0x1ED5, 0x006F),
+    (0x1ED6, 0x004F), (0x1ED7, 0x006F), (0x1ED8, 0x004F), (0x1ED9, 0x006F), (0x1EDA, 0x004F),
+    (0x1EDB, 0x006F), (0x1EDC, 0x004F), (0x1EDD, 0x006F), (0x1EDE, 0x004F), (0x1EDF, 0x006F),
+    (0x1EE0, 0x004F), (0x1EE1, 0x006F), (0x1EE2, 0x004F), (0x1EE3, 0x006F), (0x1EE4, 0x0055),
+    (0x1EE5, 0x0075), (0x1EE6, 0x0055), (0x1EE7, 0x0075), (0x1EE8, 0x0055), (0x1EE9, 0x0075),
+    (0x1EEA, 0x0055), (0x1EEB, 0x0075), (0x1EEC, 0x0055), (0x1EED, 0x0075), (0x1EEE, 0x0055),
+    (0x1EEF, 0x0075), (0x1EF0, 0x0055), (0x1EF1, 0x0075), (0x1EF2, 0x0059), (0x1EF3, 0x0079),
+    (0x1EF4, 0x0059), (0x1EF5, 0x0079), (0x1EF6, 0x0059), (0x1EF7, 0x0079), (0x1EF8, 0x0059),
+    (0x1EF9, 0x0079), (0x1FEF, 0x0060), (0x2000, 0x0020), (0x2001, 0x0020), (0x2002, 0x0020),
+    (0x2003, 0x0020), (0x2004, 0x0020), (0x2005, 0x0020), (0x2006, 0x0020), (0x2007, 0x0020),
+    (0x2008, 0x0020), (0x2009, 0x0020), (0x200A, 0x0020), (0x2010, 0x002D), (0x2012, 0x002D),
+    (0x2015, 0x002D), (0x201B, 0x0027), (0x201F, 0x0022), (0x2024, 0x002E), (0x202F, 0x0020),
+    (0x2032, 0x0027), (0x2035, 0x0060), (0x205F, 0x0020), (0x2070, 0x0030), (0x2071, 0x0069),
+    (0x2074, 0x0034), (0x2075, 0x0035), (0x2076, 0x0036), (0x2077, 0x0037), (0x2078, 0x0038),
+    (0x2079, 0x0039), (0x207A, 0x002B), (0x207C, 0x003D), (0x207D, 0x0028), (0x207E, 0x0029),
+    (0x207F, 0x006E), (0x2080, 0x0030), (0x2081, 0x0031), (0x2082, 0x0032), (0x2083, 0x0033),
+    (0x2084, 0x0034), (0x2085, 0x0035), (0x2086, 0x0036), (0x2087, 0x0037), (0x2088, 0x0038),
+    (0x2089, 0x0039), (0x208A, 0x002B), (0x208C, 0x003D), (0x208D, 0x0028), (0x208E, 0x0029),
+    (0x2090, 0x0061), (0x2091, 0x0065), (0x2092, 0x006F), (0x2093, 0x0078), (0x2095, 0x0068),
+    (0x2096, 0x006B), (0x2097, 0x006C), (0x2098, 0x006D), (0x2099, 0x006E), (0x209A, 0x0070),
+    (0x209B, 0x0073), (0x209C, 0x0074), (0x2102, 0x0043), (0x210A, 0x0067), (0x210B, 0x0048),
+    (0x210C, 0x0048), (0x210D, 0x0048), (0x210E, 0x0068), (0x2110, 0x0049), (0x2111, 0x0049),
+    (0x2112, 0x004C), (0x2113, 0x006C), (0x2115, 0x004E), (0x2119, 0x0050), (0x211A, 0x0051),
+    (0x211B, 0x0052), (0x211C, 0x0052), (0x211D, 0x0052), (0x2124, 0x005A), (0x2128, 0x005A),
+    (0x212A, 0x004B), (0x212B, 0x0041), (0x212C, 0x0042), (0x212D, 0x0043), (0x212F, 0x0065),
+    (0x2130, 0x0045), (0x2131, 0x0046), (0x2133, 0x004D), (0x2134, 0x006F), (0x2139, 0x0069),
+    (0x2145, 0x0044), (0x2146, 0x0064), (0x2147, 0x0065), (0x2148, 0x0069), (0x2149, 0x006A),
+    (0x2160, 0x0049), (0x2164, 0x0056), (0x2169, 0x0058), (0x216C, 0x004C), (0x216D, 0x0043),
+    (0x216E, 0x0044), (0x216F, 0x004D), (0x2170, 0x0069), (0x2174, 0x0076), (0x2179, 0x0078),
+    (0x217C, 0x006C), (0x217D, 0x0063), (0x217E, 0x0064), (0x217F, 0x006D), (0x2212, 0x002D),
+    (0x2215, 0x002F), (0x2216, 0x005C), (0x2223, 0x007C), (0x223C, 0x007E), (0x2260, 0x003D),
+    (0x226E, 0x003C), (0x226F, 0x003E), (0x2460, 0x0031), (0x2461, 0x0032), (0x2462, 0x0033),
+    (0x2463, 0x0034), (0x2464, 0x0035), (0x2465, 0x0036), (0x2466, 0x0037), (0x2467, 0x0038),
+    (0x2468, 0x0039), (0x24B6, 0x0041), (0x24B7, 0x0042), (0x24B8, 0x0043), (0x24B9, 0x0044),
+    (0x24BA, 0x0045), (0x24BB, 0x0046), (0x24BC, 0x0047), (0x24BD, 0x0048), (0x24BE, 0x0049),
+    (0x24BF, 0x004A), (0x24C0, 0x004B), (0x24C1, 0x004C), (0x24C2, 0x004D), (0x24C3, 0x004E),
+    (0x24C4, 0x004F), (0x24C5, 0x0050), (0x24C6, 0x0051), (0x24C7, 0x0052), (0x24C8, 0x0053),
+    (0x24C9, 0x0054), (0x24CA, 0x0055), (0x24CB, 0x0056), (0x24CC, 0x0057), (0x24CD, 0x0058),
+    (0x24CE, 0x0059), (0x24CF, 0x005A), (0x24D0, 0x0061), (0x24D1, 0x0062), (0x24D2, 0x0063),
+    (0x24D3, 0x0064), (0x24D4, 0x0065), (0x24D5, 0x0066), (0x24D6, 0x0067), (0x24D7, 0x0068),
+    (0x24D8, 0x0069), (0x24D9, 0x006A), (0x24DA, 0x006B), (0x24DB, 0x006C), (0x24DC, 0x006D),
+    (0x24DD, 0x006E), (0x24DE, 0x006F), (0x24DF, 0x0070), (0x24E0, 0x0071), (0x24E1, 0x0072),
+    (0x24E2, 0x0073), (0x24E3, 0x0074), (0x24E4, 0x0075), (0x24E5, 0x0076), (0x24E6, 0x0077),
+    (0x24E7, 0x0078), (0x24E8, 0x0079), (0x24E9, 0x007A), (0x24EA, 0x0030), (0x2C7C, 0x006A),
+    (0x2C7D, 0x0056), (0x3000, 0x0020), (0xA7F2, 0x0043), (0xA7F3, 0x0046), (0xA7F4, 0x0051),
+    (0xFB29, 0x002B), (0xFE10, 0x002C), (0xFE13, 0x003A), (0xFE14, 0x003B), (0xFE15, 0x0021),
+    (0xFE16, 0x003F), (0xFE31, 0x0097), (0xFE32, 0x0096), (0xFE33, 0x005F), (0xFE34, 0x005F),
+    (0xFE35, 0x0028), (0xFE36, 0x0029), (0xFE37, 0x007B), (0xFE38, 0x007D), (0xFE47, 0x005B),
+    (0xFE48, 0x005D), (0xFE4D, 0x005F), (0xFE4E, 0x005F), (0xFE4F, 0x005F), (0xFE50, 0x002C),
+    (0xFE52, 0x002E), (0xFE54, 0x003B), (0xFE55, 0x003A), (0xFE56, 0x003F), (0xFE57, 0x0021),
+    (0xFE58, 0x0097), (0xFE59, 0x0028), (0xFE5A, 0x0029), (0xFE5B, 0x007B), (0xFE5C, 0x007D),
+    (0xFE5F, 0x0023), (0xFE60, 0x0026), (0xFE61, 0x002A), (0xFE62, 0x002B), (0xFE63, 0x002D),
+    (0xFE64, 0x003C), (0xFE65, 0x003E), (0xFE66, 0x003D), (0xFE68, 0x005C), (0xFE69, 0x0024),
+    (0xFE6A, 0x0025), (0xFE6B, 0x0040), (0xFF01, 0x0021), (0xFF02, 0x0022), (0xFF03, 0x0023),
+    (0xFF04, 0x0024), (0xFF05, 0x0025), (0xFF06, 0x0026), (0xFF07, 0x0027), (0xFF08, 0x0028),
+    (0xFF09, 0x0029), (0xFF0A, 0x002A), (0xFF0B, 0x002B), (0xFF0C, 0x002C), (0xFF0D, 0x002D),
+    (0xFF0E, 0x002E), (0xFF0F, 0x002F), (0xFF10, 0x0030), (0xFF11, 0x0031), (0xFF12, 0x0032),
+    (0xFF13, 0x0033), (0xFF14, 0x0034), (0xFF15, 0x0035), (0xFF16, 0x0036), (0xFF17, 0x0037),
+    (0xFF18, 0x0038), (0xFF19, 0x0039), (0xFF1A, 0x003A), (0xFF1B, 0x003B), (0xFF1C, 0x003C),
+    (0xFF1D, 0x003D), (0xFF1E, 0x003E), (0xFF1F, 0x003F), (0xFF20, 0x0040), (0xFF21, 0x0041),
+    (0xFF22, 0x0042), (0xFF23, 0x0043), (0xFF24, 0x0044), (0xFF25, 0x0045), (0xFF26, 0x0046),
+    (0xFF27, 0x0047), (0xFF28, 0x0048), (0xFF29, 0x0049), (0xFF2A, 0x004A), (0xFF2B, 0x004B),
+    (0xFF2C, 0x004C), (0xFF2D, 0x004D), (0xFF2E, 0x004E), (0xFF2F, 0x004F), (0xFF30, 0x0050),
+    (0xFF31, 0x0051), (0xFF32, 0x0052), (0xFF33, 0x0053), (0xFF34, 0x0054), (0xFF35, 0x0055),
+    (0xFF36, 0x0056), (0xFF37, 0x0057), (0xFF38, 0x0058), (0xFF39, 0x0059), (0xFF3A, 0x005A),
+    (0xFF3B, 0x005B), (0xFF3C, 0x005C), (0xFF3D, 0x005D), (0xFF3E, 0x005E), (0xFF3F, 0x005F),
+    (0xFF40, 0x0060), (0xFF41, 0x0061), (0xFF42, 0x0062), (0xFF43, 0x0063), (0xFF44, 0x0064),
+    (0xFF45, 0x0065), (0xFF46, 0x0066), (0xFF47, 0x0067), (0xFF48, 0x0068), (0xFF49, 0x0069),
+    (0xFF4A, 0x006A), (0xFF4B, 0x006B), (0xFF4C, 0x006C), (0xFF4D, 0x006D), (0xFF4E, 0x006E),
+    (0xFF4F, 0x006F), (0xFF50, 0x0070), (0xFF51, 0x0071), (0xFF52, 0x0072), (0xFF53, 0x0073),
+    (0xFF54, 0x0074), (0xFF55, 0x0075), (0xFF56, 0x0076), (0xFF57, 0x0077), (0xFF58, 0x0078),
+    (0xFF59, 0x0079), (0xFF5A, 0x007A), (0xFF5B, 0x007B), (0xFF5C, 0x007C), (0xFF5D, 0x007D),
+    (0xFF5E, 0x007E), (0xFFE0, 0x00A2), (0xFFE1, 0x00A3), (0xFFE2, 0x00AC), (0xFFE4, 0x00A6),
+];
+
+/// Code page 1258.
+#[rustfmt::skip]
+pub(crate) const CP1258: [(u16, u16); 820] = [
+    (0x00C3, 0x0041), (0x00CC, 0x0049), (0x00D2, 0x004F), (0x00D5, 0x004F), (0x00DD, 0x0059),
+    (0x00E3, 0x0061), (0x00EC, 0x0069), (0x00F2, 0x006F), (0x00F5, 0x006F), (0x00FD, 0x0079),
+    (0x0100, 0x0041), (0x0101, 0x0061), (0x0104, 0x0041), (0x0105, 0x0061), (0x0106, 0x0043),
+    (0x0107, 0x0063), (0x0108, 0x0043), (0x0109, 0x0063), (0x010A, 0x0043), (0x010B, 0x0063),
+    (0x010C, 0x0043), (0x010D, 0x0063), (0x010E, 0x0044), (0x010F, 0x0064), (0x0112, 0x0045),
+    (0x0113, 0x0065), (0x0114, 0x0045), (0x0115, 0x0065), (0x0116, 0x0045), (0x0117, 0x0065),
+    (0x0118, 0x0045), (0x0119, 0x0065), (0x011A, 0x0045), (0x011B, 0x0065), (0x011C, 0x0047),
+    (0x011D, 0x0067), (0x011E, 0x0047), (0x011F, 0x0067), (0x0120, 0x0047), (0x0121, 0x0067),
+    (0x0122, 0x0047), (0x0123, 0x0067), (0x0124, 0x0048), (0x0125, 0x0068), (0x0128, 0x0049),
+    (0x0129, 0x0069), (0x012A, 0x0049), (0x012B, 0x0069), (0x012C, 0x0049), (0x012D, 0x0069),
+    (0x012E, 0x0049), (0x012F, 0x0069), (0x0130, 0x0049), (0x0134, 0x004A), (0x0135, 0x006A),
+    (0x0136, 0x004B), (0x0137, 0x006B), (0x0139, 0x004C), (0x013A, 0x006C), (0x013B, 0x004C),
+    (0x013C, 0x006C), (0x013D, 0x004C), (0x013E, 0x006C), (0x0143, 0x004E), (0x0144, 0x006E),
+    (0x0145, 0x004E), (0x0146, 0x006E), (0x0147, 0x004E), (0x0148, 0x006E), (0x014C, 0x004F),
+    (0x014D, 0x006F), (0x014E, 0x004F), (0x014F, 0x006F), (0x0150, 0x004F), (0x0151, 0x006F),
+    (0x0154, 0x0052), (0x0155, 0x0072), (0x0156, 0x0052), (0x0157, 0x0072), (0x0158, 0x0052),
+    (0x0159, 0x0072), (0x015A, 0x0053), (0x015B, 0x0073), (0x015C, 0x0053), (0x015D, 0x0073),
+    (0x015E, 0x0053), (0x015F, 0x0073), (0x0160, 0x0053), (0x0161, 0x0073), (0x0162, 0x0054),
+    (0x0163, 0x0074), (0x0164, 0x0054), (0x0165, 0x0074), (0x0168, 0x0055), (0x0169, 0x0075),
+    (0x016A, 0x0055), (0x016B, 0x0075), (0x016C, 0x0055), (0x016D, 0x0075), (0x016E, 0x0055),
+    (0x016F, 0x0075), (0x0170, 0x0055), (0x0171, 0x0075), (0x0172, 0x0055), (0x0173, 0x0075),
+    (0x0174, 0x0057), (0x0175, 0x0077), (0x0176, 0x0059), (0x0177, 0x0079), (0x0179, 0x005A),
+    (0x017A, 0x007A), (0x017B, 0x005A), (0x017C, 0x007A), (0x017D, 0x005A), (0x017E, 0x007A),
+    (0x017F, 0x0073), (0x01CD, 0x0041), (0x01CE, 0x0061), (0x01CF, 0x0049), (0x01D0, 0x0069),
+    (0x01D1, 0x004F), (0x01D2, 0x006F), (0x01D3, 0x0055), (0x01D4, 0x0075), (0x01D5, 0x0055),
+    (0x01D6, 0x0075), (0x01D7, 0x0055), (0x01D8, 0x0075), (0x01D9, 0x0055), (0x01DA, 0x0075),
+    (0x01DB, 0x0055), (0x01DC, 0x0075), (0x01DE, 0x0041), (0x01DF, 0x0061), (0x01E0, 0x0041),
+    (0x01E1, 0x0061), (0x01E2, 0x00C6), (0x01E3, 0x00E6), (0x01E6, 0x0047), (0x01E7, 0x0067),
+    (0x01E8, 0x004B), (0x01E9, 0x006B), (0x01EA, 0x004F), (0x01EB, 0x006F), (0x01EC, 0x004F),
+    (0x01ED, 0x006F), (0x01F0, 0x006A), (0x01F4, 0x0047), (0x01F5, 0x0067), (0x01F8, 0x004E),
+    (0x01F9, 0x006E), (0x01FA, 0x0041), (0x01FB, 0x0061), (0x01FC, 0x00C6), (0x01FD, 0x00E6),
+    (0x01FE, 0x00D8), (0x01FF, 0x00F8), (0x0200, 0x0041), (0x0201, 0x0061), (0x0202, 0x0041),
+    (0x0203, 0x0061), (0x0204, 0x0045), (0x0205, 0x0065), (0x0206, 0x0045), (0x0207, 0x0065),
+    (0x0208, 0x0049), (0x0209, 0x0069), (0x020A, 0x0049), (0x020B, 0x0069), (0x020C, 0x004F),
+    (0x020D, 0x006F), (0x020E, 0x004F), (0x020F, 0x006F), (0x0210, 0x0052), (0x0211, 0x0072),
+    (0x0212, 0x0052), (0x0213, 0x0072), (0x0214, 0x0055), (0x0215, 0x0075), (0x0216, 0x0055),
+    (0x0217, 0x0075), (0x0218, 0x0053), (0x0219, 0x0073), (0x021A, 0x0054), (0x021B, 0x0074),
+    (0x021E, 0x0048), (0x021F, 0x0068), (0x0226, 0x0041), (0x0227, 0x0061), (0x0228, 0x0045),
+    (0x0229, 0x0065), (0x022A, 0x004F), (0x022B, 0x006F), (0x022C, 0x004F), (0x022D, 0x006F),
+    (0x022E, 0x004F), (0x022F, 0x006F), (0x0230, 0x004F), (0x0231, 0x006F), (0x0232, 0x0059),
+    (0x0233, 0x0079), (0x02B0, 0x0068), (0x02B2, 0x006A), (0x02B3, 0x0072), (0x02B7, 0x0077),
+    (0x02B8, 0x0079), (0x02E1, 0x006C), (0x02E2, 0x0073), (0x02E3, 0x0078), (0x037E, 0x003B),
+    (0x0387, 0x00B7), (0x1D2C, 0x0041), (0x1D2D, 0x00C6), (0x1D2E, 0x0042), (0x1D30, 0x0044),
+    (0x1D31, 0x0045), (0x1D33, 0x0047), (0x1D34, 0x0048), (0x1D35, 0x0049), (0x1D36, 0x004A),
+    (0x1D37, 0x004B), (0x1D38, 0x004C), (0x1D39, 0x004D), (0x1D3A, 0x004E), (0x1D3C, 0x004F),
+    (0x1D3E, 0x0050), (0x1D3F, 0x0052), (0x1D40, 0x0054), (0x1D41, 0x0055), (0x1D42, 0x0057),
+    (0x1D43, 0x0061), (0x1D47, 0x0062), (0x1D48, 0x0064), (0x1D49, 0x0065), (0x1D4D, 0x0067),
+    (0x1D4F, 0x006B), (0x1D50, 0x006D), (0x1D52, 0x006F), (0x1D56, 0x0070), (0x1D57, 0x0074),
+    (0x1D58, 0x0075), (0x1D5B, 0x0076), (0x1D62, 0x0069), (0x1D63, 0x0072), (0x1D64, 0x0075),
+    (0x1D65, 0x0076), (0x1D9C, 0x0063), (0x1DA0, 0x0066), (0x1DBB, 0x007A), (0x1E00, 0x0041),
+    (0x1E01, 0x0061), (0x1E02, 0x0042), (0x1E03, 0x0062), (0x1E04, 0x0042), (0x1E05, 0x0062),
+    (0x1E06, 0x0042), (0x1E07, 0x0062), (0x1E08, 0x0043), (0x1E09, 0x0063), (0x1E0A, 0x0044),
+    (0x1E0B, 0x0064), (0x1E0C, 0x0044), (0x1E0D, 0x0064), (0x1E0E, 0x0044), (0x1E0F, 0x0064),
+    (0x1E10, 0x0044), (0x1E11, 0x0064), (0x1E12, 0x0044), (0x1E13, 0x0064), (0x1E14, 0x0045),
+    (0x1E15, 0x0065), (0x1E16, 0x0045), (0x1E17, 0x0065), (0x1E18, 0x0045), (0x1E19, 0x0065),
+    (0x1E1A, 0x0045), (0x1E1B, 0x0065), (0x1E1C, 0x0045), (0x1E1D, 0x0065), (0x1E1E, 0x0046),
+    (0x1E1F, 0x0066), (0x1E20, 0x0047), (0x1E21, 0x0067), (0x1E22, 0x0048), (0x1E23, 0x0068),
+    (0x1E24, 0x0048), (0x1E25, 0x0068), (0x1E26, 0x0048), (0x1E27, 0x0068), (0x1E28, 0x0048),
+    (0x1E29, 0x0068), (0x1E2A, 0x0048), (0x1E2B, 0x0068), (0x1E2C, 0x0049), (0x1E2D, 0x0069),
+    (0x1E2E, 0x0049), (0x1E2F, 0x0069), (0x1E30, 0x004B), (0x1E31, 0x006B), (0x1E32, 0x004B),
+    (0x1E33, 0x006B), (0x1E34, 0x004B), (0x1E35, 0x006B), (0x1E36, 0x004C), (0x1E37, 0x006C),
+    (0x1E38, 0x004C), (0x1E39, 0x006C), (0x1E3A, 0x004C), (0x1E3B, 0x006C), (0x1E3C, 0x004C),
+    (0x1E3D, 0x006C), (0x1E3E, 0x004D), (0x1E3F, 0x006D), (0x1E40, 0x004D), (0x1E41, 0x006D),
+    (0x1E42, 0x004D), (0x1E43, 0x006D), (0x1E44, 0x004E), (0x1E45, 0x006E), (0x1E46, 0x004E),
+    (0x1E47, 0x006E), (0x1E48, 0x004E), (0x1E49, 0x006E), (0x1E4A, 0x004E), (0x1E4B, 0x006E),
+    (0x1E4C, 0x004F), (0x1E4D, 0x006F), (0x1E4E, 0x004F), (0x1E4F, 0x006F), (0x1E50, 0x004F),
+    (0x1E51, 0x006F), (0x1E52, 0x004F), (0x1E53, 0x006F), (0x1E54, 0x0050), (0x1E55, 0x0070),
+    (0x1E56, 0x0050), (0x1E57, 0x0070), (0x1E58, 0x0052), (0x1E59, 0x0072), (0x1E5A, 0x0052),
+    (0x1E5B, 0x0072), (0x1E5C, 0x0052), (0x1E5D, 0x0072), (0x1E5E, 0x0052), (0x1E5F, 0x0072),
+    (0x1E60, 0x0053), (0x1E61, 0x0073), (0x1E62, 0x0053), (0x1E63, 0x0073), (0x1E64, 0x0053),
+    (0x1E65, 0x0073), (0x1E66, 0x0053), (0x1E67, 0x0073), (0x1E68, 0x0053), (0x1E69, 0x0073),
+    (0x1E6A, 0x0054), (0x1E6B, 0x0074), (0x1E6C, 0x0054), (0x1E6D, 0x0074), (0x1E6E, 0x0054),
+    (0x1E6F, 0x0074), (0x1E70, 0x0054), (0x1E71, 0x0074), (0x1E72, 0x0055), (0x1E73, 0x0075),
+    (0x1E74, 0x0055), (0x1E75, 0x0075), (0x1E76, 0x0055), (0x1E77, 0x0075), (0x1E78, 0x0055),
+    (0x1E79, 0x0075), (0x1E7A, 0x0055), (0x1E7B, 0x0075), (0x1E7C, 0x0056), (0x1E7D, 0x0076),
+    (0x1E7E, 0x0056), (0x1E7F, 0x0076), (0x1E80, 0x0057), (0x1E81, 0x0077), (0x1E82, 0x0057),
+    (0x1E83, 0x0077), (0x1E84, 0x0057), (0x1E85, 0x0077), (0x1E86, 0x0057), (0x1E87, 0x0077),
+    (0x1E88, 0x0057), (0x1E89, 0x0077), (0x1E8A, 0x0058), (0x1E8B, 0x0078), (0x1E8C, 0x0058),
+    (0x1E8D, 0x0078), (0x1E8E, 0x0059), (0x1E8F, 0x0079), (0x1E90, 0x005A), (0x1E91, 0x007A),
+    (0x1E92, 0x005A), (0x1E93, 0x007A), (0x1E94, 0x005A), (0x1E95, 0x007A), (0x1E96, 0x0068),
+    (0x1E97, 0x0074), (0x1E98, 0x0077), (0x1E99, 0x0079), (0x1E9B, 0x0073), (0x1EA0, 0x0041),
+    (0x1EA1, 0x0061), (0x1EA2, 0x0041), (0x1EA3, 0x0061), (0x1EA4, 0x0041), (0x1EA5, 0x0061),
+    (0x1EA6, 0x0041), (0x1EA7, 0x0061), (0x1EA8, 0x0041), (0x1EA9, 0x0061), (0x1EAA, 0x0041),
+    (0x1EAB, 0x0061), (0x1EAC, 0x0041), (0x1EAD, 0x0061), (0x1EAE, 0x0041), (0x1EAF, 0x0061),
+    (0x1EB0, 0x0041), (0x1EB1, 0x0061), (0x1EB2, 0x0041), (0x1EB3, 0x0061), (0x1EB4, 0x0041),
+    (0x1EB5, 0x0061), (0x1EB6, 0x0041), (0x1EB7, 0x0061), (0x1EB8, 0x0045), (0x1EB9, 0x0065),
+    (0x1EBA, 0x0045), (0x1EBB, 0x0065), (0x1EBC, 0x0045), (0x1EBD, 0x0065), (0x1EBE, 0x0045),
+    (0x1EBF, 0x0065), (0x1EC0, 0x0045), (0x1EC1, 0x0065), (0x1EC2, 0x0045), (0x1EC3, 0x0065),
+    (0x1EC4, 0x0045), (0x1EC5, 0x0065), (0x1EC6, 0x0045), (0x1EC7, 0x0065), (0x1EC8, 0x0049),
+    (0x1EC9, 0x0069), (0x1ECA, 0x0049), (0x1ECB, 0x0069), (0x1ECC, 0x004F), (0x1ECD, 0x006F),
+    (0x1ECE, 0x004F), (0x1ECF, 0x006F), (0x1ED0, 0x004F), (0x1ED1, 0x006F), (0x1ED2, 0x004F),
+    (0x1ED3, 0x006F), (0x1ED4, 0x004F), (0x1ED5, 0x006F), (0x1ED6, 0x004F), (0x1ED7, 0x006F),
+    (0x1ED8, 0x004F), (0x1ED9, 0x006F), (0x1EDA, 0x004F), (0x1EDB, 0x006F), (0x1EDC, 0x004F),
+    (0x1EDD, 0x006F), (0x1EDE, 0x004F), (0x1EDF, 0x006F), (0x1EE0, 0x004F), (0x1EE1, 0x006F),
+    (0x1EE2, 0x004F), (0x1EE3, 0x006F), (0x1EE4, 0x0055), (0x1EE5, 0x0075), (0x1EE6, 0x0055),
+    (0x1EE7, 0x0075), (0x1EE8, 0x0055), (0x1EE9, 0x0075), (0x1EEA, 0x0055), (0x1EEB, 0x0075),
+    (0x1EEC, 0x0055), (0x1EED, 0x0075), (0x1EEE, 0x0055), (0x1EEF, 0x0075), (0x1EF0, 0x0055),
+    (0x1EF1, 0x0075), (0x1EF2, 0x0059), (0x1EF3, 0x0079), (0x1EF4, 0x0059), (0x1EF5, 0x0079),
+    (0x1EF6, 0x0059), (0x1EF7, 0x0079), (0x1EF8, 0x0059), (0x1EF9, 0x0079), (0x1FEF, 0x0060),
+    (0x2000, 0x0020), (0x2001, 0x0020), (0x2002, 0x0020), (0x2003, 0x0020), (0x2004, 0x0020),
+    (0x2005, 0x0020), (0x2006, 0x0020), (0x2007, 0x0020), (0x2008, 0x0020), (0x2009, 0x0020),
+    (0x200A, 0x0020), (0x2010, 0x002D), (0x2012, 0x002D), (0x2015, 0x002D), (0x201B, 0x0027),
+    (0x201F, 0x0022), (0x2024, 0x002E), (0x202F, 0x0020), (0x2032, 0x0027), (0x2035, 0x0060),
+    (0x205F, 0x0020), (0x2070, 0x0030), (0x2071, 0x0069), (0x2074, 0x0034), (0x2075, 0x0035),
+    (0x2076, 0x0036), (0x2077, 0x0037), (0x2078, 0x0038), (0x2079, 0x0039), (0x207A, 0x002B),
+    (0x207C, 0x003D), (0x207D, 0x0028), (0x207E, 0x0029), (0x207F, 0x006E), (0x2080, 0x0030),
+    (0x2081, 0x0031), (0x2082, 0x0032), (0x2083, 0x0033), (0x2084, 0x0034), (0x2085, 0x0035),
+    (0x2086, 0x0036), (0x2087, 0x0037), (0x2088, 0x0038), (0x2089, 0x0039), (0x208A, 0x002B),
+    (0x208C, 0x003D), (0x208D, 0x0028), (0x208E, 0x0029), (0x2090, 0x0061), (0x2091, 0x0065),
+    (0x2092, 0x006F), (0x2093, 0x0078), (0x2095, 0x0068), (0x2096, 0x006B), (0x2097, 0x006C),
+    (0x2098, 0x006D), (0x2099, 0x006E), (0x209A, 0x0070), (0x209B, 0x0073), (0x209C, 0x0074),
+    (0x2102, 0x0043), (0x210A, 0x0067), (0x210B, 0x0048), (0x210C, 0x0048), (0x210D, 0x0048),
+    (0x210E, 0x0068), (0x2110, 0x0049), (0x2111, 0x0049), (0x2112, 0x004C), (0x2113, 0x006C),
+    (0x2115, 0x004E), (0x2119, 0x0050), (0x211A, 0x0051), (0x211B, 0x0052), (0x211C, 0x0052),
+    (0x211D, 0x0052), (0x2124, 0x005A), (0x2128, 0x005A), (0x212A, 0x004B), (0x212B, 0x0041),
+    (0x212C, 0x0042), (0x212D, 0x0043), (0x212F, 0x0065), (0x2130, 0x0045), (0x2131, 0x0046),
+    (0x2133, 0x004D), (0x2134, 0x006F), (0x2139, 0x0069), (0x2145, 0x0044), (0x2146, 0x0064),
+    (0x2147, 0x0065), (0x2148, 0x0069), (0x2149, 0x006A), (0x2160, 0x0049), (0x2164, 0x0056),
+    (0x2169, 0x0058), (0x216C, 0x004C), (0x216D, 0x0043), (0x216E, 0x0044), (0x216F, 0x004D),
+    (0x2170, 0x0069), (0x2174, 0x0076), (0x2179, 0x0078), (0x217C, 0x006C), (0x217D, 0x0063),
+    (0x217E, 0x0064), (0x217F, 0x006D), (0x2212, 0x002D), (0x2215, 0x002F), (0x2216, 0x005C),
+    (0x2223, 0x007C), (0x223C, 0x007E), (0x2260, 0x003D), (0x226E, 0x003C), (0x226F, 0x003E),
+    (0x2460, 0x0031), (0x2461, 0x0032), (0x2462, 0x0033), (0x2463, 0x0034), (0x2464, 0x0035),
+    (0x2465, 0x0036), (0x2466, 0x0037), (0x2467, 0x0038), (0x2468, 0x0039), (0x24B6, 0x0041),
+    (0x24B7, 0x0042), (0x24B8, 0x0043), (0x24B9, 0x0044), (0x24BA, 0x0045), (0x24BB, 0x0046),
+    (0x24BC, 0x0047), (0x24BD, 0x0048), (0x24BE, 0x0049), (0x24BF, 0x004A), (0x24C0, 0x004B),
+    (0x24C1, 0x004C), (0x24C2, 0x004D), (0x24C3, 0x004E), (0x24C4, 0x004F), (0x24C5, 0x0050),
+    (0x24C6, 0x0051), (0x24C7, 0x0052), (0x24C8, 0x0053), (0x24C9, 0x0054), (0x24CA, 0x0055),
+    (0x24CB, 0x0056), (0x24CC, 0x0057), (0x24CD, 0x0058), (0x24CE, 0x0059), (0x24CF, 0x005A),
+    (0x24D0, 0x0061), (0x24D1, 0x0062), (0x24D2, 0x0063), (0x24D3, 0x0064), (0x24D4, 0x0065),
+    (0x24D5, 0x0066), (0x24D6, 0x0067), (0x24D7, 0x0068), (0x24D8, 0x0069), (0x24D9, 0x006A),
+    (0x24DA, 0x006B), (0x24DB, 0x006C), (0x24DC, 0x006D), (0x24DD, 0x006E), (0x24DE, 0x006F),
+    (0x24DF, 0x0070), (0x24E0, 0x0071), (0x24E1, 0x0072), (0x24E2, 0x0073), (0x24E3, 0x0074),
+    (0x24E4, 0x0075), (0x24E5, 0x0076), (0x24E6, 0x0077), (0x24E7, 0x0078), (0x24E8, 0x0079),
+    (0x24E9, 0x007A), (0x24EA, 0x0030), (0x2C7C, 0x006A), (0x2C7D, 0x0056), (0x3000, 0x0020),
+    (0xA7F2, 0x0043), (0xA7F3, 0x0046), (0xA7F4, 0x0051), (0xA7F9, 0x009C), (0xFB29, 0x002B),
+    (0xFE10, 0x002C), (0xFE13, 0x003A), (0xFE14, 0x003B), (0xFE15, 0x0021), (0xFE16, 0x003F),
+    (0xFE31, 0x0097), (0xFE32, 0x0096), (0xFE33, 0x005F), (0xFE34, 0x005F), (0xFE35, 0x0028),
+    (0xFE36, 0x0029), (0xFE37, 0x007B), (0xFE38, 0x007D), (0xFE47, 0x005B), (0xFE48, 0x005D),
+    (0xFE4D, 0x005F), (0xFE4E, 0x005F), (0xFE4F, 0x005F), (0xFE50, 0x002C), (0xFE52, 0x002E),
+    (0xFE54, 0x003B), (0xFE55, 0x003A), (0xFE56, 0x003F), (0xFE57, 0x0021), (0xFE58, 0x0097),
+    (0xFE59, 0x0028), (0xFE5A, 0x0029), (0xFE5B, 0x007B), (0xFE5C, 0x007D), (0xFE5F, 0x0023),
+    (0xFE60, 0x0026), (0xFE61, 0x002A), (0xFE62, 0x002B), (0xFE63, 0x002D), (0xFE64, 0x003C),
+    (0xFE65, 0x003E), (0xFE66, 0x003D), (0xFE68, 0x005C), (0xFE69, 0x0024), (0xFE6A, 0x0025),
+    (0xFE6B, 0x0040), (0xFF01, 0x0021), (0xFF02, 0x0022), (0xFF03, 0x0023), (0xFF04, 0x0024),
+    (0xFF05, 0x0025), (0xFF06, 0x0026), (0xFF07, 0x0027), (0xFF08, 0x0028), (0xFF09, 0x0029),
+    (0xFF0A, 0x002A), (0xFF0B, 0x002B), (0xFF0C, 0x002C), (0xFF0D, 0x002D), (0xFF0E, 0x002E),
+    (0xFF0F, 0x002F), (0xFF10, 0x0030), (0xFF11, 0x0031), (0xFF12, 0x0032), (0xFF13, 0x0033),
+    (0xFF14, 0x0034), (0xFF15, 0x0035), (0xFF16, 0x0036), (0xFF17, 0x0037), (0xFF18, 0x0038),
+    (0xFF19, 0x0039), (0xFF1A, 0x003A), (0xFF1B, 0x003B), (0xFF1C, 0x003C), (0xFF1D, 0x003D),
+    (0xFF1E, 0x003E), (0xFF1F, 0x003F), (0xFF20, 0x0040), (0xFF21, 0x0041), (0xFF22, 0x0042),
+    (0xFF23, 0x0043), (0xFF24, 0x0044), (0xFF25, 0x0045), (0xFF26, 0x0046), (0xFF27, 0x0047),
+    (0xFF28, 0x0048), (0xFF29, 0x0049), (0xFF2A, 0x004A), (0xFF2B, 0x004B), (0xFF2C, 0x004C),
+    (0xFF2D, 0x004D), (0xFF2E, 0x004E), (0xFF2F, 0x004F), (0xFF30, 0x0050), (0xFF31, 0x0051),
+    (0xFF32, 0x0052), (0xFF33, 0x0053), (0xFF34, 0x0054), (0xFF35, 0x0055), (0xFF36, 0x0056),
+    (0xFF37, 0x0057), (0xFF38, 0x0058), (0xFF39, 0x0059), (0xFF3A, 0x005A), (0xFF3B, 0x005B),
+    (0xFF3C, 0x005C), (0xFF3D, 0x005D), (0xFF3E, 0x005E), (0xFF3F, 0x005F), (0xFF40, 0x0060),
+    (0xFF41, 0x0061), (0xFF42, 0x0062), (0xFF43, 0x0063), (0xFF44, 0x0064), (0xFF45, 0x0065),
+    (0xFF46, 0x0066), (0xFF47, 0x0067), (0xFF48, 0x0068), (0xFF49, 0x0069), (0xFF4A, 0x006A),
+    (0xFF4B, 0x006B), (0xFF4C, 0x006C), (0xFF4D, 0x006D), (0xFF4E, 0x006E), (0xFF4F, 0x006F),
+    (0xFF50, 0x0070), (0xFF51, 0x0071), (0xFF52, 0x0072), (0xFF53, 0x0073), (0xFF54, 0x0074),
+    (0xFF55, 0x0075), (0xFF56, 0x0076), (0xFF57, 0x0077), (0xFF58, 0x0078), (0xFF59, 0x0079),
+    (0xFF5A, 0x007A), (0xFF5B, 0x007B), (0xFF5C, 0x007C), (0xFF5D, 0x007D), (0xFF5E, 0x007E),
+    (0xFFE0, 0x00A2), (0xFFE1, 0x00A3), (0xFFE2, 0x00AC), (0xFFE4, 0x00A6), (0xFFE5, 0x00A5),
+];
+
+/// Code page 437.
+#[rustfmt::skip]
+pub(crate) const CP437: [(u16, u16); 915] = [
+    (0x00B3, 0x0033), (0x00B9, 0x0031), (0x00C0, 0x0041), (0x00C1, 0x0041), (0x00C2, 0x0041),
+    (0x00C3, 0x0041), (0x00C8, 0x0045), (0x00CA, 0x0045), (0x00CB, 0x0045), (0x00CC, 0x0049),
+    (0x00CD, 0x0049), (0x00CE, 0x0049), (0x00CF, 0x0049), (0x00D2, 0x004F), (0x00D3, 0x004F),
+    (0x00D4, 0x004F), (0x00D5, 0x004F), (0x00D9, 0x0055), (0x00DA, 0x0055), (0x00DB, 0x0055),
+    (0x00DD, 0x0059), (0x00E3, 0x0061), (0x00F5, 0x006F), (0x00FD, 0x0079), (0x0100, 0x0041),
+    (0x0101, 0x0061), (0x0102, 0x0041), (0x0103, 0x0061), (0x0104, 0x0041), (0x0105, 0x0061),
+    (0x0106, 0x0043), (0x0107, 0x0063), (0x0108, 0x0043), (0x0109, 0x0063), (0x010A, 0x0043),
+    (0x010B, 0x0063), (0x010C, 0x0043), (0x010D, 0x0063), (0x010E, 0x0044), (0x010F, 0x0064),
+    (0x0112, 0x0045), (0x0113, 0x0065), (0x0114, 0x0045), (0x0115, 0x0065), (0x0116, 0x0045),
+    (0x0117, 0x0065), (0x0118, 0x0045), (0x0119, 0x0065), (0x011A, 0x0045), (0x011B, 0x0065),
+    (0x011C, 0x0047), (0x011D, 0x0067), (0x011E, 0x0047), (0x011F, 0x0067), (0x0120, 0x0047),
+    (0x0121, 0x0067), (0x0122, 0x0047), (0x0123, 0x0067), (0x0124, 0x0048), (0x0125, 0x0068),
+    (0x0128, 0x0049), (0x0129, 0x0069), (0x012A, 0x0049), (0x012B, 0x0069), (0x012C, 0x0049),
+    (0x012D, 0x0069), (0x012E, 0x0049), (0x012F, 0x0069), (0x0130, 0x0049), (0x0134, 0x004A),
+    (0x0135, 0x006A), (0x0136, 0x004B), (0x0137, 0x006B), (0x0139, 0x004C), (0x013A, 0x006C),
+    (0x013B, 0x004C), (0x013C, 0x006C), (0x013D, 0x004C), (0x013E, 0x006C), (0x0143, 0x004E),
+    (0x0144, 0x006E), (0x0145, 0x004E), (0x0146, 0x006E), (0x0147, 0x004E), (0x0148, 0x006E),
+    (0x014C, 0x004F), (0x014D, 0x006F), (0x014E, 0x004F), (0x014F, 0x006F), (0x0150, 0x004F),
+    (0x0151, 0x006F), (0x0154, 0x0052), (0x0155, 0x0072), (0x0156, 0x0052), (0x0157, 0x0072),
+    (0x0158, 0x0052), (0x0159, 0x0072), (0x015A, 0x0053), (0x015B, 0x0073), (0x015C, 0x0053),
+    (0x015D, 0x0073), (0x015E, 0x0053), (0x015F, 0x0073), (0x0160, 0x0053), (0x0161, 0x0073),
+    (0x0162, 0x0054), (0x0163, 0x0074), (0x0164, 0x0054), (0x0165, 0x0074), (0x0168, 0x0055),
+    (0x0169, 0x0075), (0x016A, 0x0055), (0x016B, 0x0075), (0x016C, 0x0055), (0x016D, 0x0075),
+    (0x016E, 0x0055), (0x016F, 0x0075), (0x0170, 0x0055), (0x0171, 0x0075), (0x0172, 0x0055),
+    (0x0173, 0x0075), (0x0174, 0x0057), (0x0175, 0x0077), (0x0176, 0x0059), (0x0177, 0x0079),
+    (0x0178, 0x0059), (0x0179, 0x005A), (0x017A, 0x007A), (0x017B, 0x005A), (0x017C, 0x007A),
+    (0x017D, 0x005A), (0x017E, 0x007A), (0x017F, 0x0073), (0x01A0, 0x004F), (0x01A1, 0x006F),
+    (0x01AF, 0x0055), (0x01B0, 0x0075), (0x01CD, 0x0041), (0x01CE, 0x0061), (0x01CF, 0x0049),
+    (0x01D0, 0x0069), (0x01D1, 0x004F), (0x01D2, 0x006F), (0x01D3, 0x0055), (0x01D4, 0x0075),
+    (0x01D5, 0x0055), (0x01D6, 0x0075), (0x01D7, 0x0055), (0x01D8, 0x0075), (0x01D9, 0x0055),
+    (0x01DA, 0x0075), (0x01DB, 0x0055), (0x01DC, 0x0075), (0x01DE, 0x0041), (0x01DF, 0x0061),
+    (0x01E0, 0x0041), (0x01E1, 0x0061), (0x01E2, 0x0092), (0x01E3, 0x0091), (0x01E6, 0x0047),
+    (0x01E7, 0x0067), (0x01E8, 0x004B), (0x01E9, 0x006B), (0x01EA, 0x004F), (0x01EB, 0x006F),
+    (0x01EC, 0x004F), (0x01ED, 0x006F), (0x01F0, 0x006A), (0x01F4, 0x0047), (0x01F5, 0x0067),
+    (0x01F8, 0x004E), (0x01F9, 0x006E), (0x01FA, 0x0041), (0x01FB, 0x0061), (0x01FC, 0x0092),
+    (0x01FD, 0x0091), (0x0200, 0x0041), (0x0201, 0x0061), (0x0202, 0x0041), (0x0203, 0x0061),
+    (0x0204, 0x0045), (0x0205, 0x0065), (0x0206, 0x0045), (0x0207, 0x0065), (0x0208, 0x0049),
+    (0x0209, 0x0069), (0x020A, 0x0049), (0x020B, 0x0069), (0x020C, 0x004F), (0x020D, 0x006F),
+    (0x020E, 0x004F), (0x020F, 0x006F), (0x0210, 0x0052), (0x0211, 0x0072), (0x0212, 0x0052),
+    (0x0213, 0x0072), (0x0214, 0x0055), (0x0215, 0x0075), (0x0216, 0x0055), (0x0217, 0x0075),
+    (0x0218, 0x0053), (0x0219, 0x0073), (0x021A, 0x0054), (0x021B, 0x0074), (0x021E, 0x0048),
+    (0x021F, 0x0068), (0x0226, 0x0041), (0x0227, 0x0061), (0x0228, 0x0045), (0x0229, 0x0065),
+    (0x022A, 0x004F), (0x022B, 0x006F), (0x022C, 0x004F), (0x022D, 0x006F), (0x022E, 0x004F),
+    (0x022F, 0x006F), (0x0230, 0x004F), (0x0231, 0x006F), (0x0232, 0x0059), (0x0233, 0x0079),
+    (0x02B0, 0x0068), (0x02B2, 0x006A), (0x02B3, 0x0072), (0x02B7, 0x0077), (0x02B8, 0x0079),
+    (0x02E1, 0x006C), (0x02E2, 0x0073), (0x02E3, 0x0078), (0x037E, 0x003B), (0x0387, 0x00FA),
+    (0x038F, 0x00EA), (0x03AC, 0x00E0), (0x03AD, 0x00EE), (0x03D5, 0x00ED), (0x03D6, 0x00E3),
+    (0x03F4, 0x00E9), (0x03F5, 0x00EE), (0x03F9, 0x00E4), (0x1D2C, 0x0041), (0x1D2D, 0x0092),
+    (0x1D2E, 0x0042), (0x1D30, 0x0044), (0x1D31, 0x0045), (0x1D33, 0x0047), (0x1D34, 0x0048),
+    (0x1D35, 0x0049), (0x1D36, 0x004A), (0x1D37, 0x004B), (0x1D38, 0x004C), (0x1D39, 0x004D),
+    (0x1D3A, 0x004E), (0x1D3C, 0x004F), (0x1D3E, 0x0050), (0x1D3F, 0x0052), (0x1D40, 0x0054),
+    (0x1D41, 0x0055), (0x1D42, 0x0057), (0x1D43, 0x0061), (0x1D47, 0x0062), (0x1D48, 0x0064),
+    (0x1D49, 0x0065), (0x1D4D, 0x0067), (0x1D4F, 0x006B), (0x1D50, 0x006D), (0x1D52, 0x006F),
+    (0x1D56, 0x0070), (0x1D57, 0x0074), (0x1D58, 0x0075), (0x1D5B, 0x0076), (0x1D5F, 0x00EB),
+    (0x1D60, 0x00ED), (0x1D62, 0x0069), (0x1D63, 0x0072), (0x1D64, 0x0075), (0x1D65, 0x0076),
+    (0x1D69, 0x00ED), (0x1D9C, 0x0063), (0x1DA0, 0x0066), (0x1DBB, 0x007A), (0x1E00, 0x0041),
+    (0x1E01, 0x0061), (0x1E02, 0x0042), (0x1E03, 0x0062), (0x1E04, 0x0042), (0x1E05, 0x0062),
+    (0x1E06, 0x0042), (0x1E07, 0x0062), (0x1E08, 0x0043), (0x1E09, 0x0063), (0x1E0A, 0x0044),
+    (0x1E0B, 0x0064), (0x1E0C, 0x0044), (0x1E0D, 0x0064), (0x1E0E, 0x0044), (0x1E0F, 0x0064),
+    (0x1E10, 0x0044), (0x1E11, 0x0064), (0x1E12, 0x0044), (0x1E13, 0x0064), (0x1E14, 0x0045),
+    (0x1E15, 0x0065), (0x1E16, 0x0045), (0x1E17, 0x0065), (0x1E18, 0x0045), (0x1E19, 0x0065),
+    (0x1E1A, 0x0045), (0x1E1B, 0x0065), (0x1E1C, 0x0045), (0x1E1D, 0x0065), (0x1E1E, 0x0046),
+    (0x1E1F, 0x0066), (0x1E20, 0x0047), (0x1E21, 0x0067), (0x1E22, 0x0048), (0x1E23, 0x0068),
+    (0x1E24, 0x0048), (0x1E25, 0x0068), (0x1E26, 0x0048), (0x1E27, 0x0068), (0x1E28, 0x0048),
+    (0x1E29, 0x0068), (0x1E2A, 0x0048), (0x1E2B, 0x0068), (0x1E2C, 0x0049), (0x1E2D, 0x0069),
+    (0x1E2E, 0x0049), (0x1E2F, 0x0069), (0x1E30, 0x004B), (0x1E31, 0x006B), (0x1E32, 0x004B),
+    (0x1E33, 0x006B), (0x1E34, 0x004B), (0x1E35, 0x006B), (0x1E36, 0x004C), (0x1E37, 0x006C),
+    (0x1E38, 0x004C), (0x1E39, 0x006C), (0x1E3A, 0x004C), (0x1E3B, 0x006C), (0x1E3C, 0x004C),
+    (0x1E3D, 0x006C), (0x1E3E, 0x004D), (0x1E3F, 0x006D), (0x1E40, 0x004D), (0x1E41, 0x006D),
+    (0x1E42, 0x004D), (0x1E43, 0x006D), (0x1E44, 0x004E), (0x1E45, 0x006E), (0x1E46, 0x004E),
+    (0x1E47, 0x006E), (0x1E48, 0x004E), (0x1E49, 0x006E), (0x1E4A, 0x004E), (0x1E4B, 0x006E),
+    (0x1E4C, 0x004F), (0x1E4D, 0x006F), (0x1E4E, 0x004F), (0x1E4F, 0x006F), (0x1E50, 0x004F),
+    (0x1E51, 0x006F), (0x1E52, 0x004F), (0x1E53, 0x006F), (0x1E54, 0x0050), (0x1E55, 0x0070),
+    (0x1E56, 0x0050), (0x1E57, 0x0070), (0x1E58, 0x0052), (0x1E59, 0x0072), (0x1E5A, 0x0052),
+    (0x1E5B, 0x0072), (0x1E5C, 0x0052), (0x1E5D, 0x0072), (0x1E5E, 0x0052), (0x1E5F, 0x0072),
+    (0x1E60, 0x0053), (0x1E61, 0x0073), (0x1E62, 0x0053), (0x1E63, 0x0073), (0x1E64, 0x0053),
+    (0x1E65, 0x0073), (0x1E66, 0x0053), (0x1E67, 0x0073), (0x1E68, 0x0053), (0x1E69, 0x0073),
+    (0x1E6A, 0x0054), (0x1E6B, 0x0074), (0x1E6C, 0x0054), (0x1E6D, 0x0074), (0x1E6E, 0x0054),
+    (0x1E6F, 0x0074), (0x1E70, 0x0054), (0x1E71, 0x0074), (0x1E72, 0x0055), (0x1E73, 0x0075),
+    (0x1E74, 0x0055), (0x1E75, 0x0075), (0x1E76, 0x0055), (0x1E77, 0x0075), (0x1E78, 0x0055),
+    (0x1E79, 0x0075), (0x1E7A, 0x0055), (0x1E7B, 0x0075), (0x1E7C, 0x0056), (0x1E7D, 0x0076),
+    (0x1E7E, 0x0056), (0x1E7F, 0x0076), (0x1E80, 0x0057), (0x1E81, 0x0077), (0x1E82, 0x0057),
+    (0x1E83, 0x0077), (0x1E84, 0x0057), (0x1E85, 0x0077), (0x1E86, 0x0057), (0x1E87, 0x0077),
+    (0x1E88, 0x0057), (0x1E89, 0x0077), (0x1E8A, 0x0058), (0x1E8B, 0x0078), (0x1E8C, 0x0058),
+    (0x1E8D, 0x0078), (0x1E8E, 0x0059), (0x1E8F, 0x0079), (0x1E90, 0x005A), (0x1E91, 0x007A),
+    (0x1E92, 0x005A), (0x1E93, 0x007A), (0x1E94, 0x005A), (0x1E95, 0x007A), (0x1E96, 0x0068),
+    (0x1E97, 0x0074), (0x1E98, 0x0077), (0x1E99, 0x0079), (0x1E9B, 0x0073), (0x1EA0, 0x0041),
+    (0x1EA1, 0x0061), (0x1EA2, 0x0041), (0x1EA3, 0x0061), (0x1EA4, 0x0041), (0x1EA5, 0x0061),
+    (0x1EA6, 0x0041), (0x1EA7, 0x0061), (0x1EA8, 0x0041), (0x1EA9, 0x0061), (0x1EAA, 0x0041),
+    (0x1EAB, 0x0061), (0x1EAC, 0x0041), (0x1EAD, 0x0061), (0x1EAE, 0x0041), (0x1EAF, 0x0061),
+    (0x1EB0, 0x0041), (0x1EB1, 0x0061), (0x1EB2, 0x0041), (0x1EB3, 0x0061), (0x1EB4, 0x0041),
+    (0x1EB5, 0x0061), (0x1EB6, 0x0041), (0x1EB7, 0x0061), (0x1EB8, 0x0045), (0x1EB9, 0x0065),
+    (0x1EBA, 0x0045), (0x1EBB, 0x0065), (0x1EBC, 0x0045), (0x1EBD, 0x0065), (0x1EBE, 0x0045),
+    (0x1EBF, 0x0065), (0x1EC0, 0x0045), (0x1EC1, 0x0065), (0x1EC2, 0x0045), (0x1EC3, 0x0065),
+    (0x1EC4, 0x0045), (0x1EC5, 0x0065), (0x1EC6, 0x0045), (0x1EC7, 0x0065), (0x1EC8, 0x0049),
+    (0x1EC9, 0x0069), (0x1ECA, 0x0049), (0x1ECB, 0x0069), (0x1ECC, 0x004F), (0x1ECD, 0x006F),
+    (0x1ECE, 0x004F), (0x1ECF, 0x006F), (0x1ED0, 0x004F), (0x1ED1, 0x006F), (0x1ED2, 0x004F),
+    (0x1ED3, 0x006F), (0x1ED4, 0x004F), (0x1ED5, 0x006F), (0x1ED6, 0x004F), (0x1ED7, 0x006F),
+    (0x1ED8, 0x004F), (0x1ED9, 0x006F), (0x1EDA, 0x004F), (0x1EDB, 0x006F), (0x1EDC, 0x004F),
+    (0x1EDD, 0x006F), (0x1EDE, 0x004F), (0x1EDF, 0x006F), (0x1EE0, 0x004F), (0x1EE1, 0x006F),
+    (0x1EE2, 0x004F), (0x1EE3, 0x006F), (0x1EE4, 0x0055), (0x1EE5, 0x0075), (0x1EE6, 0x0055),
+    (0x1EE7, 0x0075), (0x1EE8, 0x0055), (0x1EE9, 0x0075), (0x1EEA, 0x0055), (0x1EEB, 0x0075),
+    (0x1EEC, 0x0055), (0x1EED, 0x0075), (0x1EEE, 0x0055), (0x1EEF, 0x0075), (0x1EF0, 0x0055),
+    (0x1EF1, 0x0075), (0x1EF2, 0x0059), (0x1EF3, 0x0079), (0x1EF4, 0x0059), (0x1EF5, 0x0079),
+    (0x1EF6, 0x0059), (0x1EF7, 0x0079), (0x1EF8, 0x0059), (0x1EF9, 0x0079), (0x1F00, 0x00E0),
+    (0x1F01, 0x00E0), (0x1F02, 0x00E0), (0x1F03, 0x00E0), (0x1F04, 0x00E0), (0x1F05, 0x00E0),
+    (0x1F06, 0x00E0), (0x1F07, 0x00E0), (0x1F10, 0x00EE), (0x1F11, 0x00EE), (0x1F12, 0x00EE),
+    (0x1F13, 0x00EE), (0x1F14, 0x00EE), (0x1F15, 0x00EE), (0x1F68, 0x00EA), (0x1F69, 0x00EA),
+    (0x1F6A, 0x00EA), (0x1F6B, 0x00EA), (0x1F6C, 0x00EA), (0x1F6D, 0x00EA), (0x1F6E, 0x00EA),
+    (0x1F6F, 0x00EA), (0x1F70, 0x00E0), (0x1F71, 0x00E0), (0x1F72, 0x00EE), (0x1F73, 0x00EE),
+    (0x1F80, 0x00E0), (0x1F81, 0x00E0), (0x1F82, 0x00E0), (0x1F83, 0x00E0), (0x1F84, 0x00E0),
+    (0x1F85, 0x00E0), (0x1F86, 0x00E0), (0x1F87, 0x00E0), (0x1FA8, 0x00EA), (0x1FA9, 0x00EA),
+    (0x1FAA, 0x00EA), (0x1FAB, 0x00EA), (0x1FAC, 0x00EA), (0x1FAD, 0x00EA), (0x1FAE, 0x00EA),
+    (0x1FAF, 0x00EA), (0x1FB0, 0x00E0), (0x1FB1, 0x00E0), (0x1FB2, 0x00E0), (0x1FB3, 0x00E0),
+    (0x1FB4, 0x00E0), (0x1FB6, 0x00E0), (0x1FB7, 0x00E0), (0x1FEF, 0x0060), (0x1FFA, 0x00EA),
+    (0x1FFB, 0x00EA), (0x1FFC, 0x00EA), (0x2000, 0x0020), (0x2001, 0x0020), (0x2002, 0x0020),
+    (0x2003, 0x0020), (0x2004, 0x0020), (0x2005, 0x0020), (0x2006, 0x0020), (0x2007, 0x0020),
+    (0x2008, 0x0020), (0x2009, 0x0020), (0x200A, 0x0020), (0x2010, 0x002D), (0x2012, 0x002D),
+    (0x2013, 0x002D), (0x2014, 0x002D), (0x2015, 0x002D), (0x2018, 0x0027), (0x2019, 0x0027),
+    (0x201A, 0x002C), (0x201B, 0x0027), (0x201C, 0x0022), (0x201D, 0x0022), (0x201E, 0x0022),
+    (0x201F, 0x0022), (0x2024, 0x002E), (0x202F, 0x0020), (0x2032, 0x0027), (0x2035, 0x0060),
+    (0x2039, 0x003C), (0x203A, 0x003E), (0x205F, 0x0020), (0x2070, 0x0030), (0x2071, 0x0069),
+    (0x2074, 0x0034), (0x2075, 0x0035), (0x2076, 0x0036), (0x2077, 0x0037), (0x2078, 0x0038),
+    (0x2079, 0x0039), (0x207A, 0x002B), (0x207C, 0x003D), (0x207D, 0x0028), (0x207E, 0x0029),
+    (0x2080, 0x0030), (0x2081, 0x0031), (0x2082, 0x0032), (0x2083, 0x0033), (0x2084, 0x0034),
+    (0x2085, 0x0035), (0x2086, 0x0036), (0x2087, 0x0037), (0x2088, 0x0038), (0x2089, 0x0039),
+    (0x208A, 0x002B), (0x208C, 0x003D), (0x208D, 0x0028), (0x208E, 0x0029), (0x2090, 0x0061),
+    (0x2091, 0x0065), (0x2092, 0x006F), (0x2093, 0x0078), (0x2095, 0x0068), (0x2096, 0x006B),
+    (0x2097, 0x006C), (0x2098, 0x006D), (0x2099, 0x006E), (0x209A, 0x0070), (0x209B, 0x0073),
+    (0x209C, 0x0074), (0x2102, 0x0043), (0x210A, 0x0067), (0x210B, 0x0048), (0x210C, 0x0048),
+    (0x210D, 0x0048), (0x210E, 0x0068), (0x2110, 0x0049), (0x2111, 0x0049), (0x2112, 0x004C),
+    (0x2113, 0x006C), (0x2115, 0x004E), (0x2119, 0x0050), (0x211A, 0x0051), (0x211B, 0x0052),
+    (0x211C, 0x0052), (0x211D, 0x0052), (0x2124, 0x005A), (0x2126, 0x00EA), (0x2128, 0x005A),
+    (0x212A, 0x004B), (0x212B, 0x0041), (0x212C, 0x0042), (0x212D, 0x0043), (0x212F, 0x0065),
+    (0x2130, 0x0045), (0x2131, 0x0046), (0x2133, 0x004D), (0x2134, 0x006F), (0x2139, 0x0069),
+    (0x213C, 0x00E3), (0x213E, 0x00E2), (0x2145, 0x0044), (0x2146, 0x0064), (0x2147, 0x0065),
+    (0x2148, 0x0069), (0x2149, 0x006A), (0x2160, 0x0049), (0x2164, 0x0056), (0x2169, 0x0058),
+    (0x216C, 0x004C), (0x216D, 0x0043), (0x216E, 0x0044), (0x216F, 0x004D), (0x2170, 0x0069),
+    (0x2174, 0x0076), (0x2179, 0x0078), (0x217C, 0x006C), (0x217D, 0x0063), (0x217E, 0x0064),
+    (0x217F, 0x006D), (0x2212, 0x002D), (0x2215, 0x002F), (0x2216, 0x005C), (0x2223, 0x007C),
+    (0x223C, 0x007E), (0x2249, 0x00F7), (0x2260, 0x003D), (0x2262, 0x00F0), (0x226E, 0x003C),
+    (0x226F, 0x003E), (0x2270, 0x00F3), (0x2271, 0x00F2), (0x2460, 0x0031), (0x2461, 0x0032),
+    (0x2462, 0x0033), (0x2463, 0x0034), (0x2464, 0x0035), (0x2465, 0x0036), (0x2466, 0x0037),
+    (0x2467, 0x0038), (0x2468, 0x0039), (0x24B6, 0x0041), (0x24B7, 0x0042), (0x24B8, 0x0043),
+    (0x24B9, 0x0044), (0x24BA, 0x0045), (0x24BB, 0x0046), (0x24BC, 0x0047), (0x24BD, 0x0048),
+    (0x24BE, 0x0049), (0x24BF, 0x004A), (0x24C0, 0x004B), (0x24C1, 0x004C), (0x24C2, 0x004D),
+    (0x24C3, 0x004E), (0x24C4, 0x004F), (0x24C5, 0x0050), (0x24C6, 0x0051), (0x24C7, 0x0052),
+    (0x24C8, 0x0053), (0x24C9, 0x0054), (0x24CA, 0x0055), (0x24CB, 0x0056), (0x24CC, 0x0057),
+    (0x24CD, 0x0058), (0x24CE, 0x0059), (0x24CF, 0x005A), (0x24D0, 0x0061), (0x24D1, 0x0062),
+    (0x24D2, 0x0063), (0x24D3, 0x0064), (0x24D4, 0x0065), (0x24D5, 0x0066), (0x24D6, 0x0067),
+    (0x24D7, 0x0068), (0x24D8, 0x0069), (0x24D9, 0x006A), (0x24DA, 0x006B), (0x24DB, 0x006C),
+    (0x24DC, 0x006D), (0x24DD, 0x006E), (0x24DE, 0x006F), (0x24DF, 0x0070), (0x24E0, 0x0071),
+    (0x24E1, 0x0072), (0x24E2, 0x0073), (0x24E3, 0x0074), (0x24E4, 0x0075), (0x24E5, 0x0076),
+    (0x24E6, 0x0077), (0x24E7, 0x0078), (0x24E8, 0x0079), (0x24E9, 0x007A), (0x24EA, 0x0030),
+    (0x2C7C, 0x006A), (0x2C7D, 0x0056), (0x3000, 0x0020), (0xA7F2, 0x0043), (0xA7F3, 0x0046),
+    (0xA7F4, 0x0051), (0xFB29, 0x002B), (0xFE10, 0x002C), (0xFE13, 0x003A), (0xFE14, 0x003B),
+    (0xFE15, 0x0021), (0xFE16, 0x003F), (0xFE33, 0x005F), (0xFE34, 0x005F), (0xFE35, 0x0028),
+    (0xFE36, 0x0029), (0xFE37, 0x007B), (0xFE38, 0x007D), (0xFE47, 0x005B), (0xFE48, 0x005D),
+    (0xFE4D, 0x005F), (0xFE4E, 0x005F), (0xFE4F, 0x005F), (0xFE50, 0x002C), (0xFE52, 0x002E),
+    (0xFE54, 0x003B), (0xFE55, 0x003A), (0xFE56, 0x003F), (0xFE57, 0x0021), (0xFE59, 0x0028),
+    (0xFE5A, 0x0029), (0xFE5B, 0x007B), (0xFE5C, 0x007D), (0xFE5F, 0x0023), (0xFE60, 0x0026),
+    (0xFE61, 0x002A), (0xFE62, 0x002B), (0xFE63, 0x002D), (0xFE64, 0x003C), (0xFE65, 0x003E),
+    (0xFE66, 0x003D), (0xFE68, 0x005C), (0xFE69, 0x0024), (0xFE6A, 0x0025), (0xFE6B, 0x0040),
+    (0xFF01, 0x0021), (0xFF02, 0x0022), (0xFF03, 0x0023), (0xFF04, 0x0024), (0xFF05, 0x0025),
+    (0xFF06, 0x0026), (0xFF07, 0x0027), (0xFF08, 0x0028), (0xFF09, 0x0029), (0xFF0A, 0x002A),
+    (0xFF0B, 0x002B), (0xFF0C, 0x002C), (0xFF0D, 0x002D), (0xFF0E, 0x002E), (0xFF0F, 0x002F),
+    (0xFF10, 0x0030), (0xFF11, 0x0031), (0xFF12, 0x0032), (0xFF13, 0x0033), (0xFF14, 0x0034),
+    (0xFF15, 0x0035), (0xFF16, 0x0036), (0xFF17, 0x0037), (0xFF18, 0x0038), (0xFF19, 0x0039),
+    (0xFF1A, 0x003A), (0xFF1B, 0x003B), (0xFF1C, 0x003C), (0xFF1D, 0x003D), (0xFF1E, 0x003E),
+    (0xFF1F, 0x003F), (0xFF20, 0x0040), (0xFF21, 0x0041), (0xFF22, 0x0042), (0xFF23, 0x0043),
+    (0xFF24, 0x0044), (0xFF25, 0x0045), (0xFF26, 0x0046), (0xFF27, 0x0047), (0xFF28, 0x0048),
+    (0xFF29, 0x0049), (0xFF2A, 0x004A), (0xFF2B, 0x004B), (0xFF2C, 0x004C), (0xFF2D, 0x004D),
+    (0xFF2E, 0x004E), (0xFF2F, 0x004F), (0xFF30, 0x0050), (0xFF31, 0x0051), (0xFF32, 0x0052),
+    (0xFF33, 0x0053), (0xFF34, 0x0054), (0xFF35, 0x0055), (0xFF36, 0x0056), (0xFF37, 0x0057),
+    (0xFF38, 0x0058), (0xFF39, 0x0059), (0xFF3A, 0x005A), (0xFF3B, 0x005B), (0xFF3C, 0x005C),
+    (0xFF3D, 0x005D), (0xFF3E, 0x005E), (0xFF3F, 0x005F), (0xFF40, 0x0060), (0xFF41, 0x0061),
+    (0xFF42, 0x0062), (0xFF43, 0x0063), (0xFF44, 0x0064), (0xFF45, 0x0065), (0xFF46, 0x0066),
+    (0xFF47, 0x0067), (0xFF48, 0x0068), (0xFF49, 0x0069), (0xFF4A, 0x006A), (0xFF4B, 0x006B),
+    (0xFF4C, 0x006C), (0xFF4D, 0x006D), (0xFF4E, 0x006E), (0xFF4F, 0x006F), (0xFF50, 0x0070),
+    (0xFF51, 0x0071), (0xFF52, 0x0072), (0xFF53, 0x0073), (0xFF54, 0x0074), (0xFF55, 0x0075),
+    (0xFF56, 0x0076), (0xFF57, 0x0077), (0xFF58, 0x0078), (0xFF59, 0x0079), (0xFF5A, 0x007A),
+    (0xFF5B, 0x007B), (0xFF5C, 0x007C), (0xFF5D, 0x007D), (0xFF5E, 0x007E), (0xFFE0, 0x009B),
+    (0xFFE1, 0x009C), (0xFFE2, 0x00AA), (0xFFE5, 0x009D), (0xFFE8, 0x00B3), (0xFFED, 0x00FE),
+];
+
+/// Code page 850.
+#[rustfmt::skip]
+pub(crate) const CP850: [(u16, u16); 826] = [
+    (0x0100, 0x0041), (0x0101, 0x0061), (0x0102, 0x0041), (0x0103, 0x0061), (0x0104, 0x0041),
+    (0x0105, 0x0061), (0x0106, 0x0043), (0x0107, 0x0063), (0x0108, 0x0043), (0x0109, 0x0063),
+    (0x010A, 0x0043), (0x010B, 0x0063), (0x010C, 0x0043), (0x010D, 0x0063), (0x010E, 0x0044),
+    (0x010F, 0x0064), (0x0112, 0x0045), (0x0113, 0x0065), (0x0114, 0x0045), (0x0115, 0x0065),
+    (0x0116, 0x0045), (0x0117, 0x0065), (0x0118, 0x0045), (0x0119, 0x0065), (0x011A, 0x0045),
+    (0x011B, 0x0065), (0x011C, 0x0047), (0x011D, 0x0067), (0x011E, 0x0047), (0x011F, 0x0067),
+    (0x0120, 0x0047), (0x0121, 0x0067), (0x0122, 0x0047), (0x0123, 0x0067), (0x0124, 0x0048),
+    (0x0125, 0x0068), (0x0128, 0x0049), (0x0129, 0x0069), (0x012A, 0x0049), (0x012B, 0x0069),
+    (0x012C, 0x0049), (0x012D, 0x0069), (0x012E, 0x0049), (0x012F, 0x0069), (0x0130, 0x0049),
+    (0x0134, 0x004A), (0x0135, 0x006A), (0x0136, 0x004B), (0x0137, 0x006B), (0x0139, 0x004C),
+    (0x013A, 0x006C), (0x013B, 0x004C), (0x013C, 0x006C), (0x013D, 0x004C), (0x013E, 0x006C),
+    (0x0143, 0x004E), (0x0144, 0x006E), (0x0145, 0x004E), (0x0146, 0x006E), (0x0147, 0x004E),
+    (0x0148, 0x006E), (0x014C, 0x004F), (0x014D, 0x006F), (0x014E, 0x004F), (0x014F, 0x006F),
+    (0x0150, 0x004F), (0x0151, 0x006F), (0x0154, 0x0052), (0x0155, 0x0072), (0x0156, 0x0052),
+    (0x0157, 0x0072), (0x0158, 0x0052), (0x0159, 0x0072), (0x015A, 0x0053), (0x015B, 0x0073),
+    (0x015C, 0x0053), (0x015D, 0x0073), (0x015E, 0x0053), (0x015F, 0x0073), (0x0160, 0x0053),
+    (0x0161, 0x0073), (0x0162, 0x0054), (0x0163, 0x0074), (0x0164, 0x0054), (0x0165, 0x0074),
+    (0x0168, 0x0055), (0x0169, 0x0075), (0x016A, 0x0055), (0x016B, 0x0075), (0x016C, 0x0055),
+    (0x016D, 0x0075), (0x016E, 0x0055), (0x016F, 0x0075), (0x0170, 0x0055), (0x0171, 0x0075),
+    (0x0172, 0x0055), (0x0173, 0x0075), (0x0174, 0x0057), (0x0175, 0x0077), (0x0176, 0x0059),
+    (0x0177, 0x0079), (0x0178, 0x0059), (0x0179, 0x005A), (0x017A, 0x007A), (0x017B, 0x005A),
+    (0x017C, 0x007A), (0x017D, 0x005A), (0x017E, 0x007A), (0x017F, 0x0073), (0x01A0, 0x004F),
+    (0x01A1, 0x006F), (0x01AF, 0x0055), (0x01B0, 0x0075), (0x01CD, 0x0041), (0x01CE, 0x0061),
+    (0x01CF, 0x0049), (0x01D0, 0x0069), (0x01D1, 0x004F), (0x01D2, 0x006F), (0x01D3, 0x0055),
+    (0x01D4, 0x0075), (0x01D5, 0x0055), (0x01D6, 0x0075), (0x01D7, 0x0055), (0x01D8, 0x0075),
+    (0x01D9, 0x0055), (0x01DA, 0x0075), (0x01DB, 0x0055), (0x01DC, 0x0075), (0x01DE, 0x0041),
+    (0x01DF, 0x0061), (0x01E0, 0x0041), (0x01E1, 0x0061), (0x01E2, 0x0092), (0x01E3, 0x0091),
+    (0x01E6, 0x0047), (0x01E7, 0x0067), (0x01E8, 0x004B), (0x01E9, 0x006B), (0x01EA, 0x004F),
+    (0x01EB, 0x006F), (0x01EC, 0x004F), (0x01ED, 0x006F), (0x01F0, 0x006A), (0x01F4, 0x0047),
+    (0x01F5, 0x0067), (0x01F8, 0x004E), (0x01F9, 0x006E), (0x01FA, 0x0041), (0x01FB, 0x0061),
+    (0x01FC, 0x0092), (0x01FD, 0x0091), (0x01FE, 0x009D), (0x01FF, 0x009B), (0x0200, 0x0041),
+    (0x0201, 0x0061), (0x0202, 0x0041), (0x0203, 0x0061), (0x0204, 0x0045), (0x0205, 0x0065),
+    (0x0206, 0x0045), (0x0207, 0x0065), (0x0208, 0x0049), (0x0209, 0x0069), (0x020A, 0x0049),
+    (0x020B, 0x0069), (0x020C, 0x004F), (0x020D, 0x006F), (0x020E, 0x004F), (0x020F, 0x006F),
+    (0x0210, 0x0052), (0x0211, 0x0072), (0x0212, 0x0052), (0x0213, 0x0072), (0x0214, 0x0055),
+    (0x0215, 0x0075), (0x0216, 0x0055), (0x0217, 0x0075), (0x0218, 0x0053), (0x0219, 0x0073),
+    (0x021A, 0x0054), (0x021B, 0x0074), (0x021E, 0x0048), (0x021F, 0x0068), (0x0226, 0x0041),
+    (0x0227, 0x0061), (0x0228, 0x0045), (0x0229, 0x0065), (0x022A, 0x004F), (0x022B, 0x006F),
+    (0x022C, 0x004F), (0x022D, 0x006F), (0x022E, 0x004F), (0x022F, 0x006F), (0x0230, 0x004F),
+    (0x0231, 0x006F), (0x0232, 0x0059), (0x0233, 0x0079), (0x02B0, 0x0068), (0x02B2, 0x006A),
+    (0x02B3, 0x0072), (0x02B7, 0x0077), (0x02B8, 0x0079), (0x02E1, 0x006C), (0x02E2, 0x0073),
+    (0x02E3, 0x0078), (0x037E, 0x003B), (0x0387, 0x00FA), (0x1D2C, 0x0041), (0x1D2D, 0x0092),
+    (0x1D2E, 0x0042), (0x1D30, 0x0044), (0x1D31, 0x0045), (0x1D33, 0x0047), (0x1D34, 0x0048),
+    (0x1D35, 0x0049), (0x1D36, 0x004A), (0x1D37, 0x004B), (0x1D38, 0x004C), (0x1D39, 0x004D),
+    (0x1D3A, 0x004E), (0x1D3C, 0x004F), (0x1D3E, 0x0050), (0x1D3F, 0x0052), (0x1D40, 0x0054),
+    (0x1D41, 0x0055), (0x1D42, 0x0057), (0x1D43, 0x0061), (0x1D47, 0x0062), (0x1D48, 0x0064),
+    (0x1D49, 0x0065), (0x1D4D, 0x0067), (0x1D4F, 0x006B), (0x1D50, 0x006D), (0x1D52, 0x006F),
+    (0x1D56, 0x0070), (0x1D57, 0x0074), (0x1D58, 0x0075), (0x1D5B, 0x0076), (0x1D62, 0x0069),
+    (0x1D63, 0x0072), (0x1D64, 0x0075), (0x1D65, 0x0076), (0x1D9C, 0x0063), (0x1D9E, 0x00D0),
+    (0x1DA0, 0x0066), (0x1DBB, 0x007A), (0x1E00, 0x0041), (0x1E01, 0x0061), (0x1E02, 0x0042),
+    (0x1E03, 0x0062), (0x1E04, 0x0042), (0x1E05, 0x0062), (0x1E06, 0x0042), (0x1E07, 0x0062),
+    (0x1E08, 0x0043), (0x1E09, 0x0063), (0x1E0A, 0x0044), (0x1E0B, 0x0064), (0x1E0C, 0x0044),
+    (0x1E0D, 0x0064), (0x1E0E, 0x0044), (0x1E0F, 0x0064), (0x1E10, 0x0044), (0x1E11, 0x0064),
+    (0x1E12, 0x0044), (0x1E13, 0x0064), (0x1E14, 0x0045), (0x1E15, 0x0065), (0x1E16, 0x0045),
+    (0x1E17, 0x0065), (0x1E18, 0x0045), (0x1E19, 0x0065), (0x1E1A, 0x0045), (0x1E1B, 0x0065),
+    (0x1E1C, 0x0045), (0x1E1D, 0x0065), (0x1E1E, 0x0046), (0x1E1F, 0x0066), (0x1E20, 0x0047),
+    (0x1E21, 0x0067), (0x1E22, 0x0048), (0x1E23, 0x0068), (0x1E24, 0x0048), (0x1E25, 0x0068),
+    (0x1E26, 0x0048), (0x1E27, 0x0068), (0x1E28, 0x0048), (0x1E29, 0x0068), (0x1E2A, 0x0048),
+    (0x1E2B, 0x0068), (0x1E2C, 0x0049), (0x1E2D, 0x0069), (0x1E2E, 0x0049), (0x1E2F, 0x0069),
+    (0x1E30, 0x004B), (0x1E31, 0x006B), (0x1E32, 0x004B), (0x1E33, 0x006B), (0x1E34, 0x004B),
+    (0x1E35, 0x006B), (0x1E36, 0x004C), (0x1E37, 0x006C), (0x1E38, 0x004C), (0x1E39, 0x006C),
+    (0x1E3A, 0x004C), (0x1E3B, 0x006C), (0x1E3C, 0x004C), (0x1E3D, 0x006C), (0x1E3E, 0x004D),
+    (0x1E3F, 0x006D), (0x1E40, 0x004D), (0x1E41, 0x006D), (0x1E42, 0x004D), (0x1E43, 0x006D),
+    (0x1E44, 0x004E), (0x1E45, 0x006E), (0x1E46, 0x004E), (0x1E47, 0x006E), (0x1E48, 0x004E),
+    (0x1E49, 0x006E), (0x1E4A, 0x004E), (0x1E4B, 0x006E), (0x1E4C, 0x004F), (0x1E4D, 0x006F),
+    (0x1E4E, 0x004F), (0x1E4F, 0x006F), (0x1E50, 0x004F), (0x1E51, 0x006F), (0x1E52, 0x004F),
+    (0x1E53, 0x006F), (0x1E54, 0x0050), (0x1E55, 0x0070), (0x1E56, 0x0050), (0x1E57, 0x0070),
+    (0x1E58, 0x0052), (0x1E59, 0x0072), (0x1E5A, 0x0052), (0x1E5B, 0x0072), (0x1E5C, 0x0052),
+    (0x1E5D, 0x0072), (0x1E5E, 0x0052), (0x1E5F, 0x0072), (0x1E60, 0x0053), (0x1E61, 0x0073),
+    (0x1E62, 0x0053), (0x1E63, 0x0073), (0x1E64, 0x0053), (0x1E65, 0x0073), (0x1E66, 0x0053),
+    (0x1E67, 0x0073), (0x1E68, 0x0053), (0x1E69, 0x0073), (0x1E6A, 0x0054), (0x1E6B, 0x0074),
+    (0x1E6C, 0x0054), (0x1E6D, 0x0074), (0x1E6E, 0x0054), (0x1E6F, 0x0074), (0x1E70, 0x0054),
+    (0x1E71, 0x0074), (0x1E72, 0x0055), (0x1E73, 0x0075), (0x1E74, 0x0055), (0x1E75, 0x0075),
+    (0x1E76, 0x0055), (0x1E77, 0x0075), (0x1E78, 0x0055), (0x1E79, 0x0075), (0x1E7A, 0x0055),
+    (0x1E7B, 0x0075), (0x1E7C, 0x0056), (0x1E7D, 0x0076), (0x1E7E, 0x0056), (0x1E7F, 0x0076),
+    (0x1E80, 0x0057), (0x1E81, 0x0077), (0x1E82, 0x0057), (0x1E83, 0x0077), (0x1E84, 0x0057),
+    (0x1E85, 0x0077), (0x1E86, 0x0057), (0x1E87, 0x0077), (0x1E88, 0x0057), (0x1E89, 0x0077),
+    (0x1E8A, 0x0058), (0x1E8B, 0x0078), (0x1E8C, 0x0058), (0x1E8D, 0x0078), (0x1E8E, 0x0059),
+    (0x1E8F, 0x0079), (0x1E90, 0x005A), (0x1E91, 0x007A), (0x1E92, 0x005A), (0x1E93, 0x007A),
+    (0x1E94, 0x005A), (0x1E95, 0x007A), (0x1E96, 0x0068), (0x1E97, 0x0074), (0x1E98, 0x0077),
+    (0x1E99, 0x0079), (0x1E9B, 0x0073), (0x1EA0, 0x0041), (0x1EA1, 0x0061), (0x1EA2, 0x0041),
+    (0x1EA3, 0x0061), (0x1EA4, 0x0041), (0x1EA5, 0x0061), (0x1EA6, 0x0041), (0x1EA7, 0x0061),
+    (0x1EA8, 0x0041), (0x1EA9, 0x0061), (0x1EAA, 0x0041), (0x1EAB, 0x0061), (0x1EAC, 0x0041),
+    (0x1EAD, 0x0061), (0x1EAE, 0x0041), (0x1EAF, 0x0061), (0x1EB0, 0x0041), (0x1EB1, 0x0061),
+    (0x1EB2, 0x0041), (0x1EB3, 0x0061), (0x1EB4, 0x0041), (0x1EB5, 0x0061), (0x1EB6, 0x0041),
+    (0x1EB7, 0x0061), (0x1EB8, 0x0045), (0x1EB9, 0x0065), (0x1EBA, 0x0045), (0x1EBB, 0x0065),
+    (0x1EBC, 0x0045), (0x1EBD, 0x0065), (0x1EBE, 0x0045), (0x1EBF, 0x0065), (0x1EC0, 0x0045),
+    (0x1EC1, 0x0065), (0x1EC2, 0x0045), (0x1EC3, 0x0065), (0x1EC4, 0x0045), (0x1EC5, 0x0065),
+    (0x1EC6, 0x0045), (0x1EC7, 0x0065), (0x1EC8, 0x0049), (0x1EC9, 0x0069), (0x1ECA, 0x0049),
+    (0x1ECB, 0x0069), (0x1ECC, 0x004F), (0x1ECD, 0x006F), (0x1ECE, 0x004F), (0x1ECF, 0x006F),
+    (0x1ED0, 0x004F), (0x1ED1, 0x006F), (0x1ED2, 0x004F), (0x1ED3, 0x006F), (0x1ED4, 0x004F),
+    (0x1ED5, 0x006F), (0x1ED6, 0x004F), (0x1ED7, 0x006F), (0x1ED8, 0x004F), (0x1ED9, 0x006F),
+    (0x1EDA, 0x004F), (0x1EDB, 0x006F), (0x1EDC, 0x004F), (0x1EDD, 0x006F), (0x1EDE, 0x004F),
+    (0x1EDF, 0x006F), (0x1EE0, 0x004F), (0x1EE1, 0x006F), (0x1EE2, 0x004F), (0x1EE3, 0x006F),
+    (0x1EE4, 0x0055), (0x1EE5, 0x0075), (0x1EE6, 0x0055), (0x1EE7, 0x0075), (0x1EE8, 0x0055),
+    (0x1EE9, 0x0075), (0x1EEA, 0x0055), (0x1EEB, 0x0075), (0x1EEC, 0x0055), (0x1EED, 0x0075),
+    (0x1EEE, 0x0055), (0x1EEF, 0x0075), (0x1EF0, 0x0055), (0x1EF1, 0x0075), (0x1EF2, 0x0059),
+    (0x1EF3, 0x0079), (0x1EF4, 0x0059), (0x1EF5, 0x0079), (0x1EF6, 0x0059), (0x1EF7, 0x0079),
+    (0x1EF8, 0x0059), (0x1EF9, 0x0079), (0x1FEF, 0x0060), (0x2000, 0x0020), (0x2001, 0x0020),
+    (0x2002, 0x0020), (0x2003, 0x0020), (0x2004, 0x0020), (0x2005, 0x0020), (0x2006, 0x0020),
+    (0x2007, 0x0020), (0x2008, 0x0020), (0x2009, 0x0020), (0x200A, 0x0020), (0x2010, 0x002D),
+    (0x2012, 0x002D), (0x2013, 0x002D), (0x2014, 0x002D), (0x2015, 0x002D), (0x2018, 0x0027),
+    (0x2019, 0x0027), (0x201A, 0x002C), (0x201B, 0x0027), (0x201C, 0x0022), (0x201D, 0x0022),
+    (0x201E, 0x0022), (0x201F, 0x0022), (0x2024, 0x002E), (0x202F, 0x0020), (0x2032, 0x0027),
+    (0x2035, 0x0060), (0x2039, 0x003C), (0x203A, 0x003E), (0x205F, 0x0020), (0x2070, 0x0030),
+    (0x2071, 0x0069), (0x2074, 0x0034), (0x2075, 0x0035), (0x2076, 0x0036), (0x2077, 0x0037),
+    (0x2078, 0x0038), (0x2079, 0x0039), (0x207A, 0x002B), (0x207C, 0x003D), (0x207D, 0x0028),
+    (0x207E, 0x0029), (0x207F, 0x006E), (0x2080, 0x0030), (0x2081, 0x0031), (0x2082, 0x0032),
+    (0x2083, 0x0033), (0x2084, 0x0034), (0x2085, 0x0035), (0x2086, 0x0036), (0x2087, 0x0037),
+    (0x2088, 0x0038), (0x2089, 0x0039), (0x208A, 0x002B), (0x208C, 0x003D), (0x208D, 0x0028),
+    (0x208E, 0x0029), (0x2090, 0x0061), (0x2091, 0x0065), (0x2092, 0x006F), (0x2093, 0x0078),
+    (0x2095, 0x0068), (0x2096, 0x006B), (0x2097, 0x006C), (0x2098, 0x006D), (0x2099, 0x006E),
+    (0x209A, 0x0070), (0x209B, 0x0073), (0x209C, 0x0074), (0x2102, 0x0043), (0x210A, 0x0067),
+    (0x210B, 0x0048), (0x210C, 0x0048), (0x210D, 0x0048), (0x210E, 0x0068), (0x2110, 0x0049),
+    (0x2111, 0x0049), (0x2112, 0x004C), (0x2113, 0x006C), (0x2115, 0x004E), (0x2119, 0x0050),
+    (0x211A, 0x0051), (0x211B, 0x0052), (0x211C, 0x0052), (0x211D, 0x0052), (0x2124, 0x005A),
+    (0x2128, 0x005A), (0x212A, 0x004B), (0x212B, 0x0041), (0x212C, 0x0042), (0x212D, 0x0043),
+    (0x212F, 0x0065), (0x2130, 0x0045), (0x2131, 0x0046), (0x2133, 0x004D), (0x2134, 0x006F),
+    (0x2139, 0x0069), (0x2145, 0x0044), (0x2146, 0x0064), (0x2147, 0x0065), (0x2148, 0x0069),
+    (0x2149, 0x006A), (0x2160, 0x0049), (0x2164, 0x0056), (0x2169, 0x0058), (0x216C, 0x004C),
+    (0x216D, 0x0043), (0x216E, 0x0044), (0x216F, 0x004D), (0x2170, 0x0069), (0x2174, 0x0076),
+    (0x2179, 0x0078), (0x217C, 0x006C), (0x217D, 0x0063), (0x217E, 0x0064), (0x217F, 0x006D),
+    (0x2212, 0x002D), (0x2215, 0x002F), (0x2216, 0x005C), (0x2223, 0x007C), (0x223C, 0x007E),
+    (0x2260, 0x003D), (0x226E, 0x003C), (0x226F, 0x003E), (0x2460, 0x0031), (0x2461, 0x0032),
+    (0x2462, 0x0033), (0x2463, 0x0034), (0x2464, 0x0035), (0x2465, 0x0036), (0x2466, 0x0037),
+    (0x2467, 0x0038), (0x2468, 0x0039), (0x24B6, 0x0041), (0x24B7, 0x0042), (0x24B8, 0x0043),
+    (0x24B9, 0x0044), (0x24BA, 0x0045), (0x24BB, 0x0046), (0x24BC, 0x0047), (0x24BD, 0x0048),
+    (0x24BE, 0x0049), (0x24BF, 0x004A), (0x24C0, 0x004B), (0x24C1, 0x004C), (0x24C2, 0x004D),
+    (0x24C3, 0x004E), (0x24C4, 0x004F), (0x24C5, 0x0050), (0x24C6, 0x0051), (0x24C7, 0x0052),
+    (0x24C8, 0x0053), (0x24C9, 0x0054), (0x24CA, 0x0055), (0x24CB, 0x0056), (0x24CC, 0x0057),
+    (0x24CD, 0x0058), (0x24CE, 0x0059), (0x24CF, 0x005A), (0x24D0, 0x0061), (0x24D1, 0x0062),
+    (0x24D2, 0x0063), (0x24D3, 0x0064), (0x24D4, 0x0065), (0x24D5, 0x0066), (0x24D6, 0x0067),
+    (0x24D7, 0x0068), (0x24D8, 0x0069), (0x24D9, 0x006A), (0x24DA, 0x006B), (0x24DB, 0x006C),
+    (0x24DC, 0x006D), (0x24DD, 0x006E), (0x24DE, 0x006F), (0x24DF, 0x0070), (0x24E0, 0x0071),
+    (0x24E1, 0x0072), (0x24E2, 0x0073), (0x24E3, 0x0074), (0x24E4, 0x0075), (0x24E5, 0x0076),
+    (0x24E6, 0x0077), (0x24E7, 0x0078), (0x24E8, 0x0079), (0x24E9, 0x007A), (0x24EA, 0x0030),
+    (0x2C7C, 0x006A), (0x2C7D, 0x0056), (0x3000, 0x0020), (0xA7F2, 0x0043), (0xA7F3, 0x0046),
+    (0xA7F4, 0x0051), (0xFB29, 0x002B), (0xFE10, 0x002C), (0xFE13, 0x003A), (0xFE14, 0x003B),
+    (0xFE15, 0x0021), (0xFE16, 0x003F), (0xFE33, 0x005F), (0xFE34, 0x005F), (0xFE35, 0x0028),
+    (0xFE36, 0x0029), (0xFE37, 0x007B), (0xFE38, 0x007D), (0xFE47, 0x005B), (0xFE48, 0x005D),
+    (0xFE4D, 0x005F), (0xFE4E, 0x005F), (0xFE4F, 0x005F), (0xFE50, 0x002C), (0xFE52, 0x002E),
+    (0xFE54, 0x003B), (0xFE55, 0x003A), (0xFE56, 0x003F), (0xFE57, 0x0021), (0xFE59, 0x0028),
+    (0xFE5A, 0x0029), (0xFE5B, 0x007B), (0xFE5C, 0x007D), (0xFE5F, 0x0023), (0xFE60, 0x0026),
+    (0xFE61, 0x002A), (0xFE62, 0x002B), (0xFE63, 0x002D), (0xFE64, 0x003C), (0xFE65, 0x003E),
+    (0xFE66, 0x003D), (0xFE68, 0x005C), (0xFE69, 0x0024), (0xFE6A, 0x0025), (0xFE6B, 0x0040),
+    (0xFF01, 0x0021), (0xFF02, 0x0022), (0xFF03, 0x0023), (0xFF04, 0x0024), (0xFF05, 0x0025),
+    (0xFF06, 0x0026), (0xFF07, 0x0027), (0xFF08, 0x0028), (0xFF09, 0x0029), (0xFF0A, 0x002A),
+    (0xFF0B, 0x002B), (0xFF0C, 0x002C), (0xFF0D, 0x002D), (0xFF0E, 0x002E), (0xFF0F, 0x002F),
+    (0xFF10, 0x0030), (0xFF11, 0x0031), (0xFF12, 0x0032), (0xFF13, 0x0033), (0xFF14, 0x0034),
+    (0xFF15, 0x0035), (0xFF16, 0x0036), (0xFF17, 0x0037), (0xFF18, 0x0038), (0xFF19, 0x0039),
+    (0xFF1A, 0x003A), (0xFF1B, 0x003B), (0xFF1C, 0x003C), (0xFF1D, 0x003D), (0xFF1E, 0x003E),
+    (0xFF1F, 0x003F), (0xFF20, 0x0040), (0xFF21, 0x0041), (0xFF22, 0x0042), (0xFF23, 0x0043),
+    (0xFF24, 0x0044), (0xFF25, 0x0045), (0xFF26, 0x0046), (0xFF27, 0x0047), (0xFF28, 0x0048),
+    (0xFF29, 0x0049), (0xFF2A, 0x004A), (0xFF2B, 0x004B), (0xFF2C, 0x004C), (0xFF2D, 0x004D),
+    (0xFF2E, 0x004E), (0xFF2F, 0x004F), (0xFF30, 0x0050), (0xFF31, 0x0051), (0xFF32, 0x0052),
+    (0xFF33, 0x0053), (0xFF34, 0x0054), (0xFF35, 0x0055), (0xFF36, 0x0056), (0xFF37, 0x0057),
+    (0xFF38, 0x0058), (0xFF39, 0x0059), (0xFF3A, 0x005A), (0xFF3B, 0x005B), (0xFF3C, 0x005C),
+    (0xFF3D, 0x005D), (0xFF3E, 0x005E), (0xFF3F, 0x005F), (0xFF40, 0x0060), (0xFF41, 0x0061),
+    (0xFF42, 0x0062), (0xFF43, 0x0063), (0xFF44, 0x0064), (0xFF45, 0x0065), (0xFF46, 0x0066),
+    (0xFF47, 0x0067), (0xFF48, 0x0068), (0xFF49, 0x0069), (0xFF4A, 0x006A), (0xFF4B, 0x006B),
+    (0xFF4C, 0x006C), (0xFF4D, 0x006D), (0xFF4E, 0x006E), (0xFF4F, 0x006F), (0xFF50, 0x0070),
+    (0xFF51, 0x0071), (0xFF52, 0x0072), (0xFF53, 0x0073), (0xFF54, 0x0074), (0xFF55, 0x0075),
+    (0xFF56, 0x0076), (0xFF57, 0x0077), (0xFF58, 0x0078), (0xFF59, 0x0079), (0xFF5A, 0x007A),
+    (0xFF5B, 0x007B), (0xFF5C, 0x007C), (0xFF5D, 0x007D), (0xFF5E, 0x007E), (0xFFE0, 0x00BD),
+    (0xFFE1, 0x009C), (0xFFE2, 0x00AA), (0xFFE4, 0x00DD), (0xFFE5, 0x00BE), (0xFFE8, 0x00B3),
+    (0xFFED, 0x00FE),
+];
+
+/// Code page 720.
+#[rustfmt::skip]
+pub(crate) const CP720: [(u16, u16); 995] = [
+    (0x00AA, 0x0061), (0x00B3, 0x0033), (0x00B9, 0x0031), (0x00BA, 0x006F), (0x00C0, 0x0041),
+    (0x00C1, 0x0041), (0x00C2, 0x0041), (0x00C3, 0x0041), (0x00C4, 0x0041), (0x00C5, 0x0041),
+    (0x00C7, 0x0043), (0x00C8, 0x0045), (0x00C9, 0x0045), (0x00CA, 0x0045), (0x00CB, 0x0045),
+    (0x00CC, 0x0049), (0x00CD, 0x0049), (0x00CE, 0x0049), (0x00CF, 0x0049), (0x00D1, 0x004E),
+    (0x00D2, 0x004F), (0x00D3, 0x004F), (0x00D4, 0x004F), (0x00D5, 0x004F), (0x00D6, 0x004F),
+    (0x00D9, 0x0055), (0x00DA, 0x0055), (0x00DB, 0x0055), (0x00DC, 0x0055), (0x00DD, 0x0059),
+    (0x00E1, 0x0061), (0x00E3, 0x0061), (0x00E4, 0x0061), (0x00E5, 0x0061), (0x00EC, 0x0069),
+    (0x00ED, 0x0069), (0x00F1, 0x006E), (0x00F2, 0x006F), (0x00F3, 0x006F), (0x00F5, 0x006F),
+    (0x00F6, 0x006F), (0x00FA, 0x0075), (0x00FC, 0x0075), (0x00FD, 0x0079), (0x00FF, 0x0079),
+    (0x0100, 0x0041), (0x0101, 0x0061), (0x0102, 0x0041), (0x0103, 0x0061), (0x0104, 0x0041),
+    (0x0105, 0x0061), (0x0106, 0x0043), (0x0107, 0x0063), (0x0108, 0x0043), (0x0109, 0x0063),
+    (0x010A, 0x0043), (0x010B, 0x0063), (0x010C, 0x0043), (0x010D, 0x0063), (0x010E, 0x0044),
+    (0x010F, 0x0064), (0x0112, 0x0045), (0x0113, 0x0065), (0x0114, 0x0045), (0x0115, 0x0065),
+    (0x0116, 0x0045), (0x0117, 0x0065), (0x0118, 0x0045), (0x0119, 0x0065), (0x011A, 0x0045),
+    (0x011B, 0x0065), (0x011C, 0x0047), (0x011D, 0x0067), (0x011E, 0x0047), (0x011F, 0x0067),
+    (0x0120, 0x0047), (0x0121, 0x0067), (0x0122, 0x0047), (0x0123, 0x0067), (0x0124, 0x0048),
+    (0x0125, 0x0068), (0x0128, 0x0049), (0x0129, 0x0069), (0x012A, 0x0049), (0x012B, 0x0069),
+    (0x012C, 0x0049), (0x012D, 0x0069), (0x012E, 0x0049), (0x012F, 0x0069), (0x0130, 0x0049),
+    (0x0134, 0x004A), (0x0135, 0x006A), (0x0136, 0x004B), (0x0137, 0x006B), (0x0139, 0x004C),
+    (0x013A, 0x006C), (0x013B, 0x004C), (0x013C, 0x006C), (0x013D, 0x004C), (0x013E, 0x006C),
+    (0x0143, 0x004E), (0x0144, 0x006E), (0x0145, 0x004E), (0x0146, 0x006E), (0x0147, 0x004E),
+    (0x0148, 0x006E), (0x014C, 0x004F), (0x014D, 0x006F), (0x014E, 0x004F), (0x014F, 0x006F),
+    (0x0150, 0x004F), (0x0151, 0x006F), (0x0154, 0x0052), (0x0155, 0x0072), (0x0156, 0x0052),
+    (0x0157, 0x0072), (0x0158, 0x0052), (0x0159, 0x0072), (0x015A, 0x0053), (0x015B, 0x0073),
+    (0x015C, 0x0053), (0x015D, 0x0073), (0x015E, 0x0053), (0x015F, 0x0073), (0x0160, 0x0053),
+    (0x0161, 0x0073), (0x0162, 0x0054), (0x0163, 0x0074), (0x0164, 0x0054), (0x0165, 0x0074),
+    (0x0168, 0x0055), (0x0169, 0x0075), (0x016A, 0x0055), (0x016B, 0x0075), (0x016C, 0x0055),
+    (0x016D, 0x0075), (0x016E, 0x0055), (0x016F, 0x0075), (0x0170, 0x0055), (0x0171, 0x0075),
+    (0x0172, 0x0055), (0x0173, 0x0075), (0x0174, 0x0057), (0x0175, 0x0077), (0x0176, 0x0059),
+    (0x0177, 0x0079), (0x0178, 0x0059), (0x0179, 0x005A), (0x017A, 0x007A), (0x017B, 0x005A),
+    (0x017C, 0x007A), (0x017D, 0x005A), (0x017E, 0x007A), (0x017F, 0x0073), (0x01A0, 0x004F),
+    (0x01A1, 0x006F), (0x01AF, 0x0055), (0x01B0, 0x0075), (0x01CD, 0x0041), (0x01CE, 0x0061),
+    (0x01CF, 0x0049), (0x01D0, 0x0069), (0x01D1, 0x004F), (0x01D2, 0x006F), (0x01D3, 0x0055),
+    (0x01D4, 0x0075), (0x01D5, 0x0055), (0x01D6, 0x0075), (0x01D7, 0x0055), (0x01D8, 0x0075),
+    (0x01D9, 0x0055), (0x01DA, 0x0075), (0x01DB, 0x0055), (0x01DC, 0x0075), (0x01DE, 0x0041),
+    (0x01DF, 0x0061), (0x01E0, 0x0041), (0x01E1, 0x0061), (0x01E6, 0x0047), (0x01E7, 0x0067),
+    (0x01E8, 0x004B), (0x01E9, 0x006B), (0x01EA, 0x004F), (0x01EB, 0x006F), (0x01EC, 0x004F),
+    (0x01ED, 0x006F), (0x01F0, 0x006A), (0x01F4, 0x0047), (0x01F5, 0x0067), (0x01F8, 0x004E),
+    (0x01F9, 0x006E), (0x01FA, 0x0041), (0x01FB, 0x0061), (0x0200, 0x0041), (0x0201, 0x0061),
+    (0x0202, 0x0041), (0x0203, 0x0061), (0x0204, 0x0045), (0x0205, 0x0065), (0x0206, 0x0045),
+    (0x0207, 0x0065), (0x0208, 0x0049), (0x0209, 0x0069), (0x020A, 0x0049), (0x020B, 0x0069),
+    (0x020C, 0x004F), (0x020D, 0x006F), (0x020E, 0x004F), (0x020F, 0x006F), (0x0210, 0x0052),
+    (0x0211, 0x0072), (0x0212, 0x0052), (0x0213, 0x0072), (0x0214, 0x0055), (0x0215, 0x0075),
+    (0x0216, 0x0055), (0x0217, 0x0075), (0x0218, 0x0053), (0x0219, 0x0073), (0x021A, 0x0054),
+    (0x021B, 0x0074), (0x021E, 0x0048), (0x021F, 0x0068), (0x0226, 0x0041), (0x0227, 0x0061),
+    (0x0228, 0x0045), (0x0229, 0x0065), (0x022A, 0x004F), (0x022B, 0x006F), (0x022C, 0x004F),
+    (0x022D, 0x006F), (0x022E, 0x004F), (0x022F, 0x006F), (0x0230, 0x004F), (0x0231, 0x006F),
+    (0x0232, 0x0059), (0x0233, 0x0079), (0x02B0, 0x0068), (0x02B2, 0x006A), (0x02B3, 0x0072),
+    (0x02B7, 0x0077), (0x02B8, 0x0079), (0x02E1, 0x006C), (0x02E2, 0x0073), (0x02E3, 0x0078),
+    (0x037E, 0x003B), (0x0387, 0x00FA), (0x1D2C, 0x0041), (0x1D2E, 0x0042), (0x1D30, 0x0044),
+    (0x1D31, 0x0045), (0x1D33, 0x0047), (0x1D34, 0x0048), (0x1D35, 0x0049), (0x1D36, 0x004A),
+    (0x1D37, 0x004B), (0x1D38, 0x004C), (0x1D39, 0x004D), (0x1D3A, 0x004E), (0x1D3C, 0x004F),
+    (0x1D3E, 0x0050), (0x1D3F, 0x0052), (0x1D40, 0x0054), (0x1D41, 0x0055), (0x1D42, 0x0057),
+    (0x1D43, 0x0061), (0x1D47, 0x0062), (0x1D48, 0x0064), (0x1D49, 0x0065), (0x1D4D, 0x0067),
+    (0x1D4F, 0x006B), (0x1D50, 0x006D), (0x1D52, 0x006F), (0x1D56, 0x0070), (0x1D57, 0x0074),
+    (0x1D58, 0x0075), (0x1D5B, 0x0076), (0x1D62, 0x0069), (0x1D63, 0x0072), (0x1D64, 0x0075),
+    (0x1D65, 0x0076), (0x1D9C, 0x0063), (0x1DA0, 0x0066), (0x1DBB, 0x007A), (0x1E00, 0x0041),
+    (0x1E01, 0x0061), (0x1E02, 0x0042), (0x1E03, 0x0062), (0x1E04, 0x0042), (0x1E05, 0x0062),
+    (0x1E06, 0x0042), (0x1E07, 0x0062), (0x1E08, 0x0043), (0x1E09, 0x0063), (0x1E0A, 0x0044),
+    (0x1E0B, 0x0064), (0x1E0C, 0x0044), (0x1E0D, 0x0064), (0x1E0E, 0x0044), (0x1E0F, 0x0064),
+    (0x1E10, 0x0044), (0x1E11, 0x0064), (0x1E12, 0x0044), (0x1E13, 0x0064), (0x1E14, 0x0045),
+    (0x1E15, 0x0065), (0x1E16, 0x0045), (0x1E17, 0x0065), (0x1E18, 0x0045), (0x1E19, 0x0065),
+    (0x1E1A, 0x0045), (0x1E1B, 0x0065), (0x1E1C, 0x0045), (0x1E1D, 0x0065), (0x1E1E, 0x0046),
+    (0x1E1F, 0x0066), (0x1E20, 0x0047), (0x1E21, 0x0067), (0x1E22, 0x0048), (0x1E23, 0x0068),
+    (0x1E24, 0x0048), (0x1E25, 0x0068), (0x1E26, 0x0048), (0x1E27, 0x0068), (0x1E28, 0x0048),
+    (0x1E29, 0x0068), (0x1E2A, 0x0048), (0x1E2B, 0x0068), (0x1E2C, 0x0049), (0x1E2D, 0x0069),
+    (0x1E2E, 0x0049), (0x1E2F, 0x0069), (0x1E30, 0x004B), (0x1E31, 0x006B), (0x1E32, 0x004B),
+    (0x1E33, 0x006B), (0x1E34, 0x004B), (0x1E35, 0x006B), (0x1E36, 0x004C), (0x1E37, 0x006C),
+    (0x1E38, 0x004C), (0x1E39, 0x006C), (0x1E3A, 0x004C), (0x1E3B, 0x006C), (0x1E3C, 0x004C),
+    (0x1E3D, 0x006C), (0x1E3E, 0x004D), (0x1E3F, 0x006D), (0x1E40, 0x004D), (0x1E41, 0x006D),
+    (0x1E42, 0x004D), (0x1E43, 0x006D), (0x1E44, 0x004E), (0x1E45, 0x006E), (0x1E46, 0x004E),
+    (0x1E47, 0x006E), (0x1E48, 0x004E), (0x1E49, 0x006E), (0x1E4A, 0x004E), (0x1E4B, 0x006E),
+    (0x1E4C, 0x004F), (0x1E4D, 0x006F), (0x1E4E, 0x004F), (0x1E4F, 0x006F), (0x1E50, 0x004F),
+    (0x1E51, 0x006F), (0x1E52, 0x004F), (0x1E53, 0x006F), (0x1E54, 0x0050), (0x1E55, 0x0070),
+    (0x1E56, 0x0050), (0x1E57, 0x0070), (0x1E58, 0x0052), (0x1E59, 0x0072), (0x1E5A, 0x0052),
+    (0x1E5B, 0x0072), (0x1E5C, 0x0052), (0x1E5D, 0x0072), (0x1E5E, 0x0052), (0x1E5F, 0x0072),
+    (0x1E60, 0x0053), (0x1E61, 0x0073), (0x1E62, 0x0053), (0x1E63, 0x0073), (0x1E64, 0x0053),
+    (0x1E65, 0x0073), (0x1E66, 0x0053), (0x1E67, 0x0073), (0x1E68, 0x0053), (0x1E69, 0x0073),
+    (0x1E6A, 0x0054), (0x1E6B, 0x0074), (0x1E6C, 0x0054), (0x1E6D, 0x0074), (0x1E6E, 0x0054),
+    (0x1E6F, 0x0074), (0x1E70, 0x0054), (0x1E71, 0x0074), (0x1E72, 0x0055), (0x1E73, 0x0075),
+    (0x1E74, 0x0055), (0x1E75, 0x0075), (0x1E76, 0x0055), (0x1E77, 0x0075), (0x1E78, 0x0055),
+    (0x1E79, 0x0075), (0x1E7A, 0x0055), (0x1E7B, 0x0075), (0x1E7C, 0x0056), (0x1E7D, 0x0076),
+    (0x1E7E, 0x0056), (0x1E7F, 0x0076), (0x1E80, 0x0057), (0x1E81, 0x0077), (0x1E82, 0x0057),
+    (0x1E83, 0x0077), (0x1E84, 0x0057), (0x1E85, 0x0077), (0x1E86, 0x0057), (0x1E87, 0x0077),
+    (0x1E88, 0x0057), (0x1E89, 0x0077), (0x1E8A, 0x0058), (0x1E8B, 0x0078), (0x1E8C, 0x0058),
+    (0x1E8D, 0x0078), (0x1E8E, 0x0059), (0x1E8F, 0x0079), (0x1E90, 0x005A), (0x1E91, 0x007A),
+    (0x1E92, 0x005A), (0x1E93, 0x007A), (0x1E94, 0x005A), (0x1E95, 0x007A), (0x1E96, 0x0068),
+    (0x1E97, 0x0074), (0x1E98, 0x0077), (0x1E99, 0x0079), (0x1E9B, 0x0073), (0x1EA0, 0x0041),
+    (0x1EA1, 0x0061), (0x1EA2, 0x0041), (0x1EA3, 0x0061), (0x1EA4, 0x0041), (0x1EA5, 0x0061),
+    (0x1EA6, 0x0041), (0x1EA7, 0x0061), (0x1EA8, 0x0041), (0x1EA9, 0x0061), (0x1EAA, 0x0041),
+    (0x1EAB, 0x0061), (0x1EAC, 0x0041), (0x1EAD, 0x0061), (0x1EAE, 0x0041), (0x1EAF, 0x0061),
+    (0x1EB0, 0x0041), (0x1EB1, 0x0061), (0x1EB2, 0x0041), (0x1EB3, 0x0061), (0x1EB4, 0x0041),
+    (0x1EB5, 0x0061), (0x1EB6, 0x0041), (0x1EB7, 0x0061), (0x1EB8, 0x0045), (0x1EB9, 0x0065),
+    (0x1EBA, 0x0045), (0x1EBB, 0x0065), (0x1EBC, 0x0045), (0x1EBD, 0x0065), (0x1EBE, 0x0045),
+    (0x1EBF, 0x0065), (0x1EC0, 0x0045), (0x1EC1, 0x0065), (0x1EC2, 0x0045), (0x1EC3, 0x0065),
+    (0x1EC4, 0x0045), (0x1EC5, 0x0065), (0x1EC6, 0x0045), (0x1EC7, 0x0065), (0x1EC8, 0x0049),
+    (0x1EC9, 0x0069), (0x1ECA, 0x0049), (0x1ECB, 0x0069), (0x1ECC, 0x004F), (0x1ECD, 0x006F),
+    (0x1ECE, 0x004F), (0x1ECF, 0x006F), (0x1ED0, 0x004F), (0x1ED1, 0x006F), (0x1ED2, 0x004F),
+    (0x1ED3, 0x006F), (0x1ED4, 0x004F), (0x1ED5, 0x006F), (0x1ED6, 0x004F), (0x1ED7, 0x006F),
+    (0x1ED8, 0x004F), (0x1ED9, 0x006F), (0x1EDA, 0x004F), (0x1EDB, 0x006F), (0x1EDC, 0x004F),
+    (0x1EDD, 0x006F), (0x1EDE, 0x004F), (0x1EDF, 0x006F), (0x1EE0, 0x004F), (0x1EE1, 0x006F),
+    (0x1EE2, 0x004F), (0x1EE3, 0x006F), (0x1EE4, 0x0055), (0x1EE5, 0x0075), (0x1EE6, 0x0055),
+    (0x1EE7, 0x0075), (0x1EE8, 0x0055), (0x1EE9, 0x0075), (0x1EEA, 0x0055), (0x1EEB, 0x0075),
+    (0x1EEC, 0x0055), (0x1EED, 0x0075), (0x1EEE, 0x0055), (0x1EEF, 0x0075), (0x1EF0, 0x0055),
+    (0x1EF1, 0x0075), (0x1EF2, 0x0059), (0x1EF3, 0x0079), (0x1EF4, 0x0059), (0x1EF5, 0x0079),
+    (0x1EF6, 0x0059), (0x1EF7, 0x0079), (0x1EF8, 0x0059), (0x1EF9, 0x0079), (0x1FEF, 0x0060),
+    (0x2000, 0x0020), (0x2001, 0x0020), (0x2002, 0x0020), (0x2003, 0x0020), (0x2004, 0x0020),
+    (0x2005, 0x0020), (0x2006, 0x0020), (0x2007, 0x0020), (0x2008, 0x0020), (0x2009, 0x0020),
+    (0x200A, 0x0020), (0x2010, 0x002D), (0x2012, 0x002D), (0x2013, 0x002D), (0x2014, 0x002D),
+    (0x2015, 0x002D), (0x2018, 0x0027), (0x2019, 0x0027), (0x201A, 0x002C), (0x201B, 0x0027),
+    (0x201C, 0x0022), (0x201D, 0x0022), (0x201E, 0x0022), (0x201F, 0x0022), (0x2024, 0x002E),
+    (0x202F, 0x0020), (0x2032, 0x0027), (0x2035, 0x0060), (0x2039, 0x003C), (0x203A, 0x003E),
+    (0x205F, 0x0020), (0x2070, 0x0030), (0x2071, 0x0069), (0x2074, 0x0034), (0x2075, 0x0035),
+    (0x2076, 0x0036), (0x2077, 0x0037), (0x2078, 0x0038), (0x2079, 0x0039), (0x207A, 0x002B),
+    (0x207C, 0x003D), (0x207D, 0x0028), (0x207E, 0x0029), (0x2080, 0x0030), (0x2081, 0x0031),
+    (0x2082, 0x0032), (0x2083, 0x0033), (0x2084, 0x0034), (0x2085, 0x0035), (0x2086, 0x0036),
+    (0x2087, 0x0037), (0x2088, 0x0038), (0x2089, 0x0039), (0x208A, 0x002B), (0x208C, 0x003D),
+    (0x208D, 0x0028), (0x208E, 0x0029), (0x2090, 0x0061), (0x2091, 0x0065), (0x2092, 0x006F),
+    (0x2093, 0x0078), (0x2095, 0x0068), (0x2096, 0x006B), (0x2097, 0x006C), (0x2098, 0x006D),
+    (0x2099, 0x006E), (0x209A, 0x0070), (0x209B, 0x0073), (0x209C, 0x0074), (0x2102, 0x0043),
+    (0x210A, 0x0067), (0x210B, 0x0048), (0x210C, 0x0048), (0x210D, 0x0048), (0x210E, 0x0068),
+    (0x2110, 0x0049), (0x2111, 0x0049), (0x2112, 0x004C), (0x2113, 0x006C), (0x2115, 0x004E),
+    (0x2119, 0x0050), (0x211A, 0x0051), (0x211B, 0x0052), (0x211C, 0x0052), (0x211D, 0x0052),
+    (0x2124, 0x005A), (0x2128, 0x005A), (0x212A, 0x004B), (0x212B, 0x0041), (0x212C, 0x0042),
+    (0x212D, 0x0043), (0x212F, 0x0065), (0x2130, 0x0045), (0x2131, 0x0046), (0x2133, 0x004D),
+    (0x2134, 0x006F), (0x2139, 0x0069), (0x2145, 0x0044), (0x2146, 0x0064), (0x2147, 0x0065),
+    (0x2148, 0x0069), (0x2149, 0x006A), (0x2160, 0x0049), (0x2164, 0x0056), (0x2169, 0x0058),
+    (0x216C, 0x004C), (0x216D, 0x0043), (0x216E, 0x0044), (0x216F, 0x004D), (0x2170, 0x0069),
+    (0x2174, 0x0076), (0x2179, 0x0078), (0x217C, 0x006C), (0x217D, 0x0063), (0x217E, 0x0064),
+    (0x217F, 0x006D), (0x2212, 0x002D), (0x2215, 0x002F), (0x2216, 0x005C), (0x2223, 0x007C),
+    (0x223C, 0x007E), (0x2249, 0x00F7), (0x2260, 0x003D), (0x2262, 0x00F0), (0x226E, 0x003C),
+    (0x226F, 0x003E), (0x2460, 0x0031), (0x2461, 0x0032), (0x2462, 0x0033), (0x2463, 0x0034),
+    (0x2464, 0x0035), (0x2465, 0x0036), (0x2466, 0x0037), (0x2467, 0x0038), (0x2468, 0x0039),
+    (0x24B6, 0x0041), (0x24B7, 0x0042), (0x24B8, 0x0043), (0x24B9, 0x0044), (0x24BA, 0x0045),
+    (0x24BB, 0x0046), (0x24BC, 0x0047), (0x24BD, 0x0048), (0x24BE, 0x0049), (0x24BF, 0x004A),
+    (0x24C0, 0x004B), (0x24C1, 0x004C), (0x24C2, 0x004D), (0x24C3, 0x004E), (0x24C4, 0x004F),
+    (0x24C5, 0x0050), (0x24C6, 0x0051), (0x24C7, 0x0052), (0x24C8, 0x0053), (0x24C9, 0x0054),
+    (0x24CA, 0x0055), (0x24CB, 0x0056), (0x24CC, 0x0057), (0x24CD, 0x0058), (0x24CE, 0x0059),
+    (0x24CF, 0x005A), (0x24D0, 0x0061), (0x24D1, 0x0062), (0x24D2, 0x0063), (0x24D3, 0x0064),
+    (0x24D4, 0x0065), (0x24D5, 0x0066), (0x24D6, 0x0067), (0x24D7, 0x0068), (0x24D8, 0x0069),
+    (0x24D9, 0x006A), (0x24DA, 0x006B), (0x24DB, 0x006C), (0x24DC, 0x006D), (0x24DD, 0x006E),
+    (0x24DE, 0x006F), (0x24DF, 0x0070), (0x24E0, 0x0071), (0x24E1, 0x0072), (0x24E2, 0x0073),
+    (0x24E3, 0x0074), (0x24E4, 0x0075), (0x24E5, 0x0076), (0x24E6, 0x0077), (0x24E7, 0x0078),
+    (0x24E8, 0x0079), (0x24E9, 0x007A), (0x24EA, 0x0030), (0x2C7C, 0x006A), (0x2C7D, 0x0056),
+    (0x3000, 0x0020), (0xA7F2, 0x0043), (0xA7F3, 0x0046), (0xA7F4, 0x0051), (0xFB29, 0x002B),
+    (0xFBE8, 0x00EE), (0xFBE9, 0x00EE), (0xFC5B, 0x00A8), (0xFC5C, 0x00A9), (0xFC5D, 0x00EE),
+    (0xFC90, 0x00EE), (0xFCD9, 0x00EC), (0xFCF2, 0x0095), (0xFCF3, 0x0095), (0xFCF4, 0x0095),
+    (0xFD3C, 0x009F), (0xFD3D, 0x009F), (0xFE10, 0x002C), (0xFE13, 0x003A), (0xFE14, 0x003B),
+    (0xFE15, 0x0021), (0xFE16, 0x003F), (0xFE33, 0x005F), (0xFE34, 0x005F), (0xFE35, 0x0028),
+    (0xFE36, 0x0029), (0xFE37, 0x007B), (0xFE38, 0x007D), (0xFE47, 0x005B), (0xFE48, 0x005D),
+    (0xFE4D, 0x005F), (0xFE4E, 0x005F), (0xFE4F, 0x005F), (0xFE50, 0x002C), (0xFE52, 0x002E),
+    (0xFE54, 0x003B), (0xFE55, 0x003A), (0xFE56, 0x003F), (0xFE57, 0x0021), (0xFE59, 0x0028),
+    (0xFE5A, 0x0029), (0xFE5B, 0x007B), (0xFE5C, 0x007D), (0xFE5F, 0x0023), (0xFE60, 0x0026),
+    (0xFE61, 0x002A), (0xFE62, 0x002B), (0xFE63, 0x002D), (0xFE64, 0x003C), (0xFE65, 0x003E),
+    (0xFE66, 0x003D), (0xFE68, 0x005C), (0xFE69, 0x0024), (0xFE6A, 0x0025), (0xFE6B, 0x0040),
+    (0xFE71, 0x0095), (0xFE77, 0x0095), (0xFE79, 0x0095), (0xFE7B, 0x0095), (0xFE7D, 0x0095),
+    (0xFE7F, 0x0095), (0xFE80, 0x0098), (0xFE81, 0x009F), (0xFE82, 0x009F), (0xFE83, 0x009F),
+    (0xFE84, 0x009F), (0xFE85, 0x00ED), (0xFE86, 0x00ED), (0xFE87, 0x009F), (0xFE88, 0x009F),
+    (0xFE89, 0x00EF), (0xFE8A, 0x00EF), (0xFE8B, 0x00EF), (0xFE8C, 0x00EF), (0xFE8D, 0x009F),
+    (0xFE8E, 0x009F), (0xFE8F, 0x00A0), (0xFE90, 0x00A0), (0xFE91, 0x00A0), (0xFE92, 0x00A0),
+    (0xFE93, 0x00A1), (0xFE94, 0x00A1), (0xFE95, 0x00A2), (0xFE96, 0x00A2), (0xFE97, 0x00A2),
+    (0xFE98, 0x00A2), (0xFE99, 0x00A3), (0xFE9A, 0x00A3), (0xFE9B, 0x00A3), (0xFE9C, 0x00A3),
+    (0xFE9D, 0x00A4), (0xFE9E, 0x00A4), (0xFE9F, 0x00A4), (0xFEA0, 0x00A4), (0xFEA1, 0x00A5),
+    (0xFEA2, 0x00A5), (0xFEA3, 0x00A5), (0xFEA4, 0x00A5), (0xFEA5, 0x00A6), (0xFEA6, 0x00A6),
+    (0xFEA7, 0x00A6), (0xFEA8, 0x00A6), (0xFEA9, 0x00A7), (0xFEAA, 0x00A7), (0xFEAB, 0x00A8),
+    (0xFEAC, 0x00A8), (0xFEAD, 0x00A9), (0xFEAE, 0x00A9), (0xFEAF, 0x00AA), (0xFEB0, 0x00AA),
+    (0xFEB1, 0x00AB), (0xFEB2, 0x00AB), (0xFEB3, 0x00AB), (0xFEB4, 0x00AB), (0xFEB5, 0x00AC),
+    (0xFEB6, 0x00AC), (0xFEB7, 0x00AC), (0xFEB8, 0x00AC), (0xFEB9, 0x00AD), (0xFEBA, 0x00AD),
+    (0xFEBB, 0x00AD), (0xFEBC, 0x00AD), (0xFEBD, 0x00E0), (0xFEBE, 0x00E0), (0xFEBF, 0x00E0),
+    (0xFEC0, 0x00E0), (0xFEC1, 0x00E1), (0xFEC2, 0x00E1), (0xFEC3, 0x00E1), (0xFEC4, 0x00E1),
+    (0xFEC5, 0x00E2), (0xFEC6, 0x00E2), (0xFEC7, 0x00E2), (0xFEC8, 0x00E2), (0xFEC9, 0x00E3),
+    (0xFECA, 0x00E3), (0xFECB, 0x00E3), (0xFECC, 0x00E3), (0xFECD, 0x00E4), (0xFECE, 0x00E4),
+    (0xFECF, 0x00E4), (0xFED0, 0x00E4), (0xFED1, 0x00E5), (0xFED2, 0x00E5), (0xFED3, 0x00E5),
+    (0xFED4, 0x00E5), (0xFED5, 0x00E7), (0xFED6, 0x00E7), (0xFED7, 0x00E7), (0xFED8, 0x00E7),
+    (0xFED9, 0x00E8), (0xFEDA, 0x00E8), (0xFEDB, 0x00E8), (0xFEDC, 0x00E8), (0xFEDD, 0x00E9),
+    (0xFEDE, 0x00E9), (0xFEDF, 0x00E9), (0xFEE0, 0x00E9), (0xFEE1, 0x00EA), (0xFEE2, 0x00EA),
+    (0xFEE3, 0x00EA), (0xFEE4, 0x00EA), (0xFEE5, 0x00EB), (0xFEE6, 0x00EB), (0xFEE7, 0x00EB),
+    (0xFEE8, 0x00EB), (0xFEE9, 0x00EC), (0xFEEA, 0x00EC), (0xFEEB, 0x00EC), (0xFEEC, 0x00EC),
+    (0xFEED, 0x00ED), (0xFEEE, 0x00ED), (0xFEEF, 0x00EE), (0xFEF0, 0x00EE), (0xFEF1, 0x00EF),
+    (0xFEF2, 0x00EF), (0xFEF3, 0x00EF), (0xFEF4, 0x00EF), (0xFF01, 0x0021), (0xFF02, 0x0022),
+    (0xFF03, 0x0023), (0xFF04, 0x0024), (0xFF05, 0x0025), (0xFF06, 0x0026), (0xFF07, 0x0027),
+    (0xFF08, 0x0028), (0xFF09, 0x0029), (0xFF0A, 0x002A), (0xFF0B, 0x002B), (0xFF0C, 0x002C),
+    (0xFF0D, 0x002D), (0xFF0E, 0x002E), (0xFF0F, 0x002F), (0xFF10, 0x0030), (0xFF11, 0x0031),
+    (0xFF12, 0x0032), (0xFF13, 0x0033), (0xFF14, 0x0034), (0xFF15, 0x0035), (0xFF16, 0x0036),
+    (0xFF17, 0x0037), (0xFF18, 0x0038), (0xFF19, 0x0039), (0xFF1A, 0x003A), (0xFF1B, 0x003B),
+    (0xFF1C, 0x003C), (0xFF1D, 0x003D), (0xFF1E, 0x003E), (0xFF1F, 0x003F), (0xFF20, 0x0040),
+    (0xFF21, 0x0041), (0xFF22, 0x0042), (0xFF23, 0x0043), (0xFF24, 0x0044), (0xFF25, 0x0045),
+    (0xFF26, 0x0046), (0xFF27, 0x0047), (0xFF28, 0x0048), (0xFF29, 0x0049), (0xFF2A, 0x004A),
+    (0xFF2B, 0x004B), (0xFF2C, 0x004C), (0xFF2D, 0x004D), (0xFF2E, 0x004E), (0xFF2F, 0x004F),
+    (0xFF30, 0x0050), (0xFF31, 0x0051), (0xFF32, 0x0052), (0xFF33, 0x0053), (0xFF34, 0x0054),
+    (0xFF35, 0x0055), (0xFF36, 0x0056), (0xFF37, 0x0057), (0xFF38, 0x0058), (0xFF39, 0x0059),
+    (0xFF3A, 0x005A), (0xFF3B, 0x005B), (0xFF3C, 0x005C), (0xFF3D, 0x005D), (0xFF3E, 0x005E),
+    (0xFF3F, 0x005F), (0xFF40, 0x0060), (0xFF41, 0x0061), (0xFF42, 0x0062), (0xFF43, 0x0063),
+    (0xFF44, 0x0064), (0xFF45, 0x0065), (0xFF46, 0x0066), (0xFF47, 0x0067), (0xFF48, 0x0068),
+    (0xFF49, 0x0069), (0xFF4A, 0x006A), (0xFF4B, 0x006B), (0xFF4C, 0x006C), (0xFF4D, 0x006D),
+    (0xFF4E, 0x006E), (0xFF4F, 0x006F), (0xFF50, 0x0070), (0xFF51, 0x0071), (0xFF52, 0x0072),
+    (0xFF53, 0x0073), (0xFF54, 0x0074), (0xFF55, 0x0075), (0xFF56, 0x0076), (0xFF57, 0x0077),
+    (0xFF58, 0x0078), (0xFF59, 0x0079), (0xFF5A, 0x007A), (0xFF5B, 0x007B), (0xFF5C, 0x007C),
+    (0xFF5D, 0x007D), (0xFF5E, 0x007E), (0xFFE1, 0x009C), (0xFFE8, 0x00B3), (0xFFED, 0x00FE),
+];
+
+/// Code page 737.
+#[rustfmt::skip]
+pub(crate) const CP737: [(u16, u16); 1124] = [
+    (0x00AA, 0x0061), (0x00AB, 0x003C), (0x00B3, 0x0033), (0x00B5, 0x00A3), (0x00B9, 0x0031),
+    (0x00BA, 0x006F), (0x00BB, 0x003E), (0x00C0, 0x0041), (0x00C1, 0x0041), (0x00C2, 0x0041),
+    (0x00C3, 0x0041), (0x00C4, 0x0041), (0x00C5, 0x0041), (0x00C7, 0x0043), (0x00C8, 0x0045),
+    (0x00C9, 0x0045), (0x00CA, 0x0045), (0x00CB, 0x0045), (0x00CC, 0x0049), (0x00CD, 0x0049),
+    (0x00CE, 0x0049), (0x00CF, 0x0049), (0x00D1, 0x004E), (0x00D2, 0x004F), (0x00D3, 0x004F),
+    (0x00D4, 0x004F), (0x00D5, 0x004F), (0x00D6, 0x004F), (0x00D9, 0x0055), (0x00DA, 0x0055),
+    (0x00DB, 0x0055), (0x00DC, 0x0055), (0x00DD, 0x0059), (0x00E0, 0x0061), (0x00E1, 0x0061),
+    (0x00E2, 0x0061), (0x00E3, 0x0061), (0x00E4, 0x0061), (0x00E5, 0x0061), (0x00E7, 0x0063),
+    (0x00E8, 0x0065), (0x00E9, 0x0065), (0x00EA, 0x0065), (0x00EB, 0x0065), (0x00EC, 0x0069),
+    (0x00ED, 0x0069), (0x00EE, 0x0069), (0x00EF, 0x0069), (0x00F1, 0x006E), (0x00F2, 0x006F),
+    (0x00F3, 0x006F), (0x00F4, 0x006F), (0x00F5, 0x006F), (0x00F6, 0x006F), (0x00F9, 0x0075),
+    (0x00FA, 0x0075), (0x00FB, 0x0075), (0x00FC, 0x0075), (0x00FD, 0x0079), (0x00FF, 0x0079),
+    (0x0100, 0x0041), (0x0101, 0x0061), (0x0102, 0x0041), (0x0103, 0x0061), (0x0104, 0x0041),
+    (0x0105, 0x0061), (0x0106, 0x0043), (0x0107, 0x0063), (0x0108, 0x0043), (0x0109, 0x0063),
+    (0x010A, 0x0043), (0x010B, 0x0063), (0x010C, 0x0043), (0x010D, 0x0063), (0x010E, 0x0044),
+    (0x010F, 0x0064), (0x0112, 0x0045), (0x0113, 0x0065), (0x0114, 0x0045), (0x0115, 0x0065),
+    (0x0116, 0x0045), (0x0117, 0x0065), (0x0118, 0x0045), (0x0119, 0x0065), (0x011A, 0x0045),
+    (0x011B, 0x0065), (0x011C, 0x0047), (0x011D, 0x0067), (0x011E, 0x0047), (0x011F, 0x0067),
+    (0x0120, 0x0047), (0x0121, 0x0067), (0x0122, 0x0047), (0x0123, 0x0067), (0x0124, 0x0048),
+    (0x0125, 0x0068), (0x0128, 0x0049), (0x0129, 0x0069), (0x012A, 0x0049), (0x012B, 0x0069),
+    (0x012C, 0x0049), (0x012D, 0x0069), (0x012E, 0x0049), (0x012F, 0x0069), (0x0130, 0x0049),
+    (0x0134, 0x004A), (0x0135, 0x006A), (0x0136, 0x004B), (0x0137, 0x006B), (0x0139, 0x004C),
+    (0x013A, 0x006C), (0x013B, 0x004C), (0x013C, 0x006C), (0x013D, 0x004C), (0x013E, 0x006C),
+    (0x0143, 0x004E), (0x0144, 0x006E), (0x0145, 0x004E), (0x0146, 0x006E), (0x0147, 0x004E),
+    (0x0148, 0x006E), (0x014C, 0x004F), (0x014D, 0x006F), (0x014E, 0x004F), (0x014F, 0x006F),
+    (0x0150, 0x004F), (0x0151, 0x006F), (0x0154, 0x0052), (0x0155, 0x0072), (0x0156, 0x0052),
+    (0x0157, 0x0072), (0x0158, 0x0052), (0x0159, 0x0072), (0x015A, 0x0053), (0x015B, 0x0073),
+    (0x015C, 0x0053), (0x015D, 0x0073), (0x015E, 0x0053), (0x015F, 0x0073), (0x0160, 0x0053),
+    (0x0161, 0x0073), (0x0162, 0x0054), (0x0163, 0x0074), (0x0164, 0x0054), (0x0165, 0x0074),
+    (0x0168, 0x0055), (0x0169, 0x0075), (0x016A, 0x0055), (0x016B, 0x0075), (0x016C, 0x0055),
+    (0x016D, 0x0075), (0x016E, 0x0055), (0x016F, 0x0075), (0x0170, 0x0055), (0x0171, 0x0075),
+    (0x0172, 0x0055), (0x0173, 0x0075), (0x0174, 0x0057), (0x0175, 0x0077), (0x0176, 0x0059),
+    (0x0177, 0x0079), (0x0178, 0x0059), (0x0179, 0x005A), (0x017A, 0x007A), (0x017B, 0x005A),
+    (0x017C, 0x007A), (0x017D, 0x005A), (0x017E, 0x007A), (0x017F, 0x0073), (0x01A0, 0x004F),
+    (0x01A1, 0x006F), (0x01AF, 0x0055), (0x01B0, 0x0075), (0x01CD, 0x0041), (0x01CE, 0x0061),
+    (0x01CF, 0x0049), (0x01D0, 0x0069), (0x01D1, 0x004F), (0x01D2, 0x006F), (0x01D3, 0x0055),
+    (0x01D4, 0x0075), (0x01D5, 0x0055), (0x01D6, 0x0075), (0x01D7, 0x0055), (0x01D8, 0x0075),
+    (0x01D9, 0x0055), (0x01DA, 0x0075), (0x01DB, 0x0055), (0x01DC, 0x0075), (0x01DE, 0x0041),
+    (0x01DF, 0x0061), (0x01E0, 0x0041), (0x01E1, 0x0061), (0x01E6, 0x0047), (0x01E7, 0x0067),
+    (0x01E8, 0x004B), (0x01E9, 0x006B), (0x01EA, 0x004F), (0x01EB, 0x006F), (0x01EC, 0x004F),
+    (0x01ED, 0x006F), (0x01F0, 0x006A), (0x01F4, 0x0047), (0x01F5, 0x0067), (0x01F8, 0x004E),
+    (0x01F9, 0x006E), (0x01FA, 0x0041), (0x01FB, 0x0061), (0x0200, 0x0041), (0x0201, 0x0061),
+    (0x0202, 0x0041), (0x0203, 0x0061), (0x0204, 0x0045), (0x0205, 0x0065), (0x0206, 0x0045),
+    (0x0207, 0x0065), (0x0208, 0x0049), (0x0209, 0x0069), (0x020A, 0x0049), (0x020B, 0x0069),
+    (0x020C, 0x004F), (0x020D, 0x006F), (0x020E, 0x004F), (0x020F, 0x006F), (0x0210, 0x0052),
+    (0x0211, 0x0072), (0x0212, 0x0052), (0x0213, 0x0072), (0x0214, 0x0055), (0x0215, 0x0075),
+    (0x0216, 0x0055), (0x0217, 0x0075), (0x0218, 0x0053), (0x0219, 0x0073), (0x021A, 0x0054),
+    (0x021B, 0x0074), (0x021E, 0x0048), (0x021F, 0x0068), (0x0226, 0x0041), (0x0227, 0x0061),
+    (0x0228, 0x0045), (0x0229, 0x0065), (0x022A, 0x004F), (0x022B, 0x006F), (0x022C, 0x004F),
+    (0x022D, 0x006F), (0x022E, 0x004F), (0x022F, 0x006F), (0x0230, 0x004F), (0x0231, 0x006F),
+    (0x0232, 0x0059), (0x0233, 0x0079), (0x02B0, 0x0068), (0x02B2, 0x006A), (0x02B3, 0x0072),
+    (0x02B7, 0x0077), (0x02B8, 0x0079), (0x02E1, 0x006C), (0x02E2, 0x0073), (0x02E3, 0x0078),
+    (0x037E, 0x003B), (0x0387, 0x00FA), (0x0390, 0x00A0), (0x03B0, 0x00AC), (0x03D0, 0x0099),
+    (0x03D1, 0x009F), (0x03D2, 0x0093), (0x03D3, 0x0093), (0x03D4, 0x0093), (0x03D5, 0x00AD),
+    (0x03D6, 0x00A7), (0x03F0, 0x00A1), (0x03F1, 0x00A8), (0x03F2, 0x00AA), (0x03F4, 0x0087),
+    (0x03F5, 0x009C), (0x03F9, 0x0091), (0x1D2C, 0x0041), (0x1D2E, 0x0042), (0x1D30, 0x0044),
+    (0x1D31, 0x0045), (0x1D33, 0x0047), (0x1D34, 0x0048), (0x1D35, 0x0049), (0x1D36, 0x004A),
+    (0x1D37, 0x004B), (0x1D38, 0x004C), (0x1D39, 0x004D), (0x1D3A, 0x004E), (0x1D3C, 0x004F),
+    (0x1D3E, 0x0050), (0x1D3F, 0x0052), (0x1D40, 0x0054), (0x1D41, 0x0055), (0x1D42, 0x0057),
+    (0x1D43, 0x0061), (0x1D47, 0x0062), (0x1D48, 0x0064), (0x1D49, 0x0065), (0x1D4D, 0x0067),
+    (0x1D4F, 0x006B), (0x1D50, 0x006D), (0x1D52, 0x006F), (0x1D56, 0x0070), (0x1D57, 0x0074),
+    (0x1D58, 0x0075), (0x1D5B, 0x0076), (0x1D5D, 0x0099), (0x1D5E, 0x009A), (0x1D5F, 0x009B),
+    (0x1D60, 0x00AD), (0x1D61, 0x00AE), (0x1D62, 0x0069), (0x1D63, 0x0072), (0x1D64, 0x0075),
+    (0x1D65, 0x0076), (0x1D66, 0x0099), (0x1D67, 0x009A), (0x1D68, 0x00A8), (0x1D69, 0x00AD),
+    (0x1D6A, 0x00AE), (0x1D9C, 0x0063), (0x1DA0, 0x0066), (0x1DBB, 0x007A), (0x1DBF, 0x009F),
+    (0x1E00, 0x0041), (0x1E01, 0x0061), (0x1E02, 0x0042), (0x1E03, 0x0062), (0x1E04, 0x0042),
+    (0x1E05, 0x0062), (0x1E06, 0x0042), (0x1E07, 0x0062), (0x1E08, 0x0043), (0x1E09, 0x0063),
+    (0x1E0A, 0x0044), (0x1E0B, 0x0064), (0x1E0C, 0x0044), (0x1E0D, 0x0064), (0x1E0E, 0x0044),
+    (0x1E0F, 0x0064), (0x1E10, 0x0044), (0x1E11, 0x0064), (0x1E12, 0x0044), (0x1E13, 0x0064),
+    (0x1E14, 0x0045), (0x1E15, 0x0065), (0x1E16, 0x0045), (0x1E17, 0x0065), (0x1E18, 0x0045),
+    (0x1E19, 0x0065), (0x1E1A, 0x0045), (0x1E1B, 0x0065), (0x1E1C, 0x0045), (0x1E1D, 0x0065),
+    (0x1E1E, 0x0046), (0x1E1F, 0x0066), (0x1E20, 0x0047), (0x1E21, 0x0067), (0x1E22, 0x0048),
+    (0x1E23, 0x0068), (0x1E24, 0x0048), (0x1E25, 0x0068), (0x1E26, 0x0048), (0x1E27, 0x0068),
+    (0x1E28, 0x0048), (0x1E29, 0x0068), (0x1E2A, 0x0048), (0x1E2B, 0x0068), (0x1E2C, 0x0049),
+    (0x1E2D, 0x0069), (0x1E2E, 0x0049), (0x1E2F, 0x0069), (0x1E30, 0x004B), (0x1E31, 0x006B),
+    (0x1E32, 0x004B), (0x1E33, 0x006B), (0x1E34, 0x004B), (0x1E35, 0x006B), (0x1E36, 0x004C),
+    (0x1E37, 0x006C), (0x1E38, 0x004C), (0x1E39, 0x006C), (0x1E3A, 0x004C), (0x1E3B, 0x006C),
+    (0x1E3C, 0x004C), (0x1E3D, 0x006C), (0x1E3E, 0x004D), (0x1E3F, 0x006D), (0x1E40, 0x004D),
+    (0x1E41, 0x006D), (0x1E42, 0x004D), (0x1E43, 0x006D), (0x1E44, 0x004E), (0x1E45, 0x006E),
+    (0x1E46, 0x004E), (0x1E47, 0x006E), (0x1E48, 0x004E), (0x1E49, 0x006E), (0x1E4A, 0x004E),
+    (0x1E4B, 0x006E), (0x1E4C, 0x004F), (0x1E4D, 0x006F), (0x1E4E, 0x004F), (0x1E4F, 0x006F),
+    (0x1E50, 0x004F), (0x1E51, 0x006F), (0x1E52, 0x004F), (0x1E53, 0x006F), (0x1E54, 0x0050),
+    (0x1E55, 0x0070), (0x1E56, 0x0050), (0x1E57, 0x0070), (0x1E58, 0x0052), (0x1E59, 0x0072),
+    (0x1E5A, 0x0052), (0x1E5B, 0x0072), (0x1E5C, 0x0052), (0x1E5D, 0x0072), (0x1E5E, 0x0052),
+    (0x1E5F, 0x0072), (0x1E60, 0x0053), (0x1E61, 0x0073), (0x1E62, 0x0053), (0x1E63, 0x0073),
+    (0x1E64, 0x0053), (0x1E65, 0x0073), (0x1E66, 0x0053), (0x1E67, 0x0073), (0x1E68, 0x0053),
+    (0x1E69, 0x0073), (0x1E6A, 0x0054), (0x1E6B, 0x0074), (0x1E6C, 0x0054), (0x1E6D, 0x0074),
+    (0x1E6E, 0x0054), (0x1E6F, 0x0074), (0x1E70, 0x0054), (0x1E71, 0x0074), (0x1E72, 0x0055),
+    (0x1E73, 0x0075), (0x1E74, 0x0055), (0x1E75, 0x0075), (0x1E76, 0x0055), (0x1E77, 0x0075),
+    (0x1E78, 0x0055), (0x1E79, 0x0075), (0x1E7A, 0x0055), (0x1E7B, 0x0075), (0x1E7C, 0x0056),
+    (0x1E7D, 0x0076), (0x1E7E, 0x0056), (0x1E7F, 0x0076), (0x1E80, 0x0057), (0x1E81, 0x0077),
+    (0x1E82, 0x0057), (0x1E83, 0x0077), (0x1E84, 0x0057), (0x1E85, 0x0077), (0x1E86, 0x0057),
+    (0x1E87, 0x0077), (0x1E88, 0x0057), (0x1E89, 0x0077), (0x1E8A, 0x0058), (0x1E8B, 0x0078),
+    (0x1E8C, 0x0058), (0x1E8D, 0x0078), (0x1E8E, 0x0059), (0x1E8F, 0x0079), (0x1E90, 0x005A),
+    (0x1E91, 0x007A), (0x1E92, 0x005A), (0x1E93, 0x007A), (0x1E94, 0x005A), (0x1E95, 0x007A),
+    (0x1E96, 0x0068), (0x1E97, 0x0074), (0x1E98, 0x0077), (0x1E99, 0x0079), (0x1E9B, 0x0073),
+    (0x1EA0, 0x0041), (0x1EA1, 0x0061), (0x1EA2, 0x0041), (0x1EA3, 0x0061), (0x1EA4, 0x0041),
+    (0x1EA5, 0x0061), (0x1EA6, 0x0041), (0x1EA7, 0x0061), (0x1EA8, 0x0041), (0x1EA9, 0x0061),
+    (0x1EAA, 0x0041), (0x1EAB, 0x0061), (0x1EAC, 0x0041), (0x1EAD, 0x0061), (0x1EAE, 0x0041),
+    (0x1EAF, 0x0061), (0x1EB0, 0x0041), (0x1EB1, 0x0061), (0x1EB2, 0x0041), (0x1EB3, 0x0061),
+    (0x1EB4, 0x0041), (0x1EB5, 0x0061), (0x1EB6, 0x0041), (0x1EB7, 0x0061), (0x1EB8, 0x0045),
+    (0x1EB9, 0x0065), (0x1EBA, 0x0045), (0x1EBB, 0x0065), (0x1EBC, 0x0045), (0x1EBD, 0x0065),
+    (0x1EBE, 0x0045), (0x1EBF, 0x0065), (0x1EC0, 0x0045), (0x1EC1, 0x0065), (0x1EC2, 0x0045),
+    (0x1EC3, 0x0065), (0x1EC4, 0x0045), (0x1EC5, 0x0065), (0x1EC6, 0x0045), (0x1EC7, 0x0065),
+    (0x1EC8, 0x0049), (0x1EC9, 0x0069), (0x1ECA, 0x0049), (0x1ECB, 0x0069), (0x1ECC, 0x004F),
+    (0x1ECD, 0x006F), (0x1ECE, 0x004F), (0x1ECF, 0x006F), (0x1ED0, 0x004F), (0x1ED1, 0x006F),
+    (0x1ED2, 0x004F), (0x1ED3, 0x006F), (0x1ED4, 0x004F), (0x1ED5, 0x006F), (0x1ED6, 0x004F),
+    (0x1ED7, 0x006F), (0x1ED8, 0x004F), (0x1ED9, 0x006F), (0x1EDA, 0x004F), (0x1EDB, 0x006F),
+    (0x1EDC, 0x004F), (0x1EDD, 0x006F), (0x1EDE, 0x004F), (0x1EDF, 0x006F), (0x1EE0, 0x004F),
+    (0x1EE1, 0x006F), (0x1EE2, 0x004F), (0x1EE3, 0x006F), (0x1EE4, 0x0055), (0x1EE5, 0x0075),
+    (0x1EE6, 0x0055), (0x1EE7, 0x0075), (0x1EE8, 0x0055), (0x1EE9, 0x0075), (0x1EEA, 0x0055),
+    (0x1EEB, 0x0075), (0x1EEC, 0x0055), (0x1EED, 0x0075), (0x1EEE, 0x0055), (0x1EEF, 0x0075),
+    (0x1EF0, 0x0055), (0x1EF1, 0x0075), (0x1EF2, 0x0059), (0x1EF3, 0x0079), (0x1EF4, 0x0059),
+    (0x1EF5, 0x0079), (0x1EF6, 0x0059), (0x1EF7, 0x0079), (0x1EF8, 0x0059), (0x1EF9, 0x0079),
+    (0x1F00, 0x0098), (0x1F01, 0x0098), (0x1F02, 0x0098), (0x1F03, 0x0098), (0x1F04, 0x0098),
+    (0x1F05, 0x0098), (0x1F06, 0x0098), (0x1F07, 0x0098), (0x1F08, 0x0080), (0x1F09, 0x0080),
+    (0x1F0A, 0x0080), (0x1F0B, 0x0080), (0x1F0C, 0x0080), (0x1F0D, 0x0080), (0x1F0E, 0x0080),
+    (0x1F0F, 0x0080), (0x1F10, 0x009C), (0x1F11, 0x009C), (0x1F12, 0x009C), (0x1F13, 0x009C),
+    (0x1F14, 0x009C), (0x1F15, 0x009C), (0x1F18, 0x0084), (0x1F19, 0x0084), (0x1F1A, 0x0084),
+    (0x1F1B, 0x0084), (0x1F1C, 0x0084), (0x1F1D, 0x0084), (0x1F20, 0x009E), (0x1F21, 0x009E),
+    (0x1F22, 0x009E), (0x1F23, 0x009E), (0x1F24, 0x009E), (0x1F25, 0x009E), (0x1F26, 0x009E),
+    (0x1F27, 0x009E), (0x1F28, 0x0086), (0x1F29, 0x0086), (0x1F2A, 0x0086), (0x1F2B, 0x0086),
+    (0x1F2C, 0x0086), (0x1F2D, 0x0086), (0x1F2E, 0x0086), (0x1F2F, 0x0086), (0x1F30, 0x00A0),
+    (0x1F31, 0x00A0), (0x1F32, 0x00A0), (0x1F33, 0x00A0), (0x1F34, 0x00A0), (0x1F35, 0x00A0),
+    (0x1F36, 0x00A0), (0x1F37, 0x00A0), (0x1F38, 0x0088), (0x1F39, 0x0088), (0x1F3A, 0x0088),
+    (0x1F3B, 0x0088), (0x1F3C, 0x0088), (0x1F3D, 0x0088), (0x1F3E, 0x0088), (0x1F3F, 0x0088),
+    (0x1F40, 0x00A6), (0x1F41, 0x00A6), (0x1F42, 0x00A6), (0x1F43, 0x00A6), (0x1F44, 0x00A6),
+    (0x1F45, 0x00A6), (0x1F48, 0x008E), (0x1F49, 0x008E), (0x1F4A, 0x008E), (0x1F4B, 0x008E),
+    (0x1F4C, 0x008E), (0x1F4D, 0x008E), (0x1F50, 0x00AC), (0x1F51, 0x00AC), (0x1F52, 0x00AC),
+    (0x1F53, 0x00AC), (0x1F54, 0x00AC), (0x1F55, 0x00AC), (0x1F56, 0x00AC), (0x1F57, 0x00AC),
+    (0x1F59, 0x0093), (0x1F5B, 0x0093), (0x1F5D, 0x0093), (0x1F5F, 0x0093), (0x1F60, 0x00E0),
+    (0x1F61, 0x00E0), (0x1F62, 0x00E0), (0x1F63, 0x00E0), (0x1F64, 0x00E0), (0x1F65, 0x00E0),
+    (0x1F66, 0x00E0), (0x1F67, 0x00E0), (0x1F68, 0x0097), (0x1F69, 0x0097), (0x1F6A, 0x0097),
+    (0x1F6B, 0x0097), (0x1F6C, 0x0097), (0x1F6D, 0x0097), (0x1F6E, 0x0097), (0x1F6F, 0x0097),
+    (0x1F70, 0x0098), (0x1F71, 0x0098), (0x1F72, 0x009C), (0x1F73, 0x009C), (0x1F74, 0x009E),
+    (0x1F75, 0x009E), (0x1F76, 0x00A0), (0x1F77, 0x00A0), (0x1F78, 0x00A6), (0x1F79, 0x00A6),
+    (0x1F7A, 0x00AC), (0x1F7B, 0x00AC), (0x1F7C, 0x00E0), (0x1F7D, 0x00E0), (0x1F80, 0x0098),
+    (0x1F81, 0x0098), (0x1F82, 0x0098), (0x1F83, 0x0098), (0x1F84, 0x0098), (0x1F85, 0x0098),
+    (0x1F86, 0x0098), (0x1F87, 0x0098), (0x1F88, 0x0080), (0x1F89, 0x0080), (0x1F8A, 0x0080),
+    (0x1F8B, 0x0080), (0x1F8C, 0x0080), (0x1F8D, 0x0080), (0x1F8E, 0x0080), (0x1F8F, 0x0080),
+    (0x1F90, 0x009E), (0x1F91, 0x009E), (0x1F92, 0x009E), (0x1F93, 0x009E), (0x1F94, 0x009E),
+    (0x1F95, 0x009E), (0x1F96, 0x009E), (0x1F97, 0x009E), (0x1F98, 0x0086), (0x1F99, 0x0086),
+    (0x1F9A, 0x0086), (0x1F9B, 0x0086), (0x1F9C, 0x0086), (0x1F9D, 0x0086), (0x1F9E, 0x0086),
+    (0x1F9F, 0x0086), (0x1FA0, 0x00E0), (0x1FA1, 0x00E0), (0x1FA2, 0x00E0), (0x1FA3, 0x00E0),
+    (0x1FA4, 0x00E0), (0x1FA5, 0x00E0), (0x1FA6, 0x00E0), (0x1FA7, 0x00E0), (0x1FA8, 0x0097),
+    (0x1FA9, 0x0097), (0x1FAA, 0x0097), (0x1FAB, 0x0097), (0x1FAC, 0x0097), (0x1FAD, 0x0097),
+    (0x1FAE, 0x0097), (0x1FAF, 0x0097), (0x1FB0, 0x0098), (0x1FB1, 0x0098), (0x1FB2, 0x0098),
+    (0x1FB3, 0x0098), (0x1FB4, 0x0098), (0x1FB6, 0x0098), (0x1FB7, 0x0098), (0x1FB8, 0x0080),
+    (0x1FB9, 0x0080), (0x1FBA, 0x0080), (0x1FBB, 0x0080), (0x1FBC, 0x0080), (0x1FBE, 0x00A0),
+    (0x1FC2, 0x009E), (0x1FC3, 0x009E), (0x1FC4, 0x009E), (0x1FC6, 0x009E), (0x1FC7, 0x009E),
+    (0x1FC8, 0x0084), (0x1FC9, 0x0084), (0x1FCA, 0x0086), (0x1FCB, 0x0086), (0x1FCC, 0x0086),
+    (0x1FD0, 0x00A0), (0x1FD1, 0x00A0), (0x1FD2, 0x00A0), (0x1FD3, 0x00A0), (0x1FD6, 0x00A0),
+    (0x1FD7, 0x00A0), (0x1FD8, 0x0088), (0x1FD9, 0x0088), (0x1FDA, 0x0088), (0x1FDB, 0x0088),
+    (0x1FE0, 0x00AC), (0x1FE1, 0x00AC), (0x1FE2, 0x00AC), (0x1FE3, 0x00AC), (0x1FE4, 0x00A8),
+    (0x1FE5, 0x00A8), (0x1FE6, 0x00AC), (0x1FE7, 0x00AC), (0x1FE8, 0x0093), (0x1FE9, 0x0093),
+    (0x1FEA, 0x0093), (0x1FEB, 0x0093), (0x1FEC, 0x0090), (0x1FEF, 0x0060), (0x1FF2, 0x00E0),
+    (0x1FF3, 0x00E0), (0x1FF4, 0x00E0), (0x1FF6, 0x00E0), (0x1FF7, 0x00E0), (0x1FF8, 0x008E),
+    (0x1FF9, 0x008E), (0x1FFA, 0x0097), (0x1FFB, 0x0097), (0x1FFC, 0x0097), (0x2000, 0x0020),
+    (0x2001, 0x0020), (0x2002, 0x0020), (0x2003, 0x0020), (0x2004, 0x0020), (0x2005, 0x0020),
+    (0x2006, 0x0020), (0x2007, 0x0020), (0x2008, 0x0020), (0x2009, 0x0020), (0x200A, 0x0020),
+    (0x2010, 0x002D), (0x2012, 0x002D), (0x2013, 0x002D), (0x2014, 0x002D), (0x2015, 0x002D),
+    (0x2018, 0x0027), (0x2019, 0x0027), (0x201A, 0x002C), (0x201B, 0x0027), (0x201C, 0x0022),
+    (0x201D, 0x0022), (0x201E, 0x0022), (0x201F, 0x0022), (0x2024, 0x002E), (0x202F, 0x0020),
+    (0x2032, 0x0027), (0x2035, 0x0060), (0x2039, 0x003C), (0x203A, 0x003E), (0x205F, 0x0020),
+    (0x2070, 0x0030), (0x2071, 0x0069), (0x2074, 0x0034), (0x2075, 0x0035), (0x2076, 0x0036),
+    (0x2077, 0x0037), (0x2078, 0x0038), (0x2079, 0x0039), (0x207A, 0x002B), (0x207C, 0x003D),
+    (0x207D, 0x0028), (0x207E, 0x0029), (0x2080, 0x0030), (0x2081, 0x0031), (0x2082, 0x0032),
+    (0x2083, 0x0033), (0x2084, 0x0034), (0x2085, 0x0035), (0x2086, 0x0036), (0x2087, 0x0037),
+    (0x2088, 0x0038), (0x2089, 0x0039), (0x208A, 0x002B), (0x208C, 0x003D), (0x208D, 0x0028),
+    (0x208E, 0x0029), (0x2090, 0x0061), (0x2091, 0x0065), (0x2092, 0x006F), (0x2093, 0x0078),
+    (0x2095, 0x0068), (0x2096, 0x006B), (0x2097, 0x006C), (0x2098, 0x006D), (0x2099, 0x006E),
+    (0x209A, 0x0070), (0x209B, 0x0073), (0x209C, 0x0074), (0x2102, 0x0043), (0x210A, 0x0067),
+    (0x210B, 0x0048), (0x210C, 0x0048), (0x210D, 0x0048), (0x210E, 0x0068), (0x2110, 0x0049),
+    (0x2111, 0x0049), (0x2112, 0x004C), (0x2113, 0x006C), (0x2115, 0x004E), (0x2119, 0x0050),
+    (0x211A, 0x0051), (0x211B, 0x0052), (0x211C, 0x0052), (0x211D, 0x0052), (0x2124, 0x005A),
+    (0x2126, 0x0097), (0x2128, 0x005A), (0x212A, 0x004B), (0x212B, 0x0041), (0x212C, 0x0042),
+    (0x212D, 0x0043), (0x212F, 0x0065), (0x2130, 0x0045), (0x2131, 0x0046), (0x2133, 0x004D),
+    (0x2134, 0x006F), (0x2139, 0x0069), (0x213C, 0x00A7), (0x213D, 0x009A), (0x213E, 0x0082),
+    (0x213F, 0x008F), (0x2145, 0x0044), (0x2146, 0x0064), (0x2147, 0x0065), (0x2148, 0x0069),
+    (0x2149, 0x006A), (0x2160, 0x0049), (0x2164, 0x0056), (0x2169, 0x0058), (0x216C, 0x004C),
+    (0x216D, 0x0043), (0x216E, 0x0044), (0x216F, 0x004D), (0x2170, 0x0069), (0x2174, 0x0076),
+    (0x2179, 0x0078), (0x217C, 0x006C), (0x217D, 0x0063), (0x217E, 0x0064), (0x217F, 0x006D),
+    (0x2212, 0x002D), (0x2215, 0x002F), (0x2216, 0x005C), (0x2223, 0x007C), (0x223C, 0x007E),
+    (0x2249, 0x00F7), (0x2260, 0x003D), (0x226E, 0x003C), (0x226F, 0x003E), (0x2270, 0x00F3),
+    (0x2271, 0x00F2), (0x2460, 0x0031), (0x2461, 0x0032), (0x2462, 0x0033), (0x2463, 0x0034),
+    (0x2464, 0x0035), (0x2465, 0x0036), (0x2466, 0x0037), (0x2467, 0x0038), (0x2468, 0x0039),
+    (0x24B6, 0x0041), (0x24B7, 0x0042), (0x24B8, 0x0043), (0x24B9, 0x0044), (0x24BA, 0x0045),
+    (0x24BB, 0x0046), (0x24BC, 0x0047), (0x24BD, 0x0048), (0x24BE, 0x0049), (0x24BF, 0x004A),
+    (0x24C0, 0x004B), (0x24C1, 0x004C), (0x24C2, 0x004D), (0x24C3, 0x004E), (0x24C4, 0x004F),
+    (0x24C5, 0x0050), (0x24C6, 0x0051), (0x24C7, 0x0052), (0x24C8, 0x0053), (0x24C9, 0x0054),
+    (0x24CA, 0x0055), (0x24CB, 0x0056), (0x24CC, 0x0057), (0x24CD, 0x0058), (0x24CE, 0x0059),
+    (0x24CF, 0x005A), (0x24D0, 0x0061), (0x24D1, 0x0062), (0x24D2, 0x0063), (0x24D3, 0x0064),
+    (0x24D4, 0x0065), (0x24D5, 0x0066), (0x24D6, 0x0067), (0x24D7, 0x0068), (0x24D8, 0x0069),
+    (0x24D9, 0x006A), (0x24DA, 0x006B), (0x24DB, 0x006C), (0x24DC, 0x006D), (0x24DD, 0x006E),
+    (0x24DE, 0x006F), (0x24DF, 0x0070), (0x24E0, 0x0071), (0x24E1, 0x0072), (0x24E2, 0x0073),
+    (0x24E3, 0x0074), (0x24E4, 0x0075), (0x24E5, 0x0076), (0x24E6, 0x0077), (0x24E7, 0x0078),
+    (0x24E8, 0x0079), (0x24E9, 0x007A), (0x24EA, 0x0030), (0x2C7C, 0x006A), (0x2C7D, 0x0056),
+    (0x3000, 0x0020), (0xA7F2, 0x0043), (0xA7F3, 0x0046), (0xA7F4, 0x0051), (0xFB29, 0x002B),
+    (0xFE10, 0x002C), (0xFE13, 0x003A), (0xFE14, 0x003B), (0xFE15, 0x0021), (0xFE16, 0x003F),
+    (0xFE33, 0x005F), (0xFE34, 0x005F), (0xFE35, 0x0028), (0xFE36, 0x0029), (0xFE37, 0x007B),
+    (0xFE38, 0x007D), (0xFE47, 0x005B), (0xFE48, 0x005D), (0xFE4D, 0x005F), (0xFE4E, 0x005F),
+    (0xFE4F, 0x005F), (0xFE50, 0x002C), (0xFE52, 0x002E), (0xFE54, 0x003B), (0xFE55, 0x003A),
+    (0xFE56, 0x003F), (0xFE57, 0x0021), (0xFE59, 0x0028), (0xFE5A, 0x0029), (0xFE5B, 0x007B),
+    (0xFE5C, 0x007D), (0xFE5F, 0x0023), (0xFE60, 0x0026), (0xFE61, 0x002A), (0xFE62, 0x002B),
+    (0xFE63, 0x002D), (0xFE64, 0x003C), (0xFE65, 0x003E), (0xFE66, 0x003D), (0xFE68, 0x005C),
+    (0xFE69, 0x0024), (0xFE6A, 0x0025), (0xFE6B, 0x0040), (0xFF01, 0x0021), (0xFF02, 0x0022),
+    (0xFF03, 0x0023), (0xFF04, 0x0024), (0xFF05, 0x0025), (0xFF06, 0x0026), (0xFF07, 0x0027),
+    (0xFF08, 0x0028), (0xFF09, 0x0029), (0xFF0A, 0x002A), (0xFF0B, 0x002B), (0xFF0C, 0x002C),
+    (0xFF0D, 0x002D), (0xFF0E, 0x002E), (0xFF0F, 0x002F), (0xFF10, 0x0030), (0xFF11, 0x0031),
+    (0xFF12, 0x0032), (0xFF13, 0x0033), (0xFF14, 0x0034), (0xFF15, 0x0035), (0xFF16, 0x0036),
+    (0xFF17, 0x0037), (0xFF18, 0x0038), (0xFF19, 0x0039), (0xFF1A, 0x003A), (0xFF1B, 0x003B),
+    (0xFF1C, 0x003C), (0xFF1D, 0x003D), (0xFF1E, 0x003E), (0xFF1F, 0x003F), (0xFF20, 0x0040),
+    (0xFF21, 0x0041), (0xFF22, 0x0042), (0xFF23, 0x0043), (0xFF24, 0x0044), (0xFF25, 0x0045),
+    (0xFF26, 0x0046), (0xFF27, 0x0047), (0xFF28, 0x0048), (0xFF29, 0x0049), (0xFF2A, 0x004A),
+    (0xFF2B, 0x004B), (0xFF2C, 0x004C), (0xFF2D, 0x004D), (0xFF2E, 0x004E), (0xFF2F, 0x004F),
+    (0xFF30, 0x0050), (0xFF31, 0x0051), (0xFF32, 0x0052), (0xFF33, 0x0053), (0xFF34, 0x0054),
+    (0xFF35, 0x0055), (0xFF36, 0x0056), (0xFF37, 0x0057), (0xFF38, 0x0058), (0xFF39, 0x0059),
+    (0xFF3A, 0x005A), (0xFF3B, 0x005B), (0xFF3C, 0x005C), (0xFF3D, 0x005D), (0xFF3E, 0x005E),
+    (0xFF3F, 0x005F), (0xFF40, 0x0060), (0xFF41, 0x0061), (0xFF42, 0x0062), (0xFF43, 0x0063),
+    (0xFF44, 0x0064), (0xFF45, 0x0065), (0xFF46, 0x0066), (0xFF47, 0x0067), (0xFF48, 0x0068),
+    (0xFF49, 0x0069), (0xFF4A, 0x006A), (0xFF4B, 0x006B), (0xFF4C, 0x006C), (0xFF4D, 0x006D),
+    (0xFF4E, 0x006E), (0xFF4F, 0x006F), (0xFF50, 0x0070), (0xFF51, 0x0071), (0xFF52, 0x0072),
+    (0xFF53, 0x0073), (0xFF54, 0x0074), (0xFF55, 0x0075), (0xFF56, 0x0076), (0xFF57, 0x0077),
+    (0xFF58, 0x0078), (0xFF59, 0x0079), (0xFF5A, 0x007A), (0xFF5B, 0x007B), (0xFF5C, 0x007C),
+    (0xFF5D, 0x007D), (0xFF5E, 0x007E), (0xFFE8, 0x00B3), (0xFFED, 0x00FE),
+];
+
+/// Code page 775.
+#[rustfmt::skip]
+pub(crate) const CP775: [(u16, u16); 815] = [
+    (0x00AA, 0x0061), (0x00BA, 0x006F), (0x00C0, 0x0041), (0x00C1, 0x0041), (0x00C2, 0x0041),
+    (0x00C3, 0x0041), (0x00C7, 0x0043), (0x00C8, 0x0045), (0x00CA, 0x0045), (0x00CB, 0x0045),
+    (0x00CC, 0x0049), (0x00CD, 0x0049), (0x00CE, 0x0049), (0x00CF, 0x0049), (0x00D1, 0x004E),
+    (0x00D2, 0x004F), (0x00D4, 0x004F), (0x00D9, 0x0055), (0x00DA, 0x0055), (0x00DB, 0x0055),
+    (0x00DD, 0x0059), (0x00E0, 0x0061), (0x00E1, 0x0061), (0x00E2, 0x0061), (0x00E3, 0x0061),
+    (0x00E7, 0x0063), (0x00E8, 0x0065), (0x00EA, 0x0065), (0x00EB, 0x0065), (0x00EC, 0x0069),
+    (0x00ED, 0x0069), (0x00EE, 0x0069), (0x00EF, 0x0069), (0x00F1, 0x006E), (0x00F2, 0x006F),
+    (0x00F4, 0x006F), (0x00F9, 0x0075), (0x00FA, 0x0075), (0x00FB, 0x0075), (0x00FD, 0x0079),
+    (0x00FF, 0x0079), (0x0102, 0x0041), (0x0103, 0x0061), (0x0108, 0x0043), (0x0109, 0x0063),
+    (0x010A, 0x0043), (0x010B, 0x0063), (0x010E, 0x0044), (0x010F, 0x0064), (0x0114, 0x0045),
+    (0x0115, 0x0065), (0x011A, 0x0045), (0x011B, 0x0065), (0x011C, 0x0047), (0x011D, 0x0067),
+    (0x011E, 0x0047), (0x011F, 0x0067), (0x0120, 0x0047), (0x0121, 0x0067), (0x0124, 0x0048),
+    (0x0125, 0x0068), (0x0128, 0x0049), (0x0129, 0x0069), (0x012C, 0x0049), (0x012D, 0x0069),
+    (0x0130, 0x0049), (0x0134, 0x004A), (0x0135, 0x006A), (0x0139, 0x004C), (0x013A, 0x006C),
+    (0x013D, 0x004C), (0x013E, 0x006C), (0x0147, 0x004E), (0x0148, 0x006E), (0x014E, 0x004F),
+    (0x014F, 0x006F), (0x0150, 0x004F), (0x0151, 0x006F), (0x0154, 0x0052), (0x0155, 0x0072),
+    (0x0158, 0x0052), (0x0159, 0x0072), (0x015C, 0x0053), (0x015D, 0x0073), (0x015E, 0x0053),
+    (0x015F, 0x0073), (0x0162, 0x0054), (0x0163, 0x0074), (0x0164, 0x0054), (0x0165, 0x0074),
+    (0x0168, 0x0055), (0x0169, 0x0075), (0x016C, 0x0055), (0x016D, 0x0075), (0x016E, 0x0055),
+    (0x016F, 0x0075), (0x0170, 0x0055), (0x0171, 0x0075), (0x0174, 0x0057), (0x0175, 0x0077),
+    (0x0176, 0x0059), (0x0177, 0x0079), (0x0178, 0x0059), (0x017F, 0x0073), (0x01A0, 0x004F),
+    (0x01A1, 0x006F), (0x01AF, 0x0055), (0x01B0, 0x0075), (0x01CD, 0x0041), (0x01CE, 0x0061),
+    (0x01CF, 0x0049), (0x01D0, 0x0069), (0x01D1, 0x004F), (0x01D2, 0x006F), (0x01D3, 0x0055),
+    (0x01D4, 0x0075), (0x01D5, 0x0055), (0x01D6, 0x0075), (0x01D7, 0x0055), (0x01D8, 0x0075),
+    (0x01D9, 0x0055), (0x01DA, 0x0075), (0x01DB, 0x0055), (0x01DC, 0x0075), (0x01DE, 0x0041),
+    (0x01DF, 0x0061), (0x01E0, 0x0041), (0x01E1, 0x0061), (0x01E2, 0x0092), (0x01E3, 0x0091),
+    (0x01E6, 0x0047), (0x01E7, 0x0067), (0x01E8, 0x004B), (0x01E9, 0x006B), (0x01EA, 0x004F),
+    (0x01EB, 0x006F), (0x01EC, 0x004F), (0x01ED, 0x006F), (0x01F0, 0x006A), (0x01F4, 0x0047),
+    (0x01F5, 0x0067), (0x01F8, 0x004E), (0x01F9, 0x006E), (0x01FA, 0x0041), (0x01FB, 0x0061),
+    (0x01FC, 0x0092), (0x01FD, 0x0091), (0x01FE, 0x009D), (0x01FF, 0x009B), (0x0200, 0x0041),
+    (0x0201, 0x0061), (0x0202, 0x0041), (0x0203, 0x0061), (0x0204, 0x0045), (0x0205, 0x0065),
+    (0x0206, 0x0045), (0x0207, 0x0065), (0x0208, 0x0049), (0x0209, 0x0069), (0x020A, 0x0049),
+    (0x020B, 0x0069), (0x020C, 0x004F), (0x020D, 0x006F), (0x020E, 0x004F), (0x020F, 0x006F),
+    (0x0210, 0x0052), (0x0211, 0x0072), (0x0212, 0x0052), (0x0213, 0x0072), (0x0214, 0x0055),
+    (0x0215, 0x0075), (0x0216, 0x0055), (0x0217, 0x0075), (0x0218, 0x0053), (0x0219, 0x0073),
+    (0x021A, 0x0054), (0x021B, 0x0074), (0x021E, 0x0048), (0x021F, 0x0068), (0x0226, 0x0041),
+    (0x0227, 0x0061), (0x0228, 0x0045), (0x0229, 0x0065), (0x022A, 0x004F), (0x022B, 0x006F),
+    (0x022C, 0x004F), (0x022D, 0x006F), (0x022E, 0x004F), (0x022F, 0x006F), (0x0230, 0x004F),
+    (0x0231, 0x006F), (0x0232, 0x0059), (0x0233, 0x0079), (0x02B0, 0x0068), (0x02B2, 0x006A),
+    (0x02B3, 0x0072), (0x02B7, 0x0077), (0x02B8, 0x0079), (0x02E1, 0x006C), (0x02E2, 0x0073),
+    (0x02E3, 0x0078), (0x037E, 0x003B), (0x0387, 0x00FA), (0x1D2C, 0x0041), (0x1D2D, 0x0092),
+    (0x1D2E, 0x0042), (0x1D30, 0x0044), (0x1D31, 0x0045), (0x1D33, 0x0047), (0x1D34, 0x0048),
+    (0x1D35, 0x0049), (0x1D36, 0x004A), (0x1D37, 0x004B), (0x1D38, 0x004C), (0x1D39, 0x004D),
+    (0x1D3A, 0x004E), (0x1D3C, 0x004F), (0x1D3E, 0x0050), (0x1D3F, 0x0052), (0x1D40, 0x0054),
+    (0x1D41, 0x0055), (0x1D42, 0x0057), (0x1D43, 0x0061), (0x1D47, 0x0062), (0x1D48, 0x0064),
+    (0x1D49, 0x0065), (0x1D4D, 0x0067), (0x1D4F, 0x006B), (0x1D50, 0x006D), (0x1D52, 0x006F),
+    (0x1D56, 0x0070), (0x1D57, 0x0074), (0x1D58, 0x0075), (0x1D5B, 0x0076), (0x1D62, 0x0069),
+    (0x1D63, 0x0072), (0x1D64, 0x0075), (0x1D65, 0x0076), (0x1D9C, 0x0063), (0x1DA0, 0x0066),
+    (0x1DBB, 0x007A), (0x1E00, 0x0041), (0x1E01, 0x0061), (0x1E02, 0x0042), (0x1E03, 0x0062),
+    (0x1E04, 0x0042), (0x1E05, 0x0062), (0x1E06, 0x0042), (0x1E07, 0x0062), (0x1E08, 0x0043),
+    (0x1E09, 0x0063), (0x1E0A, 0x0044), (0x1E0B, 0x0064), (0x1E0C, 0x0044), (0x1E0D, 0x0064),
+    (0x1E0E, 0x0044), (0x1E0F, 0x0064), (0x1E10, 0x0044), (0x1E11, 0x0064), (0x1E12, 0x0044),
+    (0x1E13, 0x0064), (0x1E14, 0x0045), (0x1E15, 0x0065), (0x1E16, 0x0045), (0x1E17, 0x0065),
+    (0x1E18, 0x0045), (0x1E19, 0x0065), (0x1E1A, 0x0045), (0x1E1B, 0x0065), (0x1E1C, 0x0045),
+    (0x1E1D, 0x0065), (0x1E1E, 0x0046), (0x1E1F, 0x0066), (0x1E20, 0x0047), (0x1E21, 0x0067),
+    (0x1E22, 0x0048), (0x1E23, 0x0068), (0x1E24, 0x0048), (0x1E25, 0x0068), (0x1E26, 0x0048),
+    (0x1E27, 0x0068), (0x1E28, 0x0048), (0x1E29, 0x0068), (0x1E2A, 0x0048), (0x1E2B, 0x0068),
+    (0x1E2C, 0x0049), (0x1E2D, 0x0069), (0x1E2E, 0x0049), (0x1E2F, 0x0069), (0x1E30, 0x004B),
+    (0x1E31, 0x006B), (0x1E32, 0x004B), (0x1E33, 0x006B), (0x1E34, 0x004B), (0x1E35, 0x006B),
+    (0x1E36, 0x004C), (0x1E37, 0x006C), (0x1E38, 0x004C), (0x1E39, 0x006C), (0x1E3A, 0x004C),
+    (0x1E3B, 0x006C), (0x1E3C, 0x004C), (0x1E3D, 0x006C), (0x1E3E, 0x004D), (0x1E3F, 0x006D),
+    (0x1E40, 0x004D), (0x1E41, 0x006D), (0x1E42, 0x004D), (0x1E43, 0x006D), (0x1E44, 0x004E),
+    (0x1E45, 0x006E), (0x1E46, 0x004E), (0x1E47, 0x006E), (0x1E48, 0x004E), (0x1E49, 0x006E),
+    (0x1E4A, 0x004E), (0x1E4B, 0x006E), (0x1E4C, 0x004F), (0x1E4D, 0x006F), (0x1E4E, 0x004F),
+    (0x1E4F, 0x006F), (0x1E50, 0x004F), (0x1E51, 0x006F), (0x1E52, 0x004F), (0x1E53, 0x006F),
+    (0x1E54, 0x0050), (0x1E55, 0x0070), (0x1E56, 0x0050), (0x1E57, 0x0070), (0x1E58, 0x0052),
+    (0x1E59, 0x0072), (0x1E5A, 0x0052), (0x1E5B, 0x0072), (0x1E5C, 0x0052), (0x1E5D, 0x0072),
+    (0x1E5E, 0x0052), (0x1E5F, 0x0072), (0x1E60, 0x0053), (0x1E61, 0x0073), (0x1E62, 0x0053),
+    (0x1E63, 0x0073), (0x1E64, 0x0053), (0x1E65, 0x0073), (0x1E66, 0x0053), (0x1E67, 0x0073),
+    (0x1E68, 0x0053), (0x1E69, 0x0073), (0x1E6A, 0x0054), (0x1E6B, 0x0074), (0x1E6C, 0x0054),
+    (0x1E6D, 0x0074), (0x1E6E, 0x0054), (0x1E6F, 0x0074), (0x1E70, 0x0054), (0x1E71, 0x0074),
+    (0x1E72, 0x0055), (0x1E73, 0x0075), (0x1E74, 0x0055), (0x1E75, 0x0075), (0x1E76, 0x0055),
+    (0x1E77, 0x0075), (0x1E78, 0x0055), (0x1E79, 0x0075), (0x1E7A, 0x0055), (0x1E7B, 0x0075),
+    (0x1E7C, 0x0056), (0x1E7D, 0x0076), (0x1E7E, 0x0056), (0x1E7F, 0x0076), (0x1E80, 0x0057),
+    (0x1E81, 0x0077), (0x1E82, 0x0057), (0x1E83, 0x0077), (0x1E84, 0x0057), (0x1E85, 0x0077),
+    (0x1E86, 0x0057), (0x1E87, 0x0077), (0x1E88, 0x0057), (0x1E89, 0x0077), (0x1E8A, 0x0058),
+    (0x1E8B, 0x0078), (0x1E8C, 0x0058), (0x1E8D, 0x0078), (0x1E8E, 0x0059), (0x1E8F, 0x0079),
+    (0x1E90, 0x005A), (0x1E91, 0x007A), (0x1E92, 0x005A), (0x1E93, 0x007A), (0x1E94, 0x005A),
+    (0x1E95, 0x007A), (0x1E96, 0x0068), (0x1E97, 0x0074), (0x1E98, 0x0077), (0x1E99, 0x0079),
+    (0x1E9B, 0x0073), (0x1EA0, 0x0041), (0x1EA1, 0x0061), (0x1EA2, 0x0041), (0x1EA3, 0x0061),
+    (0x1EA4, 0x0041), (0x1EA5, 0x0061), (0x1EA6, 0x0041), (0x1EA7, 0x0061), (0x1EA8, 0x0041),
+    (0x1EA9, 0x0061), (0x1EAA, 0x0041), (0x1EAB, 0x0061), (0x1EAC, 0x0041), (0x1EAD, 0x0061),
+    (0x1EAE, 0x0041), (0x1EAF, 0x0061), (0x1EB0, 0x0041), (0x1EB1, 0x0061), (0x1EB2, 0x0041),
+    (0x1EB3, 0x0061), (0x1EB4, 0x0041), (0x1EB5, 0x0061), (0x1EB6, 0x0041), (0x1EB7, 0x0061),
+    (0x1EB8, 0x0045), (0x1EB9, 0x0065), (0x1EBA, 0x0045), (0x1EBB, 0x0065), (0x1EBC, 0x0045),
+    (0x1EBD, 0x0065), (0x1EBE, 0x0045), (0x1EBF, 0x0065), (0x1EC0, 0x0045), (0x1EC1, 0x0065),
+    (0x1EC2, 0x0045), (0x1EC3, 0x0065), (0x1EC4, 0x0045), (0x1EC5, 0x0065), (0x1EC6, 0x0045),
+    (0x1EC7, 0x0065), (0x1EC8, 0x0049), (0x1EC9, 0x0069), (0x1ECA, 0x0049), (0x1ECB, 0x0069),
+    (0x1ECC, 0x004F), (0x1ECD, 0x006F), (0x1ECE, 0x004F), (0x1ECF, 0x006F), (0x1ED0, 0x004F),
+    (0x1ED1, 0x006F), (0x1ED2, 0x004F), (0x1ED3, 0x006F), (0x1ED4, 0x004F), (0x1ED5, 0x006F),
+    (0x1ED6, 0x004F), (0x1ED7, 0x006F), (0x1ED8, 0x004F), (0x1ED9, 0x006F), (0x1EDA, 0x004F),
+    (0x1EDB, 0x006F), (0x1EDC, 0x004F), (0x1EDD, 0x006F), (0x1EDE, 0x004F), (0x1EDF, 0x006F),
+    (0x1EE0, 0x004F), (0x1EE1, 0x006F), (0x1EE2, 0x004F), (0x1EE3, 0x006F), (0x1EE4, 0x0055),
+    (0x1EE5, 0x0075), (0x1EE6, 0x0055), (0x1EE7, 0x0075), (0x1EE8, 0x0055), (0x1EE9, 0x0075),
+    (0x1EEA, 0x0055), (0x1EEB, 0x0075), (0x1EEC, 0x0055), (0x1EED, 0x0075), (0x1EEE, 0x0055),
+    (0x1EEF, 0x0075), (0x1EF0, 0x0055), (0x1EF1, 0x0075), (0x1EF2, 0x0059), (0x1EF3, 0x0079),
+    (0x1EF4, 0x0059), (0x1EF5, 0x0079), (0x1EF6, 0x0059), (0x1EF7, 0x0079), (0x1EF8, 0x0059),
+    (0x1EF9, 0x0079), (0x1FEF, 0x0060), (0x2000, 0x0020), (0x2001, 0x0020), (0x2002, 0x0020),
+    (0x2003, 0x0020), (0x2004, 0x0020), (0x2005, 0x0020), (0x2006, 0x0020), (0x2007, 0x0020),
+    (0x2008, 0x0020), (0x2009, 0x0020), (0x200A, 0x0020), (0x2010, 0x002D), (0x2012, 0x002D),
+    (0x2013, 0x002D), (0x2014, 0x002D), (0x2015, 0x002D), (0x2018, 0x0027), (0x201A, 0x002C),
+    (0x201B, 0x0027), (0x201F, 0x0022), (0x2024, 0x002E), (0x202F, 0x0020), (0x2032, 0x0027),
+    (0x2035, 0x0060), (0x2039, 0x003C), (0x203A, 0x003E), (0x205F, 0x0020), (0x2070, 0x0030),
+    (0x2071, 0x0069), (0x2074, 0x0034), (0x2075, 0x0035), (0x2076, 0x0036), (0x2077, 0x0037),
+    (0x2078, 0x0038), (0x2079, 0x0039), (0x207A, 0x002B), (0x207C, 0x003D), (0x207D, 0x0028),
+    (0x207E, 0x0029), (0x207F, 0x006E), (0x2080, 0x0030), (0x2081, 0x0031), (0x2082, 0x0032),
+    (0x2083, 0x0033), (0x2084, 0x0034), (0x2085, 0x0035), (0x2086, 0x0036), (0x2087, 0x0037),
+    (0x2088, 0x0038), (0x2089, 0x0039), (0x208A, 0x002B), (0x208C, 0x003D), (0x208D, 0x0028),
+    (0x208E, 0x0029), (0x2090, 0x0061), (0x2091, 0x0065), (0x2092, 0x006F), (0x2093, 0x0078),
+    (0x2095, 0x0068), (0x2096, 0x006B), (0x2097, 0x006C), (0x2098, 0x006D), (0x2099, 0x006E),
+    (0x209A, 0x0070), (0x209B, 0x0073), (0x209C, 0x0074), (0x2102, 0x0043), (0x210A, 0x0067),
+    (0x210B, 0x0048), (0x210C, 0x0048), (0x210D, 0x0048), (0x210E, 0x0068), (0x2110, 0x0049),
+    (0x2111, 0x0049), (0x2112, 0x004C), (0x2113, 0x006C), (0x2115, 0x004E), (0x2119, 0x0050),
+    (0x211A, 0x0051), (0x211B, 0x0052), (0x211C, 0x0052), (0x211D, 0x0052), (0x2124, 0x005A),
+    (0x2128, 0x005A), (0x212A, 0x004B), (0x212B, 0x0041), (0x212C, 0x0042), (0x212D, 0x0043),
+    (0x212F, 0x0065), (0x2130, 0x0045), (0x2131, 0x0046), (0x2133, 0x004D), (0x2134, 0x006F),
+    (0x2139, 0x0069), (0x2145, 0x0044), (0x2146, 0x0064), (0x2147, 0x0065), (0x2148, 0x0069),
+    (0x2149, 0x006A), (0x2160, 0x0049), (0x2164, 0x0056), (0x2169, 0x0058), (0x216C, 0x004C),
+    (0x216D, 0x0043), (0x216E, 0x0044), (0x216F, 0x004D), (0x2170, 0x0069), (0x2174, 0x0076),
+    (0x2179, 0x0078), (0x217C, 0x006C), (0x217D, 0x0063), (0x217E, 0x0064), (0x217F, 0x006D),
+    (0x2212, 0x002D), (0x2215, 0x002F), (0x2216, 0x005C), (0x2223, 0x007C), (0x223C, 0x007E),
+    (0x2260, 0x003D), (0x226E, 0x003C), (0x226F, 0x003E), (0x2460, 0x0031), (0x2461, 0x0032),
+    (0x2462, 0x0033), (0x2463, 0x0034), (0x2464, 0x0035), (0x2465, 0x0036), (0x2466, 0x0037),
+    (0x2467, 0x0038), (0x2468, 0x0039), (0x24B6, 0x0041), (0x24B7, 0x0042), (0x24B8, 0x0043),
+    (0x24B9, 0x0044), (0x24BA, 0x0045), (0x24BB, 0x0046), (0x24BC, 0x0047), (0x24BD, 0x0048),
+    (0x24BE, 0x0049), (0x24BF, 0x004A), (0x24C0, 0x004B), (0x24C1, 0x004C), (0x24C2, 0x004D),
+    (0x24C3, 0x004E), (0x24C4, 0x004F), (0x24C5, 0x0050), (0x24C6, 0x0051), (0x24C7, 0x0052),
+    (0x24C8, 0x0053), (0x24C9, 0x0054), (0x24CA, 0x0055), (0x24CB, 0x0056), (0x24CC, 0x0057),
+    (0x24CD, 0x0058), (0x24CE, 0x0059), (0x24CF, 0x005A), (0x24D0, 0x0061), (0x24D1, 0x0062),
+    (0x24D2, 0x0063), (0x24D3, 0x0064), (0x24D4, 0x0065), (0x24D5, 0x0066), (0x24D6, 0x0067),
+    (0x24D7, 0x0068), (0x24D8, 0x0069), (0x24D9, 0x006A), (0x24DA, 0x006B), (0x24DB, 0x006C),
+    (0x24DC, 0x006D), (0x24DD, 0x006E), (0x24DE, 0x006F), (0x24DF, 0x0070), (0x24E0, 0x0071),
+    (0x24E1, 0x0072), (0x24E2, 0x0073), (0x24E3, 0x0074), (0x24E4, 0x0075), (0x24E5, 0x0076),
+    (0x24E6, 0x0077), (0x24E7, 0x0078), (0x24E8, 0x0079), (0x24E9, 0x007A), (0x24EA, 0x0030),
+    (0x2C7C, 0x006A), (0x2C7D, 0x0056), (0x3000, 0x0020), (0xA7F2, 0x0043), (0xA7F3, 0x0046),
+    (0xA7F4, 0x0051), (0xFB29, 0x002B), (0xFE10, 0x002C), (0xFE13, 0x003A), (0xFE14, 0x003B),
+    (0xFE15, 0x0021), (0xFE16, 0x003F), (0xFE33, 0x005F), (0xFE34, 0x005F), (0xFE35, 0x0028),
+    (0xFE36, 0x0029), (0xFE37, 0x007B), (0xFE38, 0x007D), (0xFE47, 0x005B), (0xFE48, 0x005D),
+    (0xFE4D, 0x005F), (0xFE4E, 0x005F), (0xFE4F, 0x005F), (0xFE50, 0x002C), (0xFE52, 0x002E),
+    (0xFE54, 0x003B), (0xFE55, 0x003A), (0xFE56, 0x003F), (0xFE57, 0x0021), (0xFE59, 0x0028),
+    (0xFE5A, 0x0029), (0xFE5B, 0x007B), (0xFE5C, 0x007D), (0xFE5F, 0x0023), (0xFE60, 0x0026),
+    (0xFE61, 0x002A), (0xFE62, 0x002B), (0xFE63, 0x002D), (0xFE64, 0x003C), (0xFE65, 0x003E),
+    (0xFE66, 0x003D), (0xFE68, 0x005C), (0xFE69, 0x0024), (0xFE6A, 0x0025), (0xFE6B, 0x0040),
+    (0xFF01, 0x0021), (0xFF02, 0x0022), (0xFF03, 0x0023), (0xFF04, 0x0024), (0xFF05, 0x0025),
+    (0xFF06, 0x0026), (0xFF07, 0x0027), (0xFF08, 0x0028), (0xFF09, 0x0029), (0xFF0A, 0x002A),
+    (0xFF0B, 0x002B), (0xFF0C, 0x002C), (0xFF0D, 0x002D), (0xFF0E, 0x002E), (0xFF0F, 0x002F),
+    (0xFF10, 0x0030), (0xFF11, 0x0031), (0xFF12, 0x0032), (0xFF13, 0x0033), (0xFF14, 0x0034),
+    (0xFF15, 0x0035), (0xFF16, 0x0036), (0xFF17, 0x0037), (0xFF18, 0x0038), (0xFF19, 0x0039),
+    (0xFF1A, 0x003A), (0xFF1B, 0x003B), (0xFF1C, 0x003C), (0xFF1D, 0x003D), (0xFF1E, 0x003E),
+    (0xFF1F, 0x003F), (0xFF20, 0x0040), (0xFF21, 0x0041), (0xFF22, 0x0042), (0xFF23, 0x0043),
+    (0xFF24, 0x0044), (0xFF25, 0x0045), (0xFF26, 0x0046), (0xFF27, 0x0047), (0xFF28, 0x0048),
+    (0xFF29, 0x0049), (0xFF2A, 0x004A), (0xFF2B, 0x004B), (0xFF2C, 0x004C), (0xFF2D, 0x004D),
+    (0xFF2E, 0x004E), (0xFF2F, 0x004F), (0xFF30, 0x0050), (0xFF31, 0x0051), (0xFF32, 0x0052),
+    (0xFF33, 0x0053), (0xFF34, 0x0054), (0xFF35, 0x0055), (0xFF36, 0x0056), (0xFF37, 0x0057),
+    (0xFF38, 0x0058), (0xFF39, 0x0059), (0xFF3A, 0x005A), (0xFF3B, 0x005B), (0xFF3C, 0x005C),
+    (0xFF3D, 0x005D), (0xFF3E, 0x005E), (0xFF3F, 0x005F), (0xFF40, 0x0060), (0xFF41, 0x0061),
+    (0xFF42, 0x0062), (0xFF43, 0x0063), (0xFF44, 0x0064), (0xFF45, 0x0065), (0xFF46, 0x0066),
+    (0xFF47, 0x0067), (0xFF48, 0x0068), (0xFF49, 0x0069), (0xFF4A, 0x006A), (0xFF4B, 0x006B),
+    (0xFF4C, 0x006C), (0xFF4D, 0x006D), (0xFF4E, 0x006E), (0xFF4F, 0x006F), (0xFF50, 0x0070),
+    (0xFF51, 0x0071), (0xFF52, 0x0072), (0xFF53, 0x0073), (0xFF54, 0x0074), (0xFF55, 0x0075),
+    (0xFF56, 0x0076), (0xFF57, 0x0077), (0xFF58, 0x0078), (0xFF59, 0x0079), (0xFF5A, 0x007A),
+    (0xFF5B, 0x007B), (0xFF5C, 0x007C), (0xFF5D, 0x007D), (0xFF5E, 0x007E), (0xFFE0, 0x0096),
+    (0xFFE1, 0x009C), (0xFFE2, 0x00AA), (0xFFE4, 0x00A7), (0xFFE8, 0x00B3), (0xFFED, 0x00FE),
+];
+
+/// Code page 852.
+#[rustfmt::skip]
+pub(crate) const CP852: [(u16, u16); 795] = [
+    (0x00AA, 0x0061), (0x00B2, 0x0032), (0x00B3, 0x0033), (0x00B9, 0x0031), (0x00BA, 0x006F),
+    (0x00C0, 0x0041), (0x00C3, 0x0041), (0x00C5, 0x0041), (0x00C8, 0x0045), (0x00CA, 0x0045),
+    (0x00CC, 0x0049), (0x00CF, 0x0049), (0x00D1, 0x004E), (0x00D2, 0x004F), (0x00D5, 0x004F),
+    (0x00D9, 0x0055), (0x00DB, 0x0055), (0x00E0, 0x0061), (0x00E3, 0x0061), (0x00E5, 0x0061),
+    (0x00E8, 0x0065), (0x00EA, 0x0065), (0x00EC, 0x0069), (0x00EF, 0x0069), (0x00F1, 0x006E),
+    (0x00F2, 0x006F), (0x00F5, 0x006F), (0x00F9, 0x0075), (0x00FB, 0x0075), (0x00FF, 0x0079),
+    (0x0100, 0x0041), (0x0101, 0x0061), (0x0108, 0x0043), (0x0109, 0x0063), (0x010A, 0x0043),
+    (0x010B, 0x0063), (0x0112, 0x0045), (0x0113, 0x0065), (0x0114, 0x0045), (0x0115, 0x0065),
+    (0x0116, 0x0045), (0x0117, 0x0065), (0x011C, 0x0047), (0x011D, 0x0067), (0x011E, 0x0047),
+    (0x011F, 0x0067), (0x0120, 0x0047), (0x0121, 0x0067), (0x0122, 0x0047), (0x0123, 0x0067),
+    (0x0124, 0x0048), (0x0125, 0x0068), (0x0128, 0x0049), (0x0129, 0x0069), (0x012A, 0x0049),
+    (0x012B, 0x0069), (0x012C, 0x0049), (0x012D, 0x0069), (0x012E, 0x0049), (0x012F, 0x0069),
+    (0x0130, 0x0049), (0x0134, 0x004A), (0x0135, 0x006A), (0x0136, 0x004B), (0x0137, 0x006B),
+    (0x013B, 0x004C), (0x013C, 0x006C), (0x0145, 0x004E), (0x0146, 0x006E), (0x014C, 0x004F),
+    (0x014D, 0x006F), (0x014E, 0x004F), (0x014F, 0x006F), (0x0156, 0x0052), (0x0157, 0x0072),
+    (0x015C, 0x0053), (0x015D, 0x0073), (0x0168, 0x0055), (0x0169, 0x0075), (0x016A, 0x0055),
+    (0x016B, 0x0075), (0x016C, 0x0055), (0x016D, 0x0075), (0x0172, 0x0055), (0x0173, 0x0075),
+    (0x0174, 0x0057), (0x0175, 0x0077), (0x0176, 0x0059), (0x0177, 0x0079), (0x0178, 0x0059),
+    (0x017F, 0x0073), (0x01A0, 0x004F), (0x01A1, 0x006F), (0x01AF, 0x0055), (0x01B0, 0x0075),
+    (0x01CD, 0x0041), (0x01CE, 0x0061), (0x01CF, 0x0049), (0x01D0, 0x0069), (0x01D1, 0x004F),
+    (0x01D2, 0x006F), (0x01D3, 0x0055), (0x01D4, 0x0075), (0x01D5, 0x0055), (0x01D6, 0x0075),
+    (0x01D7, 0x0055), (0x01D8, 0x0075), (0x01D9, 0x0055), (0x01DA, 0x0075), (0x01DB, 0x0055),
+    (0x01DC, 0x0075), (0x01DE, 0x0041), (0x01DF, 0x0061), (0x01E0, 0x0041), (0x01E1, 0x0061),
+    (0x01E6, 0x0047), (0x01E7, 0x0067), (0x01E8, 0x004B), (0x01E9, 0x006B), (0x01EA, 0x004F),
+    (0x01EB, 0x006F), (0x01EC, 0x004F), (0x01ED, 0x006F), (0x01F0, 0x006A), (0x01F4, 0x0047),
+    (0x01F5, 0x0067), (0x01F8, 0x004E), (0x01F9, 0x006E), (0x01FA, 0x0041), (0x01FB, 0x0061),
+    (0x0200, 0x0041), (0x0201, 0x0061), (0x0202, 0x0041), (0x0203, 0x0061), (0x0204, 0x0045),
+    (0x0205, 0x0065), (0x0206, 0x0045), (0x0207, 0x0065), (0x0208, 0x0049), (0x0209, 0x0069),
+    (0x020A, 0x0049), (0x020B, 0x0069), (0x020C, 0x004F), (0x020D, 0x006F), (0x020E, 0x004F),
+    (0x020F, 0x006F), (0x0210, 0x0052), (0x0211, 0x0072), (0x0212, 0x0052), (0x0213, 0x0072),
+    (0x0214, 0x0055), (0x0215, 0x0075), (0x0216, 0x0055), (0x0217, 0x0075), (0x0218, 0x0053),
+    (0x0219, 0x0073), (0x021A, 0x0054), (0x021B, 0x0074), (0x021E, 0x0048), (0x021F, 0x0068),
+    (0x0226, 0x0041), (0x0227, 0x0061), (0x0228, 0x0045), (0x0229, 0x0065), (0x022A, 0x004F),
+    (0x022B, 0x006F), (0x022C, 0x004F), (0x022D, 0x006F), (0x022E, 0x004F), (0x022F, 0x006F),
+    (0x0230, 0x004F), (0x0231, 0x006F), (0x0232, 0x0059), (0x0233, 0x0079), (0x02B0, 0x0068),
+    (0x02B2, 0x006A), (0x02B3, 0x0072), (0x02B7, 0x0077), (0x02B8, 0x0079), (0x02E1, 0x006C),
+    (0x02E2, 0x0073), (0x02E3, 0x0078), (0x037E, 0x003B), (0x1D2C, 0x0041), (0x1D2E, 0x0042),
+    (0x1D30, 0x0044), (0x1D31, 0x0045), (0x1D33, 0x0047), (0x1D34, 0x0048), (0x1D35, 0x0049),
+    (0x1D36, 0x004A), (0x1D37, 0x004B), (0x1D38, 0x004C), (0x1D39, 0x004D), (0x1D3A, 0x004E),
+    (0x1D3C, 0x004F), (0x1D3E, 0x0050), (0x1D3F, 0x0052), (0x1D40, 0x0054), (0x1D41, 0x0055),
+    (0x1D42, 0x0057), (0x1D43, 0x0061), (0x1D47, 0x0062), (0x1D48, 0x0064), (0x1D49, 0x0065),
+    (0x1D4D, 0x0067), (0x1D4F, 0x006B), (0x1D50, 0x006D), (0x1D52, 0x006F), (0x1D56, 0x0070),
+    (0x1D57, 0x0074), (0x1D58, 0x0075), (0x1D5B, 0x0076), (0x1D62, 0x0069), (0x1D63, 0x0072),
+    (0x1D64, 0x0075), (0x1D65, 0x0076), (0x1D9C, 0x0063), (0x1DA0, 0x0066), (0x1DBB, 0x007A),
+    (0x1E00, 0x0041), (0x1E01, 0x0061), (0x1E02, 0x0042), (0x1E03, 0x0062), (0x1E04, 0x0042),
+    (0x1E05, 0x0062), (0x1E06, 0x0042), (0x1E07, 0x0062), (0x1E08, 0x0043), (0x1E09, 0x0063),
+    (0x1E0A, 0x0044), (0x1E0B, 0x0064), (0x1E0C, 0x0044), (0x1E0D, 0x0064), (0x1E0E, 0x0044),
+    (0x1E0F, 0x0064), (0x1E10, 0x0044), (0x1E11, 0x0064), (0x1E12, 0x0044), (0x1E13, 0x0064),
+    (0x1E14, 0x0045), (0x1E15, 0x0065), (0x1E16, 0x0045), (0x1E17, 0x0065), (0x1E18, 0x0045),
+    (0x1E19, 0x0065), (0x1E1A, 0x0045), (0x1E1B, 0x0065), (0x1E1C, 0x0045), (0x1E1D, 0x0065),
+    (0x1E1E, 0x0046), (0x1E1F, 0x0066), (0x1E20, 0x0047), (0x1E21, 0x0067), (0x1E22, 0x0048),
+    (0x1E23, 0x0068), (0x1E24, 0x0048), (0x1E25, 0x0068), (0x1E26, 0x0048), (0x1E27, 0x0068),
+    (0x1E28, 0x0048), (0x1E29, 0x0068), (0x1E2A, 0x0048), (0x1E2B, 0x0068), (0x1E2C, 0x0049),
+    (0x1E2D, 0x0069), (0x1E2E, 0x0049), (0x1E2F, 0x0069), (0x1E30, 0x004B), (0x1E31, 0x006B),
+    (0x1E32, 0x004B), (0x1E33, 0x006B), (0x1E34, 0x004B), (0x1E35, 0x006B), (0x1E36, 0x004C),
+    (0x1E37, 0x006C), (0x1E38, 0x004C), (0x1E39, 0x006C), (0x1E3A, 0x004C), (0x1E3B, 0x006C),
+    (0x1E3C, 0x004C), (0x1E3D, 0x006C), (0x1E3E, 0x004D), (0x1E3F, 0x006D), (0x1E40, 0x004D),
+    (0x1E41, 0x006D), (0x1E42, 0x004D), (0x1E43, 0x006D), (0x1E44, 0x004E), (0x1E45, 0x006E),
+    (0x1E46, 0x004E), (0x1E47, 0x006E), (0x1E48, 0x004E), (0x1E49, 0x006E), (0x1E4A, 0x004E),
+    (0x1E4B, 0x006E), (0x1E4C, 0x004F), (0x1E4D, 0x006F), (0x1E4E, 0x004F), (0x1E4F, 0x006F),
+    (0x1E50, 0x004F), (0x1E51, 0x006F), (0x1E52, 0x004F), (0x1E53, 0x006F), (0x1E54, 0x0050),
+    (0x1E55, 0x0070), (0x1E56, 0x0050), (0x1E57, 0x0070), (0x1E58, 0x0052), (0x1E59, 0x0072),
+    (0x1E5A, 0x0052), (0x1E5B, 0x0072), (0x1E5C, 0x0052), (0x1E5D, 0x0072), (0x1E5E, 0x0052),
+    (0x1E5F, 0x0072), (0x1E60, 0x0053), (0x1E61, 0x0073), (0x1E62, 0x0053), (0x1E63, 0x0073),
+    (0x1E64, 0x0053), (0x1E65, 0x0073), (0x1E66, 0x0053), (0x1E67, 0x0073), (0x1E68, 0x0053),
+    (0x1E69, 0x0073), (0x1E6A, 0x0054), (0x1E6B, 0x0074), (0x1E6C, 0x0054), (0x1E6D, 0x0074),
+    (0x1E6E, 0x0054), (0x1E6F, 0x0074), (0x1E70, 0x0054), (0x1E71, 0x0074), (0x1E72, 0x0055),
+    (0x1E73, 0x0075), (0x1E74, 0x0055), (0x1E75, 0x0075), (0x1E76, 0x0055), (0x1E77, 0x0075),
+    (0x1E78, 0x0055), (0x1E79, 0x0075), (0x1E7A, 0x0055), (0x1E7B, 0x0075), (0x1E7C, 0x0056),
+    (0x1E7D, 0x0076), (0x1E7E, 0x0056), (0x1E7F, 0x0076), (0x1E80, 0x0057), (0x1E81, 0x0077),
+    (0x1E82, 0x0057), (0x1E83, 0x0077), (0x1E84, 0x0057), (0x1E85, 0x0077), (0x1E86, 0x0057),
+    (0x1E87, 0x0077), (0x1E88, 0x0057), (0x1E89, 0x0077), (0x1E8A, 0x0058), (0x1E8B, 0x0078),
+    (0x1E8C, 0x0058), (0x1E8D, 0x0078), (0x1E8E, 0x0059), (0x1E8F, 0x0079), (0x1E90, 0x005A),
+    (0x1E91, 0x007A), (0x1E92, 0x005A), (0x1E93, 0x007A), (0x1E94, 0x005A), (0x1E95, 0x007A),
+    (0x1E96, 0x0068), (0x1E97, 0x0074), (0x1E98, 0x0077), (0x1E99, 0x0079), (0x1E9B, 0x0073),
+    (0x1EA0, 0x0041), (0x1EA1, 0x0061), (0x1EA2, 0x0041), (0x1EA3, 0x0061), (0x1EA4, 0x0041),
+    (0x1EA5, 0x0061), (0x1EA6, 0x0041), (0x1EA7, 0x0061), (0x1EA8, 0x0041), (0x1EA9, 0x0061),
+    (0x1EAA, 0x0041), (0x1EAB, 0x0061), (0x1EAC, 0x0041), (0x1EAD, 0x0061), (0x1EAE, 0x0041),
+    (0x1EAF, 0x0061), (0x1EB0, 0x0041), (0x1EB1, 0x0061), (0x1EB2, 0x0041), (0x1EB3, 0x0061),
+    (0x1EB4, 0x0041), (0x1EB5, 0x0061), (0x1EB6, 0x0041), (0x1EB7, 0x0061), (0x1EB8, 0x0045),
+    (0x1EB9, 0x0065), (0x1EBA, 0x0045), (0x1EBB, 0x0065), (0x1EBC, 0x0045), (0x1EBD, 0x0065),
+    (0x1EBE, 0x0045), (0x1EBF, 0x0065), (0x1EC0, 0x0045), (0x1EC1, 0x0065), (0x1EC2, 0x0045),
+    (0x1EC3, 0x0065), (0x1EC4, 0x0045), (0x1EC5, 0x0065), (0x1EC6, 0x0045), (0x1EC7, 0x0065),
+    (0x1EC8, 0x0049), (0x1EC9, 0x0069), (0x1ECA, 0x0049), (0x1ECB, 0x0069), (0x1ECC, 0x004F),
+    (0x1ECD, 0x006F), (0x1ECE, 0x004F), (0x1ECF, 0x006F), (0x1ED0, 0x004F), (0x1ED1, 0x006F),
+    (0x1ED2, 0x004F), (0x1ED3, 0x006F), (0x1ED4, 0x004F), (0x1ED5, 0x006F), (0x1ED6, 0x004F),
+    (0x1ED7, 0x006F), (0x1ED8, 0x004F), (0x1ED9, 0x006F), (0x1EDA, 0x004F), (0x1EDB, 0x006F),
+    (0x1EDC, 0x004F), (0x1EDD, 0x006F), (0x1EDE, 0x004F), (0x1EDF, 0x006F), (0x1EE0, 0x004F),
+    (0x1EE1, 0x006F), (0x1EE2, 0x004F), (0x1EE3, 0x006F), (0x1EE4, 0x0055), (0x1EE5, 0x0075),
+    (0x1EE6, 0x0055), (0x1EE7, 0x0075), (0x1EE8, 0x0055), (0x1EE9, 0x0075), (0x1EEA, 0x0055),
+    (0x1EEB, 0x0075), (0x1EEC, 0x0055), (0x1EED, 0x0075), (0x1EEE, 0x0055), (0x1EEF, 0x0075),
+    (0x1EF0, 0x0055), (0x1EF1, 0x0075), (0x1EF2, 0x0059), (0x1EF3, 0x0079), (0x1EF4, 0x0059),
+    (0x1EF5, 0x0079), (0x1EF6, 0x0059), (0x1EF7, 0x0079), (0x1EF8, 0x0059), (0x1EF9, 0x0079),
+    (0x1FEF, 0x0060), (0x2000, 0x0020), (0x2001, 0x0020), (0x2002, 0x0020), (0x2003, 0x0020),
+    (0x2004, 0x0020), (0x2005, 0x0020), (0x2006, 0x0020), (0x2007, 0x0020), (0x2008, 0x0020),
+    (0x2009, 0x0020), (0x200A, 0x0020), (0x2010, 0x002D), (0x2012, 0x002D), (0x2013, 0x002D),
+    (0x2014, 0x002D), (0x2015, 0x002D), (0x2018, 0x0027), (0x2019, 0x0027), (0x201A, 0x002C),
+    (0x201B, 0x0027), (0x201C, 0x0022), (0x201D, 0x0022), (0x201E, 0x0022), (0x201F, 0x0022),
+    (0x2024, 0x002E), (0x202F, 0x0020), (0x2032, 0x0027), (0x2035, 0x0060), (0x2039, 0x003C),
+    (0x203A, 0x003E), (0x205F, 0x0020), (0x2070, 0x0030), (0x2071, 0x0069), (0x2074, 0x0034),
+    (0x2075, 0x0035), (0x2076, 0x0036), (0x2077, 0x0037), (0x2078, 0x0038), (0x2079, 0x0039),
+    (0x207A, 0x002B), (0x207C, 0x003D), (0x207D, 0x0028), (0x207E, 0x0029), (0x207F, 0x006E),
+    (0x2080, 0x0030), (0x2081, 0x0031), (0x2082, 0x0032), (0x2083, 0x0033), (0x2084, 0x0034),
+    (0x2085, 0x0035), (0x2086, 0x0036), (0x2087, 0x0037), (0x2088, 0x0038), (0x2089, 0x0039),
+    (0x208A, 0x002B), (0x208C, 0x003D), (0x208D, 0x0028), (0x208E, 0x0029), (0x2090, 0x0061),
+    (0x2091, 0x0065), (0x2092, 0x006F), (0x2093, 0x0078), (0x2095, 0x0068), (0x2096, 0x006B),
+    (0x2097, 0x006C), (0x2098, 0x006D), (0x2099, 0x006E), (0x209A, 0x0070), (0x209B, 0x0073),
+    (0x209C, 0x0074), (0x2102, 0x0043), (0x210A, 0x0067), (0x210B, 0x0048), (0x210C, 0x0048),
+    (0x210D, 0x0048), (0x210E, 0x0068), (0x2110, 0x0049), (0x2111, 0x0049), (0x2112, 0x004C),
+    (0x2113, 0x006C), (0x2115, 0x004E), (0x2119, 0x0050), (0x211A, 0x0051), (0x211B, 0x0052),
+    (0x211C, 0x0052), (0x211D, 0x0052), (0x2124, 0x005A), (0x2128, 0x005A), (0x212A, 0x004B),
+    (0x212B, 0x0041), (0x212C, 0x0042), (0x212D, 0x0043), (0x212F, 0x0065), (0x2130, 0x0045),
+    (0x2131, 0x0046), (0x2133, 0x004D), (0x2134, 0x006F), (0x2139, 0x0069), (0x2145, 0x0044),
+    (0x2146, 0x0064), (0x2147, 0x0065), (0x2148, 0x0069), (0x2149, 0x006A), (0x2160, 0x0049),
+    (0x2164, 0x0056), (0x2169, 0x0058), (0x216C, 0x004C), (0x216D, 0x0043), (0x216E, 0x0044),
+    (0x216F, 0x004D), (0x2170, 0x0069), (0x2174, 0x0076), (0x2179, 0x0078), (0x217C, 0x006C),
+    (0x217D, 0x0063), (0x217E, 0x0064), (0x217F, 0x006D), (0x2212, 0x002D), (0x2215, 0x002F),
+    (0x2216, 0x005C), (0x2223, 0x007C), (0x223C, 0x007E), (0x2260, 0x003D), (0x226E, 0x003C),
+    (0x226F, 0x003E), (0x2460, 0x0031), (0x2461, 0x0032), (0x2462, 0x0033), (0x2463, 0x0034),
+    (0x2464, 0x0035), (0x2465, 0x0036), (0x2466, 0x0037), (0x2467, 0x0038), (0x2468, 0x0039),
+    (0x24B6, 0x0041), (0x24B7, 0x0042), (0x24B8, 0x0043), (0x24B9, 0x0044), (0x24BA, 0x0045),
+    (0x24BB, 0x0046), (0x24BC, 0x0047), (0x24BD, 0x0048), (0x24BE, 0x0049), (0x24BF, 0x004A),
+    (0x24C0, 0x004B), (0x24C1, 0x004C), (0x24C2, 0x004D), (0x24C3, 0x004E), (0x24C4, 0x004F),
+    (0x24C5, 0x0050), (0x24C6, 0x0051), (0x24C7, 0x0052), (0x24C8, 0x0053), (0x24C9, 0x0054),
+    (0x24CA, 0x0055), (0x24CB, 0x0056), (0x24CC, 0x0057), (0x24CD, 0x0058), (0x24CE, 0x0059),
+    (0x24CF, 0x005A), (0x24D0, 0x0061), (0x24D1, 0x0062), (0x24D2, 0x0063), (0x24D3, 0x0064),
+    (0x24D4, 0x0065), (0x24D5, 0x0066), (0x24D6, 0x0067), (0x24D7, 0x0068), (0x24D8, 0x0069),
+    (0x24D9, 0x006A), (0x24DA, 0x006B), (0x24DB, 0x006C), (0x24DC, 0x006D), (0x24DD, 0x006E),
+    (0x24DE, 0x006F), (0x24DF, 0x0070), (0x24E0, 0x0071), (0x24E1, 0x0072), (0x24E2, 0x0073),
+    (0x24E3, 0x0074), (0x24E4, 0x0075), (0x24E5, 0x0076), (0x24E6, 0x0077), (0x24E7, 0x0078),
+    (0x24E8, 0x0079), (0x24E9, 0x007A), (0x24EA, 0x0030), (0x2C7C, 0x006A), (0x2C7D, 0x0056),
+    (0x3000, 0x0020), (0xA7F2, 0x0043), (0xA7F3, 0x0046), (0xA7F4, 0x0051), (0xFB29, 0x002B),
+    (0xFE10, 0x002C), (0xFE13, 0x003A), (0xFE14, 0x003B), (0xFE15, 0x0021), (0xFE16, 0x003F),
+    (0xFE33, 0x005F), (0xFE34, 0x005F), (0xFE35, 0x0028), (0xFE36, 0x0029), (0xFE37, 0x007B),
+    (0xFE38, 0x007D), (0xFE47, 0x005B), (0xFE48, 0x005D), (0xFE4D, 0x005F), (0xFE4E, 0x005F),
+    (0xFE4F, 0x005F), (0xFE50, 0x002C), (0xFE52, 0x002E), (0xFE54, 0x003B), (0xFE55, 0x003A),
+    (0xFE56, 0x003F), (0xFE57, 0x0021), (0xFE59, 0x0028), (0xFE5A, 0x0029), (0xFE5B, 0x007B),
+    (0xFE5C, 0x007D), (0xFE5F, 0x0023), (0xFE60, 0x0026), (0xFE61, 0x002A), (0xFE62, 0x002B),
+    (0xFE63, 0x002D), (0xFE64, 0x003C), (0xFE65, 0x003E), (0xFE66, 0x003D), (0xFE68, 0x005C),
+    (0xFE69, 0x0024), (0xFE6A, 0x0025), (0xFE6B, 0x0040), (0xFF01, 0x0021), (0xFF02, 0x0022),
+    (0xFF03, 0x0023), (0xFF04, 0x0024), (0xFF05, 0x0025), (0xFF06, 0x0026), (0xFF07, 0x0027),
+    (0xFF08, 0x0028), (0xFF09, 0x0029), (0xFF0A, 0x002A), (0xFF0B, 0x002B), (0xFF0C, 0x002C),
+    (0xFF0D, 0x002D), (0xFF0E, 0x002E), (0xFF0F, 0x002F), (0xFF10, 0x0030), (0xFF11, 0x0031),
+    (0xFF12, 0x0032), (0xFF13, 0x0033), (0xFF14, 0x0034), (0xFF15, 0x0035), (0xFF16, 0x0036),
+    (0xFF17, 0x0037), (0xFF18, 0x0038), (0xFF19, 0x0039), (0xFF1A, 0x003A), (0xFF1B, 0x003B),
+    (0xFF1C, 0x003C), (0xFF1D, 0x003D), (0xFF1E, 0x003E), (0xFF1F, 0x003F), (0xFF20, 0x0040),
+    (0xFF21, 0x0041), (0xFF22, 0x0042), (0xFF23, 0x0043), (0xFF24, 0x0044), (0xFF25, 0x0045),
+    (0xFF26, 0x0046), (0xFF27, 0x0047), (0xFF28, 0x0048), (0xFF29, 0x0049), (0xFF2A, 0x004A),
+    (0xFF2B, 0x004B), (0xFF2C, 0x004C), (0xFF2D, 0x004D), (0xFF2E, 0x004E), (0xFF2F, 0x004F),
+    (0xFF30, 0x0050), (0xFF31, 0x0051), (0xFF32, 0x0052), (0xFF33, 0x0053), (0xFF34, 0x0054),
+    (0xFF35, 0x0055), (0xFF36, 0x0056), (0xFF37, 0x0057), (0xFF38, 0x0058), (0xFF39, 0x0059),
+    (0xFF3A, 0x005A), (0xFF3B, 0x005B), (0xFF3C, 0x005C), (0xFF3D, 0x005D), (0xFF3E, 0x005E),
+    (0xFF3F, 0x005F), (0xFF40, 0x0060), (0xFF41, 0x0061), (0xFF42, 0x0062), (0xFF43, 0x0063),
+    (0xFF44, 0x0064), (0xFF45, 0x0065), (0xFF46, 0x0066), (0xFF47, 0x0067), (0xFF48, 0x0068),
+    (0xFF49, 0x0069), (0xFF4A, 0x006A), (0xFF4B, 0x006B), (0xFF4C, 0x006C), (0xFF4D, 0x006D),
+    (0xFF4E, 0x006E), (0xFF4F, 0x006F), (0xFF50, 0x0070), (0xFF51, 0x0071), (0xFF52, 0x0072),
+    (0xFF53, 0x0073), (0xFF54, 0x0074), (0xFF55, 0x0075), (0xFF56, 0x0076), (0xFF57, 0x0077),
+    (0xFF58, 0x0078), (0xFF59, 0x0079), (0xFF5A, 0x007A), (0xFF5B, 0x007B), (0xFF5C, 0x007C),
+    (0xFF5D, 0x007D), (0xFF5E, 0x007E), (0xFFE2, 0x00AA), (0xFFE8, 0x00B3), (0xFFED, 0x00FE),
+];
+
+/// Code page 855.
+#[rustfmt::skip]
+pub(crate) const CP855: [(u16, u16); 907] = [
+    (0x00AA, 0x0061), (0x00B2, 0x0032), (0x00B3, 0x0033), (0x00B9, 0x0031), (0x00BA, 0x006F),
+    (0x00C0, 0x0041), (0x00C1, 0x0041), (0x00C2, 0x0041), (0x00C3, 0x0041), (0x00C4, 0x0041),
+    (0x00C5, 0x0041), (0x00C7, 0x0043), (0x00C8, 0x0045), (0x00C9, 0x0045), (0x00CA, 0x0045),
+    (0x00CB, 0x0045), (0x00CC, 0x0049), (0x00CD, 0x0049), (0x00CE, 0x0049), (0x00CF, 0x0049),
+    (0x00D1, 0x004E), (0x00D2, 0x004F), (0x00D3, 0x004F), (0x00D4, 0x004F), (0x00D5, 0x004F),
+    (0x00D6, 0x004F), (0x00D9, 0x0055), (0x00DA, 0x0055), (0x00DB, 0x0055), (0x00DC, 0x0055),
+    (0x00DD, 0x0059), (0x00E0, 0x0061), (0x00E1, 0x0061), (0x00E2, 0x0061), (0x00E3, 0x0061),
+    (0x00E4, 0x0061), (0x00E5, 0x0061), (0x00E7, 0x0063), (0x00E8, 0x0065), (0x00E9, 0x0065),
+    (0x00EA, 0x0065), (0x00EB, 0x0065), (0x00EC, 0x0069), (0x00ED, 0x0069), (0x00EE, 0x0069),
+    (0x00EF, 0x0069), (0x00F1, 0x006E), (0x00F2, 0x006F), (0x00F3, 0x006F), (0x00F4, 0x006F),
+    (0x00F5, 0x006F), (0x00F6, 0x006F), (0x00F9, 0x0075), (0x00FA, 0x0075), (0x00FB, 0x0075),
+    (0x00FC, 0x0075), (0x00FD, 0x0079), (0x00FF, 0x0079), (0x0100, 0x0041), (0x0101, 0x0061),
+    (0x0102, 0x0041), (0x0103, 0x0061), (0x0104, 0x0041), (0x0105, 0x0061), (0x0106, 0x0043),
+    (0x0107, 0x0063), (0x0108, 0x0043), (0x0109, 0x0063), (0x010A, 0x0043), (0x010B, 0x0063),
+    (0x010C, 0x0043), (0x010D, 0x0063), (0x010E, 0x0044), (0x010F, 0x0064), (0x0112, 0x0045),
+    (0x0113, 0x0065), (0x0114, 0x0045), (0x0115, 0x0065), (0x0116, 0x0045), (0x0117, 0x0065),
+    (0x0118, 0x0045), (0x0119, 0x0065), (0x011A, 0x0045), (0x011B, 0x0065), (0x011C, 0x0047),
+    (0x011D, 0x0067), (0x011E, 0x0047), (0x011F, 0x0067), (0x0120, 0x0047), (0x0121, 0x0067),
+    (0x0122, 0x0047), (0x0123, 0x0067), (0x0124, 0x0048), (0x0125, 0x0068), (0x0128, 0x0049),
+    (0x0129, 0x0069), (0x012A, 0x0049), (0x012B, 0x0069), (0x012C, 0x0049), (0x012D, 0x0069),
+    (0x012E, 0x0049), (0x012F, 0x0069), (0x0130, 0x0049), (0x0134, 0x004A), (0x0135, 0x006A),
+    (0x0136, 0x004B), (0x0137, 0x006B), (0x0139, 0x004C), (0x013A, 0x006C), (0x013B, 0x004C),
+    (0x013C, 0x006C), (0x013D, 0x004C), (0x013E, 0x006C), (0x0143, 0x004E), (0x0144, 0x006E),
+    (0x0145, 0x004E), (0x0146, 0x006E), (0x0147, 0x004E), (0x0148, 0x006E), (0x014C, 0x004F),
+    (0x014D, 0x006F), (0x014E, 0x004F), (0x014F, 0x006F), (0x0150, 0x004F), (0x0151, 0x006F),
+    (0x0154, 0x0052), (0x0155, 0x0072), (0x0156, 0x0052), (0x0157, 0x0072), (0x0158, 0x0052),
+    (0x0159, 0x0072), (0x015A, 0x0053), (0x015B, 0x0073), (0x015C, 0x0053), (0x015D, 0x0073),
+    (0x015E, 0x0053), (0x015F, 0x0073), (0x0160, 0x0053), (0x0161, 0x0073), (0x0162, 0x0054),
+    (0x0163, 0x0074), (0x0164, 0x0054), (0x0165, 0x0074), (0x0168, 0x0055), (0x0169, 0x0075),
+    (0x016A, 0x0055), (0x016B, 0x0075), (0x016C, 0x0055), (0x016D, 0x0075), (0x016E, 0x0055),
+    (0x016F, 0x0075), (0x0170, 0x0055), (0x0171, 0x0075), (0x0172, 0x0055), (0x0173, 0x0075),
+    (0x0174, 0x0057), (0x0175, 0x0077), (0x0176, 0x0059), (0x0177, 0x0079), (0x0178, 0x0059),
+    (0x0179, 0x005A), (0x017A, 0x007A), (0x017B, 0x005A), (0x017C, 0x007A), (0x017D, 0x005A),
+    (0x017E, 0x007A), (0x017F, 0x0073), (0x01A0, 0x004F), (0x01A1, 0x006F), (0x01AF, 0x0055),
+    (0x01B0, 0x0075), (0x01CD, 0x0041), (0x01CE, 0x0061), (0x01CF, 0x0049), (0x01D0, 0x0069),
+    (0x01D1, 0x004F), (0x01D2, 0x006F), (0x01D3, 0x0055), (0x01D4, 0x0075), (0x01D5, 0x0055),
+    (0x01D6, 0x0075), (0x01D7, 0x0055), (0x01D8, 0x0075), (0x01D9, 0x0055), (0x01DA, 0x0075),
+    (0x01DB, 0x0055), (0x01DC, 0x0075), (0x01DE, 0x0041), (0x01DF, 0x0061), (0x01E0, 0x0041),
+    (0x01E1, 0x0061), (0x01E6, 0x0047), (0x01E7, 0x0067), (0x01E8, 0x004B), (0x01E9, 0x006B),
+    (0x01EA, 0x004F), (0x01EB, 0x006F), (0x01EC, 0x004F), (0x01ED, 0x006F), (0x01F0, 0x006A),
+    (0x01F4, 0x0047), (0x01F5, 0x0067), (0x01F8, 0x004E), (0x01F9, 0x006E), (0x01FA, 0x0041),
+    (0x01FB, 0x0061), (0x0200, 0x0041), (0x0201, 0x0061), (0x0202, 0x0041), (0x0203, 0x0061),
+    (0x0204, 0x0045), (0x0205, 0x0065), (0x0206, 0x0045), (0x0207, 0x0065), (0x0208, 0x0049),
+    (0x0209, 0x0069), (0x020A, 0x0049), (0x020B, 0x0069), (0x020C, 0x004F), (0x020D, 0x006F),
+    (0x020E, 0x004F), (0x020F, 0x006F), (0x0210, 0x0052), (0x0211, 0x0072), (0x0212, 0x0052),
+    (0x0213, 0x0072), (0x0214, 0x0055), (0x0215, 0x0075), (0x0216, 0x0055), (0x0217, 0x0075),
+    (0x0218, 0x0053), (0x0219, 0x0073), (0x021A, 0x0054), (0x021B, 0x0074), (0x021E, 0x0048),
+    (0x021F, 0x0068), (0x0226, 0x0041), (0x0227, 0x0061), (0x0228, 0x0045), (0x0229, 0x0065),
+    (0x022A, 0x004F), (0x022B, 0x006F), (0x022C, 0x004F), (0x022D, 0x006F), (0x022E, 0x004F),
+    (0x022F, 0x006F), (0x0230, 0x004F), (0x0231, 0x006F), (0x0232, 0x0059), (0x0233, 0x0079),
+    (0x02B0, 0x0068), (0x02B2, 0x006A), (0x02B3, 0x0072), (0x02B7, 0x0077), (0x02B8, 0x0079),
+    (0x02E1, 0x006C), (0x02E2, 0x0073), (0x02E3, 0x0078), (0x037E, 0x003B), (0x0400, 0x00A9),
+    (0x040D, 0x00B8), (0x0450, 0x00A8), (0x045D, 0x00B7), (0x04C1, 0x00EA), (0x04C2, 0x00E9),
+    (0x04D0, 0x00A1), (0x04D1, 0x00A0), (0x04D2, 0x00A1), (0x04D3, 0x00A0), (0x04D6, 0x00A9),
+    (0x04D7, 0x00A8), (0x04DC, 0x00EA), (0x04DD, 0x00E9), (0x04DE, 0x00F4), (0x04DF, 0x00F3),
+    (0x04E2, 0x00B8), (0x04E3, 0x00B7), (0x04E4, 0x00B8), (0x04E5, 0x00B7), (0x04E6, 0x00D7),
+    (0x04E7, 0x00D6), (0x04EC, 0x00F8), (0x04ED, 0x00F7), (0x04EE, 0x00E8), (0x04EF, 0x00E7),
+    (0x04F0, 0x00E8), (0x04F1, 0x00E7), (0x04F2, 0x00E8), (0x04F3, 0x00E7), (0x04F4, 0x00FC),
+    (0x04F5, 0x00FB), (0x04F8, 0x00F2), (0x04F9, 0x00F1), (0x1D2C, 0x0041), (0x1D2E, 0x0042),
+    (0x1D30, 0x0044), (0x1D31, 0x0045), (0x1D33, 0x0047), (0x1D34, 0x0048), (0x1D35, 0x0049),
+    (0x1D36, 0x004A), (0x1D37, 0x004B), (0x1D38, 0x004C), (0x1D39, 0x004D), (0x1D3A, 0x004E),
+    (0x1D3C, 0x004F), (0x1D3E, 0x0050), (0x1D3F, 0x0052), (0x1D40, 0x0054), (0x1D41, 0x0055),
+    (0x1D42, 0x0057), (0x1D43, 0x0061), (0x1D47, 0x0062), (0x1D48, 0x0064), (0x1D49, 0x0065),
+    (0x1D4D, 0x0067), (0x1D4F, 0x006B), (0x1D50, 0x006D), (0x1D52, 0x006F), (0x1D56, 0x0070),
+    (0x1D57, 0x0074), (0x1D58, 0x0075), (0x1D5B, 0x0076), (0x1D62, 0x0069), (0x1D63, 0x0072),
+    (0x1D64, 0x0075), (0x1D65, 0x0076), (0x1D78, 0x00D4), (0x1D9C, 0x0063), (0x1DA0, 0x0066),
+    (0x1DBB, 0x007A), (0x1E00, 0x0041), (0x1E01, 0x0061), (0x1E02, 0x0042), (0x1E03, 0x0062),
+    (0x1E04, 0x0042), (0x1E05, 0x0062), (0x1E06, 0x0042), (0x1E07, 0x0062), (0x1E08, 0x0043),
+    (0x1E09, 0x0063), (0x1E0A, 0x0044), (0x1E0B, 0x0064), (0x1E0C, 0x0044), (0x1E0D, 0x0064),
+    (0x1E0E, 0x0044), (0x1E0F, 0x0064), (0x1E10, 0x0044), (0x1E11, 0x0064), (0x1E12, 0x0044),
+    (0x1E13, 0x0064), (0x1E14, 0x0045), (0x1E15, 0x0065), (0x1E16, 0x0045), (0x1E17, 0x0065),
+    (0x1E18, 0x0045), (0x1E19, 0x0065), (0x1E1A, 0x0045), (0x1E1B, 0x0065), (0x1E1C, 0x0045),
+    (0x1E1D, 0x0065), (0x1E1E, 0x0046), (0x1E1F, 0x0066), (0x1E20, 0x0047), (0x1E21, 0x0067),
+    (0x1E22, 0x0048), (0x1E23, 0x0068), (0x1E24, 0x0048), (0x1E25, 0x0068), (0x1E26, 0x0048),
+    (0x1E27, 0x0068), (0x1E28, 0x0048), (0x1E29, 0x0068), (0x1E2A, 0x0048), (0x1E2B, 0x0068),
+    (0x1E2C, 0x0049), (0x1E2D, 0x0069), (0x1E2E, 0x0049), (0x1E2F, 0x0069), (0x1E30, 0x004B),
+    (0x1E31, 0x006B), (0x1E32, 0x004B), (0x1E33, 0x006B), (0x1E34, 0x004B), (0x1E35, 0x006B),
+    (0x1E36, 0x004C), (0x1E37, 0x006C), (0x1E38, 0x004C), (0x1E39, 0x006C), (0x1E3A, 0x004C),
+    (0x1E3B, 0x006C), (0x1E3C, 0x004C), (0x1E3D, 0x006C), (0x1E3E, 0x004D), (0x1E3F, 0x006D),
+    (0x1E40, 0x004D), (0x1E41, 0x006D), (0x1E42, 0x004D), (0x1E43, 0x006D), (0x1E44, 0x004E),
+    (0x1E45, 0x006E), (0x1E46, 0x004E), (0x1E47, 0x006E), (0x1E48, 0x004E), (0x1E49, 0x006E),
+    (0x1E4A, 0x004E), (0x1E4B, 0x006E), (0x1E4C, 0x004F), (0x1E4D, 0x006F), (0x1E4E, 0x004F),
+    (0x1E4F, 0x006F), (0x1E50, 0x004F), (0x1E51, 0x006F), (0x1E52, 0x004F), (0x1E53, 0x006F),
+    (0x1E54, 0x0050), (0x1E55, 0x0070), (0x1E56, 0x0050), (0x1E57, 0x0070), (0x1E58, 0x0052),
+    (0x1E59, 0x0072), (0x1E5A, 0x0052), (0x1E5B, 0x0072), (0x1E5C, 0x0052), (0x1E5D, 0x0072),
+    (0x1E5E, 0x0052), (0x1E5F, 0x0072), (0x1E60, 0x0053), (0x1E61, 0x0073), (0x1E62, 0x0053),
+    (0x1E63, 0x0073), (0x1E64, 0x0053), (0x1E65, 0x0073), (0x1E66, 0x0053), (0x1E67, 0x0073),
+    (0x1E68, 0x0053), (0x1E69, 0x0073), (0x1E6A, 0x0054), (0x1E6B, 0x0074), (0x1E6C, 0x0054),
+    (0x1E6D, 0x0074), (0x1E6E, 0x0054), (0x1E6F, 0x0074), (0x1E70, 0x0054), (0x1E71, 0x0074),
+    (0x1E72, 0x0055), (0x1E73, 0x0075), (0x1E74, 0x0055), (0x1E75, 0x0075), (0x1E76, 0x0055),
+    (0x1E77, 0x0075), (0x1E78, 0x0055), (0x1E79, 0x0075), (0x1E7A, 0x0055), (0x1E7B, 0x0075),
+    (0x1E7C, 0x0056), (0x1E7D, 0x0076), (0x1E7E, 0x0056), (0x1E7F, 0x0076), (0x1E80, 0x0057),
+    (0x1E81, 0x0077), (0x1E82, 0x0057), (0x1E83, 0x0077), (0x1E84, 0x0057), (0x1E85, 0x0077),
+    (0x1E86, 0x0057), (0x1E87, 0x0077), (0x1E88, 0x0057), (0x1E89, 0x0077), (0x1E8A, 0x0058),
+    (0x1E8B, 0x0078), (0x1E8C, 0x0058), (0x1E8D, 0x0078), (0x1E8E, 0x0059), (0x1E8F, 0x0079),
+    (0x1E90, 0x005A), (0x1E91, 0x007A), (0x1E92, 0x005A), (0x1E93, 0x007A), (0x1E94, 0x005A),
+    (0x1E95, 0x007A), (0x1E96, 0x0068), (0x1E97, 0x0074), (0x1E98, 0x0077), (0x1E99, 0x0079),
+    (0x1E9B, 0x0073), (0x1EA0, 0x0041), (0x1EA1, 0x0061), (0x1EA2, 0x0041), (0x1EA3, 0x0061),
+    (0x1EA4, 0x0041), (0x1EA5, 0x0061), (0x1EA6, 0x0041), (0x1EA7, 0x0061), (0x1EA8, 0x0041),
+    (0x1EA9, 0x0061), (0x1EAA, 0x0041), (0x1EAB, 0x0061), (0x1EAC, 0x0041), (0x1EAD, 0x0061),
+    (0x1EAE, 0x0041), (0x1EAF, 0x0061), (0x1EB0, 0x0041), (0x1EB1, 0x0061), (0x1EB2, 0x0041),
+    (0x1EB3, 0x0061), (0x1EB4, 0x0041), (0x1EB5, 0x0061), (0x1EB6, 0x0041), (0x1EB7, 0x0061),
+    (0x1EB8, 0x0045), (0x1EB9, 0x0065), (0x1EBA, 0x0045), (0x1EBB, 0x0065), (0x1EBC, 0x0045),
+    (0x1EBD, 0x0065), (0x1EBE, 0x0045), (0x1EBF, 0x0065), (0x1EC0, 0x0045), (0x1EC1, 0x0065),
+    (0x1EC2, 0x0045), (0x1EC3, 0x0065), (0x1EC4, 0x0045), (0x1EC5, 0x0065), (0x1EC6, 0x0045),
+    (0x1EC7, 0x0065), (0x1EC8, 0x0049), (0x1EC9, 0x0069), (0x1ECA, 0x0049), (0x1ECB, 0x0069),
+    (0x1ECC, 0x004F), (0x1ECD, 0x006F), (0x1ECE, 0x004F), (0x1ECF, 0x006F), (0x1ED0, 0x004F),
+    (0x1ED1, 0x006F), (0x1ED2, 0x004F), (0x1ED3, 0x006F), (0x1ED4, 0x004F), (0x1ED5, 0x006F),
+    (0x1ED6, 0x004F), (0x1ED7, 0x006F), (0x1ED8, 0x004F), (0x1ED9, 0x006F), (0x1EDA, 0x004F),
+    (0x1EDB, 0x006F), (0x1EDC, 0x004F), (0x1EDD, 0x006F), (0x1EDE, 0x004F), (0x1EDF, 0x006F),
+    (0x1EE0, 0x004F), (0x1EE1, 0x006F), (0x1EE2, 0x004F), (0x1EE3, 0x006F), (0x1EE4, 0x0055),
+    (0x1EE5, 0x0075), (0x1EE6, 0x0055), (0x1EE7, 0x0075), (0x1EE8, 0x0055), (0x1EE9, 0x0075),
+    (0x1EEA, 0x0055), (0x1EEB, 0x0075), (0x1EEC, 0x0055), (0x1EED, 0x0075), (0x1EEE, 0x0055),
+    (0x1EEF, 0x0075), (0x1EF0, 0x0055), (0x1EF1, 0x0075), (0x1EF2, 0x0059), (0x1EF3, 0x0079),
+    (0x1EF4, 0x0059), (0x1EF5, 0x0079), (0x1EF6, 0x0059), (0x1EF7, 0x0079), (0x1EF8, 0x0059),
+    (0x1EF9, 0x0079), (0x1FEF, 0x0060), (0x2000, 0x0020), (0x2001, 0x0020), (0x2002, 0x0020),
+    (0x2003, 0x0020), (0x2004, 0x0020), (0x2005, 0x0020), (0x2006, 0x0020), (0x2007, 0x0020),
+    (0x2008, 0x0020), (0x2009, 0x0020), (0x200A, 0x0020), (0x2010, 0x002D), (0x2012, 0x002D),
+    (0x2013, 0x002D), (0x2014, 0x002D), (0x2015, 0x002D), (0x2018, 0x0027), (0x2019, 0x0027),
+    (0x201A, 0x002C), (0x201B, 0x0027), (0x201C, 0x0022), (0x201D, 0x0022), (0x201E, 0x0022),
+    (0x201F, 0x0022), (0x2024, 0x002E), (0x202F, 0x0020), (0x2032, 0x0027), (0x2035, 0x0060),
+    (0x2039, 0x003C), (0x203A, 0x003E), (0x205F, 0x0020), (0x2070, 0x0030), (0x2071, 0x0069),
+    (0x2074, 0x0034), (0x2075, 0x0035), (0x2076, 0x0036), (0x2077, 0x0037), (0x2078, 0x0038),
+    (0x2079, 0x0039), (0x207A, 0x002B), (0x207C, 0x003D), (0x207D, 0x0028), (0x207E, 0x0029),
+    (0x207F, 0x006E), (0x2080, 0x0030), (0x2081, 0x0031), (0x2082, 0x0032), (0x2083, 0x0033),
+    (0x2084, 0x0034), (0x2085, 0x0035), (0x2086, 0x0036), (0x2087, 0x0037), (0x2088, 0x0038),
+    (0x2089, 0x0039), (0x208A, 0x002B), (0x208C, 0x003D), (0x208D, 0x0028), (0x208E, 0x0029),
+    (0x2090, 0x0061), (0x2091, 0x0065), (0x2092, 0x006F), (0x2093, 0x0078), (0x2095, 0x0068),
+    (0x2096, 0x006B), (0x2097, 0x006C), (0x2098, 0x006D), (0x2099, 0x006E), (0x209A, 0x0070),
+    (0x209B, 0x0073), (0x209C, 0x0074), (0x2102, 0x0043), (0x210A, 0x0067), (0x210B, 0x0048),
+    (0x210C, 0x0048), (0x210D, 0x0048), (0x210E, 0x0068), (0x2110, 0x0049), (0x2111, 0x0049),
+    (0x2112, 0x004C), (0x2113, 0x006C), (0x2115, 0x004E), (0x2119, 0x0050), (0x211A, 0x0051),
+    (0x211B, 0x0052), (0x211C, 0x0052), (0x211D, 0x0052), (0x2124, 0x005A), (0x2128, 0x005A),
+    (0x212A, 0x004B), (0x212B, 0x0041), (0x212C, 0x0042), (0x212D, 0x0043), (0x212F, 0x0065),
+    (0x2130, 0x0045), (0x2131, 0x0046), (0x2133, 0x004D), (0x2134, 0x006F), (0x2139, 0x0069),
+    (0x2145, 0x0044), (0x2146, 0x0064), (0x2147, 0x0065), (0x2148, 0x0069), (0x2149, 0x006A),
+    (0x2160, 0x0049), (0x2164, 0x0056), (0x2169, 0x0058), (0x216C, 0x004C), (0x216D, 0x0043),
+    (0x216E, 0x0044), (0x216F, 0x004D), (0x2170, 0x0069), (0x2174, 0x0076), (0x2179, 0x0078),
+    (0x217C, 0x006C), (0x217D, 0x0063), (0x217E, 0x0064), (0x217F, 0x006D), (0x2212, 0x002D),
+    (0x2215, 0x002F), (0x2216, 0x005C), (0x2223, 0x007C), (0x223C, 0x007E), (0x2260, 0x003D),
+    (0x226E, 0x003C), (0x226F, 0x003E), (0x2460, 0x0031), (0x2461, 0x0032), (0x2462, 0x0033),
+    (0x2463, 0x0034), (0x2464, 0x0035), (0x2465, 0x0036), (0x2466, 0x0037), (0x2467, 0x0038),
+    (0x2468, 0x0039), (0x24B6, 0x0041), (0x24B7, 0x0042), (0x24B8, 0x0043), (0x24B9, 0x0044),
+    (0x24BA, 0x0045), (0x24BB, 0x0046), (0x24BC, 0x0047), (0x24BD, 0x0048), (0x24BE, 0x0049),
+    (0x24BF, 0x004A), (0x24C0, 0x004B), (0x24C1, 0x004C), (0x24C2, 0x004D), (0x24C3, 0x004E),
+    (0x24C4, 0x004F), (0x24C5, 0x0050), (0x24C6, 0x0051), (0x24C7, 0x0052), (0x24C8, 0x0053),
+    (0x24C9, 0x0054), (0x24CA, 0x0055), (0x24CB, 0x0056), (0x24CC, 0x0057), (0x24CD, 0x0058),
+    (0x24CE, 0x0059), (0x24CF, 0x005A), (0x24D0, 0x0061), (0x24D1, 0x0062), (0x24D2, 0x0063),
+    (0x24D3, 0x0064), (0x24D4, 0x0065), (0x24D5, 0x0066), (0x24D6, 0x0067), (0x24D7, 0x0068),
+    (0x24D8, 0x0069), (0x24D9, 0x006A), (0x24DA, 0x006B), (0x24DB, 0x006C), (0x24DC, 0x006D),
+    (0x24DD, 0x006E), (0x24DE, 0x006F), (0x24DF, 0x0070), (0x24E0, 0x0071), (0x24E1, 0x0072),
+    (0x24E2, 0x0073), (0x24E3, 0x0074), (0x24E4, 0x0075), (0x24E5, 0x0076), (0x24E6, 0x0077),
+    (0x24E7, 0x0078), (0x24E8, 0x0079), (0x24E9, 0x007A), (0x24EA, 0x0030), (0x2C7C, 0x006A),
+    (0x2C7D, 0x0056), (0x3000, 0x0020), (0xA69C, 0x009E), (0xA69D, 0x00ED), (0xA7F2, 0x0043),
+    (0xA7F3, 0x0046), (0xA7F4, 0x0051), (0xFB29, 0x002B), (0xFE10, 0x002C), (0xFE13, 0x003A),
+    (0xFE14, 0x003B), (0xFE15, 0x0021), (0xFE16, 0x003F), (0xFE33, 0x005F), (0xFE34, 0x005F),
+    (0xFE35, 0x0028), (0xFE36, 0x0029), (0xFE37, 0x007B), (0xFE38, 0x007D), (0xFE47, 0x005B),
+    (0xFE48, 0x005D), (0xFE4D, 0x005F), (0xFE4E, 0x005F), (0xFE4F, 0x005F), (0xFE50, 0x002C),
+    (0xFE52, 0x002E), (0xFE54, 0x003B), (0xFE55, 0x003A), (0xFE56, 0x003F), (0xFE57, 0x0021),
+    (0xFE59, 0x0028), (0xFE5A, 0x0029), (0xFE5B, 0x007B), (0xFE5C, 0x007D), (0xFE5F, 0x0023),
+    (0xFE60, 0x0026), (0xFE61, 0x002A), (0xFE62, 0x002B), (0xFE63, 0x002D), (0xFE64, 0x003C),
+    (0xFE65, 0x003E), (0xFE66, 0x003D), (0xFE68, 0x005C), (0xFE69, 0x0024), (0xFE6A, 0x0025),
+    (0xFE6B, 0x0040), (0xFF01, 0x0021), (0xFF02, 0x0022), (0xFF03, 0x0023), (0xFF04, 0x0024),
+    (0xFF05, 0x0025), (0xFF06, 0x0026), (0xFF07, 0x0027), (0xFF08, 0x0028), (0xFF09, 0x0029),
+    (0xFF0A, 0x002A), (0xFF0B, 0x002B), (0xFF0C, 0x002C), (0xFF0D, 0x002D), (0xFF0E, 0x002E),
+    (0xFF0F, 0x002F), (0xFF10, 0x0030), (0xFF11, 0x0031), (0xFF12, 0x0032), (0xFF13, 0x0033),
+    (0xFF14, 0x0034), (0xFF15, 0x0035), (0xFF16, 0x0036), (0xFF17, 0x0037), (0xFF18, 0x0038),
+    (0xFF19, 0x0039), (0xFF1A, 0x003A), (0xFF1B, 0x003B), (0xFF1C, 0x003C), (0xFF1D, 0x003D),
+    (0xFF1E, 0x003E), (0xFF1F, 0x003F), (0xFF20, 0x0040), (0xFF21, 0x0041), (0xFF22, 0x0042),
+    (0xFF23, 0x0043), (0xFF24, 0x0044), (0xFF25, 0x0045), (0xFF26, 0x0046), (0xFF27, 0x0047),
+    (0xFF28, 0x0048), (0xFF29, 0x0049), (0xFF2A, 0x004A), (0xFF2B, 0x004B), (0xFF2C, 0x004C),
+    (0xFF2D, 0x004D), (0xFF2E, 0x004E), (0xFF2F, 0x004F), (0xFF30, 0x0050), (0xFF31, 0x0051),
+    (0xFF32, 0x0052), (0xFF33, 0x0053), (0xFF34, 0x0054), (0xFF35, 0x0055), (0xFF36, 0x0056),
+    (0xFF37, 0x0057), (0xFF38, 0x0058), (0xFF39, 0x0059), (0xFF3A, 0x005A), (0xFF3B, 0x005B),
+    (0xFF3C, 0x005C), (0xFF3D, 0x005D), (0xFF3E, 0x005E), (0xFF3F, 0x005F), (0xFF40, 0x0060),
+    (0xFF41, 0x0061), (0xFF42, 0x0062), (0xFF43, 0x0063), (0xFF44, 0x0064), (0xFF45, 0x0065),
+    (0xFF46, 0x0066), (0xFF47, 0x0067), (0xFF48, 0x0068), (0xFF49, 0x0069), (0xFF4A, 0x006A),
+    (0xFF4B, 0x006B), (0xFF4C, 0x006C), (0xFF4D, 0x006D), (0xFF4E, 0x006E), (0xFF4F, 0x006F),
+    (0xFF50, 0x0070), (0xFF51, 0x0071), (0xFF52, 0x0072), (0xFF53, 0x0073), (0xFF54, 0x0074),
+    (0xFF55, 0x0075), (0xFF56, 0x0076), (0xFF57, 0x0077), (0xFF58, 0x0078), (0xFF59, 0x0079),
+    (0xFF5A, 0x007A), (0xFF5B, 0x007B), (0xFF5C, 0x007C), (0xFF5D, 0x007D), (0xFF5E, 0x007E),
+    (0xFFE8, 0x00B3), (0xFFED, 0x00FE),
+];
+
+/// Code page 857.
+#[rustfmt::skip]
+pub(crate) const CP857: [(u16, u16); 822] = [
+    (0x00DD, 0x0059), (0x00FD, 0x0079), (0x0100, 0x0041), (0x0101, 0x0061), (0x0102, 0x0041),
+    (0x0103, 0x0061), (0x0104, 0x0041), (0x0105, 0x0061), (0x0106, 0x0043), (0x0107, 0x0063),
+    (0x0108, 0x0043), (0x0109, 0x0063), (0x010A, 0x0043), (0x010B, 0x0063), (0x010C, 0x0043),
+    (0x010D, 0x0063), (0x010E, 0x0044), (0x010F, 0x0064), (0x0112, 0x0045), (0x0113, 0x0065),
+    (0x0114, 0x0045), (0x0115, 0x0065), (0x0116, 0x0045), (0x0117, 0x0065), (0x0118, 0x0045),
+    (0x0119, 0x0065), (0x011A, 0x0045), (0x011B, 0x0065), (0x011C, 0x0047), (0x011D, 0x0067),
+    (0x0120, 0x0047), (0x0121, 0x0067), (0x0122, 0x0047), (0x0123, 0x0067), (0x0124, 0x0048),
+    (0x0125, 0x0068), (0x0128, 0x0049), (0x0129, 0x0069), (0x012A, 0x0049), (0x012B, 0x0069),
+    (0x012C, 0x0049), (0x012D, 0x0069), (0x012E, 0x0049), (0x012F, 0x0069), (0x0134, 0x004A),
+    (0x0135, 0x006A), (0x0136, 0x004B), (0x0137, 0x006B), (0x0139, 0x004C), (0x013A, 0x006C),
+    (0x013B, 0x004C), (0x013C, 0x006C), (0x013D, 0x004C), (0x013E, 0x006C), (0x0143, 0x004E),
+    (0x0144, 0x006E), (0x0145, 0x004E), (0x0146, 0x006E), (0x0147, 0x004E), (0x0148, 0x006E),
+    (0x014C, 0x004F), (0x014D, 0x006F), (0x014E, 0x004F), (0x014F, 0x006F), (0x0150, 0x004F),
+    (0x0151, 0x006F), (0x0154, 0x0052), (0x0155, 0x0072), (0x0156, 0x0052), (0x0157, 0x0072),
+    (0x0158, 0x0052), (0x0159, 0x0072), (0x015A, 0x0053), (0x015B, 0x0073), (0x015C, 0x0053),
+    (0x015D, 0x0073), (0x0160, 0x0053), (0x0161, 0x0073), (0x0162, 0x0054), (0x0163, 0x0074),
+    (0x0164, 0x0054), (0x0165, 0x0074), (0x0168, 0x0055), (0x0169, 0x0075), (0x016A, 0x0055),
+    (0x016B, 0x0075), (0x016C, 0x0055), (0x016D, 0x0075), (0x016E, 0x0055), (0x016F, 0x0075),
+    (0x0170, 0x0055), (0x0171, 0x0075), (0x0172, 0x0055), (0x0173, 0x0075), (0x0174, 0x0057),
+    (0x0175, 0x0077), (0x0176, 0x0059), (0x0177, 0x0079), (0x0178, 0x0059), (0x0179, 0x005A),
+    (0x017A, 0x007A), (0x017B, 0x005A), (0x017C, 0x007A), (0x017D, 0x005A), (0x017E, 0x007A),
+    (0x017F, 0x0073), (0x01A0, 0x004F), (0x01A1, 0x006F), (0x01AF, 0x0055), (0x01B0, 0x0075),
+    (0x01CD, 0x0041), (0x01CE, 0x0061), (0x01CF, 0x0049), (0x01D0, 0x0069), (0x01D1, 0x004F),
+    (0x01D2, 0x006F), (0x01D3, 0x0055), (0x01D4, 0x0075), (0x01D5, 0x0055), (0x01D6, 0x0075),
+    (0x01D7, 0x0055), (0x01D8, 0x0075), (0x01D9, 0x0055), (0x01DA, 0x0075), (0x01DB, 0x0055),
+    (0x01DC, 0x0075), (0x01DE, 0x0041), (0x01DF, 0x0061), (0x01E0, 0x0041), (0x01E1, 0x0061),
+    (0x01E2, 0x0092), (0x01E3, 0x0091), (0x01E6, 0x0047), (0x01E7, 0x0067), (0x01E8, 0x004B),
+    (0x01E9, 0x006B), (0x01EA, 0x004F), (0x01EB, 0x006F), (0x01EC, 0x004F), (0x01ED, 0x006F),
+    (0x01F0, 0x006A), (0x01F4, 0x0047), (0x01F5, 0x0067), (0x01F8, 0x004E), (0x01F9, 0x006E),
+    (0x01FA, 0x0041), (0x01FB, 0x0061), (0x01FC, 0x0092), (0x01FD, 0x0091), (0x01FE, 0x009D),
+    (0x01FF, 0x009B), (0x0200, 0x0041), (0x0201, 0x0061), (0x0202, 0x0041), (0x0203, 0x0061),
+    (0x0204, 0x0045), (0x0205, 0x0065), (0x0206, 0x0045), (0x0207, 0x0065), (0x0208, 0x0049),
+    (0x0209, 0x0069), (0x020A, 0x0049), (0x020B, 0x0069), (0x020C, 0x004F), (0x020D, 0x006F),
+    (0x020E, 0x004F), (0x020F, 0x006F), (0x0210, 0x0052), (0x0211, 0x0072), (0x0212, 0x0052),
+    (0x0213, 0x0072), (0x0214, 0x0055), (0x0215, 0x0075), (0x0216, 0x0055), (0x0217, 0x0075),
+    (0x0218, 0x0053), (0x0219, 0x0073), (0x021A, 0x0054), (0x021B, 0x0074), (0x021E, 0x0048),
+    (0x021F, 0x0068), (0x0226, 0x0041), (0x0227, 0x0061), (0x0228, 0x0045), (0x0229, 0x0065),
+    (0x022A, 0x004F), (0x022B, 0x006F), (0x022C, 0x004F), (0x022D, 0x006F), (0x022E, 0x004F),
+    (0x022F, 0x006F), (0x0230, 0x004F), (0x0231, 0x006F), (0x0232, 0x0059), (0x0233, 0x0079),
+    (0x02B0, 0x0068), (0x02B2, 0x006A), (0x02B3, 0x0072), (0x02B7, 0x0077), (0x02B8, 0x0079),
+    (0x02E1, 0x006C), (0x02E2, 0x0073), (0x02E3, 0x0078), (0x037E, 0x003B), (0x0387, 0x00FA),
+    (0x1D2C, 0x0041), (0x1D2D, 0x0092), (0x1D2E, 0x0042), (0x1D30, 0x0044), (0x1D31, 0x0045),
+    (0x1D33, 0x0047), (0x1D34, 0x0048), (0x1D35, 0x0049), (0x1D36, 0x004A), (0x1D37, 0x004B),
+    (0x1D38, 0x004C), (0x1D39, 0x004D), (0x1D3A, 0x004E), (0x1D3C, 0x004F), (0x1D3E, 0x0050),
+    (0x1D3F, 0x0052), (0x1D40, 0x0054), (0x1D41, 0x0055), (0x1D42, 0x0057), (0x1D43, 0x0061),
+    (0x1D47, 0x0062), (0x1D48, 0x0064), (0x1D49, 0x0065), (0x1D4D, 0x0067), (0x1D4F, 0x006B),
+    (0x1D50, 0x006D), (0x1D52, 0x006F), (0x1D56, 0x0070), (0x1D57, 0x0074), (0x1D58, 0x0075),
+    (0x1D5B, 0x0076), (0x1D62, 0x0069), (0x1D63, 0x0072), (0x1D64, 0x0075), (0x1D65, 0x0076),
+    (0x1D9C, 0x0063), (0x1DA0, 0x0066), (0x1DBB, 0x007A), (0x1E00, 0x0041), (0x1E01, 0x0061),
+    (0x1E02, 0x0042), (0x1E03, 0x0062), (0x1E04, 0x0042), (0x1E05, 0x0062), (0x1E06, 0x0042),
+    (0x1E07, 0x0062), (0x1E08, 0x0043), (0x1E09, 0x0063), (0x1E0A, 0x0044), (0x1E0B, 0x0064),
+    (0x1E0C, 0x0044), (0x1E0D, 0x0064), (0x1E0E, 0x0044), (0x1E0F, 0x0064), (0x1E10, 0x0044),
+    (0x1E11, 0x0064), (0x1E12, 0x0044), (0x1E13, 0x0064), (0x1E14, 0x0045), (0x1E15, 0x0065),
+    (0x1E16, 0x0045), (0x1E17, 0x0065), (0x1E18, 0x0045), (0x1E19, 0x0065), (0x1E1A, 0x0045),
+    (0x1E1B, 0x0065), (0x1E1C, 0x0045), (0x1E1D, 0x0065), (0x1E1E, 0x0046), (0x1E1F, 0x0066),
+    (0x1E20, 0x0047), (0x1E21, 0x0067), (0x1E22, 0x0048), (0x1E23, 0x0068), (0x1E24, 0x0048),
+    (0x1E25, 0x0068), (0x1E26, 0x0048), (0x1E27, 0x0068), (0x1E28, 0x0048), (0x1E29, 0x0068),
+    (0x1E2A, 0x0048), (0x1E2B, 0x0068), (0x1E2C, 0x0049), (0x1E2D, 0x0069), (0x1E2E, 0x0049),
+    (0x1E2F, 0x0069), (0x1E30, 0x004B), (0x1E31, 0x006B), (0x1E32, 0x004B), (0x1E33, 0x006B),
+    (0x1E34, 0x004B), (0x1E35, 0x006B), (0x1E36, 0x004C), (0x1E37, 0x006C), (0x1E38, 0x004C),
+    (0x1E39, 0x006C), (0x1E3A, 0x004C), (0x1E3B, 0x006C), (0x1E3C, 0x004C), (0x1E3D, 0x006C),
+    (0x1E3E, 0x004D), (0x1E3F, 0x006D), (0x1E40, 0x004D), (0x1E41, 0x006D), (0x1E42, 0x004D),
+    (0x1E43, 0x006D), (0x1E44, 0x004E), (0x1E45, 0x006E), (0x1E46, 0x004E), (0x1E47, 0x006E),
+    (0x1E48, 0x004E), (0x1E49, 0x006E), (0x1E4A, 0x004E), (0x1E4B, 0x006E), (0x1E4C, 0x004F),
+    (0x1E4D, 0x006F), (0x1E4E, 0x004F), (0x1E4F, 0x006F), (0x1E50, 0x004F), (0x1E51, 0x006F),
+    (0x1E52, 0x004F), (0x1E53, 0x006F), (0x1E54, 0x0050), (0x1E55, 0x0070), (0x1E56, 0x0050),
+    (0x1E57, 0x0070), (0x1E58, 0x0052), (0x1E59, 0x0072), (0x1E5A, 0x0052), (0x1E5B, 0x0072),
+    (0x1E5C, 0x0052), (0x1E5D, 0x0072), (0x1E5E, 0x0052), (0x1E5F, 0x0072), (0x1E60, 0x0053),
+    (0x1E61, 0x0073), (0x1E62, 0x0053), (0x1E63, 0x0073), (0x1E64, 0x0053), (0x1E65, 0x0073),
+    (0x1E66, 0x0053), (0x1E67, 0x0073), (0x1E68, 0x0053), (0x1E69, 0x0073), (0x1E6A, 0x0054),
+    (0x1E6B, 0x0074), (0x1E6C, 0x0054), (0x1E6D, 0x0074), (0x1E6E, 0x0054), (0x1E6F, 0x0074),
+    (0x1E70, 0x0054), (0x1E71, 0x0074), (0x1E72, 0x0055), (0x1E73, 0x0075), (0x1E74, 0x0055),
+    (0x1E75, 0x0075), (0x1E76, 0x0055), (0x1E77, 0x0075), (0x1E78, 0x0055), (0x1E79, 0x0075),
+    (0x1E7A, 0x0055), (0x1E7B, 0x0075), (0x1E7C, 0x0056), (0x1E7D, 0x0076), (0x1E7E, 0x0056),
+    (0x1E7F, 0x0076), (0x1E80, 0x0057), (0x1E81, 0x0077), (0x1E82, 0x0057), (0x1E83, 0x0077),
+    (0x1E84, 0x0057), (0x1E85, 0x0077), (0x1E86, 0x0057), (0x1E87, 0x0077), (0x1E88, 0x0057),
+    (0x1E89, 0x0077), (0x1E8A, 0x0058), (0x1E8B, 0x0078), (0x1E8C, 0x0058), (0x1E8D, 0x0078),
+    (0x1E8E, 0x0059), (0x1E8F, 0x0079), (0x1E90, 0x005A), (0x1E91, 0x007A), (0x1E92, 0x005A),
+    (0x1E93, 0x007A), (0x1E94, 0x005A), (0x1E95, 0x007A), (0x1E96, 0x0068), (0x1E97, 0x0074),
+    (0x1E98, 0x0077), (0x1E99, 0x0079), (0x1E9B, 0x0073), (0x1EA0, 0x0041), (0x1EA1, 0x0061),
+    (0x1EA2, 0x0041), (0x1EA3, 0x0061), (0x1EA4, 0x0041), (0x1EA5, 0x0061), (0x1EA6, 0x0041),
+    (0x1EA7, 0x0061), (0x1EA8, 0x0041), (0x1EA9, 0x0061), (0x1EAA, 0x0041), (0x1EAB, 0x0061),
+    (0x1EAC, 0x0041), (0x1EAD, 0x0061), (0x1EAE, 0x0041), (0x1EAF, 0x0061), (0x1EB0, 0x0041),
+    (0x1EB1, 0x0061), (0x1EB2, 0x0041), (0x1EB3, 0x0061), (0x1EB4, 0x0041), (0x1EB5, 0x0061),
+    (0x1EB6, 0x0041), (0x1EB7, 0x0061), (0x1EB8, 0x0045), (0x1EB9, 0x0065), (0x1EBA, 0x0045),
+    (0x1EBB, 0x0065), (0x1EBC, 0x0045), (0x1EBD, 0x0065), (0x1EBE, 0x0045), (0x1EBF, 0x0065),
+    (0x1EC0, 0x0045), (0x1EC1, 0x0065), (0x1EC2, 0x0045), (0x1EC3, 0x0065), (0x1EC4, 0x0045),
+    (0x1EC5, 0x0065), (0x1EC6, 0x0045), (0x1EC7, 0x0065), (0x1EC8, 0x0049), (0x1EC9, 0x0069),
+    (0x1ECA, 0x0049), (0x1ECB, 0x0069), (0x1ECC, 0x004F), (0x1ECD, 0x006F), (0x1ECE, 0x004F),
+    (0x1ECF, 0x006F), (0x1ED0, 0x004F), (0x1ED1, 0x006F), (0x1ED2, 0x004F), (0x1ED3, 0x006F),
+    (0x1ED4, 0x004F), (0x1ED5, 0x006F), (0x1ED6, 0x004F), (0x1ED7, 0x006F), (0x1ED8, 0x004F),
+    (0x1ED9, 0x006F), (0x1EDA, 0x004F), (0x1EDB, 0x006F), (0x1EDC, 0x004F), (0x1EDD, 0x006F),
+    (0x1EDE, 0x004F), (0x1EDF, 0x006F), (0x1EE0, 0x004F), (0x1EE1, 0x006F), (0x1EE2, 0x004F),
+    (0x1EE3, 0x006F), (0x1EE4, 0x0055), (0x1EE5, 0x0075), (0x1EE6, 0x0055), (0x1EE7, 0x0075),
+    (0x1EE8, 0x0055), (0x1EE9, 0x0075), (0x1EEA, 0x0055), (0x1EEB, 0x0075), (0x1EEC, 0x0055),
+    (0x1EED, 0x0075), (0x1EEE, 0x0055), (0x1EEF, 0x0075), (0x1EF0, 0x0055), (0x1EF1, 0x0075),
+    (0x1EF2, 0x0059), (0x1EF3, 0x0079), (0x1EF4, 0x0059), (0x1EF5, 0x0079), (0x1EF6, 0x0059),
+    (0x1EF7, 0x0079), (0x1EF8, 0x0059), (0x1EF9, 0x0079), (0x1FEF, 0x0060), (0x2000, 0x0020),
+    (0x2001, 0x0020), (0x2002, 0x0020), (0x2003, 0x0020), (0x2004, 0x0020), (0x2005, 0x0020),
+    (0x2006, 0x0020), (0x2007, 0x0020), (0x2008, 0x0020), (0x2009, 0x0020), (0x200A, 0x0020),
+    (0x2010, 0x002D), (0x2012, 0x002D), (0x2013, 0x002D), (0x2014, 0x002D), (0x2015, 0x002D),
+    (0x2018, 0x0027), (0x2019, 0x0027), (0x201A, 0x002C), (0x201B, 0x0027), (0x201C, 0x0022),
+    (0x201D, 0x0022), (0x201E, 0x0022), (0x201F, 0x0022), (0x2024, 0x002E), (0x202F, 0x0020),
+    (0x2032, 0x0027), (0x2035, 0x0060), (0x2039, 0x003C), (0x203A, 0x003E), (0x205F, 0x0020),
+    (0x2070, 0x0030), (0x2071, 0x0069), (0x2074, 0x0034), (0x2075, 0x0035), (0x2076, 0x0036),
+    (0x2077, 0x0037), (0x2078, 0x0038), (0x2079, 0x0039), (0x207A, 0x002B), (0x207C, 0x003D),
+    (0x207D, 0x0028), (0x207E, 0x0029), (0x207F, 0x006E), (0x2080, 0x0030), (0x2081, 0x0031),
+    (0x2082, 0x0032), (0x2083, 0x0033), (0x2084, 0x0034), (0x2085, 0x0035), (0x2086, 0x0036),
+    (0x2087, 0x0037), (0x2088, 0x0038), (0x2089, 0x0039), (0x208A, 0x002B), (0x208C, 0x003D),
+    (0x208D, 0x0028), (0x208E, 0x0029), (0x2090, 0x0061), (0x2091, 0x0065), (0x2092, 0x006F),
+    (0x2093, 0x0078), (0x2095, 0x0068), (0x2096, 0x006B), (0x2097, 0x006C), (0x2098, 0x006D),
+    (0x2099, 0x006E), (0x209A, 0x0070), (0x209B, 0x0073), (0x209C, 0x0074), (0x2102, 0x0043),
+    (0x210A, 0x0067), (0x210B, 0x0048), (0x210C, 0x0048), (0x210D, 0x0048), (0x210E, 0x0068),
+    (0x2110, 0x0049), (0x2111, 0x0049), (0x2112, 0x004C), (0x2113, 0x006C), (0x2115, 0x004E),
+    (0x2119, 0x0050), (0x211A, 0x0051), (0x211B, 0x0052), (0x211C, 0x0052), (0x211D, 0x0052),
+    (0x2124, 0x005A), (0x2128, 0x005A), (0x212A, 0x004B), (0x212B, 0x0041), (0x212C, 0x0042),
+    (0x212D, 0x0043), (0x212F, 0x0065), (0x2130, 0x0045), (0x2131, 0x0046), (0x2133, 0x004D),
+    (0x2134, 0x006F), (0x2139, 0x0069), (0x2145, 0x0044), (0x2146, 0x0064), (0x2147, 0x0065),
+    (0x2148, 0x0069), (0x2149, 0x006A), (0x2160, 0x0049), (0x2164, 0x0056), (0x2169, 0x0058),
+    (0x216C, 0x004C), (0x216D, 0x0043), (0x216E, 0x0044), (0x216F, 0x004D), (0x2170, 0x0069),
+    (0x2174, 0x0076), (0x2179, 0x0078), (0x217C, 0x006C), (0x217D, 0x0063), (0x217E, 0x0064),
+    (0x217F, 0x006D), (0x2212, 0x002D), (0x2215, 0x002F), (0x2216, 0x005C), (0x2223, 0x007C),
+    (0x223C, 0x007E), (0x2260, 0x003D), (0x226E, 0x003C), (0x226F, 0x003E), (0x2460, 0x0031),
+    (0x2461, 0x0032), (0x2462, 0x0033), (0x2463, 0x0034), (0x2464, 0x0035), (0x2465, 0x0036),
+    (0x2466, 0x0037), (0x2467, 0x0038), (0x2468, 0x0039), (0x24B6, 0x0041), (0x24B7, 0x0042),
+    (0x24B8, 0x0043), (0x24B9, 0x0044), (0x24BA, 0x0045), (0x24BB, 0x0046), (0x24BC, 0x0047),
+    (0x24BD, 0x0048), (0x24BE, 0x0049), (0x24BF, 0x004A), (0x24C0, 0x004B), (0x24C1, 0x004C),
+    (0x24C2, 0x004D), (0x24C3, 0x004E), (0x24C4, 0x004F), (0x24C5, 0x0050), (0x24C6, 0x0051),
+    (0x24C7, 0x0052), (0x24C8, 0x0053), (0x24C9, 0x0054), (0x24CA, 0x0055), (0x24CB, 0x0056),
+    (0x24CC, 0x0057), (0x24CD, 0x0058), (0x24CE, 0x0059), (0x24CF, 0x005A), (0x24D0, 0x0061),
+    (0x24D1, 0x0062), (0x24D2, 0x0063), (0x24D3, 0x0064), (0x24D4, 0x0065), (0x24D5, 0x0066),
+    (0x24D6, 0x0067), (0x24D7, 0x0068), (0x24D8, 0x0069), (0x24D9, 0x006A), (0x24DA, 0x006B),
+    (0x24DB, 0x006C), (0x24DC, 0x006D), (0x24DD, 0x006E), (0x24DE, 0x006F), (0x24DF, 0x0070),
+    (0x24E0, 0x0071), (0x24E1, 0x0072), (0x24E2, 0x0073), (0x24E3, 0x0074), (0x24E4, 0x0075),
+    (0x24E5, 0x0076), (0x24E6, 0x0077), (0x24E7, 0x0078), (0x24E8, 0x0079), (0x24E9, 0x007A),
+    (0x24EA, 0x0030), (0x2C7C, 0x006A), (0x2C7D, 0x0056), (0x3000, 0x0020), (0xA7F2, 0x0043),
+    (0xA7F3, 0x0046), (0xA7F4, 0x0051), (0xFB29, 0x002B), (0xFE10, 0x002C), (0xFE13, 0x003A),
+    (0xFE14, 0x003B), (0xFE15, 0x0021), (0xFE16, 0x003F), (0xFE33, 0x005F), (0xFE34, 0x005F),
+    (0xFE35, 0x0028), (0xFE36, 0x0029), (0xFE37, 0x007B), (0xFE38, 0x007D), (0xFE47, 0x005B),
+    (0xFE48, 0x005D), (0xFE4D, 0x005F), (0xFE4E, 0x005F), (0xFE4F, 0x005F), (0xFE50, 0x002C),
+    (0xFE52, 0x002E), (0xFE54, 0x003B), (0xFE55, 0x003A), (0xFE56, 0x003F), (0xFE57, 0x0021),
+    (0xFE59, 0x0028), (0xFE5A, 0x0029), (0xFE5B, 0x007B), (0xFE5C, 0x007D), (0xFE5F, 0x0023),
+    (0xFE60, 0x0026), (0xFE61, 0x002A), (0xFE62, 0x002B), (0xFE63, 0x002D), (0xFE64, 0x003C),
+    (0xFE65, 0x003E), (0xFE66, 0x003D), (0xFE68, 0x005C), (0xFE69, 0x0024), (0xFE6A, 0x0025),
+    (0xFE6B, 0x0040), (0xFF01, 0x0021), (0xFF02, 0x0022), (0xFF03, 0x0023), (0xFF04, 0x0024),
+    (0xFF05, 0x0025), (0xFF06, 0x0026), (0xFF07, 0x0027), (0xFF08, 0x0028), (0xFF09, 0x0029),
+    (0xFF0A, 0x002A), (0xFF0B, 0x002B), (0xFF0C, 0x002C), (0xFF0D, 0x002D), (0xFF0E, 0x002E),
+    (0xFF0F, 0x002F), (0xFF10, 0x0030), (0xFF11, 0x0031), (0xFF12, 0x0032), (0xFF13, 0x0033),
+    (0xFF14, 0x0034), (0xFF15, 0x0035), (0xFF16, 0x0036), (0xFF17, 0x0037), (0xFF18, 0x0038),
+    (0xFF19, 0x0039), (0xFF1A, 0x003A), (0xFF1B, 0x003B), (0xFF1C, 0x003C), (0xFF1D, 0x003D),
+    (0xFF1E, 0x003E), (0xFF1F, 0x003F), (0xFF20, 0x0040), (0xFF21, 0x0041), (0xFF22, 0x0042),
+    (0xFF23, 0x0043), (0xFF24, 0x0044), (0xFF25, 0x0045), (0xFF26, 0x0046), (0xFF27, 0x0047),
+    (0xFF28, 0x0048), (0xFF29, 0x0049), (0xFF2A, 0x004A), (0xFF2B, 0x004B), (0xFF2C, 0x004C),
+    (0xFF2D, 0x004D), (0xFF2E, 0x004E), (0xFF2F, 0x004F), (0xFF30, 0x0050), (0xFF31, 0x0051),
+    (0xFF32, 0x0052), (0xFF33, 0x0053), (0xFF34, 0x0054), (0xFF35, 0x0055), (0xFF36, 0x0056),
+    (0xFF37, 0x0057), (0xFF38, 0x0058), (0xFF39, 0x0059), (0xFF3A, 0x005A), (0xFF3B, 0x005B),
+    (0xFF3C, 0x005C), (0xFF3D, 0x005D), (0xFF3E, 0x005E), (0xFF3F, 0x005F), (0xFF40, 0x0060),
+    (0xFF41, 0x0061), (0xFF42, 0x0062), (0xFF43, 0x0063), (0xFF44, 0x0064), (0xFF45, 0x0065),
+    (0xFF46, 0x0066), (0xFF47, 0x0067), (0xFF48, 0x0068), (0xFF49, 0x0069), (0xFF4A, 0x006A),
+    (0xFF4B, 0x006B), (0xFF4C, 0x006C), (0xFF4D, 0x006D), (0xFF4E, 0x006E), (0xFF4F, 0x006F),
+    (0xFF50, 0x0070), (0xFF51, 0x0071), (0xFF52, 0x0072), (0xFF53, 0x0073), (0xFF54, 0x0074),
+    (0xFF55, 0x0075), (0xFF56, 0x0076), (0xFF57, 0x0077), (0xFF58, 0x0078), (0xFF59, 0x0079),
+    (0xFF5A, 0x007A), (0xFF5B, 0x007B), (0xFF5C, 0x007C), (0xFF5D, 0x007D), (0xFF5E, 0x007E),
+    (0xFFE0, 0x00BD), (0xFFE1, 0x009C), (0xFFE2, 0x00AA), (0xFFE4, 0x00DD), (0xFFE5, 0x00BE),
+    (0xFFE8, 0x00B3), (0xFFED, 0x00FE),
+];
+
+/// Code page 862.
+#[rustfmt::skip]
+pub(crate) const CP862: [(u16, u16); 981] = [
+    (0x00B3, 0x0033), (0x00B9, 0x0031), (0x00C0, 0x0041), (0x00C1, 0x0041), (0x00C2, 0x0041),
+    (0x00C3, 0x0041), (0x00C4, 0x0041), (0x00C5, 0x0041), (0x00C7, 0x0043), (0x00C8, 0x0045),
+    (0x00C9, 0x0045), (0x00CA, 0x0045), (0x00CB, 0x0045), (0x00CC, 0x0049), (0x00CD, 0x0049),
+    (0x00CE, 0x0049), (0x00CF, 0x0049), (0x00D2, 0x004F), (0x00D3, 0x004F), (0x00D4, 0x004F),
+    (0x00D5, 0x004F), (0x00D6, 0x004F), (0x00D9, 0x0055), (0x00DA, 0x0055), (0x00DB, 0x0055),
+    (0x00DC, 0x0055), (0x00DD, 0x0059), (0x00E0, 0x0061), (0x00E2, 0x0061), (0x00E3, 0x0061),
+    (0x00E4, 0x0061), (0x00E5, 0x0061), (0x00E7, 0x0063), (0x00E8, 0x0065), (0x00E9, 0x0065),
+    (0x00EA, 0x0065), (0x00EB, 0x0065), (0x00EC, 0x0069), (0x00EE, 0x0069), (0x00EF, 0x0069),
+    (0x00F2, 0x006F), (0x00F4, 0x006F), (0x00F5, 0x006F), (0x00F6, 0x006F), (0x00F9, 0x0075),
+    (0x00FB, 0x0075), (0x00FC, 0x0075), (0x00FD, 0x0079), (0x00FF, 0x0079), (0x0100, 0x0041),
+    (0x0101, 0x0061), (0x0102, 0x0041), (0x0103, 0x0061), (0x0104, 0x0041), (0x0105, 0x0061),
+    (0x0106, 0x0043), (0x0107, 0x0063), (0x0108, 0x0043), (0x0109, 0x0063), (0x010A, 0x0043),
+    (0x010B, 0x0063), (0x010C, 0x0043), (0x010D, 0x0063), (0x010E, 0x0044), (0x010F, 0x0064),
+    (0x0112, 0x0045), (0x0113, 0x0065), (0x0114, 0x0045), (0x0115, 0x0065), (0x0116, 0x0045),
+    (0x0117, 0x0065), (0x0118, 0x0045), (0x0119, 0x0065), (0x011A, 0x0045), (0x011B, 0x0065),
+    (0x011C, 0x0047), (0x011D, 0x0067), (0x011E, 0x0047), (0x011F, 0x0067), (0x0120, 0x0047),
+    (0x0121, 0x0067), (0x0122, 0x0047), (0x0123, 0x0067), (0x0124, 0x0048), (0x0125, 0x0068),
+    (0x0128, 0x0049), (0x0129, 0x0069), (0x012A, 0x0049), (0x012B, 0x0069), (0x012C, 0x0049),
+    (0x012D, 0x0069), (0x012E, 0x0049), (0x012F, 0x0069), (0x0130, 0x0049), (0x0134, 0x004A),
+    (0x0135, 0x006A), (0x0136, 0x004B), (0x0137, 0x006B), (0x0139, 0x004C), (0x013A, 0x006C),
+    (0x013B, 0x004C), (0x013C, 0x006C), (0x013D, 0x004C), (0x013E, 0x006C), (0x0143, 0x004E),
+    (0x0144, 0x006E), (0x0145, 0x004E), (0x0146, 0x006E), (0x0147, 0x004E), (0x0148, 0x006E),
+    (0x014C, 0x004F), (0x014D, 0x006F), (0x014E, 0x004F), (0x014F, 0x006F), (0x0150, 0x004F),
+    (0x0151, 0x006F), (0x0154, 0x0052), (0x0155, 0x0072), (0x0156, 0x0052), (0x0157, 0x0072),
+    (0x0158, 0x0052), (0x0159, 0x0072), (0x015A, 0x0053), (0x015B, 0x0073), (0x015C, 0x0053),
+    (0x015D, 0x0073), (0x015E, 0x0053), (0x015F, 0x0073), (0x0160, 0x0053), (0x0161, 0x0073),
+    (0x0162, 0x0054), (0x0163, 0x0074), (0x0164, 0x0054), (0x0165, 0x0074), (0x0168, 0x0055),
+    (0x0169, 0x0075), (0x016A, 0x0055), (0x016B, 0x0075), (0x016C, 0x0055), (0x016D, 0x0075),
+    (0x016E, 0x0055), (0x016F, 0x0075), (0x0170, 0x0055), (0x0171, 0x0075), (0x0172, 0x0055),
+    (0x0173, 0x0075), (0x0174, 0x0057), (0x0175, 0x0077), (0x0176, 0x0059), (0x0177, 0x0079),
+    (0x0178, 0x0059), (0x0179, 0x005A), (0x017A, 0x007A), (0x017B, 0x005A), (0x017C, 0x007A),
+    (0x017D, 0x005A), (0x017E, 0x007A), (0x017F, 0x0073), (0x01A0, 0x004F), (0x01A1, 0x006F),
+    (0x01AF, 0x0055), (0x01B0, 0x0075), (0x01CD, 0x0041), (0x01CE, 0x0061), (0x01CF, 0x0049),
+    (0x01D0, 0x0069), (0x01D1, 0x004F), (0x01D2, 0x006F), (0x01D3, 0x0055), (0x01D4, 0x0075),
+    (0x01D5, 0x0055), (0x01D6, 0x0075), (0x01D7, 0x0055), (0x01D8, 0x0075), (0x01D9, 0x0055),
+    (0x01DA, 0x0075), (0x01DB, 0x0055), (0x01DC, 0x0075), (0x01DE, 0x0041), (0x01DF, 0x0061),
+    (0x01E0, 0x0041), (0x01E1, 0x0061), (0x01E6, 0x0047), (0x01E7, 0x0067), (0x01E8, 0x004B),
+    (0x01E9, 0x006B), (0x01EA, 0x004F), (0x01EB, 0x006F), (0x01EC, 0x004F), (0x01ED, 0x006F),
+    (0x01F0, 0x006A), (0x01F4, 0x0047), (0x01F5, 0x0067), (0x01F8, 0x004E), (0x01F9, 0x006E),
+    (0x01FA, 0x0041), (0x01FB, 0x0061), (0x0200, 0x0041), (0x0201, 0x0061), (0x0202, 0x0041),
+    (0x0203, 0x0061), (0x0204, 0x0045), (0x0205, 0x0065), (0x0206, 0x0045), (0x0207, 0x0065),
+    (0x0208, 0x0049), (0x0209, 0x0069), (0x020A, 0x0049), (0x020B, 0x0069), (0x020C, 0x004F),
+    (0x020D, 0x006F), (0x020E, 0x004F), (0x020F, 0x006F), (0x0210, 0x0052), (0x0211, 0x0072),
+    (0x0212, 0x0052), (0x0213, 0x0072), (0x0214, 0x0055), (0x0215, 0x0075), (0x0216, 0x0055),
+    (0x0217, 0x0075), (0x0218, 0x0053), (0x0219, 0x0073), (0x021A, 0x0054), (0x021B, 0x0074),
+    (0x021E, 0x0048), (0x021F, 0x0068), (0x0226, 0x0041), (0x0227, 0x0061), (0x0228, 0x0045),
+    (0x0229, 0x0065), (0x022A, 0x004F), (0x022B, 0x006F), (0x022C, 0x004F), (0x022D, 0x006F),
+    (0x022E, 0x004F), (0x022F, 0x006F), (0x0230, 0x004F), (0x0231, 0x006F), (0x0232, 0x0059),
+    (0x0233, 0x0079), (0x02B0, 0x0068), (0x02B2, 0x006A), (0x02B3, 0x0072), (0x02B7, 0x0077),
+    (0x02B8, 0x0079), (0x02E1, 0x006C), (0x02E2, 0x0073), (0x02E3, 0x0078), (0x037E, 0x003B),
+    (0x0387, 0x00FA), (0x038F, 0x00EA), (0x03AC, 0x00E0), (0x03AD, 0x00EE), (0x03D5, 0x00ED),
+    (0x03D6, 0x00E3), (0x03F4, 0x00E9), (0x03F5, 0x00EE), (0x03F9, 0x00E4), (0x1D2C, 0x0041),
+    (0x1D2E, 0x0042), (0x1D30, 0x0044), (0x1D31, 0x0045), (0x1D33, 0x0047), (0x1D34, 0x0048),
+    (0x1D35, 0x0049), (0x1D36, 0x004A), (0x1D37, 0x004B), (0x1D38, 0x004C), (0x1D39, 0x004D),
+    (0x1D3A, 0x004E), (0x1D3C, 0x004F), (0x1D3E, 0x0050), (0x1D3F, 0x0052), (0x1D40, 0x0054),
+    (0x1D41, 0x0055), (0x1D42, 0x0057), (0x1D43, 0x0061), (0x1D47, 0x0062), (0x1D48, 0x0064),
+    (0x1D49, 0x0065), (0x1D4D, 0x0067), (0x1D4F, 0x006B), (0x1D50, 0x006D), (0x1D52, 0x006F),
+    (0x1D56, 0x0070), (0x1D57, 0x0074), (0x1D58, 0x0075), (0x1D5B, 0x0076), (0x1D5F, 0x00EB),
+    (0x1D60, 0x00ED), (0x1D62, 0x0069), (0x1D63, 0x0072), (0x1D64, 0x0075), (0x1D65, 0x0076),
+    (0x1D69, 0x00ED), (0x1D9C, 0x0063), (0x1DA0, 0x0066), (0x1DBB, 0x007A), (0x1E00, 0x0041),
+    (0x1E01, 0x0061), (0x1E02, 0x0042), (0x1E03, 0x0062), (0x1E04, 0x0042), (0x1E05, 0x0062),
+    (0x1E06, 0x0042), (0x1E07, 0x0062), (0x1E08, 0x0043), (0x1E09, 0x0063), (0x1E0A, 0x0044),
+    (0x1E0B, 0x0064), (0x1E0C, 0x0044), (0x1E0D, 0x0064), (0x1E0E, 0x0044), (0x1E0F, 0x0064),
+    (0x1E10, 0x0044), (0x1E11, 0x0064), (0x1E12, 0x0044), (0x1E13, 0x0064), (0x1E14, 0x0045),
+    (0x1E15, 0x0065), (0x1E16, 0x0045), (0x1E17, 0x0065), (0x1E18, 0x0045), (0x1E19, 0x0065),
+    (0x1E1A, 0x0045), (0x1E1B, 0x0065), (0x1E1C, 0x0045), (0x1E1D, 0x0065), (0x1E1E, 0x0046),
+    (0x1E1F, 0x0066), (0x1E20, 0x0047), (0x1E21, 0x0067), (0x1E22, 0x0048), (0x1E23, 0x0068),
+    (0x1E24, 0x0048), (0x1E25, 0x0068), (0x1E26, 0x0048), (0x1E27, 0x0068), (0x1E28, 0x0048),
+    (0x1E29, 0x0068), (0x1E2A, 0x0048), (0x1E2B, 0x0068), (0x1E2C, 0x0049), (0x1E2D, 0x0069),
+    (0x1E2E, 0x0049), (0x1E2F, 0x0069), (0x1E30, 0x004B), (0x1E31, 0x006B), (0x1E32, 0x004B),
+    (0x1E33, 0x006B), (0x1E34, 0x004B), (0x1E35, 0x006B), (0x1E36, 0x004C), (0x1E37, 0x006C),
+    (0x1E38, 0x004C), (0x1E39, 0x006C), (0x1E3A, 0x004C), (0x1E3B, 0x006C), (0x1E3C, 0x004C),
+    (0x1E3D, 0x006C), (0x1E3E, 0x004D), (0x1E3F, 0x006D), (0x1E40, 0x004D), (0x1E41, 0x006D),
+    (0x1E42, 0x004D), (0x1E43, 0x006D), (0x1E44, 0x004E), (0x1E45, 0x006E), (0x1E46, 0x004E),
+    (0x1E47, 0x006E), (0x1E48, 0x004E), (0x1E49, 0x006E), (0x1E4A, 0x004E), (0x1E4B, 0x006E),
+    (0x1E4C, 0x004F), (0x1E4D, 0x006F), (0x1E4E, 0x004F), (0x1E4F, 0x006F), (0x1E50, 0x004F),
+    (0x1E51, 0x006F), (0x1E52, 0x004F), (0x1E53, 0x006F), (0x1E54, 0x0050), (0x1E55, 0x0070),
+    (0x1E56, 0x0050), (0x1E57, 0x0070), (0x1E58, 0x0052), (0x1E59, 0x0072), (0x1E5A, 0x0052),
+    (0x1E5B, 0x0072), (0x1E5C, 0x0052), (0x1E5D, 0x0072), (0x1E5E, 0x0052), (0x1E5F, 0x0072),
+    (0x1E60, 0x0053), (0x1E61, 0x0073), (0x1E62, 0x0053), (0x1E63, 0x0073), (0x1E64, 0x0053),
+    (0x1E65, 0x0073), (0x1E66, 0x0053), (0x1E67, 0x0073), (0x1E68, 0x0053), (0x1E69, 0x0073),
+    (0x1E6A, 0x0054), (0x1E6B, 0x0074), (0x1E6C, 0x0054), (0x1E6D, 0x0074), (0x1E6E, 0x0054),
+    (0x1E6F, 0x0074), (0x1E70, 0x0054), (0x1E71, 0x0074), (0x1E72, 0x0055), (0x1E73, 0x0075),
+    (0x1E74, 0x0055), (0x1E75, 0x0075), (0x1E76, 0x0055), (0x1E77, 0x0075), (0x1E78, 0x0055),
+    (0x1E79, 0x0075), (0x1E7A, 0x0055), (0x1E7B, 0x0075), (0x1E7C, 0x0056), (0x1E7D, 0x0076),
+    (0x1E7E, 0x0056), (0x1E7F, 0x0076), (0x1E80, 0x0057), (0x1E81, 0x0077), (0x1E82, 0x0057),
+    (0x1E83, 0x0077), (0x1E84, 0x0057), (0x1E85, 0x0077), (0x1E86, 0x0057), (0x1E87, 0x0077),
+    (0x1E88, 0x0057), (0x1E89, 0x0077), (0x1E8A, 0x0058), (0x1E8B, 0x0078), (0x1E8C, 0x0058),
+    (0x1E8D, 0x0078), (0x1E8E, 0x0059), (0x1E8F, 0x0079), (0x1E90, 0x005A), (0x1E91, 0x007A),
+    (0x1E92, 0x005A), (0x1E93, 0x007A), (0x1E94, 0x005A), (0x1E95, 0x007A), (0x1E96, 0x0068),
+    (0x1E97, 0x0074), (0x1E98, 0x0077), (0x1E99, 0x0079), (0x1E9B, 0x0073), (0x1EA0, 0x0041),
+    (0x1EA1, 0x0061), (0x1EA2, 0x0041), (0x1EA3, 0x0061), (0x1EA4, 0x0041), (0x1EA5, 0x0061),
+    (0x1EA6, 0x0041), (0x1EA7, 0x0061), (0x1EA8, 0x0041), (0x1EA9, 0x0061), (0x1EAA, 0x0041),
+    (0x1EAB, 0x0061), (0x1EAC, 0x0041), (0x1EAD, 0x0061), (0x1EAE, 0x0041), (0x1EAF, 0x0061),
+    (0x1EB0, 0x0041), (0x1EB1, 0x0061), (0x1EB2, 0x0041), (0x1EB3, 0x0061), (0x1EB4, 0x0041),
+    (0x1EB5, 0x0061), (0x1EB6, 0x0041), (0x1EB7, 0x0061), (0x1EB8, 0x0045), (0x1EB9, 0x0065),
+    (0x1EBA, 0x0045), (0x1EBB, 0x0065), (0x1EBC, 0x0045), (0x1EBD, 0x0065), (0x1EBE, 0x0045),
+    (0x1EBF, 0x0065), (0x1EC0, 0x0045), (0x1EC1, 0x0065), (0x1EC2, 0x0045), (0x1EC3, 0x0065),
+    (0x1EC4, 0x0045), (0x1EC5, 0x0065), (0x1EC6, 0x0045), (0x1EC7, 0x0065), (0x1EC8, 0x0049),
+    (0x1EC9, 0x0069), (0x1ECA, 0x0049), (0x1ECB, 0x0069), (0x1ECC, 0x004F), (0x1ECD, 0x006F),
+    (0x1ECE, 0x004F), (0x1ECF, 0x006F), (0x1ED0, 0x004F), (0x1ED1, 0x006F), (0x1ED2, 0x004F),
+    (0x1ED3, 0x006F), (0x1ED4, 0x004F), (0x1ED5, 0x006F), (0x1ED6, 0x004F), (0x1ED7, 0x006F),
+    (0x1ED8, 0x004F), (0x1ED9, 0x006F), (0x1EDA, 0x004F), (0x1EDB, 0x006F), (0x1EDC, 0x004F),
+    (0x1EDD, 0x006F), (0x1EDE, 0x004F), (0x1EDF, 0x006F), (0x1EE0, 0x004F), (0x1EE1, 0x006F),
+    (0x1EE2, 0x004F), (0x1EE3, 0x006F), (0x1EE4, 0x0055), (0x1EE5, 0x0075), (0x1EE6, 0x0055),
+    (0x1EE7, 0x0075), (0x1EE8, 0x0055), (0x1EE9, 0x0075), (0x1EEA, 0x0055), (0x1EEB, 0x0075),
+    (0x1EEC, 0x0055), (0x1EED, 0x0075), (0x1EEE, 0x0055), (0x1EEF, 0x0075), (0x1EF0, 0x0055),
+    (0x1EF1, 0x0075), (0x1EF2, 0x0059), (0x1EF3, 0x0079), (0x1EF4, 0x0059), (0x1EF5, 0x0079),
+    (0x1EF6, 0x0059), (0x1EF7, 0x0079), (0x1EF8, 0x0059), (0x1EF9, 0x0079), (0x1F00, 0x00E0),
+    (0x1F01, 0x00E0), (0x1F02, 0x00E0), (0x1F03, 0x00E0), (0x1F04, 0x00E0), (0x1F05, 0x00E0),
+    (0x1F06, 0x00E0), (0x1F07, 0x00E0), (0x1F10, 0x00EE), (0x1F11, 0x00EE), (0x1F12, 0x00EE),
+    (0x1F13, 0x00EE), (0x1F14, 0x00EE), (0x1F15, 0x00EE), (0x1F68, 0x00EA), (0x1F69, 0x00EA),
+    (0x1F6A, 0x00EA), (0x1F6B, 0x00EA), (0x1F6C, 0x00EA), (0x1F6D, 0x00EA), (0x1F6E, 0x00EA),
+    (0x1F6F, 0x00EA), (0x1F70, 0x00E0), (0x1F71, 0x00E0), (0x1F72, 0x00EE), (0x1F73, 0x00EE),
+    (0x1F80, 0x00E0), (0x1F81, 0x00E0), (0x1F82, 0x00E0), (0x1F83, 0x00E0), (0x1F84, 0x00E0),
+    (0x1F85, 0x00E0), (0x1F86, 0x00E0), (0x1F87, 0x00E0), (0x1FA8, 0x00EA), (0x1FA9, 0x00EA),
+    (0x1FAA, 0x00EA), (0x1FAB, 0x00EA), (0x1FAC, 0x00EA), (0x1FAD, 0x00EA), (0x1FAE, 0x00EA),
+    (0x1FAF, 0x00EA), (0x1FB0, 0x00E0), (0x1FB1, 0x00E0), (0x1FB2, 0x00E0), (0x1FB3, 0x00E0),
+    (0x1FB4, 0x00E0), (0x1FB6, 0x00E0), (0x1FB7, 0x00E0), (0x1FEF, 0x0060), (0x1FFA, 0x00EA),
+    (0x1FFB, 0x00EA), (0x1FFC, 0x00EA), (0x2000, 0x0020), (0x2001, 0x0020), (0x2002, 0x0020),
+    (0x2003, 0x0020), (0x2004, 0x0020), (0x2005, 0x0020), (0x2006, 0x0020), (0x2007, 0x0020),
+    (0x2008, 0x0020), (0x2009, 0x0020), (0x200A, 0x0020), (0x2010, 0x002D), (0x2012, 0x002D),
+    (0x2013, 0x002D), (0x2014, 0x002D), (0x2015, 0x002D), (0x2018, 0x0027), (0x2019, 0x0027),
+    (0x201A, 0x002C), (0x201B, 0x0027), (0x201C, 0x0022), (0x201D, 0x0022), (0x201E, 0x0022),
+    (0x201F, 0x0022), (0x2024, 0x002E), (0x202F, 0x0020), (0x2032, 0x0027), (0x2035, 0x0060),
+    (0x2039, 0x003C), (0x203A, 0x003E), (0x205F, 0x0020), (0x2070, 0x0030), (0x2071, 0x0069),
+    (0x2074, 0x0034), (0x2075, 0x0035), (0x2076, 0x0036), (0x2077, 0x0037), (0x2078, 0x0038),
+    (0x2079, 0x0039), (0x207A, 0x002B), (0x207C, 0x003D), (0x207D, 0x0028), (0x207E, 0x0029),
+    (0x2080, 0x0030), (0x2081, 0x0031), (0x2082, 0x0032), (0x2083, 0x0033), (0x2084, 0x0034),
+    (0x2085, 0x0035), (0x2086, 0x0036), (0x2087, 0x0037), (0x2088, 0x0038), (0x2089, 0x0039),
+    (0x208A, 0x002B), (0x208C, 0x003D), (0x208D, 0x0028), (0x208E, 0x0029), (0x2090, 0x0061),
+    (0x2091, 0x0065), (0x2092, 0x006F), (0x2093, 0x0078), (0x2095, 0x0068), (0x2096, 0x006B),
+    (0x2097, 0x006C), (0x2098, 0x006D), (0x2099, 0x006E), (0x209A, 0x0070), (0x209B, 0x0073),
+    (0x209C, 0x0074), (0x2102, 0x0043), (0x210A, 0x0067), (0x210B, 0x0048), (0x210C, 0x0048),
+    (0x210D, 0x0048), (0x210E, 0x0068), (0x2110, 0x0049), (0x2111, 0x0049), (0x2112, 0x004C),
+    (0x2113, 0x006C), (0x2115, 0x004E), (0x2119, 0x0050), (0x211A, 0x0051), (0x211B, 0x0052),
+    (0x211C, 0x0052), (0x211D, 0x0052), (0x2124, 0x005A), (0x2126, 0x00EA), (0x2128, 0x005A),
+    (0x212A, 0x004B), (0x212B, 0x0041), (0x212C, 0x0042), (0x212D, 0x0043), (0x212F, 0x0065),
+    (0x2130, 0x0045), (0x2131, 0x0046), (0x2133, 0x004D), (0x2134, 0x006F), (0x2135, 0x0080),
+    (0x2136, 0x0081), (0x2137, 0x0082), (0x2138, 0x0083), (0x2139, 0x0069), (0x213C, 0x00E3),
+    (0x213E, 0x00E2), (0x2145, 0x0044), (0x2146, 0x0064), (0x2147, 0x0065), (0x2148, 0x0069),
+    (0x2149, 0x006A), (0x2160, 0x0049), (0x2164, 0x0056), (0x2169, 0x0058), (0x216C, 0x004C),
+    (0x216D, 0x0043), (0x216E, 0x0044), (0x216F, 0x004D), (0x2170, 0x0069), (0x2174, 0x0076),
+    (0x2179, 0x0078), (0x217C, 0x006C), (0x217D, 0x0063), (0x217E, 0x0064), (0x217F, 0x006D),
+    (0x2212, 0x002D), (0x2215, 0x002F), (0x2216, 0x005C), (0x2223, 0x007C), (0x223C, 0x007E),
+    (0x2249, 0x00F7), (0x2260, 0x003D), (0x2262, 0x00F0), (0x226E, 0x003C), (0x226F, 0x003E),
+    (0x2270, 0x00F3), (0x2271, 0x00F2), (0x2460, 0x0031), (0x2461, 0x0032), (0x2462, 0x0033),
+    (0x2463, 0x0034), (0x2464, 0x0035), (0x2465, 0x0036), (0x2466, 0x0037), (0x2467, 0x0038),
+    (0x2468, 0x0039), (0x24B6, 0x0041), (0x24B7, 0x0042), (0x24B8, 0x0043), (0x24B9, 0x0044),
+    (0x24BA, 0x0045), (0x24BB, 0x0046), (0x24BC, 0x0047), (0x24BD, 0x0048), (0x24BE, 0x0049),
+    (0x24BF, 0x004A), (0x24C0, 0x004B), (0x24C1, 0x004C), (0x24C2, 0x004D), (0x24C3, 0x004E),
+    (0x24C4, 0x004F), (0x24C5, 0x0050), (0x24C6, 0x0051), (0x24C7, 0x0052), (0x24C8, 0x0053),
+    (0x24C9, 0x0054), (0x24CA, 0x0055), (0x24CB, 0x0056), (0x24CC, 0x0057), (0x24CD, 0x0058),
+    (0x24CE, 0x0059), (0x24CF, 0x005A), (0x24D0, 0x0061), (0x24D1, 0x0062), (0x24D2, 0x0063),
+    (0x24D3, 0x0064), (0x24D4, 0x0065), (0x24D5, 0x0066), (0x24D6, 0x0067), (0x24D7, 0x0068),
+    (0x24D8, 0x0069), (0x24D9, 0x006A), (0x24DA, 0x006B), (0x24DB, 0x006C), (0x24DC, 0x006D),
+    (0x24DD, 0x006E), (0x24DE, 0x006F), (0x24DF, 0x0070), (0x24E0, 0x0071), (0x24E1, 0x0072),
+    (0x24E2, 0x0073), (0x24E3, 0x0074), (0x24E4, 0x0075), (0x24E5, 0x0076), (0x24E6, 0x0077),
+    (0x24E7, 0x0078), (0x24E8, 0x0079), (0x24E9, 0x007A), (0x24EA, 0x0030), (0x2C7C, 0x006A),
+    (0x2C7D, 0x0056), (0x3000, 0x0020), (0xA7F2, 0x0043), (0xA7F3, 0x0046), (0xA7F4, 0x0051),
+    (0xFB1D, 0x0089), (0xFB20, 0x0092), (0xFB21, 0x0080), (0xFB22, 0x0083), (0xFB23, 0x0084),
+    (0xFB24, 0x008B), (0xFB25, 0x008C), (0xFB26, 0x008D), (0xFB27, 0x0098), (0xFB28, 0x009A),
+    (0xFB29, 0x002B), (0xFB2A, 0x0099), (0xFB2B, 0x0099), (0xFB2C, 0x0099), (0xFB2D, 0x0099),
+    (0xFB2E, 0x0080), (0xFB2F, 0x0080), (0xFB30, 0x0080), (0xFB31, 0x0081), (0xFB32, 0x0082),
+    (0xFB33, 0x0083), (0xFB34, 0x0084), (0xFB35, 0x0085), (0xFB36, 0x0086), (0xFB38, 0x0088),
+    (0xFB39, 0x0089), (0xFB3A, 0x008A), (0xFB3B, 0x008B), (0xFB3C, 0x008C), (0xFB3E, 0x008E),
+    (0xFB40, 0x0090), (0xFB41, 0x0091), (0xFB43, 0x0093), (0xFB44, 0x0094), (0xFB46, 0x0096),
+    (0xFB47, 0x0097), (0xFB48, 0x0098), (0xFB49, 0x0099), (0xFB4A, 0x009A), (0xFB4B, 0x0085),
+    (0xFB4C, 0x0081), (0xFB4D, 0x008B), (0xFB4E, 0x0094), (0xFE10, 0x002C), (0xFE13, 0x003A),
+    (0xFE14, 0x003B), (0xFE15, 0x0021), (0xFE16, 0x003F), (0xFE33, 0x005F), (0xFE34, 0x005F),
+    (0xFE35, 0x0028), (0xFE36, 0x0029), (0xFE37, 0x007B), (0xFE38, 0x007D), (0xFE47, 0x005B),
+    (0xFE48, 0x005D), (0xFE4D, 0x005F), (0xFE4E, 0x005F), (0xFE4F, 0x005F), (0xFE50, 0x002C),
+    (0xFE52, 0x002E), (0xFE54, 0x003B), (0xFE55, 0x003A), (0xFE56, 0x003F), (0xFE57, 0x0021),
+    (0xFE59, 0x0028), (0xFE5A, 0x0029), (0xFE5B, 0x007B), (0xFE5C, 0x007D), (0xFE5F, 0x0023),
+    (0xFE60, 0x0026), (0xFE61, 0x002A), (0xFE62, 0x002B), (0xFE63, 0x002D), (0xFE64, 0x003C),
+    (0xFE65, 0x003E), (0xFE66, 0x003D), (0xFE68, 0x005C), (0xFE69, 0x0024), (0xFE6A, 0x0025),
+    (0xFE6B, 0x0040), (0xFF01, 0x0021), (0xFF02, 0x0022), (0xFF03, 0x0023), (0xFF04, 0x0024),
+    (0xFF05, 0x0025), (0xFF06, 0x0026), (0xFF07, 0x0027), (0xFF08, 0x0028), (0xFF09, 0x0029),
+    (0xFF0A, 0x002A), (0xFF0B, 0x002B), (0xFF0C, 0x002C), (0xFF0D, 0x002D), (0xFF0E, 0x002E),
+    (0xFF0F, 0x002F), (0xFF10, 0x0030), (0xFF11, 0x0031), (0xFF12, 0x0032), (0xFF13, 0x0033),
+    (0xFF14, 0x0034), (0xFF15, 0x0035), (0xFF16, 0x0036), (0xFF17, 0x0037), (0xFF18, 0x0038),
+    (0xFF19, 0x0039), (0xFF1A, 0x003A), (0xFF1B, 0x003B), (0xFF1C, 0x003C), (0xFF1D, 0x003D),
+    (0xFF1E, 0x003E), (0xFF1F, 0x003F), (0xFF20, 0x0040), (0xFF21, 0x0041), (0xFF22, 0x0042),
+    (0xFF23, 0x0043), (0xFF24, 0x0044), (0xFF25, 0x0045), (0xFF26, 0x0046), (0xFF27, 0x0047),
+    (0xFF28, 0x0048), (0xFF29, 0x0049), (0xFF2A, 0x004A), (0xFF2B, 0x004B), (0xFF2C, 0x004C),
+    (0xFF2D, 0x004D), (0xFF2E, 0x004E), (0xFF2F, 0x004F), (0xFF30, 0x0050), (0xFF31, 0x0051),
+    (0xFF32, 0x0052), (0xFF33, 0x0053), (0xFF34, 0x0054), (0xFF35, 0x0055), (0xFF36, 0x0056),
+    (0xFF37, 0x0057), (0xFF38, 0x0058), (0xFF39, 0x0059), (0xFF3A, 0x005A), (0xFF3B, 0x005B),
+    (0xFF3C, 0x005C), (0xFF3D, 0x005D), (0xFF3E, 0x005E), (0xFF3F, 0x005F), (0xFF40, 0x0060),
+    (0xFF41, 0x0061), (0xFF42, 0x0062), (0xFF43, 0x0063), (0xFF44, 0x0064), (0xFF45, 0x0065),
+    (0xFF46, 0x0066), (0xFF47, 0x0067), (0xFF48, 0x0068), (0xFF49, 0x0069), (0xFF4A, 0x006A),
+    (0xFF4B, 0x006B), (0xFF4C, 0x006C), (0xFF4D, 0x006D), (0xFF4E, 0x006E), (0xFF4F, 0x006F),
+    (0xFF50, 0x0070), (0xFF51, 0x0071), (0xFF52, 0x0072), (0xFF53, 0x0073), (0xFF54, 0x0074),
+    (0xFF55, 0x0075), (0xFF56, 0x0076), (0xFF57, 0x0077), (0xFF58, 0x0078), (0xFF59, 0x0079),
+    (0xFF5A, 0x007A), (0xFF5B, 0x007B), (0xFF5C, 0x007C), (0xFF5D, 0x007D), (0xFF5E, 0x007E),
+    (0xFFE0, 0x009B), (0xFFE1, 0x009C), (0xFFE2, 0x00AA), (0xFFE5, 0x009D), (0xFFE8, 0x00B3),
+    (0xFFED, 0x00FE),
+];
+
+/// Code page 866.
+#[rustfmt::skip]
+pub(crate) const CP866: [(u16, u16); 914] = [
+    (0x00AA, 0x0061), (0x00AB, 0x003C), (0x00B2, 0x0032), (0x00B3, 0x0033), (0x00B9, 0x0031),
+    (0x00BA, 0x006F), (0x00BB, 0x003E), (0x00C0, 0x0041), (0x00C1, 0x0041), (0x00C2, 0x0041),
+    (0x00C3, 0x0041), (0x00C4, 0x0041), (0x00C5, 0x0041), (0x00C7, 0x0043), (0x00C8, 0x0045),
+    (0x00C9, 0x0045), (0x00CA, 0x0045), (0x00CB, 0x0045), (0x00CC, 0x0049), (0x00CD, 0x0049),
+    (0x00CE, 0x0049), (0x00CF, 0x0049), (0x00D1, 0x004E), (0x00D2, 0x004F), (0x00D3, 0x004F),
+    (0x00D4, 0x004F), (0x00D5, 0x004F), (0x00D6, 0x004F), (0x00D9, 0x0055), (0x00DA, 0x0055),
+    (0x00DB, 0x0055), (0x00DC, 0x0055), (0x00DD, 0x0059), (0x00E0, 0x0061), (0x00E1, 0x0061),
+    (0x00E2, 0x0061), (0x00E3, 0x0061), (0x00E4, 0x0061), (0x00E5, 0x0061), (0x00E7, 0x0063),
+    (0x00E8, 0x0065), (0x00E9, 0x0065), (0x00EA, 0x0065), (0x00EB, 0x0065), (0x00EC, 0x0069),
+    (0x00ED, 0x0069), (0x00EE, 0x0069), (0x00EF, 0x0069), (0x00F1, 0x006E), (0x00F2, 0x006F),
+    (0x00F3, 0x006F), (0x00F4, 0x006F), (0x00F5, 0x006F), (0x00F6, 0x006F), (0x00F9, 0x0075),
+    (0x00FA, 0x0075), (0x00FB, 0x0075), (0x00FC, 0x0075), (0x00FD, 0x0079), (0x00FF, 0x0079),
+    (0x0100, 0x0041), (0x0101, 0x0061), (0x0102, 0x0041), (0x0103, 0x0061), (0x0104, 0x0041),
+    (0x0105, 0x0061), (0x0106, 0x0043), (0x0107, 0x0063), (0x0108, 0x0043), (0x0109, 0x0063),
+    (0x010A, 0x0043), (0x010B, 0x0063), (0x010C, 0x0043), (0x010D, 0x0063), (0x010E, 0x0044),
+    (0x010F, 0x0064), (0x0112, 0x0045), (0x0113, 0x0065), (0x0114, 0x0045), (0x0115, 0x0065),
+    (0x0116, 0x0045), (0x0117, 0x0065), (0x0118, 0x0045), (0x0119, 0x0065), (0x011A, 0x0045),
+    (0x011B, 0x0065), (0x011C, 0x0047), (0x011D, 0x0067), (0x011E, 0x0047), (0x011F, 0x0067),
+    (0x0120, 0x0047), (0x0121, 0x0067), (0x0122, 0x0047), (0x0123, 0x0067), (0x0124, 0x0048),
+    (0x0125, 0x0068), (0x0128, 0x0049), (0x0129, 0x0069), (0x012A, 0x0049), (0x012B, 0x0069),
+    (0x012C, 0x0049), (0x012D, 0x0069), (0x012E, 0x0049), (0x012F, 0x0069), (0x0130, 0x0049),
+    (0x0134, 0x004A), (0x0135, 0x006A), (0x0136, 0x004B), (0x0137, 0x006B), (0x0139, 0x004C),
+    (0x013A, 0x006C), (0x013B, 0x004C), (0x013C, 0x006C), (0x013D, 0x004C), (0x013E, 0x006C),
+    (0x0143, 0x004E), (0x0144, 0x006E), (0x0145, 0x004E), (0x0146, 0x006E), (0x0147, 0x004E),
+    (0x0148, 0x006E), (0x014C, 0x004F), (0x014D, 0x006F), (0x014E, 0x004F), (0x014F, 0x006F),
+    (0x0150, 0x004F), (0x0151, 0x006F), (0x0154, 0x0052), (0x0155, 0x0072), (0x0156, 0x0052),
+    (0x0157, 0x0072), (0x0158, 0x0052), (0x0159, 0x0072), (0x015A, 0x0053), (0x015B, 0x0073),
+    (0x015C, 0x0053), (0x015D, 0x0073), (0x015E, 0x0053), (0x015F, 0x0073), (0x0160, 0x0053),
+    (0x0161, 0x0073), (0x0162, 0x0054), (0x0163, 0x0074), (0x0164, 0x0054), (0x0165, 0x0074),
+    (0x0168, 0x0055), (0x0169, 0x0075), (0x016A, 0x0055), (0x016B, 0x0075), (0x016C, 0x0055),
+    (0x016D, 0x0075), (0x016E, 0x0055), (0x016F, 0x0075), (0x0170, 0x0055), (0x0171, 0x0075),
+    (0x0172, 0x0055), (0x0173, 0x0075), (0x0174, 0x0057), (0x0175, 0x0077), (0x0176, 0x0059),
+    (0x0177, 0x0079), (0x0178, 0x0059), (0x0179, 0x005A), (0x017A, 0x007A), (0x017B, 0x005A),
+    (0x017C, 0x007A), (0x017D, 0x005A), (0x017E, 0x007A), (0x017F, 0x0073), (0x01A0, 0x004F),
+    (0x01A1, 0x006F), (0x01AF, 0x0055), (0x01B0, 0x0075), (0x01CD, 0x0041), (0x01CE, 0x0061),
+    (0x01CF, 0x0049), (0x01D0, 0x0069), (0x01D1, 0x004F), (0x01D2, 0x006F), (0x01D3, 0x0055),
+    (0x01D4, 0x0075), (0x01D5, 0x0055), (0x01D6, 0x0075), (0x01D7, 0x0055), (0x01D8, 0x0075),
+    (0x01D9, 0x0055), (0x01DA, 0x0075), (0x01DB, 0x0055), (0x01DC, 0x0075), (0x01DE, 0x0041),
+    (0x01DF, 0x0061), (0x01E0, 0x0041), (0x01E1, 0x0061), (0x01E6, 0x0047), (0x01E7, 0x0067),
+    (0x01E8, 0x004B), (0x01E9, 0x006B), (0x01EA, 0x004F), (0x01EB, 0x006F), (0x01EC, 0x004F),
+    (0x01ED, 0x006F), (0x01F0, 0x006A), (0x01F4, 0x0047), (0x01F5, 0x0067), (0x01F8, 0x004E),
+    (0x01F9, 0x006E), (0x01FA, 0x0041), (0x01FB, 0x0061), (0x0200, 0x0041), (0x0201, 0x0061),
+    (0x0202, 0x0041), (0x0203, 0x0061), (0x0204, 0x0045), (0x0205, 0x0065), (0x0206, 0x0045),
+    (0x0207, 0x0065), (0x0208, 0x0049), (0x0209, 0x0069), (0x020A, 0x0049), (0x020B, 0x0069),
+    (0x020C, 0x004F), (0x020D, 0x006F), (0x020E, 0x004F), (0x020F, 0x006F), (0x0210, 0x0052),
+    (0x0211, 0x0072), (0x0212, 0x0052), (0x0213, 0x0072), (0x0214, 0x0055), (0x0215, 0x0075),
+    (0x0216, 0x0055), (0x0217, 0x0075), (0x0218, 0x0053), (0x0219, 0x0073), (0x021A, 0x0054),
+    (0x021B, 0x0074), (0x021E, 0x0048), (0x021F, 0x0068), (0x0226, 0x0041), (0x0227, 0x0061),
+    (0x0228, 0x0045), (0x0229, 0x0065), (0x022A, 0x004F), (0x022B, 0x006F), (0x022C, 0x004F),
+    (0x022D, 0x006F), (0x022E, 0x004F), (0x022F, 0x006F), (0x0230, 0x004F), (0x0231, 0x006F),
+    (0x0232, 0x0059), (0x0233, 0x0079), (0x02B0, 0x0068), (0x02B2, 0x006A), (0x02B3, 0x0072),
+    (0x02B7, 0x0077), (0x02B8, 0x0079), (0x02E1, 0x006C), (0x02E2, 0x0073), (0x02E3, 0x0078),
+    (0x037E, 0x003B), (0x0387, 0x00FA), (0x0400, 0x0085), (0x0403, 0x0083), (0x040C, 0x008A),
+    (0x040D, 0x0088), (0x0450, 0x00A5), (0x0453, 0x00A3), (0x045C, 0x00AA), (0x045D, 0x00A8),
+    (0x04C1, 0x0086), (0x04C2, 0x00A6), (0x04D0, 0x0080), (0x04D1, 0x00A0), (0x04D2, 0x0080),
+    (0x04D3, 0x00A0), (0x04D6, 0x0085), (0x04D7, 0x00A5), (0x04DC, 0x0086), (0x04DD, 0x00A6),
+    (0x04DE, 0x0087), (0x04DF, 0x00A7), (0x04E2, 0x0088), (0x04E3, 0x00A8), (0x04E4, 0x0088),
+    (0x04E5, 0x00A8), (0x04E6, 0x008E), (0x04E7, 0x00AE), (0x04EC, 0x009D), (0x04ED, 0x00ED),
+    (0x04EE, 0x0093), (0x04EF, 0x00E3), (0x04F0, 0x0093), (0x04F1, 0x00E3), (0x04F2, 0x0093),
+    (0x04F3, 0x00E3), (0x04F4, 0x0097), (0x04F5, 0x00E7), (0x04F8, 0x009B), (0x04F9, 0x00EB),
+    (0x1D2C, 0x0041), (0x1D2E, 0x0042), (0x1D30, 0x0044), (0x1D31, 0x0045), (0x1D33, 0x0047),
+    (0x1D34, 0x0048), (0x1D35, 0x0049), (0x1D36, 0x004A), (0x1D37, 0x004B), (0x1D38, 0x004C),
+    (0x1D39, 0x004D), (0x1D3A, 0x004E), (0x1D3C, 0x004F), (0x1D3E, 0x0050), (0x1D3F, 0x0052),
+    (0x1D40, 0x0054), (0x1D41, 0x0055), (0x1D42, 0x0057), (0x1D43, 0x0061), (0x1D47, 0x0062),
+    (0x1D48, 0x0064), (0x1D49, 0x0065), (0x1D4D, 0x0067), (0x1D4F, 0x006B), (0x1D50, 0x006D),
+    (0x1D52, 0x006F), (0x1D56, 0x0070), (0x1D57, 0x0074), (0x1D58, 0x0075), (0x1D5B, 0x0076),
+    (0x1D62, 0x0069), (0x1D63, 0x0072), (0x1D64, 0x0075), (0x1D65, 0x0076), (0x1D78, 0x00AD),
+    (0x1D9C, 0x0063), (0x1DA0, 0x0066), (0x1DBB, 0x007A), (0x1E00, 0x0041), (0x1E01, 0x0061),
+    (0x1E02, 0x0042), (0x1E03, 0x0062), (0x1E04, 0x0042), (0x1E05, 0x0062), (0x1E06, 0x0042),
+    (0x1E07, 0x0062), (0x1E08, 0x0043), (0x1E09, 0x0063), (0x1E0A, 0x0044), (0x1E0B, 0x0064),
+    (0x1E0C, 0x0044), (0x1E0D, 0x0064), (0x1E0E, 0x0044), (0x1E0F, 0x0064), (0x1E10, 0x0044),
+    (0x1E11, 0x0064), (0x1E12, 0x0044), (0x1E13, 0x0064), (0x1E14, 0x0045), (0x1E15, 0x0065),
+    (0x1E16, 0x0045), (0x1E17, 0x0065), (0x1E18, 0x0045), (0x1E19, 0x0065), (0x1E1A, 0x0045),
+    (0x1E1B, 0x0065), (0x1E1C, 0x0045), (0x1E1D, 0x0065), (0x1E1E, 0x0046), (0x1E1F, 0x0066),
+    (0x1E20, 0x0047), (0x1E21, 0x0067), (0x1E22, 0x0048), (0x1E23, 0x0068), (0x1E24, 0x0048),
+    (0x1E25, 0x0068), (0x1E26, 0x0048), (0x1E27, 0x0068), (0x1E28, 0x0048), (0x1E29, 0x0068),
+    (0x1E2A, 0x0048), (0x1E2B, 0x0068), (0x1E2C, 0x0049), (0x1E2D, 0x0069), (0x1E2E, 0x0049),
+    (0x1E2F, 0x0069), (0x1E30, 0x004B), (0x1E31, 0x006B), (0x1E32, 0x004B), (0x1E33, 0x006B),
+    (0x1E34, 0x004B), (0x1E35, 0x006B), (0x1E36, 0x004C), (0x1E37, 0x006C), (0x1E38, 0x004C),
+    (0x1E39, 0x006C), (0x1E3A, 0x004C), (0x1E3B, 0x006C), (0x1E3C, 0x004C), (0x1E3D, 0x006C),
+    (0x1E3E, 0x004D), (0x1E3F, 0x006D), (0x1E40, 0x004D), (0x1E41, 0x006D), (0x1E42, 0x004D),
+    (0x1E43, 0x006D), (0x1E44, 0x004E), (0x1E45, 0x006E), (0x1E46, 0x004E), (0x1E47, 0x006E),
+    (0x1E48, 0x004E), (0x1E49, 0x006E), (0x1E4A, 0x004E), (0x1E4B, 0x006E), (0x1E4C, 0x004F),
+    (0x1E4D, 0x006F), (0x1E4E, 0x004F), (0x1E4F, 0x006F), (0x1E50, 0x004F), (0x1E51, 0x006F),
+    (0x1E52, 0x004F), (0x1E53, 0x006F), (0x1E54, 0x0050), (0x1E55, 0x0070), (0x1E56, 0x0050),
+    (0x1E57, 0x0070), (0x1E58, 0x0052), (0x1E59, 0x0072), (0x1E5A, 0x0052), (0x1E5B, 0x0072),
+    (0x1E5C, 0x0052), (0x1E5D, 0x0072), (0x1E5E, 0x0052), (0x1E5F, 0x0072), (0x1E60, 0x0053),
+    (0x1E61, 0x0073), (0x1E62, 0x0053), (0x1E63, 0x0073), (0x1E64, 0x0053), (0x1E65, 0x0073),
+    (0x1E66, 0x0053), (0x1E67, 0x0073), (0x1E68, 0x0053), (0x1E69, 0x0073), (0x1E6A, 0x0054),
+    (0x1E6B, 0x0074), (0x1E6C, 0x0054), (0x1E6D, 0x0074), (0x1E6E, 0x0054), (0x1E6F, 0x0074),
+    (0x1E70, 0x0054), (0x1E71, 0x0074), (0x1E72, 0x0055), (0x1E73, 0x0075), (0x1E74, 0x0055),
+    (0x1E75, 0x0075), (0x1E76, 0x0055), (0x1E77, 0x0075), (0x1E78, 0x0055), (0x1E79, 0x0075),
+    (0x1E7A, 0x0055), (0x1E7B, 0x0075), (0x1E7C, 0x0056), (0x1E7D, 0x0076), (0x1E7E, 0x0056),
+    (0x1E7F, 0x0076), (0x1E80, 0x0057), (0x1E81, 0x0077), (0x1E82, 0x0057), (0x1E83, 0x0077),
+    (0x1E84, 0x0057), (0x1E85, 0x0077), (0x1E86, 0x0057), (0x1E87, 0x0077), (0x1E88, 0x0057),
+    (0x1E89, 0x0077), (0x1E8A, 0x0058), (0x1E8B, 0x0078), (0x1E8C, 0x0058), (0x1E8D, 0x0078),
+    (0x1E8E, 0x0059), (0x1E8F, 0x0079), (0x1E90, 0x005A), (0x1E91, 0x007A), (0x1E92, 0x005A),
+    (0x1E93, 0x007A), (0x1E94, 0x005A), (0x1E95, 0x007A), (0x1E96, 0x0068), (0x1E97, 0x0074),
+    (0x1E98, 0x0077), (0x1E99, 0x0079), (0x1E9B, 0x0073), (0x1EA0, 0x0041), (0x1EA1, 0x0061),
+    (0x1EA2, 0x0041), (0x1EA3, 0x0061), (0x1EA4, 0x0041), (0x1EA5, 0x0061), (0x1EA6, 0x0041),
+    (0x1EA7, 0x0061), (0x1EA8, 0x0041), (0x1EA9, 0x0061), (0x1EAA, 0x0041), (0x1EAB, 0x0061),
+    (0x1EAC, 0x0041), (0x1EAD, 0x0061), (0x1EAE, 0x0041), (0x1EAF, 0x0061), (0x1EB0, 0x0041),
+    (0x1EB1, 0x0061), (0x1EB2, 0x0041), (0x1EB3, 0x0061), (0x1EB4, 0x0041), (0x1EB5, 0x0061),
+    (0x1EB6, 0x0041), (0x1EB7, 0x0061), (0x1EB8, 0x0045), (0x1EB9, 0x0065), (0x1EBA, 0x0045),
+    (0x1EBB, 0x0065), (0x1EBC, 0x0045), (0x1EBD, 0x0065), (0x1EBE, 0x0045), (0x1EBF, 0x0065),
+    (0x1EC0, 0x0045), (0x1EC1, 0x0065), (0x1EC2, 0x0045), (0x1EC3, 0x0065), (0x1EC4, 0x0045),
+    (0x1EC5, 0x0065), (0x1EC6, 0x0045), (0x1EC7, 0x0065), (0x1EC8, 0x0049), (0x1EC9, 0x0069),
+    (0x1ECA, 0x0049), (0x1ECB, 0x0069), (0x1ECC, 0x004F), (0x1ECD, 0x006F), (0x1ECE, 0x004F),
+    (0x1ECF, 0x006F), (0x1ED0, 0x004F), (0x1ED1, 0x006F), (0x1ED2, 0x004F), (0x1ED3, 0x006F),
+    (0x1ED4, 0x004F), (0x1ED5, 0x006F), (0x1ED6, 0x004F), (0x1ED7, 0x006F), (0x1ED8, 0x004F),
+    (0x1ED9, 0x006F), (0x1EDA, 0x004F), (0x1EDB, 0x006F), (0x1EDC, 0x004F), (0x1EDD, 0x006F),
+    (0x1EDE, 0x004F), (0x1EDF, 0x006F), (0x1EE0, 0x004F), (0x1EE1, 0x006F), (0x1EE2, 0x004F),
+    (0x1EE3, 0x006F), (0x1EE4, 0x0055), (0x1EE5, 0x0075), (0x1EE6, 0x0055), (0x1EE7, 0x0075),
+    (0x1EE8, 0x0055), (0x1EE9, 0x0075), (0x1EEA, 0x0055), (0x1EEB, 0x0075), (0x1EEC, 0x0055),
+    (0x1EED, 0x0075), (0x1EEE, 0x0055), (0x1EEF, 0x0075), (0x1EF0, 0x0055), (0x1EF1, 0x0075),
+    (0x1EF2, 0x0059), (0x1EF3, 0x0079), (0x1EF4, 0x0059), (0x1EF5, 0x0079), (0x1EF6, 0x0059),
+    (0x1EF7, 0x0079), (0x1EF8, 0x0059), (0x1EF9, 0x0079), (0x1FEF, 0x0060), (0x2000, 0x0020),
+    (0x2001, 0x0020), (0x2002, 0x0020), (0x2003, 0x0020), (0x2004, 0x0020), (0x2005, 0x0020),
+    (0x2006, 0x0020), (0x2007, 0x0020), (0x2008, 0x0020), (0x2009, 0x0020), (0x200A, 0x0020),
+    (0x2010, 0x002D), (0x2012, 0x002D), (0x2013, 0x002D), (0x2014, 0x002D), (0x2015, 0x002D),
+    (0x2018, 0x0027), (0x2019, 0x0027), (0x201A, 0x002C), (0x201B, 0x0027), (0x201C, 0x0022),
+    (0x201D, 0x0022), (0x201E, 0x0022), (0x201F, 0x0022), (0x2024, 0x002E), (0x202F, 0x0020),
+    (0x2032, 0x0027), (0x2035, 0x0060), (0x2039, 0x003C), (0x203A, 0x003E), (0x205F, 0x0020),
+    (0x2070, 0x0030), (0x2071, 0x0069), (0x2074, 0x0034), (0x2075, 0x0035), (0x2076, 0x0036),
+    (0x2077, 0x0037), (0x2078, 0x0038), (0x2079, 0x0039), (0x207A, 0x002B), (0x207C, 0x003D),
+    (0x207D, 0x0028), (0x207E, 0x0029), (0x207F, 0x006E), (0x2080, 0x0030), (0x2081, 0x0031),
+    (0x2082, 0x0032), (0x2083, 0x0033), (0x2084, 0x0034), (0x2085, 0x0035), (0x2086, 0x0036),
+    (0x2087, 0x0037), (0x2088, 0x0038), (0x2089, 0x0039), (0x208A, 0x002B), (0x208C, 0x003D),
+    (0x208D, 0x0028), (0x208E, 0x0029), (0x2090, 0x0061), (0x2091, 0x0065), (0x2092, 0x006F),
+    (0x2093, 0x0078), (0x2095, 0x0068), (0x2096, 0x006B), (0x2097, 0x006C), (0x2098, 0x006D),
+    (0x2099, 0x006E), (0x209A, 0x0070), (0x209B, 0x0073), (0x209C, 0x0074), (0x2102, 0x0043),
+    (0x210A, 0x0067), (0x210B, 0x0048), (0x210C, 0x0048), (0x210D, 0x0048), (0x210E, 0x0068),
+    (0x2110, 0x0049), (0x2111, 0x0049), (0x2112, 0x004C), (0x2113, 0x006C), (0x2115, 0x004E),
+    (0x2119, 0x0050), (0x211A, 0x0051), (0x211B, 0x0052), (0x211C, 0x0052), (0x211D, 0x0052),
+    (0x2124, 0x005A), (0x2128, 0x005A), (0x212A, 0x004B), (0x212B, 0x0041), (0x212C, 0x0042),
+    (0x212D, 0x0043), (0x212F, 0x0065), (0x2130, 0x0045), (0x2131, 0x0046), (0x2133, 0x004D),
+    (0x2134, 0x006F), (0x2139, 0x0069), (0x2145, 0x0044), (0x2146, 0x0064), (0x2147, 0x0065),
+    (0x2148, 0x0069), (0x2149, 0x006A), (0x2160, 0x0049), (0x2164, 0x0056), (0x2169, 0x0058),
+    (0x216C, 0x004C), (0x216D, 0x0043), (0x216E, 0x0044), (0x216F, 0x004D), (0x2170, 0x0069),
+    (0x2174, 0x0076), (0x2179, 0x0078), (0x217C, 0x006C), (0x217D, 0x0063), (0x217E, 0x0064),
+    (0x217F, 0x006D), (0x2212, 0x002D), (0x2215, 0x002F), (0x2216, 0x005C), (0x2223, 0x007C),
+    (0x223C, 0x007E), (0x2260, 0x003D), (0x226E, 0x003C), (0x226F, 0x003E), (0x2460, 0x0031),
+    (0x2461, 0x0032), (0x2462, 0x0033), (0x2463, 0x0034), (0x2464, 0x0035), (0x2465, 0x0036),
+    (0x2466, 0x0037), (0x2467, 0x0038), (0x2468, 0x0039), (0x24B6, 0x0041), (0x24B7, 0x0042),
+    (0x24B8, 0x0043), (0x24B9, 0x0044), (0x24BA, 0x0045), (0x24BB, 0x0046), (0x24BC, 0x0047),
+    (0x24BD, 0x0048), (0x24BE, 0x0049), (0x24BF, 0x004A), (0x24C0, 0x004B), (0x24C1, 0x004C),
+    (0x24C2, 0x004D), (0x24C3, 0x004E), (0x24C4, 0x004F), (0x24C5, 0x0050), (0x24C6, 0x0051),
+    (0x24C7, 0x0052), (0x24C8, 0x0053), (0x24C9, 0x0054), (0x24CA, 0x0055), (0x24CB, 0x0056),
+    (0x24CC, 0x0057), (0x24CD, 0x0058), (0x24CE, 0x0059), (0x24CF, 0x005A), (0x24D0, 0x0061),
+    (0x24D1, 0x0062), (0x24D2, 0x0063), (0x24D3, 0x0064), (0x24D4, 0x0065), (0x24D5, 0x0066),
+    (0x24D6, 0x0067), (0x24D7, 0x0068), (0x24D8, 0x0069), (0x24D9, 0x006A), (0x24DA, 0x006B),
+    (0x24DB, 0x006C), (0x24DC, 0x006D), (0x24DD, 0x006E), (0x24DE, 0x006F), (0x24DF, 0x0070),
+    (0x24E0, 0x0071), (0x24E1, 0x0072), (0x24E2, 0x0073), (0x24E3, 0x0074), (0x24E4, 0x0075),
+    (0x24E5, 0x0076), (0x24E6, 0x0077), (0x24E7, 0x0078), (0x24E8, 0x0079), (0x24E9, 0x007A),
+    (0x24EA, 0x0030), (0x2C7C, 0x006A), (0x2C7D, 0x0056), (0x3000, 0x0020), (0xA69C, 0x00EA),
+    (0xA69D, 0x00EC), (0xA7F2, 0x0043), (0xA7F3, 0x0046), (0xA7F4, 0x0051), (0xFB29, 0x002B),
+    (0xFE10, 0x002C), (0xFE13, 0x003A), (0xFE14, 0x003B), (0xFE15, 0x0021), (0xFE16, 0x003F),
+    (0xFE33, 0x005F), (0xFE34, 0x005F), (0xFE35, 0x0028), (0xFE36, 0x0029), (0xFE37, 0x007B),
+    (0xFE38, 0x007D), (0xFE47, 0x005B), (0xFE48, 0x005D), (0xFE4D, 0x005F), (0xFE4E, 0x005F),
+    (0xFE4F, 0x005F), (0xFE50, 0x002C), (0xFE52, 0x002E), (0xFE54, 0x003B), (0xFE55, 0x003A),
+    (0xFE56, 0x003F), (0xFE57, 0x0021), (0xFE59, 0x0028), (0xFE5A, 0x0029), (0xFE5B, 0x007B),
+    (0xFE5C, 0x007D), (0xFE5F, 0x0023), (0xFE60, 0x0026), (0xFE61, 0x002A), (0xFE62, 0x002B),
+    (0xFE63, 0x002D), (0xFE64, 0x003C), (0xFE65, 0x003E), (0xFE66, 0x003D), (0xFE68, 0x005C),
+    (0xFE69, 0x0024), (0xFE6A, 0x0025), (0xFE6B, 0x0040), (0xFF01, 0x0021), (0xFF02, 0x0022),
+    (0xFF03, 0x0023), (0xFF04, 0x0024), (0xFF05, 0x0025), (0xFF06, 0x0026), (0xFF07, 0x0027),
+    (0xFF08, 0x0028), (0xFF09, 0x0029), (0xFF0A, 0x002A), (0xFF0B, 0x002B), (0xFF0C, 0x002C),
+    (0xFF0D, 0x002D), (0xFF0E, 0x002E), (0xFF0F, 0x002F), (0xFF10, 0x0030), (0xFF11, 0x0031),
+    (0xFF12, 0x0032), (0xFF13, 0x0033), (0xFF14, 0x0034), (0xFF15, 0x0035), (0xFF16, 0x0036),
+    (0xFF17, 0x0037), (0xFF18, 0x0038), (0xFF19, 0x0039), (0xFF1A, 0x003A), (0xFF1B, 0x003B),
+    (0xFF1C, 0x003C), (0xFF1D, 0x003D), (0xFF1E, 0x003E), (0xFF1F, 0x003F), (0xFF20, 0x0040),
+    (0xFF21, 0x0041), (0xFF22, 0x0042), (0xFF23, 0x0043), (0xFF24, 0x0044), (0xFF25, 0x0045),
+    (0xFF26, 0x0046), (0xFF27, 0x0047), (0xFF28, 0x0048), (0xFF29, 0x0049), (0xFF2A, 0x004A),
+    (0xFF2B, 0x004B), (0xFF2C, 0x004C), (0xFF2D, 0x004D), (0xFF2E, 0x004E), (0xFF2F, 0x004F),
+    (0xFF30, 0x0050), (0xFF31, 0x0051), (0xFF32, 0x0052), (0xFF33, 0x0053), (0xFF34, 0x0054),
+    (0xFF35, 0x0055), (0xFF36, 0x0056), (0xFF37, 0x0057), (0xFF38, 0x0058), (0xFF39, 0x0059),
+    (0xFF3A, 0x005A), (0xFF3B, 0x005B), (0xFF3C, 0x005C), (0xFF3D, 0x005D), (0xFF3E, 0x005E),
+    (0xFF3F, 0x005F), (0xFF40, 0x0060), (0xFF41, 0x0061), (0xFF42, 0x0062), (0xFF43, 0x0063),
+    (0xFF44, 0x0064), (0xFF45, 0x0065), (0xFF46, 0x0066), (0xFF47, 0x0067), (0xFF48, 0x0068),
+    (0xFF49, 0x0069), (0xFF4A, 0x006A), (0xFF4B, 0x006B), (0xFF4C, 0x006C), (0xFF4D, 0x006D),
+    (0xFF4E, 0x006E), (0xFF4F, 0x006F), (0xFF50, 0x0070), (0xFF51, 0x0071), (0xFF52, 0x0072),
+    (0xFF53, 0x0073), (0xFF54, 0x0074), (0xFF55, 0x0075), (0xFF56, 0x0076), (0xFF57, 0x0077),
+    (0xFF58, 0x0078), (0xFF59, 0x0079), (0xFF5A, 0x007A), (0xFF5B, 0x007B), (0xFF5C, 0x007C),
+    (0xFF5D, 0x007D), (0xFF5E, 0x007E), (0xFFE8, 0x00B3), (0xFFED, 0x00FE),
+];
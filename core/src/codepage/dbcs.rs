@@ -0,0 +1,126 @@
+//! Double-byte code pages.
+//!
+//! A table is generated by tools/gen_codepage.py and consists of little-endian
+//! `u16`s:
+//!
+//! - The characters of the bytes 0x80-0xFF. [`LEAD`] means a lead byte.
+//! - 256 characters for the trail bytes of each lead byte 0x81-0xFE.
+//! - The number of the encoding overrides, followed by pairs of a character
+//!   and its code. A character is encoded to the first code in the table
+//!   unless it is overridden.
+//!
+//! 0 means the byte or the byte sequence is unassigned.
+//!
+//! The tables of 932, 936, 949 and 950 aren't generated from tools/bestfit/
+//! yet, which isn't vendored, so they differ from the output of the script
+//! until they are regenerated.
+use std::{collections::HashMap, sync::OnceLock};
+
+/// Marks a lead byte.
+const LEAD: u16 = 0xFFFF;
+const ROWS: usize = 0x80;
+
+pub(crate) static CP932: Dbcs = Dbcs::new(include_bytes!("cp932.bin"));
+pub(crate) static CP936: Dbcs = Dbcs::new(include_bytes!("cp936.bin"));
+pub(crate) static CP949: Dbcs = Dbcs::new(include_bytes!("cp949.bin"));
+pub(crate) static CP950: Dbcs = Dbcs::new(include_bytes!("cp950.bin"));
+/// The one and two-byte part of GB18030.
+pub(crate) static CP54936: Dbcs = Dbcs::new(include_bytes!("cp54936.bin"));
+
+pub(crate) struct Dbcs {
+    data: &'static [u8],
+    encoder: OnceLock<HashMap<u16, u16>>,
+}
+
+impl Dbcs {
+    const fn new(data: &'static [u8]) -> Self {
+        Self {
+            data,
+            encoder: OnceLock::new(),
+        }
+    }
+
+    #[inline]
+    fn get(&self, i: usize) -> u16 {
+        u16::from_le_bytes([self.data[i * 2], self.data[i * 2 + 1]])
+    }
+
+    /// Returns true if `b` is a lead byte.
+    pub(crate) fn is_lead(&self, b: u8) -> bool {
+        b >= 0x80 && self.get(b as usize - 0x80) == LEAD
+    }
+
+    /// Returns the character of a single byte `b`, or 0 if it is unassigned.
+    pub(crate) fn single(&self, b: u8) -> u16 {
+        match b {
+            0x00..=0x7F => b as u16,
+            _ if self.is_lead(b) => 0,
+            _ => self.get(b as usize - 0x80),
+        }
+    }
+
+    /// Returns the character of `lead` and `trail`, or 0 if it is unassigned.
+    pub(crate) fn double(&self, lead: u8, trail: u8) -> u16 {
+        if !self.is_lead(lead) {
+            return 0;
+        }
+        self.get(ROWS + (lead as usize - 0x81) * 0x100 + trail as usize)
+    }
+
+    /// Returns the character at the start of `x`, or 0 if it is unassigned,
+    /// and its length in bytes.
+    pub(crate) fn decode(&self, x: &[u8]) -> (u16, usize) {
+        match *x {
+            [lead, trail, ..] if self.is_lead(lead) => {
+                match self.double(lead, trail) {
+                    // The trail byte may start the next character.
+                    0 => (0, 1),
+                    c => (c, 2),
+                }
+            }
+            [b, ..] => (self.single(b), 1),
+            [] => (0, 0),
+        }
+    }
+
+    /// Returns the code of `c`. A code less than 0x100 is a single byte.
+    pub(crate) fn encode(&self, c: u16) -> Option<u16> {
+        if c < 0x80 {
+            return Some(c);
+        }
+        self.encoder
+            .get_or_init(|| self.make_encoder())
+            .get(&c)
+            .copied()
+    }
+
+    fn make_encoder(&self) -> HashMap<u16, u16> {
+        let mut ret = HashMap::new();
+        for b in 0x80..=0xFF {
+            match self.single(b) {
+                0 => {}
+                c => {
+                    ret.entry(c).or_insert(b as u16);
+                }
+            }
+        }
+        for lead in 0x81..=0xFE {
+            for trail in 0x00..=0xFF {
+                match self.double(lead, trail) {
+                    0 => {}
+                    c => {
+                        ret.entry(c)
+                            .or_insert(u16::from_be_bytes([lead, trail]));
+                    }
+                }
+            }
+        }
+        let overrides = ROWS + 0x7E * 0x100;
+        for i in 0..self.get(overrides) as usize {
+            let c = self.get(overrides + 1 + i * 2);
+            let code = self.get(overrides + 2 + i * 2);
+            ret.insert(c, code);
+        }
+        ret
+    }
+}
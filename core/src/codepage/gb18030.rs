@@ -0,0 +1,71 @@
+//! GB18030 (code page 54936).
+//!
+//! The one and two-byte sequences are in a table. The other characters are
+//! four bytes, which are computed from their linear index.
+mod ranges;
+
+use super::dbcs::CP54936;
+use ranges::RANGES;
+
+/// The linear index of U+10000.
+const SUPPLEMENTARY_LINEAR: u32 = 0x2E248;
+
+/// Returns the code of `c`.
+pub(crate) fn encode(c: char) -> u32 {
+    let c = c as u32;
+    if let Some(code) = u16::try_from(c).ok().and_then(|x| CP54936.encode(x)) {
+        return code.into();
+    }
+    let linear = match c {
+        0x10000.. => SUPPLEMENTARY_LINEAR + (c - 0x10000),
+        _ => {
+            let i = RANGES.partition_point(|&(x, _)| x as u32 <= c) - 1;
+            RANGES[i].1 + (c - RANGES[i].0 as u32)
+        }
+    };
+    from_linear(linear)
+}
+
+/// Returns the character at the start of `x` and its length in bytes.
+pub(crate) fn decode(x: &[u8]) -> (Option<char>, usize) {
+    let [
+        b1 @ 0x81..=0xFE,
+        b2 @ 0x30..=0x39,
+        b3 @ 0x81..=0xFE,
+        b4 @ 0x30..=0x39,
+        ..,
+    ] = *x
+    else {
+        let (c, len) = CP54936.decode(x);
+        return (char::from_u32(c.into()).filter(|&c| c != '\0'), len);
+    };
+    let code = u32::from_be_bytes([b1, b2, b3, b4]);
+    let linear = to_linear(code);
+    let c = match linear {
+        SUPPLEMENTARY_LINEAR.. => 0x10000 + (linear - SUPPLEMENTARY_LINEAR),
+        _ => {
+            let i = RANGES.partition_point(|&(_, x)| x <= linear) - 1;
+            RANGES[i].0 as u32 + (linear - RANGES[i].1)
+        }
+    };
+    // The code is invalid unless it is the one of the character.
+    let c = char::from_u32(c).filter(|&c| encode(c) == code);
+    (c, 4)
+}
+
+/// Returns the four-byte code of a linear index.
+fn from_linear(mut linear: u32) -> u32 {
+    let b4 = linear % 10 + 0x30;
+    linear /= 10;
+    let b3 = linear % 126 + 0x81;
+    linear /= 126;
+    let b2 = linear % 10 + 0x30;
+    let b1 = linear / 10 + 0x81;
+    (b1 << 24) | (b2 << 16) | (b3 << 8) | b4
+}
+
+/// Returns the linear index of a four-byte code.
+fn to_linear(code: u32) -> u32 {
+    let [b1, b2, b3, b4] = code.to_be_bytes().map(u32::from);
+    (((b1 - 0x81) * 10 + (b2 - 0x30)) * 126 + (b3 - 0x81)) * 10 + (b4 - 0x30)
+}
@@ -1,7 +1,6 @@
-use crate::raw::*;
+use crate::{CpInfo, codepage, raw::*};
 use std::ptr::{null, null_mut};
 
-pub(crate) const CP_UTF8: UINT = 65001;
 pub(crate) const MB_ERR_INVALID_CHARS: DWORD = 0x8;
 pub(crate) const WC_NO_BEST_FIT_CHARS: DWORD = 0x400;
 pub(crate) const ERROR_INVALID_PARAMETER: DWORD = 0x57;
@@ -10,17 +9,97 @@ pub(crate) const ERROR_NO_UNICODE_TRANSLATION: DWORD = 0x459;
 
 pub(crate) type OsResult<T> = Result<T, u32>;
 
-pub(crate) fn utf8_to_wide(x: &str) -> OsResult<Vec<u16>> {
-    // UTF-8 to Unicode is loss less
-    multi_byte_to_wide_char_wrap(CP_UTF8, MB_ERR_INVALID_CHARS, x.as_bytes())
+pub(crate) fn mb_to_wide(code_page: u32, x: &[u8]) -> OsResult<Vec<u16>> {
+    if x.is_empty() {
+        return Ok(Vec::new());
+    }
+    multi_byte_to_wide_char_wrap(code_page, MB_ERR_INVALID_CHARS, x)
+}
+
+pub(crate) fn mb_to_wide_lossy(code_page: u32, x: &[u8]) -> OsResult<Vec<u16>> {
+    if x.is_empty() {
+        return Ok(Vec::new());
+    }
+    multi_byte_to_wide_char_wrap(code_page, 0, x)
+}
+
+pub(crate) fn wide_to_mb(
+    code_page: u32,
+    x: &[u16],
+    best_fit: bool,
+) -> OsResult<Vec<u8>> {
+    if x.is_empty() {
+        return Ok(Vec::new());
+    }
+    // WideCharToMultiByte doesn't accept WC_NO_BEST_FIT_CHARS for GB18030.
+    if code_page == codepage::CP_GB18030 {
+        return codepage::wide_to_mb(code_page, x, best_fit);
+    }
+    wide_char_to_multi_byte_wrap(code_page, wc_flags(best_fit), x, None, true)
+}
+
+pub(crate) fn wide_to_mb_lossy(
+    code_page: u32,
+    x: &[u16],
+    default_char: Option<u8>,
+    best_fit: bool,
+) -> OsResult<Vec<u8>> {
+    if x.is_empty() {
+        return Ok(Vec::new());
+    }
+    if code_page == codepage::CP_GB18030 {
+        return codepage::wide_to_mb_lossy(
+            code_page,
+            x,
+            default_char,
+            best_fit,
+        );
+    }
+    wide_char_to_multi_byte_wrap(
+        code_page,
+        wc_flags(best_fit),
+        x,
+        default_char,
+        false,
+    )
 }
 
-pub(crate) fn wide_to_mb(code_page: u32, x: &[u16]) -> OsResult<Vec<u8>> {
-    wide_char_to_multi_byte_wrap(code_page, WC_NO_BEST_FIT_CHARS, x, true)
+pub(crate) fn cp_info(code_page: u32) -> OsResult<CpInfo> {
+    let info = get_cp_info_ex(code_page)?;
+    Ok(CpInfo {
+        code_page: info.CodePage,
+        name: cp_name(&info.CodePageName),
+        max_char_size: info.MaxCharSize,
+    })
 }
 
-pub(crate) fn wide_to_mb_lossy(code_page: u32, x: &[u16]) -> OsResult<Vec<u8>> {
-    wide_char_to_multi_byte_wrap(code_page, WC_NO_BEST_FIT_CHARS, x, false)
+/// Returns the name in `CodePageName`, e.g. `ANSI - Latin I` of
+/// `1252  (ANSI - Latin I)`.
+fn cp_name(x: &[u16]) -> String {
+    let len = x.iter().position(|&c| c == 0).unwrap_or(x.len());
+    let name = String::from_utf16_lossy(&x[..len]);
+    let name = name.trim_start_matches(|c: char| c.is_ascii_digit()).trim();
+    name.strip_prefix('(')
+        .and_then(|x| x.strip_suffix(')'))
+        .unwrap_or(name)
+        .to_string()
+}
+
+/// Safe wrapper function of GetCPInfoExW.
+fn get_cp_info_ex(code_page: UINT) -> OsResult<_cpinfoexw> {
+    unsafe {
+        let mut info = std::mem::zeroed();
+        match GetCPInfoExW(code_page, 0, &mut info) {
+            0 => Err(GetLastError()),
+            _ => Ok(info),
+        }
+    }
+}
+
+/// The best fit mode uses the best fit tables of Windows.
+#[inline]
+fn wc_flags(best_fit: bool) -> DWORD {
+    if best_fit { 0 } else { WC_NO_BEST_FIT_CHARS }
 }
 
 /// Safe wrapper function of MultiByteToWideChar.
@@ -62,7 +141,8 @@ fn wide_char_to_multi_byte<'a>(
     default_char: impl Into<Option<u8>>,
     used_default_char: impl Into<Option<&'a mut i32>>,
 ) -> OsResult<usize> {
-    let dc = default_char.into().map_or(null(), |x| &x);
+    let dc = default_char.into();
+    let dc = dc.as_ref().map_or(null(), |x| x as *const u8);
     unsafe {
         match WideCharToMultiByte(
             code_page,
@@ -91,10 +171,11 @@ pub(crate) fn wide_char_to_multi_byte_wrap(
     code_page: UINT,
     wc_flags: DWORD,
     x: &[u16],
+    default_char: Option<u8>,
     used_default_char: bool,
 ) -> OsResult<Vec<u8>> {
-    let x = if x.is_empty() { &[0x00] } else { x };
-    let l = x.len() * 4;
+    // A UTF-16 code unit is converted to at most MaxCharSize bytes.
+    let l = x.len() * get_cp_info_ex(code_page)?.MaxCharSize as usize;
     let mut ret: Vec<u8> = Vec::with_capacity(l);
     unsafe {
         ret.set_len(l);
@@ -111,7 +192,7 @@ pub(crate) fn wide_char_to_multi_byte_wrap(
         wc_flags,
         x,
         ret.as_mut_slice(),
-        None,
+        default_char,
         udc,
     ) {
         Ok(l2) => {
@@ -123,9 +204,13 @@ pub(crate) fn wide_char_to_multi_byte_wrap(
             }
             Ok(ret)
         }
-        Err(ERROR_INSUFFICIENT_BUFFER) => {
-            wide_char_to_multi_byte2(code_page, wc_flags, x, used_default_char)
-        }
+        Err(ERROR_INSUFFICIENT_BUFFER) => wide_char_to_multi_byte2(
+            code_page,
+            wc_flags,
+            x,
+            default_char,
+            used_default_char,
+        ),
         Err(x) => Err(x),
     }
 }
@@ -137,6 +222,7 @@ pub(crate) fn wide_char_to_multi_byte2(
     code_page: UINT,
     wc_flags: DWORD,
     x: &[u16],
+    default_char: Option<u8>,
     used_default_char: bool,
 ) -> OsResult<Vec<u8>> {
     // get the required buffer size.
@@ -158,7 +244,7 @@ pub(crate) fn wide_char_to_multi_byte2(
         wc_flags,
         x,
         ret.as_mut_slice(),
-        None,
+        default_char,
         udc,
     )?;
     if udc_flag != 0 {
@@ -174,7 +260,6 @@ fn multi_byte_to_wide_char_wrap(
     mb_flags: DWORD,
     x: &[u8],
 ) -> OsResult<Vec<u16>> {
-    let x = if x.is_empty() { &[0x00] } else { x };
     let l = x.len();
     let mut ret: Vec<u16> = Vec::with_capacity(l);
     unsafe {
@@ -254,3 +339,12 @@ pub(crate) fn get_system_default_acp() -> crate::raw::OsResult<u32> {
             | LOCALE_RETURN_NUMBER,
     )
 }
+
+pub(crate) fn get_system_default_oemcp() -> crate::raw::OsResult<u32> {
+    let locale = to_utf16("!x-sys-default-locale");
+
+    get_locale_cp(
+        locale.as_ptr(),
+        LOCALE_IDEFAULTCODEPAGE | LOCALE_RETURN_NUMBER,
+    )
+}
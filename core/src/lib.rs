@@ -0,0 +1,150 @@
+// Copyright takubokudori.
+// This source code is licensed under the MIT or Apache-2.0 license.
+//! # Windy-macros-core
+//!
+//! The code page conversion of [windy-macros](https://crates.io/crates/windy-macros), which can be used at run time
+//! or from other build tools.
+//!
+//! On a Windows host, the conversion uses `MultiByteToWideChar` and `WideCharToMultiByte`. On other hosts, it uses
+//! built-in tables of the code pages 874, 932, 936, 949, 950, 1250-1258, 437, 720, 737, 775, 850, 852, 855, 857,
+//! 862, 866 and 54936 (GB18030), which give the same bytes as Windows except for the approximated best fit mappings.
+//!
+//! The conversion is implemented by a [`Backend`]: `Win32Backend` on Windows and [`TableBackend`] on any host.
+//! [`default_backend`] returns the one for the host, which the functions use.
+//!
+//! With the `windy` feature on Windows, `safe` has the constructors of the windy strings used by the macros with
+//! `safe`.
+//!
+//! ```
+//! use windy_macros_core::{EncodeOptions, encode, encode_wide};
+//!
+//! assert_eq!(vec![0x61, 0x3042], encode_wide("aあ"));
+//! assert_eq!(
+//!     Ok(vec![0x61, 0x82, 0xA0]),
+//!     encode(932, "aあ", &EncodeOptions::default())
+//! );
+//!
+//! let options = EncodeOptions {
+//!     lossy: true,
+//!     replace: Some(b'_'),
+//!     ..Default::default()
+//! };
+//! assert_eq!(Ok(b"a_".to_vec()), encode(1252, "aあ", &options));
+//! ```
+//!
+//! # Errors
+//!
+//! The functions return the Win32 error code on failure, which is
+//! [`ERROR_NO_UNICODE_TRANSLATION`] if a character can't be converted and
+//! [`ERROR_INVALID_PARAMETER`] if the code page isn't supported.
+//!
+//! # License
+//!
+//! This software is released under the MIT or Apache-2.0 License, see LICENSE-MIT or LICENSE-APACHE.
+mod backend;
+mod codepage;
+#[cfg(windows)]
+mod convert;
+#[cfg(windows)]
+mod raw;
+#[cfg(all(windows, feature = "windy"))]
+pub mod safe;
+
+#[cfg(windows)]
+pub use crate::backend::Win32Backend;
+pub use crate::backend::{
+    Backend, CpInfo, TableBackend, backend, default_backend,
+};
+
+/// The result of the conversion, whose error is the Win32 error code.
+pub type OsResult<T> = Result<T, u32>;
+
+/// The parameter is incorrect, e.g. the code page isn't supported.
+pub const ERROR_INVALID_PARAMETER: u32 = 0x57;
+/// No mapping for the Unicode character exists in the target code page.
+pub const ERROR_NO_UNICODE_TRANSLATION: u32 = 0x459;
+
+/// The options of [`encode`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EncodeOptions {
+    /// Replaces the characters which can't be converted instead of failing.
+    pub lossy: bool,
+    /// Uses the best fit mapping of Windows, e.g. `Ａ` to `A`.
+    pub best_fit: bool,
+    /// The character used by `lossy`, which is `?` if `None`.
+    pub replace: Option<u8>,
+}
+
+/// Encodes `x` to UTF-16.
+///
+/// UTF-8 to UTF-16 is loss less, so it doesn't need any backend.
+pub fn encode_wide(x: &str) -> Vec<u16> { x.encode_utf16().collect() }
+
+/// Encodes `x` to the code page with the [`default_backend`].
+pub fn encode(
+    code_page: u32,
+    x: &str,
+    options: &EncodeOptions,
+) -> OsResult<Vec<u8>> {
+    default_backend().encode(code_page, &encode_wide(x), options)
+}
+
+/// Converts `x` in the code page to UTF-16, failing on invalid bytes.
+pub fn mb_to_wide(code_page: u32, x: &[u8]) -> OsResult<Vec<u16>> {
+    default_backend().decode(code_page, x, false)
+}
+
+/// Converts `x` in the code page to UTF-16, replacing invalid bytes.
+pub fn mb_to_wide_lossy(code_page: u32, x: &[u8]) -> OsResult<Vec<u16>> {
+    default_backend().decode(code_page, x, true)
+}
+
+/// Converts `x` to the code page, failing on characters which can't be
+/// converted.
+pub fn wide_to_mb(
+    code_page: u32,
+    x: &[u16],
+    best_fit: bool,
+) -> OsResult<Vec<u8>> {
+    let options = EncodeOptions {
+        best_fit,
+        ..Default::default()
+    };
+    default_backend().encode(code_page, x, &options)
+}
+
+/// Converts `x` to the code page, replacing characters which can't be
+/// converted with `default_char` or `?`.
+pub fn wide_to_mb_lossy(
+    code_page: u32,
+    x: &[u16],
+    default_char: Option<u8>,
+    best_fit: bool,
+) -> OsResult<Vec<u8>> {
+    let options = EncodeOptions {
+        lossy: true,
+        best_fit,
+        replace: default_char,
+    };
+    default_backend().encode(code_page, x, &options)
+}
+
+/// Returns the ANSI code page which Windows uses for the locale of the host.
+///
+/// On a non-Windows host, it is guessed from `LC_ALL`, `LC_CTYPE` and `LANG`.
+pub fn get_system_default_acp() -> OsResult<u32> {
+    default_backend().system_default_acp()
+}
+
+/// Returns the OEM code page which Windows uses for the locale of the host.
+///
+/// On a non-Windows host, it is guessed from `LC_ALL`, `LC_CTYPE` and `LANG`.
+pub fn get_system_default_oemcp() -> OsResult<u32> {
+    default_backend().system_default_oemcp()
+}
+
+/// Returns the OEM code page of the locales whose ANSI code page is `acp`.
+///
+/// Code page 1252 is used by both 437 (United States) and 850 (Western
+/// Europe), and 437 is returned.
+pub fn acp_to_oemcp(acp: u32) -> Option<u32> { codepage::acp_to_oemcp(acp) }
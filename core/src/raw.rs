@@ -37,6 +37,7 @@ pub(crate) const WC_NO_BEST_FIT_CHARS: DWORD = 0x400;
 pub(crate) const ERROR_INSUFFICIENT_BUFFER: DWORD = 0x7a;
 pub(crate) const ERROR_NO_UNICODE_TRANSLATION: DWORD = 0x459;
 
+pub(crate) const LOCALE_IDEFAULTCODEPAGE: u32 = 0x0000000B;
 pub(crate) const LOCALE_IDEFAULTANSICODEPAGE: u32 = 0x00001004;
 pub(crate) const LOCALE_RETURN_NUMBER: u32 = 0x20000000;
 
@@ -46,13 +47,13 @@ pub(crate) const MAX_PATH: usize = 260;
 
 #[repr(C)]
 #[derive(Debug)]
-struct _cpinfoexw {
-    MaxCharSize: u32,
-    DefaultChar: [u8; MAX_DEFAULTCHAR],
-    LeadByte: [u8; MAX_LEADBYTES],
-    UnicodeDefaultChar: u16,
-    CodePage: u32,
-    CodePageName: [u16; MAX_PATH],
+pub(crate) struct _cpinfoexw {
+    pub(crate) MaxCharSize: u32,
+    pub(crate) DefaultChar: [u8; MAX_DEFAULTCHAR],
+    pub(crate) LeadByte: [u8; MAX_LEADBYTES],
+    pub(crate) UnicodeDefaultChar: u16,
+    pub(crate) CodePage: u32,
+    pub(crate) CodePageName: [u16; MAX_PATH],
 }
 
 unsafe extern "system" {
@@ -83,5 +84,11 @@ unsafe extern "system" {
         cchData: i32,
     ) -> i32;
 
+    pub(crate) fn GetCPInfoExW(
+        CodePage: u32,
+        dwFlags: u32,
+        lpCPInfoEx: *mut _cpinfoexw,
+    ) -> i32;
+
     pub(crate) fn GetLastError() -> u32;
 }
@@ -0,0 +1,37 @@
+//! Safe constructors of the windy strings, which the macros of windy-macros
+//! call with `safe` so that the caller's crate has no `unsafe` code.
+//!
+//! They are `const fn`, so the strings can be made in `const` and `static`
+//! items.
+//!
+//! They rely on the layout of the windy strings, so the `windy` feature
+//! requires exactly windy 0.3.1.
+use windy::{AStr, WStr};
+
+/// Returns `x` as `&WStr`.
+///
+/// # Panics
+///
+/// Panics if `x` isn't null-terminated.
+pub const fn wstr(x: &[u16]) -> &WStr {
+    assert!(
+        matches!(x.last(), Some(0)),
+        "the string isn't null-terminated"
+    );
+    // `WStr` is a null-terminated `[u16]` with the same layout.
+    unsafe { &*(x as *const [u16] as *const WStr) }
+}
+
+/// Returns `x` as `&AStr`.
+///
+/// # Panics
+///
+/// Panics if `x` isn't null-terminated.
+pub const fn astr(x: &[u8]) -> &AStr {
+    assert!(
+        matches!(x.last(), Some(0)),
+        "the string isn't null-terminated"
+    );
+    // `AStr` is a null-terminated `[u8]` with the same layout.
+    unsafe { &*(x as *const [u8] as *const AStr) }
+}
@@ -0,0 +1,155 @@
+// Copyright takubokudori.
+// This source code is licensed under the MIT or Apache-2.0 license.
+//! Tests that give the same results on any host.
+
+#[cfg(test)]
+mod tests {
+    use windy_macros_core::*;
+
+    #[test]
+    fn test_encode_wide() {
+        assert_eq!(vec![0x74, 0x30C6, 0xD83C, 0xDF63], encode_wide("tテ🍣"));
+        assert!(encode_wide("").is_empty());
+    }
+
+    #[test]
+    fn test_encode() {
+        let o = EncodeOptions::default();
+        assert_eq!(Ok(b"test".to_vec()), encode(1252, "test", &o));
+        assert_eq!(Ok(Vec::new()), encode(1252, "", &o));
+        assert_eq!(Ok(vec![0x83, 0x65]), encode(932, "テ", &o));
+        assert_eq!(Ok(vec![0x9A]), encode(737, "γ", &o));
+        assert_eq!(Ok(vec![0x94, 0x39, 0xB9, 0x37]), encode(54936, "🍣", &o));
+        assert_eq!(Err(ERROR_NO_UNICODE_TRANSLATION), encode(1252, "テ", &o));
+        assert_eq!(Err(ERROR_NO_UNICODE_TRANSLATION), encode(1252, "Ａ", &o));
+        assert_eq!(Err(ERROR_INVALID_PARAMETER), encode(12345, "test", &o));
+    }
+
+    #[test]
+    fn test_encode_options() {
+        let lossy = EncodeOptions {
+            lossy: true,
+            ..Default::default()
+        };
+        assert_eq!(Ok(b"a?".to_vec()), encode(1252, "aテ", &lossy));
+        let replace = EncodeOptions {
+            replace: Some(b'_'),
+            ..lossy.clone()
+        };
+        assert_eq!(Ok(b"a_".to_vec()), encode(1252, "aテ", &replace));
+        let best_fit = EncodeOptions {
+            best_fit: true,
+            ..Default::default()
+        };
+        assert_eq!(Ok(b"A".to_vec()), encode(1252, "Ａ", &best_fit));
+    }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(Ok(encode_wide("テ")), mb_to_wide(932, &[0x83, 0x65]));
+        assert_eq!(Err(ERROR_NO_UNICODE_TRANSLATION), mb_to_wide(932, &[0x83]));
+        assert_eq!(Ok(encode_wide("テ")), mb_to_wide_lossy(932, &[0x83, 0x65]));
+        // The bytes which Windows maps to the C1 controls and the private use
+        // area.
+        let table = backend("table").unwrap();
+        let decode = |cp, x: &[u8]| table.decode(cp, x, false);
+        assert_eq!(Ok(vec![0xE000]), decode(949, &[0xC9, 0xA1]));
+        assert_eq!(Ok(vec![0xE0BB]), decode(949, &[0xFE, 0xFE]));
+        assert_eq!(Ok(vec![0x80]), decode(949, &[0x80]));
+        assert_eq!(Ok(vec![0x80]), decode(950, &[0x80]));
+        assert_eq!(Ok(vec![0xF8F5]), decode(936, &[0xFF]));
+        assert_eq!(Ok(vec![0xF8F8]), decode(950, &[0xFF]));
+        assert_eq!(Ok(vec![0xF8C1, 0xF8C4]), decode(874, &[0xDB, 0xDE]));
+    }
+
+    #[test]
+    fn test_acp_to_oemcp() {
+        assert_eq!(Some(437), acp_to_oemcp(1252));
+        assert_eq!(Some(866), acp_to_oemcp(1251));
+        assert_eq!(Some(932), acp_to_oemcp(932));
+        assert_eq!(None, acp_to_oemcp(65001));
+    }
+
+    #[test]
+    fn test_backend() {
+        let table = backend("table").unwrap();
+        assert_eq!("table", table.name());
+        assert!(backend("unknown").is_none());
+        assert_eq!(cfg!(windows), backend("win32").is_some());
+        assert_eq!(Ok(1), table.cp_info(1252).map(|x| x.max_char_size));
+        assert_eq!(Ok(2), table.cp_info(932).map(|x| x.max_char_size));
+        assert_eq!(Ok(4), table.cp_info(54936).map(|x| x.max_char_size));
+        assert_eq!(Err(ERROR_INVALID_PARAMETER), table.cp_info(12345));
+        let info = table.cp_info(932).unwrap();
+        assert_eq!(932, info.code_page);
+        assert_eq!("ANSI/OEM - Japanese Shift-JIS", info.name);
+    }
+
+    // The tables give the same results as Windows, except for the best fit
+    // mappings, which are partly approximated, and the character for invalid
+    // bytes in lossy decoding, which is U+FFFD instead of the default
+    // character of the code page. Those aren't compared.
+    #[cfg(windows)]
+    #[test]
+    fn test_table_backend() {
+        let cps = [
+            874, 932, 936, 949, 950, 1250, 1251, 1252, 1253, 1254, 1255, 1256,
+            1257, 1258, 437, 720, 737, 775, 850, 852, 855, 857, 862, 866,
+            54936,
+        ];
+        let w = (1..=0xFFFF).filter(|x| !(0xD800..0xE000).contains(x));
+        let w = w.map(|x| x as u16).collect::<Vec<_>>();
+        // Strings of several characters, including surrogate pairs, and an
+        // empty string.
+        let s = ["", "aé€テ🍣ＡĀ", "\u{10000}\u{2F800}x", "\u{FFFD}\u{E000}"];
+        let options = [false, true].map(|lossy| EncodeOptions {
+            lossy,
+            ..Default::default()
+        });
+        for cp in cps {
+            let size = |x: CpInfo| x.max_char_size;
+            assert_eq!(
+                Win32Backend.cp_info(cp).map(size),
+                TableBackend.cp_info(cp).map(size),
+                "{}",
+                cp
+            );
+            for o in &options {
+                for c in w.chunks(1).chain(w.chunks(64)) {
+                    assert_eq!(
+                        Win32Backend.encode(cp, c, o),
+                        TableBackend.encode(cp, c, o),
+                        "U+{:04X} in {} with {:?}",
+                        c[0],
+                        cp,
+                        o
+                    );
+                }
+                for x in s {
+                    let x = encode_wide(x);
+                    assert_eq!(
+                        Win32Backend.encode(cp, &x, o),
+                        TableBackend.encode(cp, &x, o),
+                        "{:?} in {} with {:?}",
+                        String::from_utf16_lossy(&x),
+                        cp,
+                        o
+                    );
+                }
+            }
+            // All single bytes and all pairs of bytes.
+            let singles = (0..=0xFFu8).map(|x| vec![x]);
+            let pairs = (0x80..=0xFFu8)
+                .flat_map(|x| (0x00..=0xFFu8).map(move |y| vec![x, y]));
+            for x in singles.chain(pairs) {
+                assert_eq!(
+                    Win32Backend.decode(cp, &x, false),
+                    TableBackend.decode(cp, &x, false),
+                    "{:02X?} in {}",
+                    x,
+                    cp
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use crate::expand::expand;
+use std::path::PathBuf;
+use syn::{
+    Expr, Ident, Lit, LitChar, Path, Token,
+    parse::{Parse, ParseStream},
+};
+
+/// Arguments of a macro, which are options followed by literals.
+///
+/// ```text
+/// [static | option [= value],]* literal [, literal]*
+/// ```
+#[derive(Clone)]
+pub(crate) struct Args {
+    /// Puts the data in a static, `static`, which only the str macros take.
+    pub(crate) is_static: bool,
+    /// The code page, `cp = <u32>`.
+    pub(crate) cp: Option<u32>,
+    /// The replacement character of the lossy macros, `replace = <char>`.
+    pub(crate) replace: Option<LitChar>,
+    /// The best fit mode, `best_fit`.
+    pub(crate) best_fit: Option<Ident>,
+    /// Allows interior NULs, `allow_nul`.
+    pub(crate) allow_nul: bool,
+    /// Appends the terminating NUL, `nul = <bool>`, which only the array
+    /// macros take.
+    pub(crate) nul: bool,
+    /// The length which the array is padded to with zeros, `len = <usize>`,
+    /// which only the array macros take.
+    pub(crate) len: Option<usize>,
+    /// Constructs the strings without `unsafe` code, `safe`, which only the
+    /// string and str macros take.
+    pub(crate) safe: bool,
+    /// The path to the windy crate, `crate = <path>`.
+    pub(crate) windy: Option<Path>,
+    /// Converts to the OEM code page instead of the ANSI one, which the OEM
+    /// macros set.
+    pub(crate) oem: bool,
+    /// The literals, which are joined into one string.
+    pub(crate) lits: Vec<Lit>,
+    /// The files included by `include_str!`.
+    pub(crate) includes: Vec<PathBuf>,
+}
+
+/// The kind of a macro, which determines the options it takes.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    String,
+    Str,
+    Array,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Self::parse_with(input, Kind::String)
+    }
+}
+
+impl Args {
+    /// Parses the arguments of a str macro.
+    pub(crate) fn parse_str(input: ParseStream) -> syn::Result<Self> {
+        Self::parse_with(input, Kind::Str)
+    }
+
+    /// Parses the arguments of an array macro.
+    pub(crate) fn parse_array(input: ParseStream) -> syn::Result<Self> {
+        Self::parse_with(input, Kind::Array)
+    }
+
+    /// Returns the first literal, where errors are reported.
+    pub(crate) fn lit(&self) -> &Lit { &self.lits[0] }
+
+    fn parse_with(input: ParseStream, kind: Kind) -> syn::Result<Self> {
+        let mut is_static = false;
+        let mut cp = None;
+        let mut replace = None;
+        let mut best_fit = None;
+        let mut allow_nul = false;
+        let mut safe = false;
+        let mut nul = None;
+        let mut len = None;
+        let mut windy = None;
+        let mut includes = Vec::new();
+        while input.peek(Token![static])
+            || (input.peek(Ident) || input.peek(Token![crate]))
+                && (input.peek2(Token![=]) || input.peek2(Token![,]))
+        {
+            // `static` isn't followed by a comma.
+            if let Some(key) = input.parse::<Option<Token![static]>>()? {
+                if kind != Kind::Str {
+                    return Err(syn::Error::new(
+                        key.span,
+                        "`static` can only be used with the str macros",
+                    ));
+                }
+                if is_static {
+                    return Err(syn::Error::new(
+                        key.span,
+                        "duplicate option `static`",
+                    ));
+                }
+                is_static = true;
+                continue;
+            }
+            if let Some(key) = input.parse::<Option<Token![crate]>>()? {
+                if kind == Kind::Array {
+                    return Err(syn::Error::new(
+                        key.span,
+                        "`crate` can't be used with the array macros",
+                    ));
+                }
+                if windy.is_some() {
+                    return Err(syn::Error::new(
+                        key.span,
+                        "duplicate option `crate`",
+                    ));
+                }
+                input.parse::<Token![=]>()?;
+                windy = Some(input.parse()?);
+                input.parse::<Token![,]>()?;
+                continue;
+            }
+            let key: Ident = input.parse()?;
+            match key.to_string().as_str() {
+                "cp" if cp.is_none() => {
+                    input.parse::<Token![=]>()?;
+                    cp = match parse_literal(input, &mut includes)? {
+                        Lit::Int(x) => Some(x.base10_parse()?),
+                        x => return Err(expected(&x, "an integer")),
+                    };
+                }
+                "replace" if replace.is_none() => {
+                    input.parse::<Token![=]>()?;
+                    replace = match parse_literal(input, &mut includes)? {
+                        Lit::Char(x) => Some(x),
+                        x => return Err(expected(&x, "a character")),
+                    };
+                }
+                "best_fit" if best_fit.is_none() => best_fit = Some(key),
+                "allow_nul" if !allow_nul => allow_nul = true,
+                "safe" if kind == Kind::Array => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        "`safe` can't be used with the array macros",
+                    ));
+                }
+                "safe" if !safe => safe = true,
+                "nul" | "len" if kind != Kind::Array => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!(
+                            "`{}` can only be used with the array macros",
+                            key
+                        ),
+                    ));
+                }
+                "nul" if nul.is_none() => {
+                    input.parse::<Token![=]>()?;
+                    nul = match parse_literal(input, &mut includes)? {
+                        Lit::Bool(x) => Some(x.value),
+                        x => return Err(expected(&x, "a bool")),
+                    };
+                }
+                "len" if len.is_none() => {
+                    input.parse::<Token![=]>()?;
+                    len = match parse_literal(input, &mut includes)? {
+                        Lit::Int(x) => Some(x.base10_parse()?),
+                        x => return Err(expected(&x, "an integer")),
+                    };
+                }
+                "cp" | "replace" | "best_fit" | "allow_nul" | "safe"
+                | "nul" | "len" => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!("duplicate option `{}`", key),
+                    ));
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!("unknown option `{}`", key),
+                    ));
+                }
+            }
+            input.parse::<Token![,]>()?;
+        }
+        let mut lits = vec![parse_literal(input, &mut includes)?];
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            lits.push(parse_literal(input, &mut includes)?);
+        }
+        Ok(Self {
+            is_static,
+            cp,
+            replace,
+            best_fit,
+            allow_nul,
+            nul: nul.unwrap_or(true),
+            len,
+            safe,
+            windy,
+            oem: false,
+            lits,
+            includes,
+        })
+    }
+}
+
+/// Parses a literal, or a built-in macro which expands to a string literal.
+///
+/// A literal passed through a metavariable of `macro_rules!`, e.g. `$x:expr`,
+/// is wrapped in invisible groups, so they are unwrapped.
+pub(crate) fn parse_literal(
+    input: ParseStream,
+    includes: &mut Vec<PathBuf>,
+) -> syn::Result<Lit> {
+    let mut expr: Expr = input.parse()?;
+    loop {
+        match expr {
+            Expr::Group(x) => expr = *x.expr,
+            Expr::Lit(x) => return Ok(x.lit),
+            Expr::Macro(x) => return expand(&x.mac, includes),
+            x => {
+                return Err(syn::Error::new_spanned(x, "expected a literal"));
+            }
+        }
+    }
+}
+
+fn expected(x: &Lit, what: &str) -> syn::Error {
+    syn::Error::new(x.span(), format!("expected {}", what))
+}
@@ -0,0 +1,201 @@
+//! Project-wide configuration.
+//!
+//! The configuration is read from the environment variables and the
+//! `[package.metadata.windy-macros]` or `[workspace.metadata.windy-macros]`
+//! table of the manifest of the crate being compiled.
+use std::{
+    cell::RefCell,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+/// The environment variable of the default ANSI code page.
+pub(crate) const ACP_ENV: &str = "WINDY_MACROS_ACP";
+
+/// The environment variable of the default OEM code page.
+pub(crate) const OEMCP_ENV: &str = "WINDY_MACROS_OEMCP";
+
+/// The environment variable of the backend.
+pub(crate) const BACKEND_ENV: &str = "WINDY_MACROS_BACKEND";
+
+thread_local! {
+    /// The environment variables read during the current expansion.
+    static TRACKED_VARS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    /// The manifests read during the current expansion.
+    static TRACKED_FILES: RefCell<Vec<PathBuf>> = const { RefCell::new(Vec::new()) };
+}
+
+const PACKAGE_TABLE: &str = "package.metadata.windy-macros";
+const WORKSPACE_TABLE: &str = "workspace.metadata.windy-macros";
+
+/// Returns the configured default ANSI code page, which is a number or
+/// `system`.
+///
+/// [`ACP_ENV`] takes precedence over `acp` of the package and then of the
+/// workspace.
+pub(crate) fn default_acp() -> Option<String> {
+    if let Some(x) = var(ACP_ENV).filter(|x| !x.is_empty()) {
+        return Some(x);
+    }
+    metadata("acp")
+}
+
+/// Returns the configured default OEM code page, which is a number or
+/// `system`.
+///
+/// [`OEMCP_ENV`] takes precedence over `oemcp` of the package and then of the
+/// workspace.
+pub(crate) fn default_oemcp() -> Option<String> {
+    if let Some(x) = var(OEMCP_ENV).filter(|x| !x.is_empty()) {
+        return Some(x);
+    }
+    metadata("oemcp")
+}
+
+/// Returns the configured backend, which is `table` or `win32`.
+///
+/// [`BACKEND_ENV`] takes precedence over `backend` of the package and then of
+/// the workspace.
+pub(crate) fn backend() -> Option<String> {
+    if let Some(x) = var(BACKEND_ENV).filter(|x| !x.is_empty()) {
+        return Some(x);
+    }
+    metadata("backend")
+}
+
+/// Returns the cfg predicate of the Unicode build for the T macros, which is
+/// `unicode` of the package or the workspace, or `windy_unicode`.
+pub(crate) fn unicode_cfg() -> String {
+    metadata("unicode").unwrap_or_else(|| "windy_unicode".to_string())
+}
+
+/// Returns whether the string and str macros construct the strings without
+/// `unsafe` code as with `safe`, which is `safe` of the package or the
+/// workspace.
+pub(crate) fn safe() -> bool { metadata("safe").is_some_and(|x| x == "true") }
+
+/// Returns the name of the windy crate in the crate being compiled, which is
+/// not `windy` if the dependency is renamed.
+pub(crate) fn windy_crate() -> String { crate_name("windy") }
+
+/// Returns the name of the windy-macros-core crate in the crate being
+/// compiled, whose constructors the macros call with `safe`.
+pub(crate) fn core_crate() -> String { crate_name("windy-macros-core") }
+
+/// Returns the name of the dependency on `package` in the crate being
+/// compiled, which is not `package` if the dependency is renamed.
+fn crate_name(package: &str) -> String {
+    let dir = env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from);
+    let name =
+        dir.and_then(|x| renamed_dependency(&x.join("Cargo.toml"), package));
+    name.unwrap_or_else(|| package.to_string())
+        .replace('-', "_")
+}
+
+/// Returns the environment variable `key`.
+///
+/// cargo doesn't know that a proc macro reads it, so it is recorded and
+/// emitted as `option_env!` by the expansion to rebuild the caller when it is
+/// changed.
+pub(crate) fn var(key: &str) -> Option<String> {
+    TRACKED_VARS.with_borrow_mut(|x| {
+        if !x.iter().any(|x| x == key) {
+            x.push(key.to_string());
+        }
+    });
+    env::var(key).ok()
+}
+
+/// Returns the environment variables read by [`var`] since the last call.
+pub(crate) fn take_tracked_vars() -> Vec<String> { TRACKED_VARS.take() }
+
+/// Returns the manifests read by [`entries`] since the last call.
+pub(crate) fn take_tracked_files() -> Vec<PathBuf> { TRACKED_FILES.take() }
+
+/// Returns the value of `key` in the metadata of the package or the
+/// workspace.
+fn metadata(key: &str) -> Option<String> {
+    let dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR")?);
+    let manifest = dir.join("Cargo.toml");
+    if let Some(x) = manifest_value(&manifest, PACKAGE_TABLE, key) {
+        return Some(x);
+    }
+    dir.ancestors().find_map(|x| {
+        manifest_value(&x.join("Cargo.toml"), WORKSPACE_TABLE, key)
+    })
+}
+
+/// Returns the value of `key` in `table` of the manifest at `path`.
+fn manifest_value(path: &Path, table: &str, key: &str) -> Option<String> {
+    entries(path)?
+        .into_iter()
+        .find(|(t, k, _)| t == table && k == key)
+        .map(|(_, _, v)| unquote(&v).to_string())
+}
+
+/// Removes the quotes of a basic or literal string.
+fn unquote(x: &str) -> &str {
+    ['"', '\'']
+        .into_iter()
+        .find_map(|q| x.strip_prefix(q)?.strip_suffix(q))
+        .unwrap_or(x)
+}
+
+/// Returns the name of the dependency on `package` in the manifest at `path`
+/// if it is renamed with `package = "<package>"`.
+///
+/// Both `name = { package = "..." }` and `[dependencies.name]` are supported.
+fn renamed_dependency(path: &Path, package: &str) -> Option<String> {
+    let is_dependencies = |x: &str| {
+        x.ends_with("dependencies") && !x.ends_with("build-dependencies")
+    };
+    entries(path)?.into_iter().find_map(|(table, key, value)| {
+        if is_dependencies(&table) {
+            let value = value.strip_prefix('{')?.strip_suffix('}')?;
+            let renamed = value.split(',').any(|x| {
+                x.split_once('=').is_some_and(|(k, v)| {
+                    k.trim() == "package"
+                        && v.trim().trim_matches('"') == package
+                })
+            });
+            return renamed.then_some(key);
+        }
+        let (prefix, name) = table.rsplit_once('.')?;
+        let renamed = is_dependencies(prefix)
+            && key == "package"
+            && value.trim_matches('"') == package;
+        renamed.then(|| name.to_string())
+    })
+}
+
+/// Returns the `(table, key, value)` entries of the manifest at `path`.
+///
+/// Only `key = value` lines under a `[table]` header are supported, which is
+/// enough for the metadata and the dependencies.
+fn entries(path: &Path) -> Option<Vec<(String, String, String)>> {
+    let s = fs::read_to_string(path).ok()?;
+    // cargo doesn't know that a proc macro reads it either, so it is recorded
+    // and emitted as `include_bytes!` like the environment variables.
+    TRACKED_FILES.with_borrow_mut(|x| {
+        if !x.iter().any(|x| x == path) {
+            x.push(path.to_path_buf());
+        }
+    });
+    let mut current = String::new();
+    let mut ret = Vec::new();
+    for line in s.lines() {
+        let line = line.trim();
+        if let Some(x) = line.strip_prefix('[') {
+            let x = x.split(']').next().unwrap_or_default();
+            current = x.replace(['"', ' '], "");
+            continue;
+        }
+        let Some((k, v)) = line.split_once('=') else {
+            continue;
+        };
+        let k = k.trim().trim_matches('"').to_string();
+        let v = v.split('#').next().unwrap_or_default().trim().to_string();
+        ret.push((current.clone(), k, v));
+    }
+    Some(ret)
+}
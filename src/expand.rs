@@ -0,0 +1,142 @@
+//! Eager expansion of the built-in macros which expand to a string literal.
+//!
+//! A proc macro receives the tokens of a macro call as they are, so
+//! `concat!`, `stringify!`, `env!`, `option_env!` and `include_str!` in the
+//! arguments are evaluated here like the compiler does.
+use crate::{args::parse_literal, config, lit_to_string};
+use std::{fs, path::PathBuf};
+use syn::{
+    Lit, LitStr, Macro, Token,
+    parse::{ParseStream, Parser},
+    spanned::Spanned,
+};
+
+/// Expands `mac` to a string literal.
+///
+/// The files included by `include_str!` are pushed to `includes`.
+pub(crate) fn expand(
+    mac: &Macro,
+    includes: &mut Vec<PathBuf>,
+) -> syn::Result<Lit> {
+    let span = mac.path.span();
+    let err = |msg: String| syn::Error::new(span, msg);
+    let name = builtin_name(mac).ok_or_else(|| {
+        err(format!(
+            "unsupported macro `{}!`; only concat!, stringify!, env!, \
+             option_env! and include_str! can be used",
+            path_to_string(mac)
+        ))
+    })?;
+    let s = match name {
+        "concat" => {
+            let args = parse_args(mac, includes)?;
+            let mut s = String::new();
+            for x in &args {
+                s.push_str(&lit_to_string(x)?);
+            }
+            s
+        }
+        "stringify" => mac.tokens.to_string(),
+        "env" | "option_env" => {
+            let args = parse_args(mac, includes)?;
+            let (key, msg) = match args.as_slice() {
+                [key] => (str_value(key)?, None),
+                [key, msg] if name == "env" => {
+                    (str_value(key)?, Some(str_value(msg)?))
+                }
+                _ => return Err(err(format!("{}! takes 1 argument", name))),
+            };
+            config::var(&key).ok_or_else(|| match msg {
+                Some(msg) => err(msg),
+                None if name == "env" => err(format!(
+                    "environment variable `{}` not defined at compile time",
+                    key
+                )),
+                // The macros can't return an `Option`.
+                None => err(format!(
+                    "environment variable `{}` not defined at compile time, \
+                     so option_env! can't be converted to a string",
+                    key
+                )),
+            })?
+        }
+        "include_str" => {
+            let args = parse_args(mac, includes)?;
+            let [path] = args.as_slice() else {
+                return Err(err("include_str! takes 1 argument".to_string()));
+            };
+            let path = resolve(&str_value(path)?, path)?;
+            let s = fs::read_to_string(&path).map_err(|x| {
+                err(format!("couldn't read `{}`: {}", path.display(), x))
+            })?;
+            // `include_bytes!` in the output needs an absolute path.
+            includes.push(fs::canonicalize(&path).unwrap_or(path));
+            s
+        }
+        _ => unreachable!(),
+    };
+    Ok(Lit::Str(LitStr::new(&s, span)))
+}
+
+/// Returns the name of `mac` if it is a supported built-in macro.
+///
+/// `std::` and `core::` may be prefixed.
+fn builtin_name(mac: &Macro) -> Option<&'static str> {
+    let mut segments = mac.path.segments.iter().rev();
+    let name = segments.next()?;
+    match segments.next() {
+        None if mac.path.leading_colon.is_none() => {}
+        Some(x) if x.ident == "std" || x.ident == "core" => {}
+        _ => return None,
+    }
+    if segments.next().is_some() || !name.arguments.is_none() {
+        return None;
+    }
+    ["concat", "stringify", "env", "option_env", "include_str"]
+        .into_iter()
+        .find(|x| name.ident == x)
+}
+
+fn path_to_string(mac: &Macro) -> String {
+    let path = mac.path.segments.iter().map(|x| x.ident.to_string());
+    let path = path.collect::<Vec<_>>().join("::");
+    match mac.path.leading_colon {
+        Some(_) => format!("::{}", path),
+        None => path,
+    }
+}
+
+/// Parses the comma-separated literals in `mac`.
+fn parse_args(
+    mac: &Macro,
+    includes: &mut Vec<PathBuf>,
+) -> syn::Result<Vec<Lit>> {
+    let parser = |input: ParseStream| {
+        let mut ret = Vec::new();
+        while !input.is_empty() {
+            ret.push(parse_literal(input, includes)?);
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+        Ok(ret)
+    };
+    parser.parse2(mac.tokens.clone())
+}
+
+fn str_value(x: &Lit) -> syn::Result<String> {
+    match x {
+        Lit::Str(x) => Ok(x.value()),
+        _ => Err(syn::Error::new(x.span(), "expected a string literal")),
+    }
+}
+
+/// Returns `path` relative to the directory of the file where `lit` is.
+fn resolve(path: &str, lit: &Lit) -> syn::Result<PathBuf> {
+    let file = lit.span().unwrap().local_file().ok_or_else(|| {
+        syn::Error::new(lit.span(), "couldn't get the path of the source file")
+    })?;
+    let dir = file.parent().unwrap_or(&file);
+    Ok(dir.join(path))
+}
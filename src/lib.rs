@@ -17,394 +17,1773 @@
 //! - `&AStr` using `astr!` or `astr_lossy!`.
 //! - `[u8]` using `aarr!` or `aarr_lossy!`.
 //! - `[u16]` using `warr!` or `warr_lossy!`.
+//! - `AString`, `&AStr` and `[u8]` in the OEM code page using `ostring!`, `ostr!`, `oarr!` and the lossy ones.
+//! - Either of them using `tstring!`, `tstr!`, `tarr!` and the lossy ones, like `TCHAR`.
 //!
 //! at compile time.
 //!
+//! The `WString`, `&WStr` and `[u16]` macros work on any host, so they can be used when cross-compiling
+//! from Linux. On a non-Windows host, the ANSI and OEM macros use built-in tables of the code pages 874, 932, 936,
+//! 949, 950, 1250-1258, 437, 720, 737, 775, 850, 852, 855, 857, 862, 866 and 54936 (GB18030), which give the same bytes
+//! as Windows except for `best_fit` (see below).
+//!
+//! The conversion is also available at run time in [windy-macros-core](https://crates.io/crates/windy-macros-core).
+//!
+//! The literal can be made by `concat!`, `stringify!`, `env!`, `option_env!` and `include_str!`, which are expanded
+//! before the conversion. `include_str!` is relative to the file where it is called like the original one.
+//!
+//! ```
+//! use windy_macros::warr;
+//!
+//! let x = warr!(concat!("MyApp v", env!("CARGO_PKG_VERSION")));
+//! ```
+//!
+//! Comma-separated literals are joined into one string.
+//!
+//! ```
+//! use windy_macros::warr;
+//!
+//! let x = warr!("Error ", 42, ": ", 'x');
+//! assert_eq!(warr!("Error 42: x"), x);
+//! ```
+//!
+//! Byte strings such as `b"\x82\xA0"` are decoded from the code page (see below), and C strings such as `c"あ"` are
+//! UTF-8. On a non-Windows host, the lossy macros decode invalid bytes to U+FFFD, while Windows uses the default
+//! character of the code page, e.g. U+30FB for 932.
+//!
+//! ```
+//! use windy_macros::warr;
+//!
+//! let x = warr!(cp = 932, b"\x82\xA0");
+//! assert_eq!(warr!("あ"), x);
+//! ```
+//!
+//! An interior NUL fails to compile because Win32 APIs stop reading the string there. `allow_nul` allows it.
+//!
+//! ```compile_fail
+//! let x = windy_macros::warr!("abc\0def");
+//! ```
+//!
+//! ```
+//! use windy_macros::warr;
+//!
+//! let x = warr!(allow_nul, "abc\0def");
+//! assert_eq!(8, x.len());
+//! ```
+//!
+//! `nul = false` makes the array macros omit the terminating NUL, e.g. for length-prefixed data.
+//!
+//! ```
+//! use windy_macros::aarr;
+//!
+//! let x = aarr!(nul = false, cp = 1252, "abc");
+//! assert_eq!(*b"abc", x);
+//! ```
+//!
+//! `len = <length>` pads the array with zeros to the length, e.g. for fixed-size fields of Win32 structs. The converted
+//! data including the NUL has to fit in it.
+//!
+//! ```
+//! use windy_macros::warr;
+//!
+//! let x: [u16; 32] = warr!(len = 32, "Segoe UI");
+//! assert_eq!(0, x[8]);
+//! ```
+//!
+//! ```compile_fail
+//! use windy_macros::warr;
+//!
+//! let x = warr!(len = 3, "abc");
+//! ```
+//!
+//! `static` makes the str macros put the data in a static array, so they can be used in `const` and `static` items.
+//!
+//! ```
+//! # #[cfg(windows)]
+//! # fn main() {
+//! use windy::WStr;
+//! use windy_macros::wstr;
+//!
+//! static TITLE: &WStr = wstr!(static "My App");
+//! const NAMES: [&WStr; 2] = [wstr!(static "foo"), wstr!(static "bar")];
+//! # }
+//! # #[cfg(not(windows))]
+//! # fn main() {}
+//! ```
+//!
+//! The string and str macros construct the strings from the checked data with `unsafe` code. `safe` makes them call the
+//! `const fn` constructors of windy-macros-core instead, so the expansions have no `unsafe` code and can be used in crates
+//! with `#![forbid(unsafe_code)]`. The str macros with `safe` can be used in `const` items without `static` as well. It
+//! needs windy-macros-core with the `windy` feature, and `safe = true` in `[package.metadata.windy-macros]` makes it the
+//! default. The feature pins windy to 0.3.1, whose string layout the constructors rely on. The array macros expand to
+//! plain arrays without any `unsafe`.
+//!
+//! ```toml
+//! [dependencies]
+//! windy-macros-core = { version = "0.3.0", features = ["windy"] }
+//! ```
+//!
+//! ```
+//! #![forbid(unsafe_code)]
+//! # #[cfg(windows)]
+//! # fn main() {
+//! use windy::WStr;
+//! use windy_macros::wstr;
+//!
+//! const TITLE: &WStr = wstr!(safe, "My App");
+//! # }
+//! # #[cfg(not(windows))]
+//! # fn main() {}
+//! ```
+//!
+//! The generated code refers to windy by the name of the dependency in Cargo.toml, so a renamed dependency works.
+//! `crate = <path>` specifies the path to windy instead, which lets a crate re-export the macros.
+//!
+//! ```
+//! # #[cfg(windows)]
+//! pub use windy;
+//!
+//! #[macro_export]
+//! macro_rules! my_wstr {
+//!     ($($t:tt)*) => { windy_macros::wstr!(crate = $crate::windy, $($t)*) };
+//! }
+//! # #[cfg(windows)]
+//! # fn main() {
+//! #     let _ = my_wstr!("test");
+//! # }
+//! # #[cfg(not(windows))]
+//! # fn main() {}
+//! ```
+//!
+//! # Code page
+//!
+//! The ANSI macros convert to the default ANSI code page configured for the crate, so the bytes don't depend on the
+//! machine. It is set by `acp` in Cargo.toml:
+//!
+//! ```toml
+//! [package.metadata.windy-macros]
+//! acp = 932
+//! ```
+//!
+//! `[workspace.metadata.windy-macros]` in the workspace's Cargo.toml works as well, and the `WINDY_MACROS_ACP` environment
+//! variable takes precedence over both. Changing either of them rebuilds the crate.
+//!
+//! `acp = "system"` uses the system default ANSI code page of the host. On a non-Windows host, it is the one Windows uses
+//! for the locale (`LC_ALL`, `LC_CTYPE` or `LANG`), e.g. 932 for `ja_JP.UTF-8`, or 1252 if it is unknown.
+//!
+//! On a Windows host, the macros convert with `WideCharToMultiByte` (the `win32` backend). `backend = "table"` or the
+//! `WINDY_MACROS_BACKEND` environment variable selects the built-in tables instead, which give the same bytes on any
+//! host, e.g. to check the output on Windows against a build on Linux. With the `table` backend, `acp = "system"`
+//! follows the locale variables even on Windows.
+//!
+//! If no code page is configured, the ANSI macros fail to compile. `cp = <code page>` converts to the given code page
+//! regardless of the configuration.
+//!
+//! ```
+//! use windy_macros::aarr;
+//!
+//! let b = aarr!(cp = 932, "テスト");
+//! assert_eq!([0x83, 0x65, 0x83, 0x58, 0x83, 0x67, 0x00], b);
+//! ```
+//!
+//! `acp_info!()` defines the number, name and maximum character size of the default ANSI code page as consts, e.g.
+//! to size buffers for it. A code page which the backend doesn't know is rejected at compile time.
+//!
+//! The OEM macros convert to the OEM code page, which the console uses. Its default is set by `oemcp` or
+//! `WINDY_MACROS_OEMCP` in the same way. Otherwise, it is the OEM code page for the ANSI code page, e.g. 866 for
+//! 1251, and 437 for 1252, which Western European locales other than English use 850 instead of. With
+//! `acp = "system"`, it is the system default OEM code page of the host.
+//!
+//! ```
+//! use windy_macros::oarr;
+//!
+//! let b = oarr!(cp = 437, "├─ OK");
+//! assert_eq!(b"\xC3\xC4 OK\0", &b);
+//! ```
+//!
+//! The lossy ANSI macros replace characters which can't be converted with `?`. `replace = <char>` specifies another
+//! one, which must be a single byte in the code page. NUL needs `allow_nul` because it makes an interior NUL.
+//!
+//! ```
+//! use windy_macros::aarr_lossy;
+//!
+//! let b = aarr_lossy!(cp = 1252, replace = '_', "file:名前");
+//! assert_eq!(b"file:__\0", &b);
+//! ```
+//!
+//! ```compile_fail
+//! use windy_macros::aarr_lossy;
+//!
+//! let b = aarr_lossy!(cp = 1252, replace = '\0', "aあb");
+//! ```
+//!
+//! The ANSI macros don't use the best fit mapping of Windows by default, so `Ａ` can't be converted to code page 1252.
+//! `best_fit` enables it. On a non-Windows host, the best fit mappings are approximated by the compatibility
+//! decomposition, e.g. `Ａ` to `A` and `ā` to `a`. Some mappings of Windows are missing, e.g. `∞` to `8` in code page 1252.
+//!
+//! ```
+//! use windy_macros::aarr;
+//!
+//! let b = aarr!(cp = 1252, best_fit, "ＡＢＣ");
+//! assert_eq!(b"ABC\0", &b);
+//! ```
+//!
+//! # Unicode and ANSI builds
+//!
+//! The T macros expand to the wide macros if the `windy_unicode` cfg is enabled, and to the ANSI macros otherwise, so
+//! one call site serves both builds. The options only for the ANSI macros are ignored in the Unicode build. The cfg is
+//! set by `unicode` in Cargo.toml, e.g. to follow a feature:
+//!
+//! ```toml
+//! [package.metadata.windy-macros]
+//! unicode = 'feature = "unicode"'
+//! ```
+//!
+//! The cfg is evaluated in the crate which calls the macros, so a custom one such as `windy_unicode` has to be declared
+//! for the `unexpected_cfgs` lint:
+//!
+//! ```toml
+//! [lints.rust]
+//! unexpected_cfgs = { level = "warn", check-cfg = ["cfg(windy_unicode)"] }
+//! ```
+//!
+//! ```
+//! use windy_macros::tarr;
+//!
+//! let b = tarr!(cp = 1252, "abc");
+//! assert_eq!(4, b.len());
+//! ```
+//!
 //! # License
 //!
 //! This software is released under the MIT or Apache-2.0 License, see LICENSE-MIT or LICENSE-APACHE.
-#[cfg(not(windows))]
-compile_error!("windy-macros is Windows-host-only.");
-use crate::convert::*;
-use std::str::FromStr;
+use crate::args::Args;
+use proc_macro2::Span;
+use quote::ToTokens;
+use std::{fmt::Debug, path::PathBuf, str::FromStr};
 use syn::{Lit, parse_macro_input};
-use windy::*;
-
-mod convert;
-mod raw;
+use windy_macros_core::*;
 
-#[allow(unused)]
-pub(crate) const WC_ERR_INVALID_CHARS: u32 = 0x80;
-#[allow(unused)]
-pub(crate) const WC_NO_BEST_FIT_CHARS: u32 = 0x400;
+mod args;
+mod config;
+mod expand;
 
 /// Returns [`String`].
-fn lit_to_string(ast: Lit) -> String {
-    match ast {
+fn lit_to_string(ast: &Lit) -> syn::Result<String> {
+    Ok(match ast {
         Lit::Str(x) => x.value(),
         Lit::Char(x) => x.value().to_string(),
         Lit::Int(x) => x.base10_digits().to_string(),
         Lit::Float(x) => x.base10_digits().to_string(),
         Lit::Bool(x) => x.value.to_string(),
-        _ => panic!("Unsupported literal"),
-    }
+        _ => return Err(syn::Error::new(ast.span(), "unsupported literal")),
+    })
 }
 
-/// Returns `[u8]`.
-macro_rules! lit_to_bs_lossy {
-    ($x:ident, $ast:ident) => {{
-        let s = lit_to_string($ast);
-        let s = $x::from_str_lossy(&s);
-        let bytes = s.to_bytes_with_nul();
-        format!("{:?}", bytes)
-    }};
+/// Returns the literals of `args` joined into a [`String`].
+fn args_to_string(args: &Args, lossy: bool) -> syn::Result<String> {
+    Ok(args_to_strings(args, lossy)?.concat())
 }
 
-/// Returns `[u8]`.
-macro_rules! lit_to_bs {
-    ($x:ident, $ast:ident) => {{
-        let s = lit_to_string($ast);
-        let s = $x::from_str(&s).unwrap_or_else(|x| {
-            panic!(
-                concat!(
-                    "{} couldn't be converted to ",
-                    stringify!($x),
-                    ": {:X?}"
+/// Returns the strings of the literals of `args`.
+///
+/// Byte strings are decoded from the code page and C strings are UTF-8.
+///
+/// Interior NULs are rejected unless `allow_nul` is specified, because Win32
+/// APIs stop reading there.
+fn args_to_strings(args: &Args, lossy: bool) -> syn::Result<Vec<String>> {
+    let mut ret = Vec::<String>::new();
+    for lit in &args.lits {
+        let s = match lit {
+            Lit::ByteStr(x) => decode(args, lit, &x.value(), lossy)?,
+            Lit::Byte(x) => decode(args, lit, &[x.value()], lossy)?,
+            Lit::CStr(x) => x.value().into_string().map_err(|_| {
+                syn::Error::new(x.span(), "C string literal isn't UTF-8")
+            })?,
+            _ => lit_to_string(lit)?,
+        };
+        if let Some(i) = s.find('\0').filter(|_| !args.allow_nul) {
+            return Err(syn::Error::new(
+                lit.span(),
+                format!(
+                    "interior NUL at index {}; pass `allow_nul` to allow it",
+                    ret.iter().map(|x| x.chars().count()).sum::<usize>()
+                        + s[..i].chars().count()
                 ),
-                s, x
-            )
+            ));
+        }
+        ret.push(s);
+    }
+    Ok(ret)
+}
+
+/// Decodes `bytes` of `ast` from the code page.
+fn decode(
+    args: &Args,
+    ast: &Lit,
+    bytes: &[u8],
+    lossy: bool,
+) -> syn::Result<String> {
+    let cp = code_page(args, ast)?;
+    let backend = backend(ast.span())?;
+    let w = backend.decode(cp, bytes, lossy).map_err(|x| {
+        let msg = match x {
+            ERROR_NO_UNICODE_TRANSLATION => {
+                format!("the bytes are invalid in {}", cp_display(backend, cp))
+            }
+            ERROR_INVALID_PARAMETER => {
+                format!("code page {} is not supported", cp)
+            }
+            _ => format!(
+                "failed to convert from {}: error {:#X}",
+                cp_display(backend, cp),
+                x
+            ),
+        };
+        syn::Error::new(ast.span(), msg)
+    })?;
+    Ok(String::from_utf16_lossy(&w))
+}
+
+/// Fails if `replace` or `best_fit` is passed to a macro which doesn't take it.
+fn check_options(args: &Args, ansi: bool, lossy: bool) -> syn::Result<()> {
+    if let Some(x) = args.replace.as_ref().filter(|_| !(ansi && lossy)) {
+        return Err(syn::Error::new(
+            x.span(),
+            "`replace` can only be used with the lossy ANSI and OEM macros",
+        ));
+    }
+    if let Some(x) = args.best_fit.as_ref().filter(|_| !ansi) {
+        return Err(syn::Error::new(
+            x.span(),
+            "`best_fit` can only be used with the ANSI and OEM macros",
+        ));
+    }
+    Ok(())
+}
+
+/// Returns `[u16]`.
+///
+/// UTF-8 to UTF-16 is loss less, so strings don't need any Win32 API.
+fn lit_to_wide(args: &Args, lossy: bool) -> syn::Result<Vec<u16>> {
+    check_options(args, false, lossy)?;
+    let s = args_to_string(args, lossy)?;
+    let mut v = s.encode_utf16().collect::<Vec<_>>();
+    if args.nul {
+        v.push(0);
+    }
+    pad(args, &mut v)?;
+    Ok(v)
+}
+
+/// Returns the code page of the ANSI or OEM macros.
+///
+/// Code pages which the backend doesn't know are rejected before converting.
+fn code_page(args: &Args, ast: &Lit) -> syn::Result<u32> {
+    let cp = match args.oem {
+        false => ansi_code_page(args.cp, ast.span())?,
+        true => oem_code_page(args.cp, ast)?,
+    };
+    cp_info(ast.span(), cp)?;
+    Ok(cp)
+}
+
+/// Returns the information of `cp`, failing if it isn't supported.
+fn cp_info(span: Span, cp: u32) -> syn::Result<CpInfo> {
+    backend(span)?.cp_info(cp).map_err(|x| {
+        let msg = match x {
+            ERROR_INVALID_PARAMETER => {
+                format!("code page {} is not supported", cp)
+            }
+            _ => format!(
+                "failed to get the information of code page {}: error {:#X}",
+                cp, x
+            ),
+        };
+        syn::Error::new(span, msg)
+    })
+}
+
+/// Returns `code page <cp>` followed by its name if it has one, e.g.
+/// `code page 1252 (ANSI - Latin I)`.
+fn cp_display(backend: &dyn Backend, cp: u32) -> String {
+    match backend.cp_info(cp) {
+        Ok(x) if !x.name.is_empty() => format!("code page {} ({})", cp, x.name),
+        _ => format!("code page {}", cp),
+    }
+}
+
+/// Returns `cp`, or the configured default ANSI code page if it is `None`.
+///
+/// The default code page must be configured so that the bytes don't depend on the host.
+fn ansi_code_page(cp: Option<u32>, span: Span) -> syn::Result<u32> {
+    if let Some(cp) = cp {
+        return Ok(cp);
+    }
+    let err = |msg: String| syn::Error::new(span, msg);
+    let acp = config::default_acp().ok_or_else(|| {
+        err(format!(
+            "no ANSI code page is configured; pass `cp = <code page>`, set {} \
+             or set `acp` in [package.metadata.windy-macros] of Cargo.toml",
+            config::ACP_ENV
+        ))
+    })?;
+    match acp.as_str() {
+        "system" => backend(span)?.system_default_acp().map_err(|x| {
+            err(format!(
+                "failed to get the system default ANSI code page: error {:#X}",
+                x
+            ))
+        }),
+        x => x.parse().map_err(|_| {
+            err(format!(
+                "the configured ANSI code page {:?} is not a number or \
+                 `system`",
+                x
+            ))
+        }),
+    }
+}
+
+/// Returns `cp`, or the configured default OEM code page if it is `None`.
+///
+/// If no OEM code page is configured, it is the one of the configured ANSI
+/// code page.
+fn oem_code_page(cp: Option<u32>, ast: &Lit) -> syn::Result<u32> {
+    if let Some(cp) = cp {
+        return Ok(cp);
+    }
+    let err = |msg: String| syn::Error::new(ast.span(), msg);
+    let Some(oemcp) = config::default_oemcp() else {
+        if config::default_acp().as_deref() == Some("system") {
+            return system_default_oemcp(ast);
+        }
+        let acp = ansi_code_page(None, ast.span())?;
+        return acp_to_oemcp(acp).ok_or_else(|| {
+            err(format!(
+                "the ANSI code page {} has no OEM code page; pass `cp = <code \
+                 page>`, set {} or set `oemcp` in \
+                 [package.metadata.windy-macros] of Cargo.toml",
+                acp,
+                config::OEMCP_ENV
+            ))
         });
-        let bytes = s.to_bytes_with_nul();
-        format!("{:?}", bytes)
-    }};
+    };
+    match oemcp.as_str() {
+        "system" => system_default_oemcp(ast),
+        x => x.parse().map_err(|_| {
+            err(format!(
+                "the configured OEM code page {:?} is not a number or `system`",
+                x
+            ))
+        }),
+    }
+}
+
+fn system_default_oemcp(ast: &Lit) -> syn::Result<u32> {
+    backend(ast.span())?.system_default_oemcp().map_err(|x| {
+        syn::Error::new(
+            ast.span(),
+            format!(
+                "failed to get the system default OEM code page: error {:#X}",
+                x
+            ),
+        )
+    })
+}
+
+/// Returns the configured backend, or the default one of the host.
+fn backend(span: Span) -> syn::Result<&'static dyn Backend> {
+    let Some(name) = config::backend() else {
+        return Ok(default_backend());
+    };
+    windy_macros_core::backend(&name).ok_or_else(|| {
+        syn::Error::new(
+            span,
+            format!(
+                "the configured backend {:?} is not `table` or `win32`, or \
+                 isn't available on the host",
+                name
+            ),
+        )
+    })
+}
+
+/// Returns an error which tells why the strings `strs` of `lits` couldn't be
+/// converted to `cp`.
+///
+/// A character which can't be converted is reported at its literal with the
+/// index in the literal.
+fn conversion_error(
+    backend: &dyn Backend,
+    lits: &[Lit],
+    strs: &[String],
+    cp: u32,
+    best_fit: bool,
+    code: u32,
+) -> syn::Error {
+    let options = EncodeOptions {
+        best_fit,
+        ..Default::default()
+    };
+    let err = |lit: &Lit, msg: String| syn::Error::new(lit.span(), msg);
+    let msg = match code {
+        // Finds the character by converting one by one.
+        ERROR_NO_UNICODE_TRANSLATION => {
+            let found = lits.iter().zip(strs).find_map(|(lit, s)| {
+                s.chars()
+                    .enumerate()
+                    .find(|(_, c)| {
+                        let mut buf = [0; 2];
+                        let w = c.encode_utf16(&mut buf);
+                        backend.encode(cp, w, &options).is_err()
+                    })
+                    .map(|(i, c)| (lit, i, c))
+            });
+            if let Some((lit, i, c)) = found {
+                return err(
+                    lit,
+                    format!(
+                        "{:?} (U+{:04X}) at index {} can't be converted to {}",
+                        c,
+                        c as u32,
+                        i,
+                        cp_display(backend, cp)
+                    ),
+                );
+            }
+            None
+        }
+        ERROR_INVALID_PARAMETER => {
+            Some(format!("code page {} is not supported", cp))
+        }
+        _ => None,
+    };
+    let msg = msg.unwrap_or_else(|| {
+        format!(
+            "failed to convert to {}: error {:#X}",
+            cp_display(backend, cp),
+            code
+        )
+    });
+    err(&lits[0], msg)
 }
 
-/// When compiling Rust code, the default code page ends up being changed to `CP_UTF8`, which causes mojibake when converting to ANSI.
-/// Therefore, we need to obtain the original code page from before the change and use it for conversion.
-fn utf8_lit_to_ansi(ast: Lit) -> String {
-    let s = lit_to_string(ast);
-    let default_cp =
-        get_system_default_acp().expect("Failed to get system default acp");
+/// Returns the literals converted to the code page with the configured backend.
+///
+/// Characters which can't be converted are reported as errors.
+fn utf8_lit_to_ansi(args: &Args) -> syn::Result<Vec<u8>> {
+    check_options(args, true, false)?;
+    let strs = args_to_strings(args, false)?;
+    let s = strs.concat();
+    let cp = code_page(args, args.lit())?;
+    let backend = backend(args.lit().span())?;
     // UTF-8 -> Unicode -> ANSI
-    let s = utf8_to_wide(&s).unwrap();
+    let w = encode_wide(&s);
 
-    let mut v = wide_to_mb(default_cp, s.as_slice())
-        .expect("Failed to convert Wide string to MultiByte string");
-    v.reserve_exact(1);
-    v.push(0);
-    format!("{:?}", v)
+    let best_fit = args.best_fit.is_some();
+    let options = EncodeOptions {
+        best_fit,
+        ..Default::default()
+    };
+    let mut v = backend.encode(cp, &w, &options).map_err(|x| {
+        conversion_error(backend, &args.lits, &strs, cp, best_fit, x)
+    })?;
+    if args.nul {
+        v.reserve_exact(1);
+        v.push(0);
+    }
+    pad(args, &mut v)?;
+    Ok(v)
 }
 
-fn utf8_lit_to_ansi_lossy(ast: Lit) -> String {
-    let s = lit_to_string(ast);
-    let default_cp =
-        get_system_default_acp().expect("Failed to get system default acp");
+/// Returns the literals converted to the code page with the configured backend.
+///
+/// Characters which can't be converted are replaced.
+fn utf8_lit_to_ansi_lossy(args: &Args) -> syn::Result<Vec<u8>> {
+    check_options(args, true, true)?;
+    let strs = args_to_strings(args, true)?;
+    let s = strs.concat();
+    let cp = code_page(args, args.lit())?;
+    let backend = backend(args.lit().span())?;
     // UTF-8 -> Unicode -> ANSI
-    let s = utf8_to_wide(&s).unwrap();
+    let w = encode_wide(&s);
+
+    let best_fit = args.best_fit.is_some();
+    let options = EncodeOptions {
+        lossy: true,
+        best_fit,
+        replace: replacement_char(backend, args, cp)?,
+    };
+    let mut v = backend.encode(cp, &w, &options).map_err(|x| {
+        conversion_error(backend, &args.lits, &strs, cp, best_fit, x)
+    })?;
+    if args.nul {
+        v.reserve_exact(1);
+        v.push(0);
+    }
+    pad(args, &mut v)?;
+    Ok(v)
+}
+
+/// Pads `v` with zeros to `len` elements.
+fn pad<T: Clone + Default>(args: &Args, v: &mut Vec<T>) -> syn::Result<()> {
+    let Some(len) = args.len else {
+        return Ok(());
+    };
+    if v.len() > len {
+        return Err(syn::Error::new(
+            args.lit().span(),
+            format!(
+                "the converted data is {} elements{}, which exceeds `len = {}`",
+                v.len(),
+                if args.nul { " including the NUL" } else { "" },
+                len
+            ),
+        ));
+    }
+    v.resize(len, T::default());
+    Ok(())
+}
+
+/// Returns the expression of `ty` which the string macros expand to.
+///
+/// With `safe`, the string is made from the `&str` of the constructor in
+/// windy-macros-core instead of `new_nul_unchecked`.
+fn string_expr<T: Debug>(args: &Args, ty: &str, bs: &[T]) -> String {
+    if !is_safe(args) {
+        return format!(
+            "unsafe {{ __windy::{}::new_nul_unchecked({:?}) }}",
+            ty, bs
+        );
+    }
+    let str_ty = if ty == "WString" { "WStr" } else { "AStr" };
+    format!(
+        "__windy::{}::from({}(&{:?}))",
+        ty,
+        safe_constructor(str_ty),
+        bs
+    )
+}
+
+/// Returns the expression of `&ty` which the str macros expand to.
+///
+/// With `static`, the data is put in a named static array, so the expression
+/// can be evaluated in const context and the string has a fixed address.
+///
+/// With `safe`, the `const fn` constructor in windy-macros-core makes the
+/// string, so the expression can be evaluated in const context as well.
+fn str_expr<T: Debug>(args: &Args, ty: &str, elem: &str, bs: &[T]) -> String {
+    let safe = is_safe(args);
+    if !args.is_static {
+        return match safe {
+            true => format!("{}(&{:?})", safe_constructor(ty), bs),
+            false => format!(
+                "unsafe {{ __windy::{}::from_bytes_with_nul_unchecked(&{:?}) \
+                 }}",
+                ty, bs
+            ),
+        };
+    }
+    let data = format!("static DATA: [{}; {}] = {:?};", elem, bs.len(), bs);
+    match safe {
+        true => format!("{{ {} {}(&DATA) }}", data, safe_constructor(ty)),
+        false => format!(
+            "{{ {} unsafe {{ &*(&DATA as *const [{}] as *const __windy::{}) \
+             }} }}",
+            data, elem, ty
+        ),
+    }
+}
+
+/// Returns whether the strings are made without `unsafe` code, which is
+/// `safe` of the macro or the configuration.
+fn is_safe(args: &Args) -> bool { args.safe || config::safe() }
+
+/// Returns the path to the constructor of `&ty` in windy-macros-core.
+fn safe_constructor(ty: &str) -> String {
+    format!("::{}::safe::{}", config::core_crate(), ty.to_lowercase())
+}
+
+/// Returns the consts of `acp_info!` with the visibility `vis`.
+fn acp_info_items(vis: &syn::Visibility) -> syn::Result<String> {
+    let span = Span::call_site();
+    let info = cp_info(span, ansi_code_page(None, span)?)?;
+    let vis = vis.to_token_stream();
+    Ok(format!(
+        "#[doc = \"The default ANSI code page.\"] {vis} const ACP: u32 = {}; \
+         #[doc = \"The name of the default ANSI code page.\"] {vis} const \
+         ACP_NAME: &str = {:?}; #[doc = \"The maximum length of a character \
+         of the default ANSI code page in bytes.\"] {vis} const \
+         ACP_MAX_CHAR_SIZE: u32 = {};",
+        info.code_page,
+        info.name,
+        info.max_char_size,
+        vis = vis,
+    ))
+}
+
+/// Returns the byte of `replace` in `cp`.
+///
+/// NUL is rejected unless `allow_nul` is specified, because it would make an
+/// interior NUL.
+fn replacement_char(
+    backend: &dyn Backend,
+    args: &Args,
+    cp: u32,
+) -> syn::Result<Option<u8>> {
+    let Some(x) = &args.replace else {
+        return Ok(None);
+    };
+    let c = x.value();
+    let mut buf = [0; 2];
+    let w = c.encode_utf16(&mut buf);
+    match backend.encode(cp, w, &EncodeOptions::default()).as_deref() {
+        Ok(&[0]) if !args.allow_nul => Err(syn::Error::new(
+            x.span(),
+            "the replacement character is NUL, which makes an interior NUL; \
+             pass `allow_nul` to allow it",
+        )),
+        Ok(&[b]) => Ok(Some(b)),
+        Ok(_) => Err(syn::Error::new(
+            x.span(),
+            format!(
+                "{:?} is not a single byte in {}",
+                c,
+                cp_display(backend, cp)
+            ),
+        )),
+        Err(&e) => {
+            let ast = Lit::Char(x.clone());
+            let s = c.to_string();
+            Err(conversion_error(backend, &[ast], &[s], cp, false, e))
+        }
+    }
+}
+
+/// Returns the generated code, or the error as `compile_error!`.
+fn output(
+    ts: syn::Result<String>,
+    includes: &[PathBuf],
+) -> proc_macro::TokenStream {
+    let consts = tracking_consts(includes);
+    let ts = match ts {
+        Ok(ts) if consts.is_empty() => ts,
+        Ok(ts) => format!("{{ {} {} }}", consts, ts),
+        Err(e) => return e.to_compile_error().into(),
+    };
+    proc_macro::TokenStream::from_str(&ts).unwrap()
+}
+
+/// Returns unused constants which make cargo rebuild the crate when the
+/// inputs of the expansion are changed.
+///
+/// `includes` and the manifests read during the expansion are included by
+/// `include_bytes!`, and the environment variables read during the expansion
+/// are read by `option_env!`.
+fn tracking_consts(includes: &[PathBuf]) -> String {
+    let manifests = config::take_tracked_files();
+    let files = includes
+        .iter()
+        .chain(&manifests)
+        .filter_map(|x| x.to_str())
+        .map(|x| format!("const _: &[u8] = include_bytes!({:?});", x));
+    let vars = config::take_tracked_vars().into_iter().map(|x| {
+        format!(
+            "const _: ::core::option::Option<&str> = option_env!({:?});",
+            x
+        )
+    });
+    files.chain(vars).collect()
+}
+
+/// Converts `ts`, which refers to the windy crate as `__windy`, to the output.
+///
+/// The path to the crate is `crate = <path>`, or the name of the dependency in
+/// the manifest. It is imported with its own tokens, so `$crate` of a wrapper
+/// macro is resolved in the wrapper crate.
+fn output_windy(
+    ts: syn::Result<String>,
+    args: &Args,
+) -> proc_macro::TokenStream {
+    if let Err(e) = ts {
+        return e.to_compile_error().into();
+    }
+    let windy = match &args.windy {
+        Some(x) => x.to_token_stream().into(),
+        None => {
+            let x = format!("::{}", config::windy_crate());
+            proc_macro::TokenStream::from_str(&x).unwrap()
+        }
+    };
+    let mut x = proc_macro::TokenStream::from_str("use").unwrap();
+    x.extend([
+        windy,
+        proc_macro::TokenStream::from_str("as __windy;").unwrap(),
+        output(ts, &args.includes),
+    ]);
+    proc_macro::TokenTree::from(proc_macro::Group::new(
+        proc_macro::Delimiter::Brace,
+        x,
+    ))
+    .into()
+}
+
+/// Returns the output of the T macros, which is `w` if the unicode cfg is
+/// enabled and `a` otherwise.
+///
+/// Both are generated, and `cfg` removes the other one in the caller's crate.
+/// The options only for the ANSI macros are ignored by `w`. The inputs read for
+/// the cfg itself are tracked outside both.
+fn output_tchar(
+    args: &Args,
+    w: impl FnOnce(&Args) -> proc_macro::TokenStream,
+    a: impl FnOnce(&Args) -> proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let cfg = config::unicode_cfg();
+    let Ok(pred) = proc_macro::TokenStream::from_str(&cfg) else {
+        let msg =
+            format!("invalid `unicode` cfg `{}` in the configuration", cfg);
+        return syn::Error::new(args.lit().span(), msg)
+            .to_compile_error()
+            .into();
+    };
+    let mut not = proc_macro::TokenStream::from_str("not").unwrap();
+    not.extend([proc_macro::TokenTree::from(proc_macro::Group::new(
+        proc_macro::Delimiter::Parenthesis,
+        pred.clone(),
+    ))]);
+    let wide = Args {
+        replace: None,
+        best_fit: None,
+        ..args.clone()
+    };
+    let s = |x: &str| proc_macro::TokenStream::from_str(x).unwrap();
+    let mut x = s(&tracking_consts(&[]));
+    x.extend([
+        cfg_attr(pred),
+        s("let x ="),
+        w(&wide),
+        s(";"),
+        cfg_attr(not),
+        s("let x ="),
+        a(args),
+        s("; x"),
+    ]);
+    proc_macro::TokenTree::from(proc_macro::Group::new(
+        proc_macro::Delimiter::Brace,
+        x,
+    ))
+    .into()
+}
 
-    let mut v = wide_to_mb_lossy(default_cp, s.as_slice())
-        .expect("Failed to convert Wide string to MultiByte string");
-    v.reserve_exact(1);
-    v.push(0);
-    format!("{:?}", v)
+/// Returns `#[cfg(<pred>)]`.
+fn cfg_attr(pred: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let group = |delimiter, x| {
+        proc_macro::TokenTree::from(proc_macro::Group::new(delimiter, x))
+    };
+    let mut cfg = proc_macro::TokenStream::from_str("cfg").unwrap();
+    cfg.extend([group(proc_macro::Delimiter::Parenthesis, pred)]);
+    let mut x = proc_macro::TokenStream::from_str("#").unwrap();
+    x.extend([group(proc_macro::Delimiter::Bracket, cfg)]);
+    x
 }
 
-/// Returns [`windy::WString`].
+/// Returns `windy::WString`.
+///
+/// Byte strings are decoded from the code page, which can be specified with
+/// `cp = <code page>`.
 ///
-/// If an invalid value is passed, this macro will be panicked.
+/// If an invalid value is passed, this macro fails to compile.
 ///
 /// # Example
 ///
 /// ```
-/// use windy_macros::wstring;
+/// # #[cfg(windows)]
+/// fn main() {
+///     use windy_macros::wstring;
 ///
-/// let s = wstring!("test");
-/// println!("{:?}", s); // "test"
-/// let s = wstring!(4649);
-/// println!("{:?}", s); // "4649"
+///     let s = wstring!("test");
+///     println!("{:?}", s); // "test"
+///     let s = wstring!(4649);
+///     println!("{:?}", s); // "4649"
+/// }
+/// # #[cfg(not(windows))]
+/// # fn main() {}
 /// ```
 #[proc_macro]
 pub fn wstring(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let ast = parse_macro_input!(ast as Lit);
+    let args = parse_macro_input!(ast as Args);
 
-    let bs = lit_to_bs!(WString, ast);
-    let ts =
-        format!("unsafe {{ ::windy::WString::new_nul_unchecked({}) }}", bs);
-
-    proc_macro::TokenStream::from_str(&ts).unwrap()
+    output_windy(
+        lit_to_wide(&args, false).map(|bs| string_expr(&args, "WString", &bs)),
+        &args,
+    )
 }
 
-/// Returns [`windy::WString`].
+/// Returns `windy::WString`.
+///
+/// Byte strings are decoded from the code page, which can be specified with
+/// `cp = <code page>`.
 ///
 /// # Example
 ///
 /// ```
-/// use windy_macros::wstring_lossy;
+/// # #[cfg(windows)]
+/// fn main() {
+///     use windy_macros::wstring_lossy;
 ///
-/// let s = wstring_lossy!("test");
-/// println!("{:?}", s); // "test"
-/// let s = wstring_lossy!(4649);
-/// println!("{:?}", s); // "4649"
+///     let s = wstring_lossy!("test");
+///     println!("{:?}", s); // "test"
+///     let s = wstring_lossy!(4649);
+///     println!("{:?}", s); // "4649"
+/// }
+/// # #[cfg(not(windows))]
+/// # fn main() {}
 /// ```
 #[proc_macro]
 pub fn wstring_lossy(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let ast = parse_macro_input!(ast as Lit);
+    let args = parse_macro_input!(ast as Args);
 
-    let bs = lit_to_bs_lossy!(WString, ast);
-    let ts =
-        format!("unsafe {{ ::windy::WString::new_nul_unchecked({}) }}", bs);
-
-    proc_macro::TokenStream::from_str(&ts).unwrap()
+    output_windy(
+        lit_to_wide(&args, true).map(|bs| string_expr(&args, "WString", &bs)),
+        &args,
+    )
 }
 
-/// Returns [`windy::AString`].
+/// Returns `windy::AString`.
+///
+/// The code page can be specified with `cp = <code page>`.
 ///
-/// If an invalid value is passed, this macro will be panicked.
+/// `best_fit` converts characters which the code page doesn't have to similar
+/// ones, e.g. `Ａ` to `A`.
+///
+/// If an invalid value is passed, this macro fails to compile.
 ///
 /// # Example
 ///
 /// ```
-/// use windy_macros::astring;
+/// # #[cfg(windows)]
+/// fn main() {
+///     use windy_macros::astring;
 ///
-/// let s = astring!("test");
-/// println!("{:?}", s); // "test"
-/// let s = astring!(4649);
-/// println!("{:?}", s); // "4649"
+///     let s = astring!("test");
+///     println!("{:?}", s); // "test"
+///     let s = astring!(4649);
+///     println!("{:?}", s); // "4649"
+/// }
+/// # #[cfg(not(windows))]
+/// # fn main() {}
 /// ```
 #[proc_macro]
 pub fn astring(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let ast = parse_macro_input!(ast as Lit);
-
-    let bs = lit_to_bs!(AString, ast);
-    let ts =
-        format!("unsafe {{ ::windy::AString::new_nul_unchecked({}) }}", bs);
+    let args = parse_macro_input!(ast as Args);
 
-    proc_macro::TokenStream::from_str(&ts).unwrap()
+    output_windy(
+        utf8_lit_to_ansi(&args).map(|bs| string_expr(&args, "AString", &bs)),
+        &args,
+    )
 }
 
-/// Returns [`windy::AString`].
+/// Returns `windy::AString`.
+///
+/// The code page can be specified with `cp = <code page>`.
+///
+/// `best_fit` converts characters which the code page doesn't have to similar
+/// ones, e.g. `Ａ` to `A`.
+///
+/// Characters which can't be converted are replaced with `?`, or with the
+/// character specified with `replace = <char>`, which must be a single byte in
+/// the code page.
 ///
 /// # Example
 ///
 /// ```
-/// use windy_macros::astring_lossy;
+/// # #[cfg(windows)]
+/// fn main() {
+///     use windy_macros::astring_lossy;
 ///
-/// let s = astring_lossy!("test");
-/// println!("{:?}", s); // "test"
-/// let s = astring_lossy!(4649);
-/// println!("{:?}", s); // "4649"
+///     let s = astring_lossy!("test");
+///     println!("{:?}", s); // "test"
+///     let s = astring_lossy!(4649);
+///     println!("{:?}", s); // "4649"
+/// }
+/// # #[cfg(not(windows))]
+/// # fn main() {}
 /// ```
 #[proc_macro]
 pub fn astring_lossy(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let ast = parse_macro_input!(ast as Lit);
+    let args = parse_macro_input!(ast as Args);
 
-    let bs = utf8_lit_to_ansi_lossy(ast);
-    let ts =
-        format!("unsafe {{ ::windy::AString::new_nul_unchecked({}) }}", bs);
-
-    proc_macro::TokenStream::from_str(&ts).unwrap()
+    output_windy(
+        utf8_lit_to_ansi_lossy(&args)
+            .map(|bs| string_expr(&args, "AString", &bs)),
+        &args,
+    )
 }
 
-/// Returns &[`windy::WStr`].
+/// Returns &`windy::WStr`.
+///
+/// `static` puts the data in a static array, so the macro can be used in const
+/// context.
 ///
-/// If an invalid value is passed, this macro will be panicked.
+/// Byte strings are decoded from the code page, which can be specified with
+/// `cp = <code page>`.
+///
+/// If an invalid value is passed, this macro fails to compile.
 ///
 /// # Example
 ///
 /// ```
-/// use windy::WString;
-/// use windy_macros::wstr;
+/// # #[cfg(windows)]
+/// fn main() {
+///     use windy::WString;
+///     use windy_macros::wstr;
 ///
-/// let x = wstr!("test");
-/// assert_eq!(
-///     WString::from_str_lossy("test").to_bytes_with_nul(),
-///     x.to_bytes_with_nul()
-/// );
+///     let x = wstr!("test");
+///     assert_eq!(
+///         WString::from_str_lossy("test").to_bytes_with_nul(),
+///         x.to_bytes_with_nul()
+///     );
+/// }
+/// # #[cfg(not(windows))]
+/// # fn main() {}
 /// ```
 #[proc_macro]
 pub fn wstr(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let ast = parse_macro_input!(ast as Lit);
-
-    let bs = lit_to_bs!(WString, ast);
-    let ts = format!(
-        "unsafe {{ ::windy::WStr::from_bytes_with_nul_unchecked(&{}) }}",
-        bs
-    );
+    let args = parse_macro_input!(ast with Args::parse_str);
 
-    proc_macro::TokenStream::from_str(&ts).unwrap()
+    output_windy(
+        lit_to_wide(&args, false).map(|bs| str_expr(&args, "WStr", "u16", &bs)),
+        &args,
+    )
 }
 
-/// Returns &[`windy::WStr`].
+/// Returns &`windy::WStr`.
+///
+/// `static` puts the data in a static array, so the macro can be used in const
+/// context.
+///
+/// Byte strings are decoded from the code page, which can be specified with
+/// `cp = <code page>`.
 ///
 /// # Example
 ///
 /// ```
-/// use windy::WString;
-/// use windy_macros::wstr_lossy;
+/// # #[cfg(windows)]
+/// fn main() {
+///     use windy::WString;
+///     use windy_macros::wstr_lossy;
 ///
-/// let x = wstr_lossy!("test");
-/// assert_eq!(
-///     WString::from_str_lossy("test").to_bytes_with_nul(),
-///     x.to_bytes_with_nul()
-/// );
+///     let x = wstr_lossy!("test");
+///     assert_eq!(
+///         WString::from_str_lossy("test").to_bytes_with_nul(),
+///         x.to_bytes_with_nul()
+///     );
+/// }
+/// # #[cfg(not(windows))]
+/// # fn main() {}
 /// ```
 #[proc_macro]
 pub fn wstr_lossy(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let ast = parse_macro_input!(ast as Lit);
+    let args = parse_macro_input!(ast with Args::parse_str);
 
-    let bs = lit_to_bs_lossy!(WString, ast);
-    let ts = format!(
-        "unsafe {{ ::windy::WStr::from_bytes_with_nul_unchecked(&{}) }}",
-        bs
-    );
-
-    proc_macro::TokenStream::from_str(&ts).unwrap()
+    output_windy(
+        lit_to_wide(&args, true).map(|bs| str_expr(&args, "WStr", "u16", &bs)),
+        &args,
+    )
 }
 
-/// Returns &[`windy::AStr`].
+/// Returns &`windy::AStr`.
 ///
-/// If an invalid value is passed, this macro will be panicked.
+/// `static` puts the data in a static array, so the macro can be used in const
+/// context.
+///
+/// The code page can be specified with `cp = <code page>`.
+///
+/// `best_fit` converts characters which the code page doesn't have to similar
+/// ones, e.g. `Ａ` to `A`.
+///
+/// If an invalid value is passed, this macro fails to compile.
 ///
 /// # Example
 ///
 /// ```
-/// use windy::AString;
-/// use windy_macros::astr;
+/// # #[cfg(windows)]
+/// fn main() {
+///     use windy::AString;
+///     use windy_macros::astr;
 ///
-/// let x = astr!("test");
-/// assert_eq!(
-///     AString::from_str_lossy("test").to_bytes_with_nul(),
-///     x.to_bytes_with_nul()
-/// );
+///     let x = astr!("test");
+///     assert_eq!(
+///         AString::from_str_lossy("test").to_bytes_with_nul(),
+///         x.to_bytes_with_nul()
+///     );
+/// }
+/// # #[cfg(not(windows))]
+/// # fn main() {}
 /// ```
 #[proc_macro]
 pub fn astr(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let ast = parse_macro_input!(ast as Lit);
+    let args = parse_macro_input!(ast with Args::parse_str);
 
-    let bs = utf8_lit_to_ansi(ast);
-    let ts = format!(
-        "unsafe {{ ::windy::AStr::from_bytes_with_nul_unchecked(&{}) }}",
-        bs
-    );
-
-    proc_macro::TokenStream::from_str(&ts).unwrap()
+    output_windy(
+        utf8_lit_to_ansi(&args).map(|bs| str_expr(&args, "AStr", "u8", &bs)),
+        &args,
+    )
 }
 
-/// Returns &[`windy::AStr`].
+/// Returns &`windy::AStr`.
+///
+/// `static` puts the data in a static array, so the macro can be used in const
+/// context.
+///
+/// The code page can be specified with `cp = <code page>`.
+///
+/// `best_fit` converts characters which the code page doesn't have to similar
+/// ones, e.g. `Ａ` to `A`.
+///
+/// Characters which can't be converted are replaced with `?`, or with the
+/// character specified with `replace = <char>`, which must be a single byte in
+/// the code page.
 ///
 /// # Example
 ///
 /// ```
-/// use windy::AString;
-/// use windy_macros::astr_lossy;
+/// # #[cfg(windows)]
+/// fn main() {
+///     use windy::AString;
+///     use windy_macros::astr_lossy;
 ///
-/// let x = astr_lossy!("test");
-/// assert_eq!(
-///     AString::from_str_lossy("test").to_bytes_with_nul(),
-///     x.to_bytes_with_nul()
-/// );
+///     let x = astr_lossy!("test");
+///     assert_eq!(
+///         AString::from_str_lossy("test").to_bytes_with_nul(),
+///         x.to_bytes_with_nul()
+///     );
+/// }
+/// # #[cfg(not(windows))]
+/// # fn main() {}
 /// ```
 #[proc_macro]
 pub fn astr_lossy(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let ast = parse_macro_input!(ast as Lit);
-
-    let bs = utf8_lit_to_ansi_lossy(ast);
-    let ts = format!(
-        "unsafe {{ ::windy::AStr::from_bytes_with_nul_unchecked(&{}) }}",
-        bs
-    );
+    let args = parse_macro_input!(ast with Args::parse_str);
 
-    proc_macro::TokenStream::from_str(&ts).unwrap()
+    output_windy(
+        utf8_lit_to_ansi_lossy(&args)
+            .map(|bs| str_expr(&args, "AStr", "u8", &bs)),
+        &args,
+    )
 }
 
 /// Returns `[u16]`.
 ///
-/// If an invalid value is passed, this macro will be panicked.
+/// `nul = false` omits the terminating NUL, and `len = <length>` pads the
+/// array with zeros to the length.
+///
+/// Byte strings are decoded from the code page, which can be specified with
+/// `cp = <code page>`.
+///
+/// If an invalid value is passed, this macro fails to compile.
 ///
 /// # Example
 ///
 /// ```
-/// use windy::WString;
 /// use windy_macros::warr;
 ///
 /// let b = &warr!("test");
-/// assert_eq!(WString::from_str_lossy("test").to_bytes_with_nul(), b);
+/// assert_eq!(&[0x74, 0x65, 0x73, 0x74, 0x00], b);
 /// let b = &warr!(4649);
-/// assert_eq!(WString::from_str_lossy("4649").to_bytes_with_nul(), b);
+/// assert_eq!(&[0x34, 0x36, 0x34, 0x39, 0x00], b);
 /// ```
 #[proc_macro]
 pub fn warr(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let ast = parse_macro_input!(ast as Lit);
+    let args = parse_macro_input!(ast with Args::parse_array);
 
-    let ts = lit_to_bs!(WString, ast);
-
-    proc_macro::TokenStream::from_str(&ts).unwrap()
+    output(
+        lit_to_wide(&args, false).map(|bs| format!("{:?}", bs)),
+        &args.includes,
+    )
 }
 
 /// Returns `[u16]`.
 ///
+/// `nul = false` omits the terminating NUL, and `len = <length>` pads the
+/// array with zeros to the length.
+///
+/// Byte strings are decoded from the code page, which can be specified with
+/// `cp = <code page>`.
+///
 /// # Example
 ///
 /// ```
-/// use windy::WString;
 /// use windy_macros::warr_lossy;
 ///
 /// let b = &warr_lossy!("test");
-/// assert_eq!(WString::from_str_lossy("test").to_bytes_with_nul(), b);
+/// assert_eq!(&[0x74, 0x65, 0x73, 0x74, 0x00], b);
 /// let b = &warr_lossy!(4649);
-/// assert_eq!(WString::from_str_lossy("4649").to_bytes_with_nul(), b);
+/// assert_eq!(&[0x34, 0x36, 0x34, 0x39, 0x00], b);
 /// ```
 #[proc_macro]
 pub fn warr_lossy(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let ast = parse_macro_input!(ast as Lit);
-
-    let ts = lit_to_bs_lossy!(WString, ast);
+    let args = parse_macro_input!(ast with Args::parse_array);
 
-    proc_macro::TokenStream::from_str(&ts).unwrap()
+    output(
+        lit_to_wide(&args, true).map(|bs| format!("{:?}", bs)),
+        &args.includes,
+    )
 }
 
 /// Returns `[u8]`.
 ///
-/// If an invalid value is passed, this macro will be panicked.
+/// `nul = false` omits the terminating NUL, and `len = <length>` pads the
+/// array with zeros to the length.
+///
+/// The code page can be specified with `cp = <code page>`.
+///
+/// `best_fit` converts characters which the code page doesn't have to similar
+/// ones, e.g. `Ａ` to `A`.
+///
+/// If an invalid value is passed, this macro fails to compile.
 ///
 /// # Example
 ///
 /// ```
-/// use windy::AString;
-/// use windy_macros::aarr;
+/// # #[cfg(windows)]
+/// fn main() {
+///     use windy::AString;
+///     use windy_macros::aarr;
+///
+///     let b = &aarr!("test");
+///     assert_eq!(AString::from_str_lossy("test").to_bytes_with_nul(), b);
+///     let b = &aarr!(4649);
+///     assert_eq!(AString::from_str_lossy("4649").to_bytes_with_nul(), b);
+/// }
+/// # #[cfg(not(windows))]
+/// # fn main() {}
+/// ```
+///
+/// A character which can't be converted is reported at the literal.
+///
+/// ```compile_fail
+/// // error: 'あ' (U+3042) at index 0 can't be converted to code page 1252 (ANSI - Latin I)
+/// let b = windy_macros::aarr!(cp = 1252, "あ");
+/// ```
 ///
-/// let b = &aarr!("test");
-/// assert_eq!(AString::from_str_lossy("test").to_bytes_with_nul(), b);
-/// let b = &aarr!(4649);
-/// assert_eq!(AString::from_str_lossy("4649").to_bytes_with_nul(), b);
+/// A code page which isn't supported is rejected as well.
+///
+/// ```compile_fail
+/// // error: code page 12345 is not supported
+/// let b = windy_macros::aarr!(cp = 12345, "a");
 /// ```
 #[proc_macro]
 pub fn aarr(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let ast = parse_macro_input!(ast as Lit);
-
-    let ts = utf8_lit_to_ansi(ast);
+    let args = parse_macro_input!(ast with Args::parse_array);
 
-    proc_macro::TokenStream::from_str(&ts).unwrap()
+    output(
+        utf8_lit_to_ansi(&args).map(|bs| format!("{:?}", bs)),
+        &args.includes,
+    )
 }
 
 /// Returns `[u8]`.
 ///
+/// `nul = false` omits the terminating NUL, and `len = <length>` pads the
+/// array with zeros to the length.
+///
+/// The code page can be specified with `cp = <code page>`.
+///
+/// `best_fit` converts characters which the code page doesn't have to similar
+/// ones, e.g. `Ａ` to `A`.
+///
+/// Characters which can't be converted are replaced with `?`, or with the
+/// character specified with `replace = <char>`, which must be a single byte in
+/// the code page.
+///
 /// # Example
 ///
 /// ```
-/// use windy::AString;
-/// use windy_macros::aarr_lossy;
+/// # #[cfg(windows)]
+/// fn main() {
+///     use windy::AString;
+///     use windy_macros::aarr_lossy;
 ///
-/// let b = &aarr_lossy!("test");
-/// assert_eq!(AString::from_str_lossy("test").to_bytes_with_nul(), b);
-/// let b = &aarr_lossy!(4649);
-/// assert_eq!(AString::from_str_lossy("4649").to_bytes_with_nul(), b);
+///     let b = &aarr_lossy!("test");
+///     assert_eq!(AString::from_str_lossy("test").to_bytes_with_nul(), b);
+///     let b = &aarr_lossy!(4649);
+///     assert_eq!(AString::from_str_lossy("4649").to_bytes_with_nul(), b);
+/// }
+/// # #[cfg(not(windows))]
+/// # fn main() {}
 /// ```
 #[proc_macro]
 pub fn aarr_lossy(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let ast = parse_macro_input!(ast as Lit);
+    let args = parse_macro_input!(ast with Args::parse_array);
 
-    let ts = utf8_lit_to_ansi_lossy(ast);
+    output(
+        utf8_lit_to_ansi_lossy(&args).map(|bs| format!("{:?}", bs)),
+        &args.includes,
+    )
+}
 
-    proc_macro::TokenStream::from_str(&ts).unwrap()
+/// Returns `windy::WString` if the unicode cfg is enabled, or
+/// `windy::AString` otherwise.
+///
+/// The cfg is `windy_unicode`, which can be changed with `unicode = '<cfg>'` in
+/// the configuration, e.g. `unicode = 'feature = "unicode"'`.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(windows)]
+/// fn main() {
+///     use windy_macros::tstring;
+///
+///     let s = tstring!("test");
+///     println!("{:?}", s); // "test"
+/// }
+/// # #[cfg(not(windows))]
+/// # fn main() {}
+/// ```
+#[proc_macro]
+pub fn tstring(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let args = parse_macro_input!(ast as Args);
+
+    output_tchar(
+        &args,
+        |args| {
+            output_windy(
+                lit_to_wide(args, false)
+                    .map(|bs| string_expr(args, "WString", &bs)),
+                args,
+            )
+        },
+        |args| {
+            output_windy(
+                utf8_lit_to_ansi(args)
+                    .map(|bs| string_expr(args, "AString", &bs)),
+                args,
+            )
+        },
+    )
+}
+
+/// Returns `windy::WString` if the unicode cfg is enabled, or
+/// `windy::AString` otherwise.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(windows)]
+/// fn main() {
+///     use windy_macros::tstring_lossy;
+///
+///     let s = tstring_lossy!("test");
+///     println!("{:?}", s); // "test"
+/// }
+/// # #[cfg(not(windows))]
+/// # fn main() {}
+/// ```
+#[proc_macro]
+pub fn tstring_lossy(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let args = parse_macro_input!(ast as Args);
+
+    output_tchar(
+        &args,
+        |args| {
+            output_windy(
+                lit_to_wide(args, true)
+                    .map(|bs| string_expr(args, "WString", &bs)),
+                args,
+            )
+        },
+        |args| {
+            output_windy(
+                utf8_lit_to_ansi_lossy(args)
+                    .map(|bs| string_expr(args, "AString", &bs)),
+                args,
+            )
+        },
+    )
+}
+
+/// Returns &`windy::WStr` if the unicode cfg is enabled, or
+/// &`windy::AStr` otherwise.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(windows)]
+/// fn main() {
+///     use windy_macros::tstr;
+///
+///     let x = tstr!("test");
+///     assert_eq!(5, x.to_bytes_with_nul().len());
+/// }
+/// # #[cfg(not(windows))]
+/// # fn main() {}
+/// ```
+#[proc_macro]
+pub fn tstr(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let args = parse_macro_input!(ast with Args::parse_str);
+
+    output_tchar(
+        &args,
+        |args| {
+            output_windy(
+                lit_to_wide(args, false)
+                    .map(|bs| str_expr(args, "WStr", "u16", &bs)),
+                args,
+            )
+        },
+        |args| {
+            output_windy(
+                utf8_lit_to_ansi(args)
+                    .map(|bs| str_expr(args, "AStr", "u8", &bs)),
+                args,
+            )
+        },
+    )
+}
+
+/// Returns &`windy::WStr` if the unicode cfg is enabled, or
+/// &`windy::AStr` otherwise.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(windows)]
+/// fn main() {
+///     use windy_macros::tstr_lossy;
+///
+///     let x = tstr_lossy!("test");
+///     assert_eq!(5, x.to_bytes_with_nul().len());
+/// }
+/// # #[cfg(not(windows))]
+/// # fn main() {}
+/// ```
+#[proc_macro]
+pub fn tstr_lossy(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let args = parse_macro_input!(ast with Args::parse_str);
+
+    output_tchar(
+        &args,
+        |args| {
+            output_windy(
+                lit_to_wide(args, true)
+                    .map(|bs| str_expr(args, "WStr", "u16", &bs)),
+                args,
+            )
+        },
+        |args| {
+            output_windy(
+                utf8_lit_to_ansi_lossy(args)
+                    .map(|bs| str_expr(args, "AStr", "u8", &bs)),
+                args,
+            )
+        },
+    )
+}
+
+/// Returns `[u16]` if the unicode cfg is enabled, or `[u8]` otherwise.
+///
+/// # Example
+///
+/// ```
+/// use windy_macros::tarr;
+///
+/// let b = tarr!("test");
+/// assert_eq!(5, b.len());
+/// ```
+#[proc_macro]
+pub fn tarr(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let args = parse_macro_input!(ast with Args::parse_array);
+
+    output_tchar(
+        &args,
+        |args| {
+            output(
+                lit_to_wide(args, false).map(|bs| format!("{:?}", bs)),
+                &args.includes,
+            )
+        },
+        |args| {
+            output(
+                utf8_lit_to_ansi(args).map(|bs| format!("{:?}", bs)),
+                &args.includes,
+            )
+        },
+    )
+}
+
+/// Returns `[u16]` if the unicode cfg is enabled, or `[u8]` otherwise.
+///
+/// # Example
+///
+/// ```
+/// use windy_macros::tarr_lossy;
+///
+/// let b = tarr_lossy!("test");
+/// assert_eq!(5, b.len());
+/// ```
+#[proc_macro]
+pub fn tarr_lossy(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let args = parse_macro_input!(ast with Args::parse_array);
+
+    output_tchar(
+        &args,
+        |args| {
+            output(
+                lit_to_wide(args, true).map(|bs| format!("{:?}", bs)),
+                &args.includes,
+            )
+        },
+        |args| {
+            output(
+                utf8_lit_to_ansi_lossy(args).map(|bs| format!("{:?}", bs)),
+                &args.includes,
+            )
+        },
+    )
+}
+
+/// Returns `windy::AString` in the OEM code page.
+///
+/// The code page can be specified with `cp = <code page>`.
+///
+/// `best_fit` converts characters which the code page doesn't have to similar
+/// ones, e.g. `Ａ` to `A`.
+///
+/// If an invalid value is passed, this macro fails to compile.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(windows)]
+/// fn main() {
+///     use windy_macros::ostring;
+///
+///     let s = ostring!(cp = 437, "├─ test");
+///     assert_eq!(b"\xC3\xC4 test\0", s.as_bytes_with_nul());
+/// }
+/// # #[cfg(not(windows))]
+/// # fn main() {}
+/// ```
+#[proc_macro]
+pub fn ostring(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let mut args = parse_macro_input!(ast as Args);
+    args.oem = true;
+
+    output_windy(
+        utf8_lit_to_ansi(&args).map(|bs| string_expr(&args, "AString", &bs)),
+        &args,
+    )
+}
+
+/// Returns `windy::AString` in the OEM code page.
+///
+/// The code page can be specified with `cp = <code page>`.
+///
+/// `best_fit` converts characters which the code page doesn't have to similar
+/// ones, e.g. `Ａ` to `A`.
+///
+/// Characters which can't be converted are replaced with `?`, or with the
+/// character specified with `replace = <char>`, which must be a single byte in
+/// the code page.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(windows)]
+/// fn main() {
+///     use windy_macros::ostring_lossy;
+///
+///     let s = ostring_lossy!(cp = 437, "テスト");
+///     assert_eq!(b"???\0", s.as_bytes_with_nul());
+/// }
+/// # #[cfg(not(windows))]
+/// # fn main() {}
+/// ```
+#[proc_macro]
+pub fn ostring_lossy(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let mut args = parse_macro_input!(ast as Args);
+    args.oem = true;
+
+    output_windy(
+        utf8_lit_to_ansi_lossy(&args)
+            .map(|bs| string_expr(&args, "AString", &bs)),
+        &args,
+    )
+}
+
+/// Returns &`windy::AStr` in the OEM code page.
+///
+/// `static` puts the data in a static array, so the macro can be used in const
+/// context.
+///
+/// The code page can be specified with `cp = <code page>`.
+///
+/// `best_fit` converts characters which the code page doesn't have to similar
+/// ones, e.g. `Ａ` to `A`.
+///
+/// If an invalid value is passed, this macro fails to compile.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(windows)]
+/// fn main() {
+///     use windy_macros::ostr;
+///
+///     let x = ostr!(cp = 437, "├─ test");
+///     assert_eq!(b"\xC3\xC4 test\0", x.to_bytes_with_nul());
+/// }
+/// # #[cfg(not(windows))]
+/// # fn main() {}
+/// ```
+#[proc_macro]
+pub fn ostr(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let mut args = parse_macro_input!(ast with Args::parse_str);
+    args.oem = true;
+
+    output_windy(
+        utf8_lit_to_ansi(&args).map(|bs| str_expr(&args, "AStr", "u8", &bs)),
+        &args,
+    )
+}
+
+/// Returns &`windy::AStr` in the OEM code page.
+///
+/// `static` puts the data in a static array, so the macro can be used in const
+/// context.
+///
+/// The code page can be specified with `cp = <code page>`.
+///
+/// `best_fit` converts characters which the code page doesn't have to similar
+/// ones, e.g. `Ａ` to `A`.
+///
+/// Characters which can't be converted are replaced with `?`, or with the
+/// character specified with `replace = <char>`, which must be a single byte in
+/// the code page.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(windows)]
+/// fn main() {
+///     use windy_macros::ostr_lossy;
+///
+///     let x = ostr_lossy!(cp = 437, "テスト");
+///     assert_eq!(b"???\0", x.to_bytes_with_nul());
+/// }
+/// # #[cfg(not(windows))]
+/// # fn main() {}
+/// ```
+#[proc_macro]
+pub fn ostr_lossy(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let mut args = parse_macro_input!(ast with Args::parse_str);
+    args.oem = true;
+
+    output_windy(
+        utf8_lit_to_ansi_lossy(&args)
+            .map(|bs| str_expr(&args, "AStr", "u8", &bs)),
+        &args,
+    )
+}
+
+/// Returns `[u8]` in the OEM code page.
+///
+/// `nul = false` omits the terminating NUL, and `len = <length>` pads the
+/// array with zeros to the length.
+///
+/// The code page can be specified with `cp = <code page>`.
+///
+/// `best_fit` converts characters which the code page doesn't have to similar
+/// ones, e.g. `Ａ` to `A`.
+///
+/// If an invalid value is passed, this macro fails to compile.
+///
+/// # Example
+///
+/// ```
+/// use windy_macros::oarr;
+///
+/// let b = oarr!(cp = 437, "├─ test");
+/// assert_eq!(b"\xC3\xC4 test\0", &b);
+/// ```
+#[proc_macro]
+pub fn oarr(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let mut args = parse_macro_input!(ast with Args::parse_array);
+    args.oem = true;
+
+    output(
+        utf8_lit_to_ansi(&args).map(|bs| format!("{:?}", bs)),
+        &args.includes,
+    )
+}
+
+/// Returns `[u8]` in the OEM code page.
+///
+/// `nul = false` omits the terminating NUL, and `len = <length>` pads the
+/// array with zeros to the length.
+///
+/// The code page can be specified with `cp = <code page>`.
+///
+/// `best_fit` converts characters which the code page doesn't have to similar
+/// ones, e.g. `Ａ` to `A`.
+///
+/// Characters which can't be converted are replaced with `?`, or with the
+/// character specified with `replace = <char>`, which must be a single byte in
+/// the code page.
+///
+/// # Example
+///
+/// ```
+/// use windy_macros::oarr_lossy;
+///
+/// let b = oarr_lossy!(cp = 437, "テスト");
+/// assert_eq!(b"???\0", &b);
+/// ```
+#[proc_macro]
+pub fn oarr_lossy(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let mut args = parse_macro_input!(ast with Args::parse_array);
+    args.oem = true;
+
+    output(
+        utf8_lit_to_ansi_lossy(&args).map(|bs| format!("{:?}", bs)),
+        &args.includes,
+    )
+}
+
+/// Defines the information of the default ANSI code page as consts.
+///
+/// `ACP` is the code page number, `ACP_NAME` is its name and
+/// `ACP_MAX_CHAR_SIZE` is the maximum length of a character in bytes. The
+/// visibility of the consts can be passed, e.g. `acp_info!(pub)`.
+///
+/// The name is the one of English Windows on a non-Windows host and the one in
+/// the language of the system on Windows.
+///
+/// # Example
+///
+/// ```
+/// use windy_macros::acp_info;
+///
+/// acp_info!();
+///
+/// // The code page of this crate is configured to 1252 in Cargo.toml.
+/// assert_eq!(1252, ACP);
+/// assert_eq!(1, ACP_MAX_CHAR_SIZE);
+/// println!("{}", ACP_NAME); // ANSI - Latin I
+/// ```
+#[proc_macro]
+pub fn acp_info(ast: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let vis = parse_macro_input!(ast as syn::Visibility);
+
+    // The consts are items, so they aren't wrapped in a block like `output`.
+    let items = acp_info_items(&vis);
+    let consts = tracking_consts(&[]);
+    match items {
+        Ok(x) => proc_macro::TokenStream::from_str(&(consts + &x)).unwrap(),
+        Err(e) => e.to_compile_error().into(),
+    }
 }
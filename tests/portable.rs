@@ -0,0 +1,308 @@
+// Copyright takubokudori.
+// This source code is licensed under the MIT or Apache-2.0 license.
+//! Tests that don't depend on the host or on windy.
+
+#[cfg(test)]
+mod tests {
+    use windy_macros::*;
+
+    // Makes a null-terminated wide string.
+    macro_rules! mw {
+        ($x:expr) => {
+            $x.encode_utf16().chain([0]).collect::<Vec<u16>>()
+        };
+    }
+
+    #[test]
+    fn test_warr() {
+        let x: &[u16] = &warr!("test");
+        assert_eq!(mw!("test"), x);
+        assert_ne!(mw!("test2"), x);
+        assert_eq!(mw!("testテスト🍣"), &warr!("testテスト🍣"));
+        assert_eq!(mw!("あ"), &warr!('あ'));
+        assert_eq!(mw!("4649"), &warr!(4649));
+        assert_eq!(mw!("3.14"), &warr!(3.14));
+        assert_eq!(mw!("true"), &warr!(true));
+        assert_eq!(mw!(""), &warr!(""));
+    }
+
+    #[test]
+    fn test_warr_lossy() {
+        let x: &[u16] = &warr_lossy!("test");
+        assert_eq!(mw!("test"), x);
+        assert_ne!(mw!("test2"), x);
+        assert_eq!(mw!("testテスト🍣"), &warr_lossy!("testテスト🍣"));
+        assert_eq!(mw!("あ"), &warr_lossy!('あ'));
+        assert_eq!(mw!("4649"), &warr_lossy!(4649));
+        assert_eq!(mw!("3.14"), &warr_lossy!(3.14));
+        assert_eq!(mw!("true"), &warr_lossy!(true));
+    }
+
+    // The code page of this crate is configured to 1252 in Cargo.toml.
+    #[test]
+    fn test_metavariable() {
+        macro_rules! w {
+            ($x:expr) => {
+                warr!($x)
+            };
+        }
+        macro_rules! a {
+            ($cp:literal, $x:literal) => {
+                aarr_lossy!(cp = $cp, replace = '_', $x)
+            };
+        }
+        macro_rules! nested {
+            ($x:expr) => {
+                w!($x)
+            };
+        }
+        assert_eq!(mw!("テスト"), &w!("テスト"));
+        assert_eq!(mw!("4649"), &w!(4649));
+        assert_eq!(mw!("テスト"), &nested!("テスト"));
+        assert_eq!(b"\x83\x65__\0", &a!(932, "テ🍣"));
+    }
+
+    #[test]
+    fn test_multiple_literals() {
+        assert_eq!(mw!("Error 42: x"), &warr!("Error ", 42, ": ", 'x'));
+        assert_eq!(mw!("3.14true"), &warr_lossy!(3.14, true,));
+        assert_eq!(mw!("a"), &warr!("a",));
+        assert_eq!(
+            mw!(concat!("MyApp ", env!("CARGO_PKG_VERSION_MAJOR"))),
+            &warr!(concat!("My", "App"), " ", env!("CARGO_PKG_VERSION_MAJOR"))
+        );
+        assert_eq!(b"\x83\x651true\0", &aarr!(cp = 932, "テ", 1, true));
+        assert_eq!(b"ab?\0", &aarr_lossy!(cp = 1252, "a", 'b', "あ"));
+    }
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!(mw!("あテ"), &warr!(cp = 932, b"\x82\xA0\x83\x65"));
+        assert_eq!(mw!("中文"), &warr!(cp = 936, b"\xD6\xD0\xCE\xC4"));
+        assert_eq!(mw!("한국"), &warr!(cp = 949, b"\xC7\xD1\xB1\xB9"));
+        assert_eq!(mw!("中文"), &warr!(cp = 950, b"\xA4\xA4\xA4\xE5"));
+        assert_eq!(
+            mw!("中🍣\u{80}"),
+            &warr!(cp = 54936, b"\xD6\xD0\x94\x39\xB9\x37\x81\x30\x81\x30")
+        );
+        assert_eq!(mw!("Мир"), &warr!(cp = 1251, b"\xCC\xE8\xF0"));
+        // The code page of this crate is configured to 1252.
+        assert_eq!(mw!("é"), &warr!(b'\xE9'));
+        assert_eq!(mw!("aé€"), &warr_lossy!("a", b"\xE9", b'\x80'));
+        assert_eq!(mw!(""), &warr!(b""));
+        assert_eq!(mw!("テスト"), &warr!(c"テスト"));
+        assert_eq!(mw!("abc"), &warr!("a", c"b", b"c"));
+        assert_eq!(b"\x82\xA0\0", &aarr!(cp = 932, b"\x82\xA0"));
+        assert_eq!(b"\xE9\0", &aarr!(c"é"));
+        assert_eq!(b"?\0", &aarr_lossy!(cp = 1252, c"あ"));
+    }
+
+    // Windows uses another character for invalid bytes.
+    #[cfg(not(windows))]
+    #[test]
+    fn test_bytes_lossy() {
+        assert_eq!(mw!("a\u{FFFD}"), &warr_lossy!(cp = 932, b"a\x82"));
+        assert_eq!(mw!("\u{FFFD} "), &warr_lossy!(cp = 932, b"\x82 "));
+        assert_eq!(
+            mw!("\u{FFFD}"),
+            &warr_lossy!(cp = 54936, b"\x84\x31\xA5\x30")
+        );
+        assert_eq!(b"a?\0", &aarr_lossy!(cp = 932, b"a\x82"));
+    }
+
+    #[test]
+    fn test_allow_nul() {
+        assert_eq!(mw!("abc\0def"), &warr!(allow_nul, "abc\0def"));
+        assert_eq!(mw!("a\0"), &warr_lossy!(allow_nul, "a", '\0'));
+        assert_eq!(mw!("\0"), &warr!(allow_nul, c"", b"\0"));
+        assert_eq!(b"a\0b\0", &aarr!(allow_nul, "a\0b"));
+        assert_eq!(
+            b"\x83\x65\0??\0",
+            &aarr_lossy!(allow_nul, cp = 932, "テ\0🍣")
+        );
+        assert_eq!(
+            b"a\0b\0",
+            &aarr_lossy!(allow_nul, cp = 1252, replace = '\0', "aあb")
+        );
+    }
+
+    #[test]
+    fn test_nul() {
+        let x: [u16; 2] = warr!(nul = false, "ab");
+        assert_eq!(mw!("ab")[..2], x);
+        let x: [u16; 0] = warr_lossy!(nul = false, "");
+        assert_eq!(mw!("")[..0], x);
+        assert_eq!(mw!("ab"), &warr!(nul = true, "ab"));
+        let x: [u8; 2] = aarr!(nul = false, cp = 932, "テ");
+        assert_eq!(*b"\x83\x65", x);
+        assert_eq!(*b"a?", aarr_lossy!(nul = false, cp = 1252, "aあ"));
+        assert_eq!(*b"a\0", aarr!(nul = false, allow_nul, "a\0"));
+    }
+
+    #[test]
+    fn test_len() {
+        let x: [u16; 32] = warr!(len = 32, "Segoe UI");
+        assert_eq!(mw!("Segoe UI")[..], x[..9]);
+        assert!(x[9..].iter().all(|&c| c == 0));
+        assert_eq!(mw!("ab"), &warr_lossy!(len = 3, "ab"));
+        let x: [u16; 2] = warr!(len = 2, nul = false, "ab");
+        assert_eq!(mw!("ab")[..2], x);
+        let x: [u8; 260] = aarr!(len = 260, cp = 932, "テ");
+        assert_eq!(*b"\x83\x65\0", x[..3]);
+        assert!(x[3..].iter().all(|&c| c == 0));
+        assert_eq!(*b"a?\0\0", aarr_lossy!(len = 4, cp = 1252, "aあ"));
+    }
+
+    #[test]
+    fn test_oarr() {
+        // The default is the OEM code page of code page 1252.
+        assert_eq!(b"\xC3\xC4 OK\0", &oarr!("├─ OK"));
+        let b = oarr!(cp = 866, "Привет");
+        assert_eq!(b"\x8F\xE0\xA8\xA2\xA5\xE2\0", &b);
+        assert_eq!(b"\x9D\0", &oarr!(cp = 850, "Ø"));
+        assert_eq!(oarr!("├─"), oarr!(b"\xC3\xC4"));
+        assert_eq!(b"a_\0", &oarr_lossy!(replace = '_', "aあ"));
+        assert_eq!(b"A\0", &oarr_lossy!(cp = 437, best_fit, "Ａ"));
+        let b: [u8; 8] = oarr!(len = 8, cp = 737, "Ωμέγα");
+        assert_eq!(b"\x97\xA3\xE2\x9A\x98\0\0\0", &b);
+    }
+
+    #[test]
+    fn test_tarr() {
+        #[cfg(not(windy_unicode))]
+        {
+            assert_eq!(aarr!(cp = 932, "テ"), tarr!(cp = 932, "テ"));
+            assert_eq!(b"a_\0", &tarr_lossy!(replace = '_', "aあ"));
+            assert_eq!(b"A\0", &tarr!(best_fit, "Ａ"));
+        }
+        #[cfg(windy_unicode)]
+        {
+            assert_eq!(warr!("テ"), tarr!(cp = 932, "テ"));
+            assert_eq!(mw!("aあ"), &tarr_lossy!(replace = '_', "aあ"));
+            assert_eq!(mw!("Ａ"), &tarr!(best_fit, "Ａ"));
+        }
+        let x = tarr!(len = 4, nul = false, "ab");
+        assert_eq!(4, x.len());
+    }
+
+    #[test]
+    fn test_builtin_macro() {
+        let v = concat!("MyApp v", env!("CARGO_PKG_VERSION"));
+        assert_eq!(
+            mw!(v),
+            &warr!(concat!("MyApp v", env!("CARGO_PKG_VERSION")))
+        );
+        assert_eq!(
+            mw!("a1btrue"),
+            &warr!(std::concat!(concat!("a", 1), 'b', true))
+        );
+        assert_eq!(mw!("a + b"), &warr!(stringify!(a + b)));
+        assert_eq!(mw!("windy-macros"), &warr!(option_env!("CARGO_PKG_NAME")));
+        assert_eq!(mw!("テスト"), &warr!(include_str!("data/include.txt")));
+        assert_eq!(
+            b"\x83\x65\x83\x58\x83\x67\0",
+            &aarr!(cp = 932, include_str!("data/include.txt"))
+        );
+    }
+
+    #[test]
+    fn test_aarr() {
+        let x: &[u8] = &aarr!("test");
+        assert_eq!(b"test\0", x);
+        assert_ne!(b"test2\0", x);
+        assert_eq!(b"caf\xE9 \x80\0", &aarr!("café €"));
+        assert_eq!(b"\xE9\0", &aarr!('é'));
+        assert_eq!(b"4649\0", &aarr!(4649));
+        assert_eq!(b"3.14\0", &aarr!(3.14));
+        assert_eq!(b"true\0", &aarr!(true));
+        assert_eq!(b"\0", &aarr!(""));
+    }
+
+    #[test]
+    fn test_aarr_lossy() {
+        let x: &[u8] = &aarr_lossy!("test");
+        assert_eq!(b"test\0", x);
+        assert_eq!(b"caf\xE9 \x80\0", &aarr_lossy!("café €"));
+        // A surrogate pair becomes two default characters.
+        assert_eq!(b"test?????\0", &aarr_lossy!("testテスト🍣"));
+        assert_eq!(b"?\0", &aarr_lossy!('あ'));
+        assert_eq!(b"4649\0", &aarr_lossy!(4649));
+    }
+
+    #[test]
+    fn test_aarr_cp() {
+        assert_eq!(b"caf\xE9 \x80\0", &aarr!(cp = 1252, "café €"));
+        assert_eq!(b"\xCC\xE8\xF0\0", &aarr!(cp = 1251, "Мир"));
+        assert_eq!(b"\xD9\xEC\xDD\xE3\xE1\0", &aarr!(cp = 1253, "Ωμέγα"));
+        assert_eq!(b"\xE4\xB7\xC2\0", &aarr!(cp = 874, "ไทย"));
+        assert_eq!(b"\x82\0", &aarr!(cp = 437, 'é'));
+        assert_eq!(b"\x82\0", &aarr!(cp = 850, 'é'));
+        assert_eq!(b"\x83\x65\x83\x58\x83\x67\0", &aarr!(cp = 932, "テスト"));
+        assert_eq!(b"\xD6\xD0\xCE\xC4\0", &aarr!(cp = 936, "中文"));
+        assert_eq!(b"\xC7\xD1\xB1\xB9\0", &aarr!(cp = 949, "한국"));
+        assert_eq!(b"\xA4\xA4\xA4\xE5\0", &aarr!(cp = 950, "中文"));
+        assert_eq!(
+            b"\xD6\xD0\xCE\xC4\x94\x39\xB9\x37\0",
+            &aarr!(cp = 54936, "中文🍣")
+        );
+        assert_eq!(b"4649\0", &aarr!(cp = 932, 4649));
+    }
+
+    #[test]
+    fn test_aarr_lossy_cp() {
+        assert_eq!(b"caf\xE9 ?\0", &aarr_lossy!(cp = 1252, "café あ"));
+        assert_eq!(b"\x83\x65??\0", &aarr_lossy!(cp = 932, "テ🍣"));
+        assert_eq!(b"\x94\x39\xB9\x37\0", &aarr_lossy!(cp = 54936, "🍣"));
+        assert_eq!(b"?\0", &aarr_lossy!(cp = 1251, 'é'));
+    }
+
+    #[test]
+    fn test_aarr_lossy_replace() {
+        assert_eq!(b"file:__\0", &aarr_lossy!(replace = '_', "file:名前"));
+        assert_eq!(
+            b"file:__\0",
+            &aarr_lossy!(cp = 1252, replace = '_', "file:名前")
+        );
+        assert_eq!(b"\xE9\xE9\0", &aarr_lossy!(replace = 'é', "🍣"));
+        assert_eq!(
+            b"\x83\x65__\0",
+            &aarr_lossy!(cp = 932, replace = '_', "テ🍣")
+        );
+        assert_eq!(b"_\0", &aarr_lossy!(cp = 1251, replace = '_', 'é'));
+    }
+
+    #[test]
+    fn test_aarr_best_fit() {
+        assert_eq!(b"ABC\0", &aarr!(cp = 1252, best_fit, "ＡＢＣ"));
+        assert_eq!(b"\"a\"\0", &aarr!(cp = 437, best_fit, "“ā”"));
+        // A character which the code page has isn't changed.
+        assert_eq!(b"\x93\xE9\0", &aarr!(cp = 1252, best_fit, "“é"));
+        assert_eq!(b"e?\0", &aarr_lossy!(cp = 1251, best_fit, "éあ"));
+        assert_eq!(
+            b"e_\0",
+            &aarr_lossy!(cp = 1251, best_fit, replace = '_', "éあ")
+        );
+        assert_eq!(b"\x82\x60\0", &aarr!(cp = 932, best_fit, "Ａ"));
+    }
+
+    // The ANSI tables approximate the best fit mappings until they are
+    // generated from WindowsBestFit, and don't have these.
+    #[cfg_attr(not(windows), ignore = "needs the WindowsBestFit tables")]
+    #[test]
+    fn test_aarr_best_fit_tables() {
+        assert_eq!(b"8\0", &aarr_lossy!(cp = 1252, best_fit, "∞"));
+        assert_eq!(b"\\\0", &aarr_lossy!(cp = 932, best_fit, "¥"));
+    }
+
+    #[test]
+    fn test_acp_info() {
+        mod info {
+            windy_macros::acp_info!(pub);
+        }
+        // The code page of this crate is configured to 1252 in Cargo.toml.
+        assert_eq!(1252, info::ACP);
+        assert_eq!(1, info::ACP_MAX_CHAR_SIZE);
+        #[cfg(not(windows))]
+        assert_eq!("ANSI - Latin I", info::ACP_NAME);
+    }
+}
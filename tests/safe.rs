@@ -0,0 +1,86 @@
+// Copyright takubokudori.
+// This source code is licensed under the MIT or Apache-2.0 license.
+//! Tests of `safe`, whose expansions have no `unsafe` code.
+#![cfg(windows)]
+#![forbid(unsafe_code)]
+
+#[cfg(test)]
+mod tests {
+    use windy::*;
+    use windy_macros::*;
+
+    // Makes a string.
+    macro_rules! ms {
+        (@a $x:expr) => {
+            AString::from_str_lossy($x)
+        };
+        (@w $x:expr) => {
+            WString::from_str_lossy($x)
+        };
+    }
+
+    #[test]
+    fn test_string() {
+        assert_eq!(ms!(@w "testテスト🍣"), wstring!(safe, "testテスト🍣"));
+        assert_eq!(
+            ms!(@w "testテスト🍣"),
+            wstring_lossy!(safe, "testテスト🍣")
+        );
+        assert_eq!(ms!(@a "test"), astring!(safe, "test"));
+        assert_eq!(
+            ms!(@a "testテスト🍣"),
+            astring_lossy!(safe, "testテスト🍣")
+        );
+        let x = astring!(safe, cp = 932, "テスト");
+        assert_eq!(b"\x83\x65\x83\x58\x83\x67\0", x.as_bytes_with_nul());
+        let x = ostring!(safe, cp = 437, "├─ OK");
+        assert_eq!(b"\xC3\xC4 OK\0", x.as_bytes_with_nul());
+        let x = ostring_lossy!(safe, cp = 437, "テス OK");
+        assert_eq!(b"?? OK\0", x.as_bytes_with_nul());
+        #[cfg(not(windy_unicode))]
+        assert_eq!(ms!(@a "test"), tstring!(safe, "test"));
+        #[cfg(windy_unicode)]
+        assert_eq!(ms!(@w "test"), tstring!(safe, "test"));
+    }
+
+    #[test]
+    fn test_str() {
+        let x = WString::from(wstr!(safe, "testテスト🍣"));
+        assert_eq!(ms!(@w "testテスト🍣"), x);
+        let x = WString::from(wstr_lossy!(safe, "test"));
+        assert_eq!(ms!(@w "test"), x);
+        let x = astr!(safe, cp = 932, "テスト");
+        assert_eq!(b"\x83\x65\x83\x58\x83\x67\0", x.to_bytes_with_nul());
+        let x = astr_lossy!(safe, cp = 1252, "aあ");
+        assert_eq!(b"a?\0", x.to_bytes_with_nul());
+        let x = ostr!(safe, cp = 437, "├─ OK");
+        assert_eq!(b"\xC3\xC4 OK\0", x.to_bytes_with_nul());
+        let x = ostr_lossy!(safe, cp = 437, "テス OK");
+        assert_eq!(b"?? OK\0", x.to_bytes_with_nul());
+        let x = wstr!(safe, allow_nul, "a\0b");
+        assert_eq!(&[0x61, 0, 0x62, 0], x.to_bytes_with_nul());
+        #[cfg(not(windy_unicode))]
+        assert_eq!(b"test\0", tstr!(safe, "test").to_bytes_with_nul());
+    }
+
+    #[test]
+    fn test_const() {
+        const TITLE: &WStr = wstr!(safe, "My App");
+        static ANSI: &AStr = astr!(safe, static cp = 932, "テスト");
+        const NAMES: [&WStr; 2] =
+            [wstr!(static safe, "foo"), wstr_lossy!(safe, "bar")];
+        assert_eq!(
+            ms!(@w "My App").as_bytes_with_nul(),
+            TITLE.to_bytes_with_nul()
+        );
+        assert_eq!(b"\x83\x65\x83\x58\x83\x67\0", ANSI.to_bytes_with_nul());
+        assert_eq!(
+            ms!(@w "foo").as_bytes_with_nul(),
+            NAMES[0].to_bytes_with_nul()
+        );
+        assert_eq!(
+            ms!(@w "bar").as_bytes_with_nul(),
+            NAMES[1].to_bytes_with_nul()
+        );
+    }
+}
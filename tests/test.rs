@@ -186,4 +186,161 @@ mod tests {
         assert_eq!(ms!(@ab "3.14"), &aarr_lossy!(3.14));
         assert_eq!(ms!(@ab "true"), &aarr_lossy!(true));
     }
+
+    #[test]
+    fn test_cp() {
+        let b = b"\x83\x65\x83\x58\x83\x67\0";
+        assert_eq!(b, astring!(cp = 932, "テスト").as_bytes_with_nul());
+        assert_eq!(b, astr!(cp = 932, "テスト").to_bytes_with_nul());
+        assert_eq!(b, &aarr!(cp = 932, "テスト"));
+        let b = b"caf\xE9?\0";
+        assert_eq!(b, astring_lossy!(cp = 1252, "caféあ").as_bytes_with_nul());
+        assert_eq!(b, astr_lossy!(cp = 1252, "caféあ").to_bytes_with_nul());
+        assert_eq!(b, &aarr_lossy!(cp = 1252, "caféあ"));
+    }
+
+    #[test]
+    fn test_replace() {
+        let b = b"file:__\0";
+        let s = astring_lossy!(cp = 1252, replace = '_', "file:名前");
+        assert_eq!(b, s.as_bytes_with_nul());
+        let s = astr_lossy!(cp = 1252, replace = '_', "file:名前");
+        assert_eq!(b, s.to_bytes_with_nul());
+        assert_eq!(b, &aarr_lossy!(cp = 1252, replace = '_', "file:名前"));
+        let b = b"\x83\x65__\0";
+        assert_eq!(b, &aarr_lossy!(cp = 932, replace = '_', "テ🍣"));
+    }
+
+    #[test]
+    fn test_metavariable() {
+        macro_rules! w {
+            ($x:expr) => {
+                wstr!($x)
+            };
+        }
+        macro_rules! a {
+            ($x:literal) => {
+                astr!(cp = 932, $x)
+            };
+        }
+        assert_eq!(ms!(@wb "テスト"), w!("テスト").to_bytes_with_nul());
+        assert_eq!(b"\x83\x65\0", a!("テ").to_bytes_with_nul());
+    }
+
+    #[test]
+    fn test_multiple_literals() {
+        let s = wstr!("Error ", 42, ": ", 'x');
+        assert_eq!(ms!(@wb "Error 42: x"), s.to_bytes_with_nul());
+        let s = astring!("Error ", 42, ": ", 'x');
+        assert_eq!(ms!(@ab "Error 42: x"), s.as_bytes_with_nul());
+    }
+
+    #[test]
+    fn test_bytes() {
+        let s = wstr!(cp = 932, b"\x82\xA0\x83\x65");
+        assert_eq!(ms!(@wb "あテ"), s.to_bytes_with_nul());
+        let s = wstring!(c"テスト");
+        assert_eq!(ms!(@w "テスト"), s);
+        let s = astr!(cp = 932, b"\x82\xA0");
+        assert_eq!(b"\x82\xA0\0", s.to_bytes_with_nul());
+    }
+
+    #[test]
+    fn test_allow_nul() {
+        let s = wstr!(allow_nul, "abc\0def");
+        assert_eq!(ms!(@wb "abc\0def"), s.to_bytes_with_nul());
+        let s = astr!(allow_nul, cp = 1252, "abc\0def");
+        assert_eq!(b"abc\0def\0", s.to_bytes_with_nul());
+        let s = astring!(allow_nul, "abc\0def");
+        assert_eq!(b"abc\0def\0", s.as_bytes_with_nul());
+    }
+
+    #[test]
+    fn test_best_fit() {
+        let b = b"ABC\0";
+        let s = astring!(cp = 1252, best_fit, "ＡＢＣ");
+        assert_eq!(b, s.as_bytes_with_nul());
+        assert_eq!(b, astr!(cp = 1252, best_fit, "ＡＢＣ").to_bytes_with_nul());
+        assert_eq!(b, &aarr!(cp = 1252, best_fit, "ＡＢＣ"));
+        assert_eq!(b"e?\0", &aarr_lossy!(cp = 1251, best_fit, "éあ"));
+    }
+
+    mod reexport {
+        pub use windy;
+    }
+
+    macro_rules! my_wstr {
+        ($($t:tt)*) => { wstr!(crate = $crate::tests::reexport::windy, $($t)*) };
+    }
+
+    #[test]
+    fn test_crate() {
+        let s = wstring!(crate = ::windy, "test");
+        assert_eq!(ms!(@w "test"), s);
+        let s = astr!(crate = reexport::windy, cp = 1252, "test");
+        assert_eq!(ms!(@ab "test"), s.to_bytes_with_nul());
+        static S: &WStr = my_wstr!(static "test");
+        assert_eq!(ms!(@wb "test"), S.to_bytes_with_nul());
+    }
+
+    #[test]
+    fn test_same_ansi_bytes() {
+        let b = b"caf\xE9\0";
+        assert_eq!(b, astring!("café").as_bytes_with_nul());
+        assert_eq!(b, astr!("café").to_bytes_with_nul());
+        assert_eq!(b, &aarr!("café"));
+        let b = b"\x83\x65\x83\x58\x83\x67\0";
+        assert_eq!(b, astring!(cp = 932, "テスト").as_bytes_with_nul());
+        assert_eq!(b, astr!(cp = 932, "テスト").to_bytes_with_nul());
+        assert_eq!(b, &aarr!(cp = 932, "テスト"));
+        let b = b"caf?\0";
+        assert_eq!(b, astring_lossy!(cp = 1251, "café").as_bytes_with_nul());
+        assert_eq!(b, astr_lossy!(cp = 1251, "café").to_bytes_with_nul());
+        assert_eq!(b, &aarr_lossy!(cp = 1251, "café"));
+    }
+
+    #[test]
+    fn test_oem() {
+        let b = b"\xC3\xC4 OK\0";
+        assert_eq!(b, ostring!(cp = 437, "├─ OK").as_bytes_with_nul());
+        assert_eq!(b, ostr!(cp = 437, "├─ OK").to_bytes_with_nul());
+        let s = ostring_lossy!(cp = 866, "├あ OK");
+        assert_eq!(b"\xC3? OK\0", s.as_bytes_with_nul());
+        let s = ostr_lossy!(cp = 437, "テス OK");
+        assert_eq!(b"?? OK\0", s.to_bytes_with_nul());
+    }
+
+    #[test]
+    fn test_tchar() {
+        #[cfg(not(windy_unicode))]
+        {
+            assert_eq!(ms!(@a "test"), tstring!("test"));
+            assert_eq!(ms!(@a "test"), tstring_lossy!("test"));
+            assert_eq!(ms!(@ab "test"), tstr!("test").to_bytes_with_nul());
+            let s = tstr_lossy!(static "test");
+            assert_eq!(ms!(@ab "test"), s.to_bytes_with_nul());
+        }
+        #[cfg(windy_unicode)]
+        {
+            assert_eq!(ms!(@w "test"), tstring!("test"));
+            assert_eq!(ms!(@w "test"), tstring_lossy!("test"));
+            assert_eq!(ms!(@wb "test"), tstr!("test").to_bytes_with_nul());
+            let s = tstr_lossy!(static "test");
+            assert_eq!(ms!(@wb "test"), s.to_bytes_with_nul());
+        }
+    }
+
+    #[test]
+    fn test_static() {
+        static TITLE: &WStr = wstr!(static "My App");
+        const NAMES: [&WStr; 2] =
+            [wstr!(static "foo"), wstr_lossy!(static "bar")];
+        static ANSI: &AStr = astr!(static cp = 932, "テスト");
+        const LOSSY: &AStr = astr_lossy!(static cp = 1252, "aあ");
+        assert_eq!(ms!(@wb "My App"), TITLE.to_bytes_with_nul());
+        assert_eq!(ms!(@wb "foo"), NAMES[0].to_bytes_with_nul());
+        assert_eq!(ms!(@wb "bar"), NAMES[1].to_bytes_with_nul());
+        assert_eq!(b"\x83\x65\x83\x58\x83\x67\0", ANSI.to_bytes_with_nul());
+        assert_eq!(b"a?\0", LOSSY.to_bytes_with_nul());
+    }
 }
@@ -0,0 +1,410 @@
+#!/usr/bin/env python3
+# Copyright takubokudori.
+# This source code is licensed under the MIT or Apache-2.0 license.
+"""Generates the code page and best fit tables in core/src/codepage/.
+
+The code pages which Windows has a best fit table of are generated from those
+tables, which Microsoft publishes at the Unicode Consortium (WindowsBestFit):
+https://www.unicode.org/Public/MAPPINGS/VENDORS/MICSFT/WindowsBestFit/
+
+They are vendored in tools/bestfit/ so that the tables can be regenerated
+from the repository, and `--fetch` downloads them there again. `--check`
+fails if the tables in the repository differ from the generated ones instead
+of writing them.
+
+The best fit mappings of a code page are the entries of its WCTABLE which
+don't decode to the same character, e.g. U+221E to "8" in 1252.
+
+The OEM code pages, which have no best fit table, are generated from Python's
+codecs, which are generated from the Microsoft mapping tables published by the
+Unicode Consortium (VENDORS/MICSFT/PC), and their best fit mappings are
+approximated (see approximate_fit). GB18030 is generated from Python's codec
+as well.
+
+Usage: python3 tools/gen_codepage.py [--fetch] [--check]
+"""
+
+import os
+import struct
+import sys
+import unicodedata
+import urllib.request
+
+ROOT = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..")
+OUT = os.path.join(ROOT, "core", "src", "codepage")
+BESTFIT = os.path.join(ROOT, "tools", "bestfit")
+BESTFIT_URL = "https://www.unicode.org/Public/MAPPINGS/VENDORS/MICSFT/" \
+    "WindowsBestFit/bestfit%d.txt"
+
+HEADER = """\
+// Copyright takubokudori.
+// This source code is licensed under the MIT or Apache-2.0 license.
+// This file is generated by tools/gen_codepage.py. Do not edit it by hand.
+"""
+
+# The code pages which have a WindowsBestFit table.
+BESTFIT_CPS = [
+    874, 932, 936, 949, 950, 1250, 1251, 1252, 1253, 1254, 1255, 1256, 1257,
+    1258,
+]
+
+# (code page, description)
+SBCS = [
+    (874, "Thai"),
+    (1250, "Central European"),
+    (1251, "Cyrillic"),
+    (1252, "Western European"),
+    (1253, "Greek"),
+    (1254, "Turkish"),
+    (1255, "Hebrew"),
+    (1256, "Arabic"),
+    (1257, "Baltic"),
+    (1258, "Vietnamese"),
+    (437, "OEM United States"),
+    (850, "OEM Multilingual Latin 1"),
+    (720, "OEM Arabic"),
+    (737, "OEM Greek"),
+    (775, "OEM Baltic"),
+    (852, "OEM Latin 2"),
+    (855, "OEM Cyrillic"),
+    (857, "OEM Turkish"),
+    (862, "OEM Hebrew"),
+    (866, "OEM Russian"),
+]
+
+
+class BestFit:
+    """A WindowsBestFit table.
+
+    - single: the characters of the single bytes.
+    - leads: the lead bytes.
+    - double: the characters of the double bytes, whose keys are big-endian.
+    - wide: the codes which the characters are encoded to, including the best
+      fit mappings.
+    """
+
+    def __init__(self, cp):
+        self.single = {}
+        self.leads = set()
+        self.double = {}
+        self.wide = {}
+        path = os.path.join(BESTFIT, "bestfit%d.txt" % cp)
+        if not os.path.exists(path):
+            sys.exit("%s is missing; run with --fetch and commit it" % path)
+        with open(path, encoding="latin-1") as f:
+            # Comments start with a semicolon.
+            lines = (x.split(";")[0].split() for x in f)
+            self._parse(x for x in lines if x)
+
+    def _parse(self, lines):
+        def entries(n):
+            for _ in range(n):
+                x = next(lines)
+                yield int(x[0], 16), int(x[1], 16)
+
+        for words in lines:
+            key = words[0].upper()
+            if key == "MBTABLE":
+                self.single.update(entries(int(words[1])))
+            elif key == "DBCSRANGE":
+                # Each range is followed by the tables of its lead bytes.
+                for _ in range(int(words[1])):
+                    [(lo, hi)] = entries(1)
+                    for lead in range(lo, hi + 1):
+                        words = next(lines)
+                        assert words[0].upper() == "DBCSTABLE", words
+                        self.leads.add(lead)
+                        for t, c in entries(int(words[1])):
+                            self.double[lead << 8 | t] = c
+            elif key == "WCTABLE":
+                self.wide.update(entries(int(words[1])))
+
+    def decode(self, code):
+        if code < 0x100:
+            return self.single.get(code, 0)
+        return self.double.get(code, 0)
+
+
+def fetch():
+    os.makedirs(BESTFIT, exist_ok=True)
+    for cp in BESTFIT_CPS:
+        path = os.path.join(BESTFIT, "bestfit%d.txt" % cp)
+        print("downloading", BESTFIT_URL % cp)
+        urllib.request.urlretrieve(BESTFIT_URL % cp, path)
+
+
+# The paths of the tables which differ from the generated ones with --check.
+DIFFERENT = []
+
+
+def write(path, data):
+    """Writes data to path, or compares them with --check."""
+    if "--check" not in sys.argv[1:]:
+        with open(path, "wb") as f:
+            f.write(data)
+        return
+    with open(path, "rb") as f:
+        if f.read() != data:
+            DIFFERENT.append(os.path.relpath(path, ROOT))
+
+
+def sbcs_table(cp):
+    if cp in BESTFIT_CPS:
+        t = BestFit(cp)
+        for b in range(0x80):
+            assert t.single.get(b) == b, (cp, b)
+        return [t.single.get(b, 0) for b in range(0x80, 0x100)]
+    table = []
+    for b in range(0x80, 0x100):
+        try:
+            c = ord(bytes([b]).decode("cp%d" % cp))
+        except UnicodeDecodeError:
+            # Windows maps the unassigned bytes in 0x80-0x9F to the C1
+            # controls of the same value.
+            c = b if b < 0xA0 else 0
+        table.append(c)
+    for b in range(0x80):
+        assert bytes([b]).decode("cp%d" % cp) == chr(b)
+    return table
+
+
+def gen_sbcs():
+    out = [HEADER]
+    out.append("//! Single-byte code pages.\n")
+    out.append("//!\n")
+    out.append(
+        "//! Each table maps the bytes 0x80-0xFF to UTF-16. "
+        "0x00-0x7F are ASCII and\n"
+    )
+    out.append("//! 0 means the byte is unassigned.\n")
+    for cp, desc in SBCS:
+        out.append("\n/// Code page %d (%s).\n" % (cp, desc))
+        out.append("#[rustfmt::skip]\n")
+        out.append("pub(crate) const CP%d: [u16; 128] = [\n" % cp)
+        t = sbcs_table(cp)
+        for i in range(0, 128, 8):
+            row = ", ".join("0x%04X" % c for c in t[i:i + 8])
+            out.append("    %s, // 0x%02X\n" % (row, 0x80 + i))
+        out.append("];\n")
+    write(os.path.join(OUT, "sbcs.rs"), "".join(out).encode())
+
+
+# The double-byte code pages. 54936 is the two-byte part of GB18030, which is
+# generated from Python's codec.
+DBCS = [932, 936, 949, 950, 54936]
+
+# Marks a lead byte in the single-byte part of a table.
+LEAD = 0xFFFF
+
+
+def dbcs_table(cp):
+    t = BestFit(cp)
+    single = [
+        LEAD if b in t.leads else t.single.get(b, 0)
+        for b in range(0x80, 0x100)
+    ]
+    rows = [[0] * 256 for _ in range(0x81, 0xFF)]
+    for code, c in t.double.items():
+        rows[(code >> 8) - 0x81][code & 0xFF] = c
+    return single, rows
+
+
+def gb18030_table():
+    single = [0] + [LEAD] * 126 + [0]
+    rows = [[0] * 256 for _ in range(0x81, 0xFF)]
+    for lead in range(0x81, 0xFF):
+        for t in range(0x100):
+            try:
+                c = bytes([lead, t]).decode("gb18030")
+            except UnicodeDecodeError:
+                continue
+            if len(c) == 1:
+                rows[lead - 0x81][t] = ord(c)
+    return single, rows
+
+
+def encode_overrides(cp, single, rows):
+    """Returns the characters which Windows encodes to another code than the
+    first one in the table."""
+    if cp not in BESTFIT_CPS:
+        return []
+    t = BestFit(cp)
+    first = {}
+    for b, c in enumerate(single):
+        if c not in (0, LEAD):
+            first.setdefault(c, 0x80 + b)
+    for i, row in enumerate(rows):
+        for tr, c in enumerate(row):
+            if c:
+                first.setdefault(c, ((0x81 + i) << 8) | tr)
+    return [
+        (c, code) for c, code in sorted(t.wide.items())
+        if c in first and t.decode(code) == c and code != first[c]
+    ]
+
+
+def gen_dbcs():
+    for cp in DBCS:
+        if cp in BESTFIT_CPS:
+            single, rows = dbcs_table(cp)
+        else:
+            single, rows = gb18030_table()
+        overrides = encode_overrides(cp, single, rows)
+        data = struct.pack("<128H", *single)
+        for row in rows:
+            data += struct.pack("<256H", *row)
+        data += struct.pack("<H", len(overrides))
+        for c, code in overrides:
+            data += struct.pack("<HH", c, code)
+        write(os.path.join(OUT, "cp%d.bin" % cp), data)
+
+
+def gb18030_linear(b):
+    return (((b[0] - 0x81) * 10 + (b[1] - 0x30)) * 126 + (b[2] - 0x81)) * 10 \
+        + (b[3] - 0x30)
+
+
+def gen_gb18030():
+    ranges = []
+    prev = None
+    for c in range(0x80, 0x10000):
+        if 0xD800 <= c <= 0xDFFF:
+            continue
+        b = chr(c).encode("gb18030")
+        if len(b) != 4:
+            continue
+        linear = gb18030_linear(b)
+        if prev != (c - 1, linear - 1):
+            ranges.append((c, linear))
+        prev = (c, linear)
+    out = [HEADER]
+    out.append("//! The four-byte ranges of GB18030 in the BMP.\n\n")
+    out.append(
+        "/// Pairs of the first character of a range and its linear index.\n"
+    )
+    out.append("#[rustfmt::skip]\n")
+    out.append("pub(crate) const RANGES: [(u16, u32); %d] = [\n" % len(ranges))
+    for i in range(0, len(ranges), 4):
+        row = " ".join(
+            "(0x%04X, %d)," % (c, linear) for c, linear in ranges[i:i + 4]
+        )
+        out.append("    %s\n" % row)
+    out.append("];\n")
+    os.makedirs(os.path.join(OUT, "gb18030"), exist_ok=True)
+    write(os.path.join(OUT, "gb18030", "ranges.rs"), "".join(out).encode())
+
+
+# Best fit mappings which aren't compatibility decompositions.
+BEST_FIT_PATCHES = {
+    0x00AB: "<",
+    0x00BB: ">",
+    0x2010: "-",
+    0x2012: "-",
+    0x2013: "-",
+    0x2014: "-",
+    0x2015: "-",
+    0x2018: "'",
+    0x2019: "'",
+    0x201A: ",",
+    0x201B: "'",
+    0x201C: '"',
+    0x201D: '"',
+    0x201E: '"',
+    0x201F: '"',
+    0x2032: "'",
+    0x2035: "`",
+    0x2039: "<",
+    0x203A: ">",
+    0x2212: "-",
+    0x2215: "/",
+    0x2216: "\\",
+    0x2223: "|",
+    0x223C: "~",
+}
+
+
+def approximate_fit(c):
+    """Returns the character which c falls back to in an OEM code page, or
+    None.
+
+    Microsoft doesn't publish the best fit tables of the OEM code pages, so
+    they are approximated by the compatibility decomposition without the
+    combining marks, e.g. U+00E9 to "e" and U+FF21 to "A".
+    """
+    if c in BEST_FIT_PATCHES:
+        return BEST_FIT_PATCHES[c]
+    d = "".join(
+        x for x in unicodedata.normalize("NFKD", chr(c))
+        if not unicodedata.combining(x)
+    )
+    # Spacing marks such as U+00A8 decompose to a space and a combining mark.
+    if d == " " and unicodedata.category(chr(c)) != "Zs":
+        return None
+    if len(d) == 1 and d != chr(c) and ord(d) < 0x10000:
+        return d
+    return None
+
+
+def best_fit_table(cp):
+    """Returns the pairs of a character which cp doesn't have and the code
+    which it is encoded to in the best fit mode."""
+    if cp in BESTFIT_CPS:
+        t = BestFit(cp)
+        return sorted(
+            (c, code) for c, code in t.wide.items() if t.decode(code) != c
+        )
+    enc = {c: c for c in range(0x80)}
+    for i, c in enumerate(sbcs_table(cp)):
+        if c:
+            enc.setdefault(c, 0x80 + i)
+    fit = []
+    for c in range(0x80, 0x10000):
+        if 0xD800 <= c <= 0xDFFF or c in enc:
+            continue
+        d = approximate_fit(c)
+        if d is not None and ord(d) in enc:
+            fit.append((c, enc[ord(d)]))
+    return fit
+
+
+def gen_best_fit():
+    out = [HEADER]
+    out.append("//! The best fit mappings of the WindowsBestFit tables.\n")
+    out.append("//!\n")
+    out.append(
+        "//! Each table has pairs of a character which the code page doesn't "
+        "have and\n"
+    )
+    out.append(
+        "//! the code which Windows encodes it to in the best fit mode. The "
+        "OEM code\n"
+    )
+    out.append(
+        "//! pages have no such table, so theirs are approximated by the "
+        "compatibility\n"
+    )
+    out.append("//! decomposition.\n")
+    oem = [cp for cp, _ in SBCS if cp not in BESTFIT_CPS]
+    for cp in BESTFIT_CPS + oem:
+        fit = best_fit_table(cp)
+        out.append("\n/// Code page %d.\n" % cp)
+        out.append("#[rustfmt::skip]\n")
+        out.append(
+            "pub(crate) const CP%d: [(u16, u16); %d] = [\n" % (cp, len(fit))
+        )
+        for i in range(0, len(fit), 5):
+            row = " ".join("(0x%04X, 0x%04X)," % x for x in fit[i:i + 5])
+            out.append("    %s\n" % row)
+        out.append("];\n")
+    write(os.path.join(OUT, "best_fit.rs"), "".join(out).encode())
+
+
+if __name__ == "__main__":
+    if "--fetch" in sys.argv[1:]:
+        fetch()
+    gen_sbcs()
+    gen_dbcs()
+    gen_gb18030()
+    gen_best_fit()
+    if DIFFERENT:
+        sys.exit("not generated by this script: %s" % ", ".join(DIFFERENT))